@@ -5,9 +5,11 @@ use crate::avm1::object::{Object, TObject};
 use crate::avm1::property::Attribute;
 use crate::avm1::runtime::skip_actions;
 use crate::avm1::scope::Scope;
+use crate::avm1::VariableDumper;
 use crate::avm1::{fscommand, globals, scope, ArrayObject, ScriptObject, Value};
 use crate::backend::navigator::{NavigationMethod, Request};
 use crate::context::UpdateContext;
+use crate::debugger::{CodeLocation, PausedState};
 use crate::display_object::{DisplayObject, MovieClip, TDisplayObject, TDisplayObjectContainer};
 use crate::ecma_conversions::{f64_to_wrapping_i32, f64_to_wrapping_u32};
 use crate::loader::MovieLoaderVMData;
@@ -366,7 +368,8 @@ impl<'a, 'gc> Activation<'a, 'gc> {
             ),
         );
         let constant_pool = parent_activation.context.avm1.constant_pool();
-        let child_name = parent_activation.id.child(name);
+        let name: Cow<'static, str> = name.into();
+        let child_name = parent_activation.id.child(name.clone());
         let mut child_activation = Activation::from_action(
             parent_activation.context.reborrow(),
             child_name,
@@ -377,7 +380,9 @@ impl<'a, 'gc> Activation<'a, 'gc> {
             clip_obj.into(),
             None,
         );
-        child_activation.run_actions(code)
+        child_activation.run_body(code, || {
+            CodeLocation::Other(format!("{} {name}", active_clip.path()))
+        })
     }
 
     /// Add a stack frame that executes code in initializer scope.
@@ -430,6 +435,18 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         }
     }
 
+    /// Run a function body or script, which the debugger tracks as `location`.
+    pub fn run_body(
+        &mut self,
+        code: SwfSlice,
+        location: impl FnOnce() -> CodeLocation,
+    ) -> Result<ReturnType<'gc>, Error<'gc>> {
+        self.context.debugger.enter_frame(location);
+        let result = self.run_actions(code);
+        self.context.debugger.exit_frame();
+        result
+    }
+
     /// Run a single action from a given action reader.
     fn do_action<'b>(
         &mut self,
@@ -448,6 +465,7 @@ impl<'a, 'gc> Activation<'a, 'gc> {
             //Executing beyond the end of a function constitutes an implicit return.
             Ok(FrameControl::Return(ReturnType::Implicit))
         } else {
+            let offset = reader.get_ref().as_ptr() as usize - data.as_ref().as_ptr() as usize;
             let action = reader.read_action()?;
             avm_debug!(
                 self.context.avm1,
@@ -455,6 +473,10 @@ impl<'a, 'gc> Activation<'a, 'gc> {
                 self.id.depth(),
             );
 
+            if self.context.debugger.should_pause() {
+                self.pause_in_debugger(offset, &action);
+            }
+
             match action {
                 Action::Add => self.action_add(),
                 Action::Add2 => self.action_add_2(),
@@ -561,6 +583,54 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         }
     }
 
+    /// Hand control to the debugger before running `action`.
+    fn pause_in_debugger(&mut self, offset: usize, action: &Action) {
+        let mut locals = Vec::new();
+        if self.scope.class() == scope::ScopeClass::Local {
+            let object = self.scope.locals_cell();
+            for key in object.get_keys(self) {
+                let value = object
+                    .get_local_stored(key, self)
+                    .unwrap_or(Value::Undefined);
+                locals.push((key.to_string(), VariableDumper::summarize(&value)));
+            }
+        }
+        if let Some(registers) = self.local_registers {
+            let registers = registers.read();
+            for id in 0..registers.len() {
+                let value = registers.get(id).cloned().unwrap_or(Value::Undefined);
+                locals.push((format!("register{id}"), VariableDumper::summarize(&value)));
+            }
+        }
+
+        let mut scope_chain = Vec::new();
+        let mut scope = Some(self.scope);
+        while let Some(s) = scope {
+            let values = Value::Object(s.locals_cell());
+            let summary = VariableDumper::summarize(&values);
+            scope_chain.push(format!("{:?}: {summary}", s.class()));
+            scope = s.parent();
+        }
+        scope_chain.reverse();
+
+        let stack = self
+            .context
+            .avm1
+            .stack()
+            .iter()
+            .map(VariableDumper::summarize)
+            .collect();
+
+        self.context.debugger.pause_with(PausedState {
+            offset,
+            instruction: format!("{action:?}"),
+            locals,
+            scope_chain,
+            stack,
+            ..Default::default()
+        });
+    }
+
     fn stack_push(&mut self, mut value: Value<'gc>) {
         if let Value::Object(Object::StageObject(s)) = value {
             // Note that there currently exists a subtle issue with this logic:
@@ -1,5 +1,6 @@
 use crate::avm1::activation::Activation;
 use crate::avm1::{Object, ObjectPtr, TObject, Value};
+use crate::display_object::TDisplayObject;
 use crate::string::AvmString;
 use std::fmt::Write;
 
@@ -32,6 +33,32 @@ impl<'a> VariableDumper<'a> {
         dumper.output
     }
 
+    /// Describe a value on a single line, without printing the properties of
+    /// objects.
+    pub fn summarize(value: &Value<'_>) -> String {
+        let mut dumper = VariableDumper::new("");
+        match value {
+            Value::Undefined => dumper.output.push_str("undefined"),
+            Value::Null => dumper.output.push_str("null"),
+            Value::Bool(value) => dumper.output.push_str(&value.to_string()),
+            Value::Number(value) => dumper.output.push_str(&value.to_string()),
+            Value::String(value) => dumper.print_string(*value),
+            Value::Object(object) => {
+                if let Some(display_object) = object.as_display_object() {
+                    let _ = write!(dumper.output, "{}", display_object.path());
+                } else if object.as_executable().is_some() {
+                    dumper.output.push_str("[type Function]");
+                } else {
+                    dumper.output.push_str("[object Object]");
+                }
+            }
+            Value::MovieClip(reference) => {
+                let _ = write!(dumper.output, "{}", reference.path());
+            }
+        }
+        dumper.output
+    }
+
     pub fn output(&self) -> &str {
         &self.output
     }
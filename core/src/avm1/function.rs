@@ -7,6 +7,7 @@ use crate::avm1::property::Attribute;
 use crate::avm1::scope::Scope;
 use crate::avm1::value::Value;
 use crate::avm1::{ArrayObject, Object, ObjectPtr, ScriptObject, TObject};
+use crate::debugger::CodeLocation;
 use crate::display_object::{DisplayObject, TDisplayObject};
 use crate::string::{AvmString, SwfStrExt as _};
 use crate::tag_utils::SwfSlice;
@@ -316,6 +317,7 @@ impl fmt::Debug for Executable<'_> {
 }
 
 /// Indicates the default name to use for this execution in debug builds.
+#[derive(Clone, Copy)]
 pub enum ExecutionName<'gc> {
     Static(&'static str),
     Dynamic(AvmString<'gc>),
@@ -400,6 +402,7 @@ impl<'gc> Executable<'gc> {
         // The caller is the previous callee.
         let arguments_caller = activation.callee;
 
+        let function_name = af.name.map(ExecutionName::Dynamic).unwrap_or(name);
         let name = if cfg!(feature = "avm_debug") {
             Cow::Owned(af.debug_string_for_call(name, args))
         } else {
@@ -453,7 +456,14 @@ impl<'gc> Executable<'gc> {
             }
         }
 
-        Ok(frame.run_actions(af.data.clone())?.value())
+        let location = || CodeLocation::Function {
+            name: match function_name {
+                ExecutionName::Static(n) => n.to_owned(),
+                ExecutionName::Dynamic(n) => n.to_utf8_lossy().into_owned(),
+            },
+            class: None,
+        };
+        Ok(frame.run_body(af.data.clone(), location)?.value())
     }
 }
 
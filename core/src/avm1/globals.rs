@@ -30,6 +30,7 @@ pub(crate) mod displacement_map_filter;
 pub(crate) mod drop_shadow_filter;
 pub(crate) mod error;
 mod external_interface;
+pub(crate) mod file_reference;
 mod function;
 pub(crate) mod glow_filter;
 pub(crate) mod gradient_filter;
@@ -41,6 +42,7 @@ mod matrix;
 pub(crate) mod mouse;
 pub(crate) mod movie_clip;
 mod movie_clip_loader;
+pub(crate) mod netconnection;
 pub(crate) mod netstream;
 pub(crate) mod number;
 mod object;
@@ -51,6 +53,7 @@ pub(crate) mod shared_object;
 pub(crate) mod sound;
 mod stage;
 pub(crate) mod string;
+mod style_sheet;
 pub(crate) mod system;
 pub(crate) mod system_capabilities;
 pub(crate) mod system_ime;
@@ -61,6 +64,7 @@ pub(crate) mod transform;
 mod video;
 mod xml;
 mod xml_node;
+mod xml_socket;
 
 const GLOBAL_DECLS: &[Declaration] = declare_properties! {
     "trace" => method(trace; DONT_ENUM);
@@ -524,6 +528,7 @@ pub fn create_globals<'gc>(
 
     let text_field_proto = text_field::create_proto(context, object_proto, function_proto);
     let text_format_proto = text_format::create_proto(context, object_proto, function_proto);
+    let style_sheet_proto = style_sheet::create_proto(context, object_proto, function_proto);
 
     let array_proto = array::create_proto(context, object_proto, function_proto);
 
@@ -570,6 +575,15 @@ pub fn create_globals<'gc>(
 
     let video_proto = video::create_proto(context, object_proto, function_proto);
     let netstream_proto = netstream::create_proto(context, object_proto, function_proto);
+    let netconnection_proto = netconnection::create_proto(context, object_proto, function_proto);
+    let file_reference_proto = file_reference::create_proto(
+        context,
+        object_proto,
+        function_proto,
+        array_proto,
+        broadcaster_functions,
+    );
+    let xml_socket_proto = xml_socket::create_proto(context, object_proto, function_proto);
 
     //TODO: These need to be constructors and should also set `.prototype` on each one
     let object = object::create_object_object(context, object_proto, function_proto);
@@ -642,6 +656,19 @@ pub fn create_globals<'gc>(
         function_proto,
         text_field_proto,
     );
+    let style_sheet = FunctionObject::constructor(
+        gc_context,
+        Executable::Native(style_sheet::constructor),
+        constructor_to_fn!(style_sheet::constructor),
+        function_proto,
+        style_sheet_proto,
+    );
+    text_field.define_value(
+        gc_context,
+        "StyleSheet",
+        style_sheet.into(),
+        Attribute::DONT_ENUM,
+    );
     let text_format = FunctionObject::constructor(
         gc_context,
         Executable::Native(text_format::constructor),
@@ -669,6 +696,10 @@ pub fn create_globals<'gc>(
     let boolean = boolean::create_boolean_object(context, boolean_proto, function_proto);
     let date = date::create_constructor(context, object_proto, function_proto);
     let netstream = netstream::create_class(context, netstream_proto, function_proto);
+    let netconnection = netconnection::create_class(context, netconnection_proto, function_proto);
+    let file_reference =
+        file_reference::create_class(context, file_reference_proto, function_proto);
+    let xml_socket = xml_socket::create_class(context, xml_socket_proto, function_proto);
 
     let flash = ScriptObject::new(gc_context, Some(object_proto));
 
@@ -840,6 +871,15 @@ pub fn create_globals<'gc>(
         Attribute::empty(),
     );
 
+    let net = ScriptObject::new(gc_context, Some(object_proto));
+    flash.define_value(gc_context, "net", net.into(), Attribute::empty());
+    net.define_value(
+        gc_context,
+        "FileReference",
+        file_reference.into(),
+        Attribute::empty(),
+    );
+
     let globals = ScriptObject::new(gc_context, None);
     globals.define_value(
         gc_context,
@@ -1034,6 +1074,18 @@ pub fn create_globals<'gc>(
         netstream.into(),
         Attribute::DONT_ENUM,
     );
+    globals.define_value(
+        gc_context,
+        "NetConnection",
+        netconnection.into(),
+        Attribute::DONT_ENUM,
+    );
+    globals.define_value(
+        gc_context,
+        "XMLSocket",
+        xml_socket.into(),
+        Attribute::DONT_ENUM,
+    );
 
     define_properties_on(GLOBAL_DECLS, context, globals, function_proto);
 
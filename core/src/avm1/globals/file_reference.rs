@@ -0,0 +1,340 @@
+//! flash.net.FileReference object
+
+use crate::avm1::function::{Executable, FunctionObject};
+use crate::avm1::globals::as_broadcaster::BroadcasterFunctions;
+use crate::avm1::object::{NativeObject, Object, TObject};
+use crate::avm1::property_decl::{define_properties_on, Declaration};
+use crate::avm1::{Activation, ArrayObject, Attribute, Error, ScriptObject, Value};
+use crate::avm1_stub;
+use crate::backend::navigator::Request;
+use crate::backend::ui::{FileFilter, LoadedFile};
+use crate::context::GcContext;
+use crate::file_reference::{upload_request, DEFAULT_UPLOAD_FIELD_NAME};
+use crate::string::AvmString;
+use crate::vminterface::AvmObject;
+use chrono::{DateTime, Utc};
+use gc_arena::{Collect, GcCell, MutationContext};
+
+/// The state of a `FileReference` object.
+#[derive(Clone, Debug, Default, Collect)]
+#[collect(require_static)]
+pub struct FileReference {
+    /// The file picked by the user, if any.
+    file: Option<LoadedFile>,
+}
+
+const PROTO_DECLS: &[Declaration] = declare_properties! {
+    "creationDate" => property(get_creation_date);
+    "creator" => property(get_creator);
+    "modificationDate" => property(get_modification_date);
+    "name" => property(get_name);
+    "size" => property(get_size);
+    "type" => property(get_type);
+    "browse" => method(browse; DONT_ENUM | DONT_DELETE);
+    "cancel" => method(cancel; DONT_ENUM | DONT_DELETE);
+    "download" => method(download; DONT_ENUM | DONT_DELETE);
+    "upload" => method(upload; DONT_ENUM | DONT_DELETE);
+};
+
+pub fn constructor<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    this.set_native(
+        activation.context.gc_context,
+        NativeObject::FileReference(GcCell::allocate(
+            activation.context.gc_context,
+            FileReference::default(),
+        )),
+    );
+
+    let listeners = ArrayObject::new(
+        activation.context.gc_context,
+        activation.context.avm1.prototypes().array,
+        [this.into()],
+    );
+    this.define_value(
+        activation.context.gc_context,
+        "_listeners",
+        Value::Object(listeners.into()),
+        Attribute::DONT_ENUM,
+    );
+
+    Ok(this.into())
+}
+
+/// Store a file picked by the user on a `FileReference`.
+pub fn set_file<'gc>(gc_context: MutationContext<'gc, '_>, this: Object<'gc>, file: LoadedFile) {
+    if let NativeObject::FileReference(file_reference) = this.native() {
+        file_reference.write(gc_context).file = Some(file);
+    }
+}
+
+/// Read a property of the file picked by the user, or `undefined` if there
+/// isn't one.
+fn with_file<'gc>(
+    this: Object<'gc>,
+    f: impl FnOnce(&LoadedFile) -> Value<'gc>,
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let NativeObject::FileReference(file_reference) = this.native() {
+        if let Some(file) = &file_reference.read().file {
+            return Ok(f(file));
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+fn new_date<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    date: Option<DateTime<Utc>>,
+) -> Result<Value<'gc>, Error<'gc>> {
+    match date {
+        Some(date) => {
+            let date_constructor = activation.context.avm1.prototypes().date_constructor;
+            let date = date_constructor
+                .construct(activation, &[(date.timestamp_millis() as f64).into()])?;
+            Ok(date)
+        }
+        None => Ok(Value::Undefined),
+    }
+}
+
+fn get_creation_date<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let NativeObject::FileReference(file_reference) = this.native() else {
+        return Ok(Value::Undefined);
+    };
+    let date = file_reference
+        .read()
+        .file
+        .as_ref()
+        .and_then(|file| file.creation_date);
+    new_date(activation, date)
+}
+
+fn get_creator<'gc>(
+    _activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    // The Mac creator type is never known.
+    with_file(this, |_| Value::Null)
+}
+
+fn get_modification_date<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let NativeObject::FileReference(file_reference) = this.native() else {
+        return Ok(Value::Undefined);
+    };
+    let date = file_reference
+        .read()
+        .file
+        .as_ref()
+        .and_then(|file| file.modification_date);
+    new_date(activation, date)
+}
+
+fn get_name<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let gc_context = activation.context.gc_context;
+    with_file(this, |file| {
+        AvmString::new_utf8(gc_context, &file.name).into()
+    })
+}
+
+fn get_size<'gc>(
+    _activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    with_file(this, |file| file.contents.len().into())
+}
+
+fn get_type<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let gc_context = activation.context.gc_context;
+    with_file(this, |file| match file.file_type() {
+        Some(file_type) => AvmString::new_utf8(gc_context, file_type).into(),
+        None => Value::Null,
+    })
+}
+
+/// Convert the `typelist` array passed to `browse`, which holds objects with
+/// `description`, `extension` and (optionally) `macType` properties.
+fn file_filters<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    type_list: Object<'gc>,
+) -> Result<Vec<FileFilter>, Error<'gc>> {
+    let length = type_list.length(activation)?;
+    let mut filters = Vec::with_capacity(length.max(0) as usize);
+    for i in 0..length {
+        let Value::Object(filter) = type_list.get_element(activation, i) else {
+            continue;
+        };
+
+        let description = filter
+            .get("description", activation)?
+            .coerce_to_string(activation)?
+            .to_string();
+        let extensions = filter
+            .get("extension", activation)?
+            .coerce_to_string(activation)?
+            .to_string();
+        let mac_type = match filter.get("macType", activation)? {
+            Value::Undefined | Value::Null => None,
+            mac_type => Some(mac_type.coerce_to_string(activation)?.to_string()),
+        };
+        filters.push(FileFilter {
+            description,
+            extensions,
+            mac_type,
+        });
+    }
+
+    Ok(filters)
+}
+
+fn browse<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let filters = match args.get(0) {
+        Some(Value::Object(type_list)) => file_filters(activation, *type_list)?,
+        _ => vec![],
+    };
+
+    let Some(dialog) = activation
+        .context
+        .ui
+        .display_file_open_dialog(filters, false)
+    else {
+        return Ok(false.into());
+    };
+
+    let future = activation.context.load_manager.select_file_reference(
+        activation.context.player.clone(),
+        AvmObject::Avm1(this),
+        dialog,
+        false,
+    );
+    activation.context.navigator.spawn_future(future);
+
+    Ok(true.into())
+}
+
+fn cancel<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    avm1_stub!(activation, "FileReference", "cancel");
+    Ok(Value::Undefined)
+}
+
+fn download<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let url = match args.get(0) {
+        Some(url @ Value::String(_)) => url.coerce_to_string(activation)?.to_string(),
+        _ => return Ok(false.into()),
+    };
+    let file_name = match args.get(1) {
+        Some(Value::Undefined | Value::Null) | None => {
+            // Default to the last segment of the URL's path.
+            let path = url.split(['?', '#']).next().unwrap_or_default();
+            path.rsplit('/').next().unwrap_or_default().to_string()
+        }
+        Some(file_name) => file_name.coerce_to_string(activation)?.to_string(),
+    };
+
+    let Some(dialog) = activation.context.ui.display_file_save_dialog(file_name) else {
+        return Ok(false.into());
+    };
+
+    let future = activation.context.load_manager.download_file_reference(
+        activation.context.player.clone(),
+        AvmObject::Avm1(this),
+        dialog,
+        Request::get(url),
+    );
+    activation.context.navigator.spawn_future(future);
+
+    Ok(true.into())
+}
+
+fn upload<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let NativeObject::FileReference(file_reference) = this.native() else {
+        return Ok(false.into());
+    };
+    let Some(file) = file_reference.read().file.clone() else {
+        return Ok(false.into());
+    };
+    let url = match args.get(0) {
+        Some(url @ Value::String(_)) => url.coerce_to_string(activation)?.to_string(),
+        _ => return Ok(false.into()),
+    };
+
+    let request = upload_request(
+        Request::get(url),
+        DEFAULT_UPLOAD_FIELD_NAME,
+        &file.name,
+        &file.contents,
+        activation.context.rng,
+    );
+    let future = activation.context.load_manager.upload_file_reference(
+        activation.context.player.clone(),
+        AvmObject::Avm1(this),
+        request,
+    );
+    activation.context.navigator.spawn_future(future);
+
+    Ok(true.into())
+}
+
+pub fn create_proto<'gc>(
+    context: &mut GcContext<'_, 'gc>,
+    proto: Object<'gc>,
+    fn_proto: Object<'gc>,
+    array_proto: Object<'gc>,
+    broadcaster_functions: BroadcasterFunctions<'gc>,
+) -> Object<'gc> {
+    let object = ScriptObject::new(context.gc_context, Some(proto));
+    broadcaster_functions.initialize(context.gc_context, object.into(), array_proto);
+    define_properties_on(PROTO_DECLS, context, object, fn_proto);
+    object.into()
+}
+
+pub fn create_class<'gc>(
+    context: &mut GcContext<'_, 'gc>,
+    file_reference_proto: Object<'gc>,
+    fn_proto: Object<'gc>,
+) -> Object<'gc> {
+    FunctionObject::constructor(
+        context.gc_context,
+        Executable::Native(constructor),
+        constructor_to_fn!(constructor),
+        fn_proto,
+        file_reference_proto,
+    )
+}
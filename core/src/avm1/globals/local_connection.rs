@@ -2,14 +2,21 @@
 
 use crate::avm1::activation::Activation;
 use crate::avm1::error::Error;
+use crate::avm1::globals::shared_object::serialize_value;
 use crate::avm1::property_decl::{define_properties_on, Declaration};
 use crate::avm1::{Object, ScriptObject, Value};
 use crate::context::GcContext;
 use crate::display_object::TDisplayObject;
+use crate::local_connection::movie_domain;
 use crate::string::AvmString;
+use crate::vminterface::AvmObject;
+use flash_lso::types::Value as AmfValue;
 
 const PROTO_DECLS: &[Declaration] = declare_properties! {
     "domain" => method(domain; DONT_DELETE | READ_ONLY);
+    "connect" => method(connect; DONT_DELETE | READ_ONLY);
+    "send" => method(send; DONT_DELETE | READ_ONLY);
+    "close" => method(close; DONT_DELETE | READ_ONLY);
 };
 
 pub fn domain<'gc>(
@@ -19,21 +26,76 @@ pub fn domain<'gc>(
 ) -> Result<Value<'gc>, Error<'gc>> {
     let movie = activation.base_clip().movie();
 
-    let domain = if let Ok(url) = url::Url::parse(movie.url()) {
-        if url.scheme() == "file" {
-            "localhost".into()
-        } else if let Some(domain) = url.domain() {
-            AvmString::new_utf8(activation.context.gc_context, domain)
-        } else {
-            // no domain?
-            "localhost".into()
-        }
-    } else {
+    let Some(domain) = movie_domain(movie.url()) else {
         tracing::error!("LocalConnection::domain: Unable to parse movie URL");
         return Ok(Value::Null);
     };
 
-    Ok(Value::String(domain))
+    Ok(AvmString::new_utf8(activation.context.gc_context, domain).into())
+}
+
+pub fn connect<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let name = match args.get(0) {
+        Some(Value::String(name)) if !name.is_empty() && !name.contains(b':') => *name,
+        _ => return Ok(false.into()),
+    };
+
+    let domain = movie_domain(activation.base_clip().movie().url()).unwrap_or_default();
+    let connected =
+        activation
+            .context
+            .local_connections
+            .connect(&domain, AvmObject::Avm1(this), &name);
+
+    Ok(connected.into())
+}
+
+pub fn send<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let (Some(Value::String(connection_name)), Some(Value::String(method_name))) =
+        (args.get(0), args.get(1))
+    else {
+        return Ok(false.into());
+    };
+    if connection_name.is_empty() || method_name.is_empty() {
+        return Ok(false.into());
+    }
+
+    let arguments = args[2..]
+        .iter()
+        .map(|value| serialize_value(activation, *value).unwrap_or(AmfValue::Undefined))
+        .collect();
+
+    let domain = movie_domain(activation.base_clip().movie().url()).unwrap_or_default();
+    activation.context.local_connections.send(
+        &domain,
+        AvmObject::Avm1(this),
+        connection_name,
+        method_name,
+        arguments,
+    );
+
+    Ok(true.into())
+}
+
+pub fn close<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    activation
+        .context
+        .local_connections
+        .close(AvmObject::Avm1(this));
+
+    Ok(Value::Undefined)
 }
 
 pub fn constructor<'gc>(
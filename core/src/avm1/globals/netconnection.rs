@@ -0,0 +1,247 @@
+//! NetConnection class
+
+use crate::avm1::function::{Executable, FunctionObject};
+use crate::avm1::globals::shared_object::serialize_value;
+use crate::avm1::object::{NativeObject, Object, TObject};
+use crate::avm1::property_decl::{define_properties_on, Declaration};
+use crate::avm1::{Activation, Error, ScriptObject, Value};
+use crate::avm1_stub;
+use crate::backend::navigator::Request;
+use crate::context::GcContext;
+use crate::remoting::{encode_call, CONTENT_TYPE};
+use crate::streams::trigger_net_status_event;
+use crate::string::AvmString;
+use crate::vminterface::AvmObject;
+use flash_lso::types::{AMFVersion, Value as AmfValue};
+use gc_arena::{Collect, GcCell};
+
+/// The state of a `NetConnection` object.
+#[derive(Clone, Debug, Default, Collect)]
+#[collect(require_static)]
+pub struct NetConnection {
+    /// The URI passed to `connect`, if any.
+    uri: Option<String>,
+
+    /// Whether a local (`null`) connection has been made.
+    is_connected: bool,
+
+    /// Whether `uri` is a Flash Remoting gateway, which is connected to
+    /// separately for every call.
+    is_remoting: bool,
+
+    /// The ID used to match the response of the next call to its responder.
+    next_response_id: u32,
+}
+
+pub fn constructor<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    this.set_native(
+        activation.context.gc_context,
+        NativeObject::NetConnection(GcCell::allocate(
+            activation.context.gc_context,
+            NetConnection {
+                next_response_id: 1,
+                ..Default::default()
+            },
+        )),
+    );
+
+    Ok(Value::Undefined)
+}
+
+const PROTO_DECLS: &[Declaration] = declare_properties! {
+    "isConnected" => property(get_is_connected);
+    "uri" => property(get_uri);
+    "connect" => method(connect; DONT_ENUM | DONT_DELETE);
+    "call" => method(call; DONT_ENUM | DONT_DELETE);
+    "close" => method(close; DONT_ENUM | DONT_DELETE);
+};
+
+fn get_is_connected<'gc>(
+    _activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let NativeObject::NetConnection(connection) = this.native() {
+        return Ok(connection.read().is_connected.into());
+    }
+
+    Ok(Value::Undefined)
+}
+
+fn get_uri<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let NativeObject::NetConnection(connection) = this.native() {
+        if let Some(uri) = &connection.read().uri {
+            return Ok(AvmString::new_utf8(activation.context.gc_context, uri).into());
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
+fn connect<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let NativeObject::NetConnection(connection) = this.native() else {
+        return Ok(Value::Undefined);
+    };
+
+    close(activation, this, &[])?;
+
+    let command = args.get(0).unwrap_or(&Value::Undefined);
+    if matches!(command, Value::Null | Value::Undefined) {
+        let mut connection = connection.write(activation.context.gc_context);
+        connection.uri = Some("null".to_string());
+        connection.is_connected = true;
+        drop(connection);
+
+        trigger_net_status_event(
+            &mut activation.context,
+            AvmObject::Avm1(this),
+            "NetConnection.Connect.Success",
+            "status",
+        );
+        return Ok(true.into());
+    }
+
+    let command = command.coerce_to_string(activation)?.to_string();
+    let lower_command = command.to_ascii_lowercase();
+    let is_remoting = lower_command.starts_with("http:") || lower_command.starts_with("https:");
+    if !is_remoting {
+        avm1_stub!(activation, "NetConnection", "connect", "with RTMP");
+    }
+
+    let mut connection = connection.write(activation.context.gc_context);
+    connection.uri = Some(command);
+    connection.is_remoting = is_remoting;
+
+    Ok(is_remoting.into())
+}
+
+fn call<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let NativeObject::NetConnection(connection) = this.native() else {
+        return Ok(Value::Undefined);
+    };
+
+    let gateway = {
+        let connection = connection.read();
+        connection.uri.clone().filter(|_| connection.is_remoting)
+    };
+    let Some(gateway) = gateway else {
+        avm1_stub!(
+            activation,
+            "NetConnection",
+            "call",
+            "without a Flash Remoting gateway"
+        );
+        return Ok(Value::Undefined);
+    };
+
+    let command = args
+        .get(0)
+        .unwrap_or(&Value::Undefined)
+        .coerce_to_string(activation)?;
+    let responder = match args.get(1) {
+        Some(Value::Object(responder)) => Some(AvmObject::Avm1(*responder)),
+        _ => None,
+    };
+    let arguments = args
+        .get(2..)
+        .unwrap_or_default()
+        .iter()
+        .map(|value| serialize_value(activation, *value).unwrap_or(AmfValue::Undefined))
+        .collect();
+
+    let response_uri = {
+        let mut connection = connection.write(activation.context.gc_context);
+        let response_uri = format!("/{}", connection.next_response_id);
+        connection.next_response_id += 1;
+        response_uri
+    };
+
+    let body = encode_call(
+        AMFVersion::AMF0,
+        &command.to_utf8_lossy(),
+        &response_uri,
+        arguments,
+    );
+    let request = Request::post(gateway, Some((body, CONTENT_TYPE.to_string())));
+
+    let future = activation.context.load_manager.call_net_connection(
+        activation.context.player.clone(),
+        AvmObject::Avm1(this),
+        responder,
+        request,
+        response_uri,
+    );
+    activation.context.navigator.spawn_future(future);
+
+    Ok(Value::Undefined)
+}
+
+fn close<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let NativeObject::NetConnection(connection) = this.native() else {
+        return Ok(Value::Undefined);
+    };
+
+    let was_connected = {
+        let mut connection = connection.write(activation.context.gc_context);
+        let was_connected = connection.is_connected;
+        connection.uri = None;
+        connection.is_connected = false;
+        connection.is_remoting = false;
+        was_connected
+    };
+
+    if was_connected {
+        trigger_net_status_event(
+            &mut activation.context,
+            AvmObject::Avm1(this),
+            "NetConnection.Connect.Closed",
+            "status",
+        );
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn create_proto<'gc>(
+    context: &mut GcContext<'_, 'gc>,
+    proto: Object<'gc>,
+    fn_proto: Object<'gc>,
+) -> Object<'gc> {
+    let object = ScriptObject::new(context.gc_context, Some(proto));
+    define_properties_on(PROTO_DECLS, context, object, fn_proto);
+    object.into()
+}
+
+pub fn create_class<'gc>(
+    context: &mut GcContext<'_, 'gc>,
+    netconnection_proto: Object<'gc>,
+    fn_proto: Object<'gc>,
+) -> Object<'gc> {
+    FunctionObject::constructor(
+        context.gc_context,
+        Executable::Native(constructor),
+        constructor_to_fn!(constructor),
+        fn_proto,
+        netconnection_proto,
+    )
+}
@@ -4,13 +4,14 @@ use crate::avm1::property_decl::{define_properties_on, Declaration};
 use crate::avm1::{Activation, Error, ScriptObject, Value};
 use crate::context::GcContext;
 use crate::streams::NetStream;
+use crate::vminterface::AvmObject;
 
 pub fn constructor<'gc>(
     activation: &mut Activation<'_, 'gc>,
     this: Object<'gc>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    let netstream = NetStream::new(activation.context.gc_context);
+    let netstream = NetStream::new(activation.context.gc_context, Some(AvmObject::Avm1(this)));
     this.set_native(
         activation.context.gc_context,
         NativeObject::NetStream(netstream),
@@ -22,8 +23,11 @@ pub fn constructor<'gc>(
 const PROTO_DECLS: &[Declaration] = declare_properties! {
     "bytesLoaded" => property(get_bytes_loaded);
     "bytesTotal" => property(get_bytes_total);
+    "time" => property(get_time);
     "play" => method(play; DONT_ENUM | DONT_DELETE);
     "pause" => method(pause; DONT_ENUM | DONT_DELETE);
+    "seek" => method(seek; DONT_ENUM | DONT_DELETE);
+    "close" => method(close; DONT_ENUM | DONT_DELETE);
 };
 
 fn get_bytes_loaded<'gc>(
@@ -50,6 +54,18 @@ fn get_bytes_total<'gc>(
     Ok(Value::Undefined)
 }
 
+fn get_time<'gc>(
+    _activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let NativeObject::NetStream(ns) = this.native() {
+        return Ok(ns.time().into());
+    }
+
+    Ok(Value::Undefined)
+}
+
 fn play<'gc>(
     activation: &mut Activation<'_, 'gc>,
     this: Object<'gc>,
@@ -89,6 +105,36 @@ fn pause<'gc>(
     Ok(Value::Undefined)
 }
 
+fn seek<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let NativeObject::NetStream(ns) = this.native() {
+        let offset = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_f64(activation)?;
+
+        ns.seek(&mut activation.context, offset * 1000.0);
+    }
+
+    Ok(Value::Undefined)
+}
+
+fn close<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let NativeObject::NetStream(ns) = this.native() {
+        ns.close(&mut activation.context);
+    }
+
+    Ok(Value::Undefined)
+}
+
 pub fn create_proto<'gc>(
     context: &mut GcContext<'_, 'gc>,
     proto: Object<'gc>,
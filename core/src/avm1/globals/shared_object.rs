@@ -5,7 +5,7 @@ use crate::avm1::object::shared_object::SharedObject;
 use crate::avm1::object::NativeObject;
 use crate::avm1::property::Attribute;
 use crate::avm1::property_decl::{define_properties_on, Declaration};
-use crate::avm1::{Object, ScriptObject, TObject, Value};
+use crate::avm1::{ArrayObject, Object, ScriptObject, TObject, Value};
 use crate::avm1_stub;
 use crate::context::GcContext;
 use crate::display_object::TDisplayObject;
@@ -56,7 +56,7 @@ pub fn get_disk_usage<'gc>(
 }
 
 /// Serialize a Value to an AmfValue
-fn serialize_value<'gc>(
+pub(crate) fn serialize_value<'gc>(
     activation: &mut Activation<'_, 'gc>,
     elem: Value<'gc>,
 ) -> Option<AmfValue> {
@@ -112,18 +112,27 @@ fn recursive_serialize<'gc>(
 }
 
 /// Deserialize a AmfValue to a Value
-fn deserialize_value<'gc>(activation: &mut Activation<'_, 'gc>, val: &AmfValue) -> Value<'gc> {
+pub(crate) fn deserialize_value<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    val: &AmfValue,
+) -> Value<'gc> {
     match val {
         AmfValue::Null => Value::Null,
         AmfValue::Undefined => Value::Undefined,
         AmfValue::Number(f) => (*f).into(),
         AmfValue::String(s) => Value::String(AvmString::new_utf8(activation.context.gc_context, s)),
         AmfValue::Bool(b) => (*b).into(),
-        AmfValue::ECMAArray(_, associative, len) => {
+        AmfValue::ECMAArray(dense, associative, len) => {
             let array_constructor = activation.context.avm1.prototypes().array_constructor;
             if let Ok(Value::Object(obj)) =
                 array_constructor.construct(activation, &[(*len).into()])
             {
+                // Arrays serialized by AVM2 keep their dense part separately.
+                for (i, value) in dense.iter().enumerate() {
+                    let value = deserialize_value(activation, value);
+                    obj.set_element(activation, i as i32, value).unwrap();
+                }
+
                 for entry in associative {
                     let value = deserialize_value(activation, entry.value());
 
@@ -144,6 +153,18 @@ fn deserialize_value<'gc>(activation: &mut Activation<'_, 'gc>, val: &AmfValue)
                 Value::Undefined
             }
         }
+        AmfValue::StrictArray(values) => {
+            let values: Vec<_> = values
+                .iter()
+                .map(|value| deserialize_value(activation, value))
+                .collect();
+            ArrayObject::new(
+                activation.context.gc_context,
+                activation.context.avm1.prototypes().array,
+                values,
+            )
+            .into()
+        }
         AmfValue::Object(elements, _) => {
             // Deserialize Object
             let obj = ScriptObject::new(
@@ -0,0 +1,233 @@
+//! TextField.StyleSheet class
+
+use crate::avm1::activation::Activation;
+use crate::avm1::error::Error;
+use crate::avm1::globals::text_field::new_text_format;
+use crate::avm1::property_decl::{define_properties_on, Declaration};
+use crate::avm1::{ArrayObject, Attribute, Object, ScriptObject, TObject, Value};
+use crate::avm1_stub;
+use crate::context::GcContext;
+use crate::html::{parse_css, StyleSheet, TextFormat, CSS_PROPERTIES};
+use crate::string::{AvmString, WStr};
+
+const PROTO_DECLS: &[Declaration] = declare_properties! {
+    "getStyle" => method(get_style; DONT_ENUM | DONT_DELETE);
+    "setStyle" => method(set_style; DONT_ENUM | DONT_DELETE);
+    "getStyleNames" => method(get_style_names; DONT_ENUM | DONT_DELETE);
+    "clear" => method(clear; DONT_ENUM | DONT_DELETE);
+    "parseCSS" => method(parse_css_text; DONT_ENUM | DONT_DELETE);
+    "transform" => method(transform; DONT_ENUM | DONT_DELETE);
+    "load" => method(load; DONT_ENUM | DONT_DELETE);
+};
+
+/// The hidden property holding the style objects, keyed by lowercased name.
+const STYLES: &str = "_css";
+
+/// Implements `TextField.StyleSheet`
+pub fn constructor<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    clear(activation, this, &[])?;
+    Ok(this.into())
+}
+
+pub fn create_proto<'gc>(
+    context: &mut GcContext<'_, 'gc>,
+    proto: Object<'gc>,
+    fn_proto: Object<'gc>,
+) -> Object<'gc> {
+    let object = ScriptObject::new(context.gc_context, Some(proto));
+    define_properties_on(PROTO_DECLS, context, object, fn_proto);
+    object.into()
+}
+
+/// Get the object holding the styles of a style sheet.
+fn styles<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+) -> Result<Object<'gc>, Error<'gc>> {
+    match this.get(STYLES, activation)? {
+        Value::Object(styles) => Ok(styles),
+        _ => {
+            clear(activation, this, &[])?;
+            Ok(this.get(STYLES, activation)?.coerce_to_object(activation))
+        }
+    }
+}
+
+/// Make a shallow copy of a style object.
+fn copy_style<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    style: Object<'gc>,
+) -> Result<Object<'gc>, Error<'gc>> {
+    let copy = ScriptObject::new(
+        activation.context.gc_context,
+        Some(activation.context.avm1.prototypes().object),
+    );
+    for key in style.get_keys(activation) {
+        let value = style.get(key, activation)?;
+        copy.set(key, value, activation)?;
+    }
+    Ok(copy.into())
+}
+
+fn get_style<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let name = args
+        .get(0)
+        .unwrap_or(&Value::Undefined)
+        .coerce_to_string(activation)?;
+    let name = AvmString::new(activation.context.gc_context, name.to_ascii_lowercase());
+    let styles = styles(activation, this)?;
+    match styles.get(name, activation)? {
+        Value::Object(style) => Ok(copy_style(activation, style)?.into()),
+        _ => Ok(Value::Null),
+    }
+}
+
+fn set_style<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let name = args
+        .get(0)
+        .unwrap_or(&Value::Undefined)
+        .coerce_to_string(activation)?;
+    let name = AvmString::new(activation.context.gc_context, name.to_ascii_lowercase());
+    let styles = styles(activation, this)?;
+    match args.get(1) {
+        Some(Value::Object(style)) => {
+            let style = copy_style(activation, *style)?;
+            styles.set(name, style.into(), activation)?;
+        }
+        _ => {
+            styles.delete(activation, name);
+        }
+    }
+    Ok(Value::Undefined)
+}
+
+fn get_style_names<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let styles = styles(activation, this)?;
+    let names = styles.get_keys(activation).into_iter().map(Value::from);
+    Ok(ArrayObject::new(
+        activation.context.gc_context,
+        activation.context.avm1.prototypes().array,
+        names,
+    )
+    .into())
+}
+
+fn clear<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let styles = ScriptObject::new(
+        activation.context.gc_context,
+        Some(activation.context.avm1.prototypes().object),
+    );
+    this.define_value(
+        activation.context.gc_context,
+        STYLES,
+        styles.into(),
+        Attribute::DONT_ENUM,
+    );
+    Ok(Value::Undefined)
+}
+
+fn parse_css_text<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let css = args
+        .get(0)
+        .unwrap_or(&Value::Undefined)
+        .coerce_to_string(activation)?;
+    let Some(rules) = parse_css(&css) else {
+        return Ok(false.into());
+    };
+
+    let styles = styles(activation, this)?;
+    for (selector, declarations) in rules {
+        let style = ScriptObject::new(
+            activation.context.gc_context,
+            Some(activation.context.avm1.prototypes().object),
+        );
+        for (name, value) in declarations {
+            let name = AvmString::new(activation.context.gc_context, name);
+            let value = AvmString::new(activation.context.gc_context, value);
+            style.set(name, value.into(), activation)?;
+        }
+        let selector = AvmString::new(activation.context.gc_context, selector);
+        styles.set(selector, style.into(), activation)?;
+    }
+    Ok(true.into())
+}
+
+/// Convert a style object into a `TextFormat`.
+fn style_to_text_format<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    style: Object<'gc>,
+) -> Result<TextFormat, Error<'gc>> {
+    let mut properties = Vec::new();
+    for &name in CSS_PROPERTIES {
+        let value = style.get(name, activation)?;
+        if !matches!(value, Value::Undefined | Value::Null) {
+            properties.push((name, value.coerce_to_string(activation)?));
+        }
+    }
+
+    Ok(TextFormat::from_css_properties(properties.iter().map(
+        |(name, value)| (WStr::from_units(name.as_bytes()), value.as_wstr()),
+    )))
+}
+
+fn transform<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let Some(Value::Object(style)) = args.get(0) else {
+        return Ok(Value::Null);
+    };
+    let text_format = style_to_text_format(activation, *style)?;
+    Ok(new_text_format(activation, text_format).into())
+}
+
+fn load<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    avm1_stub!(activation, "TextField.StyleSheet", "load");
+    Ok(false.into())
+}
+
+/// Resolve the styles of a `TextField.StyleSheet` object, as applied to a
+/// text field.
+pub fn to_style_sheet<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+) -> Result<StyleSheet, Error<'gc>> {
+    let mut style_sheet = StyleSheet::new();
+    let styles = styles(activation, this)?;
+    for name in styles.get_keys(activation) {
+        if let Value::Object(style) = styles.get(name, activation)? {
+            let text_format = style_to_text_format(activation, style)?;
+            style_sheet.set_style(&name, text_format);
+        }
+    }
+    Ok(style_sheet)
+}
@@ -1,5 +1,6 @@
 use crate::avm1::activation::Activation;
 use crate::avm1::error::Error;
+use crate::avm1::globals::style_sheet;
 use crate::avm1::object::NativeObject;
 use crate::avm1::property_decl::{define_properties_on, Declaration};
 use crate::avm1::{globals, Object, ScriptObject, TObject, Value};
@@ -70,6 +71,7 @@ const PROTO_DECLS: &[Declaration] = declare_properties! {
     "hscroll" => property(tf_getter!(hscroll), tf_setter!(set_hscroll));
     "html" => property(tf_getter!(html), tf_setter!(set_html));
     "htmlText" => property(tf_getter!(html_text), tf_setter!(set_html_text));
+    "styleSheet" => property(tf_getter!(style_sheet), tf_setter!(set_style_sheet); VERSION_7);
     "length" => property(tf_getter!(length));
     "maxhscroll" => property(tf_getter!(maxhscroll));
     "maxscroll" => property(tf_getter!(maxscroll));
@@ -127,7 +129,7 @@ pub fn set_password<'gc>(
     Ok(())
 }
 
-fn new_text_format<'gc>(
+pub(crate) fn new_text_format<'gc>(
     activation: &mut Activation<'_, 'gc>,
     text_format: TextFormat,
 ) -> ScriptObject<'gc> {
@@ -381,6 +383,33 @@ pub fn set_html_text<'gc>(
     Ok(())
 }
 
+pub fn style_sheet<'gc>(
+    this: EditText<'gc>,
+    _activation: &mut Activation<'_, 'gc>,
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(this
+        .avm1_style_sheet()
+        .map_or(Value::Undefined, Value::Object))
+}
+
+pub fn set_style_sheet<'gc>(
+    this: EditText<'gc>,
+    activation: &mut Activation<'_, 'gc>,
+    value: Value<'gc>,
+) -> Result<(), Error<'gc>> {
+    let object = match value {
+        Value::Object(object) => Some(object),
+        _ => None,
+    };
+    let style_sheet = match object {
+        Some(object) => Some(style_sheet::to_style_sheet(activation, object)?),
+        None => None,
+    };
+    this.set_avm1_style_sheet(&mut activation.context, object);
+    this.set_style_sheet(&mut activation.context, style_sheet);
+    Ok(())
+}
+
 pub fn background<'gc>(
     this: EditText<'gc>,
     _activation: &mut Activation<'_, 'gc>,
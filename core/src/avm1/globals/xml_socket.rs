@@ -0,0 +1,131 @@
+//! XMLSocket class
+
+use crate::avm1::function::{Executable, ExecutionReason, FunctionObject};
+use crate::avm1::property_decl::{define_properties_on, Declaration};
+use crate::avm1::{Activation, Error, Object, ScriptObject, TObject, Value};
+use crate::context::GcContext;
+use crate::vminterface::AvmObject;
+use std::time::Duration;
+
+/// How long to wait for a connection to be established.
+///
+/// Unlike the AVM2 `XMLSocket`, this can't be changed by the movie.
+const TIMEOUT: Duration = Duration::from_secs(20);
+
+pub fn constructor<'gc>(
+    _activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(this.into())
+}
+
+const PROTO_DECLS: &[Declaration] = declare_properties! {
+    "connect" => method(connect; DONT_ENUM | DONT_DELETE);
+    "send" => method(send; DONT_ENUM | DONT_DELETE);
+    "close" => method(close; DONT_ENUM | DONT_DELETE);
+    "onData" => method(on_data; DONT_ENUM | DONT_DELETE);
+};
+
+fn connect<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let host = match args.get(0).unwrap_or(&Value::Undefined) {
+        Value::Null | Value::Undefined => None,
+        host => Some(host.coerce_to_string(activation)?.to_string()),
+    };
+    let port = args
+        .get(1)
+        .unwrap_or(&Value::Undefined)
+        .coerce_to_i32(activation)?;
+    let Ok(port) = u16::try_from(port) else {
+        return Ok(false.into());
+    };
+
+    let movie_url = activation.base_clip().movie().url().to_string();
+    activation.context.sockets.connect(
+        activation.context.navigator,
+        &movie_url,
+        AvmObject::Avm1(this),
+        host,
+        port,
+        TIMEOUT,
+    );
+
+    Ok(true.into())
+}
+
+fn send<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let data = args
+        .get(0)
+        .unwrap_or(&Value::Undefined)
+        .coerce_to_string(activation)?;
+
+    // Every message is terminated by a zero byte.
+    let mut data = data.to_utf8_lossy().into_owned().into_bytes();
+    data.push(0);
+    activation.context.sockets.send(AvmObject::Avm1(this), data);
+
+    Ok(Value::Undefined)
+}
+
+fn close<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    // `onClose` is only called when the server closes the connection.
+    activation.context.sockets.close(AvmObject::Avm1(this));
+
+    Ok(Value::Undefined)
+}
+
+/// The default `onData` handler, which parses the message and passes it on to `onXML`.
+fn on_data<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let src = args.get(0).copied().unwrap_or(Value::Undefined);
+    let xml_constructor = activation.context.avm1.prototypes().xml_constructor;
+    let xml = xml_constructor.construct(activation, &[src])?;
+
+    this.call_method(
+        "onXML".into(),
+        &[xml],
+        activation,
+        ExecutionReason::FunctionCall,
+    )?;
+
+    Ok(Value::Undefined)
+}
+
+pub fn create_proto<'gc>(
+    context: &mut GcContext<'_, 'gc>,
+    proto: Object<'gc>,
+    fn_proto: Object<'gc>,
+) -> Object<'gc> {
+    let object = ScriptObject::new(context.gc_context, Some(proto));
+    define_properties_on(PROTO_DECLS, context, object, fn_proto);
+    object.into()
+}
+
+pub fn create_class<'gc>(
+    context: &mut GcContext<'_, 'gc>,
+    xml_socket_proto: Object<'gc>,
+    fn_proto: Object<'gc>,
+) -> Object<'gc> {
+    FunctionObject::constructor(
+        context.gc_context,
+        Executable::Native(constructor),
+        constructor_to_fn!(constructor),
+        fn_proto,
+        xml_socket_proto,
+    )
+}
@@ -9,8 +9,10 @@ use crate::avm1::globals::convolution_filter::ConvolutionFilter;
 use crate::avm1::globals::date::Date;
 use crate::avm1::globals::displacement_map_filter::DisplacementMapFilter;
 use crate::avm1::globals::drop_shadow_filter::DropShadowFilter;
+use crate::avm1::globals::file_reference::FileReference;
 use crate::avm1::globals::glow_filter::GlowFilter;
 use crate::avm1::globals::gradient_filter::GradientFilter;
+use crate::avm1::globals::netconnection::NetConnection;
 use crate::avm1::globals::transform::TransformObject;
 use crate::avm1::object::array_object::ArrayObject;
 use crate::avm1::object::shared_object::SharedObject;
@@ -59,6 +61,8 @@ pub enum NativeObject<'gc> {
     Transform(TransformObject<'gc>),
     TextFormat(GcCell<'gc, TextFormat>),
     NetStream(NetStream<'gc>),
+    NetConnection(GcCell<'gc, NetConnection>),
+    FileReference(GcCell<'gc, FileReference>),
     BitmapData(BitmapDataWrapper<'gc>),
 }
 
@@ -7,6 +7,7 @@ use crate::avm1::property_map::PropertyMap;
 use crate::avm1::scope::Scope;
 use crate::avm1::{scope, Activation, ActivationIdentifier, Error, Object, Value};
 use crate::context::{GcContext, UpdateContext};
+use crate::debugger::CodeLocation;
 use crate::frame_lifecycle::FramePhase;
 use crate::prelude::*;
 use crate::string::AvmString;
@@ -135,7 +136,8 @@ impl<'gc> Avm1<'gc> {
             ),
         );
         let constant_pool = parent_activation.context.avm1.constant_pool;
-        let child_name = parent_activation.id.child(name);
+        let name: Cow<'static, str> = name.into();
+        let child_name = parent_activation.id.child(name.clone());
         let mut child_activation = Activation::from_action(
             parent_activation.context.reborrow(),
             child_name,
@@ -146,7 +148,14 @@ impl<'gc> Avm1<'gc> {
             clip_obj.into(),
             None,
         );
-        if let Err(e) = child_activation.run_actions(code) {
+        let location = || match active_clip.as_movie_clip() {
+            Some(clip) => CodeLocation::FrameScript {
+                clip: active_clip.path().to_string(),
+                frame: clip.current_frame(),
+            },
+            None => CodeLocation::Other(format!("{} {name}", active_clip.path())),
+        };
+        if let Err(e) = child_activation.run_body(code, location) {
             root_error_handler(&mut child_activation, e);
         }
     }
@@ -231,7 +240,8 @@ impl<'gc> Avm1<'gc> {
             clip_obj.into(),
             None,
         );
-        if let Err(e) = child_activation.run_actions(code) {
+        let location = || CodeLocation::Other(format!("{} [Init]", active_clip.path()));
+        if let Err(e) = child_activation.run_body(code, location) {
             root_error_handler(&mut child_activation, e);
         }
     }
@@ -315,6 +325,10 @@ impl<'gc> Avm1<'gc> {
         self.stack.len()
     }
 
+    pub fn stack(&self) -> &[Value<'gc>] {
+        &self.stack
+    }
+
     pub fn clear_stack(&mut self) {
         self.stack.clear()
     }
@@ -68,13 +68,21 @@ pub use crate::avm2::object::{
     ArrayObject, BitmapDataObject, ClassObject, EventObject, Object, ScriptObject,
     SoundChannelObject, StageObject, TObject,
 };
+pub use crate::avm2::property::Property;
 pub use crate::avm2::qname::QName;
 pub use crate::avm2::value::Value;
 
 use self::object::WeakObject;
 use self::scope::Scope;
 
-const BROADCAST_WHITELIST: [&str; 4] = ["enterFrame", "exitFrame", "frameConstructed", "render"];
+const BROADCAST_WHITELIST: [&str; 6] = [
+    "activate",
+    "deactivate",
+    "enterFrame",
+    "exitFrame",
+    "frameConstructed",
+    "render",
+];
 
 /// The state of an AVM2 interpreter.
 #[derive(Collect)]
@@ -109,6 +117,8 @@ pub struct Avm2<'gc> {
     pub flash_utils_internal: Namespace<'gc>,
     pub flash_geom_internal: Namespace<'gc>,
     pub flash_events_internal: Namespace<'gc>,
+    // the namespace of Ruffle's own hidden properties, `ruffle` in our AS sources
+    pub ruffle_private_namespace: Namespace<'gc>,
 
     #[collect(require_static)]
     native_method_table: &'static [Option<(&'static str, NativeMethodImpl)>],
@@ -171,6 +181,7 @@ impl<'gc> Avm2<'gc> {
             flash_utils_internal: Namespace::internal("flash.utils", context),
             flash_geom_internal: Namespace::internal("flash.geom", context),
             flash_events_internal: Namespace::internal("flash.events", context),
+            ruffle_private_namespace: Namespace::package("__ruffle__", context),
 
             native_method_table: Default::default(),
             native_instance_allocator_table: Default::default(),
@@ -369,6 +380,22 @@ impl<'gc> Avm2<'gc> {
         bucket.push(object.downgrade());
     }
 
+    /// Remove an object from every broadcast list.
+    ///
+    /// The object will no longer receive any broadcast events, even if it
+    /// still has handlers registered for them.
+    pub fn unregister_broadcast_listener(
+        context: &mut UpdateContext<'_, 'gc>,
+        object: Object<'gc>,
+    ) {
+        for bucket in context.avm2.broadcast_list.values_mut() {
+            bucket.retain(|x| {
+                x.upgrade(context.gc_context)
+                    .map_or(false, |obj| !Object::ptr_eq(obj, object))
+            });
+        }
+    }
+
     /// Dispatch an event on all objects in the current execution list.
     ///
     /// `on_type` specifies a class or interface constructor whose instances,
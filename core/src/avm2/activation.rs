@@ -8,6 +8,7 @@ use crate::avm2::error::{
     argument_error, make_null_or_undefined_error, make_reference_error, type_error,
     ReferenceErrorCode,
 };
+use crate::avm2::function::display_function;
 use crate::avm2::method::{BytecodeMethod, Method, ParamConfig};
 use crate::avm2::object::{
     ArrayObject, ByteArrayObject, ClassObject, FunctionObject, NamespaceObject, ScriptObject,
@@ -21,7 +22,8 @@ use crate::avm2::Namespace;
 use crate::avm2::QName;
 use crate::avm2::{value, Avm2, Error};
 use crate::context::{GcContext, UpdateContext};
-use crate::string::{AvmAtom, AvmString};
+use crate::debugger::{CodeLocation, PausedState};
+use crate::string::{AvmAtom, AvmString, WString};
 use crate::swf::extensions::ReadSwfExt;
 use gc_arena::{Gc, GcCell};
 use smallvec::SmallVec;
@@ -929,6 +931,11 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         let body = body?;
         let mut reader = Reader::new(&body.code);
 
+        let subclass_object = self.subclass_object;
+        self.context
+            .debugger
+            .enter_frame(|| debugger_location(method, subclass_object));
+
         let val = loop {
             let result = self.do_next_opcode(method, &mut reader, &body.code);
             match result {
@@ -938,6 +945,8 @@ impl<'a, 'gc> Activation<'a, 'gc> {
             }
         };
 
+        self.context.debugger.exit_frame();
+
         self.clear_stack();
         self.clear_scope();
         val
@@ -1015,6 +1024,10 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         if let Ok(op) = op {
             avm_debug!(self.avm2(), "Opcode: {op:?}");
 
+            if self.context.debugger.should_pause() {
+                self.pause_in_debugger(instruction_start, &op);
+            }
+
             let result = match op {
                 Op::PushByte { value } => self.op_push_byte(value),
                 Op::PushDouble { value } => self.op_push_double(method, value),
@@ -1214,6 +1227,67 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         }
     }
 
+    /// Hand control to the debugger before running `op`.
+    fn pause_in_debugger(&mut self, offset: usize, op: &Op) {
+        let mut locals = Vec::new();
+        let mut index = 0;
+        while let Some(value) = self.local_registers.get(index).copied() {
+            let summary = self.summarize_value(value);
+            locals.push((format!("register{index}"), summary));
+            index += 1;
+        }
+
+        let mut scopes = Vec::new();
+        let mut index = 0;
+        while let Some(scope) = self.outer.get(index) {
+            scopes.push(scope);
+            index += 1;
+        }
+        scopes.extend_from_slice(self.scope_frame());
+        let scope_chain = scopes
+            .into_iter()
+            .map(|scope| {
+                let summary = self.summarize_value(scope.values().into());
+                if scope.with() {
+                    format!("with: {summary}")
+                } else {
+                    summary
+                }
+            })
+            .collect();
+
+        let stack = self.context.avm2.stack[self.stack_depth..].to_vec();
+        let stack = stack
+            .into_iter()
+            .map(|value| self.summarize_value(value))
+            .collect();
+
+        self.context.debugger.pause_with(PausedState {
+            offset,
+            instruction: format!("{op:?}"),
+            locals,
+            scope_chain,
+            stack,
+            ..Default::default()
+        });
+    }
+
+    /// Describe a value on a single line, without running any ActionScript.
+    fn summarize_value(&mut self, value: Value<'gc>) -> String {
+        match value {
+            Value::Object(object) => format!(
+                "[object {}]",
+                object.instance_of_class_name(self.context.gc_context)
+            ),
+            Value::String(string) => format!("{string:?}"),
+            // Converting other primitives to strings can't call into ActionScript.
+            _ => value
+                .coerce_to_string(self)
+                .map(|string| string.to_string())
+                .unwrap_or_default(),
+        }
+    }
+
     fn unknown_op(&mut self, op: swf::avm2::types::Op) -> Result<FrameControl<'gc>, Error<'gc>> {
         tracing::error!("Unknown AVM2 opcode: {:?}", op);
         Err("Unknown op".into())
@@ -3304,11 +3378,13 @@ impl<'a, 'gc> Activation<'a, 'gc> {
 
     fn op_bkpt(&mut self) -> Result<FrameControl<'gc>, Error<'gc>> {
         // while a debugger is not attached, this is a no-op
+        self.context.debugger.pause();
         Ok(FrameControl::Continue)
     }
 
     fn op_bkpt_line(&mut self, _line_num: u32) -> Result<FrameControl<'gc>, Error<'gc>> {
         // while a debugger is not attached, this is a no-op
+        self.context.debugger.pause();
         Ok(FrameControl::Continue)
     }
 
@@ -3322,3 +3398,24 @@ impl<'a, 'gc> Activation<'a, 'gc> {
         Err(Error::AvmError(error_val))
     }
 }
+
+/// Describes a method for the debugger.
+fn debugger_location<'gc>(
+    method: Gc<'gc, BytecodeMethod<'gc>>,
+    class: Option<ClassObject<'gc>>,
+) -> CodeLocation {
+    let mut name = WString::new();
+    display_function(&mut name, &Method::Bytecode(method), class);
+    let name = name.to_string();
+    CodeLocation::Function {
+        name: name.strip_suffix("()").unwrap_or(&name).to_owned(),
+        class: class.map(|class| {
+            class
+                .inner_class_definition()
+                .read()
+                .name()
+                .to_qualified_name_no_mc()
+                .to_string()
+        }),
+    }
+}
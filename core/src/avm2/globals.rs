@@ -74,6 +74,7 @@ pub struct SystemClasses<'gc> {
     pub graphicsendfill: ClassObject<'gc>,
     pub graphicsgradientfill: ClassObject<'gc>,
     pub graphicspath: ClassObject<'gc>,
+    pub graphicsshaderfill: ClassObject<'gc>,
     pub graphicstrianglepath: ClassObject<'gc>,
     pub graphicssolidfill: ClassObject<'gc>,
     pub graphicsstroke: ClassObject<'gc>,
@@ -93,7 +94,11 @@ pub struct SystemClasses<'gc> {
     pub qname: ClassObject<'gc>,
     pub mouseevent: ClassObject<'gc>,
     pub progressevent: ClassObject<'gc>,
+    pub sampledataevent: ClassObject<'gc>,
+    pub dataevent: ClassObject<'gc>,
     pub httpstatusevent: ClassObject<'gc>,
+    pub netstatusevent: ClassObject<'gc>,
+    pub statusevent: ClassObject<'gc>,
     pub textevent: ClassObject<'gc>,
     pub errorevent: ClassObject<'gc>,
     pub ioerrorevent: ClassObject<'gc>,
@@ -125,6 +130,7 @@ pub struct SystemClasses<'gc> {
     pub vertexbuffer3d: ClassObject<'gc>,
     pub program3d: ClassObject<'gc>,
     pub urlvariables: ClassObject<'gc>,
+    pub filereference: ClassObject<'gc>,
     pub bevelfilter: ClassObject<'gc>,
     pub bitmapfilter: ClassObject<'gc>,
     pub blurfilter: ClassObject<'gc>,
@@ -135,6 +141,7 @@ pub struct SystemClasses<'gc> {
     pub glowfilter: ClassObject<'gc>,
     pub gradientbevelfilter: ClassObject<'gc>,
     pub gradientglowfilter: ClassObject<'gc>,
+    pub shaderfilter: ClassObject<'gc>,
     pub texture: ClassObject<'gc>,
     pub cubetexture: ClassObject<'gc>,
     pub rectangletexture: ClassObject<'gc>,
@@ -189,6 +196,7 @@ impl<'gc> SystemClasses<'gc> {
             graphicsendfill: object,
             graphicsgradientfill: object,
             graphicspath: object,
+            graphicsshaderfill: object,
             graphicstrianglepath: object,
             graphicssolidfill: object,
             graphicsstroke: object,
@@ -208,7 +216,11 @@ impl<'gc> SystemClasses<'gc> {
             qname: object,
             mouseevent: object,
             progressevent: object,
+            sampledataevent: object,
+            dataevent: object,
             httpstatusevent: object,
+            netstatusevent: object,
+            statusevent: object,
             textevent: object,
             errorevent: object,
             ioerrorevent: object,
@@ -240,6 +252,7 @@ impl<'gc> SystemClasses<'gc> {
             vertexbuffer3d: object,
             program3d: object,
             urlvariables: object,
+            filereference: object,
             bevelfilter: object,
             bitmapfilter: object,
             blurfilter: object,
@@ -250,6 +263,7 @@ impl<'gc> SystemClasses<'gc> {
             glowfilter: object,
             gradientbevelfilter: object,
             gradientglowfilter: object,
+            shaderfilter: object,
             texture: object,
             cubetexture: object,
             rectangletexture: object,
@@ -661,6 +675,7 @@ fn load_playerglobal<'gc>(
                 graphicsgradientfill
             ),
             ("flash.display", "GraphicsPath", graphicspath),
+            ("flash.display", "GraphicsShaderFill", graphicsshaderfill),
             (
                 "flash.display",
                 "GraphicsTrianglePath",
@@ -704,7 +719,11 @@ fn load_playerglobal<'gc>(
             ("flash.events", "ErrorEvent", errorevent),
             ("flash.events", "KeyboardEvent", keyboardevent),
             ("flash.events", "ProgressEvent", progressevent),
+            ("flash.events", "SampleDataEvent", sampledataevent),
+            ("flash.events", "DataEvent", dataevent),
             ("flash.events", "HTTPStatusEvent", httpstatusevent),
+            ("flash.events", "NetStatusEvent", netstatusevent),
+            ("flash.events", "StatusEvent", statusevent),
             ("flash.events", "SecurityErrorEvent", securityerrorevent),
             ("flash.events", "IOErrorEvent", ioerrorevent),
             ("flash.events", "MouseEvent", mouseevent),
@@ -718,6 +737,7 @@ fn load_playerglobal<'gc>(
             ("flash.media", "SoundChannel", soundchannel),
             ("flash.media", "SoundTransform", soundtransform),
             ("flash.net", "URLVariables", urlvariables),
+            ("flash.net", "FileReference", filereference),
             ("flash.utils", "ByteArray", bytearray),
             ("flash.system", "ApplicationDomain", application_domain),
             ("flash.text", "StaticText", statictext),
@@ -738,6 +758,7 @@ fn load_playerglobal<'gc>(
             ("flash.filters", "GlowFilter", glowfilter),
             ("flash.filters", "GradientBevelFilter", gradientbevelfilter),
             ("flash.filters", "GradientGlowFilter", gradientglowfilter),
+            ("flash.filters", "ShaderFilter", shaderfilter),
         ]
     );
 
@@ -35,6 +35,11 @@ package flash.display {
         public native function get blendMode():String;
         public native function set blendMode(value:String):void;
 
+        public function set blendShader(value:Shader):void {
+            stub_setter("flash.display.DisplayObject", "blendShader");
+            this.blendMode = BlendMode.SHADER;
+        }
+
         public native function get height():Number;
         public native function set height(value:Number):void;
 
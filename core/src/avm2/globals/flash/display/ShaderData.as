@@ -3,7 +3,12 @@ package flash.display {
     import __ruffle__.stub_constructor;
     
     public final dynamic class ShaderData {
+        // The bytecode of the shader, which is run by `ShaderJob`, `ShaderFilter`
+        // and shader fills.
+        internal var _byteCode:ByteArray;
+
         public function ShaderData(bytecode:ByteArray) {
+            this._byteCode = bytecode;
             this.init(bytecode);
         }
 
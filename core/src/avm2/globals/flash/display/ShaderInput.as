@@ -14,6 +14,10 @@ package flash.display {
             return _height;
         }
 
+        public function set height(value:int):void {
+            _height = value;
+        }
+
         public function get index():int {
             return _index;
         }
@@ -22,6 +26,10 @@ package flash.display {
             return _width;
         }
 
+        public function set width(value:int):void {
+            _width = value;
+        }
+
         public function get input():Object {
             return _object;
         }
@@ -29,6 +37,10 @@ package flash.display {
         public function set input(value:Object):void {
             // FIXME - validate
             _object = value;
+            if (value is BitmapData) {
+                _width = value.width;
+                _height = value.height;
+            }
         }
     }
 }
\ No newline at end of file
@@ -1,45 +1,103 @@
 package flash.display {
-    import __ruffle__.stub_method;
-    import __ruffle__.stub_getter;
-    import __ruffle__.stub_setter;
-    import __ruffle__.stub_constructor;
     import flash.events.EventDispatcher;
+    import flash.events.ShaderEvent;
+    import flash.utils.ByteArray;
+    import flash.utils.setTimeout;
+    import flash.utils.clearTimeout;
 
     public class ShaderJob extends EventDispatcher {
-        
+        private var _shader:Shader;
+        private var _target:Object;
+        private var _width:int;
+        private var _height:int;
+        private var _progress:Number = 0;
+
+        // The timeout that runs a job that was started without waiting for completion.
+        private var _pendingTimeout:uint;
+        private var _pending:Boolean = false;
+
         public function ShaderJob(shader:Shader = null, target:Object = null, width:int = 0, height:int = 0) {
-            stub_constructor("flash.display.ShaderJob");
+            this._shader = shader;
+            this._target = target;
+            this._width = width;
+            this._height = height;
         }
 
         public function cancel():void {
-            stub_method("flash.display.ShaderJob", "cancel")
+            if (this._pending) {
+                clearTimeout(this._pendingTimeout);
+                this._pending = false;
+            }
         }
 
         public function start(waitForCompletion:Boolean = false):void {
-            stub_method("flash.display.ShaderJob", "start")
+            if (waitForCompletion) {
+                this.runJob();
+                return;
+            }
+
+            // FIXME - Flash Player runs these jobs on a background thread.
+            // We run them on the next tick instead, so that they still complete
+            // asynchronously.
+            this._progress = 0;
+            this._pending = true;
+            this._pendingTimeout = setTimeout(this.finishJob, 0);
+        }
+
+        private function finishJob():void {
+            this._pending = false;
+            this.runJob();
+            this.dispatchEvent(new ShaderEvent(
+                ShaderEvent.COMPLETE,
+                false,
+                false,
+                this._target as BitmapData,
+                this._target as ByteArray,
+                this._target as Vector.<Number>
+            ));
+        }
+
+        private function runJob():void {
+            this.runShader(this._shader, this._target, this._width, this._height);
+            this._progress = 1;
         }
 
+        private native function runShader(shader:Shader, target:Object, width:int, height:int):void;
+
         public function get height():int {
-            stub_getter("flash.display.ShaderJob", "height");
-            return 0;
+            return this._height;
         }
 
         public function set height(value:int):void {
-            stub_setter("flash.display.ShaderJob", "height");
+            this._height = value;
         }
 
         public function get progress():Number {
-            stub_getter("flash.display.ShaderJob", "progress")
-            return 0;
+            return this._progress;
         }
 
         public function get shader():Shader {
-            stub_getter("flash.display.ShaderJob", "shader");
-            return null;
+            return this._shader;
         }
 
         public function set shader(value:Shader):void {
-            stub_setter("flash.display.ShaderJob", "shader");
+            this._shader = value;
+        }
+
+        public function get target():Object {
+            return this._target;
+        }
+
+        public function set target(value:Object):void {
+            this._target = value;
+        }
+
+        public function get width():int {
+            return this._width;
+        }
+
+        public function set width(value:int):void {
+            this._width = value;
         }
     }
-}
\ No newline at end of file
+}
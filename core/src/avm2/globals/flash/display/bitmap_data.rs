@@ -20,10 +20,13 @@ use crate::display_object::TDisplayObject;
 use crate::ecma_conversions::round_to_even;
 use crate::swf::BlendMode;
 use gc_arena::GcCell;
+use ruffle_render::bitmap::PixelRegion;
 use ruffle_render::filters::Filter;
 use ruffle_render::transform::Transform;
 use std::str::FromStr;
 
+use super::shader_data::run_shader_data;
+
 // Computes the integer x,y,width,height values from
 // the given `Rectangle`. This method performs `x + width`
 // and `y + height` as floating point operations before
@@ -1070,6 +1073,45 @@ pub fn apply_filter<'gc>(
                 .coerce_to_u32(activation)?,
         );
         let filter = args.get_object(activation, 3, "filter")?;
+
+        let shader_filter = activation.avm2().classes().shaderfilter;
+        if filter.is_of_type(shader_filter, &mut activation.context) {
+            let Some(shader_data) = filter
+                .get_public_property("shader", activation)?
+                .as_object()
+                .map(|shader| shader.get_public_property("data", activation))
+                .transpose()?
+                .and_then(|data| data.as_object())
+            else {
+                return Ok(Value::Undefined);
+            };
+
+            // The source is passed to the shader as its first input.
+            let source = operations::to_shader_image(
+                source_bitmap,
+                PixelRegion::for_region(
+                    source_point.0,
+                    source_point.1,
+                    source_size.0,
+                    source_size.1,
+                ),
+            );
+            let image = run_shader_data(
+                activation,
+                shader_data,
+                source.width,
+                source.height,
+                Some(source),
+            )?;
+            operations::draw_shader_image(
+                activation.context.gc_context,
+                dest_bitmap,
+                &image,
+                dest_point,
+            );
+            return Ok(Value::Undefined);
+        }
+
         let filter = Filter::from_avm2_object(activation, filter)?;
         operations::apply_filter(
             &mut activation.context,
@@ -10,13 +10,13 @@ use crate::avm2::Namespace;
 use crate::avm2::{ArrayObject, ArrayStorage};
 use crate::avm2::{ClassObject, Error};
 use crate::avm2::{Multiname, StageObject};
+use crate::avm2_stub_setter;
 use crate::display_object::{DisplayObject, HitTestOptions, TDisplayObject};
 use crate::ecma_conversions::round_to_even;
 use crate::prelude::*;
 use crate::string::AvmString;
 use crate::types::{Degrees, Percent};
 use crate::vminterface::Instantiator;
-use crate::{avm2_stub_getter, avm2_stub_setter};
 use ruffle_render::filters::Filter;
 use std::str::FromStr;
 use swf::Twips;
@@ -281,6 +281,19 @@ pub fn set_filters<'gc>(
                                 return build_argument_type_error(activation);
                             }
 
+                            let shader_filter = activation.avm2().classes().shaderfilter;
+                            if filter_object.is_of_type(shader_filter, &mut activation.context) {
+                                // Shaders only run on the CPU (for `BitmapData.applyFilter`
+                                // and `ShaderJob`), so a `ShaderFilter` isn't applied when
+                                // rendering a display object.
+                                avm2_stub_setter!(
+                                    activation,
+                                    "flash.display.DisplayObject",
+                                    "filters"
+                                );
+                                continue;
+                            }
+
                             filter_vec.push(Filter::from_avm2_object(activation, filter_object)?);
                         }
                     }
@@ -350,96 +363,164 @@ pub fn set_y<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Implements `z`'s getter.
 pub fn get_z<'gc>(
-    activation: &mut Activation<'_, 'gc>,
-    _this: Option<Object<'gc>>,
+    _activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_getter!(activation, "flash.display.DisplayObject", "z");
-    Ok(0.into())
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        return Ok(dobj.base().transform_3d().map_or(0.0, |t| t.z).into());
+    }
+
+    Ok(Value::Undefined)
 }
 
+/// Implements `z`'s setter.
 pub fn set_z<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Option<Object<'gc>>,
-    _args: &[Value<'gc>],
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_setter!(activation, "flash.display.DisplayObject", "z");
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        let z = args.get_f64(activation, 0)?;
+        dobj.base_mut(activation.context.gc_context)
+            .transform_3d_mut()
+            .z = z;
+    }
+
     Ok(Value::Undefined)
 }
 
+/// Implements `rotationX`'s getter.
 pub fn get_rotation_x<'gc>(
-    activation: &mut Activation<'_, 'gc>,
-    _this: Option<Object<'gc>>,
+    _activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_getter!(activation, "flash.display.DisplayObject", "rotationX");
-    Ok(0.into())
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        let degrees: f64 = dobj
+            .base()
+            .transform_3d()
+            .map_or(0.0, |t| t.rotation_x.into());
+        return Ok(normalize_degrees(degrees).into());
+    }
+
+    Ok(Value::Undefined)
 }
 
+/// Implements `rotationX`'s setter.
 pub fn set_rotation_x<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Option<Object<'gc>>,
-    _args: &[Value<'gc>],
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_setter!(activation, "flash.display.DisplayObject", "rotationX");
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        let degrees = args.get_f64(activation, 0)?;
+        dobj.base_mut(activation.context.gc_context)
+            .transform_3d_mut()
+            .rotation_x = Degrees::from(degrees);
+    }
+
     Ok(Value::Undefined)
 }
 
+/// Implements `rotationY`'s getter.
 pub fn get_rotation_y<'gc>(
-    activation: &mut Activation<'_, 'gc>,
-    _this: Option<Object<'gc>>,
+    _activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_getter!(activation, "flash.display.DisplayObject", "rotationY");
-    Ok(0.into())
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        let degrees: f64 = dobj
+            .base()
+            .transform_3d()
+            .map_or(0.0, |t| t.rotation_y.into());
+        return Ok(normalize_degrees(degrees).into());
+    }
+
+    Ok(Value::Undefined)
 }
 
+/// Implements `rotationY`'s setter.
 pub fn set_rotation_y<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Option<Object<'gc>>,
-    _args: &[Value<'gc>],
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_setter!(activation, "flash.display.DisplayObject", "rotationY");
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        let degrees = args.get_f64(activation, 0)?;
+        dobj.base_mut(activation.context.gc_context)
+            .transform_3d_mut()
+            .rotation_y = Degrees::from(degrees);
+    }
+
     Ok(Value::Undefined)
 }
 
+/// Implements `rotationZ`'s getter, which is the same as `rotation`.
 pub fn get_rotation_z<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Option<Object<'gc>>,
-    _args: &[Value<'gc>],
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_getter!(activation, "flash.display.DisplayObject", "rotationZ");
-    Ok(0.into())
+    get_rotation(activation, this, args)
 }
 
+/// Implements `rotationZ`'s setter, which turns the display object into a 3D object.
 pub fn set_rotation_z<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Option<Object<'gc>>,
-    _args: &[Value<'gc>],
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_setter!(activation, "flash.display.DisplayObject", "rotationZ");
-    Ok(Value::Undefined)
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        dobj.base_mut(activation.context.gc_context)
+            .transform_3d_mut();
+    }
+    set_rotation(activation, this, args)
 }
 
+/// Implements `scaleZ`'s getter.
 pub fn get_scale_z<'gc>(
-    activation: &mut Activation<'_, 'gc>,
-    _this: Option<Object<'gc>>,
+    _activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_getter!(activation, "flash.display.DisplayObject", "scaleZ");
-    Ok(1.into())
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        return Ok(dobj.base().transform_3d().map_or(1.0, |t| t.scale_z).into());
+    }
+
+    Ok(Value::Undefined)
 }
 
+/// Implements `scaleZ`'s setter.
 pub fn set_scale_z<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Option<Object<'gc>>,
-    _args: &[Value<'gc>],
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_setter!(activation, "flash.display.DisplayObject", "scaleZ");
+    if let Some(dobj) = this.and_then(|this| this.as_display_object()) {
+        let scale_z = args.get_f64(activation, 0)?;
+        dobj.base_mut(activation.context.gc_context)
+            .transform_3d_mut()
+            .scale_z = scale_z;
+    }
+
     Ok(Value::Undefined)
 }
 
+/// Wraps an angle in degrees into the range that Flash reports, from -180 to 180.
+fn normalize_degrees(degrees: f64) -> f64 {
+    let rem = degrees % 360.0;
+    if rem > 180.0 {
+        rem - 360.0
+    } else if rem < -180.0 {
+        rem + 360.0
+    } else {
+        rem
+    }
+}
+
 /// Implements `rotation`'s getter.
 pub fn get_rotation<'gc>(
     activation: &mut Activation<'_, 'gc>,
@@ -760,6 +841,11 @@ pub fn set_blend_mode<'gc>(
 
         if let Ok(mode) = BlendMode::from_str(&mode.to_string()) {
             dobj.set_blend_mode(activation.context.gc_context, mode);
+        } else if &mode == b"shader" {
+            // Shaders only run on the CPU, which the renderer can't call back into while
+            // blending, so `BlendMode.SHADER` isn't supported and draws the object normally.
+            avm2_stub_setter!(activation, "flash.display.DisplayObject", "blendMode");
+            dobj.set_blend_mode(activation.context.gc_context, BlendMode::Normal);
         } else {
             tracing::error!("Unknown blend mode {}", mode);
             return Err(make_error_2008(activation, "blendMode"));
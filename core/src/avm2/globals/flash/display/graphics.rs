@@ -2,17 +2,17 @@
 
 use crate::avm2::activation::Activation;
 use crate::avm2::error::make_error_2008;
-use crate::avm2::globals::flash::geom::transform::object_to_matrix;
-use crate::avm2::object::{Object, TObject, VectorObject};
+use crate::avm2::globals::flash::geom::transform::{matrix_to_object, object_to_matrix};
+use crate::avm2::object::{ArrayObject, Object, TObject, VectorObject};
 use crate::avm2::parameters::ParametersExt;
 use crate::avm2::value::Value;
 use crate::avm2::vector::VectorStorage;
 use crate::avm2::{ArrayStorage, Error};
 use crate::avm2_stub_method;
-use crate::display_object::TDisplayObject;
+use crate::display_object::{DisplayObject, TDisplayObject, TDisplayObjectContainer};
 use crate::drawing::Drawing;
 use crate::string::{AvmString, WStr};
-use ruffle_render::shape_utils::{DrawCommand, GradientType};
+use ruffle_render::shape_utils::{DrawCommand, DrawPath, FillRule, GradientType};
 use std::f64::consts::FRAC_1_SQRT_2;
 use swf::{
     Color, FillStyle, Fixed16, Fixed8, Gradient, GradientInterpolation, GradientRecord,
@@ -90,6 +90,38 @@ pub fn begin_bitmap_fill<'gc>(
     Ok(Value::Undefined)
 }
 
+/// Implements `Graphics.beginShaderFill`.
+///
+/// The shader is run with the inputs and parameters that it has now, over the
+/// area that the fill ends up covering.
+pub fn begin_shader_fill<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(this) = this.and_then(|t| t.as_display_object()) {
+        let shader = args.get_object(activation, 0, "shader")?;
+        let matrix = if let Some(matrix) = args.try_get_object(activation, 1) {
+            Matrix::from(object_to_matrix(matrix, activation)?)
+        } else {
+            // Users can explicitly pass in `null` to mean identity matrix
+            Matrix::IDENTITY
+        };
+
+        let Some(shader_data) = shader.get_public_property("data", activation)?.as_object() else {
+            return Ok(Value::Undefined);
+        };
+        let (shader, arguments) =
+            super::shader_data::shader_data_arguments(activation, shader_data, None)?;
+
+        if let Some(mut draw) = this.as_drawing(activation.context.gc_context) {
+            draw.set_shader_fill(shader, arguments, matrix.into());
+        }
+    }
+
+    Ok(Value::Undefined)
+}
+
 /// Implements `Graphics.beginGradientFill`.
 pub fn begin_gradient_fill<'gc>(
     activation: &mut Activation<'_, 'gc>,
@@ -809,63 +841,570 @@ pub fn line_gradient_style<'gc>(
 /// Implements `Graphics.cubicCurveTo`
 pub fn cubic_curve_to<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Option<Object<'gc>>,
-    _args: &[Value<'gc>],
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_method!(activation, "flash.display.Graphics", "cubicCurveTo");
+    if let Some(this) = this.and_then(|t| t.as_display_object()) {
+        let control_a_x = args.get_f64(activation, 0)?;
+        let control_a_y = args.get_f64(activation, 1)?;
+        let control_b_x = args.get_f64(activation, 2)?;
+        let control_b_y = args.get_f64(activation, 3)?;
+        let anchor_x = args.get_f64(activation, 4)?;
+        let anchor_y = args.get_f64(activation, 5)?;
+
+        if let Some(mut draw) = this.as_drawing(activation.context.gc_context) {
+            draw.cubic_curve_to(
+                Point::from_pixels(control_a_x, control_a_y),
+                Point::from_pixels(control_b_x, control_b_y),
+                Point::from_pixels(anchor_x, anchor_y),
+            );
+        }
+    }
+
     Ok(Value::Undefined)
 }
 
 /// Implements `Graphics.copyFrom`
 pub fn copy_from<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Option<Object<'gc>>,
-    _args: &[Value<'gc>],
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_method!(activation, "flash.display.Graphics", "copyFrom");
+    if let Some(this) = this.and_then(|t| t.as_display_object()) {
+        let source = args
+            .get_object(activation, 0, "sourceGraphics")?
+            .as_display_object()
+            .expect("Source argument is ensured to be a Graphics from actionscript");
+
+        // Copy the drawing first, as the source may be this very object.
+        let drawing = source
+            .as_drawing(activation.context.gc_context)
+            .map(|drawing| drawing.clone());
+        if let Some(drawing) = drawing {
+            if let Some(mut draw) = this.as_drawing(activation.context.gc_context) {
+                *draw = drawing;
+            }
+        }
+    }
+
     Ok(Value::Undefined)
 }
 
 /// Implements `Graphics.drawPath`
 pub fn draw_path<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Option<Object<'gc>>,
-    _args: &[Value<'gc>],
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_method!(activation, "flash.display.Graphics", "drawPath");
+    if let Some(this) = this.and_then(|t| t.as_display_object()) {
+        let commands = args.get_object(activation, 0, "commands")?;
+        let commands = vector_to_ints(activation, commands)?;
+        let data = args.get_object(activation, 1, "data")?;
+        let data = vector_to_numbers(activation, data)?;
+        let winding = args.get_string(activation, 2)?;
+        let winding = parse_winding(activation, winding)?;
+
+        if let Some(mut draw) = this.as_drawing(activation.context.gc_context) {
+            draw_path_internal(&mut draw, &commands, &data, winding);
+        }
+    }
+
     Ok(Value::Undefined)
 }
 
+/// Draws the commands of a `GraphicsPath`, consuming their coordinates from `data`.
+fn draw_path_internal(draw: &mut Drawing, commands: &[i32], data: &[f64], winding: FillRule) {
+    fn next_point(data: &mut impl Iterator<Item = f64>) -> Option<Point<Twips>> {
+        Some(Point::from_pixels(data.next()?, data.next()?))
+    }
+
+    draw.set_fill_winding_rule(winding);
+
+    let mut data = data.iter().copied();
+    for command in commands {
+        // Stop as soon as we run out of data.
+        let command = match command {
+            // `GraphicsPathCommand.NO_OP`
+            0 => continue,
+            // `GraphicsPathCommand.MOVE_TO`
+            1 => next_point(&mut data).map(DrawCommand::MoveTo),
+            // `GraphicsPathCommand.LINE_TO`
+            2 => next_point(&mut data).map(DrawCommand::LineTo),
+            // `GraphicsPathCommand.CURVE_TO`
+            3 => next_point(&mut data).and_then(|control| {
+                let anchor = next_point(&mut data)?;
+                Some(DrawCommand::CurveTo { control, anchor })
+            }),
+            // `GraphicsPathCommand.WIDE_MOVE_TO`, whose first two values are ignored
+            4 => next_point(&mut data)
+                .and_then(|_| next_point(&mut data))
+                .map(DrawCommand::MoveTo),
+            // `GraphicsPathCommand.WIDE_LINE_TO`, whose first two values are ignored
+            5 => next_point(&mut data)
+                .and_then(|_| next_point(&mut data))
+                .map(DrawCommand::LineTo),
+            // `GraphicsPathCommand.CUBIC_CURVE_TO`
+            6 => {
+                let control_a = next_point(&mut data);
+                let control_b = next_point(&mut data);
+                let anchor = next_point(&mut data);
+                match (control_a, control_b, anchor) {
+                    (Some(control_a), Some(control_b), Some(anchor)) => {
+                        draw.cubic_curve_to(control_a, control_b, anchor);
+                        continue;
+                    }
+                    _ => break,
+                }
+            }
+            _ => continue,
+        };
+        let Some(command) = command else { break; };
+        draw.draw_command(command);
+    }
+}
+
+fn parse_winding<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    winding: AvmString<'gc>,
+) -> Result<FillRule, Error<'gc>> {
+    if &winding == b"evenOdd" {
+        Ok(FillRule::EvenOdd)
+    } else if &winding == b"nonZero" {
+        Ok(FillRule::NonZero)
+    } else {
+        Err(make_error_2008(activation, "winding"))
+    }
+}
+
 /// Implements `Graphics.drawRoundRectComplex`
 pub fn draw_round_rect_complex<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Option<Object<'gc>>,
-    _args: &[Value<'gc>],
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_method!(activation, "flash.display.Graphics", "drawRoundRectComplex");
+    if let Some(this) = this.and_then(|t| t.as_display_object()) {
+        let x = args.get_f64(activation, 0)?;
+        let y = args.get_f64(activation, 1)?;
+        let width = args.get_f64(activation, 2)?;
+        let height = args.get_f64(activation, 3)?;
+        let top_left = args.get_f64(activation, 4)?;
+        let top_right = args.get_f64(activation, 5)?;
+        let bottom_left = args.get_f64(activation, 6)?;
+        let bottom_right = args.get_f64(activation, 7)?;
+
+        if let Some(mut draw) = this.as_drawing(activation.context.gc_context) {
+            draw_round_rect_complex_internal(
+                &mut draw,
+                (x, y, width, height),
+                [top_left, top_right, bottom_left, bottom_right],
+            );
+        }
+    }
+
     Ok(Value::Undefined)
 }
 
+/// Draw a roundrect with a different radius for each corner.
+///
+/// The radii are given in the order top-left, top-right, bottom-left, bottom-right.
+/// Like `draw_round_rect_internal`, this starts from the bottom-right corner.
+fn draw_round_rect_complex_internal(
+    draw: &mut Drawing,
+    (x, y, width, height): (f64, f64, f64, f64),
+    radii: [f64; 4],
+) {
+    // Clamp the radii so that the corners fit into the rectangle.
+    let max_radius = width.min(height) / 2.0;
+    let [top_left, top_right, bottom_left, bottom_right] =
+        radii.map(|radius| radius.clamp(0.0, max_radius.max(0.0)));
+
+    let right = x + width;
+    let bottom = y + height;
+
+    draw.draw_command(DrawCommand::MoveTo(Point::from_pixels(
+        right,
+        bottom - bottom_right,
+    )));
+    draw_corner(
+        draw,
+        (right - bottom_right, bottom - bottom_right),
+        bottom_right,
+        (1.0, 1.0),
+        false,
+    );
+    draw.draw_command(DrawCommand::LineTo(Point::from_pixels(
+        x + bottom_left,
+        bottom,
+    )));
+    draw_corner(
+        draw,
+        (x + bottom_left, bottom - bottom_left),
+        bottom_left,
+        (-1.0, 1.0),
+        true,
+    );
+    draw.draw_command(DrawCommand::LineTo(Point::from_pixels(x, y + top_left)));
+    draw_corner(
+        draw,
+        (x + top_left, y + top_left),
+        top_left,
+        (-1.0, -1.0),
+        false,
+    );
+    draw.draw_command(DrawCommand::LineTo(Point::from_pixels(
+        right - top_right,
+        y,
+    )));
+    draw_corner(
+        draw,
+        (right - top_right, y + top_right),
+        top_right,
+        (1.0, -1.0),
+        true,
+    );
+    draw.draw_command(DrawCommand::LineTo(Point::from_pixels(
+        right,
+        bottom - bottom_right,
+    )));
+}
+
+/// Draws a quarter of a circle around `center`, following `UNIT_CIRCLE_POINTS` mirrored by `sign`.
+fn draw_corner(
+    draw: &mut Drawing,
+    center: (f64, f64),
+    radius: f64,
+    sign: (f64, f64),
+    reverse: bool,
+) {
+    if radius == 0.0 {
+        return;
+    }
+
+    let mut ucp = UNIT_CIRCLE_POINTS;
+    if reverse {
+        ucp.reverse();
+    }
+    let point = |(ux, uy): (f64, f64)| {
+        Point::from_pixels(
+            center.0 + sign.0 * radius * ux,
+            center.1 + sign.1 * radius * uy,
+        )
+    };
+    draw.draw_command(DrawCommand::CurveTo {
+        control: point(ucp[1]),
+        anchor: point(ucp[2]),
+    });
+    draw.draw_command(DrawCommand::CurveTo {
+        control: point(ucp[3]),
+        anchor: point(ucp[4]),
+    });
+}
+
+/// The direction of triangles that `Graphics.drawTriangles` skips.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum TriangleCulling {
+    None,
+    Positive,
+    Negative,
+}
+
+fn parse_triangle_culling<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    culling: AvmString<'gc>,
+) -> Result<TriangleCulling, Error<'gc>> {
+    if &culling == b"none" {
+        Ok(TriangleCulling::None)
+    } else if &culling == b"positive" {
+        Ok(TriangleCulling::Positive)
+    } else if &culling == b"negative" {
+        Ok(TriangleCulling::Negative)
+    } else {
+        Err(make_error_2008(activation, "culling"))
+    }
+}
+
 /// Implements `Graphics.drawTriangles`
+///
+/// The triangles are drawn together as a single mesh. When UV coordinates are given for a
+/// bitmap fill, the bitmap is mapped onto each triangle with an affine transform, so the `t`
+/// values of `uvtData` are ignored.
 pub fn draw_triangles<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Option<Object<'gc>>,
-    _args: &[Value<'gc>],
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_method!(activation, "flash.display.Graphics", "drawTriangles");
+    if let Some(this) = this.and_then(|t| t.as_display_object()) {
+        let vertices = args.get_object(activation, 0, "vertices")?;
+        let vertices = vector_to_numbers(activation, vertices)?;
+        let indices = match args.try_get_object(activation, 1) {
+            Some(indices) => Some(vector_to_ints(activation, indices)?),
+            None => None,
+        };
+        let uvt_data = match args.try_get_object(activation, 2) {
+            Some(uvt_data) => Some(vector_to_numbers(activation, uvt_data)?),
+            None => None,
+        };
+        let culling = args.get_string(activation, 3)?;
+        let culling = parse_triangle_culling(activation, culling)?;
+
+        if let Some(mut draw) = this.as_drawing(activation.context.gc_context) {
+            draw_triangles_internal(
+                &mut draw,
+                &vertices,
+                indices.as_deref(),
+                uvt_data.as_deref(),
+                culling,
+            );
+        }
+    }
+
     Ok(Value::Undefined)
 }
 
+fn draw_triangles_internal(
+    draw: &mut Drawing,
+    vertices: &[f64],
+    indices: Option<&[i32]>,
+    uvt_data: Option<&[f64]>,
+    culling: TriangleCulling,
+) {
+    let points: Vec<Point<Twips>> = vertices
+        .chunks_exact(2)
+        .map(|vertex| Point::from_pixels(vertex[0], vertex[1]))
+        .collect();
+    let vertex_count = points.len();
+
+    // `uvtData` holds either `(u, v)` or `(u, v, t)` for each vertex.
+    // It's only used if it covers every vertex.
+    let uvs = uvt_data.and_then(|uvt_data| {
+        let stride = if vertex_count > 0 && uvt_data.len() >= vertex_count * 3 {
+            3
+        } else {
+            2
+        };
+        if uvt_data.len() < vertex_count * stride {
+            return None;
+        }
+        Some(
+            uvt_data
+                .chunks(stride)
+                .take(vertex_count)
+                .map(|uvt| [uvt[0] as f32, uvt[1] as f32])
+                .collect(),
+        )
+    });
+
+    let triangles: Vec<[u32; 3]> = match indices {
+        Some(indices) => indices
+            .chunks_exact(3)
+            .map(|triangle| [0, 1, 2].map(|i| triangle[i].max(0) as u32))
+            .collect(),
+        None => (0..vertex_count as u32 / 3)
+            .map(|triangle| [0, 1, 2].map(|i| triangle * 3 + i))
+            .collect(),
+    };
+
+    let indices = triangles
+        .into_iter()
+        .filter(|triangle| {
+            let [Some(a), Some(b), Some(c)] = triangle.map(|i| points.get(i as usize)) else {
+                return false;
+            };
+            if culling == TriangleCulling::None {
+                return true;
+            }
+            // With the y axis pointing down and the z axis pointing into the screen, triangles
+            // whose corners go counter-clockwise on screen have a normal that faces the viewer.
+            let cross = (b.x - a.x).get() as i64 * (c.y - a.y).get() as i64
+                - (b.y - a.y).get() as i64 * (c.x - a.x).get() as i64;
+            let is_positive = cross < 0;
+            (culling == TriangleCulling::Positive) != is_positive
+        })
+        .flatten()
+        .collect();
+
+    draw.draw_triangles(points, indices, uvs);
+}
+
 /// Implements `Graphics.drawGraphicsData`
 pub fn draw_graphics_data<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Option<Object<'gc>>,
-    _args: &[Value<'gc>],
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_method!(activation, "flash.display.Graphics", "drawGraphicsData");
+    let graphics_data = args.get_object(activation, 0, "graphicsData")?;
+    let graphics_data: Vec<_> = graphics_data
+        .as_vector_storage()
+        .map(|storage| storage.iter().collect())
+        .unwrap_or_default();
+
+    for data in graphics_data {
+        let Some(data) = data.as_object() else { continue; };
+        draw_graphics_data_internal(activation, this, data)?;
+    }
+
     Ok(Value::Undefined)
 }
 
+/// Draws a single `IGraphicsData` object, by calling the `Graphics` method it stands for.
+fn draw_graphics_data_internal<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    data: Object<'gc>,
+) -> Result<(), Error<'gc>> {
+    let classes = activation.avm2().classes();
+    let stroke = classes.graphicsstroke;
+    let solid_fill = classes.graphicssolidfill;
+    let gradient_fill = classes.graphicsgradientfill;
+    let bitmap_fill = classes.graphicsbitmapfill;
+    let shader_fill = classes.graphicsshaderfill;
+    let end_fill_class = classes.graphicsendfill;
+    let path = classes.graphicspath;
+    let triangle_path = classes.graphicstrianglepath;
+
+    if data.is_of_type(stroke, &mut activation.context) {
+        let thickness = data.get_public_property("thickness", activation)?;
+        let pixel_hinting = data.get_public_property("pixelHinting", activation)?;
+        let scale_mode = data.get_public_property("scaleMode", activation)?;
+        let caps = data.get_public_property("caps", activation)?;
+        let joints = data.get_public_property("joints", activation)?;
+        let miter_limit = data.get_public_property("miterLimit", activation)?;
+        let fill = data.get_public_property("fill", activation)?.as_object();
+
+        let (color, alpha) = match fill {
+            Some(fill) if fill.is_of_type(solid_fill, &mut activation.context) => (
+                fill.get_public_property("color", activation)?,
+                fill.get_public_property("alpha", activation)?,
+            ),
+            _ => (0.into(), 1.into()),
+        };
+        line_style(
+            activation,
+            this,
+            &[
+                thickness,
+                color,
+                alpha,
+                pixel_hinting,
+                scale_mode,
+                caps,
+                joints,
+                miter_limit,
+            ],
+        )?;
+
+        if let Some(fill) = fill {
+            if fill.is_of_type(gradient_fill, &mut activation.context) {
+                let args = gradient_fill_args(activation, fill)?;
+                line_gradient_style(activation, this, &args)?;
+            } else if fill.is_of_type(bitmap_fill, &mut activation.context) {
+                let args = bitmap_fill_args(activation, fill)?;
+                line_bitmap_style(activation, this, &args)?;
+            } else if fill.is_of_type(shader_fill, &mut activation.context) {
+                avm2_stub_method!(
+                    activation,
+                    "flash.display.Graphics",
+                    "drawGraphicsData",
+                    "with a GraphicsShaderFill stroke"
+                );
+            }
+        }
+    } else if data.is_of_type(solid_fill, &mut activation.context) {
+        let color = data.get_public_property("color", activation)?;
+        let alpha = data.get_public_property("alpha", activation)?;
+        begin_fill(activation, this, &[color, alpha])?;
+    } else if data.is_of_type(gradient_fill, &mut activation.context) {
+        let args = gradient_fill_args(activation, data)?;
+        begin_gradient_fill(activation, this, &args)?;
+    } else if data.is_of_type(bitmap_fill, &mut activation.context) {
+        let args = bitmap_fill_args(activation, data)?;
+        begin_bitmap_fill(activation, this, &args)?;
+    } else if data.is_of_type(shader_fill, &mut activation.context) {
+        let shader = data.get_public_property("shader", activation)?;
+        let matrix = data.get_public_property("matrix", activation)?;
+        begin_shader_fill(activation, this, &[shader, matrix])?;
+    } else if data.is_of_type(end_fill_class, &mut activation.context) {
+        end_fill(activation, this, &[])?;
+    } else if data.is_of_type(path, &mut activation.context) {
+        let commands = data.get_public_property("commands", activation)?;
+        let path_data = data.get_public_property("data", activation)?;
+        let winding = data.get_public_property("winding", activation)?;
+        if !matches!(commands, Value::Null | Value::Undefined)
+            && !matches!(path_data, Value::Null | Value::Undefined)
+        {
+            draw_path(activation, this, &[commands, path_data, winding])?;
+        }
+    } else if data.is_of_type(triangle_path, &mut activation.context) {
+        let vertices = data.get_public_property("vertices", activation)?;
+        let indices = data.get_public_property("indices", activation)?;
+        let uvt_data = data.get_public_property("uvtData", activation)?;
+        let culling = data.get_public_property("culling", activation)?;
+        if !matches!(vertices, Value::Null | Value::Undefined) {
+            draw_triangles(activation, this, &[vertices, indices, uvt_data, culling])?;
+        }
+    }
+
+    Ok(())
+}
+
+/// The arguments of `beginGradientFill` and `lineGradientStyle` for a `GraphicsGradientFill`.
+fn gradient_fill_args<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    fill: Object<'gc>,
+) -> Result<[Value<'gc>; 8], Error<'gc>> {
+    Ok([
+        fill.get_public_property("type", activation)?,
+        fill.get_public_property("colors", activation)?,
+        fill.get_public_property("alphas", activation)?,
+        fill.get_public_property("ratios", activation)?,
+        fill.get_public_property("matrix", activation)?,
+        fill.get_public_property("spreadMethod", activation)?,
+        fill.get_public_property("interpolationMethod", activation)?,
+        fill.get_public_property("focalPointRatio", activation)?,
+    ])
+}
+
+/// The arguments of `beginBitmapFill` and `lineBitmapStyle` for a `GraphicsBitmapFill`.
+fn bitmap_fill_args<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    fill: Object<'gc>,
+) -> Result<[Value<'gc>; 4], Error<'gc>> {
+    Ok([
+        fill.get_public_property("bitmapData", activation)?,
+        fill.get_public_property("matrix", activation)?,
+        fill.get_public_property("repeat", activation)?,
+        fill.get_public_property("smooth", activation)?,
+    ])
+}
+
+fn vector_to_numbers<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    vector: Object<'gc>,
+) -> Result<Vec<f64>, Error<'gc>> {
+    let values: Vec<_> = vector
+        .as_vector_storage()
+        .map(|storage| storage.iter().collect())
+        .unwrap_or_default();
+    values
+        .into_iter()
+        .map(|value| value.coerce_to_number(activation))
+        .collect()
+}
+
+fn vector_to_ints<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    vector: Object<'gc>,
+) -> Result<Vec<i32>, Error<'gc>> {
+    let values: Vec<_> = vector
+        .as_vector_storage()
+        .map(|storage| storage.iter().collect())
+        .unwrap_or_default();
+    values
+        .into_iter()
+        .map(|value| value.coerce_to_i32(activation))
+        .collect()
+}
+
 /// Implements `Graphics.lineBitmapStyle`
 pub fn line_bitmap_style<'gc>(
     activation: &mut Activation<'_, 'gc>,
@@ -916,11 +1455,270 @@ pub fn line_bitmap_style<'gc>(
 /// Implements `Graphics.readGraphicsData`
 pub fn read_graphics_data<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Option<Object<'gc>>,
-    _args: &[Value<'gc>],
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_method!(activation, "flash.display.Graphics", "readGraphicsData");
+    let recurse = args.get_bool(0);
+    let mut graphics_data = Vec::new();
+    if let Some(this) = this.and_then(|t| t.as_display_object()) {
+        read_graphics_data_internal(activation, this, recurse, &mut graphics_data)?;
+    }
+
     let value_type = activation.avm2().classes().igraphicsdata;
-    let new_storage = VectorStorage::new(0, false, value_type, activation);
+    let new_storage = VectorStorage::from_values(graphics_data, false, value_type);
     Ok(VectorObject::from_vector(new_storage, activation)?.into())
 }
+
+fn read_graphics_data_internal<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    display_object: DisplayObject<'gc>,
+    recurse: bool,
+    graphics_data: &mut Vec<Value<'gc>>,
+) -> Result<(), Error<'gc>> {
+    // Work on a copy, so that the drawing isn't borrowed while we create objects.
+    let drawing = display_object
+        .as_drawing(activation.context.gc_context)
+        .map(|drawing| drawing.clone());
+
+    if let Some(drawing) = drawing {
+        for path in drawing.paths() {
+            match path {
+                DrawPath::Fill {
+                    style,
+                    commands,
+                    winding_rule,
+                } => {
+                    let fill = fill_style_to_object(activation, style)?;
+                    let Some(fill) = fill else { continue; };
+                    graphics_data.push(fill);
+                    graphics_data.push(path_to_object(activation, &commands, winding_rule)?);
+                    let end_fill = activation.avm2().classes().graphicsendfill;
+                    graphics_data.push(end_fill.construct(activation, &[])?.into());
+                }
+                DrawPath::Stroke {
+                    style, commands, ..
+                } => {
+                    let fill = fill_style_to_object(activation, style.fill_style())?;
+                    let Some(fill) = fill else { continue; };
+                    let scale_mode = match (style.allow_scale_x(), style.allow_scale_y()) {
+                        (true, true) => "normal",
+                        (true, false) => "horizontal",
+                        (false, true) => "vertical",
+                        (false, false) => "none",
+                    };
+                    let caps = match style.start_cap() {
+                        LineCapStyle::None => "none",
+                        LineCapStyle::Round => "round",
+                        LineCapStyle::Square => "square",
+                    };
+                    let (joints, miter_limit) = match style.join_style() {
+                        LineJoinStyle::Round => ("round", 3.0),
+                        LineJoinStyle::Bevel => ("bevel", 3.0),
+                        LineJoinStyle::Miter(limit) => ("miter", limit.to_f64()),
+                    };
+                    let args = [
+                        style.width().to_pixels().into(),
+                        style.is_pixel_hinted().into(),
+                        scale_mode.into(),
+                        caps.into(),
+                        joints.into(),
+                        miter_limit.into(),
+                        fill,
+                    ];
+                    let stroke = activation.avm2().classes().graphicsstroke;
+                    graphics_data.push(stroke.construct(activation, &args)?.into());
+                    graphics_data.push(path_to_object(activation, &commands, FillRule::EvenOdd)?);
+                    // A stroke without a thickness ends the line style.
+                    graphics_data.push(stroke.construct(activation, &[])?.into());
+                }
+                DrawPath::Triangles {
+                    style,
+                    vertices,
+                    indices,
+                    uvs,
+                } => {
+                    let fill = fill_style_to_object(activation, style)?;
+                    let Some(fill) = fill else { continue; };
+                    graphics_data.push(fill);
+                    graphics_data.push(triangles_to_object(
+                        activation,
+                        &vertices,
+                        &indices,
+                        uvs.as_deref(),
+                    )?);
+                    let end_fill = activation.avm2().classes().graphicsendfill;
+                    graphics_data.push(end_fill.construct(activation, &[])?.into());
+                }
+            }
+        }
+    }
+
+    if recurse {
+        if let Some(container) = display_object.as_container() {
+            for child in container.iter_render_list() {
+                read_graphics_data_internal(activation, child, recurse, graphics_data)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Creates the `IGraphicsFill` object for a fill style.
+///
+/// Returns `None` for bitmap fills, as we don't keep the `BitmapData` that they were created from.
+fn fill_style_to_object<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    style: &FillStyle,
+) -> Result<Option<Value<'gc>>, Error<'gc>> {
+    let (gradient_type, gradient, focal_point) = match style {
+        FillStyle::Color(color) => {
+            let args = [color.to_rgb().into(), (color.a as f64 / 255.0).into()];
+            let solid_fill = activation.avm2().classes().graphicssolidfill;
+            return Ok(Some(solid_fill.construct(activation, &args)?.into()));
+        }
+        FillStyle::LinearGradient(gradient) => ("linear", gradient, 0.0),
+        FillStyle::RadialGradient(gradient) => ("radial", gradient, 0.0),
+        FillStyle::FocalGradient {
+            gradient,
+            focal_point,
+        } => ("radial", gradient, focal_point.to_f64()),
+        FillStyle::Bitmap { .. } => {
+            avm2_stub_method!(
+                activation,
+                "flash.display.Graphics",
+                "readGraphicsData",
+                "with bitmap fills"
+            );
+            return Ok(None);
+        }
+    };
+
+    let colors: Vec<Value<'gc>> = gradient
+        .records
+        .iter()
+        .map(|record| record.color.to_rgb().into())
+        .collect();
+    let alphas: Vec<Value<'gc>> = gradient
+        .records
+        .iter()
+        .map(|record| (record.color.a as f64 / 255.0).into())
+        .collect();
+    let ratios: Vec<Value<'gc>> = gradient
+        .records
+        .iter()
+        .map(|record| record.ratio.into())
+        .collect();
+    let spread_method = match gradient.spread {
+        GradientSpread::Pad => "pad",
+        GradientSpread::Reflect => "reflect",
+        GradientSpread::Repeat => "repeat",
+    };
+    let interpolation_method = match gradient.interpolation {
+        GradientInterpolation::Rgb => "rgb",
+        GradientInterpolation::LinearRgb => "linearRGB",
+    };
+
+    let args = [
+        gradient_type.into(),
+        ArrayObject::from_storage(activation, ArrayStorage::from_args(&colors))?.into(),
+        ArrayObject::from_storage(activation, ArrayStorage::from_args(&alphas))?.into(),
+        ArrayObject::from_storage(activation, ArrayStorage::from_args(&ratios))?.into(),
+        matrix_to_object(gradient.matrix.into(), activation)?,
+        spread_method.into(),
+        interpolation_method.into(),
+        focal_point.into(),
+    ];
+    let gradient_fill = activation.avm2().classes().graphicsgradientfill;
+    Ok(Some(gradient_fill.construct(activation, &args)?.into()))
+}
+
+/// Creates the `GraphicsPath` object for a list of draw commands.
+fn path_to_object<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    commands: &[DrawCommand],
+    winding_rule: FillRule,
+) -> Result<Value<'gc>, Error<'gc>> {
+    let mut path_commands = Vec::with_capacity(commands.len());
+    let mut path_data = Vec::with_capacity(commands.len() * 2);
+    for command in commands {
+        match command {
+            DrawCommand::MoveTo(point) => {
+                // `GraphicsPathCommand.MOVE_TO`
+                path_commands.push(1.into());
+                path_data.extend([point.x.to_pixels().into(), point.y.to_pixels().into()]);
+            }
+            DrawCommand::LineTo(point) => {
+                // `GraphicsPathCommand.LINE_TO`
+                path_commands.push(2.into());
+                path_data.extend([point.x.to_pixels().into(), point.y.to_pixels().into()]);
+            }
+            DrawCommand::CurveTo { control, anchor } => {
+                // `GraphicsPathCommand.CURVE_TO`
+                path_commands.push(3.into());
+                path_data.extend([
+                    control.x.to_pixels().into(),
+                    control.y.to_pixels().into(),
+                    anchor.x.to_pixels().into(),
+                    anchor.y.to_pixels().into(),
+                ]);
+            }
+        }
+    }
+
+    let int_class = activation.avm2().classes().int;
+    let path_commands = VectorStorage::from_values(path_commands, false, int_class);
+    let number_class = activation.avm2().classes().number;
+    let path_data = VectorStorage::from_values(path_data, false, number_class);
+    let winding = match winding_rule {
+        FillRule::EvenOdd => "evenOdd",
+        FillRule::NonZero => "nonZero",
+    };
+
+    let args = [
+        VectorObject::from_vector(path_commands, activation)?.into(),
+        VectorObject::from_vector(path_data, activation)?.into(),
+        winding.into(),
+    ];
+    let graphics_path = activation.avm2().classes().graphicspath;
+    Ok(graphics_path.construct(activation, &args)?.into())
+}
+
+/// Creates the `GraphicsTrianglePath` object for a mesh of triangles.
+fn triangles_to_object<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    vertices: &[Point<Twips>],
+    indices: &[u32],
+    uvs: Option<&[[f32; 2]]>,
+) -> Result<Value<'gc>, Error<'gc>> {
+    let number_class = activation.avm2().classes().number;
+    let int_class = activation.avm2().classes().int;
+
+    let vertices: Vec<Value<'gc>> = vertices
+        .iter()
+        .flat_map(|vertex| [vertex.x.to_pixels().into(), vertex.y.to_pixels().into()])
+        .collect();
+    let vertices = VectorStorage::from_values(vertices, false, number_class);
+    let indices: Vec<Value<'gc>> = indices.iter().map(|&index| (index as i32).into()).collect();
+    let indices = VectorStorage::from_values(indices, false, int_class);
+    let uvt_data = match uvs {
+        Some(uvs) => {
+            let uvs: Vec<Value<'gc>> = uvs
+                .iter()
+                .flat_map(|uv| [f64::from(uv[0]).into(), f64::from(uv[1]).into()])
+                .collect();
+            let uvs = VectorStorage::from_values(uvs, false, number_class);
+            VectorObject::from_vector(uvs, activation)?.into()
+        }
+        None => Value::Null,
+    };
+
+    let args = [
+        VectorObject::from_vector(vertices, activation)?.into(),
+        VectorObject::from_vector(indices, activation)?.into(),
+        uvt_data,
+        "none".into(),
+    ];
+    let triangle_path = activation.avm2().classes().graphicstrianglepath;
+    Ok(triangle_path.construct(activation, &args)?.into())
+}
@@ -11,10 +11,9 @@ use crate::avm2::value::Value;
 use crate::avm2::ClassObject;
 use crate::avm2::Multiname;
 use crate::avm2::{Error, Object};
-use crate::avm2_stub_method;
 use crate::backend::navigator::{NavigationMethod, Request};
-use crate::display_object::LoaderDisplay;
 use crate::display_object::MovieClip;
+use crate::display_object::{DisplayObject, LoaderDisplay};
 use crate::loader::MovieLoaderVMData;
 use crate::tag_utils::SwfMovie;
 use std::sync::Arc;
@@ -236,12 +235,64 @@ pub fn unload<'gc>(
     this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    // TODO: Broadcast an "unload" event on the LoaderInfo and reset LoaderInfo properties
-    avm2_stub_method!(activation, "flash.display.Loader", "unload");
-    let _ = crate::avm2::globals::flash::display::display_object_container::remove_child_at(
-        activation,
-        this,
-        &[0.into()],
-    );
+    if let Some(this) = this {
+        unload_content(activation, this, false)?;
+    }
     Ok(Value::Undefined)
 }
+
+pub fn unload_and_stop<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    // The `gc` parameter only hints that a collection should follow, which we
+    // leave up to the garbage collector.
+    if let Some(this) = this {
+        unload_content(activation, this, true)?;
+    }
+    Ok(Value::Undefined)
+}
+
+pub fn close<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(this) = this {
+        let loader_info = content_loader_info(activation, this)?;
+        activation
+            .context
+            .load_manager
+            .cancel_avm2_movie_loads(loader_info.into());
+    }
+    Ok(Value::Undefined)
+}
+
+fn unload_content<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    stop: bool,
+) -> Result<(), Error<'gc>> {
+    let loader_info = content_loader_info(activation, this)?;
+    if let Some(DisplayObject::LoaderDisplay(loader)) = this.as_display_object() {
+        loader.unload(&mut activation.context, loader_info, stop);
+    }
+    Ok(())
+}
+
+fn content_loader_info<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+) -> Result<LoaderInfoObject<'gc>, Error<'gc>> {
+    this.get_property(
+        &Multiname::new(
+            activation.avm2().flash_display_internal,
+            "_contentLoaderInfo",
+        ),
+        activation,
+    )?
+    .as_object()
+    .and_then(|o| o.as_loader_info_object().copied())
+    .ok_or_else(|| "Loader has no contentLoaderInfo".into())
+}
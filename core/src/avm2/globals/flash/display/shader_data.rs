@@ -1,9 +1,15 @@
 use crate::{
     avm2::{
-        parameters::ParametersExt, string::AvmString, Activation, Error, Object, TObject, Value,
+        parameters::ParametersExt, string::AvmString, Activation, Error, Multiname, Object,
+        TObject, Value,
+    },
+    bitmap::operations,
+    pixel_bender::{
+        parse_shader, run_shader, PixelBenderImage, PixelBenderParam, PixelBenderParamQualifier,
+        PixelBenderShader, PixelBenderShaderArgument,
     },
-    pixel_bender::{PixelBenderParam, PixelBenderParamQualifier},
 };
+use ruffle_render::bitmap::PixelRegion;
 
 use super::shader_parameter::make_shader_parameter;
 
@@ -16,7 +22,7 @@ pub fn init<'gc>(
     let mut this = this.unwrap();
     let bytecode = args.get_object(activation, 0, "bytecode")?;
     let bytecode = bytecode.as_bytearray().unwrap();
-    let shader = crate::pixel_bender::parse_shader(bytecode.bytes());
+    let shader = parse_shader(bytecode.bytes());
 
     for meta in shader.metadata {
         let name = AvmString::new_utf8(activation.context.gc_context, &meta.key);
@@ -49,3 +55,161 @@ pub fn init<'gc>(
     }
     Ok(Value::Undefined)
 }
+
+/// Runs the shader of a `ShaderData` over an image of the given size, with the
+/// inputs and parameter values that were set on it.
+///
+/// `source` replaces the first image input, which is how a `ShaderFilter`
+/// provides the image that it is applied to.
+pub fn run_shader_data<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    shader_data: Object<'gc>,
+    width: u32,
+    height: u32,
+    source: Option<PixelBenderImage>,
+) -> Result<PixelBenderImage, Error<'gc>> {
+    let (shader, arguments) = shader_data_arguments(activation, shader_data, source)?;
+    Ok(run_shader(&shader, &arguments, width, height).map_err(Error::RustError)?)
+}
+
+/// Parses the shader of a `ShaderData`, and reads the arguments to run it with
+/// from the inputs and parameter values that were set on it.
+///
+/// `source` replaces the first image input, as in `run_shader_data`.
+pub fn shader_data_arguments<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    shader_data: Object<'gc>,
+    mut source: Option<PixelBenderImage>,
+) -> Result<(PixelBenderShader, Vec<PixelBenderShaderArgument>), Error<'gc>> {
+    let ns = activation.avm2().flash_display_internal;
+    let bytecode = shader_data
+        .get_property(&Multiname::new(ns, "_byteCode"), activation)?
+        .as_object()
+        .ok_or("ShaderData has no bytecode")?;
+    let shader = parse_shader(
+        bytecode
+            .as_bytearray()
+            .ok_or("ShaderData has no bytecode")?
+            .bytes(),
+    );
+
+    let mut arguments = vec![];
+    for (index, param) in shader.params.iter().enumerate() {
+        match param {
+            PixelBenderParam::Normal {
+                qualifier: PixelBenderParamQualifier::Input,
+                name,
+                ..
+            } if name != "_OutCoord" => {
+                let Some(param_obj) = shader_data_property(activation, shader_data, name)? else {
+                    continue;
+                };
+
+                // Parameters without a value use their default value.
+                let value = param_obj.get_property(&Multiname::new(ns, "_value"), activation)?;
+                let Some(value) = value.as_object() else {
+                    continue;
+                };
+                let values: Vec<_> = value
+                    .as_array_storage()
+                    .map(|storage| storage.iter().collect())
+                    .unwrap_or_default();
+                let value = values
+                    .into_iter()
+                    .map(|value| {
+                        Ok(value
+                            .unwrap_or(Value::Undefined)
+                            .coerce_to_number(activation)? as f32)
+                    })
+                    .collect::<Result<_, Error<'gc>>>()?;
+
+                arguments.push(PixelBenderShaderArgument::ValueInput { index, value });
+            }
+            PixelBenderParam::Texture {
+                index,
+                channels,
+                name,
+            } => {
+                let image = match source.take() {
+                    Some(image) => Some(image),
+                    None => match shader_data_property(activation, shader_data, name)? {
+                        Some(input_obj) => shader_input_image(activation, input_obj, *channels)?,
+                        None => None,
+                    },
+                };
+
+                if let Some(image) = image {
+                    arguments.push(PixelBenderShaderArgument::ImageInput {
+                        index: *index,
+                        image,
+                    });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok((shader, arguments))
+}
+
+/// Looks up the `ShaderParameter` or `ShaderInput` of a parameter.
+fn shader_data_property<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    shader_data: Object<'gc>,
+    name: &str,
+) -> Result<Option<Object<'gc>>, Error<'gc>> {
+    let name = AvmString::new_utf8(activation.context.gc_context, name);
+    Ok(shader_data
+        .get_public_property(name, activation)?
+        .as_object())
+}
+
+/// Reads the `input` of a `ShaderInput`, which is either a `BitmapData`, or a
+/// `ByteArray` or `Vector.<Number>` with the size set on the `ShaderInput`.
+fn shader_input_image<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    shader_input: Object<'gc>,
+    channels: u8,
+) -> Result<Option<PixelBenderImage>, Error<'gc>> {
+    let Some(input) = shader_input
+        .get_public_property("input", activation)?
+        .as_object()
+    else {
+        return Ok(None);
+    };
+
+    if let Some(bitmap) = input.as_bitmap_data() {
+        let area = PixelRegion::for_whole_size(bitmap.width(), bitmap.height());
+        return Ok(Some(operations::to_shader_image(bitmap, area)));
+    }
+
+    let width = shader_input
+        .get_public_property("width", activation)?
+        .coerce_to_u32(activation)?;
+    let height = shader_input
+        .get_public_property("height", activation)?
+        .coerce_to_u32(activation)?;
+
+    let mut data = if let Some(bytearray) = input.as_bytearray() {
+        (0..bytearray.len() / 4)
+            .filter_map(|i| bytearray.read_float_at(i * 4).ok())
+            .collect()
+    } else if let Some(vector) = input.as_vector_storage() {
+        let values: Vec<_> = vector.iter().collect();
+        drop(vector);
+        values
+            .into_iter()
+            .map(|value| Ok(value.coerce_to_number(activation)? as f32))
+            .collect::<Result<Vec<_>, Error<'gc>>>()?
+    } else {
+        return Ok(None);
+    };
+    data.resize(width as usize * height as usize * channels as usize, 0.0);
+
+    Ok(Some(PixelBenderImage {
+        width,
+        height,
+        channels,
+        data,
+    }))
+}
@@ -0,0 +1,60 @@
+//! `flash.display.ShaderJob` native function definitions
+
+use crate::avm2::bytearray::Endian;
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::{Activation, Error, Object, TObject, Value};
+use crate::bitmap::operations;
+
+use super::shader_data::run_shader_data;
+
+/// Implements `ShaderJob.runShader`, which writes the output of a shader to a
+/// `BitmapData`, `ByteArray` or `Vector.<Number>`.
+pub fn run_shader<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let shader = args.get_object(activation, 0, "shader")?;
+    let target = args.get_object(activation, 1, "target")?;
+    let mut width = args.get_i32(activation, 2)?.max(0) as u32;
+    let mut height = args.get_i32(activation, 3)?.max(0) as u32;
+
+    let Some(shader_data) = shader.get_public_property("data", activation)?.as_object() else {
+        return Ok(Value::Undefined);
+    };
+
+    // A `BitmapData` is filled completely, unless a smaller size is given.
+    let bitmap = target.as_bitmap_data();
+    if let Some(bitmap) = bitmap {
+        if width == 0 || height == 0 {
+            width = bitmap.width();
+            height = bitmap.height();
+        }
+    }
+
+    let image = run_shader_data(activation, shader_data, width, height, None)?;
+
+    if let Some(bitmap) = bitmap {
+        operations::draw_shader_image(activation.context.gc_context, bitmap, &image, (0, 0));
+    } else if let Some(mut bytearray) = target.as_bytearray_mut(activation.context.gc_context) {
+        let endian = bytearray.endian();
+        let bytes: Vec<u8> = image
+            .data
+            .iter()
+            .flat_map(|component| match endian {
+                Endian::Big => component.to_be_bytes(),
+                Endian::Little => component.to_le_bytes(),
+            })
+            .collect();
+        bytearray.write_at(&bytes, 0)?;
+    } else if let Some(mut vector) = target.as_vector_storage_mut(activation.context.gc_context) {
+        let values = image
+            .data
+            .iter()
+            .map(|component| Value::Number(*component as f64))
+            .collect();
+        vector.replace_storage(values);
+    }
+
+    Ok(Value::Undefined)
+}
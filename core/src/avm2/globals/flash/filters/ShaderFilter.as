@@ -0,0 +1,33 @@
+﻿package flash.filters {
+	import flash.display.Shader;
+
+	public class ShaderFilter extends BitmapFilter {
+		public var bottomExtension: int = 0;
+		public var leftExtension: int = 0;
+		public var rightExtension: int = 0;
+		public var topExtension: int = 0;
+
+		private var _shader: Shader;
+
+		public function ShaderFilter(shader: Shader = null) {
+			this._shader = shader;
+		}
+
+		public function get shader(): Shader {
+			return this._shader;
+		}
+
+		public function set shader(value: Shader): void {
+			this._shader = value;
+		}
+
+		override public function clone(): BitmapFilter {
+			var filter = new ShaderFilter(this.shader);
+			filter.bottomExtension = this.bottomExtension;
+			filter.leftExtension = this.leftExtension;
+			filter.rightExtension = this.rightExtension;
+			filter.topExtension = this.topExtension;
+			return filter;
+		}
+	}
+}
@@ -389,7 +389,7 @@ package flash.geom {
 				_rawData[10] = 1e-15;
 			}
 
-			return !(components[2].x == 0 || components[2].y == 0 || components[2].y == 0);
+			return !(components[2].x == 0 || components[2].y == 0 || components[2].z == 0);
 		}
 		public function copyColumnTo(column:uint, vector3D:Vector3D):void {
 			if (column > 3) {
@@ -2,9 +2,6 @@ package flash.geom {
 	import flash.display.DisplayObject;
 	import flash.geom.Matrix3D;
 	import flash.geom.PerspectiveProjection;
-	import __ruffle__.stub_getter;
-	import __ruffle__.stub_method;
-	import __ruffle__.stub_setter;
 
 	public class Transform {
 		internal var _displayObject:DisplayObject;
@@ -24,26 +21,54 @@ package flash.geom {
 		public native function get pixelBounds():Rectangle;
 
 		public function get matrix3D():Matrix3D {
-			stub_getter("flash.geom.Transform", "matrix3D");
-			return new Matrix3D();
+			var rawData:Array = this.getMatrixRawData();
+			return rawData ? new Matrix3D(Vector.<Number>(rawData)) : null;
 		}
 
 		public function set matrix3D(m:Matrix3D):void {
-			stub_setter("flash.geom.Transform", "matrix3D");
+			this.setMatrixRawData(m ? toArray(m.rawData) : null);
 		}
 
 		public function get perspectiveProjection():PerspectiveProjection {
-			stub_getter("flash.geom.Transform", "perspectiveProjection");
-			return new PerspectiveProjection();
+			var values:Array = this.getPerspectiveProjection();
+			if (!values) {
+				return null;
+			}
+			var projection:PerspectiveProjection = new PerspectiveProjection();
+			projection._fieldOfView = values[0];
+			projection._projectionCenter = new Point(values[1], values[2]);
+			projection._width = values[3];
+			projection._displayObject = this._displayObject;
+			return projection;
 		}
 
 		public function set perspectiveProjection(val: PerspectiveProjection):void {
-			stub_setter("flash.geom.Transform", "perspectiveProjection");
+			if (val) {
+				this.setPerspectiveProjection([val.fieldOfView, val.projectionCenter.x, val.projectionCenter.y]);
+			} else {
+				this.setPerspectiveProjection(null);
+			}
 		}
 
 		public function getRelativeMatrix3D(relativeTo:DisplayObject):Matrix3D {
-			stub_method("flash.geom.Transform", "getRelativeMatrix3D");
-			return new Matrix3D();
+			var rawData:Array = this.getRelativeRawData(relativeTo);
+			return rawData ? new Matrix3D(Vector.<Number>(rawData)) : null;
+		}
+
+		// The 3D natives exchange plain arrays of numbers, so that `Matrix3D` and
+		// `PerspectiveProjection` can stay pure ActionScript classes.
+		private native function getMatrixRawData():Array;
+		private native function setMatrixRawData(rawData:Array):void;
+		private native function getPerspectiveProjection():Array;
+		private native function setPerspectiveProjection(values:Array):void;
+		private native function getRelativeRawData(relativeTo:DisplayObject):Array;
+
+		private static function toArray(vector:Vector.<Number>):Array {
+			var array:Array = [];
+			for each (var value:Number in vector) {
+				array.push(value);
+			}
+			return array;
 		}
 	}
 }
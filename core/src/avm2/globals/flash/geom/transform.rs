@@ -1,9 +1,12 @@
+use crate::avm2::array::ArrayStorage;
+use crate::avm2::object::ArrayObject;
 use crate::avm2::parameters::ParametersExt;
 use crate::avm2::Multiname;
 use crate::avm2::{Activation, Error, Object, TObject, Value};
 use crate::avm2_stub_getter;
-use crate::display_object::TDisplayObject;
+use crate::display_object::{PerspectiveProjection, TDisplayObject};
 use crate::prelude::{DisplayObject, Matrix, Twips};
+use ruffle_render::matrix3d::Matrix3D;
 use ruffle_render::quality::StageQuality;
 use swf::{ColorTransform, Fixed8, Rectangle};
 
@@ -65,7 +68,12 @@ pub fn get_matrix<'gc>(
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
     let this = this.unwrap();
-    let matrix = *get_display_object(this, activation)?.base().matrix();
+    let display_object = get_display_object(this, activation)?;
+    // Objects with a 3D transform only have a `matrix3D`.
+    if display_object.base().transform_3d().is_some() {
+        return Ok(Value::Null);
+    }
+    let matrix = *display_object.base().matrix();
     matrix_to_object(matrix, activation)
 }
 
@@ -76,12 +84,130 @@ pub fn set_matrix<'gc>(
 ) -> Result<Value<'gc>, Error<'gc>> {
     let this = this.unwrap();
     let matrix = object_to_matrix(args.get_object(activation, 0, "value")?, activation)?;
+    let display_object = get_display_object(this, activation)?;
+    let mut base = display_object.base_mut(activation.context.gc_context);
+    // Setting a 2D matrix drops any 3D transform.
+    base.set_matrix3d(None);
+    base.set_matrix(matrix);
+    Ok(Value::Undefined)
+}
+
+/// Implements the `matrix3D` getter, returning the raw data of the matrix.
+pub fn get_matrix_raw_data<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.unwrap();
+    let matrix3d = get_display_object(this, activation)?.base().matrix3d();
+    match matrix3d {
+        Some(matrix3d) => matrix3d_to_array(matrix3d, activation),
+        None => Ok(Value::Null),
+    }
+}
+
+/// Implements the `matrix3D` setter, taking the raw data of the matrix.
+pub fn set_matrix_raw_data<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.unwrap();
+    let matrix3d = match args.try_get_object(activation, 0) {
+        Some(raw_data) => Some(array_to_matrix3d(raw_data, activation)?),
+        None => None,
+    };
+    get_display_object(this, activation)?
+        .base_mut(activation.context.gc_context)
+        .set_matrix3d(matrix3d);
+    Ok(Value::Undefined)
+}
+
+/// Implements the `perspectiveProjection` getter.
+///
+/// Returns `[fieldOfView, projectionCenter.x, projectionCenter.y, movieWidth]`,
+/// or `null` if the object has no projection of its own.
+pub fn get_perspective_projection<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.unwrap();
+    let display_object = get_display_object(this, activation)?;
+    let movie = display_object.movie();
+    let projection = display_object
+        .base()
+        .perspective_projection()
+        .cloned()
+        .or_else(|| {
+            // The root of a movie always has a projection.
+            let is_root = display_object
+                .parent()
+                .map_or(false, |parent| parent.as_stage().is_some());
+            is_root.then(|| PerspectiveProjection::for_movie(&movie))
+        });
+    let Some(projection) = projection else { return Ok(Value::Null); };
+
+    let values = [
+        projection.field_of_view.into(),
+        projection.center.0.into(),
+        projection.center.1.into(),
+        movie.width().to_pixels().into(),
+    ];
+    Ok(ArrayObject::from_storage(activation, ArrayStorage::from_args(&values))?.into())
+}
+
+/// Implements the `perspectiveProjection` setter.
+///
+/// Takes `[fieldOfView, projectionCenter.x, projectionCenter.y]`, or `null` to remove the projection.
+pub fn set_perspective_projection<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.unwrap();
+    let projection = match args.try_get_object(activation, 0) {
+        Some(values) => {
+            let values = array_to_numbers(values, activation)?;
+            Some(PerspectiveProjection {
+                field_of_view: values.first().copied().unwrap_or_default(),
+                center: (
+                    values.get(1).copied().unwrap_or_default(),
+                    values.get(2).copied().unwrap_or_default(),
+                ),
+            })
+        }
+        None => None,
+    };
     get_display_object(this, activation)?
         .base_mut(activation.context.gc_context)
-        .set_matrix(matrix);
+        .set_perspective_projection(projection);
     Ok(Value::Undefined)
 }
 
+/// Implements `getRelativeMatrix3D`, returning the raw data of the matrix.
+pub fn get_relative_raw_data<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let this = this.unwrap();
+    let display_object = get_display_object(this, activation)?;
+    let relative_to = args
+        .get_object(activation, 0, "relativeTo")?
+        .as_display_object()
+        .unwrap_or(display_object);
+
+    let to_global = |object: DisplayObject<'gc>| {
+        object
+            .local_to_global_matrix3d()
+            .unwrap_or_else(|| object.local_to_global_matrix().into())
+    };
+    let relative_to_global = to_global(relative_to);
+    let Some(global_to_relative) = relative_to_global.inverse() else { return Ok(Value::Null); };
+    matrix3d_to_array(global_to_relative * to_global(display_object), activation)
+}
+
 pub fn get_concatenated_matrix<'gc>(
     activation: &mut Activation<'_, 'gc>,
     this: Option<Object<'gc>>,
@@ -247,6 +373,44 @@ pub fn object_to_matrix<'gc>(
     Ok(Matrix { a, b, c, d, tx, ty })
 }
 
+fn matrix3d_to_array<'gc>(
+    matrix3d: Matrix3D,
+    activation: &mut Activation<'_, 'gc>,
+) -> Result<Value<'gc>, Error<'gc>> {
+    let values: Vec<Value<'gc>> = matrix3d.raw_data.iter().map(|&n| n.into()).collect();
+    Ok(ArrayObject::from_storage(activation, ArrayStorage::from_args(&values))?.into())
+}
+
+fn array_to_numbers<'gc>(
+    array: Object<'gc>,
+    activation: &mut Activation<'_, 'gc>,
+) -> Result<Vec<f64>, Error<'gc>> {
+    let values: Vec<_> = array
+        .as_array_storage()
+        .map(|storage| storage.iter().collect())
+        .unwrap_or_default();
+    values
+        .into_iter()
+        .map(|value| {
+            value
+                .unwrap_or(Value::Undefined)
+                .coerce_to_number(activation)
+        })
+        .collect()
+}
+
+fn array_to_matrix3d<'gc>(
+    array: Object<'gc>,
+    activation: &mut Activation<'_, 'gc>,
+) -> Result<Matrix3D, Error<'gc>> {
+    let values = array_to_numbers(array, activation)?;
+    let mut matrix3d = Matrix3D::IDENTITY;
+    for (raw, value) in matrix3d.raw_data.iter_mut().zip(values) {
+        *raw = value;
+    }
+    Ok(matrix3d)
+}
+
 pub fn get_pixel_bounds<'gc>(
     activation: &mut Activation<'_, 'gc>,
     this: Option<Object<'gc>>,
@@ -3,6 +3,7 @@ package flash.media
     import flash.display.DisplayObject
     import flash.net.NetStream
 
+    [Ruffle(InstanceAllocator)]
     public class Video extends DisplayObject
     {
         private var _deblocking: int;
@@ -13,8 +14,11 @@ package flash.media
         public function Video(width: int = 320, height: int = 240) {
             this._videoWidth = width;
             this._videoHeight = height;
+            this.init(width, height);
         }
 
+        private native function init(width: int, height: int):void;
+
         public function get deblocking():int {
             return this._deblocking;
         }
@@ -1,14 +1,16 @@
 //! `flash.media.Sound` builtin/prototype
 
 use crate::avm2::activation::Activation;
+use crate::avm2::error::make_error_2008;
 use crate::avm2::object::{Object, QueuedPlay, SoundChannelObject, TObject};
+use crate::avm2::parameters::ParametersExt;
 use crate::avm2::value::Value;
 use crate::avm2::Error;
 use crate::backend::navigator::Request;
 use crate::character::Character;
 use crate::display_object::SoundTransform;
 use crate::{avm2_stub_constructor, avm2_stub_getter, avm2_stub_method};
-use swf::{SoundEvent, SoundInfo};
+use swf::{AudioCompression, SoundEvent, SoundFormat, SoundInfo};
 
 pub use crate::avm2::object::sound_allocator;
 
@@ -175,6 +177,28 @@ pub fn play<'gc>(
 
         let sound_channel = SoundChannelObject::empty(activation)?;
 
+        // A sound without any audio data of its own is a dynamic sound, which
+        // requests its audio from `sampleData` event handlers while it plays.
+        if sound_object.sound_handle().is_none()
+            && Object::from(sound_object)
+                .call_public_property("hasEventListener", &["sampleData".into()], activation)?
+                .coerce_to_boolean()
+        {
+            if let Some(instance) = activation.context.start_dynamic_sound(sound_object.into()) {
+                if let Some(sound_transform) = sound_transform {
+                    activation
+                        .context
+                        .set_local_sound_transform(instance, sound_transform);
+                }
+
+                sound_channel.set_sound_instance(activation, instance);
+                activation
+                    .context
+                    .attach_avm2_sound_channel(instance, sound_channel);
+            }
+            return Ok(sound_channel.into());
+        }
+
         let queued_play = QueuedPlay {
             position,
             sound_info,
@@ -195,25 +219,47 @@ pub fn play<'gc>(
 /// `Sound.extract`
 pub fn extract<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Option<Object<'gc>>,
+    this: Option<Object<'gc>>,
     args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_method!(activation, "flash.media.Sound", "extract");
-
-    let bytearray = args
-        .get(0)
-        .unwrap_or(&Value::Undefined)
-        .coerce_to_object(activation)?;
-    let length = args
-        .get(1)
-        .unwrap_or(&Value::Number(0.0))
-        .coerce_to_number(activation)?;
+    let Some(sound_object) = this.and_then(|this| this.as_sound_object()) else {
+        return Ok(0.into());
+    };
+
+    let bytearray = args.get_object(activation, 0, "target")?;
+    let length = args.get_f64(activation, 1)?;
+    let start_position = args.get_f64(activation, 2)?;
+
+    // Without a start position, extraction continues where the last call left off.
+    let start_position = if start_position < 0.0 {
+        sound_object.extract_position()
+    } else {
+        start_position as u32
+    };
+
+    let samples = sound_object
+        .sound_handle()
+        .and_then(|sound| {
+            activation
+                .context
+                .audio
+                .extract_sound(sound, start_position, length as usize)
+        })
+        .unwrap_or_default();
 
     if let Some(mut bytearray) = bytearray.as_bytearray_mut(activation.context.gc_context) {
-        bytearray.write_bytes(vec![0u8; length.ceil() as usize].as_slice())?;
+        for [left, right] in &samples {
+            bytearray.write_float(*left)?;
+            bytearray.write_float(*right)?;
+        }
     }
 
-    Ok(Value::Undefined)
+    sound_object.set_extract_position(
+        activation.context.gc_context,
+        start_position.saturating_add(samples.len() as u32),
+    );
+
+    Ok(samples.len().into())
 }
 
 /// `Sound.close`
@@ -264,23 +310,107 @@ pub fn load<'gc>(
 /// `Sound.loadCompressedDataFromByteArray`
 pub fn load_compressed_data_from_byte_array<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Option<Object<'gc>>,
-    _args: &[Value<'gc>],
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_method!(
-        activation,
-        "flash.media.Sound",
-        "loadCompressedDataFromByteArray"
-    );
+    let Some(sound_object) = this.and_then(|this| this.as_sound_object()) else {
+        return Ok(Value::Undefined);
+    };
+    if sound_object.sound_handle().is_some() {
+        tracing::warn!("Sound.loadCompressedDataFromByteArray: Sound already has data");
+        return Ok(Value::Undefined);
+    }
+
+    let bytes = args.get_object(activation, 0, "bytes")?;
+    let bytes_length = args.get_u32(activation, 1)?;
+    let data = {
+        let bytearray = bytes
+            .as_bytearray()
+            .ok_or("ArgumentError: Parameter must be a bytearray")?;
+        bytearray
+            .read_bytes(bytes_length as usize)
+            .map_err(|e| e.to_avm(activation))?
+            .to_vec()
+    };
+
+    match activation.context.audio.register_external_sound(&data) {
+        Ok(sound) => sound_object.set_sound(&mut activation.context, sound)?,
+        Err(e) => tracing::error!("Sound.loadCompressedDataFromByteArray: {}", e),
+    }
+
     Ok(Value::Undefined)
 }
 
 /// `Sound.loadPCMFromByteArray`
 pub fn load_pcm_from_byte_array<'gc>(
     activation: &mut Activation<'_, 'gc>,
-    _this: Option<Object<'gc>>,
-    _args: &[Value<'gc>],
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    avm2_stub_method!(activation, "flash.media.Sound", "loadPCMFromByteArray");
+    let Some(sound_object) = this.and_then(|this| this.as_sound_object()) else {
+        return Ok(Value::Undefined);
+    };
+    if sound_object.sound_handle().is_some() {
+        tracing::warn!("Sound.loadPCMFromByteArray: Sound already has data");
+        return Ok(Value::Undefined);
+    }
+
+    let bytes = args.get_object(activation, 0, "bytes")?;
+    let samples = args.get_u32(activation, 1)?;
+    let format = args.get_string(activation, 2)?;
+    let is_stereo = args.get_bool(3);
+    let sample_rate = args.get_f64(activation, 4)?;
+
+    let is_float = if &format == b"float" {
+        true
+    } else if &format == b"short" {
+        false
+    } else {
+        return Err(make_error_2008(activation, "format"));
+    };
+    if !(1.0..=f64::from(u16::MAX)).contains(&sample_rate) {
+        return Err(make_error_2008(activation, "sampleRate"));
+    }
+
+    // Convert the samples to 16-bit little-endian PCM, stopping early if the
+    // `ByteArray` runs out of data.
+    let num_channels = if is_stereo { 2 } else { 1 };
+    let mut data = Vec::new();
+    {
+        let bytearray = bytes
+            .as_bytearray()
+            .ok_or("ArgumentError: Parameter must be a bytearray")?;
+        for _ in 0..samples as usize * num_channels {
+            let sample = if is_float {
+                let Ok(sample) = bytearray.read_float() else {
+                    break;
+                };
+                (sample.clamp(-1.0, 1.0) * f32::from(i16::MAX)) as i16
+            } else {
+                let Ok(sample) = bytearray.read_short() else {
+                    break;
+                };
+                sample
+            };
+            data.extend_from_slice(&sample.to_le_bytes());
+        }
+    }
+
+    let sound = swf::Sound {
+        id: 0,
+        format: SoundFormat {
+            compression: AudioCompression::Uncompressed,
+            sample_rate: sample_rate as u16,
+            is_stereo,
+            is_16_bit: true,
+        },
+        num_samples: (data.len() / (2 * num_channels)) as u32,
+        data: &data,
+    };
+    match activation.context.audio.register_sound(&sound) {
+        Ok(sound) => sound_object.set_sound(&mut activation.context, sound)?,
+        Err(e) => tracing::error!("Sound.loadPCMFromByteArray: {}", e),
+    }
+
     Ok(Value::Undefined)
 }
@@ -1,5 +1,69 @@
+//! `flash.media.Video` builtin/prototype
+
+use crate::avm2::globals::flash::display::display_object::initialize_for_allocator;
+use crate::avm2::object::ClassObject;
 use crate::avm2::{Activation, Error, Object, TObject, Value};
-use crate::display_object::TDisplayObject;
+use crate::display_object::{TDisplayObject, Video};
+
+pub fn video_allocator<'gc>(
+    class: ClassObject<'gc>,
+    activation: &mut Activation<'_, 'gc>,
+) -> Result<Object<'gc>, Error<'gc>> {
+    let video_cls = activation.avm2().classes().video;
+
+    let mut class_object = Some(class);
+    let orig_class = class;
+    while let Some(class) = class_object {
+        if class == video_cls {
+            let display_object = Video::new(&mut activation.context, 320, 240).into();
+            return initialize_for_allocator(activation, display_object, orig_class);
+        }
+
+        if let Some((movie, symbol)) = activation
+            .context
+            .library
+            .avm2_class_registry()
+            .class_symbol(class)
+        {
+            let child = activation
+                .context
+                .library
+                .library_for_movie_mut(movie)
+                .instantiate_by_id(symbol, activation.context.gc_context)?;
+
+            return initialize_for_allocator(activation, child, orig_class);
+        }
+        class_object = class.superclass_object();
+    }
+    unreachable!("A Video subclass should have Video in superclass chain");
+}
+
+/// Implements `Video`'s `init` method, which is called from the constructor.
+pub fn init<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(video) = this
+        .and_then(|o| o.as_display_object())
+        .and_then(|dobj| dobj.as_video())
+    {
+        let width = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_i32(activation)?;
+        let height = args
+            .get(1)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_i32(activation)?;
+
+        video.set_size(&mut activation.context, width, height);
+    }
+
+    Ok(Value::Undefined)
+}
 
 pub fn attach_net_stream<'gc>(
     activation: &mut Activation<'_, 'gc>,
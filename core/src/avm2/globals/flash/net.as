@@ -2,7 +2,11 @@ package flash.net {
 
     import flash.net.URLRequest;
     import __ruffle__.stub_method;
-    
+
+    // Used by the classes in this package for properties that Ruffle's
+    // native code accesses, but that should stay hidden from movies.
+    namespace ruffle = "__ruffle__";
+
     internal var _classLookups:Object = {};
 
     public native function navigateToURL(request:URLRequest, window:String = null):void;
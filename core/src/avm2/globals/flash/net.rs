@@ -3,10 +3,14 @@
 use crate::avm2::object::TObject;
 use crate::avm2::{Activation, Error, Object, Value};
 
+pub mod file_reference;
+pub mod file_reference_list;
 pub mod local_connection;
+pub mod net_connection;
 pub mod net_stream;
 pub mod object_encoding;
 pub mod shared_object;
+pub mod socket;
 pub mod url_loader;
 
 /// Implements `flash.net.navigateToURL`
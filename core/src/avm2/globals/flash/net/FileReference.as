@@ -1,91 +1,128 @@
 package flash.net
 {
+    import flash.errors.IllegalOperationError;
     import flash.events.EventDispatcher;
     import flash.utils.ByteArray;
     import __ruffle__.stub_method;
 
     public class FileReference extends EventDispatcher
     {
-        private var _creationDate: Date;
-        private var _creator: String;
-        private var _data: ByteArray;
+        // The file picked by the user, set by the player.
+        ruffle var _creationDate: Date;
+        ruffle var _creator: String;
+        ruffle var _modificationDate: Date;
+        ruffle var _name: String;
+        ruffle var _size: Number;
+        ruffle var _type: String;
+        ruffle var _fileData: ByteArray;
+
+        // The contents of the file, once they have been loaded by `load`.
+        ruffle var _data: ByteArray;
+
         private var _extension: String;
-        private var _modificationDate: Date;
-        private var _name: String;
         private static var _permissionStatus: String;
-        private var _size: Number;
-        private var _type: String;
 
         public function FileReference() {
-            
+
         }
 
         public function get creationDate(): Date {
-            return this._creationDate;
-        }   
+            this.checkSelected();
+            return this.ruffle::_creationDate;
+        }
 
         public function get creator(): String {
-            retunr this._creator;
-        }   
+            this.checkSelected();
+            return this.ruffle::_creator;
+        }
 
         public function get data(): ByteArray {
-            return this._data;
-        }   
+            return this.ruffle::_data;
+        }
 
         public function get extension(): String {
             return this._extension;
-        }   
+        }
 
         public function get modificationDate(): Date {
-            return this._modificationDate;
-        }   
+            this.checkSelected();
+            return this.ruffle::_modificationDate;
+        }
 
         public function get name(): String {
-            retunr this._name;
-        }   
+            this.checkSelected();
+            return this.ruffle::_name;
+        }
 
         public static function get permissionStatus(): String {
             return FileReference._permissionStatus;
-        }   
+        }
 
         public function get size(): Number {
-            return this._size;
-        }   
+            this.checkSelected();
+            return this.ruffle::_size;
+        }
 
         public function get type(): String {
-            return this._type;
-        }   
+            this.checkSelected();
+            return this.ruffle::_type;
+        }
 
-        public function browse(typeFilter:Array = null):Boolean {
-            return false;
-        }   
+        public native function browse(typeFilter:Array = null):Boolean;
 
         public function cancel():void {
             stub_method("flash.net.FileReference", "cancel");
-        }   
+        }
 
-        public function download(request:URLRequest, defaultFileName:String = null):void {
-            stub_method("flash.net.FileReference", "download");
-        }   
+        public native function download(request:URLRequest, defaultFileName:String = null):void;
 
         public function load():void {
-            stub_method("flash.net.FileReference", "load");
-        }   
+            this.checkSelected();
+            this.beginLoad();
+        }
 
         public function requestPermission():void {
             stub_method("flash.net.FileReference", "requestPermission");
-        }   
+        }
 
         public function save(data:*, defaultFileName:String = null):void {
-            stub_method("flash.net.FileReference", "save");
-        }   
+            if (data == null) {
+                throw new ArgumentError("Error #2007: Parameter data must be non-null.", 2007);
+            }
+
+            var bytes:ByteArray;
+            if (data is ByteArray) {
+                bytes = data;
+            } else {
+                bytes = new ByteArray();
+                if (data is XML || data is XMLList) {
+                    bytes.writeUTFBytes(data.toXMLString());
+                } else {
+                    bytes.writeUTFBytes(String(data));
+                }
+            }
+            this.saveBytes(bytes, defaultFileName);
+        }
 
         public function upload(request:URLRequest, uploadDataFieldName:String = "Filedata", testUpload:Boolean = false):void {
-            stub_method("flash.net.FileReference", "upload");
-        }   
+            this.checkSelected();
+            this.beginUpload(request, uploadDataFieldName);
+        }
 
         public function uploadUnencoded(request:URLRequest):void {
             stub_method("flash.net.FileReference", "uploadUnencoded");
         }
+
+        private function checkSelected():void {
+            if (this.ruffle::_name == null) {
+                throw new IllegalOperationError("Error #2037: Functions called in incorrect sequence, or earlier call was unsuccessful.", 2037);
+            }
+        }
+
+        private native function beginLoad():void;
+
+        private native function beginUpload(request:URLRequest, uploadDataFieldName:String):void;
+
+        private native function saveBytes(data:ByteArray, defaultFileName:String):void;
     }
-}
\ No newline at end of file
+}
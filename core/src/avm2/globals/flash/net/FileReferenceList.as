@@ -1,25 +1,22 @@
 package flash.net
 {
     import flash.events.EventDispatcher;
-    import __ruffle__.stub_method;
 
     public class FileReferenceList extends EventDispatcher
     {
-        private var _fileList:Array;
+        // The files picked by the user, set by the player.
+        ruffle var _fileList:Array;
+
         public function FileReferenceList()
         {
-            _fileList = new Array();
+            this.ruffle::_fileList = new Array();
         }
 
         public function get fileList():Array
         {
-            return this._fileList;
+            return this.ruffle::_fileList;
         }
 
-        public function browse(typeFilter:Array = null):Boolean
-        {
-            stub_method("flash.net.FileReferenceList", "browse");
-            return false;
-        }
+        public native function browse(typeFilter:Array = null):Boolean;
     }
-}
\ No newline at end of file
+}
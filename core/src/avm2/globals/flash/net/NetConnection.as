@@ -1,5 +1,6 @@
 package flash.net {
     import flash.events.EventDispatcher;
+    import flash.events.NetStatusEvent;
     import flash.errors.IOError;
     import __ruffle__.stub_method;
 
@@ -9,9 +10,43 @@ package flash.net {
 
         public var objectEncoding:uint = NetConnection.defaultObjectEncoding;
 
+        private var _connected:Boolean = false;
+
+        private var _uri:String = null;
+
+        // Whether `uri` is a Flash Remoting gateway, which is connected to separately for every call.
+        private var _isRemoting:Boolean = false;
+
+        private var _nextResponseId:uint = 1;
+
+        public function get connected():Boolean {
+            return this._connected;
+        }
+
+        public function get uri():String {
+            return this._uri;
+        }
 
         public function connect(command:String, ... arguments):void {
-            stub_method("flash.net.NetConnection", "connect");
+            this.close();
+
+            if (command == null) {
+                this._uri = "null";
+                this._connected = true;
+                this.dispatchEvent(new NetStatusEvent(NetStatusEvent.NET_STATUS, false, false, {
+                    "code": "NetConnection.Connect.Success",
+                    "level": "status"
+                }));
+                return;
+            }
+
+            this._uri = command;
+            var lowerCommand:String = command.toLowerCase();
+            if (lowerCommand.indexOf("http:") == 0 || lowerCommand.indexOf("https:") == 0) {
+                this._isRemoting = true;
+            } else {
+                stub_method("flash.net.NetConnection", "connect");
+            }
         }
 
         public function addHeader(operation:String, mustUnderstand:Boolean = false, param:Object = null):void {
@@ -19,11 +54,29 @@ package flash.net {
         }
 
         public function call(command:String, responder:Responder, ... arguments):void {
-            stub_method("flash.net.NetConnection", "call");
+            if (!this._isRemoting) {
+                stub_method("flash.net.NetConnection", "call");
+                return;
+            }
+
+            var responseUri:String = "/" + this._nextResponseId++;
+            this.remotingCall(this._uri, command, responder, responseUri, arguments);
         }
-        
+
+        private native function remotingCall(gateway:String, command:String, responder:Responder, responseUri:String, arguments:Array):void;
+
         public function close():void {
-            stub_method("flash.net.NetConnection", "close");
+            var wasConnected:Boolean = this._connected;
+            this._connected = false;
+            this._isRemoting = false;
+            this._uri = null;
+
+            if (wasConnected) {
+                this.dispatchEvent(new NetStatusEvent(NetStatusEvent.NET_STATUS, false, false, {
+                    "code": "NetConnection.Connect.Closed",
+                    "level": "status"
+                }));
+            }
         }
     }
 }
@@ -17,8 +17,10 @@ package flash.net {
         public static const CONNECT_TO_FMS: String = "connectToFMS";
         public static const DIRECT_CONNECTIONS: String = "directConnections";
 
-        public function NetStream(connection:NetConnection, peer:String = CONNECT_TO_FMS) {
+        private var _client:Object;
 
+        public function NetStream(connection:NetConnection, peer:String = CONNECT_TO_FMS) {
+            this._client = this;
         }
 
         public function appendBytes(bytes:ByteArray) {
@@ -41,9 +43,7 @@ package flash.net {
             stub_method("flash.net.NetStream", "attachCamera");
         }
 
-        public function close() {
-            stub_method("flash.net.NetStream", "close");
-        }
+        public native function close():void;
 
         public function dispose() {
             stub_method("flash.net.NetStream", "dispose");
@@ -83,9 +83,7 @@ package flash.net {
 
         public native function resume();
 
-        public function seek(offset:Number) {
-            stub_method("flash.net.NetStream", "seek");
-        }
+        public native function seek(offset:Number):void;
 
         public function send(handlerName:String, ...args) {
             stub_method("flash.net.NetStream", "send");
@@ -170,12 +168,14 @@ package flash.net {
         }
 
         public function get client():Object {
-            stub_getter("flash.net.NetStream", "client");
-            return {};
+            return this._client;
         }
 
         public function set client(client:Object) {
-            stub_setter("flash.net.NetStream", "client");
+            if (client == null) {
+                throw new TypeError("Error #2004: One of the parameters is invalid.", 2004);
+            }
+            this._client = client;
         }
 
         public function get currentFPS():Number {
@@ -316,10 +316,7 @@ package flash.net {
             stub_setter("flash.net.NetStream", "soundTransform");
         };
 
-        public function get time(): Number {
-            stub_getter("flash.net.NetStream", "time");
-            return 0;
-        };
+        public native function get time(): Number;
 
         public function get useHardwareDecoder(): Boolean {
             stub_getter("flash.net.NetStream", "useHardwareDecoder");
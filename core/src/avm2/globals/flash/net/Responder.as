@@ -1,9 +1,12 @@
 package flash.net {
-    import __ruffle__.stub_constructor;
-    
     public class Responder {
+        // These are called by `NetConnection` when a response arrives.
+        ruffle var _result:Function;
+        ruffle var _status:Function;
+
         public function Responder(result:Function, status:Function = null) {
-            stub_constructor("flash.net.Responder");
+            this.ruffle::_result = result;
+            this.ruffle::_status = status;
         }
     }
 }
@@ -3,8 +3,6 @@ package flash.net
    import flash.events.EventDispatcher;
    import __ruffle__.stub_method;
 
-   namespace ruffle = "__ruffle__";
-   
    public class SharedObject extends EventDispatcher
    {
       public function SharedObject()
@@ -1,19 +1,22 @@
 package flash.net {
+    import flash.errors.IOError;
     import flash.events.EventDispatcher;
     import flash.utils.ByteArray;
     import flash.utils.Endian;
     import flash.utils.IDataInput;
     import flash.utils.IDataOutput;
 
-    import __ruffle__.stub_method;
-    import __ruffle__.stub_getter;
-    import __ruffle__.stub_setter;
-
     public class Socket extends EventDispatcher implements IDataOutput, IDataInput {
         private var _timeout:uint;
 
         private var _endian:String = Endian.BIG_ENDIAN;
 
+        // Received data that hasn't been read yet. This is appended to by the player.
+        ruffle var _readBuffer:ByteArray = new ByteArray();
+
+        // Written data that hasn't been flushed yet.
+        private var _writeBuffer:ByteArray = new ByteArray();
+
         public function Socket(host:String = null, port:int = 0) {
             this._timeout = 20000;
             if (host != null) {
@@ -22,9 +25,16 @@ package flash.net {
         }
 
         public function connect(host: String, port: int):void {
-            stub_method("flash.net.Socket", "connect");
+            if (port < 0 || port > 65535) {
+                throw new SecurityError("Error #2003: Invalid socket port number specified.", 2003);
+            }
+
+            this.ruffle::_readBuffer.clear();
+            this._writeBuffer.clear();
+            this.connectSocket(host, port, this._timeout);
         }
 
+        private native function connectSocket(host:String, port:int, timeout:uint):void;
 
         public function get timeout():uint {
             return this._timeout;
@@ -39,17 +49,22 @@ package flash.net {
         }
 
         public function close():void {
-            stub_method("flash.net.Socket", "close");
+            if (!this.connected) {
+                throw new IOError("Error #2002: Operation attempted on invalid socket.", 2002);
+            }
+
+            this.closeSocket();
+            this._writeBuffer.clear();
         }
 
+        private native function closeSocket():void;
+
         public function get bytesAvailable():uint {
-            stub_getter("flash.net.Socket", "bytesAvailable");
-            return 0;
+            return this.ruffle::_readBuffer.bytesAvailable;
         }
 
         public function get bytesPending():uint {
-            stub_getter("flash.net.Socket", "bytesPending");
-            return 0;
+            return this._writeBuffer.length;
         }
 
         public function get endian():String {
@@ -59,144 +74,137 @@ package flash.net {
         public function set endian(value:String):void {
             if (value === Endian.BIG_ENDIAN || value === Endian.LITTLE_ENDIAN) {
                 this._endian = value;
+                this.ruffle::_readBuffer.endian = value;
+                this._writeBuffer.endian = value;
             } else {
                 throw new ArgumentError("Error #2008: Parameter endian must be one of the accepted values.", 2008);
             }
         }
 
-        public function get connected():Boolean {
-            stub_getter("flash.net.Socket", "connected");
-            return false;
-        }
+        public native function get connected():Boolean;
 
         public function get objectEncoding():uint {
-            stub_getter("flash.net.Socket", "objectEncoding");
-            return 0;
+            return this._writeBuffer.objectEncoding;
         }
 
         public function set objectEncoding(value:uint):void {
-            stub_setter("flash.net.Socket", "objectEncoding");
+            this.ruffle::_readBuffer.objectEncoding = value;
+            this._writeBuffer.objectEncoding = value;
         }
 
         public function flush():void {
-            stub_method("flash.net.Socket", "flush");
+            if (!this.connected) {
+                throw new IOError("Error #2002: Operation attempted on invalid socket.", 2002);
+            }
+
+            this.sendData(this._writeBuffer);
+            this._writeBuffer.clear();
         }
 
+        private native function sendData(data:ByteArray):void;
+
         public function readBoolean():Boolean {
-            stub_method("flash.net.Socket", "readBoolean");
-            return false;
+            return this.ruffle::_readBuffer.readBoolean();
         }
 
         public function readByte():int {
-            stub_method("flash.net.Socket", "readByte");
-            return 0;
+            return this.ruffle::_readBuffer.readByte();
         }
 
         public function readBytes(bytes:ByteArray, offset:uint = 0, length:uint = 0):void {
-            stub_method("flash.net.Socket", "readBytes");
+            this.ruffle::_readBuffer.readBytes(bytes, offset, length);
         }
 
         public function readDouble():Number {
-            stub_method("flash.net.Socket", "readDouble");
-            return 0.0;
+            return this.ruffle::_readBuffer.readDouble();
         }
 
         public function readFloat():Number {
-            stub_method("flash.net.Socket", "readFloat");
-            return 0.0;
+            return this.ruffle::_readBuffer.readFloat();
         }
 
         public function readInt():int {
-            stub_method("flash.net.Socket", "readInt");
-            return 0;
+            return this.ruffle::_readBuffer.readInt();
         }
 
         public function readMultiByte(length:uint, charSet:String):String {
-            stub_method("flash.net.Socket", "readMultiByte");
-            return "";
+            return this.ruffle::_readBuffer.readMultiByte(length, charSet);
         }
 
         public function readObject():* {
-            stub_method("flash.net.Socket", "readObject");
-            return null;
+            return this.ruffle::_readBuffer.readObject();
         }
 
         public function readShort():int {
-            stub_method("flash.net.Socket", "readShort");
-            return 0;
+            return this.ruffle::_readBuffer.readShort();
         }
 
         public function readUnsignedByte():uint {
-            stub_method("flash.net.Socket", "readUnsignedByte");
-            return 0;
+            return this.ruffle::_readBuffer.readUnsignedByte();
         }
 
         public function readUnsignedInt():uint {
-            stub_method("flash.net.Socket", "readUnsignedInt");
-            return 0;
+            return this.ruffle::_readBuffer.readUnsignedInt();
         }
 
         public function readUnsignedShort():uint {
-            stub_method("flash.net.Socket", "readUnsignedShort");
-            return 0;
+            return this.ruffle::_readBuffer.readUnsignedShort();
         }
 
         public function readUTF():String {
-            stub_method("flash.net.Socket", "readUTF");
-            return "";
+            return this.ruffle::_readBuffer.readUTF();
         }
 
         public function readUTFBytes(length:uint):String {
-            stub_method("flash.net.Socket", "readUTFBytes");
-            return "";
+            return this.ruffle::_readBuffer.readUTFBytes(length);
         }
 
         public function writeBoolean(value:Boolean):void {
-            stub_method("flash.net.Socket", "writeBoolean");
+            this._writeBuffer.writeBoolean(value);
         }
 
         public function writeByte(value:int):void {
-            stub_method("flash.net.Socket", "writeByte");
+            this._writeBuffer.writeByte(value);
         }
 
         public function writeBytes(bytes:ByteArray, offset:uint = 0, length:uint = 0):void {
-            stub_method("flash.net.Socket", "writeBytes");
+            this._writeBuffer.writeBytes(bytes, offset, length);
         }
 
         public function writeDouble(value:Number):void {
-            stub_method("flash.net.Socket", "writeDouble");
+            this._writeBuffer.writeDouble(value);
         }
 
         public function writeFloat(value:Number):void {
-            stub_method("flash.net.Socket", "writeFloat");
+            this._writeBuffer.writeFloat(value);
         }
 
         public function writeInt(value:int):void {
-            stub_method("flash.net.Socket", "writeInt");
+            this._writeBuffer.writeInt(value);
         }
 
         public function writeMultiByte(value:String, charSet:String):void {
-            stub_method("flash.net.Socket", "writeMultiByte");
+            this._writeBuffer.writeMultiByte(value, charSet);
         }
 
         public function writeObject(value:*):void {
-            stub_method("flash.net.Socket", "writeObject");
+            this._writeBuffer.writeObject(value);
         }
 
         public function writeShort(value:int):void {
-            stub_method("flash.net.Socket", "writeShort");
+            this._writeBuffer.writeShort(value);
         }
 
         public function writeUnsignedInt(value:uint):void {
-            stub_method("flash.net.Socket", "writeUnsignedInt");
+            this._writeBuffer.writeUnsignedInt(value);
         }
 
         public function writeUTF(value:String):void {
-            stub_method("flash.net.Socket", "writeUTF");
+            this._writeBuffer.writeUTF(value);
         }
 
         public function writeUTFBytes(value:String):void {
-            stub_method("flash.net.Socket", "writeUTFBytes");
+            this._writeBuffer.writeUTFBytes(value);
         }
     }
 }
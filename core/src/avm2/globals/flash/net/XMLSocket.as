@@ -1,23 +1,75 @@
 package flash.net {
+    import flash.events.DataEvent;
+    import flash.events.Event;
     import flash.events.EventDispatcher;
-    import __ruffle__.stub_constructor;
-    import __ruffle__.stub_method;
+    import flash.events.IOErrorEvent;
+    import flash.events.ProgressEvent;
+    import flash.events.SecurityErrorEvent;
+    import flash.utils.ByteArray;
 
     public class XMLSocket extends EventDispatcher {
+        // Messages are sent over a plain socket, each one terminated by a zero byte.
+        private var _socket:Socket = new Socket();
+
+        // Received data of a message that isn't complete yet.
+        private var _buffer:ByteArray = new ByteArray();
+
         public function XMLSocket(host: String = null, port: int = 0) {
-            stub_constructor("flash.net.XMLSocket");
+            this._socket.addEventListener(Event.CONNECT, this.redispatch);
+            this._socket.addEventListener(Event.CLOSE, this.redispatch);
+            this._socket.addEventListener(IOErrorEvent.IO_ERROR, this.redispatch);
+            this._socket.addEventListener(SecurityErrorEvent.SECURITY_ERROR, this.redispatch);
+            this._socket.addEventListener(ProgressEvent.SOCKET_DATA, this.onSocketData);
+
+            if (host != null) {
+                this.connect(host, port);
+            }
+        }
+
+        public function get connected(): Boolean {
+            return this._socket.connected;
+        }
+
+        public function get timeout(): int {
+            return this._socket.timeout;
+        }
+
+        public function set timeout(value: int): void {
+            this._socket.timeout = value;
         }
 
         public function close(): void {
-            stub_method("flash.net.XMLSocket", "close");
+            this._socket.close();
         }
 
         public function connect(host: String, port: int): void {
-            stub_method("flash.net.XMLSocket", "connect");
+            this._buffer.clear();
+            this._socket.connect(host, port);
         }
 
         public function send(object: *): void {
-            stub_method("flash.net.XMLSocket", "send");
+            this._socket.writeUTFBytes(String(object));
+            this._socket.writeByte(0);
+            this._socket.flush();
+        }
+
+        private function redispatch(event: Event): void {
+            this.dispatchEvent(event.clone());
+        }
+
+        private function onSocketData(event: ProgressEvent): void {
+            while (this._socket.bytesAvailable > 0) {
+                var byte:int = this._socket.readByte();
+                if (byte != 0) {
+                    this._buffer.writeByte(byte);
+                    continue;
+                }
+
+                this._buffer.position = 0;
+                var message:String = this._buffer.readUTFBytes(this._buffer.length);
+                this._buffer.clear();
+                this.dispatchEvent(new DataEvent(DataEvent.DATA, false, false, message));
+            }
         }
     }
 }
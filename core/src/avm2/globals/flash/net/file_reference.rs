@@ -0,0 +1,310 @@
+//! `flash.net.FileReference` native function definitions
+
+use crate::avm2::bytearray::ByteArrayStorage;
+use crate::avm2::globals::flash::display::loader::request_from_url_request;
+use crate::avm2::object::{ByteArrayObject, TObject};
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::{Activation, Error, Multiname, Object, Value};
+use crate::backend::ui::{FileFilter, LoadedFile};
+use crate::file_reference::{upload_request, DEFAULT_UPLOAD_FIELD_NAME};
+use crate::string::AvmString;
+use crate::vminterface::AvmObject;
+use chrono::{DateTime, Utc};
+
+/// The name of a property in the `ruffle` namespace, where the player keeps
+/// the selected file.
+fn ruffle_name<'gc>(activation: &mut Activation<'_, 'gc>, name: &'static str) -> Multiname<'gc> {
+    Multiname::new(activation.avm2().ruffle_private_namespace, name)
+}
+
+fn date_value<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    date: Option<DateTime<Utc>>,
+) -> Result<Value<'gc>, Error<'gc>> {
+    match date {
+        Some(date) => {
+            let date = activation
+                .avm2()
+                .classes()
+                .date
+                .construct(activation, &[(date.timestamp_millis() as f64).into()])?;
+            Ok(date.into())
+        }
+        None => Ok(Value::Null),
+    }
+}
+
+/// Store a file picked by the user on a `FileReference`.
+pub fn set_file<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    mut this: Object<'gc>,
+    file: LoadedFile,
+) -> Result<(), Error<'gc>> {
+    let name = AvmString::new_utf8(activation.context.gc_context, &file.name);
+    let file_type: Value<'gc> = match file.file_type() {
+        Some(file_type) => AvmString::new_utf8(activation.context.gc_context, file_type).into(),
+        None => Value::Null,
+    };
+    let size = file.contents.len() as f64;
+    let creation_date = date_value(activation, file.creation_date)?;
+    let modification_date = date_value(activation, file.modification_date)?;
+    let file_data =
+        ByteArrayObject::from_storage(activation, ByteArrayStorage::from_vec(file.contents))?;
+
+    let properties = [
+        ("_name", name.into()),
+        ("_type", file_type),
+        ("_size", size.into()),
+        ("_creationDate", creation_date),
+        ("_modificationDate", modification_date),
+        ("_fileData", file_data.into()),
+        ("_data", Value::Null),
+    ];
+    for (property, value) in properties {
+        let property = ruffle_name(activation, property);
+        this.set_property(&property, value, activation)?;
+    }
+
+    Ok(())
+}
+
+/// Copy the contents of the selected file into the `data` property of a
+/// `FileReference`, returning the size of the file.
+pub fn load_data<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    mut this: Object<'gc>,
+) -> Result<usize, Error<'gc>> {
+    let file_data = ruffle_name(activation, "_fileData");
+    let Some(file_data) = this
+        .get_property(&file_data, activation)?
+        .as_object()
+        .and_then(|file_data| file_data.as_bytearray_object())
+    else {
+        return Ok(0);
+    };
+
+    let bytes = file_data.storage().bytes().to_vec();
+    let size = bytes.len();
+    let data = ByteArrayObject::from_storage(activation, ByteArrayStorage::from_vec(bytes))?;
+    let data_property = ruffle_name(activation, "_data");
+    this.set_property(&data_property, data.into(), activation)?;
+
+    Ok(size)
+}
+
+/// Convert the `typeFilter` array of `FileFilter`s passed to `browse`.
+fn file_filters<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    type_filter: Option<Object<'gc>>,
+) -> Result<Vec<FileFilter>, Error<'gc>> {
+    let Some(type_filter) = type_filter else {
+        return Ok(vec![]);
+    };
+
+    let filter_objects: Vec<Object<'gc>> = match type_filter.as_array_storage() {
+        Some(storage) => storage
+            .iter()
+            .filter_map(|filter| filter.and_then(|filter| filter.as_object()))
+            .collect(),
+        None => return Ok(vec![]),
+    };
+
+    let mut filters = Vec::with_capacity(filter_objects.len());
+    for filter in filter_objects {
+        let description = filter
+            .get_public_property("description", activation)?
+            .coerce_to_string(activation)?
+            .to_string();
+        let extensions = filter
+            .get_public_property("extension", activation)?
+            .coerce_to_string(activation)?
+            .to_string();
+        let mac_type = match filter.get_public_property("macType", activation)? {
+            Value::Null | Value::Undefined => None,
+            mac_type => Some(mac_type.coerce_to_string(activation)?.to_string()),
+        };
+        filters.push(FileFilter {
+            description,
+            extensions,
+            mac_type,
+        });
+    }
+
+    Ok(filters)
+}
+
+/// Show a file-open dialog for a `FileReference` or `FileReferenceList`.
+///
+/// Returns `false` if the dialog can't be shown.
+pub fn show_open_dialog<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Object<'gc>,
+    type_filter: Option<Object<'gc>>,
+    multiple: bool,
+) -> Result<Value<'gc>, Error<'gc>> {
+    let filters = file_filters(activation, type_filter)?;
+    let Some(dialog) = activation
+        .context
+        .ui
+        .display_file_open_dialog(filters, multiple)
+    else {
+        return Ok(false.into());
+    };
+
+    let future = activation.context.load_manager.select_file_reference(
+        activation.context.player.clone(),
+        AvmObject::Avm2(this),
+        dialog,
+        multiple,
+    );
+    activation.context.navigator.spawn_future(future);
+    Ok(true.into())
+}
+
+/// Implements `FileReference.browse`
+pub fn browse<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let Some(this) = this else {
+        return Ok(false.into());
+    };
+
+    let type_filter = args.try_get_object(activation, 0);
+    show_open_dialog(activation, this, type_filter, false)
+}
+
+/// Implements `FileReference.beginLoad`, the asynchronous part of `load`
+pub fn begin_load<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(this) = this {
+        let future = activation
+            .context
+            .load_manager
+            .load_file_reference(activation.context.player.clone(), AvmObject::Avm2(this));
+        activation.context.navigator.spawn_future(future);
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `FileReference.saveBytes`, which `save` calls once it has
+/// converted its data to a `ByteArray`
+pub fn save_bytes<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let Some(this) = this else {
+        return Ok(Value::Undefined);
+    };
+
+    let data = args.get_object(activation, 0, "data")?;
+    let data = data
+        .as_bytearray()
+        .map(|bytes| bytes.bytes().to_vec())
+        .unwrap_or_default();
+    let file_name = match args.get(1) {
+        Some(Value::Null | Value::Undefined) | None => String::new(),
+        Some(file_name) => file_name.coerce_to_string(activation)?.to_string(),
+    };
+
+    let Some(dialog) = activation.context.ui.display_file_save_dialog(file_name) else {
+        return Ok(Value::Undefined);
+    };
+    let future = activation.context.load_manager.save_file_reference(
+        activation.context.player.clone(),
+        AvmObject::Avm2(this),
+        dialog,
+        data,
+    );
+    activation.context.navigator.spawn_future(future);
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `FileReference.download`
+pub fn download<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let Some(this) = this else {
+        return Ok(Value::Undefined);
+    };
+
+    let url_request = args.get_object(activation, 0, "request")?;
+    let request = request_from_url_request(activation, url_request)?;
+    let file_name = match args.get(1) {
+        Some(Value::Null | Value::Undefined) | None => {
+            // Default to the last segment of the URL's path.
+            let url = request.url();
+            let path = url.split(['?', '#']).next().unwrap_or_default();
+            path.rsplit('/').next().unwrap_or_default().to_string()
+        }
+        Some(file_name) => file_name.coerce_to_string(activation)?.to_string(),
+    };
+
+    let Some(dialog) = activation.context.ui.display_file_save_dialog(file_name) else {
+        return Ok(Value::Undefined);
+    };
+    let future = activation.context.load_manager.download_file_reference(
+        activation.context.player.clone(),
+        AvmObject::Avm2(this),
+        dialog,
+        request,
+    );
+    activation.context.navigator.spawn_future(future);
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `FileReference.beginUpload`, the asynchronous part of `upload`
+pub fn begin_upload<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let Some(this) = this else {
+        return Ok(Value::Undefined);
+    };
+
+    let url_request = args.get_object(activation, 0, "request")?;
+    let request = request_from_url_request(activation, url_request)?;
+    let field_name = match args.get(1) {
+        Some(Value::Null | Value::Undefined) | None => DEFAULT_UPLOAD_FIELD_NAME.to_string(),
+        Some(field_name) => field_name.coerce_to_string(activation)?.to_string(),
+    };
+
+    let name_property = ruffle_name(activation, "_name");
+    let file_name = this
+        .get_property(&name_property, activation)?
+        .coerce_to_string(activation)?
+        .to_string();
+    let file_data_property = ruffle_name(activation, "_fileData");
+    let contents = this
+        .get_property(&file_data_property, activation)?
+        .as_object()
+        .and_then(|file_data| file_data.as_bytearray().map(|bytes| bytes.bytes().to_vec()))
+        .unwrap_or_default();
+
+    let request = upload_request(
+        request,
+        &field_name,
+        &file_name,
+        &contents,
+        activation.context.rng,
+    );
+    let future = activation.context.load_manager.upload_file_reference(
+        activation.context.player.clone(),
+        AvmObject::Avm2(this),
+        request,
+    );
+    activation.context.navigator.spawn_future(future);
+
+    Ok(Value::Undefined)
+}
@@ -0,0 +1,46 @@
+//! `flash.net.FileReferenceList` native function definitions
+
+use crate::avm2::globals::flash::net::file_reference::{set_file, show_open_dialog};
+use crate::avm2::object::TObject;
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::{Activation, ArrayObject, Error, Multiname, Object, Value};
+use crate::backend::ui::LoadedFile;
+
+/// Implements `FileReferenceList.browse`
+pub fn browse<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let Some(this) = this else {
+        return Ok(false.into());
+    };
+
+    let type_filter = args.try_get_object(activation, 0);
+    show_open_dialog(activation, this, type_filter, true)
+}
+
+/// Store the files picked by the user on a `FileReferenceList`, as a new
+/// `FileReference` for each file.
+pub fn set_file_list<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    mut this: Object<'gc>,
+    files: Vec<LoadedFile>,
+) -> Result<(), Error<'gc>> {
+    let mut file_list = Vec::with_capacity(files.len());
+    for file in files {
+        let file_reference = activation
+            .avm2()
+            .classes()
+            .filereference
+            .construct(activation, &[])?;
+        set_file(activation, file_reference, file)?;
+        file_list.push(Value::from(file_reference));
+    }
+
+    let file_list = ArrayObject::from_storage(activation, file_list.into_iter().collect())?;
+    let property = Multiname::new(activation.avm2().ruffle_private_namespace, "_fileList");
+    this.set_property(&property, file_list.into(), activation)?;
+
+    Ok(())
+}
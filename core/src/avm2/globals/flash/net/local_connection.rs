@@ -1,5 +1,34 @@
+use crate::avm2::amf::serialize_value;
+use crate::avm2::error::{argument_error, type_error};
+use crate::avm2::object::TObject;
+use crate::avm2::parameters::ParametersExt;
 use crate::avm2::{Activation, Error, Object, Value};
+use crate::local_connection::movie_domain;
 use crate::string::AvmString;
+use crate::vminterface::AvmObject;
+use flash_lso::types::{AMFVersion, Value as AmfValue};
+
+/// The domain of the movie that called into `LocalConnection`, which
+/// connection names are scoped to.
+///
+/// This is not necessarily the root movie, as `LocalConnection`s may be used
+/// by loaded movies from other domains. The calling movie is the one whose
+/// application domain the caller's code was loaded into.
+fn caller_domain(activation: &mut Activation<'_, '_>) -> Option<String> {
+    let library = &activation.context.library;
+    let movie = activation
+        .caller_domain()
+        .and_then(|domain| {
+            library.known_movies().into_iter().find(|movie| {
+                library
+                    .library_for_movie(movie.clone())
+                    .and_then(|library| library.try_avm2_domain())
+                    == Some(domain)
+            })
+        })
+        .unwrap_or_else(|| activation.context.swf.clone());
+    movie_domain(movie.url())
+}
 
 /// Implements `domain` getter
 pub fn get_domain<'gc>(
@@ -7,21 +36,125 @@ pub fn get_domain<'gc>(
     _this: Option<Object<'gc>>,
     _args: &[Value<'gc>],
 ) -> Result<Value<'gc>, Error<'gc>> {
-    let movie = activation.context.swf;
-
-    let domain = if let Ok(url) = url::Url::parse(movie.url()) {
-        if url.scheme() == "file" {
-            "localhost".into()
-        } else if let Some(domain) = url.domain() {
-            AvmString::new_utf8(activation.context.gc_context, domain)
-        } else {
-            // no domain?
-            "localhost".into()
-        }
-    } else {
+    let Some(domain) = caller_domain(activation) else {
         tracing::error!("LocalConnection::domain: Unable to parse movie URL");
         return Ok(Value::Null);
     };
 
-    Ok(Value::String(domain))
+    Ok(AvmString::new_utf8(activation.context.gc_context, domain).into())
+}
+
+/// Implements `LocalConnection.connect`
+pub fn connect<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let Some(this) = this else {
+        return Ok(Value::Undefined);
+    };
+
+    let Some(name) = args.try_get_string(activation, 0)? else {
+        return Err(Error::AvmError(type_error(
+            activation,
+            "Error #2007: Parameter connectionName must be non-null.",
+            2007,
+        )?));
+    };
+
+    if name.contains(b':') {
+        return Err(Error::AvmError(argument_error(
+            activation,
+            "Error #2004: One of the parameters is invalid.",
+            2004,
+        )?));
+    }
+
+    let domain = caller_domain(activation).unwrap_or_default();
+    if !activation
+        .context
+        .local_connections
+        .connect(&domain, AvmObject::Avm2(this), &name)
+    {
+        return Err(Error::AvmError(argument_error(
+            activation,
+            "Error #2082: Connect failed because the object is already connected.",
+            2082,
+        )?));
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `LocalConnection.close`
+pub fn close<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let Some(this) = this else {
+        return Ok(Value::Undefined);
+    };
+
+    if !activation
+        .context
+        .local_connections
+        .close(AvmObject::Avm2(this))
+    {
+        return Err(Error::AvmError(argument_error(
+            activation,
+            "Error #2083: Close failed because the object is not connected.",
+            2083,
+        )?));
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `LocalConnection.send`, which queues a method call to
+/// whichever movie is connected to the given name.
+pub fn send<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let Some(this) = this else {
+        return Ok(Value::Undefined);
+    };
+
+    let Some(connection_name) = args.try_get_string(activation, 0)? else {
+        return Err(Error::AvmError(type_error(
+            activation,
+            "Error #2007: Parameter connectionName must be non-null.",
+            2007,
+        )?));
+    };
+    let Some(method_name) = args.try_get_string(activation, 1)? else {
+        return Err(Error::AvmError(type_error(
+            activation,
+            "Error #2007: Parameter methodName must be non-null.",
+            2007,
+        )?));
+    };
+
+    // The receiver may be an AVM1 movie, which only understands AMF0.
+    let arguments = args
+        .get(2..)
+        .unwrap_or_default()
+        .iter()
+        .map(|value| {
+            serialize_value(activation, *value, AMFVersion::AMF0).unwrap_or(AmfValue::Undefined)
+        })
+        .collect();
+
+    let domain = caller_domain(activation).unwrap_or_default();
+    activation.context.local_connections.send(
+        &domain,
+        AvmObject::Avm2(this),
+        &connection_name,
+        &method_name,
+        arguments,
+    );
+
+    Ok(Value::Undefined)
 }
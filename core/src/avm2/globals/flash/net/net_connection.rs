@@ -0,0 +1,70 @@
+//! `flash.net.NetConnection` native function definitions
+
+use crate::avm2::amf::serialize_value;
+use crate::avm2::object::TObject;
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::{Activation, Error, Object, Value};
+use crate::backend::navigator::Request;
+use crate::remoting::{encode_call, CONTENT_TYPE};
+use crate::vminterface::AvmObject;
+use flash_lso::types::{AMFVersion, Value as AmfValue};
+
+/// Implements `NetConnection.remotingCall`, which sends a Flash Remoting call
+/// to a gateway.
+pub fn remoting_call<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let Some(this) = this else {
+        return Ok(Value::Undefined);
+    };
+
+    let gateway = args.get_string(activation, 0)?;
+    let command = args.get_string(activation, 1)?;
+    let responder = args.try_get_object(activation, 2);
+    let response_uri = args.get_string(activation, 3)?;
+    let arguments = args.get_object(activation, 4, "arguments")?;
+
+    let amf_version = match this
+        .get_public_property("objectEncoding", activation)?
+        .coerce_to_u32(activation)?
+    {
+        0 => AMFVersion::AMF0,
+        _ => AMFVersion::AMF3,
+    };
+
+    let values: Vec<_> = arguments
+        .as_array_storage()
+        .map(|storage| storage.iter().collect::<Vec<_>>())
+        .unwrap_or_default();
+    let amf_arguments = values
+        .into_iter()
+        .map(|value| {
+            serialize_value(activation, value.unwrap_or(Value::Undefined), amf_version)
+                .unwrap_or(AmfValue::Undefined)
+        })
+        .collect();
+
+    let body = encode_call(
+        amf_version,
+        &command.to_utf8_lossy(),
+        &response_uri.to_utf8_lossy(),
+        amf_arguments,
+    );
+    let request = Request::post(
+        gateway.to_utf8_lossy().into_owned(),
+        Some((body, CONTENT_TYPE.to_string())),
+    );
+
+    let future = activation.context.load_manager.call_net_connection(
+        activation.context.player.clone(),
+        AvmObject::Avm2(this),
+        responder.map(AvmObject::Avm2),
+        request,
+        response_uri.to_string(),
+    );
+    activation.context.navigator.spawn_future(future);
+
+    Ok(Value::Undefined)
+}
@@ -80,3 +80,45 @@ pub fn toggle_pause<'gc>(
 
     Ok(Value::Undefined)
 }
+
+pub fn seek<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(ns) = this.and_then(|o| o.as_netstream()) {
+        let offset = args
+            .get(0)
+            .cloned()
+            .unwrap_or(Value::Undefined)
+            .coerce_to_number(activation)?;
+
+        ns.seek(&mut activation.context, offset * 1000.0);
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn close<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(ns) = this.and_then(|o| o.as_netstream()) {
+        ns.close(&mut activation.context);
+    }
+
+    Ok(Value::Undefined)
+}
+
+pub fn get_time<'gc>(
+    _activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(ns) = this.and_then(|o| o.as_netstream()) {
+        return Ok(ns.time().into());
+    }
+
+    Ok(Value::Undefined)
+}
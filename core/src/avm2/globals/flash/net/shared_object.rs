@@ -2,7 +2,7 @@
 
 use crate::avm2::object::TObject;
 use crate::avm2::Multiname;
-use crate::avm2::{Activation, Error, Namespace, Object, Value};
+use crate::avm2::{Activation, Error, Object, Value};
 use crate::avm2_stub_method;
 use crate::display_object::DisplayObject;
 use crate::display_object::TDisplayObject;
@@ -137,10 +137,7 @@ pub fn get_local<'gc>(
     let mut this = sharedobject_cls.construct(activation, &[])?;
 
     // Set the internal name
-    let ruffle_name = Multiname::new(
-        Namespace::package("__ruffle__", &mut activation.borrow_gc()),
-        "_ruffleName",
-    );
+    let ruffle_name = Multiname::new(activation.avm2().ruffle_private_namespace, "_ruffleName");
     this.set_property(
         &ruffle_name,
         AvmString::new_utf8(activation.context.gc_context, &full_name).into(),
@@ -185,10 +182,7 @@ pub fn flush<'gc>(
             .get_public_property("data", activation)?
             .coerce_to_object(activation)?;
 
-        let ruffle_name = Multiname::new(
-            Namespace::package("__ruffle__", &mut activation.borrow_gc()),
-            "_ruffleName",
-        );
+        let ruffle_name = Multiname::new(activation.avm2().ruffle_private_namespace, "_ruffleName");
         let name = this
             .get_property(&ruffle_name, activation)?
             .coerce_to_string(activation)?;
@@ -237,10 +231,7 @@ pub fn clear<'gc>(
         this.set_public_property("data", data, activation)?;
 
         // Delete data from storage backend.
-        let ruffle_name = Multiname::new(
-            Namespace::package("__ruffle__", &mut activation.borrow_gc()),
-            "_ruffleName",
-        );
+        let ruffle_name = Multiname::new(activation.avm2().ruffle_private_namespace, "_ruffleName");
         let name = this
             .get_property(&ruffle_name, activation)?
             .coerce_to_string(activation)?;
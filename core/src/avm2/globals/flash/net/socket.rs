@@ -0,0 +1,90 @@
+//! `flash.net.Socket` native function definitions
+
+use crate::avm2::object::TObject;
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::{Activation, Error, Object, Value};
+use crate::vminterface::AvmObject;
+use std::time::Duration;
+
+/// Implements `Socket.connectSocket`, which starts connecting to a server.
+pub fn connect_socket<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let Some(this) = this else {
+        return Ok(Value::Undefined);
+    };
+
+    let host = args
+        .try_get_string(activation, 0)?
+        .map(|host| host.to_utf8_lossy().into_owned());
+    let port = args.get_u32(activation, 1)? as u16;
+    let timeout = Duration::from_millis(args.get_u32(activation, 2)?.into());
+
+    let movie_url = activation.context.swf.url().to_string();
+    activation.context.sockets.connect(
+        activation.context.navigator,
+        &movie_url,
+        AvmObject::Avm2(this),
+        host,
+        port,
+        timeout,
+    );
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Socket.closeSocket`
+pub fn close_socket<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(this) = this {
+        activation.context.sockets.close(AvmObject::Avm2(this));
+    }
+
+    Ok(Value::Undefined)
+}
+
+/// Implements `Socket.connected`'s getter
+pub fn get_connected<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let Some(this) = this else {
+        return Ok(false.into());
+    };
+
+    Ok(activation
+        .context
+        .sockets
+        .is_connected(AvmObject::Avm2(this))
+        .into())
+}
+
+/// Implements `Socket.sendData`, which writes the contents of a `ByteArray`
+/// to the connection.
+pub fn send_data<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let Some(this) = this else {
+        return Ok(Value::Undefined);
+    };
+
+    let data = args.get_object(activation, 0, "data")?;
+    let data = data
+        .as_bytearray()
+        .map(|storage| storage.bytes().to_vec())
+        .unwrap_or_default();
+
+    if !data.is_empty() {
+        activation.context.sockets.send(AvmObject::Avm2(this), data);
+    }
+
+    Ok(Value::Undefined)
+}
@@ -2,5 +2,6 @@
 
 pub mod font;
 pub mod static_text;
+pub mod style_sheet;
 pub mod text_field;
 pub mod text_format;
@@ -1,37 +1,56 @@
 package flash.text {
-    
-    import __ruffle__.stub_constructor;
-    import __ruffle__.stub_method;
-    import __ruffle__.stub_getter;
-    
+
     public dynamic class StyleSheet {
+        // Copies of the style objects, keyed by lowercased style name.
+        private var _styles:Object = {};
+
         public function StyleSheet() {}
-        
+
         public function get styleNames():Array {
-            stub_getter("flash.text.StyleSheet", "styleNames");
-            return [];
+            var names:Array = [];
+            for (var name:String in this._styles) {
+                names.push(name);
+            }
+            return names;
         }
-        
+
         public function clear():void {
-            stub_method("flash.text.StyleSheet", "clear");
+            this._styles = {};
         }
-        
+
         public function getStyle(styleName:String):Object {
-            stub_method("flash.text.StyleSheet", "getStyle");
-            return null;
+            var style:Object = this._styles[styleName.toLowerCase()];
+            return style ? copyStyle(style) : null;
         }
-        
+
         public function parseCSS(CSSText:String):void {
-            stub_method("flash.text.StyleSheet", "parseCSS");
+            var parsed:Object = innerParseCss(CSSText);
+            if (!parsed) {
+                return;
+            }
+            for (var name:String in parsed) {
+                this.setStyle(name, parsed[name]);
+            }
         }
-        
+
         public function setStyle(styleName:String, styleObject:Object):void {
-            stub_method("flash.text.StyleSheet", "setStyle");
+            if (styleObject == null) {
+                delete this._styles[styleName.toLowerCase()];
+            } else {
+                this._styles[styleName.toLowerCase()] = copyStyle(styleObject);
+            }
         }
-        
-        public function transform(formatObject:Object):TextFormat {
-            stub_method("flash.text.StyleSheet", "transform");
-            return null;
+
+        public native function transform(formatObject:Object):TextFormat;
+
+        private static function copyStyle(style:Object):Object {
+            var copy:Object = {};
+            for (var key:String in style) {
+                copy[key] = style[key];
+            }
+            return copy;
         }
+
+        private static native function innerParseCss(css:String):Object;
     }
 }
@@ -75,8 +75,19 @@ package flash.text {
         }
         public function set styleSheet(value:StyleSheet):void {
             this._styleSheet = value;
-            stub_setter("flash.text.TextField", "styleSheet");
+            if (value) {
+                var names:Array = value.styleNames;
+                var formats:Array = [];
+                for each (var name:String in names) {
+                    formats.push(value.transform(value.getStyle(name)));
+                }
+                this.setStyleSheetFormats(names, formats);
+            } else {
+                this.setStyleSheetFormats(null, null);
+            }
         }
+
+        private native function setStyleSheetFormats(names:Array, formats:Array):void;
         
         public native function get text():String;
         public native function set text(value:String):void;
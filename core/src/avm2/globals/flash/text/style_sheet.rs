@@ -0,0 +1,80 @@
+//! `flash.text.StyleSheet` builtin/prototype
+
+use crate::avm2::activation::Activation;
+use crate::avm2::object::{Object, TObject, TextFormatObject};
+use crate::avm2::parameters::ParametersExt;
+use crate::avm2::value::Value;
+use crate::avm2::Error;
+use crate::html::{parse_css, TextFormat, CSS_PROPERTIES};
+use crate::string::{AvmString, WStr};
+
+/// Implements `StyleSheet.innerParseCss`.
+///
+/// Returns an object of style objects keyed by selector, or `null` if the CSS
+/// could not be parsed.
+pub fn inner_parse_css<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let css = args.get_string(activation, 0)?;
+    let Some(rules) = parse_css(&css) else {
+        return Ok(Value::Null);
+    };
+
+    let mut result = activation
+        .avm2()
+        .classes()
+        .object
+        .construct(activation, &[])?;
+    for (selector, declarations) in rules {
+        let selector = AvmString::new(activation.context.gc_context, selector);
+        // Repeated selectors are merged, with later declarations taking precedence.
+        let mut style = match result.get_public_property(selector, activation)? {
+            Value::Object(style) => style,
+            _ => {
+                let style = activation
+                    .avm2()
+                    .classes()
+                    .object
+                    .construct(activation, &[])?;
+                result.set_public_property(selector, style.into(), activation)?;
+                style
+            }
+        };
+
+        for (name, value) in declarations {
+            let name = AvmString::new(activation.context.gc_context, name);
+            let value = AvmString::new(activation.context.gc_context, value);
+            style.set_public_property(name, value.into(), activation)?;
+        }
+    }
+
+    Ok(result.into())
+}
+
+/// Implements `StyleSheet.transform`.
+pub fn transform<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let Some(style) = args.try_get_object(activation, 0) else {
+        return Ok(Value::Null);
+    };
+
+    let mut properties = Vec::new();
+    for &name in CSS_PROPERTIES {
+        let value = style.get_public_property(name, activation)?;
+        if !matches!(value, Value::Undefined | Value::Null) {
+            properties.push((name, value.coerce_to_string(activation)?));
+        }
+    }
+
+    let text_format = TextFormat::from_css_properties(
+        properties
+            .iter()
+            .map(|(name, value)| (WStr::from_units(name.as_bytes()), value.as_wstr())),
+    );
+    Ok(TextFormatObject::from_text_format(activation, text_format)?.into())
+}
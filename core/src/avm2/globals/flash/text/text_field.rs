@@ -7,7 +7,7 @@ use crate::avm2::parameters::ParametersExt;
 use crate::avm2::value::Value;
 use crate::avm2::Error;
 use crate::display_object::{AutoSizeMode, EditText, TDisplayObject, TextSelection};
-use crate::html::TextFormat;
+use crate::html::{StyleSheet, TextFormat};
 use crate::string::AvmString;
 use crate::{avm2_stub_getter, avm2_stub_setter};
 use swf::Color;
@@ -389,6 +389,52 @@ pub fn set_html_text<'gc>(
     Ok(Value::Undefined)
 }
 
+pub fn set_style_sheet_formats<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    if let Some(this) = this
+        .and_then(|this| this.as_display_object())
+        .and_then(|this| this.as_edit_text())
+    {
+        let names = args.try_get_object(activation, 0);
+        let formats = args.try_get_object(activation, 1);
+        let style_sheet = if let (Some(names), Some(formats)) = (names, formats) {
+            let mut style_sheet = StyleSheet::new();
+            let names = names
+                .as_array_storage()
+                .map(|names| names.iter().collect::<Vec<_>>());
+            let formats = formats
+                .as_array_storage()
+                .map(|formats| formats.iter().collect::<Vec<_>>());
+            for (name, format) in names
+                .unwrap_or_default()
+                .into_iter()
+                .zip(formats.unwrap_or_default())
+            {
+                let Some(format) = format.and_then(|format| format.as_object()) else {
+                    continue;
+                };
+                let Some(format) = format.as_text_format().map(|format| format.clone()) else {
+                    continue;
+                };
+                let name = name
+                    .unwrap_or(Value::Undefined)
+                    .coerce_to_string(activation)?;
+                style_sheet.set_style(&name, format);
+            }
+            Some(style_sheet)
+        } else {
+            None
+        };
+
+        this.set_style_sheet(&mut activation.context, style_sheet);
+    }
+
+    Ok(Value::Undefined)
+}
+
 pub fn get_length<'gc>(
     _activation: &mut Activation<'_, 'gc>,
     this: Option<Object<'gc>>,
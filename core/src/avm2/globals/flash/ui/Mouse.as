@@ -1,26 +1,84 @@
 package flash.ui {
 
+    import flash.display.BitmapData;
+    import flash.geom.Point;
     import __ruffle__.stub_getter;
-    import __ruffle__.stub_method;
 
     public final class Mouse {
+        private static var _cursor:String = MouseCursor.AUTO;
+
+        // The IDs given to the player for each cursor registered with `registerCursor`.
+        private static var registeredCursors:Object = {};
+        private static var nextCursorId:int = 0;
+
         public static native function hide(): void;
         public static native function show(): void;
         public static function get supportsCursor():Boolean {
             stub_getter("flash.ui.Mouse", "supportsCursor");
             return true;
         }
-        public static function get supportsNativeCursor():Boolean {
-            stub_getter("flash.ui.Mouse", "supportsNativeCursor");
-            return true;
+        public static native function get supportsNativeCursor():Boolean;
+
+        public static function get cursor():String {
+            return _cursor;
+        }
+
+        public static function set cursor(value:String):void {
+            if (value == null) {
+                throw new TypeError("Error #2007: Parameter cursor must be non-null.", 2007);
+            }
+
+            var id:int = -1;
+            if (value != MouseCursor.AUTO && value != MouseCursor.ARROW && value != MouseCursor.BUTTON &&
+                value != MouseCursor.HAND && value != MouseCursor.IBEAM) {
+                if (!registeredCursors.hasOwnProperty(value)) {
+                    throw new ArgumentError("Error #2008: Parameter cursor must be one of the accepted values.", 2008);
+                }
+                id = registeredCursors[value];
+            }
+
+            _cursor = value;
+            setCursor(value, id);
         }
 
         public static function registerCursor(name:String, cursor:MouseCursorData):void {
-            stub_method("flash.ui.Mouse", "registerCursor");
+            if (name == null) {
+                throw new TypeError("Error #2007: Parameter name must be non-null.", 2007);
+            }
+            if (cursor == null) {
+                throw new TypeError("Error #2007: Parameter cursor must be non-null.", 2007);
+            }
+            if (cursor.data == null || cursor.data.length == 0) {
+                throw new ArgumentError("Error #2004: One of the parameters is invalid.", 2004);
+            }
+
+            var id:int;
+            if (registeredCursors.hasOwnProperty(name)) {
+                id = registeredCursors[name];
+            } else {
+                id = nextCursorId++;
+                registeredCursors[name] = id;
+            }
+
+            var hotSpot:Point = cursor.hotSpot;
+            registerCursorData(id, cursor.data, hotSpot.x, hotSpot.y, cursor.frameRate);
         }
 
         public static function unregisterCursor(name:String):void {
-            stub_method("flash.ui.Mouse", "unregisterCursor");
+            if (!registeredCursors.hasOwnProperty(name)) {
+                return;
+            }
+
+            var id:int = registeredCursors[name];
+            delete registeredCursors[name];
+            if (_cursor == name) {
+                cursor = MouseCursor.AUTO;
+            }
+            unregisterCursorData(id);
         }
+
+        private static native function setCursor(name:String, id:int):void;
+        private static native function registerCursorData(id:int, data:Vector.<BitmapData>, hotSpotX:Number, hotSpotY:Number, frameRate:Number):void;
+        private static native function unregisterCursorData(id:int):void;
     }
 }
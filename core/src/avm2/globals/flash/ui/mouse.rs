@@ -1,9 +1,12 @@
 //! `flash.ui.Mouse` builtin
 
 use crate::avm2::activation::Activation;
-use crate::avm2::object::Object;
+use crate::avm2::object::{Object, TObject};
+use crate::avm2::parameters::ParametersExt;
 use crate::avm2::value::Value;
 use crate::avm2::Error;
+use crate::backend::ui::{CursorFrame, CustomMouseCursor, MouseCursor};
+use crate::bitmap::bitmap_data::BitmapDataWrapper;
 
 pub fn hide<'gc>(
     activation: &mut Activation<'_, 'gc>,
@@ -22,3 +25,104 @@ pub fn show<'gc>(
     activation.context.ui.set_mouse_visible(true);
     Ok(Value::Undefined)
 }
+
+pub fn get_supports_native_cursor<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Option<Object<'gc>>,
+    _args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    Ok(activation.context.ui.supports_custom_mouse_cursor().into())
+}
+
+/// Implements the native part of the `Mouse.cursor` setter, once the cursor
+/// name has been validated.
+pub fn set_cursor<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let name = args.get_string(activation, 0)?;
+    let cursor = if &name == b"auto" {
+        None
+    } else if &name == b"arrow" {
+        Some(MouseCursor::Arrow)
+    } else if &name == b"button" {
+        Some(MouseCursor::Hand)
+    } else if &name == b"hand" {
+        Some(MouseCursor::Grab)
+    } else if &name == b"ibeam" {
+        Some(MouseCursor::IBeam)
+    } else {
+        Some(MouseCursor::Custom(args.get_u32(activation, 1)?))
+    };
+
+    activation
+        .context
+        .stage
+        .set_mouse_cursor_override(activation.context.gc_context, cursor);
+    Ok(Value::Undefined)
+}
+
+/// Copies the pixels of a `BitmapData` into a cursor frame.
+fn cursor_frame(bitmap_data: BitmapDataWrapper<'_>) -> CursorFrame {
+    let bitmap_data = bitmap_data.sync();
+    let bitmap_data = bitmap_data.read();
+    let mut rgba = Vec::with_capacity(bitmap_data.pixels().len() * 4);
+    for pixel in bitmap_data.pixels() {
+        let pixel = pixel.to_un_multiplied_alpha();
+        rgba.extend_from_slice(&[pixel.red(), pixel.green(), pixel.blue(), pixel.alpha()]);
+    }
+
+    CursorFrame {
+        width: bitmap_data.width(),
+        height: bitmap_data.height(),
+        rgba,
+    }
+}
+
+/// Implements the native part of `Mouse.registerCursor`, which passes the
+/// frames of a `MouseCursorData` to the UI backend.
+pub fn register_cursor_data<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let id = args.get_u32(activation, 0)?;
+    let data = args.get_object(activation, 1, "data")?;
+    let hot_spot_x = args.get_f64(activation, 2)?;
+    let hot_spot_y = args.get_f64(activation, 3)?;
+    let frame_rate = args.get_f64(activation, 4)?;
+
+    let bitmaps: Vec<Value<'gc>> = match data.as_vector_storage() {
+        Some(vector) => vector.iter().collect(),
+        None => vec![],
+    };
+    let frames = bitmaps
+        .into_iter()
+        .filter_map(|bitmap| bitmap.as_object().and_then(|b| b.as_bitmap_data()))
+        .map(cursor_frame)
+        .collect();
+
+    let cursor = CustomMouseCursor {
+        frames,
+        hot_spot: (hot_spot_x.max(0.0) as u32, hot_spot_y.max(0.0) as u32),
+        frame_rate: if frame_rate.is_finite() {
+            frame_rate.max(0.0)
+        } else {
+            0.0
+        },
+    };
+    activation.context.ui.register_mouse_cursor(id, cursor);
+    Ok(Value::Undefined)
+}
+
+/// Implements the native part of `Mouse.unregisterCursor`.
+pub fn unregister_cursor_data<'gc>(
+    activation: &mut Activation<'_, 'gc>,
+    _this: Option<Object<'gc>>,
+    args: &[Value<'gc>],
+) -> Result<Value<'gc>, Error<'gc>> {
+    let id = args.get_u32(activation, 0)?;
+    activation.context.ui.unregister_mouse_cursor(id);
+    Ok(Value::Undefined)
+}
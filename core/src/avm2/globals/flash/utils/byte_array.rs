@@ -9,7 +9,7 @@ use encoding_rs::Encoding;
 use encoding_rs::UTF_8;
 use flash_lso::amf0::read::AMF0Decoder;
 use flash_lso::amf3::read::AMF3Decoder;
-use flash_lso::types::{AMFVersion, Element};
+use flash_lso::types::AMFVersion;
 
 /// Writes a single byte to the bytearray
 pub fn write_byte<'gc>(
@@ -816,20 +816,9 @@ pub fn write_object<'gc>(
                 ObjectEncoding::Amf3 => AMFVersion::AMF3,
             };
             if let Some(amf) = crate::avm2::amf::serialize_value(activation, obj, amf_version) {
-                let element = Element::new("", amf);
-                let mut lso = flash_lso::types::Lso::new(vec![element], "", amf_version);
-                let bytes = flash_lso::write::write_to_bytes(&mut lso)
-                    .map_err(|_| "Failed to serialize object")?;
-                // This is kind of hacky: We need to strip out the header and any padding so that we only write
-                // the value. In the future, there should be a method to do this in the flash_lso crate.
-                let element_padding = match amf_version {
-                    AMFVersion::AMF0 => 8,
-                    AMFVersion::AMF3 => 7,
-                };
-                bytearray.write_bytes(
-                    &bytes[flash_lso::write::header_length(&lso.header) + element_padding
-                        ..bytes.len() - 1],
-                )?;
+                let bytes = crate::amf::write_value(amf, amf_version)
+                    .ok_or("Failed to serialize object")?;
+                bytearray.write_bytes(&bytes)?;
             }
         }
     }
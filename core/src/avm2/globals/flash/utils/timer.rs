@@ -71,6 +71,7 @@ pub fn start<'gc>(
             TimerCallback::Avm2Callback {
                 closure: on_update,
                 params: vec![],
+                domain: activation.caller_domain(),
             },
             delay as _,
             false,
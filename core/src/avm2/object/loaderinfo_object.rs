@@ -281,6 +281,33 @@ impl<'gc> LoaderInfoObject<'gc> {
     pub fn set_loader_stream(&self, stream: LoaderStream<'gc>, mc: MutationContext<'gc, '_>) {
         self.0.write(mc).loaded_stream = Some(stream);
     }
+
+    /// Allow the `init` and `complete` events to fire again for a new load.
+    pub fn reset_load_events(&self, mc: MutationContext<'gc, '_>) {
+        let mut write = self.0.write(mc);
+        write.init_event_fired = false;
+        write.complete_event_fired = false;
+    }
+
+    /// Reset this loader info after its content was unloaded, firing the
+    /// `unload` event if the content had been initialized.
+    pub fn unload(&self, context: &mut UpdateContext<'_, 'gc>) {
+        let empty_swf = Arc::new(SwfMovie::empty(context.swf.version()));
+        let was_initialized = {
+            let mut write = self.0.write(context.gc_context);
+            write.loaded_stream = Some(LoaderStream::NotYetLoaded(empty_swf, None, false));
+
+            // The unloaded content may still run for the rest of this frame,
+            // and must not fire any more events on this loader info.
+            write.complete_event_fired = true;
+            std::mem::replace(&mut write.init_event_fired, true)
+        };
+
+        if was_initialized {
+            let unload_evt = EventObject::bare_default_event(context, "unload");
+            Avm2::dispatch_event(context, unload_evt, (*self).into());
+        }
+    }
 }
 
 impl<'gc> TObject<'gc> for LoaderInfoObject<'gc> {
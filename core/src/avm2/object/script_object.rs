@@ -259,6 +259,11 @@ impl<'gc> ScriptObjectData<'gc> {
         }
     }
 
+    /// Retrieves the dynamic properties stored on this object.
+    pub fn values(&self) -> &FnvHashMap<AvmString<'gc>, Value<'gc>> {
+        &self.values
+    }
+
     pub fn get_slot(&self, id: u32) -> Result<Value<'gc>, Error<'gc>> {
         self.slots
             .get(id as usize)
@@ -29,6 +29,7 @@ pub fn sound_allocator<'gc>(
             sound_data: SoundData::NotLoaded {
                 queued_plays: Vec::new(),
             },
+            extract_position: 0,
         },
     ))
     .into())
@@ -58,6 +59,10 @@ pub struct SoundObjectData<'gc> {
 
     /// The sound this object holds.
     sound_data: SoundData<'gc>,
+
+    /// The 44.1KHz sample frame that `Sound.extract` continues from when no
+    /// start position is given.
+    extract_position: u32,
 }
 
 #[derive(Collect)]
@@ -92,6 +97,14 @@ impl<'gc> SoundObject<'gc> {
         }
     }
 
+    pub fn extract_position(self) -> u32 {
+        self.0.read().extract_position
+    }
+
+    pub fn set_extract_position(self, mc: MutationContext<'gc, '_>, position: u32) {
+        self.0.write(mc).extract_position = position;
+    }
+
     /// Returns `true` if a `SoundChannel` should be returned back to the AVM2 caller.
     pub fn play(
         self,
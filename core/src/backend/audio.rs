@@ -1,6 +1,10 @@
 use crate::{
     avm1::SoundObject,
-    avm2::{Avm2, EventObject as Avm2EventObject, SoundChannelObject},
+    avm2::{
+        Activation as Avm2Activation, Avm2, Error as Avm2Error, EventObject as Avm2EventObject,
+        Object as Avm2Object, SoundChannelObject, TObject as _,
+    },
+    buffer::Substream,
     context::UpdateContext,
     display_object::{self, DisplayObject, MovieClip, TDisplayObject},
 };
@@ -21,6 +25,10 @@ pub mod swf {
 mod mixer;
 #[cfg(feature = "audio")]
 pub use mixer::*;
+#[cfg(feature = "audio")]
+mod offline;
+#[cfg(feature = "audio")]
+pub use offline::{write_wav, OfflineAudioBackend};
 
 #[cfg(not(feature = "audio"))]
 mod decoders {
@@ -41,6 +49,41 @@ pub enum RegisterError {
     ShortMp3,
 }
 
+/// The format of a sound played by an `AudioBackend`.
+///
+/// AAC audio is only found in external media, such as FLV files or files loaded by
+/// `Sound.load`, and never in SWF sound tags, so it has no `swf::AudioCompression`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AudioFormat {
+    /// A sound in one of the formats that SWF files can hold.
+    Swf(swf::SoundFormat),
+
+    /// AAC audio, which is always 16-bit.
+    Aac { sample_rate: u16, is_stereo: bool },
+}
+
+impl AudioFormat {
+    pub fn sample_rate(&self) -> u16 {
+        match self {
+            AudioFormat::Swf(format) => format.sample_rate,
+            AudioFormat::Aac { sample_rate, .. } => *sample_rate,
+        }
+    }
+
+    pub fn is_stereo(&self) -> bool {
+        match self {
+            AudioFormat::Swf(format) => format.is_stereo,
+            AudioFormat::Aac { is_stereo, .. } => *is_stereo,
+        }
+    }
+}
+
+impl From<swf::SoundFormat> for AudioFormat {
+    fn from(format: swf::SoundFormat) -> Self {
+        AudioFormat::Swf(format)
+    }
+}
+
 pub trait AudioBackend: Downcast {
     fn play(&mut self);
     fn pause(&mut self);
@@ -51,6 +94,23 @@ pub trait AudioBackend: Downcast {
     /// Registers MP3 audio from an external source.
     fn register_mp3(&mut self, data: &[u8]) -> Result<SoundHandle, DecodeError>;
 
+    /// Registers ADTS-framed AAC audio from an external source.
+    fn register_aac(&mut self, data: &[u8]) -> Result<SoundHandle, DecodeError>;
+
+    /// Registers audio from an external source, such as a file loaded by
+    /// `Sound.load`, whose format is detected from its data.
+    fn register_external_sound(&mut self, data: &[u8]) -> Result<SoundHandle, DecodeError> {
+        if is_adts(data) {
+            self.register_aac(data)
+        } else {
+            self.register_mp3(data)
+        }
+    }
+
+    /// Forgets a registered sound, freeing its data.
+    /// Instances of the sound that are still playing are unaffected.
+    fn unregister_sound(&mut self, sound: SoundHandle);
+
     /// Plays a sound.
     fn start_sound(
         &mut self,
@@ -70,6 +130,53 @@ pub trait AudioBackend: Downcast {
         handle: &swf::SoundStreamHead,
     ) -> Result<SoundInstanceHandle, DecodeError>;
 
+    /// Starts playing a "substream" sound, which is an audio stream that is
+    /// delivered in chunks from an external source, such as the audio track of
+    /// an FLV file played by a `NetStream`.
+    ///
+    /// More data may be appended to `stream_data` while the sound is playing.
+    fn start_substream(
+        &mut self,
+        stream_data: Substream,
+        format: &AudioFormat,
+    ) -> Result<SoundInstanceHandle, DecodeError>;
+
+    /// Starts playing a "dynamic" sound, whose audio is generated by ActionScript
+    /// in response to `sampleData` events.
+    ///
+    /// The sound plays silence until samples are appended with
+    /// `append_dynamic_sound_samples`.
+    fn start_dynamic_sound(&mut self) -> Result<SoundInstanceHandle, DecodeError>;
+
+    /// Appends 44.1KHz stereo sample frames to a playing dynamic sound.
+    ///
+    /// If `end_of_stream` is set, the sound will end once all of its queued
+    /// samples have been played.
+    fn append_dynamic_sound_samples(
+        &mut self,
+        instance: SoundInstanceHandle,
+        samples: &[[f32; 2]],
+        end_of_stream: bool,
+    );
+
+    /// Get the number of sample frames queued in a dynamic sound that have not
+    /// been played yet.
+    /// Returns `None` if the sound is not/no longer playing.
+    fn get_dynamic_sound_buffered(&self, instance: SoundInstanceHandle) -> Option<usize>;
+
+    /// Decodes up to `num_sample_frames` sample frames of a registered sound,
+    /// starting at `start_sample_frame`, as used by `Sound.extract`.
+    ///
+    /// Positions and the returned samples are always in 44.1KHz stereo,
+    /// regardless of the format of the sound.
+    /// Returns `None` if the sound is not registered or could not be decoded.
+    fn extract_sound(
+        &mut self,
+        sound: SoundHandle,
+        start_sample_frame: u32,
+        num_sample_frames: usize,
+    ) -> Option<Vec<[f32; 2]>>;
+
     /// Stops a playing sound instance.
     /// No-op if the sound is not playing.
     fn stop_sound(&mut self, sound: SoundInstanceHandle);
@@ -91,7 +198,7 @@ pub trait AudioBackend: Downcast {
     fn get_sound_size(&self, sound: SoundHandle) -> Option<u32>;
 
     /// Get the sound format that a given sound was added with.
-    fn get_sound_format(&self, sound: SoundHandle) -> Option<&swf::SoundFormat>;
+    fn get_sound_format(&self, sound: SoundHandle) -> Option<&AudioFormat>;
 
     /// Set the volume transform for a sound instance.
     fn set_sound_transform(&mut self, instance: SoundInstanceHandle, transform: SoundTransform);
@@ -144,7 +251,7 @@ struct NullSound {
     size: u32,
 
     /// The stated format of the sound data.
-    format: swf::SoundFormat,
+    format: AudioFormat,
 }
 
 /// Audio backend that ignores all audio.
@@ -181,7 +288,7 @@ impl AudioBackend for NullAudioBackend {
         Ok(self.sounds.insert(NullSound {
             duration,
             size: data.len() as u32,
-            format: sound.format.clone(),
+            format: sound.format.clone().into(),
         }))
     }
 
@@ -189,15 +296,30 @@ impl AudioBackend for NullAudioBackend {
         Ok(self.sounds.insert(NullSound {
             size: 0,
             duration: 0.0,
-            format: swf::SoundFormat {
+            format: AudioFormat::Swf(swf::SoundFormat {
                 compression: swf::AudioCompression::Mp3,
                 sample_rate: 44100,
                 is_stereo: true,
                 is_16_bit: true,
+            }),
+        }))
+    }
+
+    fn register_aac(&mut self, _data: &[u8]) -> Result<SoundHandle, DecodeError> {
+        Ok(self.sounds.insert(NullSound {
+            size: 0,
+            duration: 0.0,
+            format: AudioFormat::Aac {
+                sample_rate: 44100,
+                is_stereo: true,
             },
         }))
     }
 
+    fn unregister_sound(&mut self, sound: SoundHandle) {
+        self.sounds.remove(sound);
+    }
+
     fn start_sound(
         &mut self,
         _sound: SoundHandle,
@@ -216,6 +338,39 @@ impl AudioBackend for NullAudioBackend {
         Ok(SoundInstanceHandle::from_raw_parts(0, 0))
     }
 
+    fn start_substream(
+        &mut self,
+        _stream_data: Substream,
+        _format: &AudioFormat,
+    ) -> Result<SoundInstanceHandle, DecodeError> {
+        Ok(SoundInstanceHandle::from_raw_parts(0, 0))
+    }
+
+    fn start_dynamic_sound(&mut self) -> Result<SoundInstanceHandle, DecodeError> {
+        Ok(SoundInstanceHandle::from_raw_parts(0, 0))
+    }
+
+    fn append_dynamic_sound_samples(
+        &mut self,
+        _instance: SoundInstanceHandle,
+        _samples: &[[f32; 2]],
+        _end_of_stream: bool,
+    ) {
+    }
+
+    fn get_dynamic_sound_buffered(&self, _instance: SoundInstanceHandle) -> Option<usize> {
+        None
+    }
+
+    fn extract_sound(
+        &mut self,
+        _sound: SoundHandle,
+        _start_sample_frame: u32,
+        _num_sample_frames: usize,
+    ) -> Option<Vec<[f32; 2]>> {
+        None
+    }
+
     fn stop_sound(&mut self, _sound: SoundInstanceHandle) {}
 
     fn stop_all_sounds(&mut self) {}
@@ -237,7 +392,7 @@ impl AudioBackend for NullAudioBackend {
         }
     }
 
-    fn get_sound_format(&self, sound: SoundHandle) -> Option<&swf::SoundFormat> {
+    fn get_sound_format(&self, sound: SoundHandle) -> Option<&AudioFormat> {
         self.sounds.get(sound).map(|s| &s.format)
     }
 
@@ -301,6 +456,13 @@ impl<'gc> AudioManager<'gc> {
     /// The player will adjust animation speed to stay within this many seconds of the audio track.
     pub const STREAM_DEFAULT_SYNC_THRESHOLD: f64 = 0.2;
 
+    /// The minimum number of sample frames a `sampleData` handler must provide
+    /// to keep a dynamic sound playing.
+    pub const MIN_SAMPLE_DATA_FRAMES: usize = 2048;
+
+    /// The minimum number of sample frames to keep queued for a dynamic sound.
+    pub const DYNAMIC_SOUND_BUFFER_FRAMES: usize = 8192;
+
     pub fn new() -> Self {
         Self {
             sounds: Vec::with_capacity(Self::MAX_SOUNDS),
@@ -313,7 +475,7 @@ impl<'gc> AudioManager<'gc> {
     /// Update state of active sounds. Should be called once per frame.
     pub fn update_sounds(context: &mut UpdateContext<'_, 'gc>) {
         let Some(root) = context.stage.root_clip() else {
-            return
+            return;
         };
 
         // We can't use 'context' to construct an event inside the
@@ -364,6 +526,8 @@ impl<'gc> AudioManager<'gc> {
             Avm2::dispatch_event(context, event, target.into());
         }
 
+        Self::update_dynamic_sounds(context);
+
         // Update sound transforms, if dirty.
         context.audio_manager.update_sound_transforms(context.audio);
     }
@@ -386,6 +550,7 @@ impl<'gc> AudioManager<'gc> {
                 avm1_object,
                 avm2_object: None,
                 stream_start_frame: None,
+                dynamic_sound: None,
             };
             audio.set_sound_transform(handle, self.transform_for_sound(&instance));
             self.sounds.push(instance);
@@ -395,6 +560,137 @@ impl<'gc> AudioManager<'gc> {
         }
     }
 
+    /// Starts a dynamic sound that requests its audio from the `sampleData`
+    /// event of the given AVM2 `Sound` object.
+    pub fn start_dynamic_sound(
+        &mut self,
+        audio: &mut dyn AudioBackend,
+        target: Avm2Object<'gc>,
+    ) -> Option<SoundInstanceHandle> {
+        if self.sounds.len() < Self::MAX_SOUNDS {
+            let handle = audio.start_dynamic_sound().ok()?;
+            let instance = SoundInstance {
+                sound: None,
+                instance: handle,
+                display_object: None,
+                transform: display_object::SoundTransform::default(),
+                avm1_object: None,
+                avm2_object: None,
+                stream_start_frame: None,
+                dynamic_sound: Some(DynamicSound {
+                    target,
+                    position: 0.0,
+                }),
+            };
+            audio.set_sound_transform(handle, self.transform_for_sound(&instance));
+            self.sounds.push(instance);
+            Some(handle)
+        } else {
+            None
+        }
+    }
+
+    /// Refill the queues of all playing dynamic sounds that are running low,
+    /// by dispatching `sampleData` events.
+    fn update_dynamic_sounds(context: &mut UpdateContext<'_, 'gc>) {
+        // Keep at least two frames' worth of audio queued, so that the sound
+        // doesn't run dry at low frame rates.
+        let frame_rate = context.frame_rate.max(1.0);
+        let buffer_frames =
+            Self::DYNAMIC_SOUND_BUFFER_FRAMES.max((2.0 * 44100.0 / frame_rate) as usize);
+
+        let dynamic_sounds: Vec<_> = context
+            .audio_manager
+            .sounds
+            .iter()
+            .filter_map(|sound| Some((sound.instance, sound.dynamic_sound.clone()?)))
+            .collect();
+
+        for (instance, mut dynamic_sound) in dynamic_sounds {
+            let mut ended = false;
+            while let Some(buffered) = context.audio.get_dynamic_sound_buffered(instance) {
+                if buffered >= buffer_frames {
+                    break;
+                }
+
+                let samples = Self::request_sample_data(
+                    context,
+                    dynamic_sound.target,
+                    dynamic_sound.position,
+                );
+                dynamic_sound.position += samples.len() as f64;
+
+                // Providing too few samples ends the sound once the queue is drained.
+                ended = samples.len() < Self::MIN_SAMPLE_DATA_FRAMES;
+                context
+                    .audio
+                    .append_dynamic_sound_samples(instance, &samples, ended);
+                if ended {
+                    break;
+                }
+            }
+
+            // The sound may have been stopped by the event handler.
+            if let Some(sound) = context
+                .audio_manager
+                .sounds
+                .iter_mut()
+                .find(|sound| sound.instance == instance)
+            {
+                sound.dynamic_sound = (!ended).then_some(dynamic_sound);
+            }
+        }
+    }
+
+    /// Dispatch a `sampleData` event to an AVM2 `Sound` object, and return the
+    /// stereo sample frames that the event handlers wrote into the event's `data`.
+    fn request_sample_data(
+        context: &mut UpdateContext<'_, 'gc>,
+        target: Avm2Object<'gc>,
+        position: f64,
+    ) -> Vec<[f32; 2]> {
+        let mut activation = Avm2Activation::from_nothing(context.reborrow());
+        let result: Result<_, Avm2Error<'gc>> = (|| {
+            let data = activation
+                .avm2()
+                .classes()
+                .bytearray
+                .construct(&mut activation, &[])?;
+            let event = activation.avm2().classes().sampledataevent.construct(
+                &mut activation,
+                &[
+                    "sampleData".into(),
+                    false.into(),
+                    false.into(),
+                    position.into(),
+                    data.into(),
+                ],
+            )?;
+            Ok((event, data))
+        })();
+
+        let (event, data) = match result {
+            Ok(result) => result,
+            Err(e) => {
+                tracing::error!("Error creating SampleDataEvent: {:?}", e);
+                return vec![];
+            }
+        };
+        Avm2::dispatch_event(&mut activation.context, event, target);
+
+        let Some(data) = data.as_bytearray() else {
+            return vec![];
+        };
+        // Each sample frame is a pair of 32-bit floats, in the byte order of the `ByteArray`.
+        (0..data.len() / 8)
+            .filter_map(|i| {
+                let left = data.read_float_at(i * 8).ok()?;
+                let right = data.read_float_at(i * 8 + 4).ok()?;
+                Some([left, right])
+            })
+            .collect()
+    }
+
     pub fn attach_avm2_sound_channel(
         &mut self,
         instance: SoundInstanceHandle,
@@ -483,6 +779,33 @@ impl<'gc> AudioManager<'gc> {
                 avm1_object: None,
                 avm2_object: None,
                 stream_start_frame: Some(clip_frame),
+                dynamic_sound: None,
+            };
+            audio.set_sound_transform(handle, self.transform_for_sound(&instance));
+            self.sounds.push(instance);
+            Some(handle)
+        } else {
+            None
+        }
+    }
+
+    pub fn start_substream(
+        &mut self,
+        audio: &mut dyn AudioBackend,
+        stream_data: Substream,
+        format: &AudioFormat,
+    ) -> Option<SoundInstanceHandle> {
+        if self.sounds.len() < Self::MAX_SOUNDS {
+            let handle = audio.start_substream(stream_data, format).ok()?;
+            let instance = SoundInstance {
+                sound: None,
+                instance: handle,
+                display_object: None,
+                transform: display_object::SoundTransform::default(),
+                avm1_object: None,
+                avm2_object: None,
+                stream_start_frame: None,
+                dynamic_sound: None,
             };
             audio.set_sound_transform(handle, self.transform_for_sound(&instance));
             self.sounds.push(instance);
@@ -540,6 +863,11 @@ impl<'gc> AudioManager<'gc> {
         }
     }
 
+    /// Returns the list of actively playing sounds.
+    pub fn sounds(&self) -> &[SoundInstance<'gc>] {
+        &self.sounds
+    }
+
     pub fn global_sound_transform(&self) -> &display_object::SoundTransform {
         &self.global_sound_transform
     }
@@ -665,6 +993,40 @@ pub struct SoundInstance<'gc> {
     avm2_object: Option<SoundChannelObject<'gc>>,
 
     stream_start_frame: Option<u16>,
+
+    /// The state of this sound, if it is a dynamic sound that is still
+    /// requesting audio from ActionScript.
+    dynamic_sound: Option<DynamicSound<'gc>>,
+}
+
+impl<'gc> SoundInstance<'gc> {
+    pub fn instance(&self) -> SoundInstanceHandle {
+        self.instance
+    }
+
+    pub fn sound(&self) -> Option<SoundHandle> {
+        self.sound
+    }
+
+    pub fn display_object(&self) -> Option<DisplayObject<'gc>> {
+        self.display_object
+    }
+
+    pub fn transform(&self) -> &display_object::SoundTransform {
+        &self.transform
+    }
+}
+
+/// A sound whose audio is generated by ActionScript, by listening to the
+/// `sampleData` event of an AVM2 `Sound` object.
+#[derive(Clone, Collect)]
+#[collect(no_drop)]
+struct DynamicSound<'gc> {
+    /// The `Sound` object that `sampleData` events are dispatched to.
+    target: Avm2Object<'gc>,
+
+    /// The number of sample frames that have been requested so far.
+    position: f64,
 }
 
 /// A sound transform for a playing sound, for use by audio backends.
@@ -704,3 +1066,10 @@ impl Default for SoundTransform {
         }
     }
 }
+
+/// Returns whether the given data starts with an ADTS frame header.
+pub(crate) fn is_adts(data: &[u8]) -> bool {
+    // ADTS frames start with a 12-bit sync word followed by a layer of 0,
+    // which MP3 frames never use.
+    data.len() >= 2 && data[0] == 0xFF && data[1] & 0xF6 == 0xF0
+}
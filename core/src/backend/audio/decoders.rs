@@ -1,5 +1,7 @@
 //! Audio decoders.
 
+#[cfg(feature = "aac")]
+mod aac;
 mod adpcm;
 #[cfg(feature = "mp3")]
 mod mp3;
@@ -7,6 +9,8 @@ mod mp3;
 mod nellymoser;
 mod pcm;
 
+#[cfg(feature = "aac")]
+pub use aac::{aac_metadata, AacDecoder};
 pub use adpcm::AdpcmDecoder;
 #[cfg(feature = "mp3")]
 pub use mp3::{mp3_metadata, Mp3Decoder};
@@ -14,13 +18,20 @@ pub use mp3::{mp3_metadata, Mp3Decoder};
 pub use nellymoser::NellymoserDecoder;
 pub use pcm::PcmDecoder;
 
+use crate::backend::audio::AudioFormat;
+use crate::buffer::{Substream, SubstreamChunks};
 use crate::tag_utils::{ControlFlow, SwfSlice};
 use std::io::{Cursor, Read};
+use std::sync::Arc;
 use swf::{AudioCompression, SoundFormat, TagCode};
 use thiserror::Error;
 
 #[derive(Debug, Error)]
 pub enum Error {
+    #[cfg(feature = "aac")]
+    #[error("Couldn't decode AAC")]
+    InvalidAac(#[from] aac::Error),
+
     #[cfg(feature = "mp3")]
     #[error("Couldn't decode MP3")]
     InvalidMp3(#[from] mp3::Error),
@@ -30,6 +41,9 @@ pub enum Error {
 
     #[error("Unhandled compression {0:?}")]
     UnhandledCompression(AudioCompression),
+
+    #[error("Unhandled compression AAC")]
+    UnhandledAac,
 }
 
 /// An audio decoder. Can be used as an `Iterator` to return stero sample frames.
@@ -218,6 +232,86 @@ pub fn make_stream_decoder(
     Ok(decoder)
 }
 
+/// Substream sounds encoded with ADPCM have an ADPCM header in each chunk, just like
+/// `SoundStreamBlock` tags. The decoder is recreated for each chunk of the substream.
+pub struct AdpcmSubstreamDecoder {
+    format: SoundFormat,
+    chunks: SubstreamChunks,
+    decoder: AdpcmDecoder<Cursor<Arc<[u8]>>>,
+}
+
+impl AdpcmSubstreamDecoder {
+    fn new(format: &SoundFormat, stream_data: Substream) -> Result<Self, Error> {
+        let mut chunks = stream_data.chunks();
+        let audio_data = chunks.next().unwrap_or_else(|| Arc::from([]));
+        let decoder = AdpcmDecoder::new(
+            Cursor::new(audio_data),
+            format.is_stereo,
+            format.sample_rate,
+        )?;
+        Ok(Self {
+            format: format.clone(),
+            chunks,
+            decoder,
+        })
+    }
+}
+
+impl Decoder for AdpcmSubstreamDecoder {
+    fn num_channels(&self) -> u8 {
+        self.decoder.num_channels()
+    }
+    fn sample_rate(&self) -> u16 {
+        self.decoder.sample_rate()
+    }
+}
+
+impl Iterator for AdpcmSubstreamDecoder {
+    type Item = [i16; 2];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(sample_frame) = self.decoder.next() {
+            Some(sample_frame)
+        } else if let Some(audio_data) = self.chunks.next() {
+            // We've exhausted the current chunk, so recreate the decoder
+            // to read the ADPCM header of the next one.
+            self.decoder = AdpcmDecoder::new(
+                Cursor::new(audio_data),
+                self.format.is_stereo,
+                self.format.sample_rate,
+            )
+            .ok()?;
+            self.decoder.next()
+        } else {
+            None
+        }
+    }
+}
+
+/// Makes a decoder for a `Substream` of audio data, such as the audio track of an FLV file.
+///
+/// AAC substreams consist of raw AAC frames, one per chunk, preceded by a chunk holding
+/// their `AudioSpecificConfig`.
+///
+/// Decoding stops once all of the data appended to the substream so far has been consumed.
+pub fn make_substream_decoder(
+    format: &AudioFormat,
+    stream_data: Substream,
+) -> Result<Box<dyn Decoder + Send>, Error> {
+    let decoder: Box<dyn Decoder + Send> = match format {
+        AudioFormat::Swf(format) if format.compression == AudioCompression::Adpcm => {
+            Box::new(AdpcmSubstreamDecoder::new(format, stream_data)?)
+        }
+        AudioFormat::Swf(format) => Box::new(make_decoder(format, stream_data.reader())?),
+        #[cfg(feature = "aac")]
+        AudioFormat::Aac { .. } => Box::new(AacDecoder::new_raw(stream_data)?),
+        #[cfg(not(feature = "aac"))]
+        AudioFormat::Aac { .. } => return Err(Error::UnhandledAac),
+    };
+    Ok(decoder)
+}
+
 /// Adds seeking ability to decoders where the underline stream is `std::io::Seek`.
 pub trait SeekableDecoder: Decoder {
     /// Resets the decoder to the beginning of the stream.
@@ -0,0 +1,461 @@
+use crate::backend::audio::decoders::{Decoder, SeekableDecoder};
+use crate::backend::audio::is_adts;
+use crate::buffer::{Substream, SubstreamChunks};
+use std::io::{Cursor, Read};
+use symphonia::{
+    core::{
+        self, audio, codecs, errors,
+        formats::{self, FormatReader},
+        io,
+    },
+    default::formats::AdtsReader as SymphoniaAdtsReader,
+};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("Couldn't decode AAC frame")]
+    FrameDecode(#[from] errors::Error),
+
+    #[error("No default track")]
+    NoDefaultTrack,
+
+    #[error("Invalid AudioSpecificConfig")]
+    InvalidConfig,
+
+    #[error("Invalid ADTS frame")]
+    InvalidAdtsFrame,
+
+    #[error("Invalid sample rate")]
+    InvalidSampleRate,
+
+    #[error("Invalid channels")]
+    InvalidChannels,
+}
+
+/// The sample rates that AAC streams refer to by index.
+const SAMPLE_RATES: [u32; 13] = [
+    96000, 88200, 64000, 48000, 44100, 32000, 24000, 22050, 16000, 12000, 11025, 8000, 7350,
+];
+
+/// Where an `AacDecoder` gets its packets from.
+enum PacketSource {
+    /// An ADTS stream, as found in `.aac` files.
+    Adts(SymphoniaAdtsReader),
+
+    /// Raw AAC frames, one per chunk, as found in FLV audio tags.
+    Raw(SubstreamChunks),
+}
+
+impl PacketSource {
+    fn next_packet(&mut self) -> Option<formats::Packet> {
+        match self {
+            PacketSource::Adts(reader) => reader.next_packet().ok(),
+            PacketSource::Raw(chunks) => chunks
+                .next()
+                .map(|frame| formats::Packet::new_from_slice(0, 0, 0, &frame)),
+        }
+    }
+}
+
+pub struct AacDecoder {
+    source: PacketSource,
+    decoder: Box<dyn codecs::Decoder>,
+    sample_buf: audio::SampleBuffer<i16>,
+    /// The number of interleaved channels in `sample_buf`.
+    buffer_channels: usize,
+    cur_sample: usize,
+    sample_rate: u16,
+    num_channels: u8,
+    stream_ended: bool,
+}
+
+impl AacDecoder {
+    // AAC-LC frames contain 1024 samples.
+    const SAMPLE_BUFFER_DURATION: u64 = 1024;
+
+    /// Creates a decoder for ADTS-framed AAC data.
+    pub fn new<R: 'static + Read + Send + Sync>(reader: R) -> Result<Self, Error> {
+        let source = Box::new(io::ReadOnlySource::new(reader)) as Box<dyn io::MediaSource>;
+        Self::from_adts_source(source)
+    }
+
+    /// Creates a seekable decoder for ADTS-framed AAC data.
+    pub fn new_seekable<R: 'static + AsRef<[u8]> + Send + Sync>(
+        reader: Cursor<R>,
+    ) -> Result<Self, Error> {
+        Self::from_adts_source(Box::new(reader) as Box<dyn io::MediaSource>)
+    }
+
+    fn from_adts_source(source: Box<dyn io::MediaSource>) -> Result<Self, Error> {
+        let source = io::MediaSourceStream::new(source, Default::default());
+        let reader = SymphoniaAdtsReader::try_new(source, &Default::default())?;
+        let track = reader.default_track().ok_or(Error::NoDefaultTrack)?;
+        let codec_params = track.codec_params.clone();
+        let sample_rate = codec_params.sample_rate.ok_or(Error::InvalidSampleRate)?;
+        let channels = codec_params.channels.ok_or(Error::InvalidChannels)?;
+        Self::from_source(
+            PacketSource::Adts(reader),
+            &codec_params,
+            sample_rate,
+            channels.count(),
+        )
+    }
+
+    /// Creates a decoder for a substream of raw AAC frames.
+    ///
+    /// The first chunk of the substream must be the `AudioSpecificConfig`
+    /// describing the frames, and each following chunk a single frame.
+    pub fn new_raw(stream_data: Substream) -> Result<Self, Error> {
+        let mut chunks = stream_data.chunks();
+        let config = chunks.next().ok_or(Error::InvalidConfig)?;
+        let (sample_rate, channels) = read_audio_specific_config(&config)?;
+        let mut codec_params = codecs::CodecParameters::new();
+        codec_params
+            .for_codec(codecs::CODEC_TYPE_AAC)
+            .with_sample_rate(sample_rate)
+            .with_extra_data(config.to_vec().into_boxed_slice());
+        Self::from_source(
+            PacketSource::Raw(chunks),
+            &codec_params,
+            sample_rate,
+            channels,
+        )
+    }
+
+    fn from_source(
+        source: PacketSource,
+        codec_params: &codecs::CodecParameters,
+        sample_rate: u32,
+        channels: usize,
+    ) -> Result<Self, Error> {
+        if channels == 0 {
+            return Err(Error::InvalidChannels);
+        }
+        let decoder = symphonia::default::get_codecs().make(codec_params, &Default::default())?;
+        Ok(AacDecoder {
+            source,
+            decoder,
+            sample_buf: audio::SampleBuffer::new(
+                Self::SAMPLE_BUFFER_DURATION,
+                audio::SignalSpec::new(sample_rate, audio::Channels::FRONT_LEFT),
+            ),
+            buffer_channels: 1,
+            cur_sample: 0,
+            // Any channels after the front pair are dropped.
+            num_channels: if channels == 1 { 1 } else { 2 },
+            sample_rate: sample_rate
+                .try_into()
+                .map_err(|_| Error::InvalidSampleRate)?,
+            stream_ended: false,
+        })
+    }
+
+    fn next_frame(&mut self) {
+        if self.stream_ended {
+            return;
+        }
+
+        self.cur_sample = 0;
+        while let Some(packet) = self.source.next_packet() {
+            match self.decoder.decode(&packet) {
+                Ok(decoded) => {
+                    let buffer_channels = decoded.spec().channels.count();
+                    if self.sample_buf.capacity() < decoded.capacity() * buffer_channels {
+                        // Ensure our buffer has enough space for the decoded samples.
+                        self.sample_buf = audio::SampleBuffer::new(
+                            decoded.capacity() as core::units::Duration,
+                            *decoded.spec(),
+                        );
+                    }
+                    self.buffer_channels = buffer_channels;
+                    self.sample_buf.copy_interleaved_ref(decoded);
+                    return;
+                }
+                // Decode errors are not fatal.
+                Err(errors::Error::DecodeError(_)) => (),
+                Err(_) => break,
+            }
+        }
+        // EOF reached.
+        self.stream_ended = true;
+    }
+}
+
+impl Iterator for AacDecoder {
+    type Item = [i16; 2];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        // Decoded frames may be empty, so keep going until we have samples.
+        while self.cur_sample >= self.sample_buf.len() {
+            self.next_frame();
+            if self.stream_ended {
+                return None;
+            }
+        }
+
+        let samples = &self.sample_buf.samples()[self.cur_sample..];
+        self.cur_sample += self.buffer_channels;
+        if self.buffer_channels >= 2 {
+            Some([samples[0], samples[1]])
+        } else {
+            Some([samples[0], samples[0]])
+        }
+    }
+}
+
+impl SeekableDecoder for AacDecoder {
+    #[inline]
+    fn reset(&mut self) {
+        self.seek_to_sample_frame(0);
+    }
+
+    #[inline]
+    fn seek_to_sample_frame(&mut self, frame: u32) {
+        let PacketSource::Adts(reader) = &mut self.source else {
+            return;
+        };
+
+        // Seek to the desired position,
+        let seek_result = reader.seek(
+            formats::SeekMode::Accurate,
+            formats::SeekTo::TimeStamp {
+                track_id: 0,
+                ts: frame.into(),
+            },
+        );
+        self.sample_buf.clear();
+        self.decoder.reset();
+        self.cur_sample = 0;
+        self.stream_ended = false;
+        // Seeking isn't exact, so we may end up slightly before our desired position.
+        // Pump samples until we get to the exact position.
+        let samples_remaining =
+            seek_result.map_or(0, |seek| seek.required_ts.saturating_sub(seek.actual_ts));
+        for _ in 0..samples_remaining {
+            self.next();
+        }
+    }
+}
+
+impl Decoder for AacDecoder {
+    #[inline]
+    fn num_channels(&self) -> u8 {
+        self.num_channels
+    }
+
+    #[inline]
+    fn sample_rate(&self) -> u16 {
+        self.sample_rate
+    }
+}
+
+/// Reads the sample rate and channel count from an AAC `AudioSpecificConfig`.
+fn read_audio_specific_config(config: &[u8]) -> Result<(u32, usize), Error> {
+    // The fields we need fit in the first 6 bytes.
+    if config.len() < 2 {
+        return Err(Error::InvalidConfig);
+    }
+    let mut bytes = [0; 8];
+    let len = config.len().min(bytes.len());
+    bytes[..len].copy_from_slice(&config[..len]);
+    let bits = u64::from_be_bytes(bytes);
+    let mut pos = 0;
+    let mut read_bits = |count: u32| {
+        let value = (bits << pos) >> (64 - count);
+        pos += count;
+        value as u32
+    };
+
+    if read_bits(5) == 31 {
+        // Escaped audio object type.
+        read_bits(6);
+    }
+    let sample_rate = match read_bits(4) {
+        15 => read_bits(24),
+        index => *SAMPLE_RATES
+            .get(index as usize)
+            .ok_or(Error::InvalidSampleRate)?,
+    };
+    let channels = match read_bits(4) {
+        // A channel configuration of 0 is defined in the bitstream, which we don't support.
+        0 => return Err(Error::InvalidChannels),
+        7 => 8,
+        config @ 1..=6 => config as usize,
+        _ => return Err(Error::InvalidChannels),
+    };
+    Ok((sample_rate, channels))
+}
+
+#[derive(Debug)]
+pub struct AacMetadata {
+    pub sample_rate: u16,
+    pub num_channels: u8,
+    pub num_sample_frames: u32,
+}
+
+/// Returns the sample rate and length of the given ADTS-framed AAC data.
+pub fn aac_metadata(data: &[u8]) -> Result<AacMetadata, Error> {
+    let mut metadata: Option<AacMetadata> = None;
+    let mut pos = 0;
+    while pos + 7 <= data.len() {
+        let header = &data[pos..pos + 7];
+        if !is_adts(header) {
+            break;
+        }
+
+        let frame_length = (usize::from(header[3] & 0x3) << 11)
+            | (usize::from(header[4]) << 3)
+            | usize::from(header[5] >> 5);
+        if frame_length < 7 {
+            return Err(Error::InvalidAdtsFrame);
+        }
+        let num_sample_frames = 1024 * (u32::from(header[6] & 0x3) + 1);
+
+        if let Some(metadata) = &mut metadata {
+            metadata.num_sample_frames += num_sample_frames;
+        } else {
+            let sample_rate = *SAMPLE_RATES
+                .get(usize::from((header[2] >> 2) & 0xF))
+                .ok_or(Error::InvalidSampleRate)?;
+            let channels = ((header[2] & 0x1) << 2) | (header[3] >> 6);
+            metadata = Some(AacMetadata {
+                sample_rate: sample_rate as u16,
+                num_channels: channels,
+                num_sample_frames,
+            });
+        }
+
+        pos += frame_length;
+    }
+    metadata.ok_or(Error::InvalidAdtsFrame)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::backend::audio::decoders::aac::{
+        aac_metadata, read_audio_specific_config, AacDecoder, Error,
+    };
+    use crate::backend::audio::decoders::Decoder;
+    use crate::backend::audio::{swf, AudioBackend, AudioFormat, NullAudioBackend};
+    use crate::buffer::Substream;
+    use std::io::Cursor;
+
+    /// An AAC-LC `AudioSpecificConfig` for 44100Hz mono.
+    const MONO_CONFIG: [u8; 2] = [0x12, 0x08];
+
+    /// A silent mono AAC-LC frame holding a single empty channel element.
+    const SILENT_FRAME: [u8; 4] = [0x01, 0x40, 0x20, 0x07];
+
+    /// An ADTS header for a 44100Hz mono frame containing `SILENT_FRAME`.
+    const ADTS_HEADER: [u8; 7] = [0xFF, 0xF1, 0x50, 0x40, 0x01, 0x7F, 0xFC];
+
+    fn adts_stream(num_frames: usize) -> Vec<u8> {
+        let mut data = vec![];
+        for _ in 0..num_frames {
+            data.extend_from_slice(&ADTS_HEADER);
+            data.extend_from_slice(&SILENT_FRAME);
+        }
+        data
+    }
+
+    #[test]
+    fn test_read_audio_specific_config() {
+        assert_eq!(
+            read_audio_specific_config(&MONO_CONFIG).unwrap(),
+            (44100, 1)
+        );
+        assert_eq!(
+            read_audio_specific_config(&[0x12, 0x10]).unwrap(),
+            (44100, 2)
+        );
+        assert_eq!(
+            read_audio_specific_config(&[0x15, 0x88]).unwrap(),
+            (8000, 1)
+        );
+        assert!(matches!(
+            read_audio_specific_config(&[0x12]),
+            Err(Error::InvalidConfig)
+        ));
+        assert!(matches!(
+            read_audio_specific_config(&[0x12, 0x00]),
+            Err(Error::InvalidChannels)
+        ));
+        assert!(matches!(
+            read_audio_specific_config(&[0x16, 0x88]),
+            Err(Error::InvalidSampleRate)
+        ));
+    }
+
+    #[test]
+    fn test_raw_substream() {
+        let stream = Substream::new();
+        stream.append(&MONO_CONFIG);
+        stream.append(&SILENT_FRAME);
+        stream.append(&SILENT_FRAME);
+
+        let decoder = AacDecoder::new_raw(stream).unwrap();
+        assert_eq!(decoder.sample_rate(), 44100);
+        assert_eq!(decoder.num_channels(), 1);
+        let samples: Vec<_> = decoder.collect();
+        assert_eq!(samples.len(), 2 * 1024);
+        assert!(samples.iter().all(|&sample| sample == [0, 0]));
+    }
+
+    #[test]
+    fn test_raw_substream_without_config() {
+        assert!(matches!(
+            AacDecoder::new_raw(Substream::new()),
+            Err(Error::InvalidConfig)
+        ));
+    }
+
+    #[test]
+    fn test_adts_stream() {
+        let decoder = AacDecoder::new_seekable(Cursor::new(adts_stream(2))).unwrap();
+        assert_eq!(decoder.sample_rate(), 44100);
+        assert_eq!(decoder.num_channels(), 1);
+        assert_eq!(decoder.count(), 2 * 1024);
+    }
+
+    #[test]
+    fn test_aac_metadata() {
+        let metadata = aac_metadata(&adts_stream(3)).unwrap();
+        assert_eq!(metadata.sample_rate, 44100);
+        assert_eq!(metadata.num_channels, 1);
+        assert_eq!(metadata.num_sample_frames, 3 * 1024);
+
+        assert!(matches!(
+            aac_metadata(&[0xFF, 0xFB, 0x90, 0x64, 0x00, 0x00, 0x00]),
+            Err(Error::InvalidAdtsFrame)
+        ));
+    }
+
+    #[test]
+    fn test_register_external_sound() {
+        let mut backend = NullAudioBackend::new();
+        let mut format = |data: &[u8]| {
+            let handle = backend.register_external_sound(data).unwrap();
+            backend.get_sound_format(handle).unwrap().clone()
+        };
+
+        assert!(matches!(format(&adts_stream(1)), AudioFormat::Aac { .. }));
+        // MPEG-1 Layer III frame header.
+        assert!(matches!(
+            format(&[0xFF, 0xFB, 0x90, 0x64]),
+            AudioFormat::Swf(swf::SoundFormat {
+                compression: swf::AudioCompression::Mp3,
+                ..
+            })
+        ));
+        // MP3 files often start with an ID3 tag.
+        assert!(matches!(
+            format(b"ID3\x04\x00"),
+            AudioFormat::Swf(swf::SoundFormat {
+                compression: swf::AudioCompression::Mp3,
+                ..
+            })
+        ));
+    }
+}
@@ -1,8 +1,10 @@
 use super::decoders::{self, AdpcmDecoder, Decoder, PcmDecoder, SeekableDecoder};
 use super::{SoundHandle, SoundInstanceHandle, SoundTransform};
-use crate::backend::audio::{DecodeError, RegisterError};
+use crate::backend::audio::{AudioFormat, DecodeError, RegisterError};
+use crate::buffer::Substream;
 use crate::tag_utils::SwfSlice;
 use generational_arena::Arena;
+use std::collections::{HashMap, VecDeque};
 use std::io::Cursor;
 use std::sync::{Arc, Mutex, RwLock};
 use swf::AudioCompression;
@@ -69,6 +71,17 @@ pub struct AudioMixer {
 
     /// The last two windows of output samples.
     output_memory: Arc<RwLock<CircBuf>>,
+
+    /// Decoders used by `extract_sound`, kept around so that extracting a
+    /// sound in consecutive chunks doesn't decode it from the start each time.
+    extract_streams: HashMap<SoundHandle, ExtractStream>,
+}
+
+/// A resampled stream of a registered sound, and the position in 44.1KHz
+/// sample frames that it will continue from.
+struct ExtractStream {
+    stream: Box<dyn Stream>,
+    position: u32,
 }
 
 /// An audio stream.
@@ -141,11 +154,11 @@ impl<D: Decoder> dasp::signal::Signal for DecoderStream<D> {
 /// A sound is defined by the `DefineSound` SWF tags and contains the audio data for the sound.
 struct Sound {
     /// The format of the sound, including sample rate and compression format.
-    format: swf::SoundFormat,
+    format: AudioFormat,
 
     /// The audio data of this sound.
     ///
-    /// This will be compressed in the format indicated by `format.compression`.
+    /// This will be compressed in the format indicated by `format`.
     data: Arc<[u8]>,
 
     /// Number of samples in this audio.
@@ -156,7 +169,7 @@ struct Sound {
     ///
     /// MP3 encoders introduce some silence at the beginning of the audio due to encoder delay.
     /// `skip_sample_frames` indicates how many sample frames to skip to bypass the delay.
-    /// This is `0` unless `format.compression` is `AudioCompression::Mp3`.
+    /// This is `0` unless the sound is compressed with `AudioCompression::Mp3`.
     skip_sample_frames: u16,
 }
 
@@ -194,6 +207,9 @@ struct SoundInstance {
     /// (respectively) of this sound over the buffer currently being
     /// mixed. Used to compute `peak`, and is reset after every time.
     range: ([f32; 2], [f32; 2]),
+
+    /// The queue that samples are appended to, if this is a dynamic sound.
+    dynamic_queue: Option<Arc<Mutex<DynamicSoundQueue>>>,
 }
 
 impl SoundInstance {
@@ -207,6 +223,7 @@ impl SoundInstance {
             right_transform: [0.0, 1.0],
             peak: [0.0, 0.0],
             range: ([std::f32::INFINITY; 2], [std::f32::NEG_INFINITY; 2]),
+            dynamic_queue: None,
         }
     }
 
@@ -220,6 +237,15 @@ impl SoundInstance {
             right_transform: [0.0, 1.0],
             peak: [0.0, 0.0],
             range: ([std::f32::INFINITY; 2], [std::f32::NEG_INFINITY; 2]),
+            dynamic_queue: None,
+        }
+    }
+
+    /// Creates a new `SoundInstance` from a `Stream`, for dynamic sounds.
+    fn new_dynamic(stream: Box<dyn Stream>, queue: Arc<Mutex<DynamicSoundQueue>>) -> Self {
+        SoundInstance {
+            dynamic_queue: Some(queue),
+            ..Self::new_stream(stream)
         }
     }
 
@@ -242,6 +268,7 @@ impl AudioMixer {
             num_output_channels,
             output_sample_rate,
             output_memory: Arc::new(RwLock::new(CircBuf::new())),
+            extract_streams: HashMap::new(),
         }
     }
 
@@ -291,9 +318,18 @@ impl AudioMixer {
     ///  * "Event" sounds on the timeline with custom start/loop settings
     ///  * ActionScript sounds that may have a custom start and loop setting
     fn make_seekable_decoder(
-        format: &swf::SoundFormat,
+        format: &AudioFormat,
         data: Cursor<ArcAsRef>,
     ) -> Result<Box<dyn SeekableDecoder>, decoders::Error> {
+        let format = match format {
+            AudioFormat::Swf(format) => format,
+            #[cfg(feature = "aac")]
+            AudioFormat::Aac { .. } => {
+                return Ok(Box::new(decoders::AacDecoder::new_seekable(data)?))
+            }
+            #[cfg(not(feature = "aac"))]
+            AudioFormat::Aac { .. } => return Err(decoders::Error::UnhandledAac),
+        };
         let decoder: Box<dyn SeekableDecoder> = match format.compression {
             AudioCompression::UncompressedUnknownEndian => {
                 // Cross fingers that it's little endian.
@@ -329,17 +365,22 @@ impl AudioMixer {
     }
 
     /// Transforms a `Stream` into a new `Stream` that matches the output sample rate.
-    fn make_resampler(&self, mut stream: impl Stream) -> impl Stream {
+    fn make_resampler(&self, stream: impl Stream) -> impl Stream {
+        Self::make_resampler_to(stream, self.output_sample_rate)
+    }
+
+    /// Transforms a `Stream` into a new `Stream` with the given sample rate.
+    fn make_resampler_to(mut stream: impl Stream, sample_rate: u32) -> impl Stream {
         // TODO: Allow interpolator to be user-configurable?
         let left = stream.next();
         let right = stream.next();
         let interpolator = dasp::interpolate::linear::Linear::new(left, right);
-        let sample_rate = stream.source_sample_rate().into();
+        let source_sample_rate = stream.source_sample_rate().into();
         ConverterStream(dasp::signal::interpolate::Converter::from_hz_to_hz(
             stream,
             interpolator,
-            sample_rate,
-            self.output_sample_rate.into(),
+            source_sample_rate,
+            sample_rate.into(),
         ))
     }
 
@@ -380,11 +421,17 @@ impl AudioMixer {
     /// Otherwise, `AudioMixer::make_stream_from_event_sound` should be used.
     fn make_stream_from_simple_event_sound<R: 'static + std::io::Read + Send + Sync>(
         &self,
-        format: &swf::SoundFormat,
+        format: &AudioFormat,
         data_stream: R,
     ) -> Result<Box<dyn Stream>, DecodeError> {
         // Instantiate a decoder for the compression that the sound data uses.
-        let decoder = decoders::make_decoder(format, data_stream)?;
+        let decoder: Box<dyn Decoder> = match format {
+            AudioFormat::Swf(format) => decoders::make_decoder(format, data_stream)?,
+            #[cfg(feature = "aac")]
+            AudioFormat::Aac { .. } => Box::new(decoders::AacDecoder::new(data_stream)?),
+            #[cfg(not(feature = "aac"))]
+            AudioFormat::Aac { .. } => return Err(decoders::Error::UnhandledAac),
+        };
 
         // Convert the `Decoder` to a `Stream`, and resample it to output sample rate.
         let stream = DecoderStream::new(decoder);
@@ -407,6 +454,21 @@ impl AudioMixer {
         Ok(stream)
     }
 
+    /// Creates a `Stream` that decodes and resamples an audio substream.
+    fn make_stream_from_substream<'a>(
+        &self,
+        format: &AudioFormat,
+        stream_data: Substream,
+    ) -> Result<Box<dyn 'a + Stream>, DecodeError> {
+        // Instantiate a decoder for the compression that the sound data uses.
+        let substream_decoder = decoders::make_substream_decoder(format, stream_data)?;
+
+        // Convert the `Decoder` to a `Stream`, and resample it to the output sample rate.
+        let stream = DecoderStream::new(substream_decoder);
+        let stream = Box::new(self.make_resampler(stream));
+        Ok(stream)
+    }
+
     /// Callback to the audio thread.
     /// Refill the output buffer by stepping through all active sounds
     /// and mixing in their output.
@@ -500,7 +562,7 @@ impl AudioMixer {
         };
 
         let sound = Sound {
-            format: swf_sound.format.clone(),
+            format: swf_sound.format.clone().into(),
             data: Arc::from(data),
             num_sample_frames: swf_sound.num_samples,
             skip_sample_frames,
@@ -515,12 +577,12 @@ impl AudioMixer {
         // Validate that this is actually MP3 data, and calculate duration and sample rate.
         let metadata = decoders::mp3_metadata(&data)?;
         let sound = Sound {
-            format: swf::SoundFormat {
+            format: AudioFormat::Swf(swf::SoundFormat {
                 compression: AudioCompression::Mp3,
                 sample_rate: metadata.sample_rate,
                 is_stereo: true,
                 is_16_bit: true,
-            },
+            }),
             data,
             num_sample_frames: metadata.num_sample_frames,
             skip_sample_frames: 0,
@@ -533,6 +595,36 @@ impl AudioMixer {
         Err(decoders::Error::UnhandledCompression(AudioCompression::Mp3))
     }
 
+    /// Registers an external ADTS-framed AAC sound with the audio mixer.
+    #[cfg(feature = "aac")]
+    pub fn register_aac(&mut self, data: &[u8]) -> Result<SoundHandle, DecodeError> {
+        // Validate that this is actually AAC data, and calculate duration and sample rate.
+        let metadata = decoders::aac_metadata(data)?;
+        let sound = Sound {
+            format: AudioFormat::Aac {
+                sample_rate: metadata.sample_rate,
+                is_stereo: metadata.num_channels != 1,
+            },
+            data: Arc::from(data),
+            num_sample_frames: metadata.num_sample_frames,
+            skip_sample_frames: 0,
+        };
+        Ok(self.sounds.insert(sound))
+    }
+
+    #[cfg(not(feature = "aac"))]
+    pub fn register_aac(&mut self, _data: &[u8]) -> Result<SoundHandle, DecodeError> {
+        Err(decoders::Error::UnhandledAac)
+    }
+
+    /// Removes a registered sound from the audio mixer.
+    ///
+    /// Playing instances of the sound keep their own reference to its data.
+    pub fn unregister_sound(&mut self, sound: SoundHandle) {
+        self.sounds.remove(sound);
+        self.extract_streams.remove(&sound);
+    }
+
     /// Starts a timeline audio stream.
     pub fn start_stream(
         &mut self,
@@ -554,6 +646,24 @@ impl AudioMixer {
         Ok(handle)
     }
 
+    /// Starts an audio substream.
+    ///
+    /// The substream may still be appended to while it plays.
+    pub fn start_substream(
+        &mut self,
+        stream_data: Substream,
+        format: &AudioFormat,
+    ) -> Result<SoundInstanceHandle, DecodeError> {
+        let stream = self.make_stream_from_substream(format, stream_data)?;
+
+        let mut sound_instances = self
+            .sound_instances
+            .lock()
+            .expect("Cannot be called reentrant");
+        let handle = sound_instances.insert(SoundInstance::new_stream(stream));
+        Ok(handle)
+    }
+
     /// Starts a sound.
     ///
     /// The sound must have been registered using `AudioMixer::register_sound`.
@@ -587,6 +697,128 @@ impl AudioMixer {
         Ok(handle)
     }
 
+    /// Starts a dynamic sound, which plays samples as they are appended with
+    /// `AudioMixer::append_dynamic_sound_samples`.
+    pub fn start_dynamic_sound(&mut self) -> Result<SoundInstanceHandle, DecodeError> {
+        let queue = Arc::new(Mutex::new(DynamicSoundQueue::default()));
+        let stream = DynamicSoundStream::new(Arc::clone(&queue));
+        let stream = Box::new(self.make_resampler(stream));
+
+        let mut sound_instances = self
+            .sound_instances
+            .lock()
+            .expect("Cannot be called reentrant");
+        let handle = sound_instances.insert(SoundInstance::new_dynamic(stream, queue));
+        Ok(handle)
+    }
+
+    /// Appends 44.1KHz sample frames to a playing dynamic sound.
+    pub fn append_dynamic_sound_samples(
+        &mut self,
+        instance: SoundInstanceHandle,
+        samples: &[[f32; 2]],
+        end_of_stream: bool,
+    ) {
+        use dasp::Sample;
+
+        let sound_instances = self
+            .sound_instances
+            .lock()
+            .expect("Cannot be called reentrant");
+        if let Some(queue) = sound_instances
+            .get(instance)
+            .and_then(|instance| instance.dynamic_queue.as_ref())
+        {
+            let mut queue = queue.lock().expect("Cannot be called reentrant");
+            queue.frames.extend(
+                samples
+                    .iter()
+                    .map(|[left, right]| [left.to_sample(), right.to_sample()]),
+            );
+            queue.end_of_stream |= end_of_stream;
+        }
+    }
+
+    /// Returns the number of sample frames queued in a dynamic sound that have not been
+    /// played yet.
+    ///
+    /// Returns `None` if the sound is no longer playing.
+    pub fn get_dynamic_sound_buffered(&self, instance: SoundInstanceHandle) -> Option<usize> {
+        let sound_instances = self
+            .sound_instances
+            .lock()
+            .expect("Cannot be called reentrant");
+        let queue = sound_instances.get(instance)?.dynamic_queue.as_ref()?;
+        let queue = queue.lock().expect("Cannot be called reentrant");
+        Some(queue.frames.len())
+    }
+
+    /// Decodes part of a registered sound into 44.1KHz stereo sample frames.
+    ///
+    /// The decoder is kept after each call, so that extracting a sound in
+    /// consecutive chunks only decodes it once.
+    /// Returns `None` if the sound is not registered or could not be decoded.
+    pub fn extract_sound(
+        &mut self,
+        sound_handle: SoundHandle,
+        start_sample_frame: u32,
+        num_sample_frames: usize,
+    ) -> Option<Vec<[f32; 2]>> {
+        use dasp::{signal::Signal, Sample};
+
+        // The sample rate that `Sound.extract` always produces.
+        const EXTRACT_SAMPLE_RATE: u32 = 44100;
+
+        let sound = self.sounds.get(sound_handle)?;
+        let sound_sample_frames = (f64::from(sound.num_sample_frames)
+            * f64::from(EXTRACT_SAMPLE_RATE)
+            / f64::from(sound.format.sample_rate())) as u32;
+        let Some(remaining) = sound_sample_frames.checked_sub(start_sample_frame) else {
+            return Some(vec![]);
+        };
+        let num_sample_frames = num_sample_frames.min(remaining as usize);
+
+        let cached = self
+            .extract_streams
+            .get(&sound_handle)
+            .map_or(false, |extract| extract.position == start_sample_frame);
+        if !cached {
+            // Seek by starting a new stream at the requested position.
+            let data = Cursor::new(ArcAsRef(Arc::clone(&sound.data)));
+            let decoder = Self::make_seekable_decoder(&sound.format, data).ok()?;
+            let settings = swf::SoundInfo {
+                event: swf::SoundEvent::Start,
+                in_sample: Some(start_sample_frame),
+                out_sample: None,
+                num_loops: 1,
+                envelope: None,
+            };
+            let stream = EventSoundStream::new_with_settings(
+                decoder,
+                &settings,
+                sound.num_sample_frames,
+                sound.skip_sample_frames,
+            );
+            let stream = Box::new(Self::make_resampler_to(stream, EXTRACT_SAMPLE_RATE));
+            self.extract_streams.insert(
+                sound_handle,
+                ExtractStream {
+                    stream,
+                    position: start_sample_frame,
+                },
+            );
+        }
+
+        let extract = self.extract_streams.get_mut(&sound_handle)?;
+        let mut samples = Vec::with_capacity(num_sample_frames);
+        while samples.len() < num_sample_frames && !extract.stream.is_exhausted() {
+            let [left, right] = extract.stream.next();
+            samples.push([left.to_sample(), right.to_sample()]);
+        }
+        extract.position += samples.len() as u32;
+        Some(samples)
+    }
+
     /// Stops a playing sound instance.
     pub fn stop_sound(&mut self, sound: SoundInstanceHandle) {
         let mut sound_instances = self
@@ -642,7 +874,7 @@ impl AudioMixer {
         if let Some(sound) = self.sounds.get(sound) {
             // AS duration does not subtract `skip_sample_frames`.
             let num_sample_frames: f64 = sound.num_sample_frames.into();
-            let sample_rate: f64 = sound.format.sample_rate.into();
+            let sample_rate: f64 = sound.format.sample_rate().into();
             let ms = num_sample_frames * 1000.0 / sample_rate;
             Some(ms)
         } else {
@@ -654,7 +886,7 @@ impl AudioMixer {
         self.sounds.get(sound).map(|s| s.data.len() as u32)
     }
 
-    pub fn get_sound_format(&self, sound: SoundHandle) -> Option<&swf::SoundFormat> {
+    pub fn get_sound_format(&self, sound: SoundHandle) -> Option<&AudioFormat> {
         self.sounds.get(sound).map(|s| &s.format)
     }
 
@@ -846,6 +1078,74 @@ impl Stream for EventSoundStream {
     }
 }
 
+/// The samples queued for a dynamic sound, shared between the `AudioMixer` and the
+/// sound's `DynamicSoundStream`.
+#[derive(Default)]
+struct DynamicSoundQueue {
+    /// The queued 44.1KHz sample frames that have not been played yet.
+    frames: VecDeque<[i16; 2]>,
+
+    /// Whether no more samples will be appended to this queue.
+    end_of_stream: bool,
+}
+
+/// A stream for dynamic sounds, whose samples are generated by ActionScript while the
+/// sound plays.
+///
+/// Plays silence whenever the queue runs dry, and ends once the end of the stream has
+/// been signalled and all queued samples have been played.
+struct DynamicSoundStream {
+    queue: Arc<Mutex<DynamicSoundQueue>>,
+    position: u32,
+    is_exhausted: bool,
+}
+
+impl DynamicSoundStream {
+    /// The sample rate of all dynamic sounds.
+    const SAMPLE_RATE: u16 = 44100;
+
+    fn new(queue: Arc<Mutex<DynamicSoundQueue>>) -> Self {
+        Self {
+            queue,
+            position: 0,
+            is_exhausted: false,
+        }
+    }
+}
+
+impl dasp::signal::Signal for DynamicSoundStream {
+    type Frame = [i16; 2];
+
+    #[inline]
+    fn next(&mut self) -> Self::Frame {
+        let mut queue = self.queue.lock().expect("Cannot be called reentrant");
+        if let Some(frame) = queue.frames.pop_front() {
+            self.position += 1;
+            frame
+        } else {
+            self.is_exhausted = queue.end_of_stream;
+            [0, 0]
+        }
+    }
+
+    #[inline]
+    fn is_exhausted(&self) -> bool {
+        self.is_exhausted
+    }
+}
+
+impl Stream for DynamicSoundStream {
+    #[inline]
+    fn source_position(&self) -> u32 {
+        self.position
+    }
+
+    #[inline]
+    fn source_sample_rate(&self) -> u16 {
+        Self::SAMPLE_RATE
+    }
+}
+
 /// A stream that converts a source stream to a different sample rate.
 struct ConverterStream<S, I>(dasp::signal::interpolate::Converter<S, I>)
 where
@@ -1050,6 +1350,16 @@ macro_rules! impl_audio_mixer_backend {
             self.$mixer.register_mp3(data)
         }
 
+        #[inline]
+        fn register_aac(&mut self, data: &[u8]) -> Result<SoundHandle, DecodeError> {
+            self.$mixer.register_aac(data)
+        }
+
+        #[inline]
+        fn unregister_sound(&mut self, sound: SoundHandle) {
+            self.$mixer.unregister_sound(sound)
+        }
+
         #[inline]
         fn start_stream(
             &mut self,
@@ -1062,6 +1372,15 @@ macro_rules! impl_audio_mixer_backend {
                 .start_stream(stream_handle, clip_frame, clip_data, stream_info)
         }
 
+        #[inline]
+        fn start_substream(
+            &mut self,
+            stream_data: $crate::buffer::Substream,
+            format: &$crate::backend::audio::AudioFormat,
+        ) -> Result<SoundInstanceHandle, DecodeError> {
+            self.$mixer.start_substream(stream_data, format)
+        }
+
         #[inline]
         fn start_sound(
             &mut self,
@@ -1071,6 +1390,38 @@ macro_rules! impl_audio_mixer_backend {
             self.$mixer.start_sound(sound_handle, settings)
         }
 
+        #[inline]
+        fn start_dynamic_sound(&mut self) -> Result<SoundInstanceHandle, DecodeError> {
+            self.$mixer.start_dynamic_sound()
+        }
+
+        #[inline]
+        fn append_dynamic_sound_samples(
+            &mut self,
+            instance: SoundInstanceHandle,
+            samples: &[[f32; 2]],
+            end_of_stream: bool,
+        ) {
+            self.$mixer
+                .append_dynamic_sound_samples(instance, samples, end_of_stream)
+        }
+
+        #[inline]
+        fn get_dynamic_sound_buffered(&self, instance: SoundInstanceHandle) -> Option<usize> {
+            self.$mixer.get_dynamic_sound_buffered(instance)
+        }
+
+        #[inline]
+        fn extract_sound(
+            &mut self,
+            sound: SoundHandle,
+            start_sample_frame: u32,
+            num_sample_frames: usize,
+        ) -> Option<Vec<[f32; 2]>> {
+            self.$mixer
+                .extract_sound(sound, start_sample_frame, num_sample_frames)
+        }
+
         #[inline]
         fn stop_sound(&mut self, sound: SoundInstanceHandle) {
             self.$mixer.stop_sound(sound)
@@ -1097,7 +1448,10 @@ macro_rules! impl_audio_mixer_backend {
         }
 
         #[inline]
-        fn get_sound_format(&self, sound: SoundHandle) -> Option<&swf::SoundFormat> {
+        fn get_sound_format(
+            &self,
+            sound: SoundHandle,
+        ) -> Option<&$crate::backend::audio::AudioFormat> {
             self.$mixer.get_sound_format(sound)
         }
 
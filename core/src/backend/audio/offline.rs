@@ -0,0 +1,123 @@
+use super::{
+    swf, AudioBackend, AudioMixer, DecodeError, RegisterError, SoundHandle, SoundInstanceHandle,
+    SoundTransform,
+};
+use crate::impl_audio_mixer_backend;
+use std::io::{self, Write};
+
+/// An audio backend that renders audio without any output device, for exporting or
+/// testing the audio of a movie.
+///
+/// Instead of being driven by a real-time audio callback, this mixes exactly one frame's
+/// worth of audio every time it is ticked. The owner of the player must call
+/// `AudioBackend::tick` once after every `Player::run_frame` to keep the audio in lockstep
+/// with the movie, which makes the output deterministic.
+pub struct OfflineAudioBackend {
+    mixer: AudioMixer,
+    frame_rate: f64,
+
+    /// The fraction of an audio frame (one sample per channel) that is still owed
+    /// from previous ticks.
+    pending_frames: f64,
+
+    /// The mixed audio, as interleaved stereo samples.
+    samples: Vec<i16>,
+}
+
+impl OfflineAudioBackend {
+    pub const NUM_CHANNELS: u8 = 2;
+    pub const SAMPLE_RATE: u32 = 44100;
+
+    pub fn new() -> Self {
+        Self {
+            mixer: AudioMixer::new(Self::NUM_CHANNELS, Self::SAMPLE_RATE),
+            frame_rate: 1.0,
+            pending_frames: 0.0,
+            samples: vec![],
+        }
+    }
+
+    /// The audio mixed so far, as interleaved stereo samples.
+    pub fn samples(&self) -> &[i16] {
+        &self.samples
+    }
+
+    /// Takes all of the audio that was mixed since the last call.
+    pub fn take_samples(&mut self) -> Vec<i16> {
+        std::mem::take(&mut self.samples)
+    }
+
+    /// Writes the audio mixed so far as a 16-bit PCM WAV file.
+    pub fn write_wav<W: Write>(&self, writer: W) -> io::Result<()> {
+        write_wav(
+            writer,
+            &self.samples,
+            Self::NUM_CHANNELS.into(),
+            Self::SAMPLE_RATE,
+        )
+    }
+}
+
+impl Default for OfflineAudioBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AudioBackend for OfflineAudioBackend {
+    impl_audio_mixer_backend!(mixer);
+    fn play(&mut self) {}
+    fn pause(&mut self) {}
+
+    fn set_frame_rate(&mut self, frame_rate: f64) {
+        self.frame_rate = frame_rate;
+    }
+
+    fn tick(&mut self) {
+        // Movie frames rarely last a whole number of samples, so carry the remainder over
+        // to the next tick to avoid drifting out of sync.
+        self.pending_frames += f64::from(Self::SAMPLE_RATE) / self.frame_rate;
+        let new_frames = self.pending_frames.floor();
+        self.pending_frames -= new_frames;
+
+        let start = self.samples.len();
+        self.samples.resize(
+            start + new_frames as usize * usize::from(Self::NUM_CHANNELS),
+            0,
+        );
+        self.mixer.mix::<i16>(&mut self.samples[start..]);
+    }
+}
+
+/// Writes interleaved 16-bit samples as a PCM WAV file.
+pub fn write_wav<W: Write>(
+    mut writer: W,
+    samples: &[i16],
+    num_channels: u16,
+    sample_rate: u32,
+) -> io::Result<()> {
+    let data_size = u32::try_from(samples.len() * 2)
+        .ok()
+        .filter(|size| *size <= u32::MAX - 36)
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "Too much audio for WAV"))?;
+    let block_align = num_channels * 2;
+
+    writer.write_all(b"RIFF")?;
+    writer.write_all(&(36 + data_size).to_le_bytes())?;
+    writer.write_all(b"WAVE")?;
+
+    writer.write_all(b"fmt ")?;
+    writer.write_all(&16u32.to_le_bytes())?;
+    writer.write_all(&1u16.to_le_bytes())?; // PCM
+    writer.write_all(&num_channels.to_le_bytes())?;
+    writer.write_all(&sample_rate.to_le_bytes())?;
+    writer.write_all(&(sample_rate * u32::from(block_align)).to_le_bytes())?;
+    writer.write_all(&block_align.to_le_bytes())?;
+    writer.write_all(&16u16.to_le_bytes())?; // Bits per sample
+
+    writer.write_all(b"data")?;
+    writer.write_all(&data_size.to_le_bytes())?;
+    let data: Vec<u8> = samples.iter().flat_map(|s| s.to_le_bytes()).collect();
+    writer.write_all(&data)?;
+    writer.flush()
+}
@@ -1,6 +1,7 @@
 //! Browser-related platform functions
 
 use crate::loader::Error;
+use crate::socket::{ConnectionState, SocketAction, SocketHandle};
 use crate::string::WStr;
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
@@ -8,6 +9,8 @@ use std::fmt;
 use std::future::Future;
 use std::path::{Path, PathBuf};
 use std::pin::Pin;
+use std::sync::mpsc::{Receiver, Sender};
+use std::time::Duration;
 use swf::avm1::types::SendVarsMethod;
 use url::Url;
 
@@ -221,6 +224,24 @@ pub trait NavigatorBackend {
     /// Changing http -> https for example. This function may alter any part of the
     /// URL (generally only if configured to do so by the user).
     fn pre_process_url(&self, url: Url) -> Url;
+
+    /// Open a TCP connection to the given host and port.
+    ///
+    /// The outcome of the connection attempt, any data received from the
+    /// server, and the server closing the connection must all be reported
+    /// through `sender`, tagged with `handle`.
+    ///
+    /// Data to be written to the connection arrives through `receiver`. Once
+    /// its sending half is dropped, the connection should be closed.
+    fn connect_socket(
+        &mut self,
+        host: String,
+        port: u16,
+        timeout: Duration,
+        handle: SocketHandle,
+        receiver: Receiver<Vec<u8>>,
+        sender: Sender<SocketAction>,
+    );
 }
 
 #[cfg(not(target_family = "wasm"))]
@@ -372,4 +393,16 @@ impl NavigatorBackend for NullNavigatorBackend {
     fn pre_process_url(&self, url: Url) -> Url {
         url
     }
+
+    fn connect_socket(
+        &mut self,
+        _host: String,
+        _port: u16,
+        _timeout: Duration,
+        handle: SocketHandle,
+        _receiver: Receiver<Vec<u8>>,
+        sender: Sender<SocketAction>,
+    ) {
+        let _ = sender.send(SocketAction::Connect(handle, ConnectionState::Failed));
+    }
 }
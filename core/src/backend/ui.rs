@@ -1,13 +1,22 @@
 use crate::events::{KeyCode, PlayerEvent, TextControlCode};
+use chrono::{DateTime, Utc};
 use downcast_rs::Downcast;
 use fluent_templates::loader::langid;
 pub use fluent_templates::LanguageIdentifier;
 use std::borrow::Cow;
 use std::collections::HashSet;
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
 
 pub type FullscreenError = Cow<'static, str>;
 pub static US_ENGLISH: LanguageIdentifier = langid!("en-US");
 
+/// A future that resolves once the user closes a file dialog.
+///
+/// The output is `None` if the user cancelled the dialog.
+pub type FileDialogFuture<T> = Pin<Box<dyn Future<Output = Option<T>> + 'static>>;
+
 pub trait UiBackend: Downcast {
     fn mouse_visible(&self) -> bool;
 
@@ -16,6 +25,19 @@ pub trait UiBackend: Downcast {
     /// Changes the mouse cursor image.
     fn set_mouse_cursor(&mut self, cursor: MouseCursor);
 
+    /// Whether this backend can display cursors registered with
+    /// `register_mouse_cursor`, as reported by `Mouse.supportsNativeCursor`.
+    fn supports_custom_mouse_cursor(&self) -> bool;
+
+    /// Registers a bitmap cursor, which can then be displayed by passing
+    /// `MouseCursor::Custom(id)` to `set_mouse_cursor`.
+    ///
+    /// Registering a cursor with an `id` that is already in use replaces it.
+    fn register_mouse_cursor(&mut self, id: u32, cursor: CustomMouseCursor);
+
+    /// Forgets a cursor registered with `register_mouse_cursor`.
+    fn unregister_mouse_cursor(&mut self, id: u32);
+
     /// Get the clipboard content
     fn clipboard_content(&mut self) -> String;
 
@@ -40,9 +62,94 @@ pub trait UiBackend: Downcast {
     fn open_virtual_keyboard(&self);
 
     fn language(&self) -> &LanguageIdentifier;
+
+    /// Displays a dialog for the user to pick one (or, if `multiple` is set,
+    /// several) files to open, as used by `FileReference.browse`.
+    ///
+    /// Only files matching one of `filters` should be selectable, unless
+    /// `filters` is empty.
+    ///
+    /// Returns `None` if this backend can't display file dialogs right now.
+    fn display_file_open_dialog(
+        &mut self,
+        filters: Vec<FileFilter>,
+        multiple: bool,
+    ) -> Option<FileDialogFuture<Vec<LoadedFile>>>;
+
+    /// Displays a dialog for the user to pick where to save a file, as used
+    /// by `FileReference.save` and `FileReference.download`.
+    ///
+    /// `file_name` is the name that the dialog suggests.
+    ///
+    /// Returns `None` if this backend can't display file dialogs right now.
+    fn display_file_save_dialog(
+        &mut self,
+        file_name: String,
+    ) -> Option<FileDialogFuture<Box<dyn SaveFile>>>;
 }
 impl_downcast!(UiBackend);
 
+/// A filter for the files shown in a file-open dialog.
+///
+/// Equivalent to AS3 `FileFilter`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FileFilter {
+    /// The description of the filter shown to the user, such as `Images`.
+    pub description: String,
+
+    /// A semicolon-separated list of patterns, such as `*.jpg;*.png`.
+    pub extensions: String,
+
+    /// A semicolon-separated list of Mac file types, if any.
+    pub mac_type: Option<String>,
+}
+
+impl FileFilter {
+    /// The extensions allowed by this filter, without their `*.` prefix.
+    pub fn extension_list(&self) -> impl Iterator<Item = &str> {
+        self.extensions
+            .split(';')
+            .map(|pattern| {
+                let pattern = pattern.trim();
+                pattern.strip_prefix("*.").unwrap_or(pattern)
+            })
+            .filter(|extension| !extension.is_empty() && *extension != "*")
+    }
+}
+
+/// A file picked by the user in a file-open dialog, read into memory.
+#[derive(Clone, Debug, Default)]
+pub struct LoadedFile {
+    /// The name of the file, without its directory.
+    pub name: String,
+
+    /// The contents of the file.
+    pub contents: Vec<u8>,
+
+    /// When the file was created, if known.
+    pub creation_date: Option<DateTime<Utc>>,
+
+    /// When the file was last modified, if known.
+    pub modification_date: Option<DateTime<Utc>>,
+}
+
+impl LoadedFile {
+    /// The extension of the file, including its leading dot, as reported by
+    /// `FileReference.type`.
+    pub fn file_type(&self) -> Option<&str> {
+        self.name.rfind('.').map(|i| &self.name[i..])
+    }
+}
+
+/// A file picked by the user in a file-save dialog.
+pub trait SaveFile {
+    /// The name of the file, without its directory.
+    fn name(&self) -> String;
+
+    /// Replaces the contents of the file.
+    fn write(&mut self, data: &[u8]) -> io::Result<()>;
+}
+
 /// A mouse cursor icon displayed by the Flash Player.
 /// Communicated from the core to the UI backend via `UiBackend::set_mouse_cursor`.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -62,6 +169,48 @@ pub enum MouseCursor {
     /// The grabby-dragging hand icon.
     /// Equivalent to AS3 `MouseCursor.HAND`.
     Grab,
+
+    /// A bitmap cursor registered with `UiBackend::register_mouse_cursor`.
+    /// Equivalent to a cursor registered with AS3 `Mouse.registerCursor`.
+    Custom(u32),
+}
+
+/// A bitmap mouse cursor, possibly animated.
+///
+/// Equivalent to AS3 `MouseCursorData`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CustomMouseCursor {
+    /// The images of the cursor, played in order.
+    pub frames: Vec<CursorFrame>,
+
+    /// The point of the cursor that clicks, relative to the top-left corner
+    /// of each frame, in pixels.
+    pub hot_spot: (u32, u32),
+
+    /// The number of frames shown per second, if the cursor is animated.
+    pub frame_rate: f64,
+}
+
+impl CustomMouseCursor {
+    /// The index of the frame that should be shown once `elapsed` seconds
+    /// have passed since the cursor was first displayed.
+    pub fn frame_index(&self, elapsed: f64) -> usize {
+        if self.frames.len() > 1 && self.frame_rate > 0.0 {
+            (elapsed * self.frame_rate) as usize % self.frames.len()
+        } else {
+            0
+        }
+    }
+}
+
+/// A single image of a `CustomMouseCursor`.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct CursorFrame {
+    pub width: u32,
+    pub height: u32,
+
+    /// The pixels of the image, as unmultiplied RGBA.
+    pub rgba: Vec<u8>,
 }
 
 pub struct InputManager {
@@ -111,6 +260,8 @@ impl InputManager {
             }
             PlayerEvent::MouseDown { button, .. } => self.add_key(button.into()),
             PlayerEvent::MouseUp { button, .. } => self.remove_key(button.into()),
+            // Keys released while the window isn't focused never get reported.
+            PlayerEvent::FocusLost => self.keys_down.clear(),
             _ => {}
         }
     }
@@ -160,6 +311,14 @@ impl UiBackend for NullUiBackend {
 
     fn set_mouse_cursor(&mut self, _cursor: MouseCursor) {}
 
+    fn supports_custom_mouse_cursor(&self) -> bool {
+        false
+    }
+
+    fn register_mouse_cursor(&mut self, _id: u32, _cursor: CustomMouseCursor) {}
+
+    fn unregister_mouse_cursor(&mut self, _id: u32) {}
+
     fn clipboard_content(&mut self) -> String {
         "".into()
     }
@@ -181,6 +340,21 @@ impl UiBackend for NullUiBackend {
     fn language(&self) -> &LanguageIdentifier {
         &US_ENGLISH
     }
+
+    fn display_file_open_dialog(
+        &mut self,
+        _filters: Vec<FileFilter>,
+        _multiple: bool,
+    ) -> Option<FileDialogFuture<Vec<LoadedFile>>> {
+        None
+    }
+
+    fn display_file_save_dialog(
+        &mut self,
+        _file_name: String,
+    ) -> Option<FileDialogFuture<Box<dyn SaveFile>>> {
+        None
+    }
 }
 
 impl Default for NullUiBackend {
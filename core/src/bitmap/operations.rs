@@ -9,6 +9,7 @@ use crate::bitmap::bitmap_data::{
 use crate::bitmap::turbulence::Turbulence;
 use crate::context::{RenderContext, UpdateContext};
 use crate::display_object::TDisplayObject;
+use crate::pixel_bender::PixelBenderImage;
 use gc_arena::MutationContext;
 use ruffle_render::bitmap::PixelRegion;
 use ruffle_render::commands::{CommandHandler, CommandList};
@@ -1216,6 +1217,70 @@ pub fn apply_filter<'gc>(
     }
 }
 
+/// Reads an area of a `BitmapData`, to be used as the input of a Pixel Bender shader.
+pub fn to_shader_image(source: BitmapDataWrapper<'_>, mut area: PixelRegion) -> PixelBenderImage {
+    area.clamp(source.width(), source.height());
+    let read = source.read_area(area);
+
+    let mut data = Vec::with_capacity(area.width() as usize * area.height() as usize * 4);
+    for y in area.y_min..area.y_max {
+        for x in area.x_min..area.x_max {
+            let color = read.get_pixel32_raw(x, y).to_un_multiplied_alpha();
+            data.extend(
+                [color.red(), color.green(), color.blue(), color.alpha()]
+                    .map(|component| component as f32 / 255.0),
+            );
+        }
+    }
+
+    PixelBenderImage {
+        width: area.width(),
+        height: area.height(),
+        channels: 4,
+        data,
+    }
+}
+
+/// Writes the output of a Pixel Bender shader to a `BitmapData`, with its
+/// top left corner at `dest_point`.
+///
+/// Missing color components are zero, and a missing alpha channel is opaque.
+pub fn draw_shader_image<'gc>(
+    mc: MutationContext<'gc, '_>,
+    target: BitmapDataWrapper<'gc>,
+    image: &PixelBenderImage,
+    dest_point: (u32, u32),
+) {
+    let mut dest_region =
+        PixelRegion::for_region(dest_point.0, dest_point.1, image.width, image.height);
+    dest_region.clamp(target.width(), target.height());
+    if dest_region.width() == 0 || dest_region.height() == 0 {
+        return;
+    }
+
+    let target = target.sync();
+    let mut write = target.write(mc);
+    let transparency = write.transparency();
+    let channels = image.channels as usize;
+    let to_u8 = |component: f32| (component.clamp(0.0, 1.0) * 255.0).round() as u8;
+
+    for y in dest_region.y_min..dest_region.y_max {
+        for x in dest_region.x_min..dest_region.x_max {
+            let start = ((y - dest_point.1) as usize * image.width as usize
+                + (x - dest_point.0) as usize)
+                * channels;
+            let mut pixel = [0.0, 0.0, 0.0, 1.0];
+            for (component, value) in pixel.iter_mut().zip(&image.data[start..start + channels]) {
+                *component = *value;
+            }
+            let [red, green, blue, alpha] = pixel.map(to_u8);
+            let color = Color::argb(alpha, red, green, blue).to_premultiplied_alpha(transparency);
+            write.set_pixel32_raw(x, y, color);
+        }
+    }
+    write.set_cpu_dirty(dest_region);
+}
+
 #[allow(clippy::too_many_arguments)]
 fn copy_on_cpu<'gc>(
     context: MutationContext<'gc, '_>,
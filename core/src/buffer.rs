@@ -0,0 +1,120 @@
+//! Shared buffers for incrementally-received media data
+
+use std::io::Read;
+use std::sync::{Arc, RwLock};
+
+/// A growable list of data chunks that can be shared between threads.
+///
+/// A `Substream` is used to hand media data (such as the audio tags of an
+/// FLV file) to a consumer (such as the audio mixer) while it is still being
+/// received. The producer appends chunks as they become available, and every
+/// clone of the `Substream` sees the appended chunks.
+///
+/// Chunk boundaries are preserved, since some codecs (e.g. ADPCM) carry a
+/// header at the start of every chunk.
+#[derive(Clone, Debug, Default)]
+pub struct Substream {
+    chunks: Arc<RwLock<Vec<Arc<[u8]>>>>,
+}
+
+impl Substream {
+    /// Create an empty substream.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a chunk of data to the end of this substream.
+    pub fn append(&self, data: &[u8]) {
+        self.chunks
+            .write()
+            .expect("Substream lock should not be poisoned")
+            .push(Arc::from(data));
+    }
+
+    /// The number of chunks in this substream.
+    pub fn num_chunks(&self) -> usize {
+        self.chunks
+            .read()
+            .expect("Substream lock should not be poisoned")
+            .len()
+    }
+
+    /// Returns true if no chunks have been appended yet.
+    pub fn is_empty(&self) -> bool {
+        self.num_chunks() == 0
+    }
+
+    /// Retrieve a chunk of this substream by index.
+    pub fn chunk(&self, index: usize) -> Option<Arc<[u8]>> {
+        self.chunks
+            .read()
+            .expect("Substream lock should not be poisoned")
+            .get(index)
+            .cloned()
+    }
+
+    /// Iterate over each chunk of this substream, including chunks that are
+    /// appended after iteration started.
+    pub fn chunks(&self) -> SubstreamChunks {
+        SubstreamChunks {
+            substream: self.clone(),
+            next_chunk: 0,
+        }
+    }
+
+    /// Read this substream as one contiguous stream of bytes.
+    ///
+    /// Reaching the end of the data appended so far is treated as the end of
+    /// the stream.
+    pub fn reader(&self) -> SubstreamReader {
+        SubstreamReader {
+            chunks: self.chunks(),
+            current_chunk: Arc::from([]),
+            position: 0,
+        }
+    }
+}
+
+/// An iterator over the chunks of a `Substream`.
+pub struct SubstreamChunks {
+    substream: Substream,
+    next_chunk: usize,
+}
+
+impl Iterator for SubstreamChunks {
+    type Item = Arc<[u8]>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let chunk = self.substream.chunk(self.next_chunk)?;
+        self.next_chunk += 1;
+
+        Some(chunk)
+    }
+}
+
+/// A `Read` implementation that reads through all chunks of a `Substream`.
+pub struct SubstreamReader {
+    chunks: SubstreamChunks,
+    current_chunk: Arc<[u8]>,
+    position: usize,
+}
+
+impl Read for SubstreamReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        while self.position >= self.current_chunk.len() {
+            if let Some(chunk) = self.chunks.next() {
+                self.current_chunk = chunk;
+                self.position = 0;
+            } else {
+                return Ok(0);
+            }
+        }
+
+        let remaining = &self.current_chunk[self.position..];
+        let len = std::cmp::min(buf.len(), remaining.len());
+        buf[..len].copy_from_slice(&remaining[..len]);
+        self.position += len;
+
+        Ok(len)
+    }
+}
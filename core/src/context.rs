@@ -5,21 +5,25 @@ use crate::avm1::SystemProperties;
 use crate::avm1::{Object as Avm1Object, Value as Avm1Value};
 use crate::avm2::{Avm2, Object as Avm2Object, SoundChannelObject};
 use crate::backend::{
-    audio::{AudioBackend, AudioManager, SoundHandle, SoundInstanceHandle},
+    audio::{AudioBackend, AudioFormat, AudioManager, SoundHandle, SoundInstanceHandle},
     log::LogBackend,
     navigator::NavigatorBackend,
     storage::StorageBackend,
     ui::{InputManager, UiBackend},
 };
+use crate::buffer::Substream;
 use crate::context_menu::ContextMenuState;
+use crate::debugger::Debugger;
 use crate::display_object::{EditText, InteractiveObject, MovieClip, SoundTransform, Stage};
 use crate::external::ExternalInterface;
 use crate::focus_tracker::FocusTracker;
 use crate::frame_lifecycle::FramePhase;
 use crate::library::Library;
 use crate::loader::LoadManager;
+use crate::local_connection::LocalConnections;
 use crate::player::Player;
 use crate::prelude::*;
+use crate::socket::Sockets;
 use crate::streams::StreamManager;
 use crate::string::AvmStringInterner;
 use crate::stub::StubCollection;
@@ -187,6 +191,9 @@ pub struct UpdateContext<'a, 'gc> {
     /// The instant at which the current update started.
     pub update_start: Instant,
 
+    /// The ActionScript debugger.
+    pub debugger: &'a mut Debugger,
+
     /// The maximum amount of time that can be called before a `Error::ExecutionTimeout`
     /// is raised. This defaults to 15 seconds but can be changed.
     pub max_execution_duration: Duration,
@@ -217,6 +224,12 @@ pub struct UpdateContext<'a, 'gc> {
     /// Manager of in-progress media streams.
     pub stream_manager: &'a mut StreamManager<'gc>,
 
+    /// Open TCP connections of `Socket` and `XMLSocket` objects.
+    pub sockets: &'a mut Sockets<'gc>,
+
+    /// Broker of messages between `LocalConnection` objects.
+    pub local_connections: &'a mut LocalConnections<'gc>,
+
     /// Dynamic root for allowing handles to GC objects to exist outside of the GC.
     #[cfg(feature = "egui")]
     pub dynamic_root: gc_arena::DynamicRootSet<'gc>,
@@ -259,6 +272,10 @@ impl<'a, 'gc> UpdateContext<'a, 'gc> {
             .start_sound(self.audio, sound, settings, owner, avm1_object)
     }
 
+    pub fn start_dynamic_sound(&mut self, target: Avm2Object<'gc>) -> Option<SoundInstanceHandle> {
+        self.audio_manager.start_dynamic_sound(self.audio, target)
+    }
+
     pub fn attach_avm2_sound_channel(
         &mut self,
         instance: SoundInstanceHandle,
@@ -312,6 +329,15 @@ impl<'a, 'gc> UpdateContext<'a, 'gc> {
         )
     }
 
+    pub fn start_substream(
+        &mut self,
+        stream_data: Substream,
+        format: &AudioFormat,
+    ) -> Option<SoundInstanceHandle> {
+        self.audio_manager
+            .start_substream(self.audio, stream_data, format)
+    }
+
     pub fn set_sound_transforms_dirty(&mut self) {
         self.audio_manager.set_sound_transforms_dirty()
     }
@@ -368,6 +394,7 @@ impl<'a, 'gc> UpdateContext<'a, 'gc> {
             external_interface: self.external_interface,
             start_time: self.start_time,
             update_start: self.update_start,
+            debugger: self.debugger,
             max_execution_duration: self.max_execution_duration,
             focus_tracker: self.focus_tracker,
             times_get_time_called: self.times_get_time_called,
@@ -377,6 +404,8 @@ impl<'a, 'gc> UpdateContext<'a, 'gc> {
             actions_since_timeout_check: self.actions_since_timeout_check,
             frame_phase: self.frame_phase,
             stream_manager: self.stream_manager,
+            sockets: self.sockets,
+            local_connections: self.local_connections,
             #[cfg(feature = "egui")]
             dynamic_root: self.dynamic_root,
         }
@@ -1,26 +1,51 @@
+mod audio;
+mod avm1;
+mod avm2;
+mod debugger;
 mod display_object;
 mod handle;
+mod movie;
 
 use crate::context::{RenderContext, UpdateContext};
+use crate::debug_ui::audio::AudioWindow;
+use crate::debug_ui::avm1::Avm1ObjectWindow;
+use crate::debug_ui::avm2::Avm2ObjectWindow;
+use crate::debug_ui::debugger::DebuggerWindow;
 use crate::debug_ui::display_object::DisplayObjectWindow;
-use crate::debug_ui::handle::DisplayObjectHandle;
+use crate::debug_ui::handle::{AVM1ObjectHandle, AVM2ObjectHandle, DisplayObjectHandle};
+use crate::debug_ui::movie::{MovieListWindow, MovieWindow};
 use crate::display_object::TDisplayObject;
+use crate::tag_utils::SwfMovie;
 use gc_arena::DynamicRootSet;
 use hashbrown::HashMap;
 use ruffle_render::commands::CommandHandler;
 use ruffle_render::matrix::Matrix;
+use std::sync::Arc;
 use swf::{Color, Rectangle, Twips};
 
 #[derive(Default)]
 pub struct DebugUi {
     display_objects: HashMap<DisplayObjectHandle, DisplayObjectWindow>,
+    avm1_objects: HashMap<AVM1ObjectHandle, Avm1ObjectWindow>,
+    avm2_objects: HashMap<AVM2ObjectHandle, Avm2ObjectWindow>,
+    movies: Vec<(Arc<SwfMovie>, MovieWindow)>,
+    movie_list: Option<MovieListWindow>,
+    audio: Option<AudioWindow>,
+    debugger: Option<DebuggerWindow>,
     queued_messages: Vec<Message>,
 }
 
 #[derive(Debug)]
 pub enum Message {
     TrackDisplayObject(DisplayObjectHandle),
+    TrackAVM1Object(AVM1ObjectHandle),
+    TrackAVM2Object(AVM2ObjectHandle),
+    TrackMovie(Arc<SwfMovie>),
     TrackStage,
+    TrackTopLevelMovie,
+    ShowKnownMovies,
+    ShowAudio,
+    ShowDebugger,
 }
 
 impl DebugUi {
@@ -30,12 +55,51 @@ impl DebugUi {
             let object = object.fetch(context.dynamic_root);
             window.show(egui_ctx, context, object, &mut messages)
         });
+        self.avm1_objects.retain(|object, window| {
+            let object = object.fetch(context.dynamic_root);
+            window.show(egui_ctx, context, object, &mut messages)
+        });
+        self.avm2_objects.retain(|object, window| {
+            let object = object.fetch(context.dynamic_root);
+            window.show(egui_ctx, context, object, &mut messages)
+        });
+        self.movies
+            .retain_mut(|(movie, window)| window.show(egui_ctx, context, movie.clone()));
+        if let Some(mut movie_list) = self.movie_list.take() {
+            if movie_list.show(egui_ctx, context, &mut messages) {
+                self.movie_list = Some(movie_list);
+            }
+        }
+        if let Some(mut audio) = self.audio.take() {
+            if audio.show(egui_ctx, context, &mut messages) {
+                self.audio = Some(audio);
+            }
+        }
+        if let Some(mut debugger) = self.debugger.take() {
+            if debugger.show(egui_ctx, context) {
+                self.debugger = Some(debugger);
+            }
+        }
         for message in messages {
             match message {
                 Message::TrackDisplayObject(object) => self.track_display_object(object),
+                Message::TrackAVM1Object(object) => {
+                    self.avm1_objects.insert(object, Default::default());
+                }
+                Message::TrackAVM2Object(object) => {
+                    self.avm2_objects.insert(object, Default::default());
+                }
+                Message::TrackMovie(movie) => self.track_movie(movie),
                 Message::TrackStage => {
                     self.track_display_object(DisplayObjectHandle::new(context, context.stage))
                 }
+                Message::TrackTopLevelMovie => self.track_movie(context.swf.clone()),
+                Message::ShowKnownMovies => self.movie_list = Some(Default::default()),
+                Message::ShowAudio => self.audio = Some(Default::default()),
+                Message::ShowDebugger => {
+                    context.debugger.attach();
+                    self.debugger = Some(Default::default());
+                }
             }
         }
     }
@@ -48,6 +112,16 @@ impl DebugUi {
         self.display_objects.insert(handle, Default::default());
     }
 
+    pub fn track_movie(&mut self, movie: Arc<SwfMovie>) {
+        if !self
+            .movies
+            .iter()
+            .any(|(other, _)| Arc::ptr_eq(other, &movie))
+        {
+            self.movies.push((movie, Default::default()));
+        }
+    }
+
     pub fn draw_debug_rects<'gc>(
         &self,
         context: &mut RenderContext<'_, 'gc>,
@@ -62,13 +136,32 @@ impl DebugUi {
 
                 draw_debug_rect(context, color, bounds, 3.0);
             }
+        }
 
-            if let Some(object) = window.hovered_debug_rect() {
-                let object = object.fetch(dynamic_root_set);
-                let bounds = world_matrix * object.world_bounds();
+        let hovered_debug_rects = self
+            .display_objects
+            .values()
+            .filter_map(DisplayObjectWindow::hovered_debug_rect)
+            .chain(
+                self.avm1_objects
+                    .values()
+                    .filter_map(Avm1ObjectWindow::hovered_debug_rect),
+            )
+            .chain(
+                self.avm2_objects
+                    .values()
+                    .filter_map(Avm2ObjectWindow::hovered_debug_rect),
+            )
+            .chain(
+                self.audio
+                    .iter()
+                    .filter_map(AudioWindow::hovered_debug_rect),
+            );
+        for object in hovered_debug_rects {
+            let object = object.fetch(dynamic_root_set);
+            let bounds = world_matrix * object.world_bounds();
 
-                draw_debug_rect(context, swf::Color::RED, bounds, 5.0);
-            }
+            draw_debug_rect(context, swf::Color::RED, bounds, 5.0);
         }
     }
 }
@@ -0,0 +1,184 @@
+use crate::backend::audio::{AudioBackend, AudioFormat, SoundHandle};
+use crate::context::UpdateContext;
+use crate::debug_ui::handle::DisplayObjectHandle;
+use crate::debug_ui::Message;
+use crate::display_object::{SoundTransform, TDisplayObject};
+use egui::{DragValue, Grid, Id, ProgressBar, Slider, Ui, Widget, Window};
+use swf::AudioCompression;
+
+#[derive(Debug, Default)]
+pub struct AudioWindow {
+    hovered_debug_rect: Option<DisplayObjectHandle>,
+}
+
+impl AudioWindow {
+    pub fn hovered_debug_rect(&self) -> Option<DisplayObjectHandle> {
+        self.hovered_debug_rect.clone()
+    }
+
+    pub fn show<'gc>(
+        &mut self,
+        egui_ctx: &egui::Context,
+        context: &mut UpdateContext<'_, 'gc>,
+        messages: &mut Vec<Message>,
+    ) -> bool {
+        let mut keep_open = true;
+        self.hovered_debug_rect = None;
+
+        Window::new("Audio")
+            .open(&mut keep_open)
+            .scroll2([true, true])
+            .show(egui_ctx, |ui| {
+                Grid::new(ui.id().with("global"))
+                    .num_columns(2)
+                    .show(ui, |ui| {
+                        ui.label("Master Volume");
+                        let mut volume = context.audio.volume();
+                        if Slider::new(&mut volume, 0.0..=1.0).ui(ui).changed() {
+                            context.audio.set_volume(volume);
+                        }
+                        ui.end_row();
+
+                        ui.label("Global Transform");
+                        let mut transform = context.audio_manager.global_sound_transform().clone();
+                        let id = ui.id().with("global_transform");
+                        if show_sound_transform(ui, id, &mut transform) {
+                            context.audio_manager.set_global_sound_transform(transform);
+                        }
+                        ui.end_row();
+                    });
+                ui.separator();
+
+                self.show_sounds(ui, context, messages);
+            });
+        keep_open
+    }
+
+    pub fn show_sounds<'gc>(
+        &mut self,
+        ui: &mut Ui,
+        context: &mut UpdateContext<'_, 'gc>,
+        messages: &mut Vec<Message>,
+    ) {
+        let sounds = context.audio_manager.sounds().to_vec();
+        if sounds.is_empty() {
+            ui.label("No sounds are playing.");
+            return;
+        }
+
+        Grid::new(ui.id().with("sounds"))
+            .num_columns(7)
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("Instance");
+                ui.label("Sound");
+                ui.label("Display Object");
+                ui.label("Position");
+                ui.label("Peak");
+                ui.label("Transform");
+                ui.label("");
+                ui.end_row();
+
+                for sound in sounds {
+                    let instance = sound.instance();
+                    ui.label(instance.into_raw_parts().0.to_string());
+
+                    if let Some(handle) = sound.sound() {
+                        ui.label(summary_sound(context.audio, handle));
+                    } else {
+                        ui.label("Stream");
+                    }
+
+                    if let Some(object) = sound.display_object() {
+                        let response = ui.button(object.path().to_string());
+                        if response.hovered() {
+                            self.hovered_debug_rect =
+                                Some(DisplayObjectHandle::new(context, object));
+                        }
+                        if response.clicked() {
+                            messages.push(Message::TrackDisplayObject(DisplayObjectHandle::new(
+                                context, object,
+                            )));
+                        }
+                    } else {
+                        ui.label("");
+                    }
+
+                    if let Some(position) = context.audio.get_sound_position(instance) {
+                        ui.label(format!("{:.2}s", position / 1000.0));
+                    } else {
+                        ui.label("");
+                    }
+
+                    if let Some([left, right]) = context.audio.get_sound_peak(instance) {
+                        ui.vertical(|ui| {
+                            ProgressBar::new(left).desired_width(100.0).ui(ui);
+                            ProgressBar::new(right).desired_width(100.0).ui(ui);
+                        });
+                    } else {
+                        ui.label("");
+                    }
+
+                    let mut transform = sound.transform().clone();
+                    let id = ui.id().with(instance);
+                    if show_sound_transform(ui, id, &mut transform) {
+                        context
+                            .audio_manager
+                            .set_local_sound_transform(instance, transform);
+                    }
+
+                    if ui.button("Stop").clicked() {
+                        context.audio_manager.stop_sound(context.audio, instance);
+                    }
+                    ui.end_row();
+                }
+            });
+    }
+}
+
+/// Shows editable fields for a sound transform, returning whether it was changed.
+fn show_sound_transform(ui: &mut Ui, id: Id, transform: &mut SoundTransform) -> bool {
+    let mut changed = false;
+    Grid::new(id).num_columns(2).show(ui, |ui| {
+        for (name, value) in [
+            ("Volume", &mut transform.volume),
+            ("Left to Left", &mut transform.left_to_left),
+            ("Left to Right", &mut transform.left_to_right),
+            ("Right to Left", &mut transform.right_to_left),
+            ("Right to Right", &mut transform.right_to_right),
+        ] {
+            ui.label(name);
+            changed |= DragValue::new(value)
+                .clamp_range(0..=SoundTransform::MAX_VOLUME)
+                .suffix("%")
+                .ui(ui)
+                .changed();
+            ui.end_row();
+        }
+    });
+    changed
+}
+
+pub fn summary_sound(audio: &dyn AudioBackend, sound: SoundHandle) -> String {
+    let Some(format) = audio.get_sound_format(sound) else {
+        return "(Unknown sound)".to_string();
+    };
+    let compression = match format {
+        AudioFormat::Swf(format) => match format.compression {
+            AudioCompression::UncompressedUnknownEndian | AudioCompression::Uncompressed => "PCM",
+            AudioCompression::Adpcm => "ADPCM",
+            AudioCompression::Mp3 => "MP3",
+            AudioCompression::Nellymoser16Khz
+            | AudioCompression::Nellymoser8Khz
+            | AudioCompression::Nellymoser => "Nellymoser",
+            AudioCompression::Speex => "Speex",
+        },
+        AudioFormat::Aac { .. } => "AAC",
+    };
+    let channels = if format.is_stereo() { "stereo" } else { "mono" };
+    let mut summary = format!("{compression} {}Hz {channels}", format.sample_rate());
+    if let Some(duration) = audio.get_sound_duration(sound) {
+        summary.push_str(&format!(", {:.2}s", duration / 1000.0));
+    }
+    summary
+}
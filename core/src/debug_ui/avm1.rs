@@ -0,0 +1,176 @@
+use crate::avm1::{Activation, ActivationIdentifier, Object, TObject, Value, VariableDumper};
+use crate::context::UpdateContext;
+use crate::debug_ui::handle::{AVM1ObjectHandle, DisplayObjectHandle};
+use crate::debug_ui::Message;
+use crate::display_object::TDisplayObject;
+use crate::string::AvmString;
+use egui::{Checkbox, DragValue, Grid, Id, TextEdit, Ui, Widget, Window};
+use hashbrown::HashMap;
+
+#[derive(Debug, Default)]
+pub struct Avm1ObjectWindow {
+    hovered_debug_rect: Option<DisplayObjectHandle>,
+
+    /// The values returned by getters the last time the user called them.
+    getter_values: HashMap<String, String>,
+}
+
+impl Avm1ObjectWindow {
+    pub fn hovered_debug_rect(&self) -> Option<DisplayObjectHandle> {
+        self.hovered_debug_rect.clone()
+    }
+
+    pub fn show<'gc>(
+        &mut self,
+        egui_ctx: &egui::Context,
+        context: &mut UpdateContext<'_, 'gc>,
+        object: Object<'gc>,
+        messages: &mut Vec<Message>,
+    ) -> bool {
+        let mut keep_open = true;
+        self.hovered_debug_rect = None;
+
+        let base_clip = object
+            .as_display_object()
+            .or_else(|| context.stage.root_clip());
+        let Some(base_clip) = base_clip else {
+            // There's nothing to run AVM1 code on, so the object can't be inspected.
+            return false;
+        };
+        let mut activation = Activation::from_nothing(
+            context.reborrow(),
+            ActivationIdentifier::root("[Debug UI]"),
+            base_clip,
+        );
+
+        Window::new(format!(
+            "AVM1 Object {}",
+            VariableDumper::summarize(&Value::Object(object))
+        ))
+        .id(Id::new(object.as_ptr()))
+        .open(&mut keep_open)
+        .scroll2([true, true])
+        .show(egui_ctx, |ui| {
+            Grid::new(ui.id().with("properties"))
+                .num_columns(2)
+                .show(ui, |ui| {
+                    if let Some(display_object) = object.as_display_object() {
+                        ui.label("Display Object");
+                        let response = ui.button(display_object.path().to_string());
+                        if response.hovered() {
+                            self.hovered_debug_rect = Some(DisplayObjectHandle::new(
+                                &mut activation.context,
+                                display_object,
+                            ));
+                        }
+                        if response.clicked() {
+                            messages.push(Message::TrackDisplayObject(DisplayObjectHandle::new(
+                                &mut activation.context,
+                                display_object,
+                            )));
+                        }
+                        ui.end_row();
+                    }
+
+                    ui.label("__proto__");
+                    let proto = object.proto(&mut activation);
+                    self.show_value(ui, &mut activation, proto, messages);
+                    ui.end_row();
+
+                    let mut keys = object.get_keys(&mut activation);
+                    keys.sort_unstable();
+                    for key in keys {
+                        ui.label(key.to_string());
+                        if object.getter(key, &mut activation).is_some() {
+                            // Getters may run arbitrary ActionScript, so only
+                            // call them when asked to.
+                            self.show_getter(ui, &mut activation, object, key);
+                        } else if let Some(value) = object.get_local_stored(key, &mut activation) {
+                            if let Some(new_value) =
+                                self.show_value(ui, &mut activation, value, messages)
+                            {
+                                if let Err(e) = object.set(key, new_value, &mut activation) {
+                                    tracing::error!("Failed to set property {key}: {e}");
+                                }
+                            }
+                        } else {
+                            ui.label("");
+                        }
+                        ui.end_row();
+                    }
+                });
+        });
+        keep_open
+    }
+
+    /// Shows the last value returned by a getter, along with a button to call it.
+    fn show_getter<'gc>(
+        &mut self,
+        ui: &mut Ui,
+        activation: &mut Activation<'_, 'gc>,
+        object: Object<'gc>,
+        key: AvmString<'gc>,
+    ) {
+        ui.horizontal(|ui| {
+            if ui.button("Call Getter").clicked() {
+                let summary = match object.get(key, activation) {
+                    Ok(value) => VariableDumper::summarize(&value),
+                    Err(e) => format!("Error: {e}"),
+                };
+                self.getter_values.insert(key.to_string(), summary);
+            }
+            if let Some(summary) = self.getter_values.get(&key.to_string()) {
+                ui.label(summary);
+            }
+        });
+    }
+
+    /// Shows a value, returning a new value if the user edited it.
+    fn show_value<'gc>(
+        &mut self,
+        ui: &mut Ui,
+        activation: &mut Activation<'_, 'gc>,
+        value: Value<'gc>,
+        messages: &mut Vec<Message>,
+    ) -> Option<Value<'gc>> {
+        match value {
+            Value::Undefined | Value::Null | Value::MovieClip(_) => {
+                ui.label(VariableDumper::summarize(&value));
+            }
+            Value::Bool(mut value) => {
+                if Checkbox::without_text(&mut value).ui(ui).changed() {
+                    return Some(value.into());
+                }
+            }
+            Value::Number(mut value) => {
+                if DragValue::new(&mut value).ui(ui).changed() {
+                    return Some(value.into());
+                }
+            }
+            Value::String(value) => {
+                let mut text = value.to_string();
+                if TextEdit::singleline(&mut text).ui(ui).changed() {
+                    return Some(AvmString::new_utf8(activation.context.gc_context, text).into());
+                }
+            }
+            Value::Object(object) => {
+                let response = ui.button(VariableDumper::summarize(&value));
+                if response.hovered() {
+                    if let Some(display_object) = object.as_display_object() {
+                        self.hovered_debug_rect = Some(DisplayObjectHandle::new(
+                            &mut activation.context,
+                            display_object,
+                        ));
+                    }
+                }
+                if response.clicked() {
+                    messages.push(Message::TrackAVM1Object(AVM1ObjectHandle::new(
+                        &mut activation.context,
+                        object,
+                    )));
+                }
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,277 @@
+use crate::avm2::{Activation, Error, Object, Property, TObject, Value};
+use crate::context::UpdateContext;
+use crate::debug_ui::handle::{AVM2ObjectHandle, DisplayObjectHandle};
+use crate::debug_ui::Message;
+use crate::display_object::TDisplayObject;
+use crate::string::AvmString;
+use egui::{Checkbox, CollapsingHeader, DragValue, Grid, Id, TextEdit, Ui, Widget, Window};
+use hashbrown::HashMap;
+
+#[derive(Debug, Default)]
+pub struct Avm2ObjectWindow {
+    hovered_debug_rect: Option<DisplayObjectHandle>,
+
+    /// The values returned by getters the last time the user called them.
+    getter_values: HashMap<String, String>,
+}
+
+impl Avm2ObjectWindow {
+    pub fn hovered_debug_rect(&self) -> Option<DisplayObjectHandle> {
+        self.hovered_debug_rect.clone()
+    }
+
+    pub fn show<'gc>(
+        &mut self,
+        egui_ctx: &egui::Context,
+        context: &mut UpdateContext<'_, 'gc>,
+        object: Object<'gc>,
+        messages: &mut Vec<Message>,
+    ) -> bool {
+        let mut keep_open = true;
+        self.hovered_debug_rect = None;
+        let mut activation = Activation::from_nothing(context.reborrow());
+
+        Window::new(format!(
+            "AVM2 Object {}",
+            object.instance_of_class_name(activation.context.gc_context)
+        ))
+        .id(Id::new(object.as_ptr()))
+        .open(&mut keep_open)
+        .scroll2([true, true])
+        .show(egui_ctx, |ui| {
+            if let Some(display_object) = object.as_display_object() {
+                ui.horizontal(|ui| {
+                    ui.label("Display Object");
+                    let response = ui.button(display_object.path().to_string());
+                    if response.hovered() {
+                        self.hovered_debug_rect = Some(DisplayObjectHandle::new(
+                            &mut activation.context,
+                            display_object,
+                        ));
+                    }
+                    if response.clicked() {
+                        messages.push(Message::TrackDisplayObject(DisplayObjectHandle::new(
+                            &mut activation.context,
+                            display_object,
+                        )));
+                    }
+                });
+            }
+
+            CollapsingHeader::new("Class Properties")
+                .id_source(ui.id().with("traits"))
+                .default_open(true)
+                .show(ui, |ui| {
+                    self.show_traits(ui, &mut activation, object, messages);
+                });
+
+            CollapsingHeader::new("Dynamic Properties")
+                .id_source(ui.id().with("dynamic"))
+                .default_open(true)
+                .show(ui, |ui| {
+                    self.show_dynamic_properties(ui, &mut activation, object, messages);
+                });
+        });
+        keep_open
+    }
+
+    /// Shows the public slots and getters declared by the object's class.
+    ///
+    /// Slots are read directly. Getters may run arbitrary ActionScript, so
+    /// they're only called when asked to.
+    fn show_traits<'gc>(
+        &mut self,
+        ui: &mut Ui,
+        activation: &mut Activation<'_, 'gc>,
+        mut object: Object<'gc>,
+        messages: &mut Vec<Message>,
+    ) {
+        let Some(vtable) = object.vtable() else {
+            return;
+        };
+        let mut properties = vtable.public_properties();
+        properties.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+
+        Grid::new(ui.id().with("traits"))
+            .num_columns(2)
+            .show(ui, |ui| {
+                for (name, property) in properties {
+                    let value = match property {
+                        Property::Slot { slot_id } | Property::ConstSlot { slot_id } => {
+                            object.get_slot(slot_id)
+                        }
+                        Property::Virtual { get: Some(_), .. } => {
+                            ui.label(name.to_string());
+                            self.show_getter(ui, activation, object, name);
+                            ui.end_row();
+                            continue;
+                        }
+                        // Methods aren't interesting to look at, and reading
+                        // one would create a new bound method every frame.
+                        Property::Method { .. } | Property::Virtual { get: None, .. } => continue,
+                    };
+
+                    ui.label(name.to_string());
+                    let editable = matches!(property, Property::Slot { .. });
+                    let new_value = ui
+                        .add_enabled_ui(editable, |ui| {
+                            self.show_result(ui, activation, value, messages)
+                        })
+                        .inner;
+                    if let Some(new_value) = new_value {
+                        if let Err(e) = object.set_public_property(name, new_value, activation) {
+                            tracing::error!("Failed to set property {name}: {e:?}");
+                        }
+                    }
+                    ui.end_row();
+                }
+            });
+    }
+
+    /// Shows the last value returned by a getter, along with a button to call it.
+    fn show_getter<'gc>(
+        &mut self,
+        ui: &mut Ui,
+        activation: &mut Activation<'_, 'gc>,
+        object: Object<'gc>,
+        name: AvmString<'gc>,
+    ) {
+        ui.horizontal(|ui| {
+            if ui.button("Call Getter").clicked() {
+                let summary = match object.get_public_property(name, activation) {
+                    Ok(value) => summary_value(activation, value),
+                    Err(e) => format!("Error: {e:?}"),
+                };
+                self.getter_values.insert(name.to_string(), summary);
+            }
+            if let Some(summary) = self.getter_values.get(&name.to_string()) {
+                ui.label(summary);
+            }
+        });
+    }
+
+    /// Shows the dynamic properties stored on the object.
+    ///
+    /// This reads the properties directly rather than enumerating the object,
+    /// as enumerating a `Proxy` would run ActionScript.
+    fn show_dynamic_properties<'gc>(
+        &mut self,
+        ui: &mut Ui,
+        activation: &mut Activation<'_, 'gc>,
+        mut object: Object<'gc>,
+        messages: &mut Vec<Message>,
+    ) {
+        let mut values: Vec<_> = object
+            .base()
+            .values()
+            .iter()
+            .map(|(name, value)| (*name, *value))
+            .collect();
+        values.sort_unstable_by(|(a, _), (b, _)| a.cmp(b));
+
+        Grid::new(ui.id().with("dynamic"))
+            .num_columns(2)
+            .show(ui, |ui| {
+                for (name, value) in values {
+                    ui.label(name.to_string());
+                    if let Some(new_value) = self.show_value(ui, activation, value, messages) {
+                        if let Err(e) = object.set_public_property(name, new_value, activation) {
+                            tracing::error!("Failed to set property {name}: {e:?}");
+                        }
+                    }
+                    ui.end_row();
+                }
+            });
+    }
+
+    /// Shows the result of reading a property, returning a new value if the user edited it.
+    fn show_result<'gc>(
+        &mut self,
+        ui: &mut Ui,
+        activation: &mut Activation<'_, 'gc>,
+        value: Result<Value<'gc>, Error<'gc>>,
+        messages: &mut Vec<Message>,
+    ) -> Option<Value<'gc>> {
+        match value {
+            Ok(value) => self.show_value(ui, activation, value, messages),
+            Err(e) => {
+                ui.label(format!("Error: {e:?}"));
+                None
+            }
+        }
+    }
+
+    /// Shows a value, returning a new value if the user edited it.
+    fn show_value<'gc>(
+        &mut self,
+        ui: &mut Ui,
+        activation: &mut Activation<'_, 'gc>,
+        value: Value<'gc>,
+        messages: &mut Vec<Message>,
+    ) -> Option<Value<'gc>> {
+        match value {
+            Value::Undefined => {
+                ui.label("undefined");
+            }
+            Value::Null => {
+                ui.label("null");
+            }
+            Value::Bool(mut value) => {
+                if Checkbox::without_text(&mut value).ui(ui).changed() {
+                    return Some(value.into());
+                }
+            }
+            Value::Number(mut value) => {
+                if DragValue::new(&mut value).ui(ui).changed() {
+                    return Some(value.into());
+                }
+            }
+            Value::Integer(mut value) => {
+                if DragValue::new(&mut value).ui(ui).changed() {
+                    return Some(value.into());
+                }
+            }
+            Value::String(value) => {
+                let mut text = value.to_string();
+                if TextEdit::singleline(&mut text).ui(ui).changed() {
+                    return Some(AvmString::new_utf8(activation.context.gc_context, text).into());
+                }
+            }
+            Value::Object(object) => {
+                let name = object.instance_of_class_name(activation.context.gc_context);
+                let response = ui.button(format!("[object {name}]"));
+                if response.hovered() {
+                    if let Some(display_object) = object.as_display_object() {
+                        self.hovered_debug_rect = Some(DisplayObjectHandle::new(
+                            &mut activation.context,
+                            display_object,
+                        ));
+                    }
+                }
+                if response.clicked() {
+                    messages.push(Message::TrackAVM2Object(AVM2ObjectHandle::new(
+                        &mut activation.context,
+                        object,
+                    )));
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Describes a value without running any ActionScript, unlike `toString`.
+fn summary_value<'gc>(activation: &mut Activation<'_, 'gc>, value: Value<'gc>) -> String {
+    match value {
+        Value::Undefined => "undefined".to_string(),
+        Value::Null => "null".to_string(),
+        Value::Bool(value) => value.to_string(),
+        Value::Number(value) => value.to_string(),
+        Value::Integer(value) => value.to_string(),
+        Value::String(value) => format!("\"{value}\""),
+        Value::Object(object) => format!(
+            "[object {}]",
+            object.instance_of_class_name(activation.context.gc_context)
+        ),
+    }
+}
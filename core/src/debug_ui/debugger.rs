@@ -0,0 +1,153 @@
+use crate::context::UpdateContext;
+use crate::debugger::{Breakpoint, DebuggerAction, PausedState};
+use egui::{CollapsingHeader, Grid, TextEdit, Ui, Widget, Window};
+
+#[derive(Debug, Default)]
+pub struct DebuggerWindow {
+    /// The breakpoint being typed in.
+    new_breakpoint: String,
+
+    /// Why the breakpoint that was typed in couldn't be added.
+    breakpoint_error: Option<String>,
+}
+
+impl DebuggerWindow {
+    pub fn show(&mut self, egui_ctx: &egui::Context, context: &mut UpdateContext<'_, '_>) -> bool {
+        let mut keep_open = true;
+
+        Window::new("Debugger")
+            .open(&mut keep_open)
+            .scroll2([true, true])
+            .show(egui_ctx, |ui| {
+                show_controls(ui, context);
+                ui.separator();
+                self.show_breakpoints(ui, context);
+
+                if let Some(state) = context.debugger.paused_state() {
+                    ui.separator();
+                    show_paused_state(ui, state);
+                }
+            });
+
+        if !keep_open {
+            // Nobody would be left to resume the player.
+            context.debugger.detach();
+        }
+        keep_open
+    }
+
+    fn show_breakpoints(&mut self, ui: &mut Ui, context: &mut UpdateContext<'_, '_>) {
+        let mut removed = None;
+        Grid::new(ui.id().with("breakpoints"))
+            .num_columns(2)
+            .striped(true)
+            .show(ui, |ui| {
+                for breakpoint in context.debugger.breakpoints() {
+                    ui.label(breakpoint.to_string());
+                    if ui.button("Remove").clicked() {
+                        removed = Some(breakpoint.clone());
+                    }
+                    ui.end_row();
+                }
+            });
+        if let Some(breakpoint) = removed {
+            context.debugger.remove_breakpoint(&breakpoint);
+        }
+
+        ui.horizontal(|ui| {
+            let response = TextEdit::singleline(&mut self.new_breakpoint)
+                .hint_text("Main/init, class:Main or frame:2")
+                .ui(ui);
+            let submitted =
+                response.lost_focus() && ui.input(|input| input.key_pressed(egui::Key::Enter));
+            if ui.button("Add Breakpoint").clicked() || submitted {
+                match self.new_breakpoint.trim().parse::<Breakpoint>() {
+                    Ok(breakpoint) => {
+                        context.debugger.add_breakpoint(breakpoint);
+                        self.new_breakpoint.clear();
+                        self.breakpoint_error = None;
+                    }
+                    Err(error) => self.breakpoint_error = Some(error),
+                }
+            }
+        });
+        if let Some(error) = &self.breakpoint_error {
+            ui.colored_label(ui.visuals().error_fg_color, error.as_str());
+        }
+    }
+}
+
+fn show_controls(ui: &mut Ui, context: &mut UpdateContext<'_, '_>) {
+    let debugger = &mut *context.debugger;
+    ui.horizontal(|ui| {
+        if !debugger.is_paused() {
+            ui.label("Running");
+            if ui.button("Pause").clicked() {
+                debugger.pause();
+            }
+            return;
+        }
+
+        ui.label("Paused");
+        let actions = [
+            ("Continue", DebuggerAction::Continue),
+            ("Step In", DebuggerAction::StepIn),
+            ("Step Over", DebuggerAction::StepOver),
+            ("Step Out", DebuggerAction::StepOut),
+        ];
+        for (name, action) in actions {
+            if ui.button(name).clicked() {
+                debugger.resume(action);
+            }
+        }
+    });
+}
+
+fn show_paused_state(ui: &mut Ui, state: &PausedState) {
+    ui.label(format!(
+        "Next instruction at offset {}: {}",
+        state.offset, state.instruction
+    ));
+
+    CollapsingHeader::new("Call Stack")
+        .id_source(ui.id().with("call_stack"))
+        .default_open(true)
+        .show(ui, |ui| {
+            for location in state.call_stack.iter().rev() {
+                ui.label(location.to_string());
+            }
+        });
+
+    CollapsingHeader::new("Locals")
+        .id_source(ui.id().with("locals"))
+        .default_open(true)
+        .show(ui, |ui| {
+            Grid::new(ui.id().with("locals"))
+                .num_columns(2)
+                .striped(true)
+                .show(ui, |ui| {
+                    for (name, value) in &state.locals {
+                        ui.label(name.as_str());
+                        ui.label(value.as_str());
+                        ui.end_row();
+                    }
+                });
+        });
+
+    CollapsingHeader::new("Scope Chain")
+        .id_source(ui.id().with("scope_chain"))
+        .show(ui, |ui| {
+            for scope in state.scope_chain.iter().rev() {
+                ui.label(scope.as_str());
+            }
+        });
+
+    CollapsingHeader::new("Stack")
+        .id_source(ui.id().with("stack"))
+        .default_open(true)
+        .show(ui, |ui| {
+            for value in state.stack.iter().rev() {
+                ui.label(value.as_str());
+            }
+        });
+}
@@ -1,6 +1,8 @@
+use crate::avm1::Value as Avm1Value;
+use crate::avm2::Value as Avm2Value;
 use crate::context::UpdateContext;
-use crate::debug_ui::handle::DisplayObjectHandle;
-use crate::debug_ui::Message;
+use crate::debug_ui::handle::{AVM1ObjectHandle, AVM2ObjectHandle, DisplayObjectHandle};
+use crate::debug_ui::{movie, Message};
 use crate::display_object::{DisplayObject, MovieClip, TDisplayObject, TDisplayObjectContainer};
 use egui::collapsing_header::CollapsingState;
 use egui::{Button, Checkbox, CollapsingHeader, ComboBox, Grid, Id, Ui, Widget, Window};
@@ -280,6 +282,35 @@ impl DisplayObjectWindow {
                     ui.end_row();
                 }
 
+                if let Avm1Value::Object(avm1_object) = object.object() {
+                    ui.label("AVM1 Object");
+                    if ui.button("View").clicked() {
+                        messages.push(Message::TrackAVM1Object(AVM1ObjectHandle::new(
+                            context,
+                            avm1_object,
+                        )));
+                    }
+                    ui.end_row();
+                }
+
+                if let Avm2Value::Object(avm2_object) = object.object2() {
+                    ui.label("AVM2 Object");
+                    if ui.button("View").clicked() {
+                        messages.push(Message::TrackAVM2Object(AVM2ObjectHandle::new(
+                            context,
+                            avm2_object,
+                        )));
+                    }
+                    ui.end_row();
+                }
+
+                let movie = object.movie();
+                ui.label("Movie");
+                if ui.button(movie::summary_name(&movie)).clicked() {
+                    messages.push(Message::TrackMovie(movie));
+                }
+                ui.end_row();
+
                 ui.label("Debug Rect");
                 ui.horizontal(|ui| {
                     Checkbox::without_text(&mut self.debug_rect_visible).ui(ui);
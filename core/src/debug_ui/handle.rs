@@ -1,10 +1,13 @@
+use crate::avm1::{Object as Avm1Object, ObjectPtr as Avm1ObjectPtr, TObject as _};
+use crate::avm2::object::ObjectPtr as Avm2ObjectPtr;
+use crate::avm2::{Object as Avm2Object, TObject as _};
 use crate::context::UpdateContext;
 use crate::display_object::{DisplayObject, DisplayObjectPtr, TDisplayObject};
 use gc_arena::{DynamicRoot, DynamicRootSet, Rootable};
 use std::fmt::{Debug, Formatter};
 use std::hash::{Hash, Hasher};
 
-// TODO: Make this generic somehow, we'll want AVM1 and AVM2 object handles too
+// TODO: Make these handles generic somehow, they only differ in the type they root
 #[derive(Clone)]
 pub struct DisplayObjectHandle {
     root: DynamicRoot<Rootable![DisplayObject<'gc>]>,
@@ -50,3 +53,83 @@ impl Hash for DisplayObjectHandle {
 }
 
 impl Eq for DisplayObjectHandle {}
+
+#[derive(Clone)]
+pub struct AVM1ObjectHandle {
+    root: DynamicRoot<Rootable![Avm1Object<'gc>]>,
+    ptr: *const Avm1ObjectPtr,
+}
+
+impl AVM1ObjectHandle {
+    pub fn new<'gc>(context: &mut UpdateContext<'_, 'gc>, object: Avm1Object<'gc>) -> Self {
+        Self {
+            root: context.dynamic_root.stash(context.gc_context, object),
+            ptr: object.as_ptr(),
+        }
+    }
+
+    pub fn fetch<'gc>(&self, dynamic_root_set: DynamicRootSet<'gc>) -> Avm1Object<'gc> {
+        *dynamic_root_set.fetch(&self.root)
+    }
+}
+
+impl Debug for AVM1ObjectHandle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("AVM1ObjectHandle").field(&self.ptr).finish()
+    }
+}
+
+impl PartialEq<AVM1ObjectHandle> for AVM1ObjectHandle {
+    #[inline(always)]
+    fn eq(&self, other: &AVM1ObjectHandle) -> bool {
+        self.ptr == other.ptr
+    }
+}
+
+impl Hash for AVM1ObjectHandle {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.ptr.hash(state);
+    }
+}
+
+impl Eq for AVM1ObjectHandle {}
+
+#[derive(Clone)]
+pub struct AVM2ObjectHandle {
+    root: DynamicRoot<Rootable![Avm2Object<'gc>]>,
+    ptr: *const Avm2ObjectPtr,
+}
+
+impl AVM2ObjectHandle {
+    pub fn new<'gc>(context: &mut UpdateContext<'_, 'gc>, object: Avm2Object<'gc>) -> Self {
+        Self {
+            root: context.dynamic_root.stash(context.gc_context, object),
+            ptr: object.as_ptr(),
+        }
+    }
+
+    pub fn fetch<'gc>(&self, dynamic_root_set: DynamicRootSet<'gc>) -> Avm2Object<'gc> {
+        *dynamic_root_set.fetch(&self.root)
+    }
+}
+
+impl Debug for AVM2ObjectHandle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("AVM2ObjectHandle").field(&self.ptr).finish()
+    }
+}
+
+impl PartialEq<AVM2ObjectHandle> for AVM2ObjectHandle {
+    #[inline(always)]
+    fn eq(&self, other: &AVM2ObjectHandle) -> bool {
+        self.ptr == other.ptr
+    }
+}
+
+impl Hash for AVM2ObjectHandle {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.ptr.hash(state);
+    }
+}
+
+impl Eq for AVM2ObjectHandle {}
@@ -0,0 +1,304 @@
+use crate::backend::audio::AudioBackend;
+use crate::character::Character;
+use crate::context::UpdateContext;
+use crate::debug_ui::audio::summary_sound;
+use crate::debug_ui::Message;
+use crate::display_object::{Bitmap, TDisplayObject};
+use crate::tag_utils::SwfMovie;
+use egui::{ColorImage, Grid, Id, TextureHandle, TextureOptions, Ui, Window};
+use hashbrown::HashMap;
+use ruffle_render::bitmap::PixelRegion;
+use std::sync::Arc;
+use swf::CharacterId;
+
+/// The largest size that a bitmap preview is shown at, in points.
+const MAX_PREVIEW_SIZE: f32 = 64.0;
+
+#[derive(Debug, Default)]
+pub struct MovieListWindow;
+
+impl MovieListWindow {
+    pub fn show(
+        &mut self,
+        egui_ctx: &egui::Context,
+        context: &mut UpdateContext<'_, '_>,
+        messages: &mut Vec<Message>,
+    ) -> bool {
+        let mut keep_open = true;
+
+        Window::new("Known Movies")
+            .open(&mut keep_open)
+            .scroll2([true, true])
+            .show(egui_ctx, |ui| {
+                let mut movies = context.library.known_movies();
+                movies.sort_by(|a, b| a.url().cmp(b.url()));
+
+                for movie in movies {
+                    if ui
+                        .button(summary_name(&movie))
+                        .on_hover_text(movie.url())
+                        .clicked()
+                    {
+                        messages.push(Message::TrackMovie(movie));
+                    }
+                }
+            });
+        keep_open
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Hash, Default, Copy, Clone)]
+enum Panel {
+    #[default]
+    Information,
+    Characters,
+}
+
+#[derive(Default)]
+pub struct MovieWindow {
+    open_panel: Panel,
+    character_search: String,
+
+    /// Textures of bitmap characters, uploaded the first time they're previewed.
+    bitmap_previews: HashMap<CharacterId, TextureHandle>,
+}
+
+impl MovieWindow {
+    pub fn show(
+        &mut self,
+        egui_ctx: &egui::Context,
+        context: &mut UpdateContext<'_, '_>,
+        movie: Arc<SwfMovie>,
+    ) -> bool {
+        let mut keep_open = true;
+
+        Window::new(summary_name(&movie))
+            .id(Id::new(Arc::as_ptr(&movie)))
+            .open(&mut keep_open)
+            .scroll2([true, true])
+            .show(egui_ctx, |ui| {
+                ui.horizontal(|ui| {
+                    ui.selectable_value(&mut self.open_panel, Panel::Information, "Information");
+                    ui.selectable_value(&mut self.open_panel, Panel::Characters, "Characters");
+                });
+                ui.separator();
+
+                match self.open_panel {
+                    Panel::Information => self.show_information(ui, &movie),
+                    Panel::Characters => self.show_characters(ui, context, movie.clone()),
+                }
+            });
+        keep_open
+    }
+
+    pub fn show_information(&mut self, ui: &mut Ui, movie: &SwfMovie) {
+        Grid::new(ui.id().with("information"))
+            .num_columns(2)
+            .show(ui, |ui| {
+                ui.label("URL");
+                // Read-only, but still selectable, like the display object window's name field.
+                ui.text_edit_singleline(&mut movie.url().to_string());
+                ui.end_row();
+
+                if let Some(loader_url) = movie.loader_url() {
+                    ui.label("Loader URL");
+                    ui.text_edit_singleline(&mut loader_url.to_string());
+                    ui.end_row();
+                }
+
+                ui.label("SWF Version");
+                ui.label(movie.version().to_string());
+                ui.end_row();
+
+                ui.label("ActionScript");
+                ui.label(if movie.is_action_script_3() {
+                    "ActionScript 3"
+                } else {
+                    "ActionScript 1/2"
+                });
+                ui.end_row();
+
+                ui.label("Stage Size");
+                ui.label(format!(
+                    "{}x{}",
+                    movie.width().to_pixels(),
+                    movie.height().to_pixels()
+                ));
+                ui.end_row();
+
+                ui.label("Frame Rate");
+                ui.label(movie.frame_rate().to_string());
+                ui.end_row();
+
+                ui.label("Total Frames");
+                ui.label(movie.num_frames().to_string());
+                ui.end_row();
+
+                ui.label("Compressed Size");
+                ui.label(format!("{} bytes", movie.compressed_len()));
+                ui.end_row();
+
+                ui.label("Uncompressed Size");
+                ui.label(format!("{} bytes", movie.uncompressed_len()));
+                ui.end_row();
+
+                for (key, value) in movie.parameters() {
+                    ui.label(format!("Parameter \"{key}\""));
+                    ui.text_edit_singleline(&mut value.to_string());
+                    ui.end_row();
+                }
+            });
+    }
+
+    pub fn show_characters(
+        &mut self,
+        ui: &mut Ui,
+        context: &mut UpdateContext<'_, '_>,
+        movie: Arc<SwfMovie>,
+    ) {
+        ui.horizontal(|ui| {
+            ui.label("Search");
+            ui.text_edit_singleline(&mut self.character_search);
+        });
+        ui.separator();
+
+        let Some(library) = context.library.library_for_movie(movie) else {
+            ui.label("This movie has no library.");
+            return;
+        };
+        let mut characters: Vec<_> = library.characters().iter().collect();
+        characters.sort_by_key(|(id, _)| **id);
+        let search = self.character_search.to_ascii_lowercase();
+        let mut play_sound = None;
+
+        Grid::new(ui.id().with("characters"))
+            .num_columns(4)
+            .striped(true)
+            .show(ui, |ui| {
+                ui.label("ID");
+                ui.label("Type");
+                ui.label("Details");
+                ui.label("Preview");
+                ui.end_row();
+
+                for (&id, character) in characters {
+                    let character_type = character_type(character);
+                    if !search.is_empty()
+                        && !id.to_string().contains(&search)
+                        && !character_type.to_ascii_lowercase().contains(&search)
+                    {
+                        continue;
+                    }
+
+                    ui.label(id.to_string());
+                    ui.label(character_type);
+                    ui.label(summary_character(context.audio, character));
+                    match character {
+                        Character::Bitmap(bitmap) => {
+                            let texture = self
+                                .bitmap_previews
+                                .entry(id)
+                                .or_insert_with(|| bitmap_texture(ui.ctx(), id, *bitmap));
+                            let size = texture.size_vec2();
+                            let scale = (MAX_PREVIEW_SIZE / size.max_elem()).min(1.0);
+                            ui.image(&*texture, size * scale);
+                        }
+                        Character::Sound(sound) => {
+                            if ui.button("Play").clicked() {
+                                play_sound = Some(*sound);
+                            }
+                        }
+                        _ => {
+                            ui.label("");
+                        }
+                    }
+                    ui.end_row();
+                }
+            });
+
+        if let Some(sound) = play_sound {
+            let settings = swf::SoundInfo {
+                event: swf::SoundEvent::Event,
+                in_sample: None,
+                out_sample: None,
+                num_loops: 1,
+                envelope: None,
+            };
+            context
+                .audio_manager
+                .start_sound(context.audio, sound, &settings, None, None);
+        }
+    }
+}
+
+/// Uploads the pixels of a bitmap character as an egui texture.
+fn bitmap_texture(egui_ctx: &egui::Context, id: CharacterId, bitmap: Bitmap) -> TextureHandle {
+    let bitmap_data = bitmap.bitmap_data_wrapper();
+    let (width, height) = (bitmap_data.width(), bitmap_data.height());
+    let bitmap_data = bitmap_data.read_area(PixelRegion::for_whole_size(width, height));
+    let pixels: Vec<u8> = bitmap_data
+        .pixels()
+        .iter()
+        .flat_map(|pixel| {
+            let pixel = pixel.to_un_multiplied_alpha();
+            [pixel.red(), pixel.green(), pixel.blue(), pixel.alpha()]
+        })
+        .collect();
+    let image = ColorImage::from_rgba_unmultiplied([width as usize, height as usize], &pixels);
+    egui_ctx.load_texture(format!("bitmap {id}"), image, TextureOptions::default())
+}
+
+fn summary_character(audio: &dyn AudioBackend, character: &Character) -> String {
+    match character {
+        Character::EditText(edit_text) => edit_text.self_bounds().to_string(),
+        Character::Graphic(graphic) => graphic.self_bounds().to_string(),
+        Character::MovieClip(movie_clip) => format!("{} frames", movie_clip.total_frames()),
+        Character::Bitmap(bitmap) => format!("{}x{}", bitmap.width(), bitmap.height()),
+        Character::Avm1Button(button) => button.self_bounds().to_string(),
+        Character::Avm2Button(button) => button.self_bounds().to_string(),
+        Character::Font(font) => {
+            let descriptor = font.descriptor();
+            let mut summary = descriptor.class().to_string();
+            if descriptor.bold() {
+                summary.push_str(" (bold)");
+            }
+            if descriptor.italic() {
+                summary.push_str(" (italic)");
+            }
+            summary
+        }
+        Character::MorphShape(morph_shape) => morph_shape.self_bounds().to_string(),
+        Character::Text(text) => text.self_bounds().to_string(),
+        Character::Sound(sound) => summary_sound(audio, *sound),
+        Character::Video(_) => String::new(),
+        Character::BinaryData(data) => format!("{} bytes", data.len()),
+    }
+}
+
+fn character_type(character: &Character) -> &'static str {
+    match character {
+        Character::EditText(_) => "EditText",
+        Character::Graphic(_) => "Graphic",
+        Character::MovieClip(_) => "MovieClip",
+        Character::Bitmap(_) => "Bitmap",
+        Character::Avm1Button(_) => "Avm1Button",
+        Character::Avm2Button(_) => "Avm2Button",
+        Character::Font(_) => "Font",
+        Character::MorphShape(_) => "MorphShape",
+        Character::Text(_) => "Text",
+        Character::Sound(_) => "Sound",
+        Character::Video(_) => "Video",
+        Character::BinaryData(_) => "BinaryData",
+    }
+}
+
+pub fn summary_name(movie: &SwfMovie) -> String {
+    // Show only the file name, URLs can get very long.
+    let url = movie.url();
+    let path = url.split(['?', '#']).next().unwrap_or_default();
+    match path.rsplit('/').next() {
+        Some(name) if !name.is_empty() => name.to_string(),
+        _ if !url.is_empty() => url.to_string(),
+        _ => "(Unknown movie)".to_string(),
+    }
+}
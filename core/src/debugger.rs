@@ -0,0 +1,681 @@
+//! A step debugger for AVM1 and AVM2 code.
+//!
+//! The `Debugger` tracks which pieces of code are running, and pauses
+//! execution when a `Breakpoint` is hit or a step completes. Pausing is a
+//! state of the player: while paused, no frames run, and the frontend can
+//! inspect the paused code with `Debugger::paused_state` and decide how to
+//! continue with `Debugger::resume`.
+//!
+//! ActionScript can't be suspended in the middle of running, so the code that
+//! was running when the debugger paused still runs to completion. Everything
+//! it does afterwards is recorded, and replayed as the frontend steps through
+//! it, so that the frontend sees each instruction as it was about to run.
+
+use std::collections::VecDeque;
+use std::fmt;
+use std::str::FromStr;
+
+/// How execution should continue after the debugger paused it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DebuggerAction {
+    /// Run until the next breakpoint.
+    Continue,
+
+    /// Pause at the next instruction, including in any function it calls.
+    StepIn,
+
+    /// Pause at the next instruction in the current function, or in its caller
+    /// if it returns.
+    StepOver,
+
+    /// Pause at the next instruction after the current function returns.
+    StepOut,
+}
+
+/// A condition that pauses execution when code matching it starts running.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Breakpoint {
+    /// Break when a function or method with this name is called.
+    ///
+    /// AVM2 methods can be qualified with their class, e.g. `Main/init`.
+    Function(String),
+
+    /// Break when any method of the AVM2 class with this name is called.
+    Class(String),
+
+    /// Break when a frame script runs.
+    ///
+    /// `clip` is the dot-separated path of the clip, such as `_level0.menu`,
+    /// or `None` to match any clip. `frame` is the frame number, starting at 1.
+    FrameScript { clip: Option<String>, frame: u16 },
+}
+
+impl Breakpoint {
+    fn matches(&self, location: &CodeLocation) -> bool {
+        match (self, location) {
+            (Breakpoint::Function(expected), CodeLocation::Function { name, .. }) => {
+                name == expected
+                    || name
+                        .strip_suffix(expected.as_str())
+                        .map_or(false, |prefix| {
+                            prefix.ends_with('/') || prefix.ends_with("::")
+                        })
+            }
+            (
+                Breakpoint::Class(expected),
+                CodeLocation::Function {
+                    class: Some(class), ..
+                },
+            ) => {
+                class == expected
+                    || class
+                        .strip_suffix(expected.as_str())
+                        .map_or(false, |prefix| prefix.ends_with("::"))
+            }
+            (
+                Breakpoint::FrameScript {
+                    clip: expected_clip,
+                    frame: expected_frame,
+                },
+                CodeLocation::FrameScript { clip, frame },
+            ) => {
+                frame == expected_frame
+                    && expected_clip
+                        .as_ref()
+                        .map_or(true, |expected| expected == clip)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl FromStr for Breakpoint {
+    type Err = String;
+
+    /// Parses a breakpoint from a command line argument.
+    ///
+    /// `class:Main` breaks on a class, `frame:2` or `frame:_level0.menu:2`
+    /// breaks on a frame script, and anything else breaks on a function.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(class) = s.strip_prefix("class:") {
+            return Ok(Breakpoint::Class(class.to_string()));
+        }
+        if let Some(frame) = s.strip_prefix("frame:") {
+            let (clip, frame) = match frame.rsplit_once(':') {
+                Some((clip, frame)) => (Some(clip.to_string()), frame),
+                None => (None, frame),
+            };
+            let frame = frame
+                .parse()
+                .map_err(|_| format!("Invalid frame number in breakpoint: {s}"))?;
+            return Ok(Breakpoint::FrameScript { clip, frame });
+        }
+        if s.is_empty() {
+            return Err("Breakpoints need a function name".to_string());
+        }
+        Ok(Breakpoint::Function(s.to_string()))
+    }
+}
+
+impl fmt::Display for Breakpoint {
+    /// Formats a breakpoint the way `from_str` parses it.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Breakpoint::Function(name) => f.write_str(name),
+            Breakpoint::Class(name) => write!(f, "class:{name}"),
+            Breakpoint::FrameScript { clip: None, frame } => write!(f, "frame:{frame}"),
+            Breakpoint::FrameScript {
+                clip: Some(clip),
+                frame,
+            } => write!(f, "frame:{clip}:{frame}"),
+        }
+    }
+}
+
+/// Describes a piece of code that is being run.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CodeLocation {
+    /// A function or method.
+    ///
+    /// AVM2 method names are qualified with their class, if they have one.
+    Function { name: String, class: Option<String> },
+
+    /// A frame script of a movie clip.
+    FrameScript { clip: String, frame: u16 },
+
+    /// Any other code, such as a clip event.
+    Other(String),
+}
+
+impl fmt::Display for CodeLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CodeLocation::Function { name, .. } => write!(f, "{name}()"),
+            CodeLocation::FrameScript { clip, frame } => write!(f, "{clip} (frame {frame})"),
+            CodeLocation::Other(name) => f.write_str(name),
+        }
+    }
+}
+
+/// The state of the code that the debugger paused in.
+///
+/// Values are described as strings, as they cannot outlive the pause.
+#[derive(Clone, Debug, Default)]
+pub struct PausedState {
+    /// The code that is running, with the innermost location last.
+    pub call_stack: Vec<CodeLocation>,
+
+    /// The offset of the next instruction in its function's bytecode.
+    pub offset: usize,
+
+    /// The next instruction to run.
+    pub instruction: String,
+
+    /// The local variables and registers of the current function.
+    pub locals: Vec<(String, String)>,
+
+    /// The scope chain, with the innermost scope last.
+    pub scope_chain: Vec<String>,
+
+    /// The operand stack, with the top of the stack last.
+    pub stack: Vec<String>,
+}
+
+/// Where to pause after a step.
+#[derive(Clone, Copy, Debug)]
+enum Step {
+    Into,
+    Over(usize),
+    Out(usize),
+}
+
+/// The most events that are recorded after pausing.
+///
+/// Code that runs longer than this after a pause can't be stepped through,
+/// and runs as if the debugger was continued.
+const MAX_RECORDED_EVENTS: usize = 100_000;
+
+/// An instruction that the debugger paused, or could pause, before.
+#[derive(Debug)]
+struct Pause {
+    /// How many frames were running.
+    depth: usize,
+
+    state: PausedState,
+}
+
+/// Something that happened after the debugger paused.
+#[derive(Debug)]
+enum Event {
+    /// A frame started running, making the call stack `depth` frames deep.
+    Enter { depth: usize, breakpoint: bool },
+
+    /// The frame at `depth` returned.
+    Exit { depth: usize },
+
+    /// An instruction was about to run.
+    Instruction(Pause),
+}
+
+#[derive(Default)]
+pub struct Debugger {
+    attached: bool,
+
+    breakpoints: Vec<Breakpoint>,
+
+    /// The code currently running, innermost last.
+    ///
+    /// Locations are only recorded while attached.
+    frames: Vec<Option<CodeLocation>>,
+
+    step: Option<Step>,
+
+    /// The depth of a frame that hit a breakpoint, if it has not yet paused.
+    pending_break: Option<usize>,
+
+    /// The instruction that execution is paused before.
+    paused: Option<Pause>,
+
+    /// What happened after the current pause, oldest first.
+    recorded: VecDeque<Event>,
+
+    /// Whether more happened after the current pause than could be recorded.
+    recording_overflowed: bool,
+}
+
+impl Debugger {
+    /// Starts tracking the code that runs, so that it can pause.
+    pub fn attach(&mut self) {
+        self.attached = true;
+    }
+
+    /// Stops tracking the code that runs, resuming execution if it is paused.
+    pub fn detach(&mut self) {
+        self.attached = false;
+        self.step = None;
+        self.pending_break = None;
+        self.paused = None;
+        self.recorded.clear();
+        self.recording_overflowed = false;
+    }
+
+    pub fn is_attached(&self) -> bool {
+        self.attached
+    }
+
+    pub fn breakpoints(&self) -> &[Breakpoint] {
+        &self.breakpoints
+    }
+
+    pub fn add_breakpoint(&mut self, breakpoint: Breakpoint) {
+        if !self.breakpoints.contains(&breakpoint) {
+            self.breakpoints.push(breakpoint);
+        }
+    }
+
+    /// Removes a breakpoint, returning whether it was set.
+    pub fn remove_breakpoint(&mut self, breakpoint: &Breakpoint) -> bool {
+        let len = self.breakpoints.len();
+        self.breakpoints.retain(|b| b != breakpoint);
+        self.breakpoints.len() != len
+    }
+
+    pub fn clear_breakpoints(&mut self) {
+        self.breakpoints.clear();
+    }
+
+    /// Pauses at the next instruction that runs.
+    pub fn pause(&mut self) {
+        if self.is_attached() && !self.is_paused() {
+            self.step = Some(Step::Into);
+        }
+    }
+
+    /// Whether execution is paused, in which case the player doesn't run
+    /// any frames until `resume` is called.
+    pub fn is_paused(&self) -> bool {
+        self.paused.is_some()
+    }
+
+    /// The state of the code that execution is paused in.
+    pub fn paused_state(&self) -> Option<&PausedState> {
+        self.paused.as_ref().map(|pause| &pause.state)
+    }
+
+    /// Continues execution after a pause.
+    ///
+    /// The recorded code is replayed until the next pause, if there is one.
+    /// Otherwise the player starts running frames again, and the action
+    /// applies to the code that runs from then on.
+    pub fn resume(&mut self, action: DebuggerAction) {
+        let Some(pause) = self.paused.take() else {
+            return;
+        };
+        self.step = match action {
+            DebuggerAction::Continue => None,
+            DebuggerAction::StepIn => Some(Step::Into),
+            DebuggerAction::StepOver => Some(Step::Over(pause.depth)),
+            DebuggerAction::StepOut => Some(Step::Out(pause.depth)),
+        };
+
+        while let Some(event) = self.recorded.pop_front() {
+            match event {
+                Event::Enter { depth, breakpoint } => {
+                    if breakpoint {
+                        self.pending_break.get_or_insert(depth);
+                    }
+                }
+                Event::Exit { depth } => {
+                    if self.pending_break == Some(depth) {
+                        self.pending_break = None;
+                    }
+                }
+                Event::Instruction(pause) => {
+                    if self.should_pause_at(pause.depth) {
+                        self.stop(pause);
+                        return;
+                    }
+                }
+            }
+        }
+
+        // Anything else that ran was lost, including its breakpoints.
+        if self.recording_overflowed {
+            self.recording_overflowed = false;
+            self.pending_break = None;
+        }
+    }
+
+    /// Records that the code described by `location` started running.
+    ///
+    /// Every call must be balanced by a call to `exit_frame`.
+    pub fn enter_frame(&mut self, location: impl FnOnce() -> CodeLocation) {
+        if !self.is_attached() {
+            self.frames.push(None);
+            return;
+        }
+
+        let location = location();
+        let breakpoint = self.breakpoints.iter().any(|b| b.matches(&location));
+        self.frames.push(Some(location));
+
+        let depth = self.frames.len();
+        if self.is_paused() {
+            self.record(Event::Enter { depth, breakpoint });
+        } else if breakpoint {
+            self.pending_break.get_or_insert(depth);
+        }
+    }
+
+    /// Records that the innermost code returned.
+    pub fn exit_frame(&mut self) {
+        let depth = self.frames.len();
+        if self.is_paused() {
+            self.record(Event::Exit { depth });
+        } else if self.pending_break == Some(depth) {
+            self.pending_break = None;
+        }
+        self.frames.pop();
+    }
+
+    /// Whether `pause_with` should be called before the next instruction.
+    ///
+    /// While paused, this is the case for every instruction, so that they
+    /// can be recorded.
+    #[inline]
+    pub fn should_pause(&self) -> bool {
+        if self.is_paused() {
+            return !self.recording_overflowed;
+        }
+        self.should_pause_at(self.frames.len())
+    }
+
+    fn should_pause_at(&self, depth: usize) -> bool {
+        match self.step {
+            Some(Step::Into) => true,
+            Some(Step::Over(step_depth)) if depth <= step_depth => true,
+            Some(Step::Out(step_depth)) if depth < step_depth => true,
+            _ => self.pending_break.map_or(false, |d| depth >= d),
+        }
+    }
+
+    /// The code currently running, innermost last.
+    pub fn call_stack(&self) -> Vec<CodeLocation> {
+        self.frames.iter().flatten().cloned().collect()
+    }
+
+    /// Pauses before the next instruction, which `state` describes.
+    ///
+    /// `state.call_stack` is filled in by the debugger.
+    pub fn pause_with(&mut self, mut state: PausedState) {
+        state.call_stack = self.call_stack();
+        let pause = Pause {
+            depth: self.frames.len(),
+            state,
+        };
+        if self.is_paused() {
+            self.record(Event::Instruction(pause));
+        } else if self.is_attached() {
+            self.stop(pause);
+        }
+    }
+
+    fn stop(&mut self, pause: Pause) {
+        self.step = None;
+        self.pending_break = None;
+        self.paused = Some(pause);
+    }
+
+    fn record(&mut self, event: Event) {
+        if self.recorded.len() < MAX_RECORDED_EVENTS {
+            self.recorded.push_back(event);
+        } else {
+            self.recording_overflowed = true;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn debugger() -> Debugger {
+        let mut debugger = Debugger::default();
+        debugger.attach();
+        debugger
+    }
+
+    fn function(name: &str, class: Option<&str>) -> CodeLocation {
+        CodeLocation::Function {
+            name: name.to_string(),
+            class: class.map(str::to_string),
+        }
+    }
+
+    /// Runs a single instruction, pausing if the debugger wants to.
+    fn run(debugger: &mut Debugger, instruction: &str) {
+        if debugger.should_pause() {
+            debugger.pause_with(PausedState {
+                instruction: instruction.to_string(),
+                ..Default::default()
+            });
+        }
+    }
+
+    /// Resumes with each of `actions` in turn, and then continues,
+    /// returning the instructions that were paused before.
+    fn resume(debugger: &mut Debugger, actions: &[DebuggerAction]) -> Vec<String> {
+        let mut actions = actions.iter().copied();
+        let mut pauses = vec![];
+        while let Some(state) = debugger.paused_state() {
+            pauses.push(state.instruction.clone());
+            debugger.resume(actions.next().unwrap_or(DebuggerAction::Continue));
+        }
+        pauses
+    }
+
+    #[test]
+    fn function_breakpoints() {
+        let method = function("com.example::Main/init", Some("com.example::Main"));
+        for name in ["init", "Main/init", "com.example::Main/init"] {
+            assert!(Breakpoint::Function(name.to_string()).matches(&method));
+        }
+        for name in ["nit", "Main", "Other/init"] {
+            assert!(!Breakpoint::Function(name.to_string()).matches(&method));
+        }
+
+        assert!(Breakpoint::Class("Main".to_string()).matches(&method));
+        assert!(Breakpoint::Class("com.example::Main".to_string()).matches(&method));
+        assert!(!Breakpoint::Class("ain".to_string()).matches(&method));
+        assert!(!Breakpoint::Class("Main".to_string()).matches(&function("Main", None)));
+    }
+
+    #[test]
+    fn frame_script_breakpoints() {
+        let location = CodeLocation::FrameScript {
+            clip: "_level0.menu".to_string(),
+            frame: 2,
+        };
+        let breakpoint = |clip: Option<&str>, frame| Breakpoint::FrameScript {
+            clip: clip.map(str::to_string),
+            frame,
+        };
+        assert!(breakpoint(None, 2).matches(&location));
+        assert!(breakpoint(Some("_level0.menu"), 2).matches(&location));
+        assert!(!breakpoint(Some("_level0"), 2).matches(&location));
+        assert!(!breakpoint(None, 1).matches(&location));
+    }
+
+    #[test]
+    fn parse_breakpoints() {
+        assert_eq!(
+            "Main/init".parse(),
+            Ok(Breakpoint::Function("Main/init".to_string()))
+        );
+        assert_eq!(
+            "com.example::Main/init".parse(),
+            Ok(Breakpoint::Function("com.example::Main/init".to_string()))
+        );
+        assert_eq!(
+            "class:Main".parse(),
+            Ok(Breakpoint::Class("Main".to_string()))
+        );
+        assert_eq!(
+            "frame:2".parse(),
+            Ok(Breakpoint::FrameScript {
+                clip: None,
+                frame: 2
+            })
+        );
+        assert_eq!(
+            "frame:_level0.menu:3".parse(),
+            Ok(Breakpoint::FrameScript {
+                clip: Some("_level0.menu".to_string()),
+                frame: 3
+            })
+        );
+        assert!("frame:first".parse::<Breakpoint>().is_err());
+        assert!("".parse::<Breakpoint>().is_err());
+
+        for text in ["Main/init", "class:Main", "frame:2", "frame:_level0.menu:3"] {
+            assert_eq!(text.parse::<Breakpoint>().unwrap().to_string(), text);
+        }
+    }
+
+    #[test]
+    fn breakpoint_pauses_at_first_instruction() {
+        let mut debugger = debugger();
+        debugger.add_breakpoint(Breakpoint::Function("callee".to_string()));
+
+        debugger.enter_frame(|| function("caller", None));
+        run(&mut debugger, "caller 1");
+        debugger.enter_frame(|| function("callee", None));
+        run(&mut debugger, "callee 1");
+        run(&mut debugger, "callee 2");
+        debugger.exit_frame();
+        run(&mut debugger, "caller 2");
+        debugger.exit_frame();
+
+        assert!(debugger.is_paused());
+        assert_eq!(resume(&mut debugger, &[]), ["callee 1"]);
+        assert!(!debugger.is_paused());
+    }
+
+    #[test]
+    fn step_over_skips_calls() {
+        let mut debugger = debugger();
+        debugger.enter_frame(|| function("caller", None));
+        debugger.pause();
+        run(&mut debugger, "caller 1");
+        debugger.enter_frame(|| function("callee", None));
+        run(&mut debugger, "callee 1");
+        debugger.exit_frame();
+        run(&mut debugger, "caller 2");
+        debugger.exit_frame();
+
+        let actions = [DebuggerAction::StepOver; 2];
+        assert_eq!(resume(&mut debugger, &actions), ["caller 1", "caller 2"]);
+    }
+
+    #[test]
+    fn step_out_pauses_in_caller() {
+        let mut debugger = debugger();
+        debugger.enter_frame(|| function("caller", None));
+        debugger.enter_frame(|| function("callee", None));
+        debugger.pause();
+        run(&mut debugger, "callee 1");
+        run(&mut debugger, "callee 2");
+        debugger.exit_frame();
+        run(&mut debugger, "caller 1");
+        debugger.exit_frame();
+
+        let actions = [DebuggerAction::StepOut; 2];
+        assert_eq!(resume(&mut debugger, &actions), ["callee 1", "caller 1"]);
+    }
+
+    #[test]
+    fn steps_continue_into_later_code() {
+        let mut debugger = debugger();
+        debugger.add_breakpoint(Breakpoint::Function("first".to_string()));
+        debugger.enter_frame(|| function("first", None));
+        run(&mut debugger, "first 1");
+        debugger.exit_frame();
+
+        // Nothing else ran before resuming, so the step applies to whatever
+        // runs next.
+        assert_eq!(
+            resume(&mut debugger, &[DebuggerAction::StepIn]),
+            ["first 1"]
+        );
+        assert!(!debugger.is_paused());
+
+        debugger.enter_frame(|| function("second", None));
+        run(&mut debugger, "second 1");
+        run(&mut debugger, "second 2");
+        debugger.exit_frame();
+
+        assert_eq!(resume(&mut debugger, &[]), ["second 1"]);
+    }
+
+    #[test]
+    fn continue_stops_at_recorded_breakpoint() {
+        let mut debugger = debugger();
+        debugger.add_breakpoint(Breakpoint::Function("callee".to_string()));
+        debugger.enter_frame(|| function("caller", None));
+        debugger.pause();
+        run(&mut debugger, "caller 1");
+        for i in 1..=2 {
+            debugger.enter_frame(|| function("callee", None));
+            run(&mut debugger, &format!("callee {i}"));
+            debugger.exit_frame();
+        }
+        debugger.exit_frame();
+
+        assert_eq!(
+            resume(&mut debugger, &[DebuggerAction::Continue]),
+            ["caller 1", "callee 1", "callee 2"]
+        );
+    }
+
+    #[test]
+    fn recording_is_limited() {
+        let mut debugger = debugger();
+        debugger.enter_frame(|| function("caller", None));
+        debugger.pause();
+        for i in 0..MAX_RECORDED_EVENTS + 10 {
+            run(&mut debugger, &i.to_string());
+        }
+        assert!(!debugger.should_pause());
+        debugger.exit_frame();
+
+        // Only the first instructions after the pause can be stepped through.
+        let actions = vec![DebuggerAction::StepIn; MAX_RECORDED_EVENTS];
+        let pauses = resume(&mut debugger, &actions);
+        assert_eq!(pauses.len(), MAX_RECORDED_EVENTS + 1);
+        assert_eq!(pauses.last().unwrap(), &MAX_RECORDED_EVENTS.to_string());
+        assert!(!debugger.should_pause());
+    }
+
+    #[test]
+    fn detached_debugger_never_pauses() {
+        let mut debugger = Debugger::default();
+        debugger.add_breakpoint(Breakpoint::Function("callee".to_string()));
+        debugger.pause();
+        debugger.enter_frame(|| unreachable!("locations are not needed when detached"));
+        assert!(!debugger.should_pause());
+        debugger.exit_frame();
+    }
+
+    #[test]
+    fn detaching_resumes() {
+        let mut debugger = debugger();
+        debugger.pause();
+        run(&mut debugger, "1");
+        run(&mut debugger, "2");
+        assert!(debugger.is_paused());
+
+        debugger.detach();
+        assert!(!debugger.is_paused());
+        assert!(!debugger.should_pause());
+    }
+}
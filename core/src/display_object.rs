@@ -14,7 +14,10 @@ use crate::vminterface::Instantiator;
 use bitflags::bitflags;
 use gc_arena::{Collect, MutationContext};
 use ruffle_macros::enum_trait_object;
-use ruffle_render::transform::Transform;
+use ruffle_render::bitmap::{BitmapFormat, PixelRegion};
+use ruffle_render::commands::CommandList;
+use ruffle_render::matrix3d::Matrix3D;
+use ruffle_render::transform::{Transform, TransformStack};
 use std::cell::{Ref, RefMut};
 use std::fmt::Debug;
 use std::hash::Hash;
@@ -57,6 +60,67 @@ pub use video::Video;
 
 use self::loader_display::LoaderDisplayWeak;
 
+/// The 3D part of a display object's transform, set by properties like `z` and `rotationX`.
+///
+/// The X and Y position, the X and Y scale and the Z rotation are still stored in the 2D matrix.
+#[derive(Clone, Debug)]
+pub struct Transform3D {
+    pub z: f64,
+    pub rotation_x: Degrees,
+    pub rotation_y: Degrees,
+    pub scale_z: f64,
+}
+
+impl Default for Transform3D {
+    fn default() -> Self {
+        Self {
+            z: 0.0,
+            rotation_x: Degrees::from_radians(0.0),
+            rotation_y: Degrees::from_radians(0.0),
+            scale_z: 1.0,
+        }
+    }
+}
+
+/// The perspective projection that display objects with a 3D transform are drawn with.
+#[derive(Clone, Debug)]
+pub struct PerspectiveProjection {
+    /// The field of view, in degrees.
+    pub field_of_view: f64,
+
+    /// The point that the viewer looks at, in pixels.
+    pub center: (f64, f64),
+}
+
+impl PerspectiveProjection {
+    pub const DEFAULT_FIELD_OF_VIEW: f64 = 55.0;
+
+    /// The default projection of a movie, which looks at the center of the stage.
+    pub fn for_movie(movie: &SwfMovie) -> Self {
+        Self {
+            field_of_view: Self::DEFAULT_FIELD_OF_VIEW,
+            center: (
+                movie.width().to_pixels() / 2.0,
+                movie.height().to_pixels() / 2.0,
+            ),
+        }
+    }
+
+    /// The distance of the viewer from the `z = 0` plane.
+    /// This depends on the width of the movie, which the field of view spans.
+    pub fn focal_length(&self, movie: &SwfMovie) -> f64 {
+        movie.width().to_pixels() / 2.0 / (self.field_of_view.to_radians() / 2.0).tan()
+    }
+
+    /// Sets the field of view that results in the given focal length.
+    pub fn set_focal_length(&mut self, focal_length: f64, movie: &SwfMovie) {
+        self.field_of_view = 2.0
+            * (movie.width().to_pixels() / 2.0 / focal_length)
+                .atan()
+                .to_degrees();
+    }
+}
+
 #[derive(Clone, Collect)]
 #[collect(no_drop)]
 pub struct DisplayObjectBase<'gc> {
@@ -82,6 +146,16 @@ pub struct DisplayObjectBase<'gc> {
 
     skew: f64,
 
+    /// The 3D part of this display object's transform.
+    /// This is only set once the display object has been transformed in 3D.
+    #[collect(require_static)]
+    transform_3d: Option<Transform3D>,
+
+    /// The perspective projection used by 3D children of this display object,
+    /// set by `transform.perspectiveProjection`.
+    #[collect(require_static)]
+    perspective_projection: Option<PerspectiveProjection>,
+
     /// The next display object in order of execution.
     ///
     /// `None` in an AVM2 movie.
@@ -137,6 +211,8 @@ impl<'gc> Default for DisplayObjectBase<'gc> {
             scale_x: Percent::from_unit(1.0),
             scale_y: Percent::from_unit(1.0),
             skew: 0.0,
+            transform_3d: None,
+            perspective_projection: None,
             next_avm1_clip: None,
             masker: None,
             maskee: None,
@@ -225,34 +301,11 @@ impl<'gc> DisplayObjectBase<'gc> {
     /// `_rotation` is accessed.
     fn cache_scale_rotation(&mut self) {
         if !self.scale_rotation_cached() {
-            let (a, b, c, d) = (
-                f64::from(self.transform.matrix.a),
-                f64::from(self.transform.matrix.b),
-                f64::from(self.transform.matrix.c),
-                f64::from(self.transform.matrix.d),
-            );
-            // If this object's transform matrix is:
-            // [[a c tx]
-            //  [b d ty]]
-            // After transformation, the X-axis and Y-axis will turn into the column vectors x' = <a, b> and y' = <c, d>.
-            // We derive the scale, rotation, and skew values from these transformed axes.
-            // The skew value is not exposed by ActionScript, but is remembered internally.
-            // xscale = len(x')
-            // yscale = len(y')
-            // rotation = atan2(b, a)  (the rotation of x' from the normal x-axis).
-            // skew = atan2(-c, d) - atan2(b, a)  (the signed difference between y' and x' rotation)
-
-            // This can produce some surprising results due to the overlap between flipping/rotation/skewing.
-            // For example, in Flash, using Modify->Transform->Flip Horizontal and then tracing _xscale, _yscale, and _rotation
-            // will output 100, 100, and 180. (a horizontal flip could also be a 180 degree skew followed by 180 degree rotation!)
-            let rotation_x = f64::atan2(b, a);
-            let rotation_y = f64::atan2(-c, d);
-            let scale_x = f64::sqrt(a * a + b * b);
-            let scale_y = f64::sqrt(c * c + d * d);
-            self.rotation = Degrees::from_radians(rotation_x);
-            self.scale_x = Percent::from_unit(scale_x);
-            self.scale_y = Percent::from_unit(scale_y);
-            self.skew = rotation_y - rotation_x;
+            let (rotation, scale_x, scale_y, skew) = scale_rotation_of(&self.transform.matrix);
+            self.rotation = rotation;
+            self.scale_x = scale_x;
+            self.scale_y = scale_y;
+            self.skew = skew;
         }
     }
 
@@ -338,6 +391,80 @@ impl<'gc> DisplayObjectBase<'gc> {
         matrix.d = (cos * value.unit()) as f32;
     }
 
+    pub fn transform_3d(&self) -> Option<&Transform3D> {
+        self.transform_3d.as_ref()
+    }
+
+    /// Returns the 3D part of this display object's transform, turning it into a 3D object.
+    pub fn transform_3d_mut(&mut self) -> &mut Transform3D {
+        self.set_transformed_by_script(true);
+        self.transform_3d.get_or_insert_with(Default::default)
+    }
+
+    /// The local 3D transform of this display object, or `None` if it's only transformed in 2D.
+    pub fn matrix3d(&self) -> Option<Matrix3D> {
+        let transform_3d = self.transform_3d.as_ref()?;
+        let (rotation, scale_x, scale_y) = if self.scale_rotation_cached() {
+            (self.rotation, self.scale_x, self.scale_y)
+        } else {
+            let (rotation, scale_x, scale_y, _) = scale_rotation_of(&self.transform.matrix);
+            (rotation, scale_x, scale_y)
+        };
+        Some(Matrix3D::compose(
+            [
+                self.transform.matrix.tx.to_pixels(),
+                self.transform.matrix.ty.to_pixels(),
+                transform_3d.z,
+            ],
+            [
+                transform_3d.rotation_x.into_radians(),
+                transform_3d.rotation_y.into_radians(),
+                rotation.into_radians(),
+            ],
+            [scale_x.unit(), scale_y.unit(), transform_3d.scale_z],
+        ))
+    }
+
+    /// Sets the local 3D transform of this display object.
+    /// `None` turns it back into a 2D object, keeping its 2D matrix.
+    pub fn set_matrix3d(&mut self, matrix3d: Option<Matrix3D>) {
+        self.set_transformed_by_script(true);
+        let Some(matrix3d) = matrix3d else {
+            self.transform_3d = None;
+            return;
+        };
+
+        let (translation, rotation, scale) = matrix3d.decompose();
+        self.transform_3d = Some(Transform3D {
+            z: translation[2],
+            rotation_x: Degrees::from_radians(rotation[0]),
+            rotation_y: Degrees::from_radians(rotation[1]),
+            scale_z: scale[2],
+        });
+        let (sin, cos) = rotation[2].sin_cos();
+        self.transform.matrix = Matrix {
+            a: (cos * scale[0]) as f32,
+            b: (sin * scale[0]) as f32,
+            c: (-sin * scale[1]) as f32,
+            d: (cos * scale[1]) as f32,
+            tx: Twips::from_pixels(translation[0]),
+            ty: Twips::from_pixels(translation[1]),
+        };
+        self.rotation = Degrees::from_radians(rotation[2]);
+        self.scale_x = Percent::from_unit(scale[0]);
+        self.scale_y = Percent::from_unit(scale[1]);
+        self.skew = 0.0;
+        self.set_scale_rotation_cached(true);
+    }
+
+    pub fn perspective_projection(&self) -> Option<&PerspectiveProjection> {
+        self.perspective_projection.as_ref()
+    }
+
+    pub fn set_perspective_projection(&mut self, projection: Option<PerspectiveProjection>) {
+        self.perspective_projection = projection;
+    }
+
     fn name(&self) -> AvmString<'gc> {
         self.name
     }
@@ -546,11 +673,62 @@ impl<'gc> DisplayObjectBase<'gc> {
     }
 }
 
+/// Calculates the rotation, X and Y scale and skew of a transform matrix.
+fn scale_rotation_of(matrix: &Matrix) -> (Degrees, Percent, Percent, f64) {
+    let (a, b, c, d) = (
+        f64::from(matrix.a),
+        f64::from(matrix.b),
+        f64::from(matrix.c),
+        f64::from(matrix.d),
+    );
+    // If this object's transform matrix is:
+    // [[a c tx]
+    //  [b d ty]]
+    // After transformation, the X-axis and Y-axis will turn into the column vectors x' = <a, b> and y' = <c, d>.
+    // We derive the scale, rotation, and skew values from these transformed axes.
+    // The skew value is not exposed by ActionScript, but is remembered internally.
+    // xscale = len(x')
+    // yscale = len(y')
+    // rotation = atan2(b, a)  (the rotation of x' from the normal x-axis).
+    // skew = atan2(-c, d) - atan2(b, a)  (the signed difference between y' and x' rotation)
+
+    // This can produce some surprising results due to the overlap between flipping/rotation/skewing.
+    // For example, in Flash, using Modify->Transform->Flip Horizontal and then tracing _xscale, _yscale, and _rotation
+    // will output 100, 100, and 180. (a horizontal flip could also be a 180 degree skew followed by 180 degree rotation!)
+    let rotation_x = f64::atan2(b, a);
+    let rotation_y = f64::atan2(-c, d);
+    let scale_x = f64::sqrt(a * a + b * b);
+    let scale_y = f64::sqrt(c * c + d * d);
+    (
+        Degrees::from_radians(rotation_x),
+        Percent::from_unit(scale_x),
+        Percent::from_unit(scale_y),
+        rotation_y - rotation_x,
+    )
+}
+
 pub fn render_base<'gc>(this: DisplayObject<'gc>, context: &mut RenderContext<'_, 'gc>) {
     if this.maskee().is_some() {
         return;
     }
-    context.transform_stack.push(this.base().transform());
+    let mut transform = this.base().transform().clone();
+    if let Some(matrix3d) = this.base().matrix3d() {
+        // Display objects with a 3D transform are drawn in perspective.
+        // Only the outermost one applies the projection, nested ones share its 3D space.
+        let projection = if context.transform_stack.transform().matrix3d.is_some() {
+            Matrix3D::IDENTITY
+        } else {
+            this.perspective_projection_matrix()
+        };
+        transform.matrix = Matrix::IDENTITY;
+        transform.matrix3d = Some(projection * matrix3d);
+    }
+    context.transform_stack.push(&transform);
+
+    // Filters are drawn over the bounds of the object, which don't move with its scroll rect.
+    let filters = this.filters();
+    let filter_matrix = context.transform_stack.transform().matrix;
+
     let blend_mode = this.blend_mode();
     let original_commands = if blend_mode != BlendMode::Normal {
         Some(std::mem::take(&mut context.commands))
@@ -563,7 +741,7 @@ pub fn render_base<'gc>(this: DisplayObject<'gc>, context: &mut RenderContext<'_
         // The matrix we use for actually drawing a rectangle for cropping purposes
         // Note that we do *not* apply the translation yet
         Some(
-            cur_transform.matrix
+            cur_transform.flattened_matrix()
                 * Matrix::scale(
                     rect.width().to_pixels() as f32,
                     rect.height().to_pixels() as f32,
@@ -577,7 +755,7 @@ pub fn render_base<'gc>(this: DisplayObject<'gc>, context: &mut RenderContext<'_
         // Translate everything that we render (including DisplayObject.mask)
         context.transform_stack.push(&Transform {
             matrix: Matrix::translate(-rect.x_min, -rect.y_min),
-            color_transform: Default::default(),
+            ..Default::default()
         });
     }
 
@@ -611,7 +789,9 @@ pub fn render_base<'gc>(this: DisplayObject<'gc>, context: &mut RenderContext<'_
         context.commands.activate_mask();
     }
 
-    this.render_self(context);
+    if filters.is_empty() || !render_filtered_self(this, context, &filters, filter_matrix) {
+        this.render_self(context);
+    }
 
     if let Some(rect_mat) = scroll_rect_matrix {
         // Draw the rectangle again after deactivating the mask,
@@ -642,6 +822,118 @@ pub fn render_base<'gc>(this: DisplayObject<'gc>, context: &mut RenderContext<'_
     context.transform_stack.pop();
 }
 
+/// The largest width or height of the bitmap that a display object with filters is
+/// drawn to. Larger objects are drawn without their filters.
+const MAX_FILTERED_SIZE: u32 = 4096;
+
+/// Renders a display object with its filters, by drawing it to an offscreen bitmap
+/// and applying every filter to that bitmap in order.
+///
+/// The bitmap covers the bounds of the object transformed by `bounds_matrix`, with
+/// enough room around them for the filters to draw into. It's drawn in place of the
+/// object, and the color transform is applied to the filtered result.
+///
+/// Returns `false` without drawing anything if the renderer can't apply the filters,
+/// in which case the object should be drawn without them.
+fn render_filtered_self<'gc>(
+    this: DisplayObject<'gc>,
+    context: &mut RenderContext<'_, 'gc>,
+    filters: &[Filter],
+    bounds_matrix: Matrix,
+) -> bool {
+    let transform = context.transform_stack.transform();
+    if transform.matrix3d.is_some()
+        || !filters
+            .iter()
+            .all(|filter| context.renderer.is_filter_supported(filter))
+    {
+        return false;
+    }
+
+    let bounds = this.bounds_with_transform(&bounds_matrix);
+    if !bounds.is_valid() {
+        return true;
+    }
+
+    let (padding_x, padding_y) = filters.iter().fold((0, 0), |(x, y), filter| {
+        let (filter_x, filter_y) = filter.padding();
+        (x + filter_x, y + filter_y)
+    });
+    let x_min = bounds.x_min.to_pixels().floor() as i32 - padding_x as i32;
+    let y_min = bounds.y_min.to_pixels().floor() as i32 - padding_y as i32;
+    let width = (bounds.x_max.to_pixels().ceil() as i32 - x_min) as u32 + padding_x;
+    let height = (bounds.y_max.to_pixels().ceil() as i32 - y_min) as u32 + padding_y;
+    if width > MAX_FILTERED_SIZE || height > MAX_FILTERED_SIZE {
+        return false;
+    }
+
+    let bitmap = ruffle_render::bitmap::Bitmap::new(
+        width,
+        height,
+        BitmapFormat::Rgba,
+        vec![0; width as usize * height as usize * 4],
+    );
+    let handle = match context.renderer.register_bitmap(bitmap) {
+        Ok(handle) => handle,
+        Err(e) => {
+            tracing::warn!("Failed to register bitmap for filters: {:?}", e);
+            return false;
+        }
+    };
+
+    let mut transform_stack = TransformStack::new();
+    transform_stack.push(&Transform {
+        matrix: Matrix::translate(
+            Twips::from_pixels_i32(-x_min),
+            Twips::from_pixels_i32(-y_min),
+        ) * transform.matrix,
+        ..Default::default()
+    });
+    let mut offscreen_context = RenderContext {
+        renderer: context.renderer,
+        commands: CommandList::new(),
+        gc_context: context.gc_context,
+        library: context.library,
+        transform_stack: &mut transform_stack,
+        is_offscreen: true,
+        stage: context.stage,
+    };
+    this.render_self(&mut offscreen_context);
+    let commands = offscreen_context.commands;
+
+    let region = PixelRegion::for_whole_size(width, height);
+    let quality = context.stage.quality();
+    if context
+        .renderer
+        .render_offscreen(handle.clone(), commands, quality, region)
+        .is_none()
+    {
+        return false;
+    }
+
+    for filter in filters {
+        context.renderer.apply_filter(
+            handle.clone(),
+            (0, 0),
+            (width, height),
+            handle.clone(),
+            (0, 0),
+            filter.clone(),
+        );
+    }
+
+    context.commands.render_bitmap(
+        handle,
+        Transform {
+            matrix: Matrix::translate(Twips::from_pixels_i32(x_min), Twips::from_pixels_i32(y_min)),
+            color_transform: transform.color_transform,
+            matrix3d: None,
+        },
+        false,
+    );
+    true
+}
+
 #[enum_trait_object(
     #[derive(Clone, Collect, Debug, Copy)]
     #[collect(no_drop)]
@@ -703,6 +995,27 @@ pub trait TDisplayObject<'gc>:
 
     /// The world bounding box of this object including children, relative to the stage.
     fn world_bounds(&self) -> Rectangle<Twips> {
+        if let Some(matrix3d) = self.local_to_global_matrix3d() {
+            // Project the corners of the local bounds, falling back to the flattened
+            // transform if any of them is behind the viewer.
+            let bounds = self.bounds();
+            let corners = [
+                Point::new(bounds.x_min, bounds.y_min),
+                Point::new(bounds.x_max, bounds.y_min),
+                Point::new(bounds.x_min, bounds.y_max),
+                Point::new(bounds.x_max, bounds.y_max),
+            ];
+            let projected: Option<Vec<_>> = corners
+                .iter()
+                .map(|corner| matrix3d.project(*corner))
+                .collect();
+            return match projected {
+                Some(projected) if bounds.is_valid() => projected
+                    .into_iter()
+                    .fold(Rectangle::default(), Rectangle::encompass),
+                _ => self.bounds_with_transform(&matrix3d.to_matrix()),
+            };
+        }
         self.bounds_with_transform(&self.local_to_global_matrix())
     }
 
@@ -727,7 +1040,12 @@ pub trait TDisplayObject<'gc>:
 
         if let Some(ctr) = self.as_container() {
             for child in ctr.iter_render_list() {
-                let matrix = *matrix * *child.base().matrix();
+                // Children with a 3D transform are approximated by their flattened transform.
+                let child_matrix = match child.base().matrix3d() {
+                    Some(matrix3d) => matrix3d.to_matrix(),
+                    None => *child.base().matrix(),
+                };
+                let matrix = *matrix * child_matrix;
                 bounds = bounds.union(&child.bounds_with_transform(&matrix));
             }
         }
@@ -775,7 +1093,13 @@ pub trait TDisplayObject<'gc>:
     }
 
     /// Returns the matrix for transforming from this object's local space to global stage space.
+    ///
+    /// If this object is drawn in perspective, this is only an approximation around its origin.
+    /// Use `local_to_global_matrix3d` to get the exact transform.
     fn local_to_global_matrix(&self) -> Matrix {
+        if let Some(matrix3d) = self.local_to_global_matrix3d() {
+            return matrix3d.to_matrix();
+        }
         let mut matrix = Matrix::IDENTITY;
         if let Some(rect) = self.scroll_rect() {
             matrix = Matrix::translate(-rect.x_min, -rect.y_min) * matrix;
@@ -789,14 +1113,106 @@ pub trait TDisplayObject<'gc>:
         self.local_to_global_matrix().inverse()
     }
 
+    /// Returns the matrix for transforming from this object's local space to global stage space,
+    /// including the perspective projection.
+    ///
+    /// Returns `None` if neither this object nor any of its ancestors has a 3D transform.
+    fn local_to_global_matrix3d(&self) -> Option<Matrix3D> {
+        let this: DisplayObject<'gc> = (*self).into();
+
+        // Most display objects are only transformed in 2D, so check that first.
+        let mut node = Some(this);
+        let mut is_3d = false;
+        while let Some(display_object) = node {
+            if display_object.as_stage().is_some() {
+                break;
+            }
+            if display_object.base().transform_3d().is_some() {
+                is_3d = true;
+                break;
+            }
+            node = display_object.parent();
+        }
+        if !is_3d {
+            return None;
+        }
+
+        // The perspective projection goes right above the outermost object with a 3D transform.
+        // `below` transforms into the parent space of the last 3D object that we've passed,
+        // and `above` collects the 2D transforms from there on up.
+        let mut below = Matrix3D::IDENTITY;
+        let mut above = Matrix::IDENTITY;
+        let mut outermost = this;
+        let mut node = Some(this);
+        while let Some(display_object) = node {
+            if display_object.as_stage().is_some() {
+                break;
+            }
+            if let Some(rect) = display_object.scroll_rect() {
+                above = Matrix::translate(-rect.x_min, -rect.y_min) * above;
+            }
+            let matrix3d = display_object.base().matrix3d();
+            match matrix3d {
+                Some(matrix3d) => {
+                    below = matrix3d * above * below;
+                    above = Matrix::IDENTITY;
+                    outermost = display_object;
+                }
+                None => above = *display_object.base().matrix() * above,
+            }
+            node = display_object.parent();
+        }
+
+        Some(Matrix3D::from(above) * outermost.perspective_projection_matrix() * below)
+    }
+
+    /// The perspective projection that this object is drawn with, if it's the outermost object
+    /// with a 3D transform. This is in the coordinate space of its parent.
+    ///
+    /// The projection is taken from the nearest ancestor with a `perspectiveProjection`,
+    /// or is the default projection of the movie.
+    fn perspective_projection_matrix(&self) -> Matrix3D {
+        let movie = self.movie();
+        let mut projection = PerspectiveProjection::for_movie(&movie);
+        let mut center = Point::from_pixels(projection.center.0, projection.center.1);
+
+        let mut node = self.parent();
+        while let Some(display_object) = node {
+            if let Some(ancestor_projection) = display_object.base().perspective_projection() {
+                projection = ancestor_projection.clone();
+                center = display_object
+                    .local_to_global(Point::from_pixels(projection.center.0, projection.center.1));
+                break;
+            }
+            node = display_object.parent();
+        }
+
+        let center = self
+            .parent()
+            .and_then(|parent| parent.global_to_local(center))
+            .unwrap_or(center);
+        Matrix3D::perspective_projection(
+            projection.focal_length(&movie),
+            (center.x.to_pixels(), center.y.to_pixels()),
+        )
+    }
+
     /// Converts a local position to a global stage position
     fn local_to_global(&self, local: Point<Twips>) -> Point<Twips> {
+        if let Some(matrix3d) = self.local_to_global_matrix3d() {
+            if let Some(global) = matrix3d.project(local) {
+                return global;
+            }
+        }
         self.local_to_global_matrix() * local
     }
 
     /// Converts a local position on the stage to a local position on this display object
     /// Returns `None` if the object has zero scale.
     fn global_to_local(&self, global: Point<Twips>) -> Option<Point<Twips>> {
+        if let Some(matrix3d) = self.local_to_global_matrix3d() {
+            return matrix3d.unproject(global);
+        }
         self.global_to_local_matrix().map(|matrix| matrix * global)
     }
 
@@ -804,6 +1220,11 @@ pub trait TDisplayObject<'gc>:
     /// If the object has zero scale, then the stage `TWIPS_TO_PIXELS` matrix will be used.
     /// This matches Flash's behavior for `mouseX`/`mouseY` on an object with zero scale.
     fn mouse_to_local(&self, global: Point<Twips>) -> Point<Twips> {
+        if let Some(matrix3d) = self.local_to_global_matrix3d() {
+            if let Some(local) = matrix3d.unproject(global) {
+                return local;
+            }
+        }
         // MIKE: I suspect the `TWIPS_TO_PIXELS` scale should always be involved in the
         // calculation somehow, not just in the non-invertible case.
         self.global_to_local_matrix()
@@ -19,7 +19,9 @@ use crate::display_object::{DisplayObjectBase, DisplayObjectPtr, TDisplayObject}
 use crate::drawing::Drawing;
 use crate::events::{ClipEvent, ClipEventResult, TextControlCode};
 use crate::font::{round_down_to_pixel, Glyph, TextRenderSettings};
-use crate::html::{BoxBounds, FormatSpans, LayoutBox, LayoutContent, LayoutMetrics, TextFormat};
+use crate::html::{
+    BoxBounds, FormatSpans, LayoutBox, LayoutContent, LayoutMetrics, StyleSheet, TextFormat,
+};
 use crate::prelude::*;
 use crate::string::{utils as string_utils, AvmString, SwfStrExt as _, WStr, WString};
 use crate::tag_utils::SwfMovie;
@@ -85,6 +87,22 @@ pub struct EditTextData<'gc> {
     /// rendering.
     text_spans: FormatSpans,
 
+    /// The CSS style sheet applied to HTML text, if any.
+    style_sheet: Option<StyleSheet>,
+
+    /// The AVM1 `TextField.StyleSheet` object the style sheet was set from.
+    avm1_style_sheet: Option<Avm1Object<'gc>>,
+
+    /// The HTML source of the text, kept while a style sheet is present.
+    ///
+    /// Styled text can't be raised back into HTML, so this is what `htmlText`
+    /// returns, and what gets restyled when the hovered link changes.
+    html_source: Option<WString>,
+
+    /// The text range of the link under the mouse cursor, if the style sheet
+    /// has an `a:hover` style.
+    hovered_link: Option<(usize, usize)>,
+
     /// The color of the background fill. Only applied when has_border and has_background.
     #[collect(require_static)]
     background_color: Color,
@@ -143,6 +161,25 @@ pub struct EditTextData<'gc> {
     flags: EditTextFlag,
 }
 
+impl<'gc> EditTextData<'gc> {
+    /// Lower HTML into the text spans of this text field, applying the style
+    /// sheet (if any).
+    fn lower_html(&mut self, html: &WStr) {
+        let default_format = self.text_spans.default_format().clone();
+        let is_multiline = self.flags.contains(EditTextFlag::MULTILINE);
+        self.text_spans = match &self.style_sheet {
+            Some(style_sheet) => FormatSpans::from_styled_html(
+                html,
+                default_format,
+                style_sheet,
+                self.hovered_link.map(|(start, _)| start),
+                is_multiline,
+            ),
+            None => FormatSpans::from_html(html, default_format, is_multiline),
+        };
+    }
+}
+
 // TODO: would be nicer to compute (and return) this during layout, instead of afterwards
 /// Compute line (index, offset, extent) from the layout data.
 fn get_line_data(layout: &[LayoutBox]) -> Vec<LineData> {
@@ -253,6 +290,10 @@ impl<'gc> EditText<'gc> {
             EditTextData {
                 base,
                 text_spans,
+                style_sheet: None,
+                avm1_style_sheet: None,
+                html_source: None,
+                hovered_link: None,
                 static_data: gc_arena::Gc::allocate(
                     context.gc_context,
                     EditTextStatic {
@@ -336,6 +377,8 @@ impl<'gc> EditText<'gc> {
         let mut edit_text = self.0.write(context.gc_context);
         let default_format = edit_text.text_spans.default_format().clone();
         edit_text.text_spans = FormatSpans::from_text(text.into(), default_format);
+        edit_text.html_source = None;
+        edit_text.hovered_link = None;
         drop(edit_text);
 
         self.relayout(context);
@@ -343,7 +386,11 @@ impl<'gc> EditText<'gc> {
 
     pub fn html_text(self) -> WString {
         if self.is_html() {
-            self.0.read().text_spans.to_html()
+            let edit_text = self.0.read();
+            if let Some(html_source) = &edit_text.html_source {
+                return html_source.clone();
+            }
+            edit_text.text_spans.to_html()
         } else {
             // Non-HTML text fields always return plain text.
             self.text()
@@ -353,12 +400,9 @@ impl<'gc> EditText<'gc> {
     pub fn set_html_text(self, text: &WStr, context: &mut UpdateContext<'_, 'gc>) {
         if self.is_html() {
             let mut write = self.0.write(context.gc_context);
-            let default_format = write.text_spans.default_format().clone();
-            write.text_spans = FormatSpans::from_html(
-                text,
-                default_format,
-                write.flags.contains(EditTextFlag::MULTILINE),
-            );
+            write.hovered_link = None;
+            write.html_source = write.style_sheet.is_some().then(|| text.into());
+            write.lower_html(text);
             drop(write);
 
             self.relayout(context);
@@ -367,6 +411,90 @@ impl<'gc> EditText<'gc> {
         }
     }
 
+    pub fn avm1_style_sheet(self) -> Option<Avm1Object<'gc>> {
+        self.0.read().avm1_style_sheet
+    }
+
+    pub fn set_avm1_style_sheet(
+        self,
+        context: &mut UpdateContext<'_, 'gc>,
+        object: Option<Avm1Object<'gc>>,
+    ) {
+        self.0.write(context.gc_context).avm1_style_sheet = object;
+    }
+
+    /// Set the CSS style sheet used to style HTML text.
+    ///
+    /// Any existing HTML text is restyled with the new style sheet.
+    pub fn set_style_sheet(
+        self,
+        context: &mut UpdateContext<'_, 'gc>,
+        style_sheet: Option<StyleSheet>,
+    ) {
+        let mut write = self.0.write(context.gc_context);
+        write.style_sheet = style_sheet;
+        write.hovered_link = None;
+        if write.flags.contains(EditTextFlag::HTML) {
+            let html = match write.html_source.take() {
+                Some(html) => html,
+                None => write.text_spans.to_html(),
+            };
+            write.lower_html(&html);
+            write.html_source = write.style_sheet.is_some().then_some(html);
+        }
+        drop(write);
+
+        self.relayout(context);
+    }
+
+    /// Update which link is hovered by the mouse cursor, restyling the text
+    /// if the style sheet has an `a:hover` style.
+    fn set_hovered_index(self, context: &mut UpdateContext<'_, 'gc>, index: Option<usize>) {
+        let edit_text = self.0.read();
+        if !edit_text
+            .style_sheet
+            .as_ref()
+            .map_or(false, StyleSheet::has_hover_style)
+        {
+            return;
+        }
+
+        let hovered_link = index.and_then(|index| {
+            // A link may be made of several spans with different formats.
+            let spans: Vec<_> = edit_text.text_spans.iter_spans().collect();
+            let hovered = spans
+                .iter()
+                .position(|(start, end, _, _)| (*start..*end).contains(&index))?;
+            let url = &spans[hovered].3.url;
+            if url.is_empty() {
+                return None;
+            }
+
+            let first = spans[..hovered]
+                .iter()
+                .rposition(|(_, _, _, span)| &span.url != url)
+                .map_or(0, |i| i + 1);
+            let last = spans[hovered..]
+                .iter()
+                .position(|(_, _, _, span)| &span.url != url)
+                .map_or(spans.len(), |i| hovered + i);
+            Some((spans[first].0, spans[last - 1].1))
+        });
+        if hovered_link == edit_text.hovered_link {
+            return;
+        }
+        drop(edit_text);
+
+        let mut write = self.0.write(context.gc_context);
+        write.hovered_link = hovered_link;
+        if let Some(html) = write.html_source.clone() {
+            write.lower_html(&html);
+        }
+        drop(write);
+
+        self.relayout(context);
+    }
+
     pub fn text_length(self) -> usize {
         self.0.read().text_spans.text().len()
     }
@@ -549,10 +677,11 @@ impl<'gc> EditText<'gc> {
         text: &WStr,
         context: &mut UpdateContext<'_, 'gc>,
     ) {
-        self.0
-            .write(context.gc_context)
-            .text_spans
-            .replace_text(from, to, text, None);
+        let mut write = self.0.write(context.gc_context);
+        write.text_spans.replace_text(from, to, text, None);
+        write.html_source = None;
+        write.hovered_link = None;
+        drop(write);
         self.relayout(context);
     }
 
@@ -889,6 +1018,7 @@ impl<'gc> EditText<'gc> {
                             context.transform_stack.push(&Transform {
                                 matrix: transform.matrix,
                                 color_transform: ColorTransform::IDENTITY,
+                                matrix3d: None,
                             });
                         }
                         _ => {
@@ -1878,18 +2008,32 @@ impl<'gc> TInteractiveObject<'gc> for EditText<'gc> {
         _context: &mut UpdateContext<'_, 'gc>,
         event: ClipEvent,
     ) -> ClipEventResult {
-        if event != ClipEvent::Press {
-            return ClipEventResult::NotHandled;
+        match event {
+            ClipEvent::Press | ClipEvent::MouseMoveInside | ClipEvent::RollOut { .. } => {
+                ClipEventResult::Handled
+            }
+            _ => ClipEventResult::NotHandled,
         }
-
-        ClipEventResult::Handled
     }
 
     fn event_dispatch(
         self,
         context: &mut UpdateContext<'_, 'gc>,
-        _event: ClipEvent<'gc>,
+        event: ClipEvent<'gc>,
     ) -> ClipEventResult {
+        match event {
+            ClipEvent::MouseMoveInside => {
+                let index = self.screen_position_to_index(*context.mouse_position);
+                self.set_hovered_index(context, index);
+                return ClipEventResult::NotHandled;
+            }
+            ClipEvent::RollOut { .. } => {
+                self.set_hovered_index(context, None);
+                return ClipEventResult::NotHandled;
+            }
+            _ => {}
+        }
+
         if self.is_editable() || self.is_selectable() {
             let tracker = context.focus_tracker;
             tracker.set(Some(self.into()), context);
@@ -205,7 +205,7 @@ impl<'gc> TDisplayObject<'gc> for Graphic<'gc> {
             && self.world_bounds().contains(point)
         {
             let Some(local_matrix) = self.global_to_local_matrix() else { return false; };
-            let point = local_matrix * point;
+            let Some(point) = self.global_to_local(point) else { return false; };
             if let Some(drawing) = &self.0.read().drawing {
                 if drawing.hit_test(point, &local_matrix) {
                     return true;
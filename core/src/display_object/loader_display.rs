@@ -1,4 +1,6 @@
+use crate::avm2::object::LoaderInfoObject;
 use crate::avm2::Activation;
+use crate::avm2::Avm2;
 use crate::avm2::Object as Avm2Object;
 use crate::context::RenderContext;
 use crate::context::UpdateContext;
@@ -60,6 +62,97 @@ impl<'gc> LoaderDisplay<'gc> {
     pub fn downgrade(self) -> LoaderDisplayWeak<'gc> {
         LoaderDisplayWeak(GcCell::downgrade(self.0))
     }
+
+    /// Remove the loaded content, cancelling any load still in progress, and
+    /// reset the given `LoaderInfo`.
+    ///
+    /// If `stop` is set, as with `Loader.unloadAndStop`, the content is also
+    /// stopped before being removed. Its clips and sounds stop playing, it
+    /// stops receiving broadcast events, and its timers are cancelled.
+    ///
+    /// The libraries of the unloaded movies are kept, so objects from them
+    /// that the parent still holds on to keep working. The library sounds and
+    /// timers of movies that were loaded into the loader's own application
+    /// domain are left alone, as that domain is shared with the parent.
+    pub fn unload(
+        mut self,
+        context: &mut UpdateContext<'_, 'gc>,
+        loader_info: LoaderInfoObject<'gc>,
+        stop: bool,
+    ) {
+        context
+            .load_manager
+            .cancel_avm2_movie_loads(loader_info.into());
+
+        if let Some(content) = self.child_by_index(0) {
+            if stop {
+                self.stop_content(context, content);
+            }
+            self.remove_child(context, content);
+        }
+
+        loader_info.unload(context);
+    }
+
+    fn stop_content(self, context: &mut UpdateContext<'_, 'gc>, content: DisplayObject<'gc>) {
+        let mut display_objects = vec![content];
+        let mut movies: Vec<Arc<SwfMovie>> = vec![];
+
+        while let Some(display_object) = display_objects.pop() {
+            if let Some(clip) = display_object.as_movie_clip() {
+                clip.stop(context);
+            }
+            context
+                .audio_manager
+                .stop_sounds_with_display_object(context.audio, display_object);
+            if let Avm2Value::Object(object) = display_object.object2() {
+                Avm2::unregister_broadcast_listener(context, object);
+            }
+
+            let movie = display_object.movie();
+            if !movies.iter().any(|other| Arc::ptr_eq(other, &movie)) {
+                movies.push(movie);
+            }
+
+            if let Some(container) = display_object.as_container() {
+                display_objects.extend(container.iter_render_list());
+            }
+        }
+
+        let own_movie = self.movie();
+        let own_domain = context
+            .library
+            .library_for_movie(own_movie.clone())
+            .and_then(|library| library.try_avm2_domain());
+
+        for movie in movies {
+            // Never tear down the movie this loader itself belongs to.
+            if Arc::ptr_eq(&movie, &own_movie) || Arc::ptr_eq(&movie, context.swf) {
+                continue;
+            }
+            let Some(library) = context.library.library_for_movie(movie) else {
+                continue;
+            };
+            let domain = library.try_avm2_domain();
+
+            // Content loaded into the loader's own domain shares its classes
+            // and timers with the parent, so it has to be left alone.
+            if domain.is_some() && domain == own_domain {
+                continue;
+            }
+
+            let sounds: Vec<_> = library.sounds().collect();
+            for sound in sounds {
+                context
+                    .audio_manager
+                    .stop_sounds_with_handle(context.audio, sound);
+            }
+
+            if let Some(domain) = domain {
+                context.timers.remove_avm2_timers_in_domain(domain);
+            }
+        }
+    }
 }
 
 impl<'gc> TDisplayObject<'gc> for LoaderDisplay<'gc> {
@@ -17,6 +17,7 @@ use crate::avm1::{Activation as Avm1Activation, ActivationIdentifier};
 use crate::binary_data::BinaryData;
 use crate::character::Character;
 use crate::context::{ActionType, RenderContext, UpdateContext};
+use crate::debugger::CodeLocation;
 use crate::display_object::container::{
     dispatch_removed_event, ChildContainer, TDisplayObjectContainer,
 };
@@ -2542,13 +2543,19 @@ impl<'gc> TDisplayObject<'gc> for MovieClip<'gc> {
                                 .unwrap()
                                 .avm2_domain();
 
-                            if let Err(e) = Avm2::run_stack_frame_for_callable(
+                            context.debugger.enter_frame(|| CodeLocation::FrameScript {
+                                clip: self.path().to_string(),
+                                frame: frame_id,
+                            });
+                            let result = Avm2::run_stack_frame_for_callable(
                                 callable,
                                 Some(avm2_object),
                                 &[],
                                 domain,
                                 context,
-                            ) {
+                            );
+                            context.debugger.exit_frame();
+                            if let Err(e) = result {
                                 tracing::error!(
                                     "Error occured when running AVM2 frame script: {}",
                                     e
@@ -2653,7 +2660,7 @@ impl<'gc> TDisplayObject<'gc> for MovieClip<'gc> {
                 }
             }
 
-            let point = local_matrix * point;
+            let Some(point) = self.global_to_local(point) else { return false; };
             if self.0.read().drawing.hit_test(point, &local_matrix) {
                 return true;
             }
@@ -2993,7 +3000,7 @@ impl<'gc> TInteractiveObject<'gc> for MovieClip<'gc> {
 
             // Check drawing, because this selects the current clip, it must have mouse enabled
             if self.mouse_enabled() && check_non_interactive {
-                let point = local_matrix * point;
+                let Some(point) = self.global_to_local(point) else { return None; };
                 if self.0.read().drawing.hit_test(point, &local_matrix) {
                     return Some(this);
                 }
@@ -3116,7 +3123,7 @@ impl<'gc> TInteractiveObject<'gc> for MovieClip<'gc> {
 
             // Check drawing, because this selects the current clip, it must have mouse enabled
             if self.world_bounds().contains(point) {
-                let point = local_matrix * point;
+                let Some(point) = self.global_to_local(point) else { return Avm2MousePick::Miss; };
 
                 if self.0.read().drawing.hit_test(point, &local_matrix) {
                     return if self.mouse_enabled() {
@@ -123,6 +123,11 @@ pub struct StageData<'gc> {
     /// Whether to show default context menu items
     show_menu: bool,
 
+    /// The cursor set with AS3 `Mouse.cursor`, which replaces the cursor
+    /// chosen by the hovered object. `None` if the cursor is `auto`.
+    #[collect(require_static)]
+    mouse_cursor_override: Option<MouseCursor>,
+
     /// The AVM2 view of this stage object.
     avm2_object: Avm2Object<'gc>,
 
@@ -176,6 +181,7 @@ impl<'gc> Stage<'gc> {
                 view_bounds: Default::default(),
                 window_mode: Default::default(),
                 show_menu: true,
+                mouse_cursor_override: None,
                 stage_focus_rect: true,
                 avm2_object: Avm2ScriptObject::custom_object(gc_context, None, None),
                 loader_info: Avm2ScriptObject::custom_object(gc_context, None, None),
@@ -204,7 +210,6 @@ impl<'gc> Stage<'gc> {
             .unwrap_or(Matrix::ZERO)
     }
 
-    #[allow(dead_code)]
     pub fn view_matrix(self) -> Matrix {
         self.0.read().viewport_matrix
     }
@@ -443,6 +448,19 @@ impl<'gc> Stage<'gc> {
         write.show_menu = show_menu;
     }
 
+    /// The cursor set with AS3 `Mouse.cursor`, if it isn't `auto`.
+    pub fn mouse_cursor_override(self) -> Option<MouseCursor> {
+        self.0.read().mouse_cursor_override
+    }
+
+    pub fn set_mouse_cursor_override(
+        self,
+        gc_context: MutationContext<'gc, '_>,
+        cursor: Option<MouseCursor>,
+    ) {
+        self.0.write(gc_context).mouse_cursor_override = cursor;
+    }
+
     /// Determine if we should letterbox the stage content.
     fn should_letterbox(self) -> bool {
         // Only enable letterbox in the default `ShowAll` scale mode.
@@ -809,7 +827,7 @@ impl<'gc> TDisplayObject<'gc> for Stage<'gc> {
     fn render(&self, context: &mut RenderContext<'_, 'gc>) {
         context.transform_stack.push(&Transform {
             matrix: self.0.read().viewport_matrix,
-            color_transform: Default::default(),
+            ..Default::default()
         });
 
         // All of our Stage3D instances get rendered *underneath* the main stage.
@@ -183,9 +183,10 @@ impl<'gc> TDisplayObject<'gc> for Text<'gc> {
 
             // Transform the point into the text's local space.
             let Some(local_matrix) = self.global_to_local_matrix() else { return false; };
+            let Some(local_point) = self.global_to_local(point) else { return false; };
             let tf = self.0.read();
             let Some(text_matrix) = tf.static_data.text_transform.inverse() else { return false; };
-            point = text_matrix * local_matrix * point;
+            point = text_matrix * local_point;
 
             let mut font_id = 0;
             let mut height = Twips::ZERO;
@@ -101,6 +101,15 @@ pub enum VideoSource<'gc> {
         /// to reconstruct a reference to the embedded bitstream.
         frames: BTreeMap<u32, (usize, usize)>,
     },
+    /// A video player that has not been attached to any video source yet.
+    Unconnected {
+        /// The movie whose code created the Video object.
+        movie: Arc<SwfMovie>,
+
+        /// The size of the video player.
+        #[collect(require_static)]
+        size: Rectangle<Twips>,
+    },
     /// An attached NetStream.
     NetStream {
         /// The movie whose code created the Video object.
@@ -147,18 +156,64 @@ impl<'gc> Video<'gc> {
         ))
     }
 
+    /// Construct a Video object with no video source.
+    ///
+    /// This is used for video players constructed by ActionScript, which
+    /// are expected to be attached to a `NetStream` later.
+    pub fn new(context: &mut UpdateContext<'_, 'gc>, width: i32, height: i32) -> Self {
+        let source = GcCell::allocate(
+            context.gc_context,
+            VideoSource::Unconnected {
+                movie: context.swf.clone(),
+                size: Rectangle {
+                    x_min: Twips::ZERO,
+                    y_min: Twips::ZERO,
+                    x_max: Twips::from_pixels_i32(width),
+                    y_max: Twips::from_pixels_i32(height),
+                },
+            },
+        );
+
+        Video(GcCell::allocate(
+            context.gc_context,
+            VideoData {
+                base: Default::default(),
+                source,
+                stream: VideoStream::Uninstantiated(0),
+                decoded_frame: None,
+                object: None,
+                keyframes: BTreeSet::new(),
+            },
+        ))
+    }
+
+    /// Change the size of a video player with no video source.
+    pub fn set_size(self, context: &mut UpdateContext<'_, 'gc>, width: i32, height: i32) {
+        let read = self.0.read();
+        if let VideoSource::Unconnected { size, .. } = &mut *read.source.write(context.gc_context) {
+            *size = Rectangle {
+                x_min: Twips::ZERO,
+                y_min: Twips::ZERO,
+                x_max: Twips::from_pixels_i32(width),
+                y_max: Twips::from_pixels_i32(height),
+            };
+        }
+    }
+
     /// Convert this Video into a NetStream sourced video.
     ///
     /// Existing video state related to the old video stream will be dropped.
+    /// The video keeps the size it had before the stream was attached.
     pub fn attach_netstream(self, context: &mut UpdateContext<'_, 'gc>, stream: NetStream<'gc>) {
         let movie = self.movie();
+        let size = self.self_bounds();
         let mut video = self.0.write(context.gc_context);
 
         *video.source.write(context.gc_context) = VideoSource::NetStream {
             movie,
             stream,
             num_frames: None,
-            size: None,
+            size: Some(size),
         };
 
         video.stream = VideoStream::Uninstantiated(0);
@@ -190,7 +245,7 @@ impl<'gc> Video<'gc> {
 
                 frames.insert(tag.frame_num.into(), (subslice.start, subslice.end));
             }
-            VideoSource::NetStream { .. } => {}
+            VideoSource::Unconnected { .. } | VideoSource::NetStream { .. } => {}
         }
     }
 
@@ -214,6 +269,7 @@ impl<'gc> Video<'gc> {
 
         let num_frames = match &*read.source.read() {
             VideoSource::Swf { streamdef, .. } => Some(streamdef.num_frames as usize),
+            VideoSource::Unconnected { .. } => None,
             VideoSource::NetStream { num_frames, .. } => *num_frames,
         };
 
@@ -307,7 +363,7 @@ impl<'gc> Video<'gc> {
                     }
                 }
             },
-            VideoSource::NetStream { .. } => return,
+            VideoSource::Unconnected { .. } | VideoSource::NetStream { .. } => return,
         };
 
         drop(read);
@@ -403,7 +459,7 @@ impl<'gc> TDisplayObject<'gc> for Video<'gc> {
 
                 (stream, movie.clone(), keyframes)
             }
-            VideoSource::NetStream { .. } => return,
+            VideoSource::Unconnected { .. } | VideoSource::NetStream { .. } => return,
         };
 
         let starting_seek = if let VideoStream::Uninstantiated(seek_to) = write.stream {
@@ -459,7 +515,7 @@ impl<'gc> TDisplayObject<'gc> for Video<'gc> {
     fn id(&self) -> CharacterId {
         match &*self.0.read().source.read() {
             VideoSource::Swf { streamdef, .. } => streamdef.id,
-            VideoSource::NetStream { .. } => 0,
+            VideoSource::Unconnected { .. } | VideoSource::NetStream { .. } => 0,
         }
     }
 
@@ -471,6 +527,7 @@ impl<'gc> TDisplayObject<'gc> for Video<'gc> {
                 x_max: Twips::from_pixels_i32(streamdef.width.into()),
                 y_max: Twips::from_pixels_i32(streamdef.height.into()),
             },
+            VideoSource::Unconnected { size, .. } => size.clone(),
             VideoSource::NetStream { size, .. } => size.clone().unwrap_or_default(),
         }
     }
@@ -485,7 +542,15 @@ impl<'gc> TDisplayObject<'gc> for Video<'gc> {
 
         let read = self.0.read();
 
-        if let Some((_frame_id, ref bitmap)) = read.decoded_frame {
+        let bitmap = match &*read.source.read() {
+            VideoSource::NetStream { stream, .. } => stream.last_decoded_bitmap(),
+            _ => read
+                .decoded_frame
+                .as_ref()
+                .map(|(_, bitmap)| bitmap.clone()),
+        };
+
+        if let Some(bitmap) = bitmap {
             let mut transform = context.transform_stack.transform();
             let bounds = self.self_bounds();
 
@@ -503,6 +568,7 @@ impl<'gc> TDisplayObject<'gc> for Video<'gc> {
                     frames,
                     movie,
                 } => (streamdef.is_smoothed, frames.len(), movie.version()),
+                VideoSource::Unconnected { movie, .. } => (false, 0, movie.version()),
                 VideoSource::NetStream { num_frames, .. } => {
                     (false, num_frames.unwrap_or(0), self.movie().version())
                 }
@@ -519,7 +585,7 @@ impl<'gc> TDisplayObject<'gc> for Video<'gc> {
             context
                 .commands
                 .render_bitmap(bitmap.handle.clone(), transform, smoothing);
-        } else {
+        } else if matches!(&*read.source.read(), VideoSource::Swf { .. }) {
             tracing::warn!("Video has no decoded frame to render.");
         }
 
@@ -533,6 +599,7 @@ impl<'gc> TDisplayObject<'gc> for Video<'gc> {
     fn movie(&self) -> Arc<SwfMovie> {
         match &*self.0.read().source.read() {
             VideoSource::Swf { movie, .. } => movie.clone(),
+            VideoSource::Unconnected { movie, .. } => movie.clone(),
             VideoSource::NetStream { movie, .. } => movie.clone(),
         }
     }
@@ -1,10 +1,15 @@
 use crate::context::RenderContext;
+use crate::pixel_bender::{run_shader_at, PixelBenderShader, PixelBenderShaderArgument};
 use gc_arena::Collect;
 use ruffle_render::backend::{RenderBackend, ShapeHandle};
-use ruffle_render::bitmap::{BitmapHandle, BitmapInfo, BitmapSize, BitmapSource};
+use ruffle_render::bitmap::{
+    Bitmap, BitmapFormat, BitmapHandle, BitmapInfo, BitmapSize, BitmapSource,
+};
 use ruffle_render::commands::CommandHandler;
+use ruffle_render::matrix::Matrix;
 use ruffle_render::shape_utils::{DistilledShape, DrawCommand, DrawPath, FillRule};
 use std::cell::{Cell, RefCell};
+use std::rc::Rc;
 use swf::{FillStyle, LineStyle, Point, Rectangle, Twips};
 
 #[derive(Clone, Debug, Collect)]
@@ -15,7 +20,7 @@ pub struct Drawing {
     edge_bounds: Rectangle<Twips>,
     dirty: Cell<bool>,
     paths: Vec<DrawingPath>,
-    bitmaps: Vec<BitmapInfo>,
+    bitmaps: Vec<DrawingBitmap>,
     current_fill: Option<DrawingFill>,
     current_line: Option<DrawingLine>,
     pending_lines: Vec<DrawingLine>,
@@ -98,6 +103,16 @@ impl Drawing {
 
                     this.set_fill_style(None);
                 }
+                DrawPath::Triangles {
+                    style,
+                    vertices,
+                    indices,
+                    uvs,
+                } => {
+                    this.set_fill_style(Some(style.clone()));
+                    this.draw_triangles(vertices, indices, uvs);
+                    this.set_fill_style(None);
+                }
             }
         }
 
@@ -108,10 +123,21 @@ impl Drawing {
         self.winding_rule = rule;
     }
 
+    /// Sets the winding rule of the fill that is currently being drawn, if any.
+    pub fn set_fill_winding_rule(&mut self, rule: FillRule) {
+        if let Some(fill) = &mut self.current_fill {
+            fill.winding_rule = rule;
+            self.dirty.set(true);
+        }
+    }
+
     pub fn set_fill_style(&mut self, style: Option<FillStyle>) {
         self.close_path();
         if let Some(existing) = self.current_fill.take() {
-            self.paths.push(DrawingPath::Fill(existing));
+            // A fill without any edges draws nothing, such as one that was only used for triangles.
+            if !existing.is_empty() {
+                self.paths.push(DrawingPath::Fill(existing));
+            }
         }
         self.paths
             .extend(self.pending_lines.drain(..).map(DrawingPath::Line));
@@ -129,6 +155,7 @@ impl Drawing {
             self.current_fill = Some(DrawingFill {
                 style,
                 commands: vec![DrawCommand::MoveTo(self.cursor)],
+                winding_rule: self.winding_rule,
             });
         }
         self.fill_start = self.cursor;
@@ -146,6 +173,7 @@ impl Drawing {
         self.dirty.set(true);
         self.cursor = Point::ZERO;
         self.fill_start = Point::ZERO;
+        self.winding_rule = FillRule::EvenOdd;
     }
 
     pub fn set_line_style(&mut self, style: Option<LineStyle>) {
@@ -212,74 +240,230 @@ impl Drawing {
         self.dirty.set(true);
     }
 
+    /// Draws a cubic Bezier curve from the current position.
+    ///
+    /// Shapes only support quadratic curves, so the curve is approximated by splitting it into
+    /// pieces that are each close enough to a quadratic curve.
+    pub fn cubic_curve_to(
+        &mut self,
+        control_a: Point<Twips>,
+        control_b: Point<Twips>,
+        anchor: Point<Twips>,
+    ) {
+        let to_f64 = |point: Point<Twips>| (point.x.get() as f64, point.y.get() as f64);
+        let p0 = to_f64(self.cursor);
+        let p1 = to_f64(control_a);
+        let p2 = to_f64(control_b);
+        let p3 = to_f64(anchor);
+
+        // The distance between a cubic curve and its best quadratic approximation is
+        // `sqrt(3) / 36 * |p3 - 3 * p2 + 3 * p1 - p0|`, and it shrinks with the cube of the
+        // number of pieces. Aim for an error of at most a twip.
+        let dx = p3.0 - 3.0 * p2.0 + 3.0 * p1.0 - p0.0;
+        let dy = p3.1 - 3.0 * p2.1 + 3.0 * p1.1 - p0.1;
+        let error = 3.0f64.sqrt() / 36.0 * (dx * dx + dy * dy).sqrt();
+        let pieces = error.cbrt().ceil().clamp(1.0, 64.0) as usize;
+
+        let point_at = |t: f64| {
+            let mt = 1.0 - t;
+            let a = mt * mt * mt;
+            let b = 3.0 * mt * mt * t;
+            let c = 3.0 * mt * t * t;
+            let d = t * t * t;
+            (
+                a * p0.0 + b * p1.0 + c * p2.0 + d * p3.0,
+                a * p0.1 + b * p1.1 + c * p2.1 + d * p3.1,
+            )
+        };
+        let tangent_at = |t: f64| {
+            let mt = 1.0 - t;
+            (
+                3.0 * (mt * mt * (p1.0 - p0.0)
+                    + 2.0 * mt * t * (p2.0 - p1.0)
+                    + t * t * (p3.0 - p2.0)),
+                3.0 * (mt * mt * (p1.1 - p0.1)
+                    + 2.0 * mt * t * (p2.1 - p1.1)
+                    + t * t * (p3.1 - p2.1)),
+            )
+        };
+        let to_twips = |(x, y): (f64, f64)| Point::new(Twips::new(x as i32), Twips::new(y as i32));
+
+        for i in 0..pieces {
+            let t0 = i as f64 / pieces as f64;
+            let t1 = (i + 1) as f64 / pieces as f64;
+            // The control points of the piece between `t0` and `t1` follow the tangents at its ends.
+            let dt = (t1 - t0) / 3.0;
+            let start = point_at(t0);
+            let end = point_at(t1);
+            let start_tangent = tangent_at(t0);
+            let end_tangent = tangent_at(t1);
+            let c1 = (
+                start.0 + start_tangent.0 * dt,
+                start.1 + start_tangent.1 * dt,
+            );
+            let c2 = (end.0 - end_tangent.0 * dt, end.1 - end_tangent.1 * dt);
+            // The midpoint approximation of a cubic curve by a quadratic one.
+            let control = (
+                (3.0 * (c1.0 + c2.0) - start.0 - end.0) / 4.0,
+                (3.0 * (c1.1 + c2.1) - start.1 - end.1) / 4.0,
+            );
+            let end = if i + 1 == pieces {
+                anchor
+            } else {
+                to_twips(end)
+            };
+            self.draw_command(DrawCommand::CurveTo {
+                control: to_twips(control),
+                anchor: end,
+            });
+        }
+    }
+
+    /// Draws a mesh of triangles with the current fill, on top of everything drawn so far.
+    ///
+    /// Each group of three `indices` makes up a triangle. If `uvs` is given and the current fill
+    /// is a bitmap fill, the bitmap is stretched so that each of these normalized coordinates
+    /// lands on the matching vertex. The outline of each triangle is drawn with the current
+    /// line style, and the drawing position is left as it is.
+    pub fn draw_triangles(
+        &mut self,
+        vertices: Vec<Point<Twips>>,
+        indices: Vec<u32>,
+        uvs: Option<Vec<[f32; 2]>>,
+    ) {
+        let fill_style = self.current_fill.as_ref().map(|fill| fill.style.clone());
+        if self
+            .current_fill
+            .as_ref()
+            .map_or(false, |fill| !fill.is_empty())
+        {
+            // Finish what has been drawn with this fill, so that it ends up below the triangles.
+            self.set_fill_style(fill_style.clone());
+        }
+
+        let stroke_width = self
+            .current_line
+            .as_ref()
+            .map_or(Twips::ZERO, |line| line.style.width());
+        let mut outlines = Vec::new();
+        for triangle in indices.chunks_exact(3) {
+            let [Some(&a), Some(&b), Some(&c)] =
+                [0, 1, 2].map(|i| vertices.get(triangle[i] as usize))
+            else {
+                continue;
+            };
+            for point in [a, b, c] {
+                let command = DrawCommand::MoveTo(point);
+                self.shape_bounds = stretch_bounds(&self.shape_bounds, &command, stroke_width);
+                self.edge_bounds = stretch_bounds(&self.edge_bounds, &command, Twips::ZERO);
+            }
+            outlines.extend([
+                DrawCommand::MoveTo(a),
+                DrawCommand::LineTo(b),
+                DrawCommand::LineTo(c),
+                DrawCommand::LineTo(a),
+            ]);
+        }
+
+        if let Some(style) = fill_style {
+            self.paths.push(DrawingPath::Triangles(DrawingTriangles {
+                style,
+                vertices,
+                indices,
+                uvs,
+            }));
+        }
+        if let Some(line) = &self.current_line {
+            if !outlines.is_empty() {
+                self.paths.push(DrawingPath::Line(DrawingLine {
+                    style: line.style.clone(),
+                    commands: outlines,
+                    is_closed: true,
+                }));
+            }
+        }
+        self.dirty.set(true);
+    }
+
     pub fn add_bitmap(&mut self, bitmap: BitmapInfo) -> u16 {
         let id = self.bitmaps.len() as u16;
-        self.bitmaps.push(bitmap);
+        self.bitmaps.push(DrawingBitmap::Bitmap(bitmap));
         id
     }
 
+    /// Starts a fill that runs a Pixel Bender shader, with `matrix` transforming
+    /// the coordinates of the shader.
+    ///
+    /// The shader is run when the drawing is rendered, over the area that the fill covers.
+    pub fn set_shader_fill(
+        &mut self,
+        shader: PixelBenderShader,
+        arguments: Vec<PixelBenderShaderArgument>,
+        matrix: Matrix,
+    ) {
+        let id = self.bitmaps.len() as u16;
+        self.bitmaps.push(DrawingBitmap::Shader(ShaderFill {
+            shader: Rc::new(shader),
+            arguments: Rc::new(arguments),
+            matrix,
+            output: RefCell::new(None),
+        }));
+        self.set_fill_style(Some(FillStyle::Bitmap {
+            id,
+            matrix: ShaderFill::fill_matrix(matrix, (0, 0)).into(),
+            is_smoothed: false,
+            is_repeating: false,
+        }));
+    }
+
     pub fn render(&self, context: &mut RenderContext) {
         if self.dirty.get() {
             self.dirty.set(false);
-            let mut paths = Vec::with_capacity(self.paths.len());
-
-            for path in &self.paths {
-                match path {
-                    DrawingPath::Fill(fill) => {
-                        paths.push(DrawPath::Fill {
-                            style: &fill.style,
-                            commands: fill.commands.to_owned(),
-                            winding_rule: FillRule::EvenOdd,
-                        });
-                    }
-                    DrawingPath::Line(line) => {
-                        paths.push(DrawPath::Stroke {
-                            style: &line.style,
-                            commands: line.commands.to_owned(),
-                            is_closed: line.is_closed,
-                        });
+            self.run_shader_fills(context.renderer);
+            let shader_styles: Vec<_> = self
+                .bitmaps
+                .iter()
+                .enumerate()
+                .filter_map(|(id, bitmap)| match bitmap {
+                    DrawingBitmap::Shader(shader_fill) => {
+                        Some((id as u16, shader_fill.style(id as u16)))
                     }
-                }
-            }
-
-            if let Some(fill) = &self.current_fill {
-                paths.push(DrawPath::Fill {
-                    style: &fill.style,
-                    commands: fill.commands.to_owned(),
-                    winding_rule: FillRule::EvenOdd,
-                })
-            }
-
-            for line in &self.pending_lines {
-                let mut commands = line.commands.to_owned();
-                let is_closed = if self.current_fill.is_some() {
-                    commands.push(DrawCommand::LineTo(self.fill_start));
-                    true
-                } else {
-                    self.cursor == self.fill_start
-                };
-                paths.push(DrawPath::Stroke {
-                    style: &line.style,
-                    commands,
-                    is_closed,
+                    DrawingBitmap::Bitmap(_) => None,
                 })
-            }
-
-            if let Some(line) = &self.current_line {
-                let mut commands = line.commands.to_owned();
-                let is_closed = if self.current_fill.is_some() {
-                    commands.push(DrawCommand::LineTo(self.fill_start));
-                    true
-                } else {
-                    self.cursor == self.fill_start
-                };
-                paths.push(DrawPath::Stroke {
-                    style: &line.style,
-                    commands,
-                    is_closed,
+                .collect();
+            let shader_style = |style: &FillStyle| match style {
+                FillStyle::Bitmap { id, .. } => shader_styles
+                    .iter()
+                    .find_map(|(shader_id, style)| (shader_id == id).then_some(style)),
+                _ => None,
+            };
+            let paths = self
+                .paths()
+                .into_iter()
+                .map(|path| match path {
+                    DrawPath::Fill {
+                        style,
+                        commands,
+                        winding_rule,
+                    } => DrawPath::Fill {
+                        style: shader_style(style).unwrap_or(style),
+                        commands,
+                        winding_rule,
+                    },
+                    DrawPath::Triangles {
+                        style,
+                        vertices,
+                        indices,
+                        uvs,
+                    } => DrawPath::Triangles {
+                        style: shader_style(style).unwrap_or(style),
+                        vertices,
+                        indices,
+                        uvs,
+                    },
+                    path => path,
                 })
-            }
-
+                .collect();
             let shape = DistilledShape {
                 paths,
                 shape_bounds: self.shape_bounds.clone(),
@@ -297,6 +481,78 @@ impl Drawing {
         }
     }
 
+    /// The paths of this drawing, including the ones that are still being drawn.
+    pub fn paths(&self) -> Vec<DrawPath<'_>> {
+        let mut paths = Vec::with_capacity(self.paths.len());
+
+        for path in &self.paths {
+            match path {
+                DrawingPath::Fill(fill) => {
+                    paths.push(DrawPath::Fill {
+                        style: &fill.style,
+                        commands: fill.commands.to_owned(),
+                        winding_rule: fill.winding_rule,
+                    });
+                }
+                DrawingPath::Line(line) => {
+                    paths.push(DrawPath::Stroke {
+                        style: &line.style,
+                        commands: line.commands.to_owned(),
+                        is_closed: line.is_closed,
+                    });
+                }
+                DrawingPath::Triangles(triangles) => {
+                    paths.push(DrawPath::Triangles {
+                        style: &triangles.style,
+                        vertices: triangles.vertices.to_owned(),
+                        indices: triangles.indices.to_owned(),
+                        uvs: triangles.uvs.to_owned(),
+                    });
+                }
+            }
+        }
+
+        if let Some(fill) = &self.current_fill {
+            paths.push(DrawPath::Fill {
+                style: &fill.style,
+                commands: fill.commands.to_owned(),
+                winding_rule: fill.winding_rule,
+            })
+        }
+
+        for line in &self.pending_lines {
+            let mut commands = line.commands.to_owned();
+            let is_closed = if self.current_fill.is_some() {
+                commands.push(DrawCommand::LineTo(self.fill_start));
+                true
+            } else {
+                self.cursor == self.fill_start
+            };
+            paths.push(DrawPath::Stroke {
+                style: &line.style,
+                commands,
+                is_closed,
+            })
+        }
+
+        if let Some(line) = &self.current_line {
+            let mut commands = line.commands.to_owned();
+            let is_closed = if self.current_fill.is_some() {
+                commands.push(DrawCommand::LineTo(self.fill_start));
+                true
+            } else {
+                self.cursor == self.fill_start
+            };
+            paths.push(DrawPath::Stroke {
+                style: &line.style,
+                commands,
+                is_closed,
+            })
+        }
+
+        paths
+    }
+
     pub fn self_bounds(&self) -> &Rectangle<Twips> {
         &self.shape_bounds
     }
@@ -310,7 +566,11 @@ impl Drawing {
         for path in &self.paths {
             match path {
                 DrawingPath::Fill(fill) => {
-                    if shape_utils::draw_command_fill_hit_test(&fill.commands, point) {
+                    if shape_utils::draw_command_fill_hit_test(
+                        &fill.commands,
+                        point,
+                        fill.winding_rule,
+                    ) {
                         return true;
                     }
                 }
@@ -324,12 +584,21 @@ impl Drawing {
                         return true;
                     }
                 }
+                DrawingPath::Triangles(triangles) => {
+                    if shape_utils::triangles_hit_test(
+                        &triangles.vertices,
+                        &triangles.indices,
+                        point,
+                    ) {
+                        return true;
+                    }
+                }
             }
         }
 
         // The pending fill will auto-close.
         if let Some(fill) = &self.current_fill {
-            if shape_utils::draw_command_fill_hit_test(&fill.commands, point) {
+            if shape_utils::draw_command_fill_hit_test(&fill.commands, point, fill.winding_rule) {
                 return true;
             }
         }
@@ -375,6 +644,49 @@ impl Drawing {
         false
     }
 
+    /// Runs the shaders of shader fills over the areas that they cover, unless
+    /// their output already covers that area.
+    fn run_shader_fills(&self, renderer: &mut dyn RenderBackend) {
+        let mut areas = vec![Rectangle::default(); self.bitmaps.len()];
+        let fills = self
+            .paths
+            .iter()
+            .filter_map(|path| match path {
+                DrawingPath::Fill(fill) => Some(fill),
+                _ => None,
+            })
+            .chain(&self.current_fill);
+        for fill in fills {
+            if let FillStyle::Bitmap { id, .. } = fill.style {
+                if let Some(area) = areas.get_mut(id as usize) {
+                    *area = fill.commands.iter().fold(area.clone(), |area, command| {
+                        stretch_bounds(&area, command, Twips::ZERO)
+                    });
+                }
+            }
+        }
+        for path in &self.paths {
+            if let DrawingPath::Triangles(triangles) = path {
+                if let FillStyle::Bitmap { id, .. } = triangles.style {
+                    if let Some(area) = areas.get_mut(id as usize) {
+                        *area = triangles
+                            .vertices
+                            .iter()
+                            .fold(area.clone(), |area, vertex| area.encompass(*vertex));
+                    }
+                }
+            }
+        }
+
+        for (bitmap, area) in self.bitmaps.iter().zip(areas) {
+            if let DrawingBitmap::Shader(shader_fill) = bitmap {
+                if area.is_valid() {
+                    shader_fill.run(area, renderer);
+                }
+            }
+        }
+    }
+
     // Ensures that the path is closed for a pending fill.
     fn close_path(&mut self) {
         if let Some(fill) = &mut self.current_fill {
@@ -391,13 +703,147 @@ impl Drawing {
 
 impl BitmapSource for Drawing {
     fn bitmap_size(&self, id: u16) -> Option<BitmapSize> {
-        self.bitmaps.get(id as usize).map(|bm| BitmapSize {
+        self.bitmaps.get(id as usize)?.info().map(|bm| BitmapSize {
             width: bm.width,
             height: bm.height,
         })
     }
     fn bitmap_handle(&self, id: u16, _backend: &mut dyn RenderBackend) -> Option<BitmapHandle> {
-        self.bitmaps.get(id as usize).map(|bm| bm.handle.clone())
+        self.bitmaps.get(id as usize)?.info().map(|bm| bm.handle)
+    }
+}
+
+#[derive(Debug, Clone)]
+enum DrawingBitmap {
+    Bitmap(BitmapInfo),
+    Shader(ShaderFill),
+}
+
+impl DrawingBitmap {
+    fn info(&self) -> Option<BitmapInfo> {
+        match self {
+            DrawingBitmap::Bitmap(bitmap) => Some(bitmap.clone()),
+            DrawingBitmap::Shader(shader_fill) => shader_fill
+                .output
+                .borrow()
+                .as_ref()
+                .map(|output| output.bitmap.clone()),
+        }
+    }
+}
+
+/// The largest width or height, in pixels of the shader, that a shader fill is run for.
+const MAX_SHADER_FILL_SIZE: u32 = 4096;
+
+/// A fill from `Graphics.beginShaderFill`.
+#[derive(Debug, Clone)]
+struct ShaderFill {
+    shader: Rc<PixelBenderShader>,
+    arguments: Rc<Vec<PixelBenderShaderArgument>>,
+    matrix: Matrix,
+
+    /// The output of the shader for the area that it was last run for.
+    output: RefCell<Option<ShaderFillOutput>>,
+}
+
+#[derive(Debug, Clone)]
+struct ShaderFillOutput {
+    bitmap: BitmapInfo,
+
+    /// The top left pixel of the output, in the coordinates of the shader.
+    origin: (i32, i32),
+}
+
+impl ShaderFill {
+    /// The bitmap fill matrix for an output with its top left pixel at `origin`.
+    fn fill_matrix(matrix: Matrix, origin: (i32, i32)) -> Matrix {
+        let scale = Twips::TWIPS_PER_PIXEL as f32;
+        matrix
+            * Matrix::translate(
+                Twips::from_pixels_i32(origin.0),
+                Twips::from_pixels_i32(origin.1),
+            )
+            * Matrix::scale(scale, scale)
+    }
+
+    /// The bitmap fill that draws the output of the shader, which is the bitmap `id`.
+    fn style(&self, id: u16) -> FillStyle {
+        let origin = self
+            .output
+            .borrow()
+            .as_ref()
+            .map_or((0, 0), |output| output.origin);
+        FillStyle::Bitmap {
+            id,
+            matrix: Self::fill_matrix(self.matrix, origin).into(),
+            is_smoothed: false,
+            is_repeating: false,
+        }
+    }
+
+    /// Runs the shader for the pixels covering `area` of the drawing.
+    fn run(&self, area: Rectangle<Twips>, renderer: &mut dyn RenderBackend) {
+        let Some(inverse) = self.matrix.inverse() else {
+            return;
+        };
+        let area = inverse * area;
+        let x_min = area.x_min.to_pixels().floor() as i32;
+        let y_min = area.y_min.to_pixels().floor() as i32;
+        let width = (area.x_max.to_pixels().ceil() as i32 - x_min)
+            .clamp(1, MAX_SHADER_FILL_SIZE as i32) as u32;
+        let height = (area.y_max.to_pixels().ceil() as i32 - y_min)
+            .clamp(1, MAX_SHADER_FILL_SIZE as i32) as u32;
+
+        if let Some(output) = &*self.output.borrow() {
+            let (x, y) = output.origin;
+            if x <= x_min
+                && y <= y_min
+                && x + output.bitmap.width as i32 >= x_min + width as i32
+                && y + output.bitmap.height as i32 >= y_min + height as i32
+            {
+                return;
+            }
+        }
+
+        let image =
+            match run_shader_at(&self.shader, &self.arguments, (x_min, y_min), width, height) {
+                Ok(image) => image,
+                Err(e) => {
+                    tracing::warn!("Failed to run shader fill: {}", e);
+                    return;
+                }
+            };
+        let channels = image.channels as usize;
+        let to_u8 = |component: f32| (component.clamp(0.0, 1.0) * 255.0).round() as u8;
+        let mut data = Vec::with_capacity(width as usize * height as usize * 4);
+        for pixel in image.data.chunks_exact(channels) {
+            // Missing color components are zero, and a missing alpha channel is opaque.
+            let mut color = [0.0, 0.0, 0.0, 1.0];
+            for (component, value) in color.iter_mut().zip(pixel) {
+                *component = *value;
+            }
+            let alpha = color[3].clamp(0.0, 1.0);
+            data.extend([
+                to_u8(color[0] * alpha),
+                to_u8(color[1] * alpha),
+                to_u8(color[2] * alpha),
+                to_u8(alpha),
+            ]);
+        }
+
+        match renderer.register_bitmap(Bitmap::new(width, height, BitmapFormat::Rgba, data)) {
+            Ok(handle) => {
+                self.output.replace(Some(ShaderFillOutput {
+                    bitmap: BitmapInfo {
+                        handle,
+                        width: width as u16,
+                        height: height as u16,
+                    },
+                    origin: (x_min, y_min),
+                }));
+            }
+            Err(e) => tracing::warn!("Failed to register shader fill bitmap: {:?}", e),
+        }
     }
 }
 
@@ -405,6 +851,16 @@ impl BitmapSource for Drawing {
 struct DrawingFill {
     style: FillStyle,
     commands: Vec<DrawCommand>,
+    winding_rule: FillRule,
+}
+
+impl DrawingFill {
+    /// Whether this fill doesn't have any edges yet.
+    fn is_empty(&self) -> bool {
+        self.commands
+            .iter()
+            .all(|command| matches!(command, DrawCommand::MoveTo(_)))
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -414,10 +870,19 @@ struct DrawingLine {
     is_closed: bool,
 }
 
+#[derive(Debug, Clone)]
+struct DrawingTriangles {
+    style: FillStyle,
+    vertices: Vec<Point<Twips>>,
+    indices: Vec<u32>,
+    uvs: Option<Vec<[f32; 2]>>,
+}
+
 #[derive(Debug, Clone)]
 enum DrawingPath {
     Fill(DrawingFill),
     Line(DrawingLine),
+    Triangles(DrawingTriangles),
 }
 
 fn stretch_bounds(
@@ -35,6 +35,8 @@ pub enum PlayerEvent {
     TextControl {
         code: TextControlCode,
     },
+    FocusGained,
+    FocusLost,
 }
 
 /// The distance scrolled by the mouse wheel.
@@ -641,3 +643,88 @@ pub fn key_code_to_button_key_code(key_code: KeyCode) -> Option<ButtonKeyCode> {
     };
     Some(out)
 }
+
+#[cfg(feature = "input_format")]
+mod input_format {
+    //! Conversions to and from the events of `ruffle_input_format` files.
+
+    use super::{MouseButton, MouseWheelDelta, TextControlCode};
+    use ruffle_input_format as input;
+
+    impl From<input::MouseButton> for MouseButton {
+        fn from(button: input::MouseButton) -> Self {
+            match button {
+                input::MouseButton::Left => Self::Left,
+                input::MouseButton::Middle => Self::Middle,
+                input::MouseButton::Right => Self::Right,
+            }
+        }
+    }
+
+    impl TryFrom<MouseButton> for input::MouseButton {
+        type Error = ();
+
+        fn try_from(button: MouseButton) -> Result<Self, Self::Error> {
+            match button {
+                MouseButton::Left => Ok(Self::Left),
+                MouseButton::Middle => Ok(Self::Middle),
+                MouseButton::Right => Ok(Self::Right),
+                MouseButton::Unknown => Err(()),
+            }
+        }
+    }
+
+    impl From<input::MouseWheelDelta> for MouseWheelDelta {
+        fn from(delta: input::MouseWheelDelta) -> Self {
+            match delta {
+                input::MouseWheelDelta::Lines(lines) => Self::Lines(lines),
+                input::MouseWheelDelta::Pixels(pixels) => Self::Pixels(pixels),
+            }
+        }
+    }
+
+    impl From<MouseWheelDelta> for input::MouseWheelDelta {
+        fn from(delta: MouseWheelDelta) -> Self {
+            match delta {
+                MouseWheelDelta::Lines(lines) => Self::Lines(lines),
+                MouseWheelDelta::Pixels(pixels) => Self::Pixels(pixels),
+            }
+        }
+    }
+
+    impl From<input::TextControlCode> for TextControlCode {
+        fn from(code: input::TextControlCode) -> Self {
+            match code {
+                input::TextControlCode::MoveLeft => Self::MoveLeft,
+                input::TextControlCode::MoveRight => Self::MoveRight,
+                input::TextControlCode::SelectLeft => Self::SelectLeft,
+                input::TextControlCode::SelectRight => Self::SelectRight,
+                input::TextControlCode::SelectAll => Self::SelectAll,
+                input::TextControlCode::Copy => Self::Copy,
+                input::TextControlCode::Paste => Self::Paste,
+                input::TextControlCode::Cut => Self::Cut,
+                input::TextControlCode::Backspace => Self::Backspace,
+                input::TextControlCode::Enter => Self::Enter,
+                input::TextControlCode::Delete => Self::Delete,
+            }
+        }
+    }
+
+    impl From<TextControlCode> for input::TextControlCode {
+        fn from(code: TextControlCode) -> Self {
+            match code {
+                TextControlCode::MoveLeft => Self::MoveLeft,
+                TextControlCode::MoveRight => Self::MoveRight,
+                TextControlCode::SelectLeft => Self::SelectLeft,
+                TextControlCode::SelectRight => Self::SelectRight,
+                TextControlCode::SelectAll => Self::SelectAll,
+                TextControlCode::Copy => Self::Copy,
+                TextControlCode::Paste => Self::Paste,
+                TextControlCode::Cut => Self::Cut,
+                TextControlCode::Backspace => Self::Backspace,
+                TextControlCode::Enter => Self::Enter,
+                TextControlCode::Delete => Self::Delete,
+            }
+        }
+    }
+}
@@ -0,0 +1,364 @@
+//! File dialogs and file transfers for `FileReference` and `FileReferenceList`.
+//!
+//! The dialogs themselves are shown by the `UiBackend`, and uploads and
+//! downloads go through the `NavigatorBackend`. This module delivers the
+//! resulting events to whichever VM the target object belongs to, and builds
+//! the `multipart/form-data` requests used by `FileReference.upload`.
+
+use crate::avm1::{
+    Activation as Avm1Activation, ActivationIdentifier as Avm1ActivationIdentifier,
+    Value as Avm1Value,
+};
+use crate::avm2::{
+    Activation as Avm2Activation, Avm2, Error as Avm2Error, EventObject as Avm2EventObject,
+    Object as Avm2Object,
+};
+use crate::backend::navigator::Request;
+use crate::backend::ui::LoadedFile;
+use crate::context::UpdateContext;
+use crate::string::AvmString;
+use crate::vminterface::AvmObject;
+use indexmap::IndexMap;
+use rand::Rng;
+
+/// The name of the form field holding the file, if the movie doesn't specify one.
+pub const DEFAULT_UPLOAD_FIELD_NAME: &str = "Filedata";
+
+/// An event in the lifetime of a file dialog or file transfer.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FileReferenceEvent {
+    /// The user picked a file (or several) in a file dialog.
+    Select,
+
+    /// The user cancelled a file dialog.
+    Cancel,
+
+    /// A transfer started.
+    Open,
+
+    /// Part of a transfer finished.
+    Progress { loaded: usize, total: usize },
+
+    /// A transfer finished.
+    Complete,
+
+    /// The server replied to an upload.
+    UploadCompleteData(String),
+
+    /// The server responded to a transfer with an HTTP error.
+    HttpError(u16),
+
+    /// A transfer failed, or the file couldn't be read or written.
+    IoError(String),
+}
+
+/// Delivers an event to a `FileReference` or `FileReferenceList`.
+///
+/// AVM1 listeners are sent the corresponding `onSelect`, `onComplete`, etc.
+/// message, while AVM2 objects are dispatched the corresponding event.
+pub fn dispatch_event<'gc>(
+    context: &mut UpdateContext<'_, 'gc>,
+    target: AvmObject<'gc>,
+    event: FileReferenceEvent,
+) {
+    match target {
+        AvmObject::Avm1(object) => {
+            let mut activation = Avm1Activation::from_stub(
+                context.reborrow(),
+                Avm1ActivationIdentifier::root("[FileReference]"),
+            );
+            let (method, mut args) = match event {
+                FileReferenceEvent::Select => ("onSelect", vec![]),
+                FileReferenceEvent::Cancel => ("onCancel", vec![]),
+                FileReferenceEvent::Open => ("onOpen", vec![]),
+                FileReferenceEvent::Progress { loaded, total } => (
+                    "onProgress",
+                    vec![(loaded as f64).into(), (total as f64).into()],
+                ),
+                FileReferenceEvent::Complete => ("onComplete", vec![]),
+                FileReferenceEvent::UploadCompleteData(data) => {
+                    let data = AvmString::new_utf8(activation.context.gc_context, data);
+                    ("onUploadCompleteData", vec![data.into()])
+                }
+                FileReferenceEvent::HttpError(status) => ("onHTTPError", vec![status.into()]),
+                FileReferenceEvent::IoError(_) => ("onIOError", vec![]),
+            };
+            args.insert(0, Avm1Value::Object(object));
+
+            if let Err(e) = crate::avm1::globals::as_broadcaster::broadcast_internal(
+                &mut activation,
+                object,
+                &args,
+                method.into(),
+            ) {
+                tracing::error!("Error running FileReference.{}: {:?}", method, e);
+            }
+        }
+        AvmObject::Avm2(object) => {
+            let mut activation = Avm2Activation::from_nothing(context.reborrow());
+            let result: Result<Vec<Avm2Object<'gc>>, Avm2Error<'gc>> = (|| match event {
+                FileReferenceEvent::Select => Ok(vec![Avm2EventObject::bare_default_event(
+                    &mut activation.context,
+                    "select",
+                )]),
+                FileReferenceEvent::Cancel => Ok(vec![Avm2EventObject::bare_default_event(
+                    &mut activation.context,
+                    "cancel",
+                )]),
+                FileReferenceEvent::Open => Ok(vec![Avm2EventObject::bare_default_event(
+                    &mut activation.context,
+                    "open",
+                )]),
+                FileReferenceEvent::Progress { loaded, total } => {
+                    let event = activation.avm2().classes().progressevent.construct(
+                        &mut activation,
+                        &[
+                            "progress".into(),
+                            false.into(),
+                            false.into(),
+                            loaded.into(),
+                            total.into(),
+                        ],
+                    )?;
+                    Ok(vec![event])
+                }
+                FileReferenceEvent::Complete => Ok(vec![Avm2EventObject::bare_default_event(
+                    &mut activation.context,
+                    "complete",
+                )]),
+                FileReferenceEvent::UploadCompleteData(data) => {
+                    let data = AvmString::new_utf8(activation.context.gc_context, data);
+                    let event = activation.avm2().classes().dataevent.construct(
+                        &mut activation,
+                        &[
+                            "uploadCompleteData".into(),
+                            false.into(),
+                            false.into(),
+                            data.into(),
+                        ],
+                    )?;
+                    Ok(vec![event])
+                }
+                FileReferenceEvent::HttpError(status) => {
+                    // AVM2 reports the status separately, followed by a generic I/O error.
+                    let status_event = activation.avm2().classes().httpstatusevent.construct(
+                        &mut activation,
+                        &[
+                            "httpStatus".into(),
+                            false.into(),
+                            false.into(),
+                            status.into(),
+                        ],
+                    )?;
+                    let error_event = activation.avm2().classes().ioerrorevent.construct(
+                        &mut activation,
+                        &[
+                            "ioError".into(),
+                            false.into(),
+                            false.into(),
+                            "Error #2038: File I/O Error.".into(),
+                            2038.into(),
+                        ],
+                    )?;
+                    Ok(vec![status_event, error_event])
+                }
+                FileReferenceEvent::IoError(message) => {
+                    let message = AvmString::new_utf8(
+                        activation.context.gc_context,
+                        format!("Error #2038: File I/O Error. {message}"),
+                    );
+                    let event = activation.avm2().classes().ioerrorevent.construct(
+                        &mut activation,
+                        &[
+                            "ioError".into(),
+                            false.into(),
+                            false.into(),
+                            message.into(),
+                            2038.into(),
+                        ],
+                    )?;
+                    Ok(vec![event])
+                }
+            })();
+
+            match result {
+                Ok(events) => {
+                    for event in events {
+                        Avm2::dispatch_event(&mut activation.context, event, object);
+                    }
+                }
+                Err(e) => tracing::error!("Error creating FileReference event: {:?}", e),
+            }
+        }
+    }
+}
+
+/// Stores the files picked in a file-open dialog on the object that
+/// opened it, and notifies it of the selection.
+///
+/// If `multiple` is set, the target is a `FileReferenceList`, which is
+/// given a new `FileReference` for each file.
+pub fn select_files<'gc>(
+    context: &mut UpdateContext<'_, 'gc>,
+    target: AvmObject<'gc>,
+    files: Vec<LoadedFile>,
+    multiple: bool,
+) {
+    match target {
+        AvmObject::Avm1(object) => {
+            if let Some(file) = files.into_iter().next() {
+                crate::avm1::globals::file_reference::set_file(context.gc_context, object, file);
+            }
+        }
+        AvmObject::Avm2(object) => {
+            let mut activation = Avm2Activation::from_nothing(context.reborrow());
+            let result = if multiple {
+                crate::avm2::globals::flash::net::file_reference_list::set_file_list(
+                    &mut activation,
+                    object,
+                    files,
+                )
+            } else if let Some(file) = files.into_iter().next() {
+                crate::avm2::globals::flash::net::file_reference::set_file(
+                    &mut activation,
+                    object,
+                    file,
+                )
+            } else {
+                Ok(())
+            };
+
+            if let Err(e) = result {
+                tracing::error!("Error storing selected files: {:?}", e);
+            }
+        }
+    }
+
+    dispatch_event(context, target, FileReferenceEvent::Select);
+}
+
+/// Makes the contents of the file selected by an AVM2 `FileReference`
+/// available through its `data` property, as done by `FileReference.load`.
+pub fn finish_load<'gc>(context: &mut UpdateContext<'_, 'gc>, target: AvmObject<'gc>) {
+    let AvmObject::Avm2(object) = target else {
+        return;
+    };
+
+    let mut activation = Avm2Activation::from_nothing(context.reborrow());
+    match crate::avm2::globals::flash::net::file_reference::load_data(&mut activation, object) {
+        Ok(size) => {
+            dispatch_event(&mut activation.context, target, FileReferenceEvent::Open);
+            dispatch_event(
+                &mut activation.context,
+                target,
+                FileReferenceEvent::Progress {
+                    loaded: size,
+                    total: size,
+                },
+            );
+            dispatch_event(
+                &mut activation.context,
+                target,
+                FileReferenceEvent::Complete,
+            );
+        }
+        Err(e) => tracing::error!("Error loading FileReference data: {:?}", e),
+    }
+}
+
+/// Builds the request for `FileReference.upload`.
+///
+/// The file is sent as a `multipart/form-data` POST, in the same format as
+/// Flash Player: the `Filename` field, followed by any URL variables of the
+/// original request, the file itself, and finally an `Upload` field.
+pub fn upload_request(
+    request: Request,
+    field_name: &str,
+    file_name: &str,
+    contents: &[u8],
+    rng: &mut impl Rng,
+) -> Request {
+    let variables: Vec<(String, String)> = match request.body() {
+        Some((body, _)) => url::form_urlencoded::parse(body).into_owned().collect(),
+        None => vec![],
+    };
+
+    let boundary: String = std::iter::repeat_with(|| rng.sample(rand::distributions::Alphanumeric))
+        .map(char::from)
+        .take(30)
+        .collect();
+    let boundary = format!("----------{boundary}");
+    let body = encode_multipart(&boundary, &variables, field_name, file_name, contents);
+
+    let headers: IndexMap<String, String> = request.headers().clone();
+    let mut upload = Request::post(
+        request.url().to_string(),
+        Some((body, format!("multipart/form-data; boundary={boundary}"))),
+    );
+    upload.set_headers(headers);
+    upload
+}
+
+/// Encodes a file and its accompanying form fields as `multipart/form-data`.
+fn encode_multipart(
+    boundary: &str,
+    variables: &[(String, String)],
+    field_name: &str,
+    file_name: &str,
+    contents: &[u8],
+) -> Vec<u8> {
+    fn write_field(body: &mut Vec<u8>, boundary: &str, name: &str, value: &str) {
+        body.extend_from_slice(
+            format!(
+                "--{boundary}\r\nContent-Disposition: form-data; name=\"{name}\"\r\n\r\n{value}\r\n"
+            )
+            .as_bytes(),
+        );
+    }
+
+    let mut body = Vec::with_capacity(contents.len() + 512);
+    write_field(&mut body, boundary, "Filename", file_name);
+    for (name, value) in variables {
+        write_field(&mut body, boundary, name, value);
+    }
+
+    body.extend_from_slice(
+        format!(
+            "--{boundary}\r\nContent-Disposition: form-data; name=\"{field_name}\"; filename=\"{file_name}\"\r\nContent-Type: application/octet-stream\r\n\r\n"
+        )
+        .as_bytes(),
+    );
+    body.extend_from_slice(contents);
+    body.extend_from_slice(b"\r\n");
+
+    write_field(&mut body, boundary, "Upload", "Submit Query");
+    body.extend_from_slice(format!("--{boundary}--\r\n").as_bytes());
+    body
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::file_reference::encode_multipart;
+
+    #[test]
+    fn test_encode_multipart() {
+        let body = encode_multipart(
+            "BOUNDARY",
+            &[("level".to_string(), "1".to_string())],
+            "Filedata",
+            "save.dat",
+            b"data",
+        );
+        let expected = "--BOUNDARY\r\n\
+            Content-Disposition: form-data; name=\"Filename\"\r\n\r\nsave.dat\r\n\
+            --BOUNDARY\r\n\
+            Content-Disposition: form-data; name=\"level\"\r\n\r\n1\r\n\
+            --BOUNDARY\r\n\
+            Content-Disposition: form-data; name=\"Filedata\"; filename=\"save.dat\"\r\n\
+            Content-Type: application/octet-stream\r\n\r\ndata\r\n\
+            --BOUNDARY\r\n\
+            Content-Disposition: form-data; name=\"Upload\"\r\n\r\nSubmit Query\r\n\
+            --BOUNDARY--\r\n";
+        assert_eq!(String::from_utf8(body).unwrap(), expected);
+    }
+}
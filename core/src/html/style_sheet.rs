@@ -0,0 +1,280 @@
+//! CSS style sheets for HTML text
+
+use crate::html::TextFormat;
+use crate::string::{WStr, WString};
+use gc_arena::Collect;
+use std::collections::HashMap;
+
+/// The CSS properties understood by Flash Player, in their camel-cased form.
+///
+/// Any other property is still parsed and stored by `StyleSheet.parseCSS`, but
+/// has no effect on text formatting.
+pub const CSS_PROPERTIES: &[&str] = &[
+    "color",
+    "display",
+    "fontFamily",
+    "fontSize",
+    "fontStyle",
+    "fontWeight",
+    "kerning",
+    "leading",
+    "letterSpacing",
+    "marginLeft",
+    "marginRight",
+    "textAlign",
+    "textDecoration",
+    "textIndent",
+];
+
+/// The declarations of a single CSS rule: camel-cased property names and
+/// their raw values, in declaration order.
+pub type CssDeclarations = Vec<(WString, WString)>;
+
+/// Parse a CSS document into a list of selectors and their declarations.
+///
+/// This only supports the subset of CSS that Flash Player does: a list of
+/// comma-separated selectors followed by a block of `name: value;` pairs.
+/// Comments are ignored, selectors are lowercased, and property names are
+/// converted from `hyphen-case` to `camelCase`.
+///
+/// Returns `None` if the document is malformed (e.g. has unbalanced braces).
+pub fn parse_css(css: &WStr) -> Option<Vec<(WString, CssDeclarations)>> {
+    let css = strip_comments(css);
+    let mut rest: &WStr = &css;
+    let mut rules = Vec::new();
+
+    loop {
+        rest = rest.trim();
+        if rest.is_empty() {
+            break;
+        }
+
+        let open = rest.find(b'{')?;
+        let close = open + rest[open..].find(b'}')?;
+        let selectors = &rest[..open];
+        let body = &rest[open + 1..close];
+        if body.contains(b'{') {
+            return None;
+        }
+
+        let mut declarations = CssDeclarations::new();
+        for declaration in body.split(b';') {
+            let Some((name, value)) = split_declaration(declaration) else {
+                continue;
+            };
+            declarations.push((to_camel_case(name), value.into()));
+        }
+
+        for selector in selectors.split(b',') {
+            let selector = selector.trim();
+            if selector.is_empty() {
+                return None;
+            }
+            rules.push((selector.to_ascii_lowercase(), declarations.clone()));
+        }
+
+        rest = &rest[close + 1..];
+    }
+
+    Some(rules)
+}
+
+fn strip_comments(css: &WStr) -> WString {
+    let mut out = WString::new();
+    let mut rest = css;
+    while let Some(start) = rest.find(WStr::from_units(b"/*")) {
+        out.push_str(&rest[..start]);
+        rest = &rest[start + 2..];
+        match rest.find(WStr::from_units(b"*/")) {
+            Some(end) => rest = &rest[end + 2..],
+            None => return out,
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+fn split_declaration(declaration: &WStr) -> Option<(&WStr, &WStr)> {
+    let colon = declaration.find(b':')?;
+    let name = declaration[..colon].trim();
+    let value = declaration[colon + 1..].trim();
+    if name.is_empty() {
+        return None;
+    }
+    Some((name, value))
+}
+
+/// Convert a CSS property name such as `font-family` into the `fontFamily`
+/// form used by `StyleSheet` objects.
+fn to_camel_case(name: &WStr) -> WString {
+    let mut out = WString::with_capacity(name.len(), name.is_wide());
+    let mut uppercase_next = false;
+    for c in name.iter() {
+        if c == u16::from(b'-') {
+            uppercase_next = true;
+        } else if uppercase_next {
+            uppercase_next = false;
+            out.push(char::from_u32(c.into()).map_or(c, |c| c.to_ascii_uppercase() as u16));
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+fn parse_css_number(value: &WStr) -> Option<f64> {
+    let value = value.trim();
+    let end = value
+        .iter()
+        .position(|c| !matches!(u8::try_from(c), Ok(b'0'..=b'9' | b'.' | b'-' | b'+')))
+        .unwrap_or(value.len());
+    value[..end].parse().ok()
+}
+
+fn parse_css_color(value: &WStr) -> Option<swf::Color> {
+    let hex = value.trim().strip_prefix(b'#')?;
+    if hex.len() != 6 {
+        return None;
+    }
+    let rgb = u32::from_str_radix(&hex.to_utf8_lossy(), 16).ok()?;
+    Some(swf::Color::from_rgb(rgb, 0))
+}
+
+fn parse_css_font_family(value: &WStr) -> WString {
+    let family = value.split(b',').next().unwrap_or_default().trim();
+    let family = family.trim_matches(&b"\"'"[..]);
+    if family.eq_ignore_case(WStr::from_units(b"sans-serif")) {
+        WStr::from_units(b"_sans").into()
+    } else if family.eq_ignore_case(WStr::from_units(b"serif")) {
+        WStr::from_units(b"_serif").into()
+    } else if family.eq_ignore_case(WStr::from_units(b"mono")) {
+        WStr::from_units(b"_typewriter").into()
+    } else {
+        family.into()
+    }
+}
+
+impl TextFormat {
+    /// Construct a `TextFormat` from a set of camel-cased CSS properties, as
+    /// done by `StyleSheet.transform`.
+    ///
+    /// Unknown properties and unparseable values are ignored.
+    pub fn from_css_properties<'a>(
+        properties: impl IntoIterator<Item = (&'a WStr, &'a WStr)>,
+    ) -> Self {
+        let mut tf = TextFormat::default();
+        for (name, value) in properties {
+            match &name.to_utf8_lossy()[..] {
+                "color" => {
+                    if let Some(color) = parse_css_color(value) {
+                        tf.color = Some(color);
+                    }
+                }
+                "fontFamily" => tf.font = Some(parse_css_font_family(value)),
+                "fontSize" => {
+                    if let Some(size) = parse_css_number(value) {
+                        tf.size = Some(size);
+                    }
+                }
+                "fontStyle" => {
+                    if value == WStr::from_units(b"italic") {
+                        tf.italic = Some(true);
+                    } else if value == WStr::from_units(b"normal") {
+                        tf.italic = Some(false);
+                    }
+                }
+                "fontWeight" => {
+                    if value == WStr::from_units(b"bold") {
+                        tf.bold = Some(true);
+                    } else if value == WStr::from_units(b"normal") {
+                        tf.bold = Some(false);
+                    }
+                }
+                "kerning" => {
+                    if value == WStr::from_units(b"true") {
+                        tf.kerning = Some(true);
+                    } else if value == WStr::from_units(b"false") {
+                        tf.kerning = Some(false);
+                    }
+                }
+                "leading" => tf.leading = parse_css_number(value).or(tf.leading),
+                "letterSpacing" => {
+                    tf.letter_spacing = parse_css_number(value).or(tf.letter_spacing)
+                }
+                "marginLeft" => tf.left_margin = parse_css_number(value).or(tf.left_margin),
+                "marginRight" => tf.right_margin = parse_css_number(value).or(tf.right_margin),
+                "textIndent" => tf.indent = parse_css_number(value).or(tf.indent),
+                "textAlign" => {
+                    if value == WStr::from_units(b"left") {
+                        tf.align = Some(swf::TextAlign::Left);
+                    } else if value == WStr::from_units(b"center") {
+                        tf.align = Some(swf::TextAlign::Center);
+                    } else if value == WStr::from_units(b"right") {
+                        tf.align = Some(swf::TextAlign::Right);
+                    } else if value == WStr::from_units(b"justify") {
+                        tf.align = Some(swf::TextAlign::Justify);
+                    }
+                }
+                "textDecoration" => {
+                    if value == WStr::from_units(b"underline") {
+                        tf.underline = Some(true);
+                    } else if value == WStr::from_units(b"none") {
+                        tf.underline = Some(false);
+                    }
+                }
+                _ => {}
+            }
+        }
+        tf
+    }
+}
+
+/// A resolved style sheet, as applied to an HTML text field.
+///
+/// Each selector (a tag name such as `p`, a class such as `.heading`, or one
+/// of the `a:link`/`a:hover` pseudo-classes) maps to the `TextFormat` that
+/// `StyleSheet.transform` produces for it.
+#[derive(Clone, Debug, Default, Collect)]
+#[collect(require_static)]
+pub struct StyleSheet {
+    styles: HashMap<WString, TextFormat>,
+}
+
+impl StyleSheet {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Set the format for a selector. Selectors are case-insensitive.
+    pub fn set_style(&mut self, selector: &WStr, format: TextFormat) {
+        self.styles.insert(selector.to_ascii_lowercase(), format);
+    }
+
+    /// Get the format for a selector, if there is one.
+    pub fn style(&self, selector: &WStr) -> Option<&TextFormat> {
+        self.styles.get(&selector.to_ascii_lowercase())
+    }
+
+    /// Get the format applied to an element with the given tag name and
+    /// (optional) class attribute.
+    ///
+    /// Class styles take precedence over tag styles.
+    pub fn element_style(&self, tag: &WStr, class: Option<&WStr>) -> Option<TextFormat> {
+        let tag_style = self.style(tag).cloned();
+        let class_style = class.and_then(|class| {
+            let mut selector = WString::from_unit(b'.'.into());
+            selector.push_str(class);
+            self.style(&selector).cloned()
+        });
+
+        match (tag_style, class_style) {
+            (Some(tag_style), Some(class_style)) => Some(class_style.mix_with(tag_style)),
+            (tag_style, class_style) => class_style.or(tag_style),
+        }
+    }
+
+    /// Whether this style sheet changes the appearance of hovered links.
+    pub fn has_hover_style(&self) -> bool {
+        self.style(WStr::from_units(b"a:hover")).is_some()
+    }
+}
@@ -1,6 +1,7 @@
 //! Tests for HTML module
 
 use crate::html::dimensions::{BoxBounds, Position, Size};
+use crate::html::style_sheet::{parse_css, StyleSheet};
 use crate::html::text_format::{FormatSpans, TextFormat, TextSpan};
 use crate::string::{WStr, WString};
 use swf::{Rectangle, Twips};
@@ -864,3 +865,98 @@ fn formatspans_replace_text_degenerate() {
     assert_eq!((0, 1), fs.get_span_boundaries(0, 5));
     assert_eq!((1, 2), fs.get_span_boundaries(5, 9));
 }
+
+#[test]
+fn parse_css_rules() {
+    let rules = parse_css(WStr::from_units(
+        b"/* headings */ H1, .Title { font-size: 20px; font-weight: bold }\n a:hover{color:#FF0000;}",
+    ))
+    .unwrap();
+
+    let names: Vec<_> = rules.iter().map(|(name, _)| name.to_string()).collect();
+    assert_eq!(names, ["h1", ".title", "a:hover"]);
+
+    let declarations: Vec<_> = rules[0]
+        .1
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect();
+    assert_eq!(
+        declarations,
+        [
+            ("fontSize".to_string(), "20px".to_string()),
+            ("fontWeight".to_string(), "bold".to_string())
+        ]
+    );
+
+    assert!(parse_css(WStr::from_units(b"p { color: #000000;")).is_none());
+    assert!(parse_css(WStr::from_units(b"p color: #000000; }")).is_none());
+}
+
+#[test]
+fn textformat_from_css_properties() {
+    let tf = TextFormat::from_css_properties([
+        (WStr::from_units(b"color"), WStr::from_units(b"#336699")),
+        (
+            WStr::from_units(b"fontFamily"),
+            WStr::from_units(b"'Arial', sans-serif"),
+        ),
+        (WStr::from_units(b"fontSize"), WStr::from_units(b"14px")),
+        (WStr::from_units(b"textAlign"), WStr::from_units(b"center")),
+        (
+            WStr::from_units(b"textDecoration"),
+            WStr::from_units(b"underline"),
+        ),
+        (WStr::from_units(b"display"), WStr::from_units(b"block")),
+    ]);
+
+    assert_eq!(tf.color, Some(swf::Color::from_rgb(0x336699, 0)));
+    assert_eq!(tf.font, Some(WString::from_utf8("Arial")));
+    assert_eq!(tf.size, Some(14.0));
+    assert_eq!(tf.align, Some(swf::TextAlign::Center));
+    assert_eq!(tf.underline, Some(true));
+    assert_eq!(tf.bold, None);
+}
+
+#[test]
+fn formatspans_from_styled_html() {
+    let mut style_sheet = StyleSheet::new();
+    style_sheet.set_style(
+        WStr::from_units(b"p"),
+        TextFormat {
+            size: Some(10.0),
+            ..Default::default()
+        },
+    );
+    style_sheet.set_style(
+        WStr::from_units(b".big"),
+        TextFormat {
+            size: Some(30.0),
+            bold: Some(true),
+            ..Default::default()
+        },
+    );
+    style_sheet.set_style(
+        WStr::from_units(b"a:hover"),
+        TextFormat {
+            underline: Some(true),
+            ..Default::default()
+        },
+    );
+
+    let html = WStr::from_units(b"<p>ab<span class=\"big\">cd</span><a href=\"x\">ef</a></p>");
+    let fs = FormatSpans::from_styled_html(html, TextFormat::default(), &style_sheet, None, true);
+
+    assert_eq!(fs.span(0).unwrap().size, 10.0);
+    assert!(!fs.span(0).unwrap().bold);
+    assert_eq!(fs.span(1).unwrap().size, 30.0);
+    assert!(fs.span(1).unwrap().bold);
+    assert!(!fs.span(2).unwrap().underline);
+
+    let fs =
+        FormatSpans::from_styled_html(html, TextFormat::default(), &style_sheet, Some(5), true);
+
+    assert!(!fs.span(1).unwrap().underline);
+    let (hovered, _) = fs.resolve_position_as_span(5).unwrap();
+    assert!(fs.span(hovered).unwrap().underline);
+}
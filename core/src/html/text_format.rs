@@ -2,6 +2,7 @@
 
 use crate::context::UpdateContext;
 use crate::html::iterators::TextSpanIter;
+use crate::html::StyleSheet;
 use crate::string::{Integer, SwfStrExt as _, Units, WStr, WString};
 use crate::tag_utils::SwfMovie;
 use gc_arena::Collect;
@@ -564,11 +565,42 @@ impl FormatSpans {
 
     /// Lower an HTML tree into text-span representation.
     ///
-    /// This is the "legacy" implementation of this process: it only looks for
-    /// a handful of presentational attributes in the HTML tree to generate
-    /// styling. There's also a `lower_from_css` that respects both
-    /// presentational markup and CSS stylesheets.
+    /// This only looks for a handful of presentational attributes in the HTML
+    /// tree to generate styling. See `from_styled_html` for the variant that
+    /// also respects a CSS style sheet.
     pub fn from_html(html: &WStr, default_format: TextFormat, is_multiline: bool) -> Self {
+        Self::lower_html(html, default_format, None, None, is_multiline)
+    }
+
+    /// Lower an HTML tree into text-span representation, applying the tag,
+    /// class and link styles of a style sheet on top of the presentational
+    /// attributes.
+    ///
+    /// If `hovered_index` falls within a link, that link is additionally
+    /// styled with the style sheet's `a:hover` style.
+    pub fn from_styled_html(
+        html: &WStr,
+        default_format: TextFormat,
+        style_sheet: &StyleSheet,
+        hovered_index: Option<usize>,
+        is_multiline: bool,
+    ) -> Self {
+        Self::lower_html(
+            html,
+            default_format,
+            Some(style_sheet),
+            hovered_index,
+            is_multiline,
+        )
+    }
+
+    fn lower_html(
+        html: &WStr,
+        default_format: TextFormat,
+        style_sheet: Option<&StyleSheet>,
+        hovered_index: Option<usize>,
+        is_multiline: bool,
+    ) -> Self {
         let mut format_stack = vec![default_format.clone()];
         let mut text = WString::new();
         let mut spans: Vec<TextSpan> = Vec::new();
@@ -603,6 +635,11 @@ impl FormatSpans {
         let mut opened_buffer: Vec<u8> = Vec::new();
         let mut opened_starts = Vec::new();
 
+        // Text positions of the currently open links, and the extent of the
+        // link under the mouse cursor (if any).
+        let mut link_starts = Vec::new();
+        let mut hovered_link = None;
+
         let mut reader = Reader::from_reader(&raw_bytes[..]);
         reader.expand_empty_elements(true);
         reader.check_end_names(false);
@@ -630,7 +667,20 @@ impl FormatSpans {
                         })
                     };
                     let mut format = format_stack.last().unwrap().clone();
-                    match &e.name().into_inner().to_ascii_lowercase()[..] {
+                    let tag = e.name().into_inner().to_ascii_lowercase();
+                    if let Some(style_sheet) = style_sheet {
+                        let class = attribute(b"class");
+                        let tag_name = WStr::from_units(&tag[..]);
+                        if let Some(style) = style_sheet.element_style(tag_name, class.as_deref()) {
+                            format = style.mix_with(format);
+                        }
+                        if tag == b"a" {
+                            if let Some(style) = style_sheet.style(WStr::from_units(b"a:link")) {
+                                format = style.clone().mix_with(format);
+                            }
+                        }
+                    }
+                    match &tag[..] {
                         b"br" => {
                             if is_multiline {
                                 text.push_byte(b'\n');
@@ -665,6 +715,8 @@ impl FormatSpans {
                             }
                         }
                         b"a" => {
+                            link_starts.push(text.len());
+
                             if let Some(href) = attribute(b"href") {
                                 format.url = Some(href);
                             }
@@ -787,6 +839,14 @@ impl FormatSpans {
                             // Skip pop from `format_stack`.
                             continue;
                         }
+                        b"a" => {
+                            if let (Some(start), Some(hovered)) = (link_starts.pop(), hovered_index)
+                            {
+                                if (start..text.len()).contains(&hovered) {
+                                    hovered_link = Some((start, text.len()));
+                                }
+                            }
+                        }
                         b"p" | b"li" if is_multiline => {
                             text.push_byte(b'\n');
                             if let Some(span) = spans.last_mut() {
@@ -806,12 +866,20 @@ impl FormatSpans {
             }
         }
 
-        Self {
+        let mut format_spans = Self {
             text,
             displayed_text: WString::new(),
             spans,
             default_format,
+        };
+
+        if let (Some(style_sheet), Some((start, end))) = (style_sheet, hovered_link) {
+            if let Some(style) = style_sheet.style(WStr::from_units(b"a:hover")) {
+                format_spans.set_text_format(start, end, style);
+            }
         }
+
+        format_spans
     }
 
     pub fn default_format(&self) -> &TextFormat {
@@ -16,9 +16,11 @@ extern crate num_derive;
 
 #[macro_use]
 mod avm1;
+mod amf;
 mod avm2;
 mod binary_data;
 pub mod bitmap;
+pub mod buffer;
 mod character;
 pub mod context;
 pub mod context_menu;
@@ -26,6 +28,7 @@ mod drawing;
 mod ecma_conversions;
 pub(crate) mod either;
 pub mod events;
+mod file_reference;
 pub mod focus_tracker;
 mod font;
 mod frame_lifecycle;
@@ -33,10 +36,13 @@ mod html;
 mod library;
 pub mod limits;
 pub mod loader;
+mod local_connection;
 mod locale;
 mod pixel_bender;
 mod player;
 mod prelude;
+mod remoting;
+pub mod socket;
 mod streams;
 pub mod string;
 pub mod tag_utils;
@@ -50,6 +56,7 @@ pub mod compatibility_rules;
 pub mod config;
 #[cfg(feature = "egui")]
 pub mod debug_ui;
+pub mod debugger;
 pub mod external;
 pub mod i18n;
 pub mod stub;
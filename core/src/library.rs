@@ -179,6 +179,11 @@ impl<'gc> MovieLibrary<'gc> {
         self.characters.contains_key(&id)
     }
 
+    /// Returns all characters registered in this library.
+    pub fn characters(&self) -> &HashMap<CharacterId, Character<'gc>> {
+        &self.characters
+    }
+
     pub fn character_by_id(&self, id: CharacterId) -> Option<&Character<'gc>> {
         self.characters.get(&id)
     }
@@ -306,6 +311,17 @@ impl<'gc> MovieLibrary<'gc> {
         }
     }
 
+    /// Returns the handles of all sounds defined in this movie.
+    pub fn sounds(&self) -> impl Iterator<Item = SoundHandle> + '_ {
+        self.characters.values().filter_map(|character| {
+            if let Character::Sound(sound) = character {
+                Some(*sound)
+            } else {
+                None
+            }
+        })
+    }
+
     /// Returns the `Text` with the given character ID.
     /// Returns `None` if the ID does not exist or is not a `Text`.
     pub fn get_text(&self, id: CharacterId) -> Option<Text<'gc>> {
@@ -349,6 +365,11 @@ impl<'gc> MovieLibrary<'gc> {
     pub fn avm2_domain(&self) -> Avm2Domain<'gc> {
         self.avm2_domain.unwrap()
     }
+
+    /// Get the AVM2 domain this movie runs under, if one has been set.
+    pub fn try_avm2_domain(&self) -> Option<Avm2Domain<'gc>> {
+        self.avm2_domain
+    }
 }
 
 pub struct MovieLibrarySource<'a, 'gc> {
@@ -418,6 +439,14 @@ impl<'gc> Library<'gc> {
         self.movie_libraries.get(&movie)
     }
 
+    /// Returns all movies that currently have a library.
+    pub fn known_movies(&self) -> Vec<Arc<SwfMovie>> {
+        self.movie_libraries
+            .iter()
+            .map(|(movie, _)| movie)
+            .collect()
+    }
+
     pub fn library_for_movie_mut(&mut self, movie: Arc<SwfMovie>) -> &mut MovieLibrary<'gc> {
         self.movie_libraries
             .entry(movie)
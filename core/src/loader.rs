@@ -13,6 +13,7 @@ use crate::avm2::{
     Value as Avm2Value,
 };
 use crate::backend::navigator::{OwnedFuture, Request};
+use crate::backend::ui::{FileDialogFuture, LoadedFile, SaveFile};
 use crate::bitmap::bitmap_data::Color;
 use crate::bitmap::bitmap_data::{BitmapData, BitmapDataWrapper};
 use crate::context::{ActionQueue, ActionType, UpdateContext};
@@ -20,13 +21,14 @@ use crate::display_object::{
     DisplayObject, TDisplayObject, TDisplayObjectContainer, TInteractiveObject,
 };
 use crate::events::ClipEvent;
+use crate::file_reference::{self, FileReferenceEvent};
 use crate::frame_lifecycle::catchup_display_object_to_frame;
 use crate::limits::ExecutionLimit;
 use crate::player::Player;
 use crate::streams::NetStream;
 use crate::string::AvmString;
 use crate::tag_utils::SwfMovie;
-use crate::vminterface::Instantiator;
+use crate::vminterface::{AvmObject, Instantiator};
 use encoding_rs::UTF_8;
 use gc_arena::{Collect, CollectionContext, GcCell};
 use generational_arena::{Arena, Index};
@@ -153,6 +155,12 @@ pub enum Error {
     #[error("Non-NetStream loader spawned as NetStream loader")]
     NotNetStreamLoader,
 
+    #[error("Non-NetConnection loader spawned as NetConnection loader")]
+    NotNetConnectionLoader,
+
+    #[error("Non-FileReference loader spawned as FileReference loader")]
+    NotFileReferenceLoader,
+
     #[error("HTTP Status is not OK: {0} redirected: {1}")]
     HttpNotOk(String, u16, bool),
 
@@ -220,7 +228,9 @@ impl<'gc> LoadManager<'gc> {
             | Loader::LoadURLLoader { self_handle, .. }
             | Loader::SoundAvm1 { self_handle, .. }
             | Loader::SoundAvm2 { self_handle, .. }
-            | Loader::NetStream { self_handle, .. } => *self_handle = Some(handle),
+            | Loader::NetStream { self_handle, .. }
+            | Loader::NetConnectionCall { self_handle, .. }
+            | Loader::FileReference { self_handle, .. } => *self_handle = Some(handle),
         }
         handle
     }
@@ -230,6 +240,24 @@ impl<'gc> LoadManager<'gc> {
         self.0.get(handle)
     }
 
+    /// Remove a loader that has finished its work.
+    pub fn remove_loader(&mut self, handle: Handle) {
+        self.0.remove(handle);
+    }
+
+    /// Cancel all in-progress movie loads into the given AVM2 `LoaderInfo`.
+    pub fn cancel_avm2_movie_loads(&mut self, target_loader_info: Avm2Object<'gc>) {
+        self.0.retain(|_, loader| {
+            !matches!(
+                loader,
+                Loader::Movie {
+                    vm_data: MovieLoaderVMData::Avm2 { loader_info, .. },
+                    ..
+                } if Avm2Object::ptr_eq(*loader_info, target_loader_info)
+            )
+        });
+    }
+
     /// Retrieve a loader by handle for mutation.
     pub fn get_loader_mut(&mut self, handle: Handle) -> Option<&mut Loader<'gc>> {
         self.0.get_mut(handle)
@@ -424,6 +452,127 @@ impl<'gc> LoadManager<'gc> {
         loader.stream_loader(player, request)
     }
 
+    /// Kick off a Flash Remoting call from a `NetConnection`.
+    ///
+    /// The result of the call will be delivered to `responder`, which is
+    /// addressed by the gateway with `response_uri`.
+    ///
+    /// Returns the loader's async process, which you will need to spawn.
+    pub fn call_net_connection(
+        &mut self,
+        player: Weak<Mutex<Player>>,
+        connection: AvmObject<'gc>,
+        responder: Option<AvmObject<'gc>>,
+        request: Request,
+        response_uri: String,
+    ) -> OwnedFuture<(), Error> {
+        let loader = Loader::NetConnectionCall {
+            self_handle: None,
+            connection,
+            responder,
+        };
+        let handle = self.add_loader(loader);
+        let loader = self.get_loader_mut(handle).unwrap();
+        loader.net_connection_call(player, request, response_uri)
+    }
+
+    /// Wait for the user to pick files in a file-open dialog opened by a
+    /// `FileReference` (or, if `multiple` is set, a `FileReferenceList`).
+    ///
+    /// Returns the loader's async process, which you will need to spawn.
+    pub fn select_file_reference(
+        &mut self,
+        player: Weak<Mutex<Player>>,
+        target_object: AvmObject<'gc>,
+        dialog: FileDialogFuture<Vec<LoadedFile>>,
+        multiple: bool,
+    ) -> OwnedFuture<(), Error> {
+        let loader = Loader::FileReference {
+            self_handle: None,
+            target_object,
+        };
+        let handle = self.add_loader(loader);
+        let loader = self.get_loader_mut(handle).unwrap();
+        loader.file_reference_select(player, dialog, multiple)
+    }
+
+    /// Save data from a `FileReference` to a file picked by the user.
+    ///
+    /// Returns the loader's async process, which you will need to spawn.
+    pub fn save_file_reference(
+        &mut self,
+        player: Weak<Mutex<Player>>,
+        target_object: AvmObject<'gc>,
+        dialog: FileDialogFuture<Box<dyn SaveFile>>,
+        data: Vec<u8>,
+    ) -> OwnedFuture<(), Error> {
+        let loader = Loader::FileReference {
+            self_handle: None,
+            target_object,
+        };
+        let handle = self.add_loader(loader);
+        let loader = self.get_loader_mut(handle).unwrap();
+        loader.file_reference_save(player, dialog, data)
+    }
+
+    /// Download a URL into a file picked by the user, for a `FileReference`.
+    ///
+    /// Returns the loader's async process, which you will need to spawn.
+    pub fn download_file_reference(
+        &mut self,
+        player: Weak<Mutex<Player>>,
+        target_object: AvmObject<'gc>,
+        dialog: FileDialogFuture<Box<dyn SaveFile>>,
+        request: Request,
+    ) -> OwnedFuture<(), Error> {
+        let loader = Loader::FileReference {
+            self_handle: None,
+            target_object,
+        };
+        let handle = self.add_loader(loader);
+        let loader = self.get_loader_mut(handle).unwrap();
+        loader.file_reference_download(player, dialog, request)
+    }
+
+    /// Upload the file selected by a `FileReference`.
+    ///
+    /// `request` should already contain the file, as built by
+    /// `file_reference::upload_request`.
+    ///
+    /// Returns the loader's async process, which you will need to spawn.
+    pub fn upload_file_reference(
+        &mut self,
+        player: Weak<Mutex<Player>>,
+        target_object: AvmObject<'gc>,
+        request: Request,
+    ) -> OwnedFuture<(), Error> {
+        let loader = Loader::FileReference {
+            self_handle: None,
+            target_object,
+        };
+        let handle = self.add_loader(loader);
+        let loader = self.get_loader_mut(handle).unwrap();
+        loader.file_reference_upload(player, request)
+    }
+
+    /// Load the contents of the file selected by a `FileReference` into its
+    /// `data` property.
+    ///
+    /// Returns the loader's async process, which you will need to spawn.
+    pub fn load_file_reference(
+        &mut self,
+        player: Weak<Mutex<Player>>,
+        target_object: AvmObject<'gc>,
+    ) -> OwnedFuture<(), Error> {
+        let loader = Loader::FileReference {
+            self_handle: None,
+            target_object,
+        };
+        let handle = self.add_loader(loader);
+        let loader = self.get_loader_mut(handle).unwrap();
+        loader.file_reference_load(player)
+    }
+
     /// Process tags on all loaders in the Parsing phase.
     ///
     /// Returns true if *all* loaders finished preloading.
@@ -587,6 +736,30 @@ pub enum Loader<'gc> {
         /// The stream to buffer data into.
         target_stream: NetStream<'gc>,
     },
+
+    /// Loader that is waiting for the response to a `NetConnection.call`.
+    NetConnectionCall {
+        /// The handle to refer to this loader instance.
+        #[collect(require_static)]
+        self_handle: Option<Handle>,
+
+        /// The `NetConnection` that made the call.
+        connection: AvmObject<'gc>,
+
+        /// The object to deliver the result of the call to, if any.
+        responder: Option<AvmObject<'gc>>,
+    },
+
+    /// Loader that is waiting for a file dialog or file transfer of a
+    /// `FileReference` or `FileReferenceList`.
+    FileReference {
+        /// The handle to refer to this loader instance.
+        #[collect(require_static)]
+        self_handle: Option<Handle>,
+
+        /// The object that opened the dialog or started the transfer.
+        target_object: AvmObject<'gc>,
+    },
 }
 
 impl<'gc> Loader<'gc> {
@@ -1219,7 +1392,7 @@ impl<'gc> Loader<'gc> {
 
                 let success = data
                     .and_then(|data| {
-                        let handle = uc.audio.register_mp3(&data.body)?;
+                        let handle = uc.audio.register_external_sound(&data.body)?;
                         sound_object.set_sound(uc.gc_context, Some(handle));
                         let duration = uc
                             .audio
@@ -1280,7 +1453,7 @@ impl<'gc> Loader<'gc> {
 
                 match response {
                     Ok(response) => {
-                        let handle = uc.audio.register_mp3(&response.body)?;
+                        let handle = uc.audio.register_external_sound(&response.body)?;
                         if let Err(e) = sound_object
                             .as_sound_object()
                             .expect("Not a sound object")
@@ -1333,10 +1506,10 @@ impl<'gc> Loader<'gc> {
         request: Request,
     ) -> OwnedFuture<(), Error> {
         let handle = match self {
-            Loader::SoundAvm2 { self_handle, .. } => {
+            Loader::NetStream { self_handle, .. } => {
                 self_handle.expect("Loader not self-introduced")
             }
-            _ => return Box::pin(async { Err(Error::NotLoadDataLoader) }),
+            _ => return Box::pin(async { Err(Error::NotNetStreamLoader) }),
         };
 
         let player = player
@@ -1360,10 +1533,327 @@ impl<'gc> Loader<'gc> {
                         stream.load_buffer(uc.gc_context, &mut response.body);
                     }
                     Err(err) => {
-                        stream.report_error(err);
+                        stream.report_error(uc, err);
+                    }
+                }
+
+                Ok(())
+            })
+        })
+    }
+
+    /// Creates a future for a `NetConnection.call` request.
+    fn net_connection_call(
+        &mut self,
+        player: Weak<Mutex<Player>>,
+        request: Request,
+        response_uri: String,
+    ) -> OwnedFuture<(), Error> {
+        let handle = match self {
+            Loader::NetConnectionCall { self_handle, .. } => {
+                self_handle.expect("Loader not self-introduced")
+            }
+            _ => return Box::pin(async { Err(Error::NotNetConnectionLoader) }),
+        };
+
+        let player = player
+            .upgrade()
+            .expect("Could not upgrade weak reference to player");
+
+        Box::pin(async move {
+            let fetch = player.lock().unwrap().navigator().fetch(request);
+            let response = fetch.await;
+
+            player.lock().unwrap().update(|uc| {
+                let loader = uc.load_manager.get_loader(handle);
+                let (connection, responder) = match loader {
+                    Some(&Loader::NetConnectionCall {
+                        connection,
+                        responder,
+                        ..
+                    }) => (connection, responder),
+                    None => return Err(Error::Cancelled),
+                    _ => return Err(Error::NotNetConnectionLoader),
+                };
+                uc.load_manager.remove_loader(handle);
+
+                crate::remoting::handle_response(
+                    uc,
+                    connection,
+                    responder,
+                    &response_uri,
+                    response,
+                );
+
+                Ok(())
+            })
+        })
+    }
+
+    /// Get the handle of a `FileReference` loader.
+    fn file_reference_handle(&self) -> Option<Handle> {
+        match self {
+            Loader::FileReference { self_handle, .. } => {
+                Some(self_handle.expect("Loader not self-introduced"))
+            }
+            _ => None,
+        }
+    }
+
+    /// Get the target of a `FileReference` loader, removing the loader if the
+    /// operation is `finished`.
+    fn file_reference_target(
+        uc: &mut UpdateContext<'_, 'gc>,
+        handle: Handle,
+        finished: bool,
+    ) -> Result<AvmObject<'gc>, Error> {
+        let target = match uc.load_manager.get_loader(handle) {
+            Some(&Loader::FileReference { target_object, .. }) => target_object,
+            None => return Err(Error::Cancelled),
+            _ => return Err(Error::NotFileReferenceLoader),
+        };
+        if finished {
+            uc.load_manager.remove_loader(handle);
+        }
+        Ok(target)
+    }
+
+    /// Creates a future for a file-open dialog.
+    fn file_reference_select(
+        &mut self,
+        player: Weak<Mutex<Player>>,
+        dialog: FileDialogFuture<Vec<LoadedFile>>,
+        multiple: bool,
+    ) -> OwnedFuture<(), Error> {
+        let Some(handle) = self.file_reference_handle() else {
+            return Box::pin(async { Err(Error::NotFileReferenceLoader) });
+        };
+
+        let player = player
+            .upgrade()
+            .expect("Could not upgrade weak reference to player");
+
+        Box::pin(async move {
+            let files = dialog.await;
+
+            player.lock().unwrap().update(|uc| {
+                let target = Loader::file_reference_target(uc, handle, true)?;
+                match files {
+                    Some(files) => file_reference::select_files(uc, target, files, multiple),
+                    None => file_reference::dispatch_event(uc, target, FileReferenceEvent::Cancel),
+                }
+                Ok(())
+            })
+        })
+    }
+
+    /// Creates a future for `FileReference.save`.
+    fn file_reference_save(
+        &mut self,
+        player: Weak<Mutex<Player>>,
+        dialog: FileDialogFuture<Box<dyn SaveFile>>,
+        data: Vec<u8>,
+    ) -> OwnedFuture<(), Error> {
+        let Some(handle) = self.file_reference_handle() else {
+            return Box::pin(async { Err(Error::NotFileReferenceLoader) });
+        };
+
+        let player = player
+            .upgrade()
+            .expect("Could not upgrade weak reference to player");
+
+        Box::pin(async move {
+            let file = dialog.await;
+
+            player.lock().unwrap().update(|uc| {
+                let target = Loader::file_reference_target(uc, handle, true)?;
+                let Some(mut file) = file else {
+                    file_reference::dispatch_event(uc, target, FileReferenceEvent::Cancel);
+                    return Ok(());
+                };
+
+                let result = file.write(&data);
+                let size = data.len();
+                let saved_file = LoadedFile {
+                    name: file.name(),
+                    contents: data,
+                    ..Default::default()
+                };
+                file_reference::select_files(uc, target, vec![saved_file], false);
+                file_reference::dispatch_event(uc, target, FileReferenceEvent::Open);
+                match result {
+                    Ok(()) => {
+                        file_reference::dispatch_event(
+                            uc,
+                            target,
+                            FileReferenceEvent::Progress {
+                                loaded: size,
+                                total: size,
+                            },
+                        );
+                        file_reference::dispatch_event(uc, target, FileReferenceEvent::Complete);
+                    }
+                    Err(e) => {
+                        tracing::warn!("FileReference.save: Couldn't write file: {}", e);
+                        file_reference::dispatch_event(
+                            uc,
+                            target,
+                            FileReferenceEvent::IoError(e.to_string()),
+                        );
                     }
                 }
+                Ok(())
+            })
+        })
+    }
 
+    /// Creates a future for `FileReference.download`.
+    fn file_reference_download(
+        &mut self,
+        player: Weak<Mutex<Player>>,
+        dialog: FileDialogFuture<Box<dyn SaveFile>>,
+        request: Request,
+    ) -> OwnedFuture<(), Error> {
+        let Some(handle) = self.file_reference_handle() else {
+            return Box::pin(async { Err(Error::NotFileReferenceLoader) });
+        };
+
+        let player = player
+            .upgrade()
+            .expect("Could not upgrade weak reference to player");
+
+        Box::pin(async move {
+            let Some(mut file) = dialog.await else {
+                return player.lock().unwrap().update(|uc| {
+                    let target = Loader::file_reference_target(uc, handle, true)?;
+                    file_reference::dispatch_event(uc, target, FileReferenceEvent::Cancel);
+                    Ok(())
+                });
+            };
+
+            let name = file.name();
+            player.lock().unwrap().update(|uc| {
+                let target = Loader::file_reference_target(uc, handle, false)?;
+                let selected_file = LoadedFile {
+                    name,
+                    ..Default::default()
+                };
+                file_reference::select_files(uc, target, vec![selected_file], false);
+                file_reference::dispatch_event(uc, target, FileReferenceEvent::Open);
+                Ok(())
+            })?;
+
+            let fetch = player.lock().unwrap().navigator().fetch(request);
+            let response = fetch.await;
+
+            player.lock().unwrap().update(|uc| {
+                let target = Loader::file_reference_target(uc, handle, true)?;
+                let event = match response {
+                    Ok(response) => {
+                        let size = response.body.len();
+                        match file.write(&response.body) {
+                            Ok(()) => {
+                                file_reference::dispatch_event(
+                                    uc,
+                                    target,
+                                    FileReferenceEvent::Progress {
+                                        loaded: size,
+                                        total: size,
+                                    },
+                                );
+                                FileReferenceEvent::Complete
+                            }
+                            Err(e) => FileReferenceEvent::IoError(e.to_string()),
+                        }
+                    }
+                    Err(Error::HttpNotOk(_, status, _)) => FileReferenceEvent::HttpError(status),
+                    Err(e) => FileReferenceEvent::IoError(e.to_string()),
+                };
+                file_reference::dispatch_event(uc, target, event);
+                Ok(())
+            })
+        })
+    }
+
+    /// Creates a future for `FileReference.upload`.
+    fn file_reference_upload(
+        &mut self,
+        player: Weak<Mutex<Player>>,
+        request: Request,
+    ) -> OwnedFuture<(), Error> {
+        let Some(handle) = self.file_reference_handle() else {
+            return Box::pin(async { Err(Error::NotFileReferenceLoader) });
+        };
+
+        let player = player
+            .upgrade()
+            .expect("Could not upgrade weak reference to player");
+
+        Box::pin(async move {
+            let size = request.body().as_ref().map_or(0, |(body, _)| body.len());
+            player.lock().unwrap().update(|uc| {
+                let target = Loader::file_reference_target(uc, handle, false)?;
+                file_reference::dispatch_event(uc, target, FileReferenceEvent::Open);
+                Ok(())
+            })?;
+
+            let fetch = player.lock().unwrap().navigator().fetch(request);
+            let response = fetch.await;
+
+            player.lock().unwrap().update(|uc| {
+                let target = Loader::file_reference_target(uc, handle, true)?;
+                match response {
+                    Ok(response) => {
+                        file_reference::dispatch_event(
+                            uc,
+                            target,
+                            FileReferenceEvent::Progress {
+                                loaded: size,
+                                total: size,
+                            },
+                        );
+                        file_reference::dispatch_event(uc, target, FileReferenceEvent::Complete);
+                        let data = String::from_utf8_lossy(&response.body).into_owned();
+                        file_reference::dispatch_event(
+                            uc,
+                            target,
+                            FileReferenceEvent::UploadCompleteData(data),
+                        );
+                    }
+                    Err(Error::HttpNotOk(_, status, _)) => {
+                        file_reference::dispatch_event(
+                            uc,
+                            target,
+                            FileReferenceEvent::HttpError(status),
+                        );
+                    }
+                    Err(e) => {
+                        file_reference::dispatch_event(
+                            uc,
+                            target,
+                            FileReferenceEvent::IoError(e.to_string()),
+                        );
+                    }
+                }
+                Ok(())
+            })
+        })
+    }
+
+    /// Creates a future for `FileReference.load`.
+    fn file_reference_load(&mut self, player: Weak<Mutex<Player>>) -> OwnedFuture<(), Error> {
+        let Some(handle) = self.file_reference_handle() else {
+            return Box::pin(async { Err(Error::NotFileReferenceLoader) });
+        };
+
+        let player = player
+            .upgrade()
+            .expect("Could not upgrade weak reference to player");
+
+        Box::pin(async move {
+            player.lock().unwrap().update(|uc| {
+                let target = Loader::file_reference_target(uc, handle, true)?;
+                file_reference::finish_load(uc, target);
                 Ok(())
             })
         })
@@ -1401,19 +1891,20 @@ impl<'gc> Loader<'gc> {
             }
             MovieLoaderVMData::Avm2 { loader_info, .. } => {
                 let mut activation = Avm2Activation::from_nothing(uc.reborrow());
+                let loader_info_object = loader_info.as_loader_info_object().unwrap();
+
+                // A previous load into this `Loader` may have been unloaded.
+                loader_info_object.reset_load_events(activation.context.gc_context);
 
                 // Update the LoadersTream - we still have a fake SwfMovie, but we now have the real target clip.
-                loader_info
-                    .as_loader_info_object()
-                    .unwrap()
-                    .set_loader_stream(
-                        LoaderStream::NotYetLoaded(
-                            Arc::new(SwfMovie::empty(activation.context.swf.version())),
-                            Some(clip),
-                            false,
-                        ),
-                        activation.context.gc_context,
-                    );
+                loader_info_object.set_loader_stream(
+                    LoaderStream::NotYetLoaded(
+                        Arc::new(SwfMovie::empty(activation.context.swf.version())),
+                        Some(clip),
+                        false,
+                    ),
+                    activation.context.gc_context,
+                );
 
                 let open_evt = Avm2EventObject::bare_default_event(&mut activation.context, "open");
                 Avm2::dispatch_event(uc, open_evt, loader_info);
@@ -1817,10 +2308,10 @@ impl<'gc> Loader<'gc> {
             }
         }
 
-        if let Loader::Movie { loader_status, .. } = uc.load_manager.get_loader_mut(handle).unwrap()
-        {
+        // The load may have been cancelled by an event handler.
+        if let Some(Loader::Movie { loader_status, .. }) = uc.load_manager.get_loader_mut(handle) {
             *loader_status = LoaderStatus::Succeeded;
-        };
+        }
 
         Ok(())
     }
@@ -1908,10 +2399,10 @@ impl<'gc> Loader<'gc> {
             }
         }
 
-        if let Loader::Movie { loader_status, .. } = uc.load_manager.get_loader_mut(handle).unwrap()
-        {
+        // The load may have been cancelled by an event handler.
+        if let Some(Loader::Movie { loader_status, .. }) = uc.load_manager.get_loader_mut(handle) {
             *loader_status = LoaderStatus::Failed;
-        };
+        }
 
         Ok(())
     }
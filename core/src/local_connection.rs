@@ -0,0 +1,277 @@
+//! Messaging between `LocalConnection` objects.
+//!
+//! Any movie in the player, AVM1 or AVM2, may listen on a connection name with
+//! `LocalConnection.connect`, after which any other movie can `send` method
+//! calls to it. The arguments of a call are converted to AMF values when it is
+//! sent, and back to values of the receiving VM when it is delivered on the
+//! next frame.
+
+use crate::avm1::{
+    Activation as Avm1Activation, ActivationIdentifier as Avm1ActivationIdentifier,
+    ExecutionReason as Avm1ExecutionReason, ScriptObject as Avm1ScriptObject,
+    TObject as Avm1TObject, Value as Avm1Value,
+};
+use crate::avm2::{
+    Activation as Avm2Activation, Avm2, Error as Avm2Error, TObject as Avm2TObject,
+    Value as Avm2Value,
+};
+use crate::context::UpdateContext;
+use crate::string::{AvmString, WStr};
+use crate::vminterface::AvmObject;
+use flash_lso::types::Value as AmfValue;
+use gc_arena::Collect;
+
+/// A `LocalConnection` object that is listening on a connection name.
+#[derive(Collect)]
+#[collect(no_drop)]
+struct Listener<'gc> {
+    /// The full, lowercase connection name.
+    #[collect(require_static)]
+    name: String,
+
+    object: AvmObject<'gc>,
+}
+
+/// A method call that is waiting to be delivered.
+#[derive(Collect)]
+#[collect(no_drop)]
+struct Message<'gc> {
+    /// The `LocalConnection` that sent this message, which is notified of
+    /// whether it could be delivered.
+    sender: AvmObject<'gc>,
+
+    /// The full, lowercase connection name.
+    #[collect(require_static)]
+    connection_name: String,
+
+    #[collect(require_static)]
+    method_name: String,
+
+    #[collect(require_static)]
+    arguments: Vec<AmfValue>,
+}
+
+/// Routes messages between all `LocalConnection` objects of the player.
+#[derive(Collect)]
+#[collect(no_drop)]
+pub struct LocalConnections<'gc> {
+    listeners: Vec<Listener<'gc>>,
+
+    messages: Vec<Message<'gc>>,
+}
+
+impl<'gc> LocalConnections<'gc> {
+    pub fn empty() -> Self {
+        Self {
+            listeners: vec![],
+            messages: vec![],
+        }
+    }
+
+    /// Start listening on a connection name.
+    ///
+    /// Returns `false` if the object is already connected, or if another
+    /// object is already listening on the name.
+    pub fn connect(&mut self, domain: &str, object: AvmObject<'gc>, name: &WStr) -> bool {
+        if self.is_connected(object) {
+            return false;
+        }
+
+        let name = full_name(domain, name);
+        if self.listeners.iter().any(|listener| listener.name == name) {
+            return false;
+        }
+
+        self.listeners.push(Listener { name, object });
+        true
+    }
+
+    /// Stop listening for messages.
+    ///
+    /// Returns `false` if the object wasn't connected.
+    pub fn close(&mut self, object: AvmObject<'gc>) -> bool {
+        let len = self.listeners.len();
+        self.listeners
+            .retain(|listener| !AvmObject::ptr_eq(listener.object, object));
+        self.listeners.len() != len
+    }
+
+    /// Whether the object is listening on a connection name.
+    pub fn is_connected(&self, object: AvmObject<'gc>) -> bool {
+        self.listeners
+            .iter()
+            .any(|listener| AvmObject::ptr_eq(listener.object, object))
+    }
+
+    /// Queue a method call to whatever is listening on the connection name.
+    pub fn send(
+        &mut self,
+        domain: &str,
+        sender: AvmObject<'gc>,
+        connection_name: &WStr,
+        method_name: &WStr,
+        arguments: Vec<AmfValue>,
+    ) {
+        self.messages.push(Message {
+            sender,
+            connection_name: full_name(domain, connection_name),
+            method_name: method_name.to_utf8_lossy().into_owned(),
+            arguments,
+        });
+    }
+
+    /// Deliver all queued messages, and notify their senders.
+    ///
+    /// Messages sent while doing so are delivered on the next call.
+    pub fn update_connections(context: &mut UpdateContext<'_, 'gc>) {
+        let messages = std::mem::take(&mut context.local_connections.messages);
+
+        for message in messages {
+            let receiver = context
+                .local_connections
+                .listeners
+                .iter()
+                .find(|listener| listener.name == message.connection_name)
+                .map(|listener| listener.object);
+
+            if let Some(receiver) = receiver {
+                deliver_message(context, receiver, &message.method_name, &message.arguments);
+                trigger_status_event(context, message.sender, "status");
+            } else {
+                trigger_status_event(context, message.sender, "error");
+            }
+        }
+    }
+}
+
+/// The domain of a movie, which connection names are scoped to.
+///
+/// Returns `None` if the URL of the movie can't be parsed.
+pub fn movie_domain(url: &str) -> Option<String> {
+    let url = url::Url::parse(url).ok()?;
+    if url.scheme() == "file" {
+        Some("localhost".to_string())
+    } else if let Some(domain) = url.domain() {
+        Some(domain.to_string())
+    } else {
+        // no domain?
+        Some("localhost".to_string())
+    }
+}
+
+/// Resolves a connection name as given to `connect` or `send`.
+///
+/// Names are case-insensitive, and prefixed with the domain of the movie
+/// unless they start with an underscore or name a domain themselves.
+fn full_name(domain: &str, name: &WStr) -> String {
+    let name = name.to_utf8_lossy().to_lowercase();
+    if name.starts_with('_') || name.contains(':') {
+        name
+    } else {
+        format!("{}:{}", domain.to_lowercase(), name)
+    }
+}
+
+fn deliver_message<'gc>(
+    context: &mut UpdateContext<'_, 'gc>,
+    receiver: AvmObject<'gc>,
+    method_name: &str,
+    arguments: &[AmfValue],
+) {
+    match receiver {
+        AvmObject::Avm1(object) => {
+            let mut activation = Avm1Activation::from_stub(
+                context.reborrow(),
+                Avm1ActivationIdentifier::root("[LocalConnection]"),
+            );
+            let arguments: Vec<_> = arguments
+                .iter()
+                .map(|value| {
+                    crate::avm1::globals::shared_object::deserialize_value(&mut activation, value)
+                })
+                .collect();
+            let method = AvmString::new_utf8(activation.context.gc_context, method_name);
+            if let Err(e) = object.call_method(
+                method,
+                &arguments,
+                &mut activation,
+                Avm1ExecutionReason::Special,
+            ) {
+                tracing::error!("Error running LocalConnection method {}: {:?}", method, e);
+            }
+        }
+        AvmObject::Avm2(object) => {
+            let mut activation = Avm2Activation::from_nothing(context.reborrow());
+            let result: Result<(), Avm2Error<'gc>> = (|| {
+                // Calls are made on the `client` of the connection.
+                let client = object
+                    .get_public_property("client", &mut activation)?
+                    .coerce_to_object(&mut activation)?;
+                let arguments = arguments
+                    .iter()
+                    .map(|value| crate::avm2::amf::deserialize_value(&mut activation, value))
+                    .collect::<Result<Vec<_>, _>>()?;
+                let method = AvmString::new_utf8(activation.context.gc_context, method_name);
+                client.call_public_property(method, &arguments, &mut activation)?;
+                Ok(())
+            })();
+
+            if let Err(e) = result {
+                tracing::error!(
+                    "Error running LocalConnection method {}: {:?}",
+                    method_name,
+                    e
+                );
+            }
+        }
+    }
+}
+
+/// Notify a `LocalConnection` of whether its message could be delivered.
+///
+/// This calls `onStatus` in AVM1 and dispatches a `status` event in AVM2.
+fn trigger_status_event<'gc>(
+    context: &mut UpdateContext<'_, 'gc>,
+    sender: AvmObject<'gc>,
+    level: &'static str,
+) {
+    match sender {
+        AvmObject::Avm1(object) => {
+            let mut activation = Avm1Activation::from_stub(
+                context.reborrow(),
+                Avm1ActivationIdentifier::root("[LocalConnection]"),
+            );
+            let info = Avm1ScriptObject::new(
+                activation.context.gc_context,
+                Some(activation.context.avm1.prototypes().object),
+            );
+            let _ = info.set("level", level.into(), &mut activation);
+            if let Err(e) = object.call_method(
+                "onStatus".into(),
+                &[Avm1Value::Object(info.into())],
+                &mut activation,
+                Avm1ExecutionReason::Special,
+            ) {
+                tracing::error!("Error running LocalConnection.onStatus: {:?}", e);
+            }
+        }
+        AvmObject::Avm2(object) => {
+            let mut activation = Avm2Activation::from_nothing(context.reborrow());
+            let result = activation.avm2().classes().statusevent.construct(
+                &mut activation,
+                &[
+                    "status".into(),
+                    false.into(),
+                    false.into(),
+                    Avm2Value::Null,
+                    level.into(),
+                ],
+            );
+
+            match result {
+                Ok(event) => Avm2::dispatch_event(&mut activation.context, event, object),
+                Err(e) => tracing::error!("Error creating StatusEvent: {:?}", e),
+            }
+        }
+    }
+}
@@ -1,9 +1,13 @@
-//! Pixel bender bytecode parsing code.
+//! Pixel bender bytecode parsing and execution code.
 //! This is heavling based on https://github.com/jamesward/pbjas and https://github.com/HaxeFoundation/format/tree/master/format/pbj
 
 #[cfg(test)]
 mod tests;
 
+mod executor;
+
+pub use executor::{run_shader, run_shader_at, PixelBenderImage, PixelBenderShaderArgument};
+
 use byteorder::{BigEndian, LittleEndian, ReadBytesExt};
 use num_traits::FromPrimitive;
 use std::{
@@ -492,7 +496,7 @@ fn read_value<R: Read>(
 
 fn read_uint24<R: Read>(data: &mut R) -> Result<u32, Box<dyn std::error::Error>> {
     let mut src = data.read_u16::<LittleEndian>()? as u32;
-    src += data.read_u8()? as u32;
+    src |= (data.read_u8()? as u32) << 16;
     Ok(src)
 }
 
@@ -0,0 +1,601 @@
+//! A CPU implementation of Pixel Bender shaders.
+//!
+//! Every pixel of the output runs all operations of the shader, starting from
+//! the same registers holding the values of the parameters. Registers have four
+//! components each, and come in two banks: float registers, and int registers
+//! (which also hold the results of comparisons). Most operations overwrite the
+//! destination register with the result of combining it with the source, such
+//! that `Add` means `dst += src`.
+
+use std::error::Error;
+
+use super::{
+    Opcode, Operation, PixelBenderParam, PixelBenderParamQualifier, PixelBenderShader,
+    PixelBenderType, PixelBenderTypeOpcode,
+};
+
+/// Set on a register index to refer to an int register.
+const INT_REGISTER: u16 = 0x8000;
+
+/// An image passed to a shader, or produced by it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PixelBenderImage {
+    pub width: u32,
+    pub height: u32,
+
+    /// The number of components of every pixel.
+    pub channels: u8,
+
+    /// The components of every pixel, row by row.
+    ///
+    /// Colors are not premultiplied, and their components are in the range
+    /// `0.0..=1.0`.
+    pub data: Vec<f32>,
+}
+
+impl PixelBenderImage {
+    /// The components of a pixel, or all zeros outside of the image.
+    fn pixel(&self, x: i64, y: i64) -> [f32; 4] {
+        let mut value = [0.0; 4];
+        if x < 0 || y < 0 || x >= self.width as i64 || y >= self.height as i64 {
+            return value;
+        }
+
+        let channels = self.channels as usize;
+        let start = (y as usize * self.width as usize + x as usize) * channels;
+        for (component, data) in value
+            .iter_mut()
+            .zip(&self.data[start..start + channels.min(4)])
+        {
+            *component = *data;
+        }
+        value
+    }
+
+    fn sample_nearest(&self, x: f32, y: f32) -> [f32; 4] {
+        self.pixel(x.floor() as i64, y.floor() as i64)
+    }
+
+    fn sample_linear(&self, x: f32, y: f32) -> [f32; 4] {
+        // Coordinates point at the center of a pixel at `n + 0.5`.
+        let x = x - 0.5;
+        let y = y - 0.5;
+        let (x0, y0) = (x.floor(), y.floor());
+        let (fx, fy) = (x - x0, y - y0);
+        let (x0, y0) = (x0 as i64, y0 as i64);
+
+        let top_left = self.pixel(x0, y0);
+        let top_right = self.pixel(x0 + 1, y0);
+        let bottom_left = self.pixel(x0, y0 + 1);
+        let bottom_right = self.pixel(x0 + 1, y0 + 1);
+
+        let mut value = [0.0; 4];
+        for (i, component) in value.iter_mut().enumerate() {
+            let top = top_left[i] + (top_right[i] - top_left[i]) * fx;
+            let bottom = bottom_left[i] + (bottom_right[i] - bottom_left[i]) * fx;
+            *component = top + (bottom - top) * fy;
+        }
+        value
+    }
+}
+
+/// A value for one of the inputs of a shader.
+#[derive(Clone, Debug)]
+pub enum PixelBenderShaderArgument {
+    /// The value of the `PixelBenderParam::Normal` at `index` in the
+    /// parameters of the shader, with one number per component.
+    ValueInput { index: usize, value: Vec<f32> },
+
+    /// The image of the `PixelBenderParam::Texture` with the given texture index.
+    ImageInput { index: u8, image: PixelBenderImage },
+}
+
+#[derive(Clone)]
+struct Registers {
+    floats: Vec<[f32; 4]>,
+    ints: Vec<[i32; 4]>,
+}
+
+impl Registers {
+    fn get(&self, reg: u16) -> [f32; 4] {
+        if reg & INT_REGISTER != 0 {
+            let value = self
+                .ints
+                .get((reg & !INT_REGISTER) as usize)
+                .copied()
+                .unwrap_or_default();
+            value.map(|i| i as f32)
+        } else {
+            self.floats.get(reg as usize).copied().unwrap_or_default()
+        }
+    }
+
+    fn set(&mut self, reg: u16, component: usize, value: f32) {
+        if reg & INT_REGISTER != 0 {
+            let index = (reg & !INT_REGISTER) as usize;
+            if self.ints.len() <= index {
+                self.ints.resize(index + 1, [0; 4]);
+            }
+            self.ints[index][component] = value as i32;
+        } else {
+            let index = reg as usize;
+            if self.floats.len() <= index {
+                self.floats.resize(index + 1, [0.0; 4]);
+            }
+            self.floats[index][component] = value;
+        }
+    }
+
+    /// Reads the components of a source register, as selected by its swizzle.
+    fn read_src(&self, src: u32, size: usize) -> [f32; 4] {
+        let value = self.get((src & 0xFFFF) as u16);
+        let swizzle = (src >> 16) as u8;
+        let mut result = [0.0; 4];
+        for (i, component) in result.iter_mut().enumerate().take(size) {
+            *component = value[((swizzle >> (6 - 2 * i)) & 3) as usize];
+        }
+        result
+    }
+
+    /// Reads a matrix of `size` by `size` components, as its columns.
+    ///
+    /// A 2x2 matrix fits in a single register, while larger matrices take
+    /// one register per column.
+    fn read_matrix(&self, reg: u16, size: usize) -> Result<[[f32; 4]; 4], Box<dyn Error>> {
+        let mut columns = [[0.0; 4]; 4];
+        if size == 2 {
+            let value = self.get(reg);
+            columns[0][..2].copy_from_slice(&value[..2]);
+            columns[1][..2].copy_from_slice(&value[2..]);
+        } else {
+            for (i, column) in columns.iter_mut().enumerate().take(size) {
+                *column = self.get(column_register(reg, i)?);
+            }
+        }
+        Ok(columns)
+    }
+
+    fn write_matrix(
+        &mut self,
+        reg: u16,
+        size: usize,
+        columns: &[[f32; 4]; 4],
+    ) -> Result<(), Box<dyn Error>> {
+        for (i, column) in columns.iter().enumerate().take(size) {
+            for (j, value) in column.iter().enumerate().take(size) {
+                if size == 2 {
+                    self.set(reg, i * 2 + j, *value);
+                } else {
+                    self.set(column_register(reg, i)?, j, *value);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The register holding column `i` of a matrix starting at `reg`.
+///
+/// Columns can't run past the last register of the bank that `reg` is in.
+fn column_register(reg: u16, i: usize) -> Result<u16, Box<dyn Error>> {
+    let bank = reg & INT_REGISTER;
+    (reg & !INT_REGISTER)
+        .checked_add(i as u16)
+        .filter(|index| index & INT_REGISTER == 0)
+        .map(|index| index | bank)
+        .ok_or_else(|| format!("Matrix at register {reg:#x} is out of range").into())
+}
+
+/// The components selected by a write mask, where `0b1000` is the first one.
+fn mask_components(mask: u8) -> impl Iterator<Item = usize> {
+    (0..4).filter(move |i| mask & (0b1000 >> i) != 0)
+}
+
+/// The number of rows and columns of a matrix type, if it is one.
+fn matrix_size(param_type: &PixelBenderTypeOpcode) -> Option<usize> {
+    match param_type {
+        PixelBenderTypeOpcode::TFloat2x2 => Some(2),
+        PixelBenderTypeOpcode::TFloat3x3 => Some(3),
+        PixelBenderTypeOpcode::TFloat4x4 => Some(4),
+        _ => None,
+    }
+}
+
+fn type_values(value: &PixelBenderType) -> Vec<f32> {
+    match value {
+        PixelBenderType::TFloat(f) => vec![*f],
+        PixelBenderType::TFloat2(f1, f2) => vec![*f1, *f2],
+        PixelBenderType::TFloat3(f1, f2, f3) => vec![*f1, *f2, *f3],
+        PixelBenderType::TFloat4(f1, f2, f3, f4) => vec![*f1, *f2, *f3, *f4],
+        PixelBenderType::TFloat2x2(floats) => floats.to_vec(),
+        PixelBenderType::TFloat3x3(floats) => floats.to_vec(),
+        PixelBenderType::TFloat4x4(floats) => floats.to_vec(),
+        PixelBenderType::TInt(i) => vec![*i as f32],
+        PixelBenderType::TInt2(i1, i2) => vec![*i1 as f32, *i2 as f32],
+        PixelBenderType::TInt3(i1, i2, i3) => vec![*i1 as f32, *i2 as f32, *i3 as f32],
+        PixelBenderType::TInt4(i1, i2, i3, i4) => {
+            vec![*i1 as f32, *i2 as f32, *i3 as f32, *i4 as f32]
+        }
+        PixelBenderType::TString(_) => vec![],
+    }
+}
+
+fn as_bool(value: f32) -> bool {
+    value != 0.0
+}
+
+fn from_bool(value: bool) -> f32 {
+    if value {
+        1.0
+    } else {
+        0.0
+    }
+}
+
+/// Computes a component-wise operation on a component of the destination
+/// (`a`) and of the source (`b`).
+///
+/// Returns `None` for operations that don't work on single components.
+fn component_op(opcode: &Opcode, a: f32, b: f32) -> Option<f32> {
+    Some(match opcode {
+        Opcode::Add => a + b,
+        Opcode::Sub => a - b,
+        Opcode::Mul => a * b,
+        Opcode::Rcp => 1.0 / b,
+        Opcode::Div => a / b,
+        Opcode::Atan2 => a.atan2(b),
+        Opcode::Pow => a.powf(b),
+        Opcode::Mod => a - b * (a / b).floor(),
+        Opcode::Min => a.min(b),
+        Opcode::Max => a.max(b),
+        Opcode::Step => from_bool(b >= a),
+        Opcode::Sin => b.sin(),
+        Opcode::Cos => b.cos(),
+        Opcode::Tan => b.tan(),
+        Opcode::Asin => b.asin(),
+        Opcode::Acos => b.acos(),
+        Opcode::Atan => b.atan(),
+        Opcode::Exp => b.exp(),
+        Opcode::Exp2 => b.exp2(),
+        Opcode::Log => b.ln(),
+        Opcode::Log2 => b.log2(),
+        Opcode::Sqrt => b.sqrt(),
+        Opcode::RSqrt => 1.0 / b.sqrt(),
+        Opcode::Abs => b.abs(),
+        Opcode::Sign => {
+            if b == 0.0 {
+                0.0
+            } else {
+                b.signum()
+            }
+        }
+        Opcode::Floor => b.floor(),
+        Opcode::Ceil => b.ceil(),
+        Opcode::Fract => b - b.floor(),
+        // Conversions happen when writing to a register of the other bank.
+        Opcode::Mov | Opcode::FloatToInt | Opcode::IntToFloat | Opcode::BoolToInt => b,
+        Opcode::FloatToBool | Opcode::IntToBool | Opcode::BoolToFloat => from_bool(as_bool(b)),
+        Opcode::Equal => from_bool(a == b),
+        Opcode::NotEqual => from_bool(a != b),
+        Opcode::LessThan => from_bool(a < b),
+        Opcode::LessThanEqual => from_bool(a <= b),
+        Opcode::LogicalNot => from_bool(!as_bool(b)),
+        Opcode::LogicalAnd => from_bool(as_bool(a) && as_bool(b)),
+        Opcode::LogicalOr => from_bool(as_bool(a) || as_bool(b)),
+        Opcode::LogicalXor => from_bool(as_bool(a) != as_bool(b)),
+        _ => return None,
+    })
+}
+
+/// Computes an operation on the first `size` components of the destination
+/// (`a`) and source (`b`), which results in a vector.
+///
+/// Returns `None` for operations that work on single components.
+fn vector_op(opcode: &Opcode, a: &[f32], b: &[f32]) -> Option<Vec<f32>> {
+    let length = |v: &[f32]| v.iter().map(|c| c * c).sum::<f32>().sqrt();
+    Some(match opcode {
+        Opcode::Normalize => {
+            let length = length(b);
+            b.iter().map(|c| c / length).collect()
+        }
+        Opcode::Length => vec![length(b)],
+        Opcode::Distance => {
+            let difference: Vec<_> = a.iter().zip(b).map(|(a, b)| a - b).collect();
+            vec![length(&difference)]
+        }
+        Opcode::DotProduct => vec![a.iter().zip(b).map(|(a, b)| a * b).sum()],
+        Opcode::CrossProduct if a.len() == 3 => vec![
+            a[1] * b[2] - a[2] * b[1],
+            a[2] * b[0] - a[0] * b[2],
+            a[0] * b[1] - a[1] * b[0],
+        ],
+        Opcode::VectorEqual => vec![from_bool(a == b)],
+        Opcode::VectorNotEqual => vec![from_bool(a != b)],
+        Opcode::BoolAny => vec![from_bool(b.iter().any(|c| as_bool(*c)))],
+        Opcode::BoolAll => vec![from_bool(b.iter().all(|c| as_bool(*c)))],
+        _ => return None,
+    })
+}
+
+fn run_matrix_op(
+    registers: &mut Registers,
+    opcode: &Opcode,
+    dst: u16,
+    mask: u8,
+    src: u32,
+) -> Result<(), Box<dyn Error>> {
+    let size = ((mask >> 2) & 3) as usize + 1;
+    let src = (src & 0xFFFF) as u16;
+    let matrix = registers.read_matrix(src, size)?;
+
+    match opcode {
+        Opcode::MatVecMul | Opcode::VecMatMul => {
+            let components: Vec<_> = mask_components(mask >> 4).collect();
+            let dst_value = registers.get(dst);
+            let vector: Vec<_> = components.iter().map(|c| dst_value[*c]).collect();
+            for (i, component) in components.iter().enumerate() {
+                let value = (0..vector.len())
+                    .map(|j| {
+                        if *opcode == Opcode::MatVecMul {
+                            matrix[j][i] * vector[j]
+                        } else {
+                            vector[j] * matrix[i][j]
+                        }
+                    })
+                    .sum();
+                registers.set(dst, *component, value);
+            }
+        }
+        Opcode::MatMatMul => {
+            let left = registers.read_matrix(dst, size)?;
+            let mut result = [[0.0; 4]; 4];
+            for (column, result_column) in matrix.iter().zip(result.iter_mut()).take(size) {
+                for (row, value) in result_column.iter_mut().enumerate().take(size) {
+                    *value = (0..size).map(|k| left[k][row] * column[k]).sum();
+                }
+            }
+            registers.write_matrix(dst, size, &result)?;
+        }
+        opcode => {
+            let mut result = registers.read_matrix(dst, size)?;
+            for (result_column, column) in result.iter_mut().zip(&matrix) {
+                for (a, b) in result_column.iter_mut().zip(column) {
+                    *a = component_op(opcode, *a, *b).unwrap_or(*a);
+                }
+            }
+            registers.write_matrix(dst, size, &result)?;
+        }
+    }
+    Ok(())
+}
+
+fn run_normal_op(
+    registers: &mut Registers,
+    opcode: &Opcode,
+    dst: u16,
+    mask: u8,
+    src: u32,
+) -> Result<(), Box<dyn Error>> {
+    if (mask >> 2) & 3 != 0 {
+        return run_matrix_op(registers, opcode, dst, mask, src);
+    }
+
+    let size = (mask & 3) as usize + 1;
+    let components: Vec<_> = mask_components(mask >> 4).collect();
+    let src_value = registers.read_src(src, size);
+    let dst_value = registers.get(dst);
+
+    if let Some(result) = vector_op(opcode, &dst_value[..size], &src_value[..size]) {
+        // Results with a single component are written to every selected one.
+        for (i, component) in components.iter().enumerate() {
+            let value = if result.len() == 1 {
+                result[0]
+            } else {
+                result[i]
+            };
+            registers.set(dst, *component, value);
+        }
+        return Ok(());
+    }
+
+    for (i, component) in components.iter().enumerate() {
+        if let Some(value) = component_op(opcode, dst_value[*component], src_value[i]) {
+            registers.set(dst, *component, value);
+        }
+    }
+    Ok(())
+}
+
+fn is_supported(operation: &Operation) -> bool {
+    match operation {
+        Operation::Normal { opcode, .. } => {
+            matches!(
+                opcode,
+                Opcode::MatMatMul | Opcode::VecMatMul | Opcode::MatVecMul
+            ) || component_op(opcode, 0.0, 0.0).is_some()
+                || vector_op(opcode, &[0.0; 3], &[0.0; 3]).is_some()
+        }
+        _ => true,
+    }
+}
+
+/// Runs a shader for every pixel of an image of the given size.
+///
+/// Inputs without an argument use the `defaultValue` of their metadata, or
+/// zero. Missing images are treated as being fully transparent.
+///
+/// Fails if an operation refers to a matrix that doesn't fit in the registers.
+pub fn run_shader(
+    shader: &PixelBenderShader,
+    arguments: &[PixelBenderShaderArgument],
+    width: u32,
+    height: u32,
+) -> Result<PixelBenderImage, Box<dyn Error>> {
+    run_shader_at(shader, arguments, (0, 0), width, height)
+}
+
+/// Runs a shader for an area of the given size, whose top left pixel is at
+/// `origin` in the coordinates of the shader.
+pub fn run_shader_at(
+    shader: &PixelBenderShader,
+    arguments: &[PixelBenderShaderArgument],
+    origin: (i32, i32),
+    width: u32,
+    height: u32,
+) -> Result<PixelBenderImage, Box<dyn Error>> {
+    if let Some(operation) = shader.operations.iter().find(|op| !is_supported(op)) {
+        tracing::warn!("Unsupported Pixel Bender operation {:?}", operation);
+    }
+
+    let mut initial = Registers {
+        floats: vec![],
+        ints: vec![],
+    };
+    let mut out_coord = None;
+    let mut output = None;
+    for (index, param) in shader.params.iter().enumerate() {
+        let PixelBenderParam::Normal {
+            qualifier,
+            param_type,
+            reg,
+            mask,
+            name,
+            metadata,
+        } = param
+        else {
+            continue;
+        };
+
+        if *qualifier == PixelBenderParamQualifier::Output {
+            output = Some((*reg, *mask));
+            continue;
+        }
+        if name == "_OutCoord" {
+            out_coord = Some((*reg, *mask));
+            continue;
+        }
+
+        let value = arguments
+            .iter()
+            .find_map(|argument| match argument {
+                PixelBenderShaderArgument::ValueInput { index: i, value } if *i == index => {
+                    Some(value.clone())
+                }
+                _ => None,
+            })
+            .or_else(|| {
+                metadata
+                    .iter()
+                    .find(|meta| meta.key == "defaultValue")
+                    .map(|meta| type_values(&meta.value))
+            })
+            .unwrap_or_default();
+
+        if let Some(size) = matrix_size(param_type) {
+            let mut columns = [[0.0; 4]; 4];
+            for (i, value) in value.iter().enumerate().take(size * size) {
+                columns[i / size][i % size] = *value;
+            }
+            initial.write_matrix(*reg, size, &columns)?;
+        } else {
+            for (component, value) in mask_components(*mask).zip(value) {
+                initial.set(*reg, component, value);
+            }
+        }
+    }
+
+    let image = |texture: u8| {
+        arguments.iter().find_map(|argument| match argument {
+            PixelBenderShaderArgument::ImageInput { index, image } if *index == texture => {
+                Some(image)
+            }
+            _ => None,
+        })
+    };
+
+    let (output_reg, output_mask) = output.unwrap_or((0, 0));
+    let channels = mask_components(output_mask).count();
+    let mut data = Vec::with_capacity(width as usize * height as usize * channels);
+
+    for y in 0..height {
+        for x in 0..width {
+            let mut registers = initial.clone();
+            if let Some((reg, mask)) = out_coord {
+                let coord = [
+                    (origin.0 as f32) + x as f32 + 0.5,
+                    (origin.1 as f32) + y as f32 + 0.5,
+                ];
+                for (component, value) in mask_components(mask).zip(coord) {
+                    registers.set(reg, component, value);
+                }
+            }
+
+            // The number of `If`s that we are inside of without executing them.
+            let mut skipping = 0;
+            for operation in &shader.operations {
+                match operation {
+                    Operation::If { src } => {
+                        if skipping > 0 || !as_bool(registers.read_src(*src, 1)[0]) {
+                            skipping += 1;
+                        }
+                    }
+                    Operation::Else => {
+                        if skipping == 0 {
+                            skipping = 1;
+                        } else if skipping == 1 {
+                            skipping = 0;
+                        }
+                    }
+                    Operation::EndIf => {
+                        if skipping > 0 {
+                            skipping -= 1;
+                        }
+                    }
+                    _ if skipping > 0 => {}
+                    Operation::Nop => {}
+                    Operation::Normal {
+                        opcode,
+                        dst,
+                        mask,
+                        src,
+                        ..
+                    } => run_normal_op(&mut registers, opcode, *dst, *mask, *src)?,
+                    Operation::LoadInt { dst, mask, val } => {
+                        for component in mask_components(*mask >> 4) {
+                            registers.set(*dst | INT_REGISTER, component, *val as f32);
+                        }
+                    }
+                    Operation::LoadFloat { dst, mask, val } => {
+                        for component in mask_components(*mask >> 4) {
+                            registers.set(*dst, component, *val);
+                        }
+                    }
+                    Operation::SampleNearest { dst, src, mask, tf }
+                    | Operation::SampleLinear { dst, src, mask, tf } => {
+                        let coord = registers.read_src(*src, 2);
+                        let value = match image(*tf) {
+                            Some(image) if matches!(operation, Operation::SampleLinear { .. }) => {
+                                image.sample_linear(coord[0], coord[1])
+                            }
+                            Some(image) => image.sample_nearest(coord[0], coord[1]),
+                            None => [0.0; 4],
+                        };
+                        for (i, component) in mask_components(*mask >> 4).enumerate() {
+                            registers.set(*dst, component, value[i]);
+                        }
+                    }
+                }
+            }
+
+            let value = registers.get(output_reg);
+            data.extend(mask_components(output_mask).map(|component| value[component]));
+        }
+    }
+
+    Ok(PixelBenderImage {
+        width,
+        height,
+        channels: channels as u8,
+        data,
+    })
+}
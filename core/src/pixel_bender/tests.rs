@@ -1,9 +1,10 @@
 use crate::pixel_bender::{
-    Opcode, Operation, PixelBenderMetadata, PixelBenderParam, PixelBenderParamQualifier,
-    PixelBenderShader, PixelBenderType, PixelBenderTypeOpcode,
+    Opcode, Operation, PixelBenderImage, PixelBenderMetadata, PixelBenderParam,
+    PixelBenderParamQualifier, PixelBenderShader, PixelBenderShaderArgument, PixelBenderType,
+    PixelBenderTypeOpcode,
 };
 
-use super::parse_shader;
+use super::{parse_shader, run_shader, run_shader_at};
 
 #[test]
 fn simple_shader() {
@@ -152,41 +153,41 @@ fn simple_shader() {
                 opcode: Opcode::Rcp,
                 dst: 2,
                 mask: 49,
-                src: 176,
+                src: 0xB00000,
                 other: 0,
             },
             Operation::Normal {
                 opcode: Opcode::Mul,
                 dst: 2,
                 mask: 49,
-                src: 176,
+                src: 0xB00000,
                 other: 0,
             },
             Operation::Normal {
                 opcode: Opcode::Mov,
                 dst: 3,
                 mask: 193,
-                src: 82,
+                src: 0x500002,
                 other: 0,
             },
             Operation::Normal {
                 opcode: Opcode::Mul,
                 dst: 3,
                 mask: 193,
-                src: 178,
+                src: 0xB00002,
                 other: 0,
             },
             Operation::Normal {
                 opcode: Opcode::Mov,
                 dst: 2,
                 mask: 97,
-                src: 19,
+                src: 0x100003,
                 other: 0,
             },
             Operation::SampleNearest {
                 dst: 3,
                 mask: 241,
-                src: 16,
+                src: 0x100000,
                 tf: 0,
             },
             Operation::LoadFloat {
@@ -213,21 +214,21 @@ fn simple_shader() {
                 opcode: Opcode::Mov,
                 dst: 5,
                 mask: 243,
-                src: 30,
+                src: 0x1B0003,
                 other: 0,
             },
             Operation::Normal {
                 opcode: Opcode::Add,
                 dst: 5,
                 mask: 243,
-                src: 31,
+                src: 0x1B0004,
                 other: 0,
             },
             Operation::Normal {
                 opcode: Opcode::Mov,
                 dst: 1,
                 mask: 243,
-                src: 32,
+                src: 0x1B0005,
                 other: 0,
             },
         ],
@@ -236,3 +237,85 @@ fn simple_shader() {
     let shader = parse_shader(shader);
     assert_eq!(shader, expected, "Shader parsed incorrectly!");
 }
+
+#[test]
+fn run_simple_shader() {
+    let shader = parse_shader(include_bytes!(
+        "../../../tests/tests/swfs/avm2/pixelbender_shaderdata/shader.pbj"
+    ));
+    let src = PixelBenderImage {
+        width: 2,
+        height: 1,
+        channels: 4,
+        data: vec![0.25, 0.5, 0.0, 0.5, 1.0, 0.0, 0.5, 1.0],
+    };
+
+    let output = run_shader(
+        &shader,
+        &[PixelBenderShaderArgument::ImageInput {
+            index: 0,
+            image: src,
+        }],
+        2,
+        1,
+    )
+    .expect("Shader should run");
+
+    // The shader adds `float4(100.0, 0.0, 100.0, 1.0)` to every pixel of `src`.
+    let expected = PixelBenderImage {
+        width: 2,
+        height: 1,
+        channels: 4,
+        data: vec![100.25, 0.5, 100.0, 1.5, 101.0, 0.0, 100.5, 2.0],
+    };
+    assert_eq!(output, expected, "Shader ran incorrectly!");
+}
+
+#[test]
+fn run_shader_at_origin() {
+    let shader = parse_shader(include_bytes!(
+        "../../../tests/tests/swfs/avm2/pixelbender_shaderjob/shader.pbj"
+    ));
+
+    let output = run_shader_at(
+        &shader,
+        &[PixelBenderShaderArgument::ValueInput {
+            index: 2,
+            value: vec![2.0],
+        }],
+        (3, -1),
+        1,
+        1,
+    )
+    .expect("Shader should run");
+
+    // The shader outputs `float4(outCoord() * scale, 0.0, 1.0)`.
+    let expected = PixelBenderImage {
+        width: 1,
+        height: 1,
+        channels: 4,
+        data: vec![7.0, -1.0, 0.0, 1.0],
+    };
+    assert_eq!(output, expected, "Shader ran incorrectly!");
+}
+
+#[test]
+fn run_shader_with_out_of_range_matrix() {
+    // A 4x4 matrix multiplication whose source columns would run past the
+    // last float register.
+    let shader = PixelBenderShader {
+        name: "outOfRange".to_string(),
+        version: 1,
+        params: vec![],
+        metadata: vec![],
+        operations: vec![Operation::Normal {
+            opcode: Opcode::MatMatMul,
+            dst: 0,
+            mask: 0b1100,
+            src: 0x7FFE,
+            other: 0,
+        }],
+    };
+
+    assert!(run_shader(&shader, &[], 1, 1).is_err());
+}
@@ -8,7 +8,7 @@ use crate::avm1::{Activation, ActivationIdentifier};
 use crate::avm1::{ScriptObject, TObject, Value};
 use crate::avm2::{
     object::LoaderInfoObject, object::TObject as _, Activation as Avm2Activation, Avm2, CallStack,
-    Domain as Avm2Domain, Object as Avm2Object,
+    Domain as Avm2Domain, EventObject as Avm2EventObject, Object as Avm2Object,
 };
 use crate::backend::{
     audio::{AudioBackend, AudioManager},
@@ -24,6 +24,7 @@ use crate::context::{ActionQueue, ActionType, RenderContext, UpdateContext};
 use crate::context_menu::{
     BuiltInItemFlags, ContextMenuCallback, ContextMenuItem, ContextMenuState,
 };
+use crate::debugger::Debugger;
 use crate::display_object::Avm2MousePick;
 use crate::display_object::{
     EditText, InteractiveObject, MovieClip, Stage, StageAlign, StageDisplayState, StageScaleMode,
@@ -38,8 +39,10 @@ use crate::frame_lifecycle::{run_all_phases_avm2, FramePhase};
 use crate::library::Library;
 use crate::limits::ExecutionLimit;
 use crate::loader::{LoadBehavior, LoadManager};
+use crate::local_connection::LocalConnections;
 use crate::locale::get_current_date_time;
 use crate::prelude::*;
+use crate::socket::Sockets;
 use crate::streams::StreamManager;
 use crate::string::{AvmString, AvmStringInterner};
 use crate::stub::StubCollection;
@@ -161,6 +164,12 @@ struct GcRootData<'gc> {
     /// List of actively playing streams to decode.
     stream_manager: StreamManager<'gc>,
 
+    /// Open TCP connections of `Socket` and `XMLSocket` objects.
+    sockets: Sockets<'gc>,
+
+    /// Broker of messages between `LocalConnection` objects.
+    local_connections: LocalConnections<'gc>,
+
     /// Dynamic root for allowing handles to GC objects to exist outside of the GC.
     dynamic_root: DynamicRootSet<'gc>,
 }
@@ -188,6 +197,8 @@ impl<'gc> GcRootData<'gc> {
         &mut ExternalInterface<'gc>,
         &mut AudioManager<'gc>,
         &mut StreamManager<'gc>,
+        &mut Sockets<'gc>,
+        &mut LocalConnections<'gc>,
         DynamicRootSet<'gc>,
     ) {
         (
@@ -207,6 +218,8 @@ impl<'gc> GcRootData<'gc> {
             &mut self.external_interface,
             &mut self.audio_manager,
             &mut self.stream_manager,
+            &mut self.sockets,
+            &mut self.local_connections,
             self.dynamic_root,
         )
     }
@@ -271,6 +284,9 @@ pub struct Player {
     frame_accumulator: f64,
     recent_run_frame_timings: VecDeque<f64>,
 
+    /// The number of times `run_frame` has been called.
+    frames_run: u64,
+
     /// Faked time passage for fooling hand-written busy-loop FPS limiters.
     time_offset: u32,
 
@@ -279,10 +295,14 @@ pub struct Player {
     mouse_in_stage: bool,
     mouse_position: Point<Twips>,
 
-    /// The current mouse cursor icon.
+    /// The mouse cursor icon chosen by the hovered object.
     mouse_cursor: MouseCursor,
     mouse_cursor_needs_check: bool,
 
+    /// The mouse cursor icon currently displayed by the UI backend, which may
+    /// have been overridden by AS3 `Mouse.cursor`.
+    displayed_mouse_cursor: MouseCursor,
+
     system: SystemProperties,
 
     /// The current instance ID. Used to generate default `instanceN` names.
@@ -319,6 +339,9 @@ pub struct Player {
     /// Any compatibility rules to apply for this movie.
     compatibility_rules: CompatibilityRules,
 
+    /// The ActionScript debugger.
+    debugger: Debugger,
+
     /// Debug UI windows
     #[cfg(feature = "egui")]
     debug_ui: Rc<RefCell<crate::debug_ui::DebugUi>>,
@@ -504,7 +527,7 @@ impl Player {
             return;
         }
 
-        if self.is_playing() {
+        if self.is_playing() && !self.debugger.is_paused() {
             self.frame_accumulator += dt;
             let frame_rate = self.frame_rate;
             let frame_time = 1000.0 / frame_rate;
@@ -555,9 +578,7 @@ impl Player {
             });
 
             self.update_timers(dt);
-            self.update(|context| {
-                StreamManager::tick(context, dt);
-            });
+            self.update_streams(dt);
             self.audio.tick();
         }
     }
@@ -598,6 +619,25 @@ impl Player {
         self.mouse_in_stage = is_in;
     }
 
+    /// Converts a position in the viewport, in pixels, to the corresponding position on the stage.
+    pub fn viewport_to_stage_position(&self, x: f64, y: f64) -> Point<Twips> {
+        let inverse_view_matrix = self
+            .gc_arena
+            .borrow()
+            .mutate(|_, gc_root| gc_root.data.read().stage.inverse_view_matrix());
+        inverse_view_matrix * Point::from_pixels(x, y)
+    }
+
+    /// Converts a position on the stage to the corresponding position in the viewport, in pixels.
+    pub fn stage_to_viewport_position(&self, position: Point<Twips>) -> (f64, f64) {
+        let view_matrix = self
+            .gc_arena
+            .borrow()
+            .mutate(|_, gc_root| gc_root.data.read().stage.view_matrix());
+        let position = view_matrix * position;
+        (position.x.to_pixels(), position.y.to_pixels())
+    }
+
     /// Returns the master volume of the player. 1.0 is 100% volume.
     pub fn volume(&self) -> f32 {
         self.audio.volume()
@@ -1105,6 +1145,20 @@ impl Player {
                 }
             }
 
+            // Window focus changes are broadcast to every `EventDispatcher`.
+            if context.is_action_script_3() {
+                let event_name = match event {
+                    PlayerEvent::FocusGained => Some("activate"),
+                    PlayerEvent::FocusLost => Some("deactivate"),
+                    _ => None,
+                };
+                if let Some(event_name) = event_name {
+                    let event = Avm2EventObject::bare_default_event(context, event_name);
+                    let dispatcher_class = context.avm2.classes().eventdispatcher;
+                    Avm2::broadcast_event(context, event, dispatcher_class);
+                }
+            }
+
             // Fire event listener on appropriate object
             if let Some((listener_type, event_name, args)) = listener {
                 if let Some(root_clip) = context.stage.root_clip() {
@@ -1240,7 +1294,7 @@ impl Player {
 
         // Determine the display object the mouse is hovering over.
         // Search through levels from top-to-bottom, returning the first display object that is under the mouse.
-        let needs_render = self.mutate_with_update_context(|context| {
+        let (needs_render, cursor_override) = self.mutate_with_update_context(|context| {
             let new_over_object = if mouse_in_stage {
                 run_mouse_pick(context, true)
             } else {
@@ -1432,13 +1486,15 @@ impl Player {
                 refresh
             };
             Self::run_actions(context);
-            needs_render
+            (needs_render, context.stage.mouse_cursor_override())
         });
 
         // Update mouse cursor if it has changed.
-        if new_cursor != self.mouse_cursor {
-            self.mouse_cursor = new_cursor;
-            self.ui.set_mouse_cursor(new_cursor)
+        self.mouse_cursor = new_cursor;
+        let displayed_cursor = cursor_override.unwrap_or(new_cursor);
+        if displayed_cursor != self.displayed_mouse_cursor {
+            self.displayed_mouse_cursor = displayed_cursor;
+            self.ui.set_mouse_cursor(displayed_cursor)
         }
         self.mouse_cursor_needs_check = mouse_cursor_needs_check;
 
@@ -1504,6 +1560,13 @@ impl Player {
 
     #[instrument(level = "debug", skip_all)]
     pub fn run_frame(&mut self) {
+        // Nothing runs while the debugger is paused, until the frontend resumes it.
+        if self.debugger.is_paused() {
+            return;
+        }
+
+        self.frames_run += 1;
+
         let frame_time = Duration::from_nanos((750_000_000.0 / self.frame_rate) as u64);
         let (mut execution_limit, may_execute_while_streaming) = match self.load_behavior {
             LoadBehavior::Streaming => (
@@ -1523,6 +1586,9 @@ impl Player {
         }
 
         self.update(|context| {
+            Sockets::update_sockets(context);
+            LocalConnections::update_connections(context);
+
             if context.is_action_script_3() {
                 run_all_phases_avm2(context);
             } else {
@@ -1588,6 +1654,17 @@ impl Player {
         self.needs_render = false;
     }
 
+    /// The number of frames that have been run by this player, including frames
+    /// spent waiting for the movie to preload.
+    pub fn frames_run(&self) -> u64 {
+        self.frames_run
+    }
+
+    /// The stubs that have been encountered while running this movie.
+    pub fn stubs(&self) -> &StubCollection {
+        &self.stub_tracker
+    }
+
     /// The current frame of the main timeline, if available.
     /// The first frame is frame 1.
     pub fn current_frame(&self) -> Option<u16> {
@@ -1639,6 +1716,14 @@ impl Player {
         &mut self.ui
     }
 
+    pub fn debugger(&self) -> &Debugger {
+        &self.debugger
+    }
+
+    pub fn debugger_mut(&mut self) -> &mut Debugger {
+        &mut self.debugger
+    }
+
     pub fn run_actions(context: &mut UpdateContext<'_, '_>) {
         // Note that actions can queue further actions, so a while loop is necessary here.
         while let Some(action) = context.action_queue.pop_action() {
@@ -1764,6 +1849,8 @@ impl Player {
                 external_interface,
                 audio_manager,
                 stream_manager,
+                sockets,
+                local_connections,
                 dynamic_root,
             ) = root_data.update_context_params();
 
@@ -1803,6 +1890,7 @@ impl Player {
                 external_interface,
                 start_time: self.start_time,
                 update_start: Instant::now(),
+                debugger: &mut self.debugger,
                 max_execution_duration: self.max_execution_duration,
                 focus_tracker,
                 times_get_time_called: 0,
@@ -1814,6 +1902,8 @@ impl Player {
                 frame_phase: &mut self.frame_phase,
                 stub_tracker: &mut self.stub_tracker,
                 stream_manager,
+                sockets,
+                local_connections,
                 #[cfg(feature = "egui")]
                 dynamic_root,
             };
@@ -1943,6 +2033,12 @@ impl Player {
             self.mutate_with_update_context(|context| Timers::update_timers(context, dt));
     }
 
+    /// Process all playing media streams (such as created via NetStream.play),
+    /// as if `dt` milliseconds had passed.
+    pub fn update_streams(&mut self, dt: f64) {
+        self.update(|context| StreamManager::tick(context, dt));
+    }
+
     /// Returns whether this player consumes mouse wheel events.
     /// Used by web to prevent scrolling.
     pub fn should_prevent_scrolling(&mut self) -> bool {
@@ -2284,6 +2380,8 @@ impl PlayerBuilder {
                     timers: Timers::new(),
                     unbound_text_fields: Vec::new(),
                     stream_manager: StreamManager::new(),
+                    sockets: Sockets::empty(),
+                    local_connections: LocalConnections::empty(),
                     dynamic_root,
                 },
             ),
@@ -2346,6 +2444,7 @@ impl PlayerBuilder {
                 forced_frame_rate,
                 frame_phase: Default::default(),
                 frame_accumulator: 0.0,
+                frames_run: 0,
                 recent_run_frame_timings: VecDeque::with_capacity(10),
                 start_time: Instant::now(),
                 time_offset: 0,
@@ -2359,6 +2458,7 @@ impl PlayerBuilder {
                 mouse_position: Point::ZERO,
                 mouse_cursor: MouseCursor::Arrow,
                 mouse_cursor_needs_check: false,
+                displayed_mouse_cursor: MouseCursor::Arrow,
 
                 // Misc. state
                 rng: SmallRng::seed_from_u64(get_current_date_time().timestamp_millis() as u64),
@@ -2374,6 +2474,7 @@ impl PlayerBuilder {
                 spoofed_url: self.spoofed_url.clone(),
                 compatibility_rules: self.compatibility_rules.clone(),
                 stub_tracker: StubCollection::new(),
+                debugger: Debugger::default(),
                 #[cfg(feature = "egui")]
                 debug_ui: Default::default(),
 
@@ -0,0 +1,319 @@
+//! Flash Remoting, the AMF-based RPC protocol behind `NetConnection.call`.
+//!
+//! A call is sent as an HTTP POST of an AMF packet to the gateway URL that was
+//! passed to `NetConnection.connect`. The gateway replies with a packet that
+//! targets either `<response URI>/onResult` or `<response URI>/onStatus`,
+//! which is then delivered to the responder of the call.
+
+use crate::avm1::{
+    Activation as Avm1Activation, ActivationIdentifier as Avm1ActivationIdentifier,
+    ExecutionReason as Avm1ExecutionReason, TObject as Avm1TObject,
+};
+use crate::avm2::{
+    Activation as Avm2Activation, Error as Avm2Error, Multiname, TObject as Avm2TObject,
+    Value as Avm2Value,
+};
+use crate::backend::navigator::Response;
+use crate::context::UpdateContext;
+use crate::loader::Error;
+use crate::streams::trigger_net_status_event;
+use crate::string::AvmString;
+use crate::vminterface::AvmObject;
+use flash_lso::amf0::read::AMF0Decoder;
+use flash_lso::amf3::read::AMF3Decoder;
+use flash_lso::types::{AMFVersion, Value as AmfValue};
+
+/// The MIME type of Flash Remoting requests and responses.
+pub const CONTENT_TYPE: &str = "application/x-amf";
+
+/// The AMF0 type marker of a strict array.
+const STRICT_ARRAY_MARKER: u8 = 0x0A;
+
+/// The AMF0 type marker that switches to AMF3 for the following value.
+const AVMPLUS_MARKER: u8 = 0x11;
+
+/// The AMF0 type marker of `undefined`.
+const UNDEFINED_MARKER: u8 = 0x06;
+
+/// A message received in a Flash Remoting response.
+#[derive(Debug)]
+pub struct ResponseMessage {
+    /// The URI that this message should be delivered to, such as `/1/onResult`.
+    pub target_uri: String,
+
+    /// The result (or fault) of the call.
+    pub body: AmfValue,
+}
+
+/// Encodes a Flash Remoting request packet for a single call.
+///
+/// `command` is the remote method to call (such as `Service.method`), and
+/// `response_uri` is the URI that the gateway should address its reply to.
+/// With AMF3, the arguments are encoded as AMF3 values inside the AMF0 body.
+pub fn encode_call(
+    amf_version: AMFVersion,
+    command: &str,
+    response_uri: &str,
+    arguments: Vec<AmfValue>,
+) -> Vec<u8> {
+    let mut body = vec![STRICT_ARRAY_MARKER];
+    body.extend_from_slice(&(arguments.len() as u32).to_be_bytes());
+    for argument in arguments {
+        if amf_version == AMFVersion::AMF3 {
+            body.push(AVMPLUS_MARKER);
+        }
+        match crate::amf::write_value(argument, amf_version) {
+            Some(bytes) => body.extend_from_slice(&bytes),
+            None => {
+                tracing::warn!("NetConnection.call: Couldn't serialize argument");
+                if amf_version == AMFVersion::AMF3 {
+                    body.pop();
+                }
+                body.push(UNDEFINED_MARKER);
+            }
+        }
+    }
+
+    let mut packet = vec![];
+    let version: u16 = match amf_version {
+        AMFVersion::AMF0 => 0,
+        AMFVersion::AMF3 => 3,
+    };
+    packet.extend_from_slice(&version.to_be_bytes());
+    // No headers, followed by a single message.
+    packet.extend_from_slice(&0u16.to_be_bytes());
+    packet.extend_from_slice(&1u16.to_be_bytes());
+    write_string(&mut packet, command);
+    write_string(&mut packet, response_uri);
+    packet.extend_from_slice(&(body.len() as u32).to_be_bytes());
+    packet.extend_from_slice(&body);
+    packet
+}
+
+/// Decodes the messages of a Flash Remoting response packet.
+///
+/// Values may be encoded in either AMF0 or, after an AMF3 switch marker, AMF3.
+///
+/// Returns `None` if the packet is malformed.
+pub fn decode_response(data: &[u8]) -> Option<Vec<ResponseMessage>> {
+    let mut reader = PacketReader(data);
+    let _version = reader.read_u16()?;
+
+    let num_headers = reader.read_u16()?;
+    for _ in 0..num_headers {
+        let _name = reader.read_string()?;
+        let _must_understand = reader.read_u8()?;
+        let _length = reader.read_u32()?;
+        let _value = reader.read_value()?;
+    }
+
+    let num_messages = reader.read_u16()?;
+    let mut messages = Vec::with_capacity(num_messages.into());
+    for _ in 0..num_messages {
+        let target_uri = reader.read_string()?;
+        let _response_uri = reader.read_string()?;
+        // The length may be unknown (-1), so rely on the value itself instead.
+        let _length = reader.read_u32()?;
+        let body = reader.read_value()?;
+        messages.push(ResponseMessage { target_uri, body });
+    }
+
+    Some(messages)
+}
+
+fn write_string(out: &mut Vec<u8>, string: &str) {
+    out.extend_from_slice(&(string.len() as u16).to_be_bytes());
+    out.extend_from_slice(string.as_bytes());
+}
+
+/// Reads the big-endian fields of a Flash Remoting packet.
+struct PacketReader<'a>(&'a [u8]);
+
+impl<'a> PacketReader<'a> {
+    fn read_bytes(&mut self, len: usize) -> Option<&'a [u8]> {
+        if self.0.len() < len {
+            return None;
+        }
+        let (bytes, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Some(bytes)
+    }
+
+    fn read_u8(&mut self) -> Option<u8> {
+        Some(self.read_bytes(1)?[0])
+    }
+
+    fn read_u16(&mut self) -> Option<u16> {
+        Some(u16::from_be_bytes(self.read_bytes(2)?.try_into().ok()?))
+    }
+
+    fn read_u32(&mut self) -> Option<u32> {
+        Some(u32::from_be_bytes(self.read_bytes(4)?.try_into().ok()?))
+    }
+
+    fn read_string(&mut self) -> Option<String> {
+        let len = self.read_u16()?;
+        let bytes = self.read_bytes(len.into())?;
+        Some(String::from_utf8_lossy(bytes).into_owned())
+    }
+
+    /// Reads an AMF0 value, or an AMF3 value if it's preceded by the AMF3 switch marker.
+    fn read_value(&mut self) -> Option<AmfValue> {
+        let (rest, value) = if let Some(amf3) = self.0.strip_prefix(&[AVMPLUS_MARKER]) {
+            let mut decoder = AMF3Decoder::default();
+            let (rest, value) = decoder.parse_single_element(amf3).ok()?;
+            (rest, AmfValue::clone(&value))
+        } else {
+            let mut decoder = AMF0Decoder::default();
+            let (rest, value) = decoder.parse_single_element(self.0).ok()?;
+            (rest, AmfValue::clone(&value))
+        };
+        self.0 = rest;
+        Some(value)
+    }
+}
+
+/// Delivers the response to a `NetConnection.call` to its responder.
+///
+/// If the call failed outright, the `NetConnection` itself is notified with a
+/// `NetConnection.Call.Failed` status instead.
+pub fn handle_response<'gc>(
+    context: &mut UpdateContext<'_, 'gc>,
+    connection: AvmObject<'gc>,
+    responder: Option<AvmObject<'gc>>,
+    response_uri: &str,
+    response: Result<Response, Error>,
+) {
+    let messages = match response {
+        Ok(response) => decode_response(&response.body),
+        Err(e) => {
+            tracing::warn!("NetConnection.call: Request failed: {}", e);
+            None
+        }
+    };
+    let Some(messages) = messages else {
+        trigger_net_status_event(context, connection, "NetConnection.Call.Failed", "error");
+        return;
+    };
+
+    let Some(responder) = responder else {
+        return;
+    };
+
+    for message in messages {
+        let Some(method) = message
+            .target_uri
+            .strip_prefix(response_uri)
+            .and_then(|method| method.strip_prefix('/'))
+        else {
+            tracing::warn!(
+                "NetConnection.call: Ignoring response for {}",
+                message.target_uri
+            );
+            continue;
+        };
+
+        match responder {
+            AvmObject::Avm1(object) => {
+                let mut activation = Avm1Activation::from_stub(
+                    context.reborrow(),
+                    Avm1ActivationIdentifier::root("[NetConnection]"),
+                );
+                let method = AvmString::new_utf8(activation.context.gc_context, method);
+                let value = crate::avm1::globals::shared_object::deserialize_value(
+                    &mut activation,
+                    &message.body,
+                );
+                if let Err(e) = object.call_method(
+                    method,
+                    &[value],
+                    &mut activation,
+                    Avm1ExecutionReason::Special,
+                ) {
+                    tracing::error!("Error running NetConnection responder {}: {:?}", method, e);
+                }
+            }
+            AvmObject::Avm2(object) => {
+                // The `Responder` class keeps its callbacks in internal properties.
+                let callback_name = match method {
+                    "onResult" => "_result",
+                    "onStatus" => "_status",
+                    _ => {
+                        tracing::warn!("NetConnection.call: Unknown response method {}", method);
+                        continue;
+                    }
+                };
+
+                let mut activation = Avm2Activation::from_nothing(context.reborrow());
+                let result: Result<(), Avm2Error<'gc>> = (|| {
+                    let callback_name =
+                        Multiname::new(activation.avm2().ruffle_private_namespace, callback_name);
+                    let callback = object.get_property(&callback_name, &mut activation)?;
+                    if let Avm2Value::Object(callback) = callback {
+                        let value =
+                            crate::avm2::amf::deserialize_value(&mut activation, &message.body)?;
+                        callback.call(None, &[value], &mut activation)?;
+                    }
+                    Ok(())
+                })();
+
+                if let Err(e) = result {
+                    tracing::error!("Error running NetConnection responder {}: {:?}", method, e);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::remoting::{decode_response, encode_call};
+    use flash_lso::types::{AMFVersion, Value as AmfValue};
+
+    #[test]
+    fn test_encode_call() {
+        let packet = encode_call(
+            AMFVersion::AMF0,
+            "Service.method",
+            "/1",
+            vec![AmfValue::Bool(true)],
+        );
+        let mut expected = vec![0x00, 0x00, 0x00, 0x00, 0x00, 0x01];
+        expected.extend_from_slice(b"\x00\x0EService.method");
+        expected.extend_from_slice(b"\x00\x02/1");
+        expected.extend_from_slice(&[0x00, 0x00, 0x00, 0x07]);
+        expected.extend_from_slice(&[0x0A, 0x00, 0x00, 0x00, 0x01, 0x01, 0x01]);
+        assert_eq!(packet, expected);
+    }
+
+    #[test]
+    fn test_decode_response() {
+        let mut packet = vec![0x00, 0x00, 0x00, 0x00, 0x00, 0x01];
+        packet.extend_from_slice(b"\x00\x0B/1/onResult");
+        packet.extend_from_slice(b"\x00\x04null");
+        packet.extend_from_slice(&[0xFF, 0xFF, 0xFF, 0xFF]);
+        packet.extend_from_slice(&[0x02, 0x00, 0x02]);
+        packet.extend_from_slice(b"hi");
+
+        let messages = decode_response(&packet).expect("Response should be valid");
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].target_uri, "/1/onResult");
+        assert_eq!(messages[0].body, AmfValue::String("hi".to_string()));
+
+        assert!(decode_response(&packet[..packet.len() - 1]).is_none());
+    }
+
+    #[test]
+    fn test_decode_amf3_response() {
+        let mut packet = vec![0x00, 0x03, 0x00, 0x00, 0x00, 0x01];
+        packet.extend_from_slice(b"\x00\x0B/1/onResult");
+        packet.extend_from_slice(b"\x00\x04null");
+        packet.extend_from_slice(&[0xFF, 0xFF, 0xFF, 0xFF]);
+        packet.extend_from_slice(&[0x11, 0x06, 0x05]);
+        packet.extend_from_slice(b"hi");
+
+        let messages = decode_response(&packet).expect("Response should be valid");
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].body, AmfValue::String("hi".to_string()));
+    }
+}
@@ -0,0 +1,376 @@
+//! TCP connections made by `Socket` and `XMLSocket` objects.
+//!
+//! The connections themselves are made by the `NavigatorBackend`, which
+//! reports back through a channel of `SocketAction`s. These are handled once
+//! per frame by `Sockets::update_sockets`, which fires the events of the
+//! corresponding AVM objects.
+
+use crate::avm1::{
+    Activation as Avm1Activation, ActivationIdentifier as Avm1ActivationIdentifier,
+    ExecutionReason as Avm1ExecutionReason, TObject as Avm1TObject, Value as Avm1Value,
+};
+use crate::avm2::object::EventObject as Avm2EventObject;
+use crate::avm2::{
+    Activation as Avm2Activation, Avm2, Multiname, Object as Avm2Object, TObject as Avm2TObject,
+    Value as Avm2Value,
+};
+use crate::backend::navigator::NavigatorBackend;
+use crate::context::UpdateContext;
+use crate::string::AvmString;
+use crate::vminterface::AvmObject;
+use gc_arena::{Collect, CollectionContext};
+use generational_arena::{Arena, Index};
+use std::sync::mpsc::{channel, Receiver, Sender};
+use std::time::Duration;
+use url::Url;
+
+pub type SocketHandle = Index;
+
+/// The outcome of an attempt to connect a socket.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConnectionState {
+    /// The connection was established.
+    Connected,
+
+    /// The connection was refused, or the host could not be resolved.
+    Failed,
+
+    /// The connection could not be established before the timeout elapsed.
+    TimedOut,
+}
+
+/// Something that happened to a socket, as reported by the `NavigatorBackend`.
+#[derive(Debug)]
+pub enum SocketAction {
+    /// The connection attempt of a socket finished.
+    Connect(SocketHandle, ConnectionState),
+
+    /// Data was received from the server.
+    Data(SocketHandle, Vec<u8>),
+
+    /// The connection was closed by the server.
+    Close(SocketHandle),
+}
+
+/// A connection of a single `Socket` or `XMLSocket` object.
+#[derive(Collect)]
+#[collect(no_drop)]
+struct Socket<'gc> {
+    /// The AVM2 `Socket` or AVM1 `XMLSocket` that owns this connection.
+    target: AvmObject<'gc>,
+
+    /// The host that was connected to, used in error messages.
+    #[collect(require_static)]
+    host: String,
+
+    #[collect(require_static)]
+    port: u16,
+
+    /// Sink for data to be written to the connection.
+    ///
+    /// Dropping this tells the backend to close the connection.
+    #[collect(require_static)]
+    sender: Sender<Vec<u8>>,
+
+    /// Whether the connection has been established.
+    connected: bool,
+
+    /// Received data that does not form a complete message yet.
+    ///
+    /// Only used by AVM1 `XMLSocket`s, whose messages end with a zero byte.
+    #[collect(require_static)]
+    xml_buffer: Vec<u8>,
+}
+
+/// Manages the open connections of `Socket` and `XMLSocket` objects.
+pub struct Sockets<'gc> {
+    sockets: Arena<Socket<'gc>>,
+
+    receiver: Receiver<SocketAction>,
+
+    /// Sender passed to the `NavigatorBackend` with every new connection.
+    sender: Sender<SocketAction>,
+}
+
+unsafe impl<'gc> Collect for Sockets<'gc> {
+    fn trace(&self, cc: CollectionContext) {
+        for (_, socket) in self.sockets.iter() {
+            socket.trace(cc)
+        }
+    }
+}
+
+impl<'gc> Sockets<'gc> {
+    pub fn empty() -> Self {
+        let (sender, receiver) = channel();
+
+        Self {
+            sockets: Arena::new(),
+            receiver,
+            sender,
+        }
+    }
+
+    /// Start connecting the given object to a server.
+    ///
+    /// Any previous connection of the object is closed first. If `host` is
+    /// `None`, the host that the movie was loaded from is used.
+    pub fn connect(
+        &mut self,
+        navigator: &mut dyn NavigatorBackend,
+        movie_url: &str,
+        target: AvmObject<'gc>,
+        host: Option<String>,
+        port: u16,
+        timeout: Duration,
+    ) {
+        self.close(target);
+
+        let host = host.unwrap_or_else(|| {
+            Url::parse(movie_url)
+                .ok()
+                .and_then(|url| url.host_str().map(str::to_string))
+                .unwrap_or_else(|| "localhost".to_string())
+        });
+
+        let (sender, receiver) = channel();
+        let handle = self.sockets.insert(Socket {
+            target,
+            host: host.clone(),
+            port,
+            sender,
+            connected: false,
+            xml_buffer: vec![],
+        });
+
+        navigator.connect_socket(host, port, timeout, handle, receiver, self.sender.clone());
+    }
+
+    /// Whether the given object has an established connection.
+    pub fn is_connected(&self, target: AvmObject<'gc>) -> bool {
+        self.find(target)
+            .map(|handle| self.sockets[handle].connected)
+            .unwrap_or(false)
+    }
+
+    /// Write data to the connection of the given object.
+    ///
+    /// Returns `false` if the object is not connected.
+    pub fn send(&mut self, target: AvmObject<'gc>, data: Vec<u8>) -> bool {
+        match self.find(target).map(|handle| &self.sockets[handle]) {
+            Some(socket) if socket.connected => {
+                // The backend may have hung up already, in which case a
+                // `Close` action is on its way.
+                let _ = socket.sender.send(data);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Close the connection of the given object, without firing any events.
+    ///
+    /// Returns `true` if the object was connected.
+    pub fn close(&mut self, target: AvmObject<'gc>) -> bool {
+        self.find(target)
+            .and_then(|handle| self.sockets.remove(handle))
+            .map(|socket| socket.connected)
+            .unwrap_or(false)
+    }
+
+    fn find(&self, target: AvmObject<'gc>) -> Option<SocketHandle> {
+        self.sockets
+            .iter()
+            .find(|(_, socket)| AvmObject::ptr_eq(socket.target, target))
+            .map(|(handle, _)| handle)
+    }
+
+    /// Handle everything that happened to the open connections since the
+    /// last call, firing the events of their objects.
+    pub fn update_sockets(context: &mut UpdateContext<'_, 'gc>) {
+        let actions: Vec<_> = context.sockets.receiver.try_iter().collect();
+
+        for action in actions {
+            match action {
+                SocketAction::Connect(handle, state) => {
+                    let Some(socket) = context.sockets.sockets.get_mut(handle) else {
+                        // The socket was closed while connecting.
+                        continue;
+                    };
+                    let target = socket.target;
+                    let address = (socket.host.clone(), socket.port);
+                    if state == ConnectionState::Connected {
+                        socket.connected = true;
+                    } else {
+                        context.sockets.sockets.remove(handle);
+                    }
+
+                    match target {
+                        AvmObject::Avm1(object) => {
+                            let success = state == ConnectionState::Connected;
+                            call_avm1_handler(context, object, "onConnect", &[success.into()]);
+                        }
+                        AvmObject::Avm2(object) => {
+                            connect_avm2_socket(context, object, state, address)
+                        }
+                    }
+                }
+                SocketAction::Data(handle, data) => {
+                    let Some(socket) = context.sockets.sockets.get_mut(handle) else {
+                        continue;
+                    };
+
+                    match socket.target {
+                        AvmObject::Avm1(object) => {
+                            // Every message of an `XMLSocket` ends with a zero byte.
+                            socket.xml_buffer.extend_from_slice(&data);
+                            let mut messages = vec![];
+                            while let Some(end) = socket.xml_buffer.iter().position(|b| *b == 0) {
+                                let message: Vec<u8> = socket.xml_buffer.drain(..=end).collect();
+                                messages
+                                    .push(String::from_utf8_lossy(&message[..end]).into_owned());
+                            }
+
+                            for message in messages {
+                                let message =
+                                    AvmString::new_utf8(context.gc_context, message).into();
+                                call_avm1_handler(context, object, "onData", &[message]);
+                            }
+                        }
+                        AvmObject::Avm2(object) => receive_avm2_data(context, object, data),
+                    }
+                }
+                SocketAction::Close(handle) => {
+                    let Some(socket) = context.sockets.sockets.remove(handle) else {
+                        continue;
+                    };
+
+                    match socket.target {
+                        AvmObject::Avm1(object) => {
+                            call_avm1_handler(context, object, "onClose", &[]);
+                        }
+                        AvmObject::Avm2(object) => {
+                            let close_evt = Avm2EventObject::bare_default_event(context, "close");
+                            Avm2::dispatch_event(context, close_evt, object);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn call_avm1_handler<'gc>(
+    context: &mut UpdateContext<'_, 'gc>,
+    object: crate::avm1::Object<'gc>,
+    name: &'static str,
+    args: &[Avm1Value<'gc>],
+) {
+    let mut activation = Avm1Activation::from_stub(
+        context.reborrow(),
+        Avm1ActivationIdentifier::root("[XMLSocket]"),
+    );
+    if let Err(e) = object.call_method(
+        name.into(),
+        args,
+        &mut activation,
+        Avm1ExecutionReason::Special,
+    ) {
+        tracing::error!("Error running XMLSocket.{}: {:?}", name, e);
+    }
+}
+
+fn connect_avm2_socket<'gc>(
+    context: &mut UpdateContext<'_, 'gc>,
+    object: Avm2Object<'gc>,
+    state: ConnectionState,
+    (host, port): (String, u16),
+) {
+    let mut activation = Avm2Activation::from_nothing(context.reborrow());
+    let event = match state {
+        ConnectionState::Connected => Ok(Avm2EventObject::bare_default_event(
+            &mut activation.context,
+            "connect",
+        )),
+        ConnectionState::Failed => {
+            let message = format!("Error #2031: Socket Error. URL: {host}");
+            let message = AvmString::new_utf8(activation.context.gc_context, message);
+            let io_error_evt_cls = activation.avm2().classes().ioerrorevent;
+            io_error_evt_cls.construct(
+                &mut activation,
+                &[
+                    "ioError".into(),
+                    false.into(),
+                    false.into(),
+                    message.into(),
+                    2031.into(),
+                ],
+            )
+        }
+        ConnectionState::TimedOut => {
+            // Flash Player reports a timeout as a failure to load the policy file.
+            let message = format!(
+                "Error #2048: Security sandbox violation: {} cannot load data from {host}:{port}.",
+                activation.context.swf.url()
+            );
+            let message = AvmString::new_utf8(activation.context.gc_context, message);
+            let security_error_evt_cls = activation.avm2().classes().securityerrorevent;
+            security_error_evt_cls.construct(
+                &mut activation,
+                &[
+                    "securityError".into(),
+                    false.into(),
+                    false.into(),
+                    message.into(),
+                    2048.into(),
+                ],
+            )
+        }
+    };
+
+    match event {
+        Ok(event) => Avm2::dispatch_event(&mut activation.context, event, object),
+        Err(e) => tracing::error!("Error creating Socket event: {:?}", e),
+    }
+}
+
+fn receive_avm2_data<'gc>(
+    context: &mut UpdateContext<'_, 'gc>,
+    object: Avm2Object<'gc>,
+    data: Vec<u8>,
+) {
+    let mut activation = Avm2Activation::from_nothing(context.reborrow());
+    let result = (|| {
+        // The `Socket` class reads from an internal `ByteArray`.
+        let read_buffer = Multiname::new(activation.avm2().ruffle_private_namespace, "_readBuffer");
+        if let Avm2Value::Object(read_buffer) =
+            object.get_property(&read_buffer, &mut activation)?
+        {
+            if let Some(mut storage) = read_buffer.as_bytearray_mut(activation.context.gc_context) {
+                // Drop the data that has been read already, so that the buffer doesn't
+                // keep growing for as long as the connection is open.
+                if storage.bytes_available() == 0 {
+                    storage.clear();
+                }
+                let len = storage.len();
+                storage.write_at(&data, len)?;
+            }
+        }
+
+        activation.avm2().classes().progressevent.construct(
+            &mut activation,
+            &[
+                "socketData".into(),
+                false.into(),
+                false.into(),
+                data.len().into(),
+                0.into(),
+            ],
+        )
+    })();
+
+    match result {
+        Ok(event) => Avm2::dispatch_event(&mut activation.context, event, object),
+        Err(e) => tracing::error!("Error receiving Socket data: {:?}", e),
+    }
+}
@@ -1,10 +1,33 @@
 //! NetStream implementation
 
+use crate::avm1::{
+    Activation as Avm1Activation, ActivationIdentifier as Avm1ActivationIdentifier,
+    ArrayObject as Avm1ArrayObject, ExecutionReason as Avm1ExecutionReason, Object as Avm1Object,
+    ScriptObject as Avm1ScriptObject, TObject as Avm1TObject, Value as Avm1Value,
+};
+use crate::avm2::{
+    Activation as Avm2Activation, ArrayObject as Avm2ArrayObject, ArrayStorage as Avm2ArrayStorage,
+    Avm2, Object as Avm2Object, TObject as Avm2TObject, Value as Avm2Value,
+};
+use crate::backend::audio::{AudioFormat, SoundInstanceHandle};
 use crate::backend::navigator::Request;
+use crate::buffer::Substream;
 use crate::context::UpdateContext;
 use crate::loader::Error;
 use crate::string::AvmString;
+use crate::vminterface::AvmObject;
+use flv_rs::{
+    AudioData as FlvAudioData, AudioDataType as FlvAudioDataType, Error as FlvError, FlvReader,
+    FrameType as FlvFrameType, Header as FlvHeader, ScriptData as FlvScriptData,
+    SoundFormat as FlvSoundFormat, SoundSize as FlvSoundSize, SoundType as FlvSoundType,
+    Tag as FlvTag, TagData as FlvTagData, Value as FlvValue, VideoData as FlvVideoData,
+    VideoPacket as FlvVideoPacket,
+};
 use gc_arena::{Collect, GcCell, MutationContext};
+use ruffle_render::bitmap::BitmapInfo;
+use ruffle_video::frame::EncodedFrame;
+use ruffle_video::VideoStreamHandle;
+use swf::{AudioCompression, SoundFormat, VideoCodec, VideoDeblocking};
 
 /// Manager for all media streams.
 ///
@@ -71,7 +94,31 @@ impl<'gc> StreamManager<'gc> {
     /// support video framerates separate from the Stage frame rate.
     ///
     /// This does not borrow `&mut self` as we need the `UpdateContext`, too.
-    pub fn tick(_context: &mut UpdateContext<'_, 'gc>, _dt: f64) {}
+    pub fn tick(context: &mut UpdateContext<'_, 'gc>, dt: f64) {
+        let streams = context.stream_manager.playing_streams.clone();
+        for stream in streams {
+            stream.tick(context, dt)
+        }
+    }
+}
+
+/// The container format of the media in a `NetStream`, along with any
+/// per-format decoding state.
+#[derive(Clone, Debug)]
+pub enum NetStreamType {
+    /// The stream is an FLV file.
+    Flv {
+        header: FlvHeader,
+
+        /// The video stream that video tags are decoded with.
+        ///
+        /// This is registered upon encountering the first video tag, as FLV
+        /// headers do not declare which codec is in use.
+        video_stream: Option<VideoStreamHandle>,
+
+        /// The frame ID of the next video tag to decode.
+        frame_id: u32,
+    },
 }
 
 /// A stream representing download of some (audiovisual) data.
@@ -81,8 +128,8 @@ impl<'gc> StreamManager<'gc> {
 ///
 ///  * `LoadManager` fills individual `NetStream` buffers with data (or, in the
 ///    future, empties them out for media upload)
-///  * `StreamManager` processes media data in the `NetStream` buffer (in the
-///    future, sending it to the audio backend or `SoundManager`)
+///  * `StreamManager` processes media data in the `NetStream` buffer, decoding
+///    video frames and handing audio data to the audio backend
 ///  * `Video` display objects linked to this `NetStream` display the latest
 ///    decoded frame.
 ///
@@ -90,7 +137,7 @@ impl<'gc> StreamManager<'gc> {
 /// is intended to be a VM-agnostic version of those.
 #[derive(Clone, Debug, Collect, Copy)]
 #[collect(no_drop)]
-pub struct NetStream<'gc>(GcCell<'gc, NetStreamData>);
+pub struct NetStream<'gc>(GcCell<'gc, NetStreamData<'gc>>);
 
 impl<'gc> PartialEq for NetStream<'gc> {
     fn eq(&self, other: &Self) -> bool {
@@ -101,26 +148,87 @@ impl<'gc> PartialEq for NetStream<'gc> {
 impl<'gc> Eq for NetStream<'gc> {}
 
 #[derive(Clone, Debug, Collect)]
-#[collect(require_static)]
-pub struct NetStreamData {
+#[collect(no_drop)]
+pub struct NetStreamData<'gc> {
     /// All data currently loaded in the stream.
+    #[collect(require_static)]
     buffer: Vec<u8>,
+
+    /// The buffer position of the next piece of media to be played.
+    offset: usize,
+
+    /// The buffer position of the next audio data to hand to the audio
+    /// backend.
+    ///
+    /// Audio data is sent ahead of the video so that the audio backend does
+    /// not run dry between stream ticks.
+    audio_offset: usize,
+
+    /// The container format of the stream, once it has been determined.
+    #[collect(require_static)]
+    stream_type: Option<NetStreamType>,
+
+    /// The current playback position of the stream, in milliseconds.
+    stream_time: f64,
+
+    /// The audio data of the stream, as it is being played.
+    #[collect(require_static)]
+    audio_substream: Option<Substream>,
+
+    /// The sound format of the data in `audio_substream`.
+    #[collect(require_static)]
+    audio_format: Option<AudioFormat>,
+
+    /// The sound instance playing `audio_substream`.
+    #[collect(require_static)]
+    audio_stream: Option<SoundInstanceHandle>,
+
+    /// The `AudioSpecificConfig` from the last AAC sequence header, which
+    /// every AAC substream must begin with.
+    #[collect(require_static)]
+    aac_config: Option<Vec<u8>>,
+
+    /// The last decoded video frame of the stream.
+    #[collect(require_static)]
+    last_decoded_bitmap: Option<BitmapInfo>,
+
+    /// The AVM side of this stream, which receives status events and
+    /// metadata callbacks.
+    avm_object: Option<AvmObject<'gc>>,
 }
 
 impl<'gc> NetStream<'gc> {
-    pub fn new(gc_context: MutationContext<'gc, '_>) -> Self {
+    pub fn new(gc_context: MutationContext<'gc, '_>, avm_object: Option<AvmObject<'gc>>) -> Self {
         Self(GcCell::allocate(
             gc_context,
-            NetStreamData { buffer: Vec::new() },
+            NetStreamData {
+                buffer: Vec::new(),
+                offset: 0,
+                audio_offset: 0,
+                stream_type: None,
+                stream_time: 0.0,
+                audio_substream: None,
+                audio_format: None,
+                audio_stream: None,
+                aac_config: None,
+                last_decoded_bitmap: None,
+                avm_object,
+            },
         ))
     }
 
+    pub fn set_avm_object(self, gc_context: MutationContext<'gc, '_>, avm_object: AvmObject<'gc>) {
+        self.0.write(gc_context).avm_object = Some(avm_object);
+    }
+
     pub fn load_buffer(self, gc_context: MutationContext<'gc, '_>, data: &mut Vec<u8>) {
         self.0.write(gc_context).buffer.append(data);
     }
 
-    pub fn report_error(self, _error: Error) {
-        //TODO: Report an `asyncError` to AVM1 or 2.
+    pub fn report_error(self, context: &mut UpdateContext<'_, 'gc>, error: Error) {
+        tracing::error!("Error loading NetStream: {}", error);
+        StreamManager::ensure_paused(context, self);
+        self.trigger_status_event(context, "NetStream.Play.StreamNotFound", "error");
     }
 
     pub fn bytes_loaded(self) -> usize {
@@ -131,6 +239,16 @@ impl<'gc> NetStream<'gc> {
         self.0.read().buffer.len()
     }
 
+    /// The current playback position of the stream, in seconds.
+    pub fn time(self) -> f64 {
+        self.0.read().stream_time / 1000.0
+    }
+
+    /// The last video frame decoded from this stream, if any.
+    pub fn last_decoded_bitmap(self) -> Option<BitmapInfo> {
+        self.0.read().last_decoded_bitmap.clone()
+    }
+
     /// Start playing media from this NetStream.
     ///
     /// If `name` is specified, this will also trigger streaming download of
@@ -138,18 +256,23 @@ impl<'gc> NetStream<'gc> {
     /// available in the buffer.
     pub fn play(self, context: &mut UpdateContext<'_, 'gc>, name: Option<AvmString<'gc>>) {
         if let Some(name) = name {
+            self.reset(context);
+
             let request = Request::get(name.to_string());
-            context
+            let future = context
                 .load_manager
                 .load_netstream(context.player.clone(), self, request);
+            context.navigator.spawn_future(future);
         }
 
         StreamManager::ensure_playing(context, self);
+        self.trigger_status_event(context, "NetStream.Play.Start", "status");
     }
 
     /// Pause stream playback.
     pub fn pause(self, context: &mut UpdateContext<'_, 'gc>) {
         StreamManager::ensure_paused(context, self);
+        self.stop_audio(context);
     }
 
     /// Resume stream playback.
@@ -160,5 +283,590 @@ impl<'gc> NetStream<'gc> {
     /// Resume stream playback if paused, pause otherwise.
     pub fn toggle_paused(self, context: &mut UpdateContext<'_, 'gc>) {
         StreamManager::toggle_paused(context, self);
+        self.stop_audio(context);
+    }
+
+    /// Stop playback and discard all loaded data.
+    pub fn close(self, context: &mut UpdateContext<'_, 'gc>) {
+        StreamManager::ensure_paused(context, self);
+        self.reset(context);
+    }
+
+    /// Seek to a given time in the stream, in milliseconds.
+    ///
+    /// Playback resumes from the last keyframe at or before the requested
+    /// time, as Flash Player does for progressively downloaded video.
+    pub fn seek(self, context: &mut UpdateContext<'_, 'gc>, time: f64) {
+        self.stop_audio(context);
+
+        let mut write = self.0.write(context.gc_context);
+        let data = &mut *write;
+        let header_end = match &data.stream_type {
+            Some(NetStreamType::Flv { header, .. }) => header.data_offset as usize + 4,
+            None => return,
+        };
+
+        let mut reader = FlvReader::with_position(&data.buffer, header_end);
+        let mut seek_point = (header_end, 0);
+        while let Ok(tag) = FlvTag::parse(&mut reader) {
+            if f64::from(tag.timestamp) > time {
+                break;
+            }
+
+            if let FlvTagData::Video(FlvVideoData {
+                frame_type: FlvFrameType::Keyframe,
+                ..
+            }) = tag.data
+            {
+                seek_point = (reader.position(), tag.timestamp);
+            }
+        }
+
+        // `reader.position()` is the end of the keyframe tag; go back to its start.
+        let (keyframe_end, keyframe_time) = seek_point;
+        let mut reader = FlvReader::with_position(&data.buffer, header_end);
+        let mut keyframe_start = header_end;
+        while reader.position() < keyframe_end {
+            keyframe_start = reader.position();
+            if FlvTag::parse(&mut reader).is_err() {
+                break;
+            }
+        }
+
+        data.offset = keyframe_start;
+        data.audio_offset = keyframe_start;
+        data.stream_time = f64::from(keyframe_time);
+        drop(write);
+
+        self.trigger_status_event(context, "NetStream.Seek.Notify", "status");
+    }
+
+    /// Discard all loaded data and playback state.
+    fn reset(self, context: &mut UpdateContext<'_, 'gc>) {
+        self.stop_audio(context);
+
+        let mut write = self.0.write(context.gc_context);
+        write.buffer.clear();
+        write.offset = 0;
+        write.audio_offset = 0;
+        write.stream_type = None;
+        write.stream_time = 0.0;
+        write.aac_config = None;
+        write.last_decoded_bitmap = None;
+    }
+
+    /// Stop any audio this stream is playing.
+    ///
+    /// Audio data that has already been sent to the audio backend is
+    /// discarded, and will be sent again when playback resumes.
+    fn stop_audio(self, context: &mut UpdateContext<'_, 'gc>) {
+        let mut write = self.0.write(context.gc_context);
+        if let Some(instance) = write.audio_stream.take() {
+            context.stop_sound(instance);
+        }
+
+        write.audio_substream = None;
+        write.audio_format = None;
+        write.audio_offset = write.offset;
+    }
+
+    /// Process the media in this stream up to `dt` milliseconds past the
+    /// current stream time.
+    pub fn tick(self, context: &mut UpdateContext<'_, 'gc>, dt: f64) {
+        let mut write = self.0.write(context.gc_context);
+        let data = &mut *write;
+
+        if data.stream_type.is_none() {
+            let mut reader = FlvReader::new(&data.buffer);
+            match FlvHeader::parse(&mut reader) {
+                Ok(header) => {
+                    data.offset = reader.position();
+                    data.audio_offset = reader.position();
+                    data.stream_type = Some(NetStreamType::Flv {
+                        header,
+                        video_stream: None,
+                        frame_id: 0,
+                    });
+                }
+                Err(FlvError::EndOfData) => return,
+                Err(e) => {
+                    tracing::error!("Unsupported NetStream media: {}", e);
+                    drop(write);
+                    StreamManager::ensure_paused(context, self);
+                    self.trigger_status_event(
+                        context,
+                        "NetStream.Play.FileStructureInvalid",
+                        "error",
+                    );
+                    return;
+                }
+            }
+        }
+
+        let end_time = data.stream_time + dt;
+        let mut script_tags = Vec::new();
+        let mut is_buffering = false;
+
+        // Take the buffer so tags can borrow from it while the rest of the
+        // stream state is updated.
+        let buffer = std::mem::take(&mut data.buffer);
+
+        // Send audio ahead of the playhead. Audio tags are not time-gated:
+        // the audio backend plays them back at their own pace.
+        let mut reader = FlvReader::with_position(&buffer, data.audio_offset);
+        while let Ok(tag) = FlvTag::parse(&mut reader) {
+            if let FlvTagData::Audio(audio) = tag.data {
+                Self::queue_audio(context, data, audio);
+            }
+        }
+        data.audio_offset = reader.position();
+
+        let mut reader = FlvReader::with_position(&buffer, data.offset);
+        loop {
+            let tag_start = reader.position();
+            let tag = match FlvTag::parse(&mut reader) {
+                Ok(tag) => tag,
+                Err(FlvError::EndOfData) => {
+                    is_buffering = true;
+                    break;
+                }
+                Err(e) => {
+                    // Malformed tag bodies are returned as `FlvTagData::Invalid`,
+                    // so this is only reached if the FLV reader itself is broken.
+                    tracing::error!("Error parsing FLV tag: {}", e);
+                    break;
+                }
+            };
+
+            if f64::from(tag.timestamp) > end_time {
+                reader.seek(tag_start);
+                break;
+            }
+
+            match tag.data {
+                FlvTagData::Video(video) => {
+                    if let Some(bitmap) = Self::decode_video(context, data, video) {
+                        data.last_decoded_bitmap = Some(bitmap);
+                    }
+                }
+                FlvTagData::Script(_) => {
+                    // Script callbacks may call back into this stream, so
+                    // they are run once we are done with the buffer.
+                    script_tags.push(buffer[tag_start..reader.position()].to_vec());
+                }
+                FlvTagData::Audio(_) => {}
+                FlvTagData::Unknown { tag_type, .. } => {
+                    tracing::warn!("Ignoring unknown FLV tag type {}", tag_type);
+                }
+                FlvTagData::Invalid {
+                    tag_type, error, ..
+                } => {
+                    tracing::error!("Skipping invalid FLV tag of type {}: {}", tag_type, error);
+                }
+            }
+        }
+
+        data.offset = reader.position();
+
+        // If we ran out of data, hold the playhead until more arrives.
+        let reached_end = is_buffering && data.offset >= buffer.len();
+        if !is_buffering {
+            data.stream_time = end_time;
+        }
+
+        data.buffer = buffer;
+
+        drop(write);
+
+        for tag_data in script_tags {
+            let mut reader = FlvReader::new(&tag_data);
+            if let Ok(FlvTag {
+                data: FlvTagData::Script(script_data),
+                ..
+            }) = FlvTag::parse(&mut reader)
+            {
+                self.run_script_data(context, script_data);
+            }
+        }
+
+        if reached_end {
+            StreamManager::ensure_paused(context, self);
+            self.trigger_status_event(context, "NetStream.Play.Stop", "status");
+        }
+    }
+
+    /// Hand an FLV audio tag to the audio backend, starting a new sound if
+    /// necessary.
+    fn queue_audio(
+        context: &mut UpdateContext<'_, 'gc>,
+        data: &mut NetStreamData<'gc>,
+        audio: FlvAudioData<'_>,
+    ) {
+        let audio_data = match audio.data {
+            FlvAudioDataType::Raw(audio_data) | FlvAudioDataType::AacRaw(audio_data) => audio_data,
+            FlvAudioDataType::AacSequenceHeader(config) => {
+                // Frames after a new sequence header must be decoded with it,
+                // so restart the sound when the next frame arrives.
+                if data.aac_config.as_deref() != Some(config) {
+                    if let Some(instance) = data.audio_stream.take() {
+                        context.stop_sound(instance);
+                    }
+                    data.aac_config = Some(config.to_vec());
+                }
+                return;
+            }
+        };
+
+        let is_stereo = audio.sound_type == FlvSoundType::Stereo;
+        let format = if audio.format == FlvSoundFormat::Aac {
+            // AAC is specific to FLV, so it has no SWF compression code of its own.
+            AudioFormat::Aac {
+                sample_rate: audio.rate.sample_rate(),
+                is_stereo,
+            }
+        } else {
+            let (compression, sample_rate) = match audio.format {
+                FlvSoundFormat::LinearPcmPlatformEndian => (
+                    AudioCompression::UncompressedUnknownEndian,
+                    audio.rate.sample_rate(),
+                ),
+                FlvSoundFormat::Adpcm => (AudioCompression::Adpcm, audio.rate.sample_rate()),
+                FlvSoundFormat::Mp3 => (AudioCompression::Mp3, audio.rate.sample_rate()),
+                FlvSoundFormat::LinearPcmLittleEndian => {
+                    (AudioCompression::Uncompressed, audio.rate.sample_rate())
+                }
+                FlvSoundFormat::Nellymoser16kMono => (AudioCompression::Nellymoser, 16000),
+                FlvSoundFormat::Nellymoser8kMono => (AudioCompression::Nellymoser, 8000),
+                FlvSoundFormat::Nellymoser => {
+                    (AudioCompression::Nellymoser, audio.rate.sample_rate())
+                }
+                FlvSoundFormat::Speex => (AudioCompression::Speex, 16000),
+                format => {
+                    tracing::warn!("NetStream: Unsupported audio format {:?}", format);
+                    return;
+                }
+            };
+            AudioFormat::Swf(SoundFormat {
+                compression,
+                sample_rate,
+                is_stereo,
+                is_16_bit: audio.size == FlvSoundSize::Bits16,
+            })
+        };
+
+        // Restart the sound if the format changed or if the previous sound
+        // ran out of data before this tag arrived.
+        let is_playing = data
+            .audio_stream
+            .map(|instance| context.audio.get_sound_position(instance).is_some())
+            .unwrap_or(false);
+        if !is_playing || data.audio_format.as_ref() != Some(&format) {
+            if let Some(instance) = data.audio_stream.take() {
+                context.stop_sound(instance);
+            }
+
+            let substream = Substream::new();
+            if let AudioFormat::Aac { .. } = format {
+                let Some(config) = &data.aac_config else {
+                    tracing::warn!("NetStream: AAC frame received before sequence header");
+                    return;
+                };
+                substream.append(config);
+            }
+            substream.append(audio_data);
+            data.audio_stream = context.start_substream(substream.clone(), &format);
+            data.audio_substream = Some(substream);
+            data.audio_format = Some(format);
+        } else if let Some(substream) = &data.audio_substream {
+            substream.append(audio_data);
+        }
+    }
+
+    /// Decode an FLV video tag, returning the decoded frame.
+    fn decode_video(
+        context: &mut UpdateContext<'_, 'gc>,
+        data: &mut NetStreamData<'gc>,
+        video: FlvVideoData<'_>,
+    ) -> Option<BitmapInfo> {
+        let Some(NetStreamType::Flv {
+            video_stream,
+            frame_id,
+            ..
+        }) = &mut data.stream_type
+        else {
+            return None;
+        };
+
+        let FlvVideoPacket::Data(mut frame_data) = video.data else {
+            // AVC packets and command frames are not supported.
+            return None;
+        };
+
+        let Some(codec) = VideoCodec::from_u8(video.codec_id as u8) else {
+            tracing::warn!("NetStream: Unsupported video codec {:?}", video.codec_id);
+            return None;
+        };
+
+        // FLV prepends a size adjustment byte to VP6 frames, which the SWF
+        // encapsulation does not have. Screen video frames in SWF, on the
+        // other hand, start with the same `FrameType`/`CodecID` byte that FLV
+        // splits off into the tag header.
+        let screen_frame;
+        match codec {
+            VideoCodec::Vp6 | VideoCodec::Vp6WithAlpha => {
+                frame_data = frame_data.get(1..)?;
+            }
+            VideoCodec::ScreenVideo | VideoCodec::ScreenVideoV2 => {
+                let format = ((video.frame_type as u8) << 4) | video.codec_id as u8;
+                screen_frame = [&[format], frame_data].concat();
+                frame_data = screen_frame.as_slice();
+            }
+            VideoCodec::H263 => {}
+        }
+
+        let stream = match video_stream {
+            Some(stream) => *stream,
+            None => {
+                match context.video.register_video_stream(
+                    0,
+                    (0, 0),
+                    codec,
+                    VideoDeblocking::UseVideoPacketValue,
+                ) {
+                    Ok(stream) => *video_stream.insert(stream),
+                    Err(e) => {
+                        tracing::error!("NetStream: Could not register video stream: {}", e);
+                        return None;
+                    }
+                }
+            }
+        };
+
+        let encoded_frame = EncodedFrame {
+            codec,
+            data: frame_data,
+            frame_id: *frame_id,
+        };
+        *frame_id += 1;
+
+        if let Err(e) = context
+            .video
+            .preload_video_stream_frame(stream, encoded_frame)
+        {
+            tracing::error!("NetStream: Could not preload video frame: {}", e);
+            return None;
+        }
+
+        let encoded_frame = EncodedFrame {
+            codec,
+            data: frame_data,
+            frame_id: *frame_id - 1,
+        };
+        match context
+            .video
+            .decode_video_stream_frame(stream, encoded_frame, context.renderer)
+        {
+            Ok(bitmap) => Some(bitmap),
+            Err(e) => {
+                tracing::error!("NetStream: Could not decode video frame: {}", e);
+                None
+            }
+        }
+    }
+
+    /// Run the callbacks (such as `onMetaData`) for an FLV script data tag.
+    fn run_script_data(self, context: &mut UpdateContext<'_, 'gc>, script_data: FlvScriptData<'_>) {
+        let Some(avm_object) = self.0.read().avm_object else {
+            return;
+        };
+
+        for variable in script_data.0 {
+            let name = AvmString::new_utf8_bytes(context.gc_context, variable.name);
+
+            match avm_object {
+                AvmObject::Avm1(object) => {
+                    let mut activation = Avm1Activation::from_stub(
+                        context.reborrow(),
+                        Avm1ActivationIdentifier::root("[NetStream]"),
+                    );
+                    let value = flv_to_avm1_value(&mut activation, &variable.data);
+                    if let Err(e) = object.call_method(
+                        name,
+                        &[value],
+                        &mut activation,
+                        Avm1ExecutionReason::Special,
+                    ) {
+                        tracing::error!("Error running NetStream.{}: {:?}", name, e);
+                    }
+                }
+                AvmObject::Avm2(object) => {
+                    let mut activation = Avm2Activation::from_nothing(context.reborrow());
+                    let result =
+                        flv_to_avm2_value(&mut activation, &variable.data).and_then(|value| {
+                            let client = object
+                                .get_public_property("client", &mut activation)?
+                                .as_object()
+                                .unwrap_or(object);
+                            if client.has_public_property(name, &mut activation) {
+                                client.call_public_property(name, &[value], &mut activation)?;
+                            }
+                            Ok(())
+                        });
+                    if let Err(e) = result {
+                        tracing::error!("Error running NetStream client {}: {:?}", name, e);
+                    }
+                }
+            }
+        }
     }
+
+    /// Notify the AVM side of this stream of a status change.
+    ///
+    /// This calls `onStatus` in AVM1 and dispatches a `netStatus` event in
+    /// AVM2.
+    pub fn trigger_status_event(
+        self,
+        context: &mut UpdateContext<'_, 'gc>,
+        code: &'static str,
+        level: &'static str,
+    ) {
+        if let Some(avm_object) = self.0.read().avm_object {
+            trigger_net_status_event(context, avm_object, code, level);
+        }
+    }
+}
+
+/// Notify the AVM side of a `NetStream` or `NetConnection` of a status change.
+///
+/// This calls `onStatus` in AVM1 and dispatches a `netStatus` event in AVM2.
+pub fn trigger_net_status_event<'gc>(
+    context: &mut UpdateContext<'_, 'gc>,
+    object: AvmObject<'gc>,
+    code: &'static str,
+    level: &'static str,
+) {
+    match object {
+        AvmObject::Avm1(object) => {
+            let mut activation = Avm1Activation::from_stub(
+                context.reborrow(),
+                Avm1ActivationIdentifier::root("[NetStatus]"),
+            );
+            let info: Avm1Object<'gc> = Avm1ScriptObject::new(
+                activation.context.gc_context,
+                Some(activation.context.avm1.prototypes().object),
+            )
+            .into();
+            let _ = info.set("code", code.into(), &mut activation);
+            let _ = info.set("level", level.into(), &mut activation);
+            if let Err(e) = object.call_method(
+                "onStatus".into(),
+                &[info.into()],
+                &mut activation,
+                Avm1ExecutionReason::Special,
+            ) {
+                tracing::error!("Error running onStatus: {:?}", e);
+            }
+        }
+        AvmObject::Avm2(object) => {
+            let mut activation = Avm2Activation::from_nothing(context.reborrow());
+            let result = (|| {
+                let mut info = activation
+                    .avm2()
+                    .classes()
+                    .object
+                    .construct(&mut activation, &[])?;
+                info.set_public_property("code", code.into(), &mut activation)?;
+                info.set_public_property("level", level.into(), &mut activation)?;
+
+                activation.avm2().classes().netstatusevent.construct(
+                    &mut activation,
+                    &["netStatus".into(), false.into(), false.into(), info.into()],
+                )
+            })();
+
+            match result {
+                Ok(event) => Avm2::dispatch_event(&mut activation.context, event, object),
+                Err(e) => tracing::error!("Error creating NetStatusEvent: {:?}", e),
+            }
+        }
+    }
+}
+
+/// Convert an FLV script data value into an AVM1 value.
+fn flv_to_avm1_value<'gc>(
+    activation: &mut Avm1Activation<'_, 'gc>,
+    value: &FlvValue<'_>,
+) -> Avm1Value<'gc> {
+    match value {
+        FlvValue::Number(n) => (*n).into(),
+        FlvValue::Boolean(b) => (*b).into(),
+        FlvValue::String(s) | FlvValue::LongString(s) | FlvValue::MovieClip(s) => {
+            AvmString::new_utf8_bytes(activation.context.gc_context, s).into()
+        }
+        FlvValue::Object(vars) | FlvValue::EcmaArray(vars) => {
+            let object: Avm1Object<'gc> = Avm1ScriptObject::new(
+                activation.context.gc_context,
+                Some(activation.context.avm1.prototypes().object),
+            )
+            .into();
+            for variable in vars {
+                let name = AvmString::new_utf8_bytes(activation.context.gc_context, variable.name);
+                let value = flv_to_avm1_value(activation, &variable.data);
+                let _ = object.set(name, value, activation);
+            }
+            object.into()
+        }
+        FlvValue::StrictArray(values) => {
+            let values: Vec<_> = values
+                .iter()
+                .map(|value| flv_to_avm1_value(activation, value))
+                .collect();
+            Avm1ArrayObject::new(
+                activation.context.gc_context,
+                activation.context.avm1.prototypes().array,
+                values,
+            )
+            .into()
+        }
+        FlvValue::Date { unix_time, .. } => (*unix_time).into(),
+        FlvValue::Null => Avm1Value::Null,
+        FlvValue::Undefined | FlvValue::Reference(_) => Avm1Value::Undefined,
+    }
+}
+
+/// Convert an FLV script data value into an AVM2 value.
+fn flv_to_avm2_value<'gc>(
+    activation: &mut Avm2Activation<'_, 'gc>,
+    value: &FlvValue<'_>,
+) -> Result<Avm2Value<'gc>, crate::avm2::Error<'gc>> {
+    Ok(match value {
+        FlvValue::Number(n) => (*n).into(),
+        FlvValue::Boolean(b) => (*b).into(),
+        FlvValue::String(s) | FlvValue::LongString(s) | FlvValue::MovieClip(s) => {
+            AvmString::new_utf8_bytes(activation.context.gc_context, s).into()
+        }
+        FlvValue::Object(vars) | FlvValue::EcmaArray(vars) => {
+            let mut object: Avm2Object<'gc> = activation
+                .avm2()
+                .classes()
+                .object
+                .construct(activation, &[])?;
+            for variable in vars {
+                let name = AvmString::new_utf8_bytes(activation.context.gc_context, variable.name);
+                let value = flv_to_avm2_value(activation, &variable.data)?;
+                object.set_public_property(name, value, activation)?;
+            }
+            object.into()
+        }
+        FlvValue::StrictArray(values) => {
+            let values = values
+                .iter()
+                .map(|value| flv_to_avm2_value(activation, value))
+                .collect::<Result<Vec<_>, _>>()?;
+            Avm2ArrayObject::from_storage(activation, Avm2ArrayStorage::from_args(&values))?.into()
+        }
+        FlvValue::Date { unix_time, .. } => (*unix_time).into(),
+        FlvValue::Null => Avm2Value::Null,
+        FlvValue::Undefined | FlvValue::Reference(_) => Avm2Value::Undefined,
+    })
 }
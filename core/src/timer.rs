@@ -9,7 +9,9 @@ use crate::avm1::{
     Activation, ActivationIdentifier, Object as Avm1Object, TObject as _, Value as Avm1Value,
 };
 use crate::avm2::object::TObject;
-use crate::avm2::{Activation as Avm2Activation, Object as Avm2Object, Value as Avm2Value};
+use crate::avm2::{
+    Activation as Avm2Activation, Domain as Avm2Domain, Object as Avm2Object, Value as Avm2Value,
+};
 use crate::context::UpdateContext;
 use crate::display_object::{DisplayObject, TDisplayObject};
 use crate::string::AvmString;
@@ -149,7 +151,9 @@ impl<'gc> Timers<'gc> {
                         true
                     }
                 }
-                TimerCallback::Avm2Callback { closure, params } => {
+                TimerCallback::Avm2Callback {
+                    closure, params, ..
+                } => {
                     let domain = context.avm2.global_domain();
                     let mut avm2_activation =
                         Avm2Activation::from_domain(context.reborrow(), domain);
@@ -254,6 +258,22 @@ impl<'gc> Timers<'gc> {
         }
     }
 
+    /// Removes all AVM2 timers created by code in the given domain, as done
+    /// by `Loader.unloadAndStop`.
+    pub fn remove_avm2_timers_in_domain(&mut self, domain: Avm2Domain<'gc>) {
+        for timer in &self.timers {
+            if let TimerCallback::Avm2Callback {
+                domain: Some(timer_domain),
+                ..
+            } = timer.callback
+            {
+                if timer_domain == domain {
+                    timer.is_alive.set(false);
+                }
+            }
+        }
+    }
+
     fn peek(&self) -> Option<&Timer<'gc>> {
         self.timers.peek()
     }
@@ -347,5 +367,7 @@ pub enum TimerCallback<'gc> {
     Avm2Callback {
         closure: Avm2Object<'gc>,
         params: Vec<Avm2Value<'gc>>,
+        /// The domain of the code that created this timer, if known.
+        domain: Option<Avm2Domain<'gc>>,
     },
 }
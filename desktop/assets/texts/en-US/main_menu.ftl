@@ -27,3 +27,7 @@ help-menu-about = About Ruffle
 
 debug-menu = Debug Tools
 debug-menu-open-stage = View Stage Info
+debug-menu-open-movie = View Movie
+debug-menu-open-movie-list = Show Known Movies
+debug-menu-open-audio = View Audio
+debug-menu-open-debugger = ActionScript Debugger
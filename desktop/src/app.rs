@@ -103,6 +103,7 @@ impl App {
                     if let Some(mut player) = self.player.get() {
                         player.flush_shared_objects();
                     }
+                    self.player.save_recording();
                     crate::shutdown();
                     return;
                 }
@@ -259,6 +260,14 @@ impl App {
                             self.player.handle_event(PlayerEvent::MouseLeave);
                             check_redraw = true;
                         }
+                        WindowEvent::Focused(focused) => {
+                            self.player.handle_event(if focused {
+                                PlayerEvent::FocusGained
+                            } else {
+                                PlayerEvent::FocusLost
+                            });
+                            check_redraw = true;
+                        }
                         WindowEvent::ModifiersChanged(new_modifiers) => {
                             modifiers = new_modifiers;
                         }
@@ -11,9 +11,13 @@ use ruffle_core::backend::navigator::{
 };
 use ruffle_core::indexmap::IndexMap;
 use ruffle_core::loader::Error;
+use ruffle_core::socket::{ConnectionState, SocketAction, SocketHandle};
+use std::io::{self, Read, Write};
+use std::net::{Shutdown, TcpStream, ToSocketAddrs};
 use std::rc::Rc;
 use std::str::FromStr;
-use std::sync::mpsc::Sender;
+use std::sync::mpsc::{Receiver, Sender};
+use std::time::Duration;
 use url::Url;
 use winit::event_loop::EventLoopProxy;
 
@@ -271,4 +275,84 @@ impl NavigatorBackend for ExternalNavigatorBackend {
         }
         url
     }
+
+    fn connect_socket(
+        &mut self,
+        host: String,
+        port: u16,
+        timeout: Duration,
+        handle: SocketHandle,
+        receiver: Receiver<Vec<u8>>,
+        sender: Sender<SocketAction>,
+    ) {
+        // Every connection gets its own threads, as the socket APIs are blocking.
+        std::thread::spawn(move || {
+            let stream = match connect_tcp(&host, port, timeout) {
+                Ok(stream) => stream,
+                Err(e) => {
+                    tracing::warn!("Couldn't connect a socket to {}:{}: {}", host, port, e);
+                    let state = if e.kind() == io::ErrorKind::TimedOut {
+                        ConnectionState::TimedOut
+                    } else {
+                        ConnectionState::Failed
+                    };
+                    let _ = sender.send(SocketAction::Connect(handle, state));
+                    return;
+                }
+            };
+
+            let mut read_stream = match stream.try_clone() {
+                Ok(read_stream) => read_stream,
+                Err(e) => {
+                    tracing::warn!("Couldn't connect a socket to {}:{}: {}", host, port, e);
+                    let _ = sender.send(SocketAction::Connect(handle, ConnectionState::Failed));
+                    return;
+                }
+            };
+            let _ = sender.send(SocketAction::Connect(handle, ConnectionState::Connected));
+
+            std::thread::spawn(move || {
+                let mut buffer = [0; 4096];
+                loop {
+                    match read_stream.read(&mut buffer) {
+                        Ok(0) => break,
+                        Ok(read) => {
+                            let data = buffer[..read].to_vec();
+                            if sender.send(SocketAction::Data(handle, data)).is_err() {
+                                // The player is gone.
+                                return;
+                            }
+                        }
+                        Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                        Err(_) => break,
+                    }
+                }
+                let _ = sender.send(SocketAction::Close(handle));
+            });
+
+            // This ends once the movie closes the socket, which also stops the reading
+            // thread above.
+            let mut stream = stream;
+            for data in receiver {
+                if let Err(e) = stream.write_all(&data) {
+                    tracing::warn!("Couldn't write to socket {}:{}: {}", host, port, e);
+                    break;
+                }
+            }
+            let _ = stream.shutdown(Shutdown::Both);
+        });
+    }
+}
+
+/// Connects to the first address of the host that accepts the connection.
+fn connect_tcp(host: &str, port: u16, timeout: Duration) -> io::Result<TcpStream> {
+    let mut last_error = None;
+    for address in (host, port).to_socket_addrs()? {
+        match TcpStream::connect_timeout(&address, timeout) {
+            Ok(stream) => return Ok(stream),
+            Err(e) => last_error = Some(e),
+        }
+    }
+    Err(last_error
+        .unwrap_or_else(|| io::Error::new(io::ErrorKind::NotFound, "Host has no addresses")))
 }
@@ -1,10 +1,13 @@
 use crate::custom_event::RuffleEvent;
 use anyhow::{Context, Error};
 use arboard::Clipboard;
-use rfd::{MessageButtons, MessageDialog, MessageLevel};
+use rfd::{AsyncFileDialog, FileHandle, MessageButtons, MessageDialog, MessageLevel};
 use ruffle_core::backend::ui::{
-    FullscreenError, LanguageIdentifier, MouseCursor, UiBackend, US_ENGLISH,
+    CustomMouseCursor, FileDialogFuture, FileFilter, FullscreenError, LanguageIdentifier,
+    LoadedFile, MouseCursor, SaveFile, UiBackend, US_ENGLISH,
 };
+use std::io;
+use std::path::PathBuf;
 use std::rc::Rc;
 use sys_locale::get_locale;
 use tracing::error;
@@ -43,6 +46,8 @@ impl DesktopUiBackend {
                 MouseCursor::Hand => egui::CursorIcon::PointingHand,
                 MouseCursor::IBeam => egui::CursorIcon::Text,
                 MouseCursor::Grab => egui::CursorIcon::Grab,
+                // Custom cursors are never registered, see `supports_custom_mouse_cursor`.
+                MouseCursor::Custom(_) => egui::CursorIcon::Default,
             }
         } else {
             egui::CursorIcon::None
@@ -50,6 +55,41 @@ impl DesktopUiBackend {
     }
 }
 
+/// Read a file picked in a file-open dialog into memory.
+async fn load_file(handle: FileHandle) -> LoadedFile {
+    let metadata = std::fs::metadata(handle.path()).ok();
+    LoadedFile {
+        name: handle.file_name(),
+        contents: handle.read().await,
+        creation_date: metadata
+            .as_ref()
+            .and_then(|m| m.created().ok())
+            .map(Into::into),
+        modification_date: metadata
+            .as_ref()
+            .and_then(|m| m.modified().ok())
+            .map(Into::into),
+    }
+}
+
+/// A file picked in a file-save dialog.
+struct DesktopSaveFile {
+    path: PathBuf,
+}
+
+impl SaveFile for DesktopSaveFile {
+    fn name(&self) -> String {
+        self.path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default()
+    }
+
+    fn write(&mut self, data: &[u8]) -> io::Result<()> {
+        std::fs::write(&self.path, data)
+    }
+}
+
 const DOWNLOAD_FAILED_MESSAGE: &str = "Ruffle failed to open or download this file.";
 
 impl UiBackend for DesktopUiBackend {
@@ -65,6 +105,15 @@ impl UiBackend for DesktopUiBackend {
         self.preferred_cursor = cursor;
     }
 
+    fn supports_custom_mouse_cursor(&self) -> bool {
+        // winit can only show the system cursors.
+        false
+    }
+
+    fn register_mouse_cursor(&mut self, _id: u32, _cursor: CustomMouseCursor) {}
+
+    fn unregister_mouse_cursor(&mut self, _id: u32) {}
+
     fn clipboard_content(&mut self) -> String {
         self.clipboard.get_text().unwrap_or_default()
     }
@@ -114,4 +163,46 @@ impl UiBackend for DesktopUiBackend {
     fn language(&self) -> &LanguageIdentifier {
         &self.language
     }
+
+    fn display_file_open_dialog(
+        &mut self,
+        filters: Vec<FileFilter>,
+        multiple: bool,
+    ) -> Option<FileDialogFuture<Vec<LoadedFile>>> {
+        let mut dialog = AsyncFileDialog::new();
+        for filter in &filters {
+            let extensions: Vec<&str> = filter.extension_list().collect();
+            if !extensions.is_empty() {
+                dialog = dialog.add_filter(&filter.description, &extensions);
+            }
+        }
+
+        Some(Box::pin(async move {
+            let handles = if multiple {
+                dialog.pick_files().await?
+            } else {
+                vec![dialog.pick_file().await?]
+            };
+
+            let mut files = Vec::with_capacity(handles.len());
+            for handle in handles {
+                files.push(load_file(handle).await);
+            }
+            Some(files)
+        }))
+    }
+
+    fn display_file_save_dialog(
+        &mut self,
+        file_name: String,
+    ) -> Option<FileDialogFuture<Box<dyn SaveFile>>> {
+        let dialog = AsyncFileDialog::new().set_file_name(&file_name);
+        Some(Box::pin(async move {
+            let handle = dialog.save_file().await?;
+            let file: Box<dyn SaveFile> = Box::new(DesktopSaveFile {
+                path: handle.path().to_owned(),
+            });
+            Some(file)
+        }))
+    }
 }
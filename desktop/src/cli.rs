@@ -3,10 +3,11 @@ use anyhow::Error;
 use clap::Parser;
 use ruffle_core::backend::navigator::OpenURLMode;
 use ruffle_core::config::Letterbox;
+use ruffle_core::debugger::Breakpoint;
 use ruffle_core::{LoadBehavior, StageAlign, StageScaleMode};
 use ruffle_render::quality::StageQuality;
 use ruffle_render_wgpu::clap::{GraphicsBackend, PowerPreference};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use url::Url;
 
 #[derive(Parser, Debug)]
@@ -128,6 +129,17 @@ pub struct Opt {
     /// but may fix others that always require an External Interface.
     #[clap(long)]
     pub dummy_external_interface: bool,
+
+    /// Record all input to the given file, in the same format as the `input.json` files used by tests.
+    /// The recording can be replayed to reproduce a session frame by frame.
+    #[clap(long)]
+    pub record_input: Option<PathBuf>,
+
+    /// Pause ActionScript at a breakpoint, and debug it in the debugger window.
+    /// This is a function (`Main/init`), a class (`class:Main`) or a frame script
+    /// (`frame:2`, `frame:_level0.menu:2`), and can be repeated multiple times.
+    #[clap(long = "break", action = clap::ArgAction::Append)]
+    pub breakpoints: Vec<Breakpoint>,
 }
 
 fn parse_movie_file_or_url(path: &str) -> Result<Url, Error> {
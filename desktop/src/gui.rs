@@ -239,10 +239,34 @@ impl RuffleGui {
                     ui.add_enabled_ui(player.is_some(), |ui| {
                         if Button::new(text(&self.locale, "debug-menu-open-stage")).ui(ui).clicked() {
                             ui.close_menu();
-                            if let Some(player) = player {
+                            if let Some(player) = &mut player {
                                 player.debug_ui_message(DebugMessage::TrackStage);
                             }
                         }
+                        if Button::new(text(&self.locale, "debug-menu-open-movie")).ui(ui).clicked() {
+                            ui.close_menu();
+                            if let Some(player) = &mut player {
+                                player.debug_ui_message(DebugMessage::TrackTopLevelMovie);
+                            }
+                        }
+                        if Button::new(text(&self.locale, "debug-menu-open-movie-list")).ui(ui).clicked() {
+                            ui.close_menu();
+                            if let Some(player) = &mut player {
+                                player.debug_ui_message(DebugMessage::ShowKnownMovies);
+                            }
+                        }
+                        if Button::new(text(&self.locale, "debug-menu-open-audio")).ui(ui).clicked() {
+                            ui.close_menu();
+                            if let Some(player) = &mut player {
+                                player.debug_ui_message(DebugMessage::ShowAudio);
+                            }
+                        }
+                        if Button::new(text(&self.locale, "debug-menu-open-debugger")).ui(ui).clicked() {
+                            ui.close_menu();
+                            if let Some(player) = &mut player {
+                                player.debug_ui_message(DebugMessage::ShowDebugger);
+                            }
+                        }
                     });
                 });
                 menu::menu_button(ui, text(&self.locale, "help-menu"), |ui| {
@@ -13,6 +13,7 @@ mod custom_event;
 mod executor;
 mod gui;
 mod player;
+mod recorder;
 mod task;
 mod time_demo;
 mod util;
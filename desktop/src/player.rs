@@ -6,16 +6,20 @@ use crate::cli::Opt;
 use crate::custom_event::RuffleEvent;
 use crate::executor::GlutinAsyncExecutor;
 use crate::gui::MovieView;
+use crate::recorder::InputRecorder;
 use crate::{CALLSTACK, RENDER_INFO, SWF_INFO};
 use anyhow::anyhow;
 use ruffle_core::backend::audio::AudioBackend;
 use ruffle_core::backend::navigator::OpenURLMode;
 use ruffle_core::config::Letterbox;
+use ruffle_core::debug_ui::Message as DebugMessage;
+use ruffle_core::debugger::Breakpoint;
 use ruffle_core::{LoadBehavior, Player, PlayerBuilder, PlayerEvent, StageAlign, StageScaleMode};
 use ruffle_render::backend::RenderBackend;
 use ruffle_render::quality::StageQuality;
 use ruffle_render_wgpu::backend::WgpuRenderBackend;
 use ruffle_render_wgpu::descriptors::Descriptors;
+use std::path::PathBuf;
 use std::rc::Rc;
 use std::sync::{Arc, Mutex, MutexGuard};
 use std::time::Duration;
@@ -47,6 +51,8 @@ pub struct PlayerOptions {
     pub frame_rate: Option<f64>,
     pub open_url_mode: OpenURLMode,
     pub dummy_external_interface: bool,
+    pub record_input: Option<PathBuf>,
+    pub breakpoints: Vec<Breakpoint>,
 }
 
 impl From<&Opt> for PlayerOptions {
@@ -72,6 +78,8 @@ impl From<&Opt> for PlayerOptions {
             frame_rate: value.frame_rate,
             open_url_mode: value.open_url_mode,
             dummy_external_interface: value.dummy_external_interface,
+            record_input: value.record_input.clone(),
+            breakpoints: value.breakpoints.clone(),
         }
     }
 }
@@ -81,6 +89,7 @@ impl From<&Opt> for PlayerOptions {
 struct ActivePlayer {
     player: Arc<Mutex<Player>>,
     executor: Arc<Mutex<GlutinAsyncExecutor>>,
+    recorder: Option<InputRecorder>,
 }
 
 impl ActivePlayer {
@@ -173,10 +182,37 @@ impl ActivePlayer {
             CALLSTACK.with(|callstack| {
                 *callstack.borrow_mut() = Some(player_lock.callstack());
             });
+            if !opt.breakpoints.is_empty() {
+                let debugger = player_lock.debugger_mut();
+                for breakpoint in &opt.breakpoints {
+                    debugger.add_breakpoint(breakpoint.clone());
+                }
+                debugger.attach();
+                player_lock.debug_ui_message(DebugMessage::ShowDebugger);
+            }
             player_lock.fetch_root_movie(movie_url.to_string(), parameters, Box::new(on_metadata));
         }
 
-        Self { player, executor }
+        Self {
+            player,
+            executor,
+            recorder: opt.record_input.clone().map(InputRecorder::new),
+        }
+    }
+
+    /// Writes out the input recorded so far, if recording is enabled.
+    fn save_recording(&mut self) {
+        if let (Some(recorder), Ok(player)) = (&mut self.recorder, self.player.lock()) {
+            if let Err(e) = recorder.save(&player) {
+                tracing::error!("Couldn't save input recording: {e:#}");
+            }
+        }
+    }
+}
+
+impl Drop for ActivePlayer {
+    fn drop(&mut self) {
+        self.save_recording();
     }
 }
 
@@ -231,14 +267,29 @@ impl PlayerController {
         }
     }
 
-    pub fn handle_event(&self, event: PlayerEvent) {
-        if let Some(mut player) = self.get() {
+    pub fn handle_event(&mut self, event: PlayerEvent) {
+        if let Some(active) = &mut self.player {
+            let mut player = active
+                .player
+                .try_lock()
+                .expect("Player lock must be available");
             if player.is_playing() {
+                if let Some(recorder) = &mut active.recorder {
+                    recorder.record(&mut player, &event);
+                }
                 player.handle_event(event);
             }
         }
     }
 
+    /// Writes out the input recorded so far, if recording is enabled.
+    /// This is also done automatically when the player is destroyed.
+    pub fn save_recording(&mut self) {
+        if let Some(player) = &mut self.player {
+            player.save_recording();
+        }
+    }
+
     pub fn poll(&self) {
         if let Some(player) = &self.player {
             player
@@ -0,0 +1,160 @@
+//! Recording of user input, for later playback through `ruffle_input_format`.
+
+use anyhow::{Context, Error};
+use ruffle_core::events::TextControlCode;
+use ruffle_core::{Player, PlayerEvent};
+use ruffle_input_format::{AutomatedEvent, MousePosition};
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::PathBuf;
+
+/// Records every event handled by a player, frame by frame.
+///
+/// The resulting file can be replayed verbatim by an `InputInjector`, such as
+/// the one used by the regression tests.
+pub struct InputRecorder {
+    path: PathBuf,
+    events: Vec<AutomatedEvent>,
+
+    /// The number of frames that the recorded `Wait`s account for.
+    ///
+    /// Input files are played back after each frame has run, so the events
+    /// of the first frame are injected without waiting at all.
+    frames_run: u64,
+}
+
+impl InputRecorder {
+    pub fn new(path: PathBuf) -> Self {
+        Self {
+            path,
+            events: Vec::new(),
+            frames_run: 1,
+        }
+    }
+
+    /// Records an event that is about to be handled by the given player.
+    pub fn record(&mut self, player: &mut Player, event: &PlayerEvent) {
+        self.sync_frames(player);
+
+        let event = match *event {
+            PlayerEvent::MouseMove { x, y } => AutomatedEvent::MouseMove {
+                pos: stage_position(player, x, y),
+            },
+            PlayerEvent::MouseDown { x, y, button } => {
+                let Ok(btn) = button.try_into() else {
+                    return;
+                };
+                AutomatedEvent::MouseDown {
+                    pos: stage_position(player, x, y),
+                    btn,
+                }
+            }
+            PlayerEvent::MouseUp { x, y, button } => {
+                let Ok(btn) = button.try_into() else {
+                    return;
+                };
+                AutomatedEvent::MouseUp {
+                    pos: stage_position(player, x, y),
+                    btn,
+                }
+            }
+            PlayerEvent::MouseWheel { delta } => AutomatedEvent::MouseWheel {
+                delta: delta.into(),
+            },
+            PlayerEvent::MouseLeave => AutomatedEvent::MouseLeave,
+            PlayerEvent::KeyDown { key_code, .. } => AutomatedEvent::KeyDown {
+                key_code: key_code as u8,
+            },
+            PlayerEvent::KeyUp { key_code, .. } => AutomatedEvent::KeyUp {
+                key_code: key_code as u8,
+            },
+            PlayerEvent::TextInput { codepoint } => AutomatedEvent::TextInput { codepoint },
+            PlayerEvent::TextControl { code } => {
+                if code == TextControlCode::Paste {
+                    // The pasted text comes from the system clipboard, so it has to be recorded too.
+                    let text = player.ui_mut().clipboard_content();
+                    self.events.push(AutomatedEvent::SetClipboardText { text });
+                }
+                AutomatedEvent::TextControl { code: code.into() }
+            }
+            PlayerEvent::FocusGained => AutomatedEvent::FocusGained,
+            PlayerEvent::FocusLost => AutomatedEvent::FocusLost,
+        };
+        self.events.push(event);
+    }
+
+    /// Records a `Wait` for every frame that the player has run since the last
+    /// call, not counting the first frame.
+    fn sync_frames(&mut self, player: &Player) {
+        let frames_run = player.frames_run();
+        while self.frames_run < frames_run {
+            self.events.push(AutomatedEvent::Wait);
+            self.frames_run += 1;
+        }
+    }
+
+    /// Writes the recorded events to disk, overwriting any previous recording.
+    pub fn save(&mut self, player: &Player) -> Result<(), Error> {
+        self.sync_frames(player);
+        let file = File::create(&self.path)
+            .with_context(|| format!("Couldn't create {}", self.path.display()))?;
+        serde_json::to_writer_pretty(BufWriter::new(file), &self.events)
+            .with_context(|| format!("Couldn't write {}", self.path.display()))?;
+        tracing::info!(
+            "Saved {} recorded input events to {}",
+            self.events.len(),
+            self.path.display()
+        );
+        Ok(())
+    }
+}
+
+/// Converts a position in the window to a position on the stage, which is what input files use.
+fn stage_position(player: &Player, x: f64, y: f64) -> MousePosition {
+    let position = player.viewport_to_stage_position(x, y);
+    MousePosition(position.x.to_pixels(), position.y.to_pixels())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ruffle_core::events::KeyCode;
+    use ruffle_core::PlayerBuilder;
+    use ruffle_input_format::InputInjector;
+
+    #[test]
+    fn replays_events_on_recorded_frame() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("recording.json");
+        let player = PlayerBuilder::new().build();
+        let mut player = player.lock().unwrap();
+        let mut recorder = InputRecorder::new(path.clone());
+
+        // Events are recorded in between frames, just like the regression
+        // tests inject them.
+        let mut recorded = vec![];
+        for frame in 1..=4 {
+            player.run_frame();
+            if frame % 2 == 1 {
+                let event = PlayerEvent::KeyDown {
+                    key_code: KeyCode::A,
+                    key_char: Some('a'),
+                };
+                recorder.record(&mut player, &event);
+                recorded.push(frame);
+            }
+        }
+        recorder.save(&player).unwrap();
+
+        let mut injector = InputInjector::from_file(&path).unwrap();
+        let mut replayed = vec![];
+        for frame in 1..=4 {
+            injector.next(|event, _buttons| {
+                assert!(matches!(event, AutomatedEvent::KeyDown { key_code: 65 }));
+                replayed.push(frame);
+            });
+        }
+
+        assert_eq!(replayed, recorded);
+    }
+}
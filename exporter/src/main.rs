@@ -1,18 +1,27 @@
+mod ui;
+mod video;
+
+use crate::ui::ExporterUiBackend;
 use anyhow::{anyhow, Result};
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use image::RgbaImage;
 use indicatif::{ProgressBar, ProgressStyle};
 use rayon::prelude::*;
+use ruffle_core::backend::audio::{write_wav, OfflineAudioBackend};
+use ruffle_core::events::KeyCode;
 use ruffle_core::limits::ExecutionLimit;
+use ruffle_core::swf::{Point, Twips};
 use ruffle_core::tag_utils::SwfMovie;
-use ruffle_core::PlayerBuilder;
+use ruffle_core::{Player, PlayerBuilder, PlayerEvent};
+use ruffle_input_format::{AutomatedEvent, InputInjector};
+use ruffle_render_software::SoftwareRenderBackend;
 use ruffle_render_wgpu::backend::{request_adapter_and_device, WgpuRenderBackend};
 use ruffle_render_wgpu::clap::{GraphicsBackend, PowerPreference};
 use ruffle_render_wgpu::descriptors::Descriptors;
 use ruffle_render_wgpu::target::TextureTarget;
 use ruffle_render_wgpu::wgpu;
-use std::fs::create_dir_all;
-use std::io::{self, Write};
+use std::fs::{create_dir_all, File};
+use std::io::{self, BufWriter, Write};
 use std::panic::catch_unwind;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -33,6 +42,94 @@ struct SizeOpt {
     height: Option<u32>,
 }
 
+#[derive(ValueEnum, Debug, Copy, Clone, PartialEq, Eq)]
+enum OutputFormat {
+    /// One PNG image per frame
+    Png,
+    /// A single animated PNG
+    Apng,
+    /// A single animated GIF
+    Gif,
+    /// An uncompressed AVI video, including the audio of the movie
+    Avi,
+}
+
+/// An output format that stores all captured frames in a single file.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum AnimatedFormat {
+    Apng,
+    Gif,
+    Avi,
+}
+
+impl OutputFormat {
+    /// The format of the single file that all captured frames are stored in,
+    /// or `None` if every frame is stored in its own file.
+    fn animated(self) -> Option<AnimatedFormat> {
+        match self {
+            OutputFormat::Png => None,
+            OutputFormat::Apng => Some(AnimatedFormat::Apng),
+            OutputFormat::Gif => Some(AnimatedFormat::Gif),
+            OutputFormat::Avi => Some(AnimatedFormat::Avi),
+        }
+    }
+
+    /// Whether all captured frames are stored in a single file.
+    fn is_animated(self) -> bool {
+        self.animated().is_some()
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            OutputFormat::Png | OutputFormat::Apng => "png",
+            OutputFormat::Gif => "gif",
+            OutputFormat::Avi => "avi",
+        }
+    }
+}
+
+/// The frames (and audio) captured from a movie.
+struct Capture {
+    frames: Vec<RgbaImage>,
+    frame_rate: f64,
+
+    /// Interleaved stereo samples, if audio was captured.
+    audio: Option<Vec<i16>>,
+}
+
+impl Capture {
+    /// Writes the captured audio as a WAV file.
+    fn save_wav(&self, path: &Path) -> Result<()> {
+        let samples = self.audio.as_deref().unwrap_or_default();
+        write_wav(
+            BufWriter::new(File::create(path)?),
+            samples,
+            OfflineAudioBackend::NUM_CHANNELS.into(),
+            OfflineAudioBackend::SAMPLE_RATE,
+        )?;
+        Ok(())
+    }
+
+    /// Writes all frames to a single animated file.
+    fn save_animated(&self, path: &Path, format: AnimatedFormat) -> Result<()> {
+        let file = BufWriter::new(File::create(path)?);
+        match format {
+            AnimatedFormat::Apng => video::write_apng(file, &self.frames, self.frame_rate),
+            AnimatedFormat::Gif => video::write_gif(file, &self.frames, self.frame_rate),
+            AnimatedFormat::Avi => video::write_avi(
+                file,
+                &self.frames,
+                self.frame_rate,
+                self.audio.as_deref().map(|samples| video::Audio {
+                    samples,
+                    num_channels: OfflineAudioBackend::NUM_CHANNELS.into(),
+                    sample_rate: OfflineAudioBackend::SAMPLE_RATE,
+                }),
+            ),
+        }
+    }
+}
+
 #[derive(Parser, Debug)]
 #[clap(name = "Ruffle Exporter", author, version)]
 struct Opt {
@@ -42,7 +139,7 @@ struct Opt {
 
     /// The file or directory (if multiple frames/files) to store the capture in.
     /// The default value will either be:
-    /// - If given one swf and one frame, the name of the swf + ".png"
+    /// - If given one swf and one frame, or an animated format, the name of the swf + the format's extension
     /// - If given one swf and multiple frames, the name of the swf as a directory
     /// - If given multiple swfs, this field is required.
     #[clap(name = "output")]
@@ -63,6 +160,10 @@ struct Opt {
     #[clap(flatten)]
     size: SizeOpt,
 
+    /// The format to store captured frames in
+    #[clap(long, value_enum, default_value = "png")]
+    format: OutputFormat,
+
     /// Type of graphics backend to use. Not all options may be supported by your current system.
     /// Default will attempt to pick the most supported graphics backend.
     #[clap(long, short, default_value = "default")]
@@ -73,6 +174,11 @@ struct Opt {
     #[clap(long, short, default_value = "high")]
     power: PowerPreference,
 
+    /// Render on the CPU with the software renderer instead of a graphics device.
+    /// This is much slower, but works on machines without a GPU.
+    #[clap(long, action)]
+    software: bool,
+
     /// Location to store a wgpu trace output
     #[clap(long)]
     #[cfg(feature = "render_trace")]
@@ -81,18 +187,42 @@ struct Opt {
     /// Skip unsupported movie types (currently AVM 2)
     #[clap(long, action)]
     skip_unsupported: bool,
+
+    /// An input file (in the same format as the `input.json` files used by tests) to play back
+    /// while capturing. Skipped frames still receive their input.
+    #[clap(long, short = 'i')]
+    input: Option<PathBuf>,
+
+    /// Also save the audio of the captured frames as a WAV file, next to the captured frames
+    #[clap(long, action)]
+    audio: bool,
+}
+
+impl Opt {
+    fn capture_audio(&self) -> bool {
+        self.audio || self.format == OutputFormat::Avi
+    }
 }
 
 /// Captures a screenshot. The resulting image uses straight alpha
+#[allow(clippy::too_many_arguments)]
 fn take_screenshot(
-    descriptors: Arc<Descriptors>,
+    descriptors: Option<Arc<Descriptors>>,
     swf_path: &Path,
     frames: u32,
     skipframes: u32,
     progress: &Option<ProgressBar>,
     size: SizeOpt,
     skip_unsupported: bool,
-) -> Result<Vec<RgbaImage>> {
+    input: Option<&Path>,
+    capture_audio: bool,
+) -> Result<Capture> {
+    let mut injector = match input {
+        Some(input) => InputInjector::from_file(input)
+            .map_err(|e| anyhow!("Couldn't read input file {:?}: {}", input, e))?,
+        None => InputInjector::empty(),
+    };
+
     let movie = SwfMovie::from_path(swf_path, None).map_err(|e| anyhow!(e.to_string()))?;
 
     if movie.is_action_script_3() && skip_unsupported {
@@ -111,17 +241,26 @@ fn take_screenshot(
         .unwrap_or_else(|| movie.height().to_pixels());
     let height = (height * size.scale).round() as u32;
 
-    let target = TextureTarget::new(&descriptors.device, (width, height))
-        .map_err(|e| anyhow!(e.to_string()))?;
-    let player = PlayerBuilder::new()
-        .with_renderer(
+    let mut builder = if let Some(descriptors) = descriptors {
+        let target = TextureTarget::new(&descriptors.device, (width, height))
+            .map_err(|e| anyhow!(e.to_string()))?;
+        PlayerBuilder::new().with_renderer(
             WgpuRenderBackend::new(descriptors, target).map_err(|e| anyhow!(e.to_string()))?,
         )
+    } else {
+        PlayerBuilder::new().with_renderer(SoftwareRenderBackend::new(width, height))
+    };
+    builder = builder
         .with_movie(movie)
-        .with_viewport_dimensions(width, height, size.scale)
-        .build();
+        .with_ui(ExporterUiBackend::default())
+        .with_viewport_dimensions(width, height, size.scale);
+    if capture_audio {
+        builder = builder.with_audio(OfflineAudioBackend::new());
+    }
+    let player = builder.build();
 
     let mut result = Vec::new();
+    let mut audio = Vec::new();
     let totalframes = frames + skipframes;
 
     for i in 0..totalframes {
@@ -136,15 +275,23 @@ fn take_screenshot(
         player.lock().unwrap().preload(&mut ExecutionLimit::none());
 
         player.lock().unwrap().run_frame();
+        if capture_audio {
+            let mut player = player.lock().unwrap();
+            player.audio_mut().tick();
+            let samples = player
+                .audio_mut()
+                .downcast_mut::<OfflineAudioBackend>()
+                .unwrap()
+                .take_samples();
+            if i >= skipframes {
+                audio.extend(samples);
+            }
+        }
+        injector.next(|event, _buttons| inject_event(&mut player.lock().unwrap(), event));
         if i >= skipframes {
             match catch_unwind(|| {
                 player.lock().unwrap().render();
-                let mut player = player.lock().unwrap();
-                let renderer = player
-                    .renderer_mut()
-                    .downcast_mut::<WgpuRenderBackend<TextureTarget>>()
-                    .unwrap();
-                renderer.capture_frame()
+                capture_frame(&mut player.lock().unwrap())
             }) {
                 Ok(Some(image)) => result.push(image),
                 Ok(None) => return Err(anyhow!("Unable to capture frame {} of {:?}", i, swf_path)),
@@ -163,7 +310,82 @@ fn take_screenshot(
             progress.inc(1);
         }
     }
-    Ok(result)
+
+    let frame_rate = player.lock().unwrap().frame_rate();
+    Ok(Capture {
+        frames: result,
+        frame_rate,
+        audio: capture_audio.then_some(audio),
+    })
+}
+
+/// Captures the last rendered frame of either renderer.
+fn capture_frame(player: &mut Player) -> Option<RgbaImage> {
+    let renderer = player.renderer_mut();
+    if let Some(renderer) = renderer.downcast_mut::<WgpuRenderBackend<TextureTarget>>() {
+        renderer.capture_frame()
+    } else {
+        renderer
+            .downcast_mut::<SoftwareRenderBackend>()?
+            .capture_frame()
+    }
+}
+
+/// Passes an event from an input file to the player.
+fn inject_event(player: &mut Player, event: &AutomatedEvent) {
+    // Input files describe the mouse position on the stage, which may be scaled to fit the output.
+    let viewport_position = |player: &Player, x: f64, y: f64| {
+        player.stage_to_viewport_position(Point::new(Twips::from_pixels(x), Twips::from_pixels(y)))
+    };
+
+    let event = match event {
+        AutomatedEvent::MouseMove { pos } => {
+            let (x, y) = viewport_position(player, pos.0, pos.1);
+            PlayerEvent::MouseMove { x, y }
+        }
+        AutomatedEvent::MouseDown { pos, btn } => {
+            let (x, y) = viewport_position(player, pos.0, pos.1);
+            PlayerEvent::MouseDown {
+                x,
+                y,
+                button: (*btn).into(),
+            }
+        }
+        AutomatedEvent::MouseUp { pos, btn } => {
+            let (x, y) = viewport_position(player, pos.0, pos.1);
+            PlayerEvent::MouseUp {
+                x,
+                y,
+                button: (*btn).into(),
+            }
+        }
+        AutomatedEvent::KeyDown { key_code } => PlayerEvent::KeyDown {
+            key_code: KeyCode::from_u8(*key_code).unwrap_or(KeyCode::Unknown),
+            key_char: None,
+        },
+        AutomatedEvent::KeyUp { key_code } => PlayerEvent::KeyUp {
+            key_code: KeyCode::from_u8(*key_code).unwrap_or(KeyCode::Unknown),
+            key_char: None,
+        },
+        AutomatedEvent::MouseWheel { delta } => PlayerEvent::MouseWheel {
+            delta: (*delta).into(),
+        },
+        AutomatedEvent::MouseLeave => PlayerEvent::MouseLeave,
+        AutomatedEvent::TextInput { codepoint } => PlayerEvent::TextInput {
+            codepoint: *codepoint,
+        },
+        AutomatedEvent::TextControl { code } => PlayerEvent::TextControl {
+            code: (*code).into(),
+        },
+        AutomatedEvent::SetClipboardText { text } => {
+            player.ui_mut().set_clipboard_content(text.to_owned());
+            return;
+        }
+        AutomatedEvent::FocusGained => PlayerEvent::FocusGained,
+        AutomatedEvent::FocusLost => PlayerEvent::FocusLost,
+        AutomatedEvent::Wait => unreachable!(),
+    };
+    player.handle_event(event);
 }
 
 fn find_files(root: &Path, with_progress: bool) -> Vec<DirEntry> {
@@ -196,17 +418,17 @@ fn find_files(root: &Path, with_progress: bool) -> Vec<DirEntry> {
     results
 }
 
-fn capture_single_swf(descriptors: Arc<Descriptors>, opt: &Opt) -> Result<()> {
+fn capture_single_swf(descriptors: Option<Arc<Descriptors>>, opt: &Opt) -> Result<()> {
     let output = opt.output_path.clone().unwrap_or_else(|| {
         let mut result = PathBuf::new();
         result.set_file_name(opt.swf.file_stem().unwrap());
-        if opt.frames == 1 {
-            result.set_extension("png");
+        if opt.frames == 1 || opt.format.is_animated() {
+            result.set_extension(opt.format.extension());
         }
         result
     });
 
-    if opt.frames > 1 {
+    if opt.frames > 1 && !opt.format.is_animated() {
         let _ = create_dir_all(&output);
     }
 
@@ -224,7 +446,7 @@ fn capture_single_swf(descriptors: Arc<Descriptors>, opt: &Opt) -> Result<()> {
         None
     };
 
-    let frames = take_screenshot(
+    let capture = take_screenshot(
         descriptors,
         &opt.swf,
         opt.frames,
@@ -232,13 +454,18 @@ fn capture_single_swf(descriptors: Arc<Descriptors>, opt: &Opt) -> Result<()> {
         &progress,
         opt.size,
         opt.skip_unsupported,
+        opt.input.as_deref(),
+        opt.capture_audio(),
     )?;
+    let frames = &capture.frames;
 
     if let Some(progress) = &progress {
         progress.set_message(opt.swf.file_stem().unwrap().to_string_lossy().into_owned());
     }
 
-    if frames.len() == 1 {
+    if let Some(format) = opt.format.animated() {
+        capture.save_animated(&output, format)?;
+    } else if frames.len() == 1 {
         let image = frames.get(0).unwrap();
         if opt.output_path == Some(PathBuf::from("-")) {
             let mut bytes: Vec<u8> = Vec::new();
@@ -262,7 +489,15 @@ fn capture_single_swf(descriptors: Arc<Descriptors>, opt: &Opt) -> Result<()> {
         }
     }
 
-    let message = if frames.len() == 1 {
+    if opt.audio {
+        if opt.frames > 1 && !opt.format.is_animated() {
+            capture.save_wav(&output.join("audio.wav"))?;
+        } else {
+            capture.save_wav(&output.with_extension("wav"))?;
+        }
+    }
+
+    let message = if frames.len() == 1 && !opt.format.is_animated() {
         if !opt.silent {
             Some(format!(
                 "Saved first frame of {} to {}",
@@ -293,7 +528,7 @@ fn capture_single_swf(descriptors: Arc<Descriptors>, opt: &Opt) -> Result<()> {
 }
 
 #[allow(clippy::branches_sharing_code)]
-fn capture_multiple_swfs(descriptors: Arc<Descriptors>, opt: &Opt) -> Result<()> {
+fn capture_multiple_swfs(descriptors: Option<Arc<Descriptors>>, opt: &Opt) -> Result<()> {
     let output = opt.output_path.clone().unwrap();
     let files = find_files(&opt.swf, !opt.silent);
 
@@ -321,7 +556,7 @@ fn capture_multiple_swfs(descriptors: Arc<Descriptors>, opt: &Opt) -> Result<()>
                     .into_owned(),
             );
         }
-        if let Ok(frames) = take_screenshot(
+        if let Ok(capture) = take_screenshot(
             descriptors.clone(),
             file.path(),
             opt.frames,
@@ -329,14 +564,28 @@ fn capture_multiple_swfs(descriptors: Arc<Descriptors>, opt: &Opt) -> Result<()>
             &progress,
             opt.size,
             opt.skip_unsupported,
+            opt.input.as_deref(),
+            opt.capture_audio(),
         ) {
+            let frames = &capture.frames;
             let mut relative_path = file
                 .path()
                 .strip_prefix(&opt.swf)
                 .unwrap_or_else(|_| file.path())
                 .to_path_buf();
 
-            if frames.len() == 1 {
+            if let Some(format) = opt.format.animated() {
+                let mut destination: PathBuf = (&output).into();
+                relative_path.set_extension(opt.format.extension());
+                destination.push(relative_path);
+                if let Some(parent) = destination.parent() {
+                    let _ = create_dir_all(parent);
+                }
+                capture.save_animated(&destination, format)?;
+                if opt.audio {
+                    capture.save_wav(&destination.with_extension("wav"))?;
+                }
+            } else if frames.len() == 1 {
                 let mut destination: PathBuf = (&output).into();
                 relative_path.set_extension("png");
                 destination.push(relative_path);
@@ -344,6 +593,9 @@ fn capture_multiple_swfs(descriptors: Arc<Descriptors>, opt: &Opt) -> Result<()>
                     let _ = create_dir_all(parent);
                 }
                 frames.get(0).unwrap().save(&destination)?;
+                if opt.audio {
+                    capture.save_wav(&destination.with_extension("wav"))?;
+                }
             } else {
                 let mut parent: PathBuf = (&output).into();
                 relative_path.set_extension("");
@@ -354,6 +606,9 @@ fn capture_multiple_swfs(descriptors: Arc<Descriptors>, opt: &Opt) -> Result<()>
                     destination.push(format!("{frame}.png"));
                     image.save(&destination)?;
                 }
+                if opt.audio {
+                    capture.save_wav(&parent.join("audio.wav"))?;
+                }
             }
         }
 
@@ -399,8 +654,7 @@ fn trace_path(_opt: &Opt) -> Option<&Path> {
     None
 }
 
-fn main() -> Result<()> {
-    let opt: Opt = Opt::parse();
+fn create_descriptors(opt: &Opt) -> Result<Arc<Descriptors>> {
     let instance = wgpu::Instance::new(wgpu::InstanceDescriptor {
         backends: opt.graphics.into(),
         dx12_shader_compiler: wgpu::Dx12Compiler::default(),
@@ -410,11 +664,20 @@ fn main() -> Result<()> {
         instance,
         None,
         opt.power.into(),
-        trace_path(&opt),
+        trace_path(opt),
     ))
     .map_err(|e| anyhow!(e.to_string()))?;
 
-    let descriptors = Arc::new(Descriptors::new(adapter, device, queue));
+    Ok(Arc::new(Descriptors::new(adapter, device, queue)))
+}
+
+fn main() -> Result<()> {
+    let opt: Opt = Opt::parse();
+    let descriptors = if opt.software {
+        None
+    } else {
+        Some(create_descriptors(&opt)?)
+    };
 
     if opt.swf.is_file() {
         capture_single_swf(descriptors, &opt)?;
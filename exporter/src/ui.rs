@@ -0,0 +1,70 @@
+//! A UI backend for running movies without any user interface.
+
+use ruffle_core::backend::ui::{
+    CustomMouseCursor, FileDialogFuture, FileFilter, FullscreenError, LanguageIdentifier,
+    LoadedFile, MouseCursor, SaveFile, UiBackend, US_ENGLISH,
+};
+
+/// Like `NullUiBackend`, but with a clipboard, so that text set by an input
+/// file can be pasted into the movie.
+#[derive(Default)]
+pub struct ExporterUiBackend {
+    clipboard: String,
+}
+
+impl UiBackend for ExporterUiBackend {
+    fn mouse_visible(&self) -> bool {
+        true
+    }
+
+    fn set_mouse_visible(&mut self, _visible: bool) {}
+
+    fn set_mouse_cursor(&mut self, _cursor: MouseCursor) {}
+
+    fn supports_custom_mouse_cursor(&self) -> bool {
+        false
+    }
+
+    fn register_mouse_cursor(&mut self, _id: u32, _cursor: CustomMouseCursor) {}
+
+    fn unregister_mouse_cursor(&mut self, _id: u32) {}
+
+    fn clipboard_content(&mut self) -> String {
+        self.clipboard.clone()
+    }
+
+    fn set_clipboard_content(&mut self, content: String) {
+        self.clipboard = content;
+    }
+
+    fn set_fullscreen(&mut self, _is_full: bool) -> Result<(), FullscreenError> {
+        Ok(())
+    }
+
+    fn display_unsupported_message(&self) {}
+
+    fn display_root_movie_download_failed_message(&self) {}
+
+    fn message(&self, _message: &str) {}
+
+    fn open_virtual_keyboard(&self) {}
+
+    fn language(&self) -> &LanguageIdentifier {
+        &US_ENGLISH
+    }
+
+    fn display_file_open_dialog(
+        &mut self,
+        _filters: Vec<FileFilter>,
+        _multiple: bool,
+    ) -> Option<FileDialogFuture<Vec<LoadedFile>>> {
+        None
+    }
+
+    fn display_file_save_dialog(
+        &mut self,
+        _file_name: String,
+    ) -> Option<FileDialogFuture<Box<dyn SaveFile>>> {
+        None
+    }
+}
@@ -0,0 +1,391 @@
+//! Encoding of captured frames into animated images and videos.
+
+use anyhow::{anyhow, Result};
+use image::codecs::gif::{GifEncoder, Repeat};
+use image::{Delay, Frame, RgbaImage};
+use std::io::Write;
+
+/// Audio to be muxed alongside the captured frames.
+pub struct Audio<'a> {
+    /// Interleaved 16-bit samples.
+    pub samples: &'a [i16],
+    pub num_channels: u16,
+    pub sample_rate: u32,
+}
+
+/// Writes the frames as an animated PNG, looping forever.
+pub fn write_apng(writer: impl Write, frames: &[RgbaImage], frame_rate: f64) -> Result<()> {
+    let (width, height) = frame_size(frames)?;
+    let mut encoder = png::Encoder::new(writer, width, height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.set_animated(frames.len() as u32, 0)?;
+    // The delay is a fraction of a second, which needs to fit in 16 bits.
+    let denominator = (frame_rate * 100.0).round().clamp(1.0, u16::MAX as f64) as u16;
+    encoder.set_frame_delay(100, denominator)?;
+
+    let mut writer = encoder.write_header()?;
+    for frame in frames {
+        writer.write_image_data(frame.as_raw())?;
+    }
+    writer.finish()?;
+    Ok(())
+}
+
+/// Writes the frames as an animated GIF, looping forever.
+///
+/// GIFs only support delays in hundredths of a second and 256 colors per frame,
+/// so this is lossy.
+pub fn write_gif(writer: impl Write, frames: &[RgbaImage], frame_rate: f64) -> Result<()> {
+    frame_size(frames)?;
+    let mut encoder = GifEncoder::new_with_speed(writer, 10);
+    encoder.set_repeat(Repeat::Infinite)?;
+    let delay = Delay::from_numer_denom_ms(100_000, (frame_rate * 100.0).round().max(1.0) as u32);
+    for frame in frames {
+        encoder.encode_frame(Frame::from_parts(frame.clone(), 0, 0, delay))?;
+    }
+    Ok(())
+}
+
+/// Writes the frames as an uncompressed AVI video, with optional uncompressed PCM audio.
+///
+/// Transparent areas of the frames are drawn over black, as the video has no alpha channel.
+pub fn write_avi(
+    mut writer: impl Write,
+    frames: &[RgbaImage],
+    frame_rate: f64,
+    audio: Option<Audio>,
+) -> Result<()> {
+    let (width, height) = frame_size(frames)?;
+    // Rows of a DIB are padded to 4 bytes.
+    let row_size = (width as usize * 3 + 3) & !3;
+    let frame_size = row_size * height as usize;
+
+    // Split the audio up into one chunk per frame, so that players can interleave it.
+    let audio_chunks: Vec<&[i16]> = match &audio {
+        Some(audio) => {
+            let channels = audio.num_channels as usize;
+            let audio_frames = audio.samples.len() / channels;
+            (0..frames.len())
+                .map(|i| {
+                    let start = audio_frames * i / frames.len();
+                    let end = audio_frames * (i + 1) / frames.len();
+                    &audio.samples[start * channels..end * channels]
+                })
+                .collect()
+        }
+        None => vec![],
+    };
+
+    // Rate and scale define the frame rate as a fraction.
+    let rate = (frame_rate * 1000.0).round().max(1.0) as u32;
+    let scale = 1000;
+
+    let mut movi = Chunks::default();
+    let mut index = vec![];
+    for (i, frame) in frames.iter().enumerate() {
+        let mut data = Vec::with_capacity(frame_size);
+        // DIBs are stored bottom-up in BGR order.
+        for row in frame.rows().rev() {
+            let row_start = data.len();
+            for pixel in row {
+                let [r, g, b, a] = pixel.0;
+                let premultiply = |c: u8| ((u16::from(c) * u16::from(a) + 127) / 255) as u8;
+                data.extend_from_slice(&[premultiply(b), premultiply(g), premultiply(r)]);
+            }
+            data.resize(row_start + row_size, 0);
+        }
+        index.push((*b"00db", movi.len(), data.len()));
+        movi.chunk(b"00db", &data);
+
+        if let Some(samples) = audio_chunks.get(i) {
+            let data: Vec<u8> = samples.iter().flat_map(|s| s.to_le_bytes()).collect();
+            index.push((*b"01wb", movi.len(), data.len()));
+            movi.chunk(b"01wb", &data);
+        }
+    }
+
+    let num_streams = if audio.is_some() { 2 } else { 1 };
+    let mut hdrl = Chunks::default();
+    let mut avih = vec![];
+    write_u32s(
+        &mut avih,
+        &[
+            (1_000_000.0 / frame_rate).round() as u32, // Microseconds per frame
+            0,                                         // Max bytes per second
+            0,                                         // Padding granularity
+            0x10,                                      // Flags: has index
+            frames.len() as u32,                       // Total frames
+            0,                                         // Initial frames
+            num_streams,                               // Streams
+            frame_size as u32,                         // Suggested buffer size
+            width,
+            height,
+            0,
+            0,
+            0,
+            0,
+        ],
+    );
+    hdrl.chunk(b"avih", &avih);
+
+    let mut video_strl = Chunks::default();
+    let mut strh = b"vidsDIB ".to_vec();
+    write_u32s(&mut strh, &[0, 0, 0, scale, rate, 0]);
+    write_u32s(
+        &mut strh,
+        &[frames.len() as u32, frame_size as u32, u32::MAX, 0],
+    );
+    write_u16s(&mut strh, &[0, 0, width as u16, height as u16]);
+    video_strl.chunk(b"strh", &strh);
+    let mut strf = vec![];
+    write_u32s(&mut strf, &[40, width, height]);
+    write_u16s(&mut strf, &[1, 24]); // Planes, bits per pixel
+    write_u32s(&mut strf, &[0, frame_size as u32, 0, 0, 0, 0]);
+    video_strl.chunk(b"strf", &strf);
+    hdrl.list(b"strl", &video_strl);
+
+    if let Some(audio) = &audio {
+        let block_align = audio.num_channels * 2;
+        let mut audio_strl = Chunks::default();
+        let mut strh = b"auds\0\0\0\0".to_vec();
+        write_u32s(&mut strh, &[0, 0, 0, 1, audio.sample_rate, 0]);
+        write_u32s(
+            &mut strh,
+            &[
+                (audio.samples.len() / audio.num_channels as usize) as u32,
+                0,
+                u32::MAX,
+                u32::from(block_align),
+            ],
+        );
+        write_u16s(&mut strh, &[0, 0, 0, 0]);
+        audio_strl.chunk(b"strh", &strh);
+        let mut strf = vec![];
+        write_u16s(&mut strf, &[1, audio.num_channels]); // PCM
+        write_u32s(
+            &mut strf,
+            &[
+                audio.sample_rate,
+                audio.sample_rate * u32::from(block_align),
+            ],
+        );
+        write_u16s(&mut strf, &[block_align, 16]);
+        audio_strl.chunk(b"strf", &strf);
+        hdrl.list(b"strl", &audio_strl);
+    }
+
+    let mut idx1 = vec![];
+    for (id, offset, size) in index {
+        idx1.extend_from_slice(&id);
+        // Offsets are relative to the "movi" identifier of the list.
+        write_u32s(&mut idx1, &[0x10, offset as u32 + 4, size as u32]);
+    }
+
+    let mut header = Chunks::default();
+    header.list(b"hdrl", &hdrl);
+    let mut footer = Chunks::default();
+    footer.chunk(b"idx1", &idx1);
+
+    // The frames make up most of the file, so write them out directly instead of copying them.
+    let size = u32::try_from(4 + header.len() + 12 + movi.len() + footer.len())
+        .map_err(|_| anyhow!("Video is too large to be stored as an AVI file"))?;
+    writer.write_all(b"RIFF")?;
+    writer.write_all(&size.to_le_bytes())?;
+    writer.write_all(b"AVI ")?;
+    writer.write_all(&header.0)?;
+    writer.write_all(b"LIST")?;
+    writer.write_all(&(movi.len() as u32 + 4).to_le_bytes())?;
+    writer.write_all(b"movi")?;
+    writer.write_all(&movi.0)?;
+    writer.write_all(&footer.0)?;
+    writer.flush()?;
+    Ok(())
+}
+
+fn frame_size(frames: &[RgbaImage]) -> Result<(u32, u32)> {
+    frames
+        .first()
+        .map(|frame| frame.dimensions())
+        .ok_or_else(|| anyhow!("No frames were captured"))
+}
+
+/// A sequence of RIFF chunks.
+#[derive(Default)]
+struct Chunks(Vec<u8>);
+
+impl Chunks {
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn chunk(&mut self, id: &[u8; 4], data: &[u8]) {
+        self.0.extend_from_slice(id);
+        self.0.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        self.0.extend_from_slice(data);
+        // Chunks are aligned to 2 bytes.
+        if data.len() % 2 != 0 {
+            self.0.push(0);
+        }
+    }
+
+    fn list(&mut self, list_type: &[u8; 4], chunks: &Chunks) {
+        self.0.extend_from_slice(b"LIST");
+        self.0
+            .extend_from_slice(&(chunks.len() as u32 + 4).to_le_bytes());
+        self.0.extend_from_slice(list_type);
+        self.0.extend_from_slice(&chunks.0);
+    }
+}
+
+fn write_u32s(out: &mut Vec<u8>, values: &[u32]) {
+    for value in values {
+        out.extend_from_slice(&value.to_le_bytes());
+    }
+}
+
+fn write_u16s(out: &mut Vec<u8>, values: &[u16]) {
+    for value in values {
+        out.extend_from_slice(&value.to_le_bytes());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::codecs::gif::GifDecoder;
+    use image::{AnimationDecoder, Rgba};
+
+    const FRAME_RATE: f64 = 25.0;
+
+    /// Three 3x2 frames of a different color each.
+    fn frames() -> Vec<RgbaImage> {
+        [[255, 0, 0, 255], [0, 255, 0, 255], [0, 0, 255, 128]]
+            .into_iter()
+            .map(|color| RgbaImage::from_pixel(3, 2, Rgba(color)))
+            .collect()
+    }
+
+    fn read_u32(data: &[u8], offset: usize) -> u32 {
+        u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap())
+    }
+
+    /// Finds the first chunk with the given identifier, returning its data.
+    fn find_chunk<'a>(data: &'a [u8], id: &[u8; 4]) -> &'a [u8] {
+        let offset = data
+            .windows(4)
+            .position(|window| window == id)
+            .expect("Chunk should exist");
+        let size = read_u32(data, offset + 4) as usize;
+        &data[offset + 8..offset + 8 + size]
+    }
+
+    #[test]
+    fn apng() {
+        let mut data = vec![];
+        write_apng(&mut data, &frames(), FRAME_RATE).unwrap();
+
+        let mut reader = png::Decoder::new(data.as_slice()).read_info().unwrap();
+        let info = reader.info();
+        assert_eq!((info.width, info.height), (3, 2));
+        let animation = info.animation_control().expect("PNG should be animated");
+        assert_eq!(animation.num_frames, 3);
+        assert_eq!(animation.num_plays, 0);
+
+        let mut buffer = vec![0; reader.output_buffer_size()];
+        let mut num_frames = 0;
+        while reader.next_frame(&mut buffer).is_ok() {
+            let control = reader.info().frame_control().unwrap();
+            assert_eq!((control.delay_num, control.delay_den), (100, 2500));
+            num_frames += 1;
+        }
+        assert_eq!(num_frames, 3);
+    }
+
+    #[test]
+    fn gif() {
+        let mut data = vec![];
+        write_gif(&mut data, &frames(), FRAME_RATE).unwrap();
+        assert!(data.starts_with(b"GIF89a"));
+
+        let frames = GifDecoder::new(data.as_slice())
+            .unwrap()
+            .into_frames()
+            .collect_frames()
+            .unwrap();
+        assert_eq!(frames.len(), 3);
+        for frame in &frames {
+            assert_eq!(frame.buffer().dimensions(), (3, 2));
+            assert_eq!(frame.delay().numer_denom_ms(), (40, 1));
+        }
+        assert_eq!(frames[1].buffer().get_pixel(0, 0), &Rgba([0, 255, 0, 255]));
+    }
+
+    #[test]
+    fn avi() {
+        let mut data = vec![];
+        write_avi(&mut data, &frames(), FRAME_RATE, None).unwrap();
+        assert_eq!(&data[..4], b"RIFF");
+        assert_eq!(read_u32(&data, 4) as usize, data.len() - 8);
+        assert_eq!(&data[8..12], b"AVI ");
+
+        let avih = find_chunk(&data, b"avih");
+        assert_eq!(read_u32(avih, 0), 40_000, "Microseconds per frame");
+        assert_eq!(read_u32(avih, 16), 3, "Total frames");
+        assert_eq!(read_u32(avih, 24), 1, "Streams");
+        assert_eq!((read_u32(avih, 32), read_u32(avih, 36)), (3, 2));
+
+        let idx1 = find_chunk(&data, b"idx1");
+        let ids: Vec<_> = idx1.chunks(16).map(|entry| &entry[..4]).collect();
+        assert_eq!(ids, [b"00db"; 3]);
+
+        // Rows are padded to 4 bytes, and the last frame is premultiplied over black.
+        let last_frame = read_u32(idx1, 2 * 16 + 8) as usize;
+        let movi = data
+            .windows(4)
+            .position(|window| window == b"movi")
+            .unwrap();
+        let frame = &data[movi + last_frame + 8..][..24];
+        assert_eq!(&frame[..12], [128, 0, 0, 128, 0, 0, 128, 0, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn avi_with_audio() {
+        let samples: Vec<i16> = (0..60).collect();
+        let audio = Audio {
+            samples: &samples,
+            num_channels: 2,
+            sample_rate: 44100,
+        };
+        let mut data = vec![];
+        write_avi(&mut data, &frames(), FRAME_RATE, Some(audio)).unwrap();
+
+        let avih = find_chunk(&data, b"avih");
+        assert_eq!(read_u32(avih, 24), 2, "Streams");
+
+        // Every frame is followed by its share of the audio.
+        let idx1 = find_chunk(&data, b"idx1");
+        let entries: Vec<_> = idx1
+            .chunks(16)
+            .map(|entry| (&entry[..4], read_u32(entry, 12)))
+            .collect();
+        assert_eq!(
+            entries,
+            [
+                (&b"00db"[..], 24),
+                (b"01wb", 40),
+                (b"00db", 24),
+                (b"01wb", 40),
+                (b"00db", 24),
+                (b"01wb", 40),
+            ]
+        );
+    }
+
+    #[test]
+    fn no_frames() {
+        assert!(write_apng(vec![], &[], FRAME_RATE).is_err());
+        assert!(write_gif(vec![], &[], FRAME_RATE).is_err());
+        assert!(write_avi(vec![], &[], FRAME_RATE, None).is_err());
+    }
+}
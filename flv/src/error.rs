@@ -0,0 +1,35 @@
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq, Clone, Copy)]
+pub enum Error {
+    /// The data ended before the structure being read was complete.
+    ///
+    /// When reading a progressively downloaded file, this is not fatal: the
+    /// same structure can be read again once more data has arrived.
+    #[error("Unexpected end of FLV data")]
+    EndOfData,
+
+    /// The file does not start with the `FLV` signature.
+    #[error("Invalid FLV signature")]
+    WrongMagic,
+
+    /// A script data value used a type marker that is not defined by the FLV
+    /// specification.
+    #[error("Unknown script data value type {0}")]
+    UnknownValueType(u8),
+
+    /// An audio tag declared a sound format that is not defined by the FLV
+    /// specification.
+    #[error("Unknown sound format {0}")]
+    UnknownSoundFormat(u8),
+
+    /// A video tag declared a codec that is not defined by the FLV
+    /// specification.
+    #[error("Unknown video codec {0}")]
+    UnknownVideoCodec(u8),
+
+    /// A video tag declared a frame type that is not defined by the FLV
+    /// specification.
+    #[error("Unknown video frame type {0}")]
+    UnknownFrameType(u8),
+}
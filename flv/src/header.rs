@@ -0,0 +1,107 @@
+use crate::error::Error;
+use crate::reader::FlvReader;
+
+/// Which kinds of media an FLV file declares that it contains.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TypeFlags {
+    pub has_audio: bool,
+    pub has_video: bool,
+}
+
+/// The header at the start of every FLV file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Header {
+    /// The FLV version. This is always 1 for files in the wild.
+    pub version: u8,
+
+    /// The kinds of tags present in this file.
+    pub type_flags: TypeFlags,
+
+    /// The byte offset of the first `PreviousTagSize` field, i.e. the size
+    /// of the header itself.
+    pub data_offset: u32,
+}
+
+impl Header {
+    /// Parse an FLV header.
+    ///
+    /// On success, the reader is positioned at the first tag of the file
+    /// (skipping any padding and the initial `PreviousTagSize0` field).
+    /// On error, the reader position is left unchanged.
+    pub fn parse(reader: &mut FlvReader<'_>) -> Result<Self, Error> {
+        let start = reader.position();
+        let result = Self::parse_internal(reader);
+        if result.is_err() {
+            reader.seek(start);
+        }
+
+        result
+    }
+
+    fn parse_internal(reader: &mut FlvReader<'_>) -> Result<Self, Error> {
+        let start = reader.position();
+        if reader.read(3)? != b"FLV" {
+            return Err(Error::WrongMagic);
+        }
+
+        let version = reader.read_u8()?;
+        let flags = reader.read_u8()?;
+        let data_offset = reader.read_u32()?;
+
+        // Skip the remainder of the header, plus `PreviousTagSize0`.
+        reader.seek(start);
+        reader.read(data_offset as usize)?;
+        reader.read_u32()?;
+
+        Ok(Self {
+            version,
+            type_flags: TypeFlags {
+                has_audio: flags & 0x04 != 0,
+                has_video: flags & 0x01 != 0,
+            },
+            data_offset,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_header() {
+        let data = [b'F', b'L', b'V', 1, 0x05, 0, 0, 0, 9, 0, 0, 0, 0];
+        let mut reader = FlvReader::new(&data);
+
+        assert_eq!(
+            Header::parse(&mut reader),
+            Ok(Header {
+                version: 1,
+                type_flags: TypeFlags {
+                    has_audio: true,
+                    has_video: true,
+                },
+                data_offset: 9,
+            })
+        );
+        assert_eq!(reader.position(), 13);
+    }
+
+    #[test]
+    fn parse_wrong_magic() {
+        let data = [b'F', b'W', b'S', 1, 0x05, 0, 0, 0, 9, 0, 0, 0, 0];
+        let mut reader = FlvReader::new(&data);
+
+        assert_eq!(Header::parse(&mut reader), Err(Error::WrongMagic));
+        assert_eq!(reader.position(), 0);
+    }
+
+    #[test]
+    fn parse_truncated_header() {
+        let data = [b'F', b'L', b'V', 1, 0x05, 0, 0, 0, 9, 0];
+        let mut reader = FlvReader::new(&data);
+
+        assert_eq!(Header::parse(&mut reader), Err(Error::EndOfData));
+        assert_eq!(reader.position(), 0);
+    }
+}
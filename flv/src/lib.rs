@@ -0,0 +1,27 @@
+//! # flv-rs
+//!
+//! Library for reading Flash Video (FLV) files.
+//!
+//! # Organization
+//!
+//! An FLV file consists of a `Header` followed by a stream of `Tag`s, each of
+//! which carries either audio data, video data, or script data. Tags can be
+//! parsed incrementally from a partially-downloaded file using `FlvReader`;
+//! parsing a tag that is not yet fully available will fail with
+//! `Error::EndOfData` without consuming any data.
+
+mod error;
+mod header;
+mod reader;
+mod script;
+mod sound;
+mod tag;
+mod video;
+
+pub use error::Error;
+pub use header::{Header, TypeFlags};
+pub use reader::FlvReader;
+pub use script::{ScriptData, Value, Variable};
+pub use sound::{AudioData, AudioDataType, SoundFormat, SoundRate, SoundSize, SoundType};
+pub use tag::{Tag, TagData};
+pub use video::{CodecId, FrameType, VideoData, VideoPacket};
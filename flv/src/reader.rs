@@ -0,0 +1,113 @@
+use crate::error::Error;
+
+/// A cursor over (possibly incomplete) FLV data.
+///
+/// All reads are big-endian, as per the FLV specification. Reads that would
+/// go past the end of the available data fail with `Error::EndOfData` and do
+/// not advance the cursor.
+#[derive(Clone, Debug)]
+pub struct FlvReader<'a> {
+    source: &'a [u8],
+    position: usize,
+}
+
+impl<'a> FlvReader<'a> {
+    /// Create a reader positioned at the start of `source`.
+    pub fn new(source: &'a [u8]) -> Self {
+        Self::with_position(source, 0)
+    }
+
+    /// Create a reader positioned at `position` within `source`.
+    pub fn with_position(source: &'a [u8], position: usize) -> Self {
+        Self { source, position }
+    }
+
+    /// The current byte offset of this reader within its source.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Move the reader to a given byte offset.
+    pub fn seek(&mut self, position: usize) {
+        self.position = position;
+    }
+
+    /// The number of bytes left to read.
+    pub fn remaining(&self) -> usize {
+        self.source.len().saturating_sub(self.position)
+    }
+
+    /// Read a number of bytes, returning a borrow of them.
+    pub fn read(&mut self, count: usize) -> Result<&'a [u8], Error> {
+        let end = self.position.checked_add(count).ok_or(Error::EndOfData)?;
+        let data = self
+            .source
+            .get(self.position..end)
+            .ok_or(Error::EndOfData)?;
+        self.position = end;
+
+        Ok(data)
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8, Error> {
+        Ok(self.read(1)?[0])
+    }
+
+    pub fn read_u16(&mut self) -> Result<u16, Error> {
+        let data = self.read(2)?;
+        Ok(u16::from_be_bytes([data[0], data[1]]))
+    }
+
+    pub fn read_i16(&mut self) -> Result<i16, Error> {
+        Ok(self.read_u16()? as i16)
+    }
+
+    pub fn read_u24(&mut self) -> Result<u32, Error> {
+        let data = self.read(3)?;
+        Ok(u32::from_be_bytes([0, data[0], data[1], data[2]]))
+    }
+
+    /// Read a signed 24-bit integer.
+    pub fn read_i24(&mut self) -> Result<i32, Error> {
+        let value = self.read_u24()?;
+        Ok(((value << 8) as i32) >> 8)
+    }
+
+    pub fn read_u32(&mut self) -> Result<u32, Error> {
+        let data = self.read(4)?;
+        Ok(u32::from_be_bytes([data[0], data[1], data[2], data[3]]))
+    }
+
+    pub fn read_f64(&mut self) -> Result<f64, Error> {
+        let data = self.read(8)?;
+        Ok(f64::from_be_bytes(
+            data.try_into().expect("8 bytes fit into a f64"),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_integers() {
+        let data = [0x01, 0x02, 0x03, 0xFF, 0xFF, 0xFE];
+        let mut reader = FlvReader::new(&data);
+
+        assert_eq!(reader.read_u24(), Ok(0x010203));
+        assert_eq!(reader.read_i24(), Ok(-2));
+        assert_eq!(reader.read_u8(), Err(Error::EndOfData));
+        assert_eq!(reader.position(), 6);
+    }
+
+    #[test]
+    fn read_past_end_does_not_advance() {
+        let data = [0x01, 0x02];
+        let mut reader = FlvReader::new(&data);
+
+        assert_eq!(reader.read_u32(), Err(Error::EndOfData));
+        assert_eq!(reader.position(), 0);
+        assert_eq!(reader.read_u16(), Ok(0x0102));
+    }
+}
@@ -0,0 +1,179 @@
+use crate::error::Error;
+use crate::reader::FlvReader;
+
+/// A single value in a script data tag.
+///
+/// These are encoded with a subset of AMF0.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value<'a> {
+    Number(f64),
+    Boolean(bool),
+    String(&'a [u8]),
+    Object(Vec<Variable<'a>>),
+    MovieClip(&'a [u8]),
+    Null,
+    Undefined,
+    Reference(u16),
+    EcmaArray(Vec<Variable<'a>>),
+    StrictArray(Vec<Value<'a>>),
+    Date {
+        /// Milliseconds since the Unix epoch.
+        unix_time: f64,
+
+        /// Offset from UTC, in minutes.
+        local_offset: i16,
+    },
+    LongString(&'a [u8]),
+}
+
+/// A named value, such as an object property or a top-level script datum.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Variable<'a> {
+    pub name: &'a [u8],
+    pub data: Value<'a>,
+}
+
+/// The contents of a script data tag.
+///
+/// Most files contain a single `onMetaData` variable holding an ECMA array
+/// that describes the file (`duration`, `width`, `height`, `framerate`, ...).
+#[derive(Clone, Debug, PartialEq)]
+pub struct ScriptData<'a>(pub Vec<Variable<'a>>);
+
+impl<'a> ScriptData<'a> {
+    /// Parse a script data tag body of the given length.
+    pub fn parse(reader: &mut FlvReader<'a>, data_size: u32) -> Result<Self, Error> {
+        let body = reader.read(data_size as usize)?;
+        let mut reader = FlvReader::new(body);
+        let mut vars = Vec::new();
+
+        while reader.remaining() > 0 {
+            let name = match Value::parse(&mut reader)? {
+                Value::String(name) | Value::LongString(name) => name,
+                // Some encoders pad the end of the tag; ignore the rest.
+                _ => break,
+            };
+            let data = Value::parse(&mut reader)?;
+
+            vars.push(Variable { name, data });
+        }
+
+        Ok(Self(vars))
+    }
+}
+
+impl<'a> Value<'a> {
+    /// Parse a type-tagged script data value.
+    pub fn parse(reader: &mut FlvReader<'a>) -> Result<Self, Error> {
+        let value_type = reader.read_u8()?;
+
+        Ok(match value_type {
+            0 => Self::Number(reader.read_f64()?),
+            1 => Self::Boolean(reader.read_u8()? != 0),
+            2 => Self::String(Self::parse_string(reader)?),
+            3 => Self::Object(Self::parse_properties(reader)?),
+            4 => Self::MovieClip(Self::parse_string(reader)?),
+            5 => Self::Null,
+            6 => Self::Undefined,
+            7 => Self::Reference(reader.read_u16()?),
+            8 => {
+                // The declared length is only a hint; the property list is
+                // terminated the same way as an object's.
+                let _length_hint = reader.read_u32()?;
+                Self::EcmaArray(Self::parse_properties(reader)?)
+            }
+            10 => {
+                let length = reader.read_u32()?;
+                let mut values = Vec::new();
+                for _ in 0..length {
+                    values.push(Self::parse(reader)?);
+                }
+
+                Self::StrictArray(values)
+            }
+            11 => Self::Date {
+                unix_time: reader.read_f64()?,
+                local_offset: reader.read_i16()?,
+            },
+            12 => {
+                let length = reader.read_u32()?;
+                Self::LongString(reader.read(length as usize)?)
+            }
+            unk => return Err(Error::UnknownValueType(unk)),
+        })
+    }
+
+    fn parse_string(reader: &mut FlvReader<'a>) -> Result<&'a [u8], Error> {
+        let length = reader.read_u16()?;
+        reader.read(length as usize)
+    }
+
+    fn parse_properties(reader: &mut FlvReader<'a>) -> Result<Vec<Variable<'a>>, Error> {
+        let mut vars = Vec::new();
+
+        loop {
+            let name = Self::parse_string(reader)?;
+            if name.is_empty() {
+                // `SCRIPTDATAOBJECTEND` is an empty name followed by marker 9.
+                let marker = reader.read_u8()?;
+                if marker == 9 {
+                    break;
+                }
+
+                return Err(Error::UnknownValueType(marker));
+            }
+
+            let data = Self::parse(reader)?;
+            vars.push(Variable { name, data });
+        }
+
+        Ok(vars)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_on_metadata() {
+        let data = [
+            0x02, 0x00, 0x0A, b'o', b'n', b'M', b'e', b't', b'a', b'D', b'a', b't', b'a', 0x08,
+            0x00, 0x00, 0x00, 0x02, 0x00, 0x05, b'w', b'i', b'd', b't', b'h', 0x00, 0x40, 0x74,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x06, b's', b't', b'e', b'r', b'e', b'o',
+            0x01, 0x01, 0x00, 0x00, 0x09,
+        ];
+        let mut reader = FlvReader::new(&data);
+
+        assert_eq!(
+            ScriptData::parse(&mut reader, data.len() as u32),
+            Ok(ScriptData(vec![Variable {
+                name: b"onMetaData",
+                data: Value::EcmaArray(vec![
+                    Variable {
+                        name: b"width",
+                        data: Value::Number(320.0),
+                    },
+                    Variable {
+                        name: b"stereo",
+                        data: Value::Boolean(true),
+                    },
+                ]),
+            }]))
+        );
+    }
+
+    #[test]
+    fn parse_strict_array() {
+        let data = [
+            0x0A, 0x00, 0x00, 0x00, 0x02, 0x05, 0x00, 0x3F, 0xF0, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00,
+        ];
+        let mut reader = FlvReader::new(&data);
+
+        assert_eq!(
+            Value::parse(&mut reader),
+            Ok(Value::StrictArray(vec![Value::Null, Value::Number(1.0)]))
+        );
+    }
+}
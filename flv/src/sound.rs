@@ -0,0 +1,184 @@
+use crate::error::Error;
+use crate::reader::FlvReader;
+
+/// The codec used to compress the audio in a tag.
+///
+/// The numeric values match the `SoundFormat` field of the FLV specification,
+/// which is shared with SWF sound formats.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SoundFormat {
+    LinearPcmPlatformEndian = 0,
+    Adpcm = 1,
+    Mp3 = 2,
+    LinearPcmLittleEndian = 3,
+    Nellymoser16kMono = 4,
+    Nellymoser8kMono = 5,
+    Nellymoser = 6,
+    G711ALawPcm = 7,
+    G711MuLawPcm = 8,
+    Aac = 10,
+    Speex = 11,
+    Mp38k = 14,
+    DeviceSpecific = 15,
+}
+
+impl TryFrom<u8> for SoundFormat {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => Self::LinearPcmPlatformEndian,
+            1 => Self::Adpcm,
+            2 => Self::Mp3,
+            3 => Self::LinearPcmLittleEndian,
+            4 => Self::Nellymoser16kMono,
+            5 => Self::Nellymoser8kMono,
+            6 => Self::Nellymoser,
+            7 => Self::G711ALawPcm,
+            8 => Self::G711MuLawPcm,
+            10 => Self::Aac,
+            11 => Self::Speex,
+            14 => Self::Mp38k,
+            15 => Self::DeviceSpecific,
+            unk => return Err(Error::UnknownSoundFormat(unk)),
+        })
+    }
+}
+
+/// The nominal sample rate of the audio in a tag.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SoundRate {
+    R5_500 = 0,
+    R11_000 = 1,
+    R22_000 = 2,
+    R44_000 = 3,
+}
+
+impl SoundRate {
+    /// The sample rate in Hz.
+    ///
+    /// This is the rate that SWF and FLV consumers actually play back at,
+    /// which differs slightly from the rounded names of the enumeration.
+    pub fn sample_rate(self) -> u16 {
+        match self {
+            Self::R5_500 => 5512,
+            Self::R11_000 => 11025,
+            Self::R22_000 => 22050,
+            Self::R44_000 => 44100,
+        }
+    }
+}
+
+/// The size of each uncompressed sample.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SoundSize {
+    Bits8 = 0,
+    Bits16 = 1,
+}
+
+/// The channel layout of the audio in a tag.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SoundType {
+    Mono = 0,
+    Stereo = 1,
+}
+
+/// The payload of an audio tag.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AudioDataType<'a> {
+    /// Audio data in the tag's declared format.
+    Raw(&'a [u8]),
+
+    /// An AAC `AudioSpecificConfig` record.
+    AacSequenceHeader(&'a [u8]),
+
+    /// A raw AAC frame.
+    AacRaw(&'a [u8]),
+}
+
+/// The contents of an audio tag.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AudioData<'a> {
+    pub format: SoundFormat,
+    pub rate: SoundRate,
+    pub size: SoundSize,
+    pub sound_type: SoundType,
+    pub data: AudioDataType<'a>,
+}
+
+impl<'a> AudioData<'a> {
+    /// Parse an audio tag body of the given length.
+    pub fn parse(reader: &mut FlvReader<'a>, data_size: u32) -> Result<Self, Error> {
+        let body = reader.read(data_size as usize)?;
+        let mut reader = FlvReader::new(body);
+
+        let format_spec = reader.read_u8()?;
+        let format = SoundFormat::try_from(format_spec >> 4)?;
+        let rate = match (format_spec >> 2) & 0x03 {
+            0 => SoundRate::R5_500,
+            1 => SoundRate::R11_000,
+            2 => SoundRate::R22_000,
+            _ => SoundRate::R44_000,
+        };
+        let size = if format_spec & 0x02 != 0 {
+            SoundSize::Bits16
+        } else {
+            SoundSize::Bits8
+        };
+        let sound_type = if format_spec & 0x01 != 0 {
+            SoundType::Stereo
+        } else {
+            SoundType::Mono
+        };
+
+        let data = if format == SoundFormat::Aac {
+            match reader.read_u8()? {
+                0 => AudioDataType::AacSequenceHeader(reader.read(reader.remaining())?),
+                _ => AudioDataType::AacRaw(reader.read(reader.remaining())?),
+            }
+        } else {
+            AudioDataType::Raw(reader.read(reader.remaining())?)
+        };
+
+        Ok(Self {
+            format,
+            rate,
+            size,
+            sound_type,
+            data,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_mp3_audio() {
+        let data = [0x2F, 0xAA, 0xBB];
+        let mut reader = FlvReader::new(&data);
+
+        assert_eq!(
+            AudioData::parse(&mut reader, data.len() as u32),
+            Ok(AudioData {
+                format: SoundFormat::Mp3,
+                rate: SoundRate::R44_000,
+                size: SoundSize::Bits16,
+                sound_type: SoundType::Stereo,
+                data: AudioDataType::Raw(&[0xAA, 0xBB]),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_aac_sequence_header() {
+        let data = [0xAF, 0x00, 0x12, 0x10];
+        let mut reader = FlvReader::new(&data);
+
+        assert_eq!(
+            AudioData::parse(&mut reader, data.len() as u32).map(|a| a.data),
+            Ok(AudioDataType::AacSequenceHeader(&[0x12, 0x10]))
+        );
+    }
+}
@@ -0,0 +1,179 @@
+use crate::error::Error;
+use crate::reader::FlvReader;
+use crate::script::ScriptData;
+use crate::sound::AudioData;
+use crate::video::VideoData;
+
+/// The contents of a tag.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TagData<'a> {
+    Audio(AudioData<'a>),
+    Video(VideoData<'a>),
+    Script(ScriptData<'a>),
+
+    /// A tag of a type this library does not understand, or one that has
+    /// been encrypted.
+    Unknown {
+        tag_type: u8,
+        data: &'a [u8],
+    },
+
+    /// A tag whose header was valid, but whose body could not be parsed.
+    ///
+    /// The tag is still skipped over in its entirety, so that readers can
+    /// carry on with the tags that follow it.
+    Invalid {
+        tag_type: u8,
+        data: &'a [u8],
+        error: Error,
+    },
+}
+
+/// A single tag in the body of an FLV file.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tag<'a> {
+    /// The presentation time of this tag, in milliseconds.
+    pub timestamp: i32,
+
+    /// The stream ID, which is always zero in FLV files.
+    pub stream_id: u32,
+
+    pub data: TagData<'a>,
+}
+
+impl<'a> Tag<'a> {
+    /// Parse a tag, including the `PreviousTagSize` field that follows it.
+    ///
+    /// On error, the reader position is left unchanged; in particular, a tag
+    /// that has not been fully downloaded yet yields `Error::EndOfData` and
+    /// can be parsed again from the same position later.
+    ///
+    /// A tag with a malformed body is not an error: it is returned as
+    /// `TagData::Invalid`, with the reader positioned after it.
+    pub fn parse(reader: &mut FlvReader<'a>) -> Result<Self, Error> {
+        let start = reader.position();
+        let result = Self::parse_internal(reader);
+        if result.is_err() {
+            reader.seek(start);
+        }
+
+        result
+    }
+
+    fn parse_internal(reader: &mut FlvReader<'a>) -> Result<Self, Error> {
+        let type_byte = reader.read_u8()?;
+        let is_filtered = type_byte & 0x20 != 0;
+        let tag_type = type_byte & 0x1F;
+        let data_size = reader.read_u24()?;
+        let timestamp = reader.read_u24()?;
+        let timestamp_extended = reader.read_u8()?;
+        let stream_id = reader.read_u24()?;
+
+        // Make sure the whole tag is available before parsing its body, so
+        // that a truncated tag is reported as such rather than as a
+        // malformed body.
+        if reader.remaining() < data_size as usize + 4 {
+            return Err(Error::EndOfData);
+        }
+
+        let body_start = reader.position();
+        let data = match tag_type {
+            8 if !is_filtered => AudioData::parse(reader, data_size).map(TagData::Audio),
+            9 if !is_filtered => VideoData::parse(reader, data_size).map(TagData::Video),
+            18 if !is_filtered => ScriptData::parse(reader, data_size).map(TagData::Script),
+            _ => reader
+                .read(data_size as usize)
+                .map(|data| TagData::Unknown { tag_type, data }),
+        };
+        let data = match data {
+            Ok(data) => data,
+            Err(error) => {
+                reader.seek(body_start);
+                TagData::Invalid {
+                    tag_type,
+                    data: reader.read(data_size as usize)?,
+                    error,
+                }
+            }
+        };
+
+        let _previous_tag_size = reader.read_u32()?;
+
+        Ok(Self {
+            timestamp: ((timestamp_extended as u32) << 24 | timestamp) as i32,
+            stream_id,
+            data,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sound::{AudioDataType, SoundFormat, SoundRate, SoundSize, SoundType};
+
+    #[test]
+    fn parse_audio_tag() {
+        let data = [
+            0x08, 0x00, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x2E, 0x11, 0x00,
+            0x00, 0x00, 0x0D,
+        ];
+        let mut reader = FlvReader::new(&data);
+
+        assert_eq!(
+            Tag::parse(&mut reader),
+            Ok(Tag {
+                timestamp: 256,
+                stream_id: 0,
+                data: TagData::Audio(AudioData {
+                    format: SoundFormat::Mp3,
+                    rate: SoundRate::R44_000,
+                    size: SoundSize::Bits16,
+                    sound_type: SoundType::Mono,
+                    data: AudioDataType::Raw(&[0x11]),
+                }),
+            })
+        );
+        assert_eq!(reader.position(), data.len());
+    }
+
+    #[test]
+    fn parse_truncated_tag() {
+        let data = [
+            0x08, 0x00, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x2E, 0x11, 0x00,
+        ];
+        let mut reader = FlvReader::new(&data);
+
+        assert_eq!(Tag::parse(&mut reader), Err(Error::EndOfData));
+        assert_eq!(reader.position(), 0);
+    }
+
+    #[test]
+    fn parse_invalid_tag() {
+        // A video tag with an undefined frame type, followed by an audio tag.
+        let data = [
+            0x09, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x72, 0x00, 0x00,
+            0x00, 0x00, 0x0D, 0x08, 0x00, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x2E, 0x11, 0x00, 0x00, 0x00, 0x0D,
+        ];
+        let mut reader = FlvReader::new(&data);
+
+        assert_eq!(
+            Tag::parse(&mut reader),
+            Ok(Tag {
+                timestamp: 0,
+                stream_id: 0,
+                data: TagData::Invalid {
+                    tag_type: 9,
+                    data: &[0x72, 0x00],
+                    error: Error::UnknownFrameType(7),
+                },
+            })
+        );
+        assert_eq!(reader.position(), 17);
+        assert!(matches!(
+            Tag::parse(&mut reader).map(|tag| tag.data),
+            Ok(TagData::Audio(_))
+        ));
+    }
+}
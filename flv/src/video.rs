@@ -0,0 +1,161 @@
+use crate::error::Error;
+use crate::reader::FlvReader;
+
+/// How a video frame relates to the frames around it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FrameType {
+    Keyframe = 1,
+    Interframe = 2,
+    InterframeDisposable = 3,
+    Generated = 4,
+    CommandFrame = 5,
+}
+
+impl TryFrom<u8> for FrameType {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Ok(match value {
+            1 => Self::Keyframe,
+            2 => Self::Interframe,
+            3 => Self::InterframeDisposable,
+            4 => Self::Generated,
+            5 => Self::CommandFrame,
+            unk => return Err(Error::UnknownFrameType(unk)),
+        })
+    }
+}
+
+/// The codec used to compress the video in a tag.
+///
+/// The numeric values match the `CodecID` field of the FLV specification,
+/// which is shared with SWF video codecs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CodecId {
+    Jpeg = 1,
+    SorensonH263 = 2,
+    ScreenVideo = 3,
+    On2Vp6 = 4,
+    On2Vp6Alpha = 5,
+    ScreenVideo2 = 6,
+    Avc = 7,
+}
+
+impl TryFrom<u8> for CodecId {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Ok(match value {
+            1 => Self::Jpeg,
+            2 => Self::SorensonH263,
+            3 => Self::ScreenVideo,
+            4 => Self::On2Vp6,
+            5 => Self::On2Vp6Alpha,
+            6 => Self::ScreenVideo2,
+            7 => Self::Avc,
+            unk => return Err(Error::UnknownVideoCodec(unk)),
+        })
+    }
+}
+
+/// The payload of a video tag.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VideoPacket<'a> {
+    /// Video data in the tag's declared codec.
+    ///
+    /// For VP6 codecs, this still includes the leading size adjustment byte
+    /// that FLV adds in front of the bitstream.
+    Data(&'a [u8]),
+
+    /// An AVC `AVCDecoderConfigurationRecord`.
+    AvcSequenceHeader(&'a [u8]),
+
+    /// One or more AVC NAL units.
+    AvcNalu {
+        composition_time_offset: i32,
+        data: &'a [u8],
+    },
+
+    /// The end of an AVC sequence.
+    AvcEndOfSequence,
+
+    /// A command frame, which carries no picture.
+    CommandFrame(&'a [u8]),
+}
+
+/// The contents of a video tag.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VideoData<'a> {
+    pub frame_type: FrameType,
+    pub codec_id: CodecId,
+    pub data: VideoPacket<'a>,
+}
+
+impl<'a> VideoData<'a> {
+    /// Parse a video tag body of the given length.
+    pub fn parse(reader: &mut FlvReader<'a>, data_size: u32) -> Result<Self, Error> {
+        let body = reader.read(data_size as usize)?;
+        let mut reader = FlvReader::new(body);
+
+        let format_spec = reader.read_u8()?;
+        let frame_type = FrameType::try_from(format_spec >> 4)?;
+        let codec_id = CodecId::try_from(format_spec & 0x0F)?;
+
+        let data = if frame_type == FrameType::CommandFrame {
+            VideoPacket::CommandFrame(reader.read(reader.remaining())?)
+        } else if codec_id == CodecId::Avc {
+            let packet_type = reader.read_u8()?;
+            let composition_time_offset = reader.read_i24()?;
+            match packet_type {
+                0 => VideoPacket::AvcSequenceHeader(reader.read(reader.remaining())?),
+                1 => VideoPacket::AvcNalu {
+                    composition_time_offset,
+                    data: reader.read(reader.remaining())?,
+                },
+                _ => VideoPacket::AvcEndOfSequence,
+            }
+        } else {
+            VideoPacket::Data(reader.read(reader.remaining())?)
+        };
+
+        Ok(Self {
+            frame_type,
+            codec_id,
+            data,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_h263_keyframe() {
+        let data = [0x12, 0x00, 0x00, 0x84];
+        let mut reader = FlvReader::new(&data);
+
+        assert_eq!(
+            VideoData::parse(&mut reader, data.len() as u32),
+            Ok(VideoData {
+                frame_type: FrameType::Keyframe,
+                codec_id: CodecId::SorensonH263,
+                data: VideoPacket::Data(&[0x00, 0x00, 0x84]),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_avc_nalu() {
+        let data = [0x27, 0x01, 0xFF, 0xFF, 0xFF, 0x65];
+        let mut reader = FlvReader::new(&data);
+
+        assert_eq!(
+            VideoData::parse(&mut reader, data.len() as u32).map(|v| v.data),
+            Ok(VideoPacket::AvcNalu {
+                composition_time_offset: -1,
+                data: &[0x65],
+            })
+        );
+    }
+}
@@ -490,11 +490,14 @@ impl RenderBackend for WebCanvasRenderBackend {
 }
 
 impl CommandHandler for WebCanvasRenderBackend {
-    fn render_bitmap(&mut self, bitmap: BitmapHandle, transform: Transform, smoothing: bool) {
+    fn render_bitmap(&mut self, bitmap: BitmapHandle, mut transform: Transform, smoothing: bool) {
         if self.mask_state == MaskState::ClearMask {
             return;
         }
 
+        // The canvas can't draw in perspective, so 3D transforms are flattened.
+        transform.matrix = transform.flattened_matrix();
+
         self.context.set_image_smoothing_enabled(smoothing);
 
         self.set_transform(&transform.matrix);
@@ -510,7 +513,9 @@ impl CommandHandler for WebCanvasRenderBackend {
         panic!("Stage3D should not have been created on canvas backend")
     }
 
-    fn render_shape(&mut self, shape: ShapeHandle, transform: Transform) {
+    fn render_shape(&mut self, shape: ShapeHandle, mut transform: Transform) {
+        transform.matrix = transform.flattened_matrix();
+
         let shape = as_shape_data(&shape);
         match &self.mask_state {
             MaskState::DrawContent => {
@@ -822,48 +827,8 @@ fn swf_shape_to_canvas_commands(
                     bounds_viewbox_matrix.unchecked_ref(),
                 );
 
-                let fill_style = match style {
-                    FillStyle::Color(color) => CanvasFillStyle::Color(color.into()),
-                    FillStyle::LinearGradient(gradient) => CanvasFillStyle::Gradient(
-                        create_linear_gradient(&backend.context, gradient, true)
-                            .expect("Couldn't create linear gradient"),
-                    ),
-                    FillStyle::RadialGradient(gradient) => CanvasFillStyle::Gradient(
-                        create_radial_gradient(&backend.context, gradient, 0.0, true)
-                            .expect("Couldn't create radial gradient"),
-                    ),
-                    FillStyle::FocalGradient {
-                        gradient,
-                        focal_point,
-                    } => CanvasFillStyle::Gradient(
-                        create_radial_gradient(
-                            &backend.context,
-                            gradient,
-                            focal_point.to_f64(),
-                            true,
-                        )
-                        .expect("Couldn't create radial gradient"),
-                    ),
-                    FillStyle::Bitmap {
-                        id,
-                        matrix,
-                        is_smoothed,
-                        is_repeating,
-                    } => {
-                        let bitmap = if let Some(bitmap) = create_bitmap_pattern(
-                            *id,
-                            *matrix,
-                            *is_smoothed,
-                            *is_repeating,
-                            bitmap_source,
-                            backend,
-                        ) {
-                            bitmap
-                        } else {
-                            continue;
-                        };
-                        CanvasFillStyle::Bitmap(bitmap)
-                    }
+                let Some(fill_style) = create_fill_style(style, bitmap_source, backend) else {
+                    continue;
                 };
 
                 canvas_data.push(CanvasDrawCommand::Fill {
@@ -871,6 +836,87 @@ fn swf_shape_to_canvas_commands(
                     fill_style,
                 });
             }
+            DrawPath::Triangles {
+                style,
+                vertices,
+                indices,
+                uvs,
+            } => {
+                // Each triangle is its own path, as overlapping triangles would cancel out
+                // with the even-odd rule.
+                for triangle in indices.chunks_exact(3) {
+                    let Some(points) = triangle
+                        .iter()
+                        .map(|&i| vertices.get(i as usize).copied())
+                        .collect::<Option<Vec<_>>>()
+                    else {
+                        continue;
+                    };
+
+                    let fill_style = match (style, uvs) {
+                        (
+                            FillStyle::Bitmap {
+                                id,
+                                is_smoothed,
+                                is_repeating,
+                                ..
+                            },
+                            Some(uvs),
+                        ) => {
+                            let Some(size) = bitmap_source.bitmap_size(*id) else {
+                                continue;
+                            };
+                            let triangle_uvs = [
+                                uvs.get(triangle[0] as usize),
+                                uvs.get(triangle[1] as usize),
+                                uvs.get(triangle[2] as usize),
+                            ];
+                            let [Some(uv0), Some(uv1), Some(uv2)] = triangle_uvs else {
+                                continue;
+                            };
+                            let Some(matrix) = triangle_bitmap_matrix(
+                                [points[0], points[1], points[2]],
+                                [*uv0, *uv1, *uv2],
+                                size.width.into(),
+                                size.height.into(),
+                            ) else {
+                                continue;
+                            };
+                            let Some(bitmap) = create_bitmap_pattern(
+                                *id,
+                                matrix,
+                                *is_smoothed,
+                                *is_repeating,
+                                bitmap_source,
+                                backend,
+                            ) else {
+                                continue;
+                            };
+                            CanvasFillStyle::Bitmap(bitmap)
+                        }
+                        _ => {
+                            let Some(fill_style) = create_fill_style(style, bitmap_source, backend)
+                            else {
+                                continue;
+                            };
+                            fill_style
+                        }
+                    };
+
+                    let mut commands = vec![DrawCommand::MoveTo(points[0])];
+                    commands.extend(points[1..].iter().map(|&point| DrawCommand::LineTo(point)));
+                    commands.push(DrawCommand::LineTo(points[0]));
+                    let canvas_path = Path2d::new().expect("Path2d constructor must succeed");
+                    canvas_path.add_path_with_transformation(
+                        &draw_commands_to_path2d(&commands, true),
+                        bounds_viewbox_matrix.unchecked_ref(),
+                    );
+                    canvas_data.push(CanvasDrawCommand::Fill {
+                        path: canvas_path,
+                        fill_style,
+                    });
+                }
+            }
             DrawPath::Stroke {
                 commands,
                 style,
@@ -947,6 +993,89 @@ fn swf_shape_to_canvas_commands(
     canvas_data
 }
 
+/// Creates the canvas fill style for an SWF fill style.
+///
+/// Returns `None` if the fill uses a bitmap that doesn't exist.
+fn create_fill_style(
+    style: &swf::FillStyle,
+    bitmap_source: &dyn BitmapSource,
+    backend: &mut WebCanvasRenderBackend,
+) -> Option<CanvasFillStyle> {
+    use swf::FillStyle;
+
+    let fill_style = match style {
+        FillStyle::Color(color) => CanvasFillStyle::Color(color.into()),
+        FillStyle::LinearGradient(gradient) => CanvasFillStyle::Gradient(
+            create_linear_gradient(&backend.context, gradient, true)
+                .expect("Couldn't create linear gradient"),
+        ),
+        FillStyle::RadialGradient(gradient) => CanvasFillStyle::Gradient(
+            create_radial_gradient(&backend.context, gradient, 0.0, true)
+                .expect("Couldn't create radial gradient"),
+        ),
+        FillStyle::FocalGradient {
+            gradient,
+            focal_point,
+        } => CanvasFillStyle::Gradient(
+            create_radial_gradient(&backend.context, gradient, focal_point.to_f64(), true)
+                .expect("Couldn't create radial gradient"),
+        ),
+        FillStyle::Bitmap {
+            id,
+            matrix,
+            is_smoothed,
+            is_repeating,
+        } => CanvasFillStyle::Bitmap(create_bitmap_pattern(
+            *id,
+            *matrix,
+            *is_smoothed,
+            *is_repeating,
+            bitmap_source,
+            backend,
+        )?),
+    };
+    Some(fill_style)
+}
+
+/// Returns the bitmap fill matrix that maps the given texture coordinates to the corners
+/// of a triangle.
+///
+/// Returns `None` if the texture coordinates don't form a triangle.
+fn triangle_bitmap_matrix(
+    vertices: [Point<Twips>; 3],
+    uvs: [[f32; 2]; 3],
+    width: f64,
+    height: f64,
+) -> Option<swf::Matrix> {
+    let texel = |uv: [f32; 2]| (f64::from(uv[0]) * width, f64::from(uv[1]) * height);
+    let (u0, v0) = texel(uvs[0]);
+    let (u1, v1) = texel(uvs[1]);
+    let (u2, v2) = texel(uvs[2]);
+    let (du1, dv1, du2, dv2) = (u1 - u0, v1 - v0, u2 - u0, v2 - v0);
+    let det = du1 * dv2 - du2 * dv1;
+    if det.abs() < f64::EPSILON {
+        return None;
+    }
+
+    let position = |point: Point<Twips>| (point.x.get() as f64, point.y.get() as f64);
+    let (x0, y0) = position(vertices[0]);
+    let (x1, y1) = position(vertices[1]);
+    let (x2, y2) = position(vertices[2]);
+    let (dx1, dy1, dx2, dy2) = (x1 - x0, y1 - y0, x2 - x0, y2 - y0);
+    let a = (dx1 * dv2 - dx2 * dv1) / det;
+    let b = (dy1 * dv2 - dy2 * dv1) / det;
+    let c = (dx2 * du1 - dx1 * du2) / det;
+    let d = (dy2 * du1 - dy1 * du2) / det;
+    Some(swf::Matrix {
+        a: swf::Fixed16::from_f64(a),
+        b: swf::Fixed16::from_f64(b),
+        c: swf::Fixed16::from_f64(c),
+        d: swf::Fixed16::from_f64(d),
+        tx: Twips::new((x0 - a * u0 - c * v0) as i32),
+        ty: Twips::new((y0 - b * u0 - d * v0) as i32),
+    })
+}
+
 fn create_linear_gradient(
     context: &CanvasRenderingContext2d,
     gradient: &swf::Gradient,
@@ -0,0 +1,291 @@
+use crate::bitmap::{as_bitmap, Pixmap, SoftwareBitmap};
+use crate::mesh::Mesh;
+use crate::raster::SampleGrid;
+use crate::surface::Surface;
+use ruffle_render::backend::{Context3D, RenderBackend, ShapeHandle, ViewportDimensions};
+use ruffle_render::bitmap::{
+    Bitmap, BitmapHandle, BitmapSource, PixelRegion, RgbaBufRead, SyncHandle,
+};
+use ruffle_render::commands::CommandList;
+use ruffle_render::error::Error;
+use ruffle_render::quality::StageQuality;
+use ruffle_render::shape_utils::DistilledShape;
+use ruffle_render::tessellator::ShapeTessellator;
+use std::borrow::Cow;
+use std::sync::Arc;
+use swf::Color;
+
+/// A `RenderBackend` that renders into an in-memory pixmap using only the CPU.
+pub struct SoftwareRenderBackend {
+    target: Pixmap,
+    shape_tessellator: ShapeTessellator,
+    quality: StageQuality,
+    viewport_scale_factor: f64,
+}
+
+impl SoftwareRenderBackend {
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            target: Pixmap::new(width, height),
+            shape_tessellator: ShapeTessellator::new(),
+            quality: StageQuality::Low,
+            viewport_scale_factor: 1.0,
+        }
+    }
+
+    /// Returns the most recently submitted frame, with straight (non-premultiplied) alpha.
+    pub fn capture_frame(&self) -> Option<image::RgbaImage> {
+        self.target.to_image()
+    }
+}
+
+/// The result of a `render_offscreen` call.
+///
+/// Rendering is done synchronously, so this just holds a copy of the rendered pixels.
+#[derive(Debug)]
+struct SoftwareSyncHandle {
+    pixels: Vec<u8>,
+    width: u32,
+}
+
+impl SyncHandle for SoftwareSyncHandle {
+    fn retrieve_offscreen_texture(self: Box<Self>, with_rgba: RgbaBufRead) -> Result<(), Error> {
+        with_rgba(&self.pixels, self.width * 4);
+        Ok(())
+    }
+}
+
+impl RenderBackend for SoftwareRenderBackend {
+    fn viewport_dimensions(&self) -> ViewportDimensions {
+        ViewportDimensions {
+            width: self.target.width(),
+            height: self.target.height(),
+            scale_factor: self.viewport_scale_factor,
+        }
+    }
+
+    fn set_viewport_dimensions(&mut self, dimensions: ViewportDimensions) {
+        self.target = Pixmap::new(dimensions.width, dimensions.height);
+        self.viewport_scale_factor = dimensions.scale_factor;
+    }
+
+    fn register_shape(
+        &mut self,
+        shape: DistilledShape,
+        bitmap_source: &dyn BitmapSource,
+    ) -> ShapeHandle {
+        let lyon_mesh = self
+            .shape_tessellator
+            .tessellate_shape(shape, bitmap_source);
+        ShapeHandle(Arc::new(Mesh::new(self, bitmap_source, lyon_mesh)))
+    }
+
+    fn render_offscreen(
+        &mut self,
+        handle: BitmapHandle,
+        commands: CommandList,
+        quality: StageQuality,
+        bounds: PixelRegion,
+    ) -> Option<Box<dyn SyncHandle>> {
+        let bitmap = as_bitmap(&handle);
+
+        // Draw onto a copy, as the commands may read from the bitmap itself.
+        let mut target = bitmap.pixmap().clone();
+        let grid = SampleGrid::new(quality.sample_count());
+        Surface::new(&mut target, &grid).draw_commands(commands);
+
+        let mut bounds = bounds;
+        bounds.clamp(target.width(), target.height());
+        let sync_handle = SoftwareSyncHandle {
+            pixels: target.copy_region(bounds),
+            width: bounds.width(),
+        };
+        *bitmap.pixmap_mut() = target;
+
+        Some(Box::new(sync_handle))
+    }
+
+    fn submit_frame(&mut self, clear: Color, commands: CommandList) {
+        let alpha = f32::from(clear.a) / 255.0;
+        self.target.fill([
+            f32::from(clear.r) / 255.0 * alpha,
+            f32::from(clear.g) / 255.0 * alpha,
+            f32::from(clear.b) / 255.0 * alpha,
+            alpha,
+        ]);
+
+        let grid = SampleGrid::new(self.quality.sample_count());
+        Surface::new(&mut self.target, &grid).draw_commands(commands);
+    }
+
+    fn register_bitmap(&mut self, bitmap: Bitmap) -> Result<BitmapHandle, Error> {
+        Ok(BitmapHandle(Arc::new(SoftwareBitmap::new(
+            Pixmap::from_bitmap(bitmap),
+        ))))
+    }
+
+    fn update_texture(
+        &mut self,
+        handle: &BitmapHandle,
+        bitmap: Bitmap,
+        region: PixelRegion,
+    ) -> Result<(), Error> {
+        as_bitmap(handle).pixmap_mut().update(bitmap, region);
+        Ok(())
+    }
+
+    fn create_context3d(&mut self) -> Result<Box<dyn Context3D>, Error> {
+        Err(Error::Unimplemented("createContext3D".into()))
+    }
+
+    fn context3d_present(&mut self, _context: &mut dyn Context3D) -> Result<(), Error> {
+        Err(Error::Unimplemented("Context3D.present".into()))
+    }
+
+    fn debug_info(&self) -> Cow<'static, str> {
+        Cow::Owned(format!(
+            "Renderer: Software\nViewport: {}x{}\nSample count: {}",
+            self.target.width(),
+            self.target.height(),
+            self.quality.sample_count()
+        ))
+    }
+
+    fn name(&self) -> &'static str {
+        "software"
+    }
+
+    fn set_quality(&mut self, quality: StageQuality) {
+        self.quality = quality;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ruffle_render::commands::CommandHandler;
+    use ruffle_render::matrix::Matrix;
+    use ruffle_render::transform::Transform;
+    use swf::{BlendMode, ColorTransform, Twips};
+
+    fn rect(commands: &mut CommandList, color: Color, x: i32, y: i32, width: f32, height: f32) {
+        commands.draw_rect(
+            color,
+            Matrix::translate(Twips::from_pixels_i32(x), Twips::from_pixels_i32(y))
+                * Matrix::scale(width, height),
+        );
+    }
+
+    fn pixel(backend: &SoftwareRenderBackend, x: u32, y: u32) -> [u8; 4] {
+        backend
+            .capture_frame()
+            .expect("Frame was rendered")
+            .get_pixel(x, y)
+            .0
+    }
+
+    #[test]
+    fn draw_rect() {
+        let mut backend = SoftwareRenderBackend::new(4, 4);
+        let mut commands = CommandList::new();
+        rect(&mut commands, Color::RED, 1, 1, 2.0, 2.0);
+        backend.submit_frame(Color::WHITE, commands);
+
+        assert_eq!(pixel(&backend, 0, 0), [255, 255, 255, 255]);
+        assert_eq!(pixel(&backend, 1, 1), [255, 0, 0, 255]);
+        assert_eq!(pixel(&backend, 2, 2), [255, 0, 0, 255]);
+        assert_eq!(pixel(&backend, 3, 3), [255, 255, 255, 255]);
+    }
+
+    #[test]
+    fn mask() {
+        let mut backend = SoftwareRenderBackend::new(4, 4);
+        let mut commands = CommandList::new();
+        commands.push_mask();
+        rect(&mut commands, Color::WHITE, 0, 0, 2.0, 4.0);
+        commands.activate_mask();
+        rect(&mut commands, Color::RED, 0, 0, 4.0, 4.0);
+        commands.deactivate_mask();
+        rect(&mut commands, Color::WHITE, 0, 0, 2.0, 4.0);
+        commands.pop_mask();
+        backend.submit_frame(Color::BLACK, commands);
+
+        assert_eq!(pixel(&backend, 1, 1), [255, 0, 0, 255]);
+        assert_eq!(pixel(&backend, 2, 1), [0, 0, 0, 255]);
+    }
+
+    #[test]
+    fn blend_mode() {
+        let mut backend = SoftwareRenderBackend::new(4, 4);
+        let mut commands = CommandList::new();
+        let mut layer = CommandList::new();
+        rect(&mut layer, Color::WHITE, 0, 0, 2.0, 4.0);
+        rect(&mut commands, Color::RED, 0, 0, 4.0, 4.0);
+        commands.blend(layer, BlendMode::Difference);
+        backend.submit_frame(Color::BLACK, commands);
+
+        assert_eq!(pixel(&backend, 1, 1), [0, 255, 255, 255]);
+        assert_eq!(pixel(&backend, 3, 1), [255, 0, 0, 255]);
+    }
+
+    #[test]
+    fn bitmap_with_color_transform() {
+        let mut backend = SoftwareRenderBackend::new(4, 4);
+        let bitmap = backend
+            .register_bitmap(Bitmap::new(
+                2,
+                1,
+                ruffle_render::bitmap::BitmapFormat::Rgba,
+                vec![255, 0, 0, 255, 0, 0, 255, 255],
+            ))
+            .expect("Bitmap is valid");
+
+        let mut commands = CommandList::new();
+        let mut color_transform = ColorTransform::IDENTITY;
+        color_transform.g_add = 255;
+        commands.render_bitmap(
+            bitmap,
+            Transform {
+                matrix: Matrix::scale(2.0, 4.0),
+                color_transform,
+                matrix3d: None,
+            },
+            false,
+        );
+        backend.submit_frame(Color::BLACK, commands);
+
+        assert_eq!(pixel(&backend, 0, 0), [255, 255, 0, 255]);
+        assert_eq!(pixel(&backend, 3, 3), [0, 255, 255, 255]);
+    }
+
+    #[test]
+    fn render_offscreen() {
+        let mut backend = SoftwareRenderBackend::new(1, 1);
+        let bitmap = backend
+            .register_bitmap(Bitmap::new(
+                2,
+                2,
+                ruffle_render::bitmap::BitmapFormat::Rgba,
+                vec![0; 16],
+            ))
+            .expect("Bitmap is valid");
+
+        let mut commands = CommandList::new();
+        rect(&mut commands, Color::BLUE, 1, 0, 1.0, 2.0);
+        let sync_handle = backend
+            .render_offscreen(
+                bitmap,
+                commands,
+                StageQuality::Low,
+                PixelRegion::for_whole_size(2, 2),
+            )
+            .expect("Software rendering is always supported");
+
+        sync_handle
+            .retrieve_offscreen_texture(Box::new(|pixels, row_bytes| {
+                assert_eq!(row_bytes, 8);
+                assert_eq!(&pixels[0..8], &[0, 0, 0, 0, 0, 0, 255, 255]);
+            }))
+            .expect("Pixels are available");
+    }
+}
@@ -0,0 +1,200 @@
+use ruffle_render::bitmap::{Bitmap, BitmapHandle, BitmapHandleImpl, PixelRegion};
+use std::cell::{Ref, RefCell, RefMut};
+
+/// A premultiplied RGBA color, with each component in the range `0.0..=1.0`.
+pub type Rgba = [f32; 4];
+
+/// A block of premultiplied RGBA pixels that can be drawn onto.
+#[derive(Clone, Debug)]
+pub struct Pixmap {
+    width: u32,
+    height: u32,
+    data: Vec<u8>,
+}
+
+impl Pixmap {
+    /// Creates a fully transparent pixmap.
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            data: vec![0; width as usize * height as usize * 4],
+        }
+    }
+
+    pub fn from_bitmap(bitmap: Bitmap) -> Self {
+        let bitmap = bitmap.to_rgba();
+        Self {
+            width: bitmap.width(),
+            height: bitmap.height(),
+            data: bitmap.data().to_vec(),
+        }
+    }
+
+    #[inline]
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    #[inline]
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Fills the whole pixmap with a single color.
+    pub fn fill(&mut self, color: Rgba) {
+        let bytes = to_bytes(color);
+        for pixel in self.data.chunks_exact_mut(4) {
+            pixel.copy_from_slice(&bytes);
+        }
+    }
+
+    /// Copies the given region of a same-sized bitmap into this pixmap.
+    pub fn update(&mut self, bitmap: Bitmap, mut region: PixelRegion) {
+        let bitmap = bitmap.to_rgba();
+        if bitmap.width() != self.width || bitmap.height() != self.height {
+            *self = Self::from_bitmap(bitmap);
+            return;
+        }
+
+        region.clamp(self.width, self.height);
+        let row_start = region.x_min as usize * 4;
+        let row_end = region.x_max as usize * 4;
+        for y in region.y_min..region.y_max {
+            let offset = y as usize * self.width as usize * 4;
+            self.data[offset + row_start..offset + row_end]
+                .copy_from_slice(&bitmap.data()[offset + row_start..offset + row_end]);
+        }
+    }
+
+    /// Copies the pixels of the given region into a tightly packed buffer.
+    pub fn copy_region(&self, mut region: PixelRegion) -> Vec<u8> {
+        region.clamp(self.width, self.height);
+        let mut pixels = Vec::with_capacity(region.width() as usize * region.height() as usize * 4);
+        for y in region.y_min..region.y_max {
+            let offset = y as usize * self.width as usize * 4;
+            pixels.extend_from_slice(
+                &self.data[offset + region.x_min as usize * 4..offset + region.x_max as usize * 4],
+            );
+        }
+        pixels
+    }
+
+    /// Returns the color of the pixel at the given position.
+    #[inline]
+    pub fn get(&self, x: u32, y: u32) -> Rgba {
+        let offset = (y as usize * self.width as usize + x as usize) * 4;
+        let pixel = &self.data[offset..offset + 4];
+        [
+            f32::from(pixel[0]) / 255.0,
+            f32::from(pixel[1]) / 255.0,
+            f32::from(pixel[2]) / 255.0,
+            f32::from(pixel[3]) / 255.0,
+        ]
+    }
+
+    /// Sets the color of the pixel at the given position.
+    #[inline]
+    pub fn set(&mut self, x: u32, y: u32, color: Rgba) {
+        let offset = (y as usize * self.width as usize + x as usize) * 4;
+        self.data[offset..offset + 4].copy_from_slice(&to_bytes(color));
+    }
+
+    /// Samples this pixmap at the given position, in pixels.
+    ///
+    /// Coordinates outside of the pixmap are either wrapped around or clamped to the edge.
+    pub fn sample(&self, x: f32, y: f32, smoothed: bool, repeating: bool) -> Rgba {
+        if self.width == 0 || self.height == 0 {
+            return [0.0; 4];
+        }
+
+        if !smoothed {
+            let x = self.wrap_x(x.floor() as i64, repeating);
+            let y = self.wrap_y(y.floor() as i64, repeating);
+            return self.get(x, y);
+        }
+
+        // Bilinear filtering between the four nearest texel centers.
+        let x = x - 0.5;
+        let y = y - 0.5;
+        let (x0, y0) = (x.floor(), y.floor());
+        let (fx, fy) = (x - x0, y - y0);
+        let (x0, y0) = (x0 as i64, y0 as i64);
+        let (left, right) = (self.wrap_x(x0, repeating), self.wrap_x(x0 + 1, repeating));
+        let (top, bottom) = (self.wrap_y(y0, repeating), self.wrap_y(y0 + 1, repeating));
+
+        let top_left = self.get(left, top);
+        let top_right = self.get(right, top);
+        let bottom_left = self.get(left, bottom);
+        let bottom_right = self.get(right, bottom);
+
+        let mut color = [0.0; 4];
+        for i in 0..4 {
+            let top = lerp(top_left[i], top_right[i], fx);
+            let bottom = lerp(bottom_left[i], bottom_right[i], fx);
+            color[i] = lerp(top, bottom, fy);
+        }
+        color
+    }
+
+    #[inline]
+    fn wrap_x(&self, x: i64, repeating: bool) -> u32 {
+        wrap(x, self.width, repeating)
+    }
+
+    #[inline]
+    fn wrap_y(&self, y: i64, repeating: bool) -> u32 {
+        wrap(y, self.height, repeating)
+    }
+
+    /// Converts this pixmap into an image with straight (non-premultiplied) alpha.
+    pub fn to_image(&self) -> Option<image::RgbaImage> {
+        let mut bytes = self.data.clone();
+        ruffle_render::utils::unmultiply_alpha_rgba(&mut bytes);
+        image::RgbaImage::from_raw(self.width, self.height, bytes)
+    }
+}
+
+#[inline]
+fn wrap(value: i64, size: u32, repeating: bool) -> u32 {
+    if repeating {
+        value.rem_euclid(i64::from(size)) as u32
+    } else {
+        value.clamp(0, i64::from(size) - 1) as u32
+    }
+}
+
+#[inline]
+pub fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + (b - a) * t
+}
+
+#[inline]
+fn to_bytes(color: Rgba) -> [u8; 4] {
+    color.map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8)
+}
+
+/// The `BitmapHandle` implementation of this backend.
+#[derive(Debug)]
+pub struct SoftwareBitmap(RefCell<Pixmap>);
+
+impl SoftwareBitmap {
+    pub fn new(pixmap: Pixmap) -> Self {
+        Self(RefCell::new(pixmap))
+    }
+
+    pub fn pixmap(&self) -> Ref<'_, Pixmap> {
+        self.0.borrow()
+    }
+
+    pub fn pixmap_mut(&self) -> RefMut<'_, Pixmap> {
+        self.0.borrow_mut()
+    }
+}
+
+impl BitmapHandleImpl for SoftwareBitmap {}
+
+pub fn as_bitmap(handle: &BitmapHandle) -> &SoftwareBitmap {
+    <dyn BitmapHandleImpl>::downcast_ref(&*handle.0)
+        .expect("Bitmap handle must be a software bitmap")
+}
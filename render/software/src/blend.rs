@@ -0,0 +1,142 @@
+use crate::bitmap::Rgba;
+use swf::BlendMode;
+
+/// Blends the premultiplied color `src` onto the premultiplied color `dst`.
+///
+/// The formulas match the blend shaders of the wgpu backend.
+pub fn blend(src: Rgba, dst: Rgba, mode: BlendMode) -> Rgba {
+    let (sa, da) = (src[3], dst[3]);
+    match mode {
+        BlendMode::Normal | BlendMode::Layer => over(src, dst),
+        BlendMode::Multiply => separable(src, dst, |s, d| s * d),
+        BlendMode::Screen => separable(src, dst, |s, d| s + d - s * d),
+        BlendMode::Lighten => separable(src, dst, f32::max),
+        BlendMode::Darken => separable(src, dst, f32::min),
+        BlendMode::Difference => separable(src, dst, |s, d| (d - s).abs()),
+        BlendMode::Invert => separable(src, dst, |_, d| 1.0 - d),
+        BlendMode::Overlay => separable(src, dst, |s, d| {
+            if d <= 0.5 {
+                2.0 * s * d
+            } else {
+                1.0 - 2.0 * (1.0 - d) * (1.0 - s)
+            }
+        }),
+        BlendMode::HardLight => separable(src, dst, |s, d| {
+            if s <= 0.5 {
+                2.0 * s * d
+            } else {
+                1.0 - 2.0 * (1.0 - d) * (1.0 - s)
+            }
+        }),
+        BlendMode::Add => {
+            if sa <= 0.0 {
+                return dst;
+            }
+            let mut out = [0.0; 4];
+            for i in 0..3 {
+                out[i] = (src[i] + dst[i]).min(1.0);
+            }
+            out[3] = sa + da * (1.0 - sa);
+            out
+        }
+        BlendMode::Subtract => {
+            if sa <= 0.0 {
+                return dst;
+            }
+            let mut out = [0.0; 4];
+            for i in 0..3 {
+                out[i] = (dst[i] - src[i]).max(0.0);
+            }
+            out[3] = sa + da * (1.0 - sa);
+            out
+        }
+        BlendMode::Alpha => {
+            if sa <= 0.0 {
+                return dst;
+            }
+            [dst[0] * sa, dst[1] * sa, dst[2] * sa, da * sa]
+        }
+        BlendMode::Erase => {
+            if sa <= 0.0 {
+                return dst;
+            }
+            let keep = 1.0 - sa;
+            [dst[0] * keep, dst[1] * keep, dst[2] * keep, da * keep]
+        }
+    }
+}
+
+/// The standard "source over" blend of premultiplied colors.
+#[inline]
+pub fn over(src: Rgba, dst: Rgba) -> Rgba {
+    let keep = 1.0 - src[3];
+    [
+        src[0] + dst[0] * keep,
+        src[1] + dst[1] * keep,
+        src[2] + dst[2] * keep,
+        src[3] + dst[3] * keep,
+    ]
+}
+
+/// Blends using a function of the straight (non-premultiplied) color components.
+fn separable(src: Rgba, dst: Rgba, f: impl Fn(f32, f32) -> f32) -> Rgba {
+    let (sa, da) = (src[3], dst[3]);
+    if sa <= 0.0 {
+        return dst;
+    }
+
+    let mut out = [0.0; 4];
+    for i in 0..3 {
+        let s = src[i] / sa;
+        let d = if da > 0.0 { dst[i] / da } else { 0.0 };
+        out[i] = src[i] * (1.0 - da) + dst[i] * (1.0 - sa) + sa * da * f(s, d);
+    }
+    out[3] = sa + da * (1.0 - sa);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RED: Rgba = [1.0, 0.0, 0.0, 1.0];
+    const HALF_BLUE: Rgba = [0.0, 0.0, 0.5, 0.5];
+    const TRANSPARENT: Rgba = [0.0; 4];
+
+    #[test]
+    fn normal() {
+        assert_eq!(
+            blend(HALF_BLUE, RED, BlendMode::Normal),
+            [0.5, 0.0, 0.5, 1.0]
+        );
+        assert_eq!(blend(TRANSPARENT, RED, BlendMode::Normal), RED);
+    }
+
+    #[test]
+    fn multiply() {
+        let gray = [0.5, 0.5, 0.5, 1.0];
+        assert_eq!(blend(gray, RED, BlendMode::Multiply), [0.5, 0.0, 0.0, 1.0]);
+    }
+
+    #[test]
+    fn erase_and_alpha() {
+        assert_eq!(
+            blend(HALF_BLUE, RED, BlendMode::Erase),
+            [0.5, 0.0, 0.0, 0.5]
+        );
+        assert_eq!(
+            blend(HALF_BLUE, RED, BlendMode::Alpha),
+            [0.5, 0.0, 0.0, 0.5]
+        );
+        assert_eq!(blend(TRANSPARENT, RED, BlendMode::Alpha), RED);
+    }
+
+    #[test]
+    fn difference() {
+        let white = [1.0; 4];
+        assert_eq!(
+            blend(white, RED, BlendMode::Difference),
+            [0.0, 1.0, 1.0, 1.0]
+        );
+    }
+}
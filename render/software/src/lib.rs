@@ -0,0 +1,16 @@
+//! A render backend that draws entirely on the CPU.
+//!
+//! This backend doesn't need a GPU, a graphics driver or a browser, which
+//! makes it useful for headless environments such as CI machines.
+//! It trades speed for portability, and is not intended for interactive use.
+
+#![deny(clippy::unwrap_used)]
+
+pub mod backend;
+mod bitmap;
+mod blend;
+mod mesh;
+mod raster;
+mod surface;
+
+pub use backend::SoftwareRenderBackend;
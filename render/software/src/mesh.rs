@@ -0,0 +1,378 @@
+use crate::bitmap::{as_bitmap, lerp, Pixmap, Rgba};
+use crate::raster::Point;
+use ruffle_render::backend::{RenderBackend, ShapeHandle, ShapeHandleImpl};
+use ruffle_render::bitmap::{BitmapHandle, BitmapSource};
+use ruffle_render::shape_utils::GradientType;
+use ruffle_render::tessellator::{
+    Draw as LyonDraw, DrawType as TessDrawType, Gradient, TexturedTriangles,
+};
+use swf::{Color, GradientInterpolation, GradientSpread};
+
+/// How many colors to precompute for a gradient.
+const GRADIENT_SIZE: usize = 256;
+
+/// A shape, tessellated into triangles.
+#[derive(Debug)]
+pub struct Mesh {
+    pub fills: Vec<Fill>,
+}
+
+impl ShapeHandleImpl for Mesh {}
+
+pub fn as_mesh(handle: &ShapeHandle) -> &Mesh {
+    <dyn ShapeHandleImpl>::downcast_ref(&*handle.0).expect("Shape handle must be a software Mesh")
+}
+
+/// A set of triangles that are filled with the same paint.
+#[derive(Debug)]
+pub struct Fill {
+    pub paint: Paint,
+
+    /// The triangles of this fill, in shape space (pixels).
+    pub triangles: Vec<[Point; 3]>,
+
+    /// Whether this fill is drawn when the shape is used as a mask.
+    /// Strokes are omitted from masks.
+    pub is_mask: bool,
+}
+
+#[derive(Debug)]
+pub enum Paint {
+    Color(Color),
+    Gradient(GradientPaint),
+    Bitmap(BitmapPaint),
+}
+
+impl Paint {
+    /// Returns the straight-alpha color of this paint at the given point in shape space.
+    pub fn sample(&self, point: Point, bitmap: Option<&Pixmap>) -> Rgba {
+        match self {
+            Paint::Color(color) => [
+                f32::from(color.r) / 255.0,
+                f32::from(color.g) / 255.0,
+                f32::from(color.b) / 255.0,
+                f32::from(color.a) / 255.0,
+            ],
+            Paint::Gradient(gradient) => gradient.sample(point),
+            Paint::Bitmap(paint) => match bitmap {
+                Some(bitmap) => {
+                    let (u, v) = apply_uv_matrix(&paint.matrix, point);
+                    unmultiply(bitmap.sample(
+                        u * bitmap.width() as f32,
+                        v * bitmap.height() as f32,
+                        paint.is_smoothed,
+                        paint.is_repeating,
+                    ))
+                }
+                None => [0.0; 4],
+            },
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct GradientPaint {
+    /// Transforms from shape space to gradient space.
+    matrix: [[f32; 3]; 3],
+    gradient_type: GradientType,
+    repeat_mode: GradientSpread,
+    focal_point: f32,
+    interpolation: GradientInterpolation,
+    colors: Vec<Rgba>,
+}
+
+impl GradientPaint {
+    fn new(gradient: Gradient) -> Self {
+        Self {
+            matrix: gradient.matrix,
+            gradient_type: gradient.gradient_type,
+            repeat_mode: gradient.repeat_mode,
+            focal_point: gradient.focal_point.to_f32(),
+            interpolation: gradient.interpolation,
+            colors: gradient_colors(&gradient),
+        }
+    }
+
+    fn sample(&self, point: Point) -> Rgba {
+        let uv = apply_uv_matrix(&self.matrix, point);
+
+        // Calculate normalized `t` position in gradient, [0.0, 1.0] being the bounds of the ratios.
+        let mut t = match self.gradient_type {
+            GradientType::Linear => uv.0,
+            GradientType::Radial => (uv.0 * 2.0 - 1.0).hypot(uv.1 * 2.0 - 1.0),
+            GradientType::Focal => {
+                let uv = (uv.0 * 2.0 - 1.0, uv.1 * 2.0 - 1.0);
+                let focal_point = self.focal_point;
+                let d = (focal_point - uv.0, -uv.1);
+                let l = d.0.hypot(d.1);
+                let d = (d.0 / l, d.1 / l);
+                l / ((1.0 - focal_point * focal_point * d.1 * d.1).sqrt() + focal_point * d.0)
+            }
+        };
+
+        t = match self.repeat_mode {
+            GradientSpread::Pad => t.clamp(0.0, 1.0),
+            GradientSpread::Reflect => {
+                let t = t.abs();
+                if (t as i32) & 1 == 0 {
+                    t.fract()
+                } else {
+                    1.0 - t.fract()
+                }
+            }
+            GradientSpread::Repeat => t - t.floor(),
+        };
+        if !t.is_finite() {
+            t = 0.0;
+        }
+
+        // Linearly filter between the precomputed colors, like a texture lookup would.
+        let position = (t * GRADIENT_SIZE as f32 - 0.5).clamp(0.0, (GRADIENT_SIZE - 1) as f32);
+        let index = position as usize;
+        let next = (index + 1).min(GRADIENT_SIZE - 1);
+        let fraction = position - index as f32;
+        let mut color = [0.0; 4];
+        for (i, component) in color.iter_mut().enumerate() {
+            *component = lerp(self.colors[index][i], self.colors[next][i], fraction);
+        }
+
+        if self.interpolation == GradientInterpolation::LinearRgb {
+            for component in &mut color[..3] {
+                *component = linear_to_srgb(*component);
+            }
+        }
+        color
+    }
+}
+
+#[derive(Debug)]
+pub struct BitmapPaint {
+    /// Transforms from shape space to UV space.
+    matrix: [[f32; 3]; 3],
+    pub bitmap: BitmapHandle,
+    is_smoothed: bool,
+    is_repeating: bool,
+}
+
+impl Mesh {
+    pub fn new(
+        backend: &mut dyn RenderBackend,
+        source: &dyn BitmapSource,
+        lyon_mesh: Vec<LyonDraw>,
+    ) -> Self {
+        let mut fills = Vec::new();
+        for draw in lyon_mesh {
+            let paint = match draw.draw_type {
+                TessDrawType::Color => None,
+                TessDrawType::Gradient(gradient) => {
+                    Some(Paint::Gradient(GradientPaint::new(gradient)))
+                }
+                TessDrawType::Bitmap(bitmap) => {
+                    let Some(handle) = source.bitmap_handle(bitmap.bitmap_id, backend) else {
+                        tracing::warn!(
+                            "Couldn't get bitmap handle for bitmap fill {}",
+                            bitmap.bitmap_id
+                        );
+                        continue;
+                    };
+                    Some(Paint::Bitmap(BitmapPaint {
+                        matrix: bitmap.matrix,
+                        bitmap: handle,
+                        is_smoothed: bitmap.is_smoothed,
+                        is_repeating: bitmap.is_repeating,
+                    }))
+                }
+                TessDrawType::TexturedTriangles(textured) => {
+                    let Some(handle) = source.bitmap_handle(textured.bitmap_id, backend) else {
+                        tracing::warn!(
+                            "Couldn't get bitmap handle for bitmap fill {}",
+                            textured.bitmap_id
+                        );
+                        continue;
+                    };
+                    // Paint each triangle with the bitmap matrix that maps it onto its UVs.
+                    for triangle in draw.indices.chunks_exact(3) {
+                        let vertices = [0, 1, 2].map(|i| &draw.vertices[triangle[i] as usize]);
+                        let uvs = [0, 1, 2].map(|i| textured.uvs[triangle[i] as usize]);
+                        let Some(matrix) =
+                            TexturedTriangles::triangle_matrix(vertices.map(|v| [v.x, v.y]), uvs)
+                        else {
+                            continue;
+                        };
+                        fills.push(Fill {
+                            paint: Paint::Bitmap(BitmapPaint {
+                                matrix,
+                                bitmap: handle.clone(),
+                                is_smoothed: textured.is_smoothed,
+                                is_repeating: textured.is_repeating,
+                            }),
+                            triangles: vec![vertices.map(|v| Point::new(v.x, v.y))],
+                            is_mask: true,
+                        });
+                    }
+                    continue;
+                }
+            };
+
+            let triangles = draw
+                .indices
+                .chunks_exact(3)
+                .enumerate()
+                .map(|(i, triangle)| {
+                    let vertices = [
+                        &draw.vertices[triangle[0] as usize],
+                        &draw.vertices[triangle[1] as usize],
+                        &draw.vertices[triangle[2] as usize],
+                    ];
+                    let is_mask = (i * 3) < draw.mask_index_count as usize;
+                    (vertices, is_mask)
+                });
+
+            match paint {
+                Some(paint) => {
+                    // Gradient and bitmap draws contain a single path, so there is nothing to
+                    // split up.
+                    fills.push(Fill {
+                        paint,
+                        triangles: triangles
+                            .map(|(vertices, _)| vertices.map(|v| Point::new(v.x, v.y)))
+                            .collect(),
+                        is_mask: draw.mask_index_count > 0,
+                    });
+                }
+                None => {
+                    // Solid color draws batch together many paths with different colors,
+                    // so split them back up into runs of triangles sharing the same color.
+                    for (vertices, is_mask) in triangles {
+                        let color = &vertices[0].color;
+                        let points = vertices.map(|v| Point::new(v.x, v.y));
+                        match fills.last_mut() {
+                            Some(Fill {
+                                paint: Paint::Color(last_color),
+                                triangles,
+                                is_mask: last_is_mask,
+                            }) if last_color == color && *last_is_mask == is_mask => {
+                                triangles.push(points);
+                            }
+                            _ => fills.push(Fill {
+                                paint: Paint::Color(color.clone()),
+                                triangles: vec![points],
+                                is_mask,
+                            }),
+                        }
+                    }
+                }
+            }
+        }
+
+        Self { fills }
+    }
+}
+
+impl Fill {
+    /// Locks the bitmap used by this fill, if any.
+    pub fn with_bitmap<R>(&self, f: impl FnOnce(Option<&Pixmap>) -> R) -> R {
+        match &self.paint {
+            Paint::Bitmap(paint) => f(Some(&as_bitmap(&paint.bitmap).pixmap())),
+            _ => f(None),
+        }
+    }
+}
+
+#[inline]
+fn apply_uv_matrix(matrix: &[[f32; 3]; 3], point: Point) -> (f32, f32) {
+    (
+        matrix[0][0] * point.x + matrix[1][0] * point.y + matrix[2][0],
+        matrix[0][1] * point.x + matrix[1][1] * point.y + matrix[2][1],
+    )
+}
+
+/// Converts a premultiplied color into a straight-alpha color.
+#[inline]
+pub fn unmultiply(color: Rgba) -> Rgba {
+    if color[3] > 0.0 {
+        [
+            color[0] / color[3],
+            color[1] / color[3],
+            color[2] / color[3],
+            color[3],
+        ]
+    } else {
+        [0.0; 4]
+    }
+}
+
+/// Converts a color component from sRGB to linear color space.
+fn srgb_to_linear(color: f32) -> f32 {
+    if color <= 0.04045 {
+        color / 12.92
+    } else {
+        f32::powf((color + 0.055) / 1.055, 2.4)
+    }
+}
+
+/// Converts a color component from linear to sRGB color space.
+fn linear_to_srgb(color: f32) -> f32 {
+    if color < 0.0031308 {
+        color * 12.92
+    } else {
+        1.055 * color.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// Precomputes the colors of a gradient, in the same way as the GPU backends.
+fn gradient_colors(gradient: &Gradient) -> Vec<Rgba> {
+    let mut colors = vec![[0.0; 4]; GRADIENT_SIZE];
+    if gradient.records.is_empty() {
+        return colors;
+    }
+
+    let convert = if gradient.interpolation == GradientInterpolation::LinearRgb {
+        srgb_to_linear
+    } else {
+        |c| c
+    };
+
+    for (t, color) in colors.iter_mut().enumerate() {
+        let mut last = 0;
+        let mut next = 0;
+
+        for (i, record) in gradient.records.iter().enumerate().rev() {
+            if (record.ratio as usize) < t {
+                last = i;
+                next = (i + 1).min(gradient.records.len() - 1);
+                break;
+            }
+        }
+
+        let last_record = &gradient.records[last];
+        let next_record = &gradient.records[next];
+
+        let a = if next == last {
+            // This can happen if we are before the first gradient record, or after the last one.
+            0.0
+        } else {
+            (t as f32 - last_record.ratio as f32)
+                / (next_record.ratio as f32 - last_record.ratio as f32)
+        };
+        let component = |last: u8, next: u8| {
+            lerp(
+                convert(f32::from(last) / 255.0),
+                convert(f32::from(next) / 255.0),
+                a,
+            )
+        };
+        *color = [
+            component(last_record.color.r, next_record.color.r),
+            component(last_record.color.g, next_record.color.g),
+            component(last_record.color.b, next_record.color.b),
+            lerp(
+                f32::from(last_record.color.a) / 255.0,
+                f32::from(next_record.color.a) / 255.0,
+                a,
+            ),
+        ];
+    }
+
+    colors
+}
@@ -0,0 +1,332 @@
+use ruffle_render::matrix::Matrix;
+use ruffle_render::transform::Transform;
+
+/// A point in pixel space.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Point {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl Point {
+    pub const fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+}
+
+/// A projective transform working in pixels, rather than twips.
+///
+/// Most display objects only need the affine part, where the last row is `[0, 0, 1]`.
+/// Display objects with a 3D transform are drawn in perspective.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Projective {
+    pub rows: [[f32; 3]; 3],
+}
+
+impl Projective {
+    /// Transforms a point, or returns `None` if it ends up behind the viewer.
+    #[inline]
+    pub fn apply(&self, point: Point) -> Option<Point> {
+        let [x, y, w] = self
+            .rows
+            .map(|row| row[0] * point.x + row[1] * point.y + row[2]);
+        if w <= 0.0 {
+            return None;
+        }
+        Some(Point::new(x / w, y / w))
+    }
+
+    pub fn inverse(&self) -> Option<Self> {
+        let m = &self.rows;
+        let cofactor = |r0: usize, r1: usize, c0: usize, c1: usize| {
+            m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0]
+        };
+        let det = m[0][0] * cofactor(1, 2, 1, 2) - m[0][1] * cofactor(1, 2, 0, 2)
+            + m[0][2] * cofactor(1, 2, 0, 1);
+        if det == 0.0 || !det.is_finite() {
+            return None;
+        }
+        let rows = [
+            [
+                cofactor(1, 2, 1, 2),
+                -cofactor(0, 2, 1, 2),
+                cofactor(0, 1, 1, 2),
+            ],
+            [
+                -cofactor(1, 2, 0, 2),
+                cofactor(0, 2, 0, 2),
+                -cofactor(0, 1, 0, 2),
+            ],
+            [
+                cofactor(1, 2, 0, 1),
+                -cofactor(0, 2, 0, 1),
+                cofactor(0, 1, 0, 1),
+            ],
+        ];
+        Some(Self {
+            rows: rows.map(|row| row.map(|v| v / det)),
+        })
+    }
+}
+
+impl From<Matrix> for Projective {
+    fn from(matrix: Matrix) -> Self {
+        Self {
+            rows: [
+                [matrix.a, matrix.c, matrix.tx.to_pixels() as f32],
+                [matrix.b, matrix.d, matrix.ty.to_pixels() as f32],
+                [0.0, 0.0, 1.0],
+            ],
+        }
+    }
+}
+
+impl From<&Transform> for Projective {
+    fn from(transform: &Transform) -> Self {
+        match transform.matrix3d {
+            Some(matrix3d) => Self {
+                rows: (matrix3d * transform.matrix)
+                    .plane_projection()
+                    .map(|row| row.map(|v| v as f32)),
+            },
+            None => transform.matrix.into(),
+        }
+    }
+}
+
+/// The positions within a pixel that are tested for coverage.
+///
+/// Each pixel supports at most 16 samples, as the coverage of a pixel is stored in a `u16`.
+#[derive(Clone, Debug)]
+pub struct SampleGrid {
+    offsets: Vec<Point>,
+}
+
+impl SampleGrid {
+    pub fn new(sample_count: u32) -> Self {
+        let offsets = match sample_count {
+            0 | 1 => vec![Point::new(0.5, 0.5)],
+            // A diagonal pattern anti-aliases both horizontal and vertical edges.
+            2 => vec![Point::new(0.25, 0.25), Point::new(0.75, 0.75)],
+            _ => {
+                let (columns, rows) = match sample_count {
+                    3..=4 => (2, 2),
+                    5..=8 => (4, 2),
+                    _ => (4, 4),
+                };
+                let mut offsets = Vec::with_capacity(columns * rows);
+                for row in 0..rows {
+                    for column in 0..columns {
+                        offsets.push(Point::new(
+                            (column as f32 + 0.5) / columns as f32,
+                            (row as f32 + 0.5) / rows as f32,
+                        ));
+                    }
+                }
+                offsets
+            }
+        };
+        Self { offsets }
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.offsets.len()
+    }
+}
+
+/// The area of a target covered by a set of triangles.
+///
+/// Every pixel stores a bit per sample, so overlapping triangles (such as adjacent
+/// triangles of the same tessellated path) never cover a sample twice.
+pub struct Coverage {
+    x_min: u32,
+    y_min: u32,
+    width: u32,
+    height: u32,
+    samples: Vec<u16>,
+    sample_count: usize,
+}
+
+impl Coverage {
+    /// Rasterizes the given triangles, clipped to a target of the given size.
+    ///
+    /// Returns `None` if no pixel of the target is touched.
+    pub fn rasterize(
+        triangles: &[[Point; 3]],
+        target_width: u32,
+        target_height: u32,
+        grid: &SampleGrid,
+    ) -> Option<Self> {
+        let mut x_min = f32::INFINITY;
+        let mut y_min = f32::INFINITY;
+        let mut x_max = f32::NEG_INFINITY;
+        let mut y_max = f32::NEG_INFINITY;
+        for point in triangles.iter().flatten() {
+            x_min = x_min.min(point.x);
+            y_min = y_min.min(point.y);
+            x_max = x_max.max(point.x);
+            y_max = y_max.max(point.y);
+        }
+
+        let x_min = x_min.floor().max(0.0);
+        let y_min = y_min.floor().max(0.0);
+        let x_max = x_max.ceil().min(target_width as f32);
+        let y_max = y_max.ceil().min(target_height as f32);
+        // This also rejects NaN bounds.
+        if !(x_min < x_max && y_min < y_max) {
+            return None;
+        }
+
+        let mut coverage = Self {
+            x_min: x_min as u32,
+            y_min: y_min as u32,
+            width: (x_max - x_min) as u32,
+            height: (y_max - y_min) as u32,
+            samples: vec![0; (x_max - x_min) as usize * (y_max - y_min) as usize],
+            sample_count: grid.len(),
+        };
+        for triangle in triangles {
+            coverage.add_triangle(triangle, grid);
+        }
+        Some(coverage)
+    }
+
+    fn add_triangle(&mut self, [p0, p1, p2]: &[Point; 3], grid: &SampleGrid) {
+        // Make sure that the triangle is wound counter-clockwise (in screen space),
+        // so that all edge functions are positive inside of it.
+        let area = edge(p0, p1, p2);
+        if area == 0.0 || !area.is_finite() {
+            return;
+        }
+        let (p1, p2) = if area < 0.0 { (p2, p1) } else { (p1, p2) };
+
+        let x_start = p0.x.min(p1.x).min(p2.x).floor().max(self.x_min as f32) as u32;
+        let y_start = p0.y.min(p1.y).min(p2.y).floor().max(self.y_min as f32) as u32;
+        let x_end = (p0.x.max(p1.x).max(p2.x).ceil() as u32).min(self.x_min + self.width);
+        let y_end = (p0.y.max(p1.y).max(p2.y).ceil() as u32).min(self.y_min + self.height);
+
+        for y in y_start..y_end {
+            let row = (y - self.y_min) as usize * self.width as usize;
+            for x in x_start..x_end {
+                let mut mask = 0;
+                for (i, offset) in grid.offsets.iter().enumerate() {
+                    let sample = Point::new(x as f32 + offset.x, y as f32 + offset.y);
+                    if edge(p0, p1, &sample) >= 0.0
+                        && edge(p1, p2, &sample) >= 0.0
+                        && edge(p2, p0, &sample) >= 0.0
+                    {
+                        mask |= 1 << i;
+                    }
+                }
+                self.samples[row + (x - self.x_min) as usize] |= mask;
+            }
+        }
+    }
+
+    /// Iterates over every covered pixel, yielding its position and how much of it is covered.
+    pub fn pixels(&self) -> impl Iterator<Item = (u32, u32, f32)> + '_ {
+        let sample_count = self.sample_count as f32;
+        self.samples
+            .iter()
+            .enumerate()
+            .filter(|(_, mask)| **mask != 0)
+            .map(move |(i, mask)| {
+                let x = self.x_min + (i % self.width as usize) as u32;
+                let y = self.y_min + (i / self.width as usize) as u32;
+                (x, y, mask.count_ones() as f32 / sample_count)
+            })
+    }
+}
+
+/// Returns twice the signed area of the triangle `a`, `b`, `c`.
+#[inline]
+fn edge(a: &Point, b: &Point, c: &Point) -> f32 {
+    (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn square(x: f32, y: f32, size: f32) -> [[Point; 3]; 2] {
+        [
+            [
+                Point::new(x, y),
+                Point::new(x + size, y),
+                Point::new(x + size, y + size),
+            ],
+            [
+                Point::new(x, y),
+                Point::new(x + size, y + size),
+                Point::new(x, y + size),
+            ],
+        ]
+    }
+
+    #[test]
+    fn shared_edges_are_not_counted_twice() {
+        let coverage = Coverage::rasterize(&square(1.0, 1.0, 2.0), 4, 4, &SampleGrid::new(4))
+            .expect("Square is on screen");
+        let pixels: Vec<_> = coverage.pixels().collect();
+        assert_eq!(
+            pixels,
+            vec![(1, 1, 1.0), (2, 1, 1.0), (1, 2, 1.0), (2, 2, 1.0)]
+        );
+    }
+
+    #[test]
+    fn partial_coverage() {
+        let coverage = Coverage::rasterize(&square(0.5, 0.0, 1.0), 4, 4, &SampleGrid::new(4))
+            .expect("Square is on screen");
+        let pixels: Vec<_> = coverage.pixels().collect();
+        assert_eq!(pixels, vec![(0, 0, 0.5), (1, 0, 0.5)]);
+    }
+
+    #[test]
+    fn offscreen_triangles_are_rejected() {
+        assert!(
+            Coverage::rasterize(&square(-10.0, -10.0, 5.0), 4, 4, &SampleGrid::new(1)).is_none()
+        );
+        assert!(Coverage::rasterize(&square(10.0, 10.0, 5.0), 4, 4, &SampleGrid::new(1)).is_none());
+    }
+
+    #[test]
+    fn inverse_affine() {
+        let projective = Projective {
+            rows: [[2.0, -1.0, 10.0], [0.5, 3.0, -4.0], [0.0, 0.0, 1.0]],
+        };
+        let inverse = projective.inverse().expect("Matrix is invertible");
+        let point = inverse
+            .apply(
+                projective
+                    .apply(Point::new(3.0, 7.0))
+                    .expect("Point is visible"),
+            )
+            .expect("Point is visible");
+        assert!((point.x - 3.0).abs() < 1e-4);
+        assert!((point.y - 7.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn inverse_perspective() {
+        let projective = Projective {
+            rows: [[2.0, -1.0, 10.0], [0.5, 3.0, -4.0], [0.002, 0.001, 1.0]],
+        };
+        let inverse = projective.inverse().expect("Matrix is invertible");
+        let point = inverse
+            .apply(
+                projective
+                    .apply(Point::new(3.0, 7.0))
+                    .expect("Point is visible"),
+            )
+            .expect("Point is visible");
+        assert!((point.x - 3.0).abs() < 1e-3);
+        assert!((point.y - 7.0).abs() < 1e-3);
+
+        let behind = Projective {
+            rows: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [-0.01, 0.0, 1.0]],
+        };
+        assert!(behind.apply(Point::new(200.0, 0.0)).is_none());
+    }
+}
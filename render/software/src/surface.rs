@@ -0,0 +1,359 @@
+use crate::bitmap::{as_bitmap, Pixmap, Rgba};
+use crate::blend::{blend, over};
+use crate::mesh::{as_mesh, unmultiply, Fill, Paint};
+use crate::raster::{Coverage, Point, Projective, SampleGrid};
+use ruffle_render::backend::ShapeHandle;
+use ruffle_render::bitmap::BitmapHandle;
+use ruffle_render::commands::{CommandHandler, CommandList};
+use ruffle_render::matrix::Matrix;
+use ruffle_render::transform::Transform;
+use swf::{BlendMode, Color, ColorTransform};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum MaskState {
+    NoMask,
+    DrawMask,
+    DrawMaskedContent,
+    ClearMask,
+}
+
+/// Executes a list of render commands onto a pixmap.
+pub struct Surface<'a> {
+    target: &'a mut Pixmap,
+    grid: &'a SampleGrid,
+    mask_state: MaskState,
+
+    /// The coverage of every active mask, one value per pixel of the target.
+    /// Every mask is already clipped by the masks below it.
+    masks: Vec<Vec<f32>>,
+}
+
+impl<'a> Surface<'a> {
+    pub fn new(target: &'a mut Pixmap, grid: &'a SampleGrid) -> Self {
+        Self {
+            target,
+            grid,
+            mask_state: MaskState::NoMask,
+            masks: Vec::new(),
+        }
+    }
+
+    pub fn draw_commands(mut self, commands: CommandList) {
+        commands.execute(&mut self);
+    }
+
+    /// Draws a set of triangles, given in the coordinate space of `matrix`.
+    fn draw_triangles(
+        &mut self,
+        triangles: &[[Point; 3]],
+        matrix: Projective,
+        paint: &Paint,
+        bitmap: Option<&Pixmap>,
+        color_transform: &ColorTransform,
+    ) {
+        if self.mask_state == MaskState::ClearMask {
+            // The mask is being removed, which doesn't need any drawing.
+            return;
+        }
+
+        let triangles = project_triangles(triangles, &matrix);
+        let Some(coverage) = Coverage::rasterize(
+            &triangles,
+            self.target.width(),
+            self.target.height(),
+            self.grid,
+        ) else {
+            return;
+        };
+
+        if self.mask_state == MaskState::DrawMask {
+            self.draw_mask(&coverage);
+            return;
+        }
+
+        // Paints that vary over the shape are sampled in the coordinate space of the shape.
+        let inverse = match paint {
+            Paint::Color(_) => None,
+            _ => match matrix.inverse() {
+                Some(inverse) => Some(inverse),
+                None => return,
+            },
+        };
+        let is_identity = *color_transform == ColorTransform::IDENTITY;
+        let mult = color_transform.mult_rgba_normalized();
+        let add = color_transform.add_rgba_normalized();
+        let solid_color = match paint {
+            Paint::Color(_) => Some(apply_color_transform(
+                paint.sample(Point::default(), None),
+                is_identity,
+                &mult,
+                &add,
+            )),
+            _ => None,
+        };
+
+        let width = self.target.width() as usize;
+        for (x, y, coverage) in coverage.pixels() {
+            let coverage = match self.masks.last() {
+                Some(mask) => coverage * mask[y as usize * width + x as usize],
+                None => coverage,
+            };
+            if coverage <= 0.0 {
+                continue;
+            }
+
+            let color = match (solid_color, inverse) {
+                (Some(color), _) => color,
+                (None, Some(inverse)) => {
+                    let Some(point) = inverse.apply(Point::new(x as f32 + 0.5, y as f32 + 0.5))
+                    else {
+                        continue;
+                    };
+                    apply_color_transform(paint.sample(point, bitmap), is_identity, &mult, &add)
+                }
+                (None, None) => continue,
+            };
+
+            let src = color.map(|c| c * coverage);
+            let dst = self.target.get(x, y);
+            self.target.set(x, y, over(src, dst));
+        }
+    }
+
+    /// Adds the covered area to the mask that is currently being drawn.
+    fn draw_mask(&mut self, coverage: &Coverage) {
+        let width = self.target.width() as usize;
+        let Some((current, parents)) = self.masks.split_last_mut() else {
+            return;
+        };
+        for (x, y, coverage) in coverage.pixels() {
+            let index = y as usize * width + x as usize;
+            let coverage = match parents.last() {
+                Some(parent) => coverage * parent[index],
+                None => coverage,
+            };
+            current[index] = current[index].max(coverage);
+        }
+    }
+
+    fn draw_bitmap(&mut self, bitmap: &BitmapHandle, transform: &Transform, smoothing: bool) {
+        let pixmap = as_bitmap(bitmap).pixmap();
+        let (width, height) = (pixmap.width() as f32, pixmap.height() as f32);
+        let triangles = quad(width, height);
+
+        if self.mask_state == MaskState::DrawMask || self.mask_state == MaskState::ClearMask {
+            // Only the area of the bitmap matters for masks.
+            self.draw_triangles(
+                &triangles,
+                transform.into(),
+                &Paint::Color(Color::WHITE),
+                None,
+                &transform.color_transform,
+            );
+            return;
+        }
+
+        let matrix: Projective = transform.into();
+        let Some(inverse) = matrix.inverse() else {
+            return;
+        };
+        let triangles = project_triangles(&triangles, &matrix);
+        let Some(coverage) = Coverage::rasterize(
+            &triangles,
+            self.target.width(),
+            self.target.height(),
+            self.grid,
+        ) else {
+            return;
+        };
+
+        let color_transform = &transform.color_transform;
+        let is_identity = *color_transform == ColorTransform::IDENTITY;
+        let mult = color_transform.mult_rgba_normalized();
+        let add = color_transform.add_rgba_normalized();
+        let target_width = self.target.width() as usize;
+        for (x, y, coverage) in coverage.pixels() {
+            let coverage = match self.masks.last() {
+                Some(mask) => coverage * mask[y as usize * target_width + x as usize],
+                None => coverage,
+            };
+            if coverage <= 0.0 {
+                continue;
+            }
+
+            let Some(point) = inverse.apply(Point::new(x as f32 + 0.5, y as f32 + 0.5)) else {
+                continue;
+            };
+            let color = pixmap.sample(point.x, point.y, smoothing, false);
+            // Bitmaps are premultiplied, so only unmultiply when the colors need adjusting.
+            let color = if is_identity {
+                color
+            } else {
+                apply_color_transform(unmultiply(color), false, &mult, &add)
+            };
+
+            let src = color.map(|c| c * coverage);
+            let dst = self.target.get(x, y);
+            self.target.set(x, y, over(src, dst));
+        }
+    }
+
+    fn draw_fill(&mut self, fill: &Fill, transform: &Transform) {
+        if self.mask_state == MaskState::DrawMask && !fill.is_mask {
+            return;
+        }
+        fill.with_bitmap(|bitmap| {
+            self.draw_triangles(
+                &fill.triangles,
+                transform.into(),
+                &fill.paint,
+                bitmap,
+                &transform.color_transform,
+            )
+        });
+    }
+}
+
+impl<'a> CommandHandler for Surface<'a> {
+    fn render_bitmap(&mut self, bitmap: BitmapHandle, transform: Transform, smoothing: bool) {
+        self.draw_bitmap(&bitmap, &transform, smoothing);
+    }
+
+    fn render_stage3d(&mut self, bitmap: BitmapHandle, transform: Transform) {
+        self.draw_bitmap(&bitmap, &transform, false);
+    }
+
+    fn render_shape(&mut self, shape: ShapeHandle, transform: Transform) {
+        let mesh = as_mesh(&shape);
+        for fill in &mesh.fills {
+            self.draw_fill(fill, &transform);
+        }
+    }
+
+    fn draw_rect(&mut self, color: Color, matrix: Matrix) {
+        self.draw_triangles(
+            &quad(1.0, 1.0),
+            matrix.into(),
+            &Paint::Color(color),
+            None,
+            &ColorTransform::IDENTITY,
+        );
+    }
+
+    fn push_mask(&mut self) {
+        debug_assert!(
+            self.mask_state == MaskState::NoMask || self.mask_state == MaskState::DrawMaskedContent
+        );
+        self.masks.push(vec![
+            0.0;
+            self.target.width() as usize
+                * self.target.height() as usize
+        ]);
+        self.mask_state = MaskState::DrawMask;
+    }
+
+    fn activate_mask(&mut self) {
+        debug_assert!(!self.masks.is_empty() && self.mask_state == MaskState::DrawMask);
+        self.mask_state = MaskState::DrawMaskedContent;
+    }
+
+    fn deactivate_mask(&mut self) {
+        debug_assert!(!self.masks.is_empty() && self.mask_state == MaskState::DrawMaskedContent);
+        self.mask_state = MaskState::ClearMask;
+    }
+
+    fn pop_mask(&mut self) {
+        debug_assert!(!self.masks.is_empty() && self.mask_state == MaskState::ClearMask);
+        self.masks.pop();
+        self.mask_state = if self.masks.is_empty() {
+            MaskState::NoMask
+        } else {
+            MaskState::DrawMaskedContent
+        };
+    }
+
+    fn blend(&mut self, commands: CommandList, blend_mode: BlendMode) {
+        match self.mask_state {
+            // Blend modes don't affect the shape of a mask.
+            MaskState::DrawMask => commands.execute(self),
+            MaskState::ClearMask => {}
+            MaskState::NoMask | MaskState::DrawMaskedContent => {
+                let mut layer = Pixmap::new(self.target.width(), self.target.height());
+                Surface::new(&mut layer, self.grid).draw_commands(commands);
+
+                let width = self.target.width() as usize;
+                for y in 0..self.target.height() {
+                    for x in 0..self.target.width() {
+                        let mask = match self.masks.last() {
+                            Some(mask) => mask[y as usize * width + x as usize],
+                            None => 1.0,
+                        };
+                        let src = layer.get(x, y);
+                        if mask <= 0.0 || src[3] <= 0.0 {
+                            continue;
+                        }
+
+                        let dst = self.target.get(x, y);
+                        let mut out = blend(src, dst, blend_mode);
+                        if mask < 1.0 {
+                            for (out, dst) in out.iter_mut().zip(dst) {
+                                *out = dst + (*out - dst) * mask;
+                            }
+                        }
+                        self.target.set(x, y, out);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Transforms triangles into pixel space.
+///
+/// Triangles that reach behind the viewer are dropped, rather than clipped.
+fn project_triangles(triangles: &[[Point; 3]], matrix: &Projective) -> Vec<[Point; 3]> {
+    triangles
+        .iter()
+        .filter_map(|triangle| {
+            let [a, b, c] = triangle.map(|point| matrix.apply(point));
+            Some([a?, b?, c?])
+        })
+        .collect()
+}
+
+/// Returns the two triangles making up the rectangle from `(0, 0)` to `(width, height)`.
+fn quad(width: f32, height: f32) -> [[Point; 3]; 2] {
+    [
+        [
+            Point::new(0.0, 0.0),
+            Point::new(width, 0.0),
+            Point::new(width, height),
+        ],
+        [
+            Point::new(0.0, 0.0),
+            Point::new(width, height),
+            Point::new(0.0, height),
+        ],
+    ]
+}
+
+/// Applies a color transform to a straight-alpha color, returning a premultiplied color.
+#[inline]
+fn apply_color_transform(color: Rgba, is_identity: bool, mult: &[f32; 4], add: &[f32; 4]) -> Rgba {
+    let color = if is_identity {
+        color
+    } else {
+        let mut out = [0.0; 4];
+        for (i, out) in out.iter_mut().enumerate() {
+            *out = (color[i] * mult[i] + add[i]).clamp(0.0, 1.0);
+        }
+        out
+    };
+    let alpha = color[3].clamp(0.0, 1.0);
+    [
+        color[0].clamp(0.0, 1.0) * alpha,
+        color[1].clamp(0.0, 1.0) * alpha,
+        color[2].clamp(0.0, 1.0) * alpha,
+        alpha,
+    ]
+}
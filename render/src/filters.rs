@@ -39,6 +39,52 @@ impl From<&swf::Filter> for Filter {
     }
 }
 
+impl Filter {
+    /// How many pixels applying this filter can draw past each side of its
+    /// source, horizontally and vertically.
+    pub fn padding(&self) -> (u32, u32) {
+        // Every pass of a blur spreads a pixel by half of the blur size.
+        let blur = |blur_x: swf::Fixed16, blur_y: swf::Fixed16, passes: u8| {
+            let passes = f32::from(passes.max(1));
+            (
+                (blur_x.to_f32().max(0.0) / 2.0 * passes).ceil() as u32,
+                (blur_y.to_f32().max(0.0) / 2.0 * passes).ceil() as u32,
+            )
+        };
+        let offset = |distance: swf::Fixed16| distance.to_f32().abs().ceil() as u32;
+
+        let ((x, y), distance) = match self {
+            Filter::BlurFilter(filter) => {
+                (blur(filter.blur_x, filter.blur_y, filter.num_passes()), 0)
+            }
+            Filter::GlowFilter(filter) => {
+                (blur(filter.blur_x, filter.blur_y, filter.num_passes()), 0)
+            }
+            Filter::DropShadowFilter(filter) => (
+                blur(filter.blur_x, filter.blur_y, filter.num_passes()),
+                offset(filter.distance),
+            ),
+            Filter::BevelFilter(filter) => (
+                blur(filter.blur_x, filter.blur_y, filter.num_passes()),
+                offset(filter.distance),
+            ),
+            Filter::GradientGlowFilter(filter) | Filter::GradientBevelFilter(filter) => (
+                blur(filter.blur_x, filter.blur_y, filter.num_passes()),
+                offset(filter.distance),
+            ),
+            Filter::ConvolutionFilter(filter) => (
+                (
+                    u32::from(filter.num_matrix_cols / 2),
+                    u32::from(filter.num_matrix_rows / 2),
+                ),
+                0,
+            ),
+            Filter::ColorMatrixFilter(_) | Filter::DisplacementMapFilter(_) => ((0, 0), 0),
+        };
+        (x + distance, y + distance)
+    }
+}
+
 impl Default for Filter {
     fn default() -> Self {
         // A default colormatrix is a filter that essentially does nothing,
@@ -136,6 +136,21 @@ impl Matrix {
         )
     }
 
+    /// Returns the column-major 4x4 matrix that renderers use to transform vertices into stage space.
+    pub fn to_world_matrix(&self) -> [[f32; 4]; 4] {
+        [
+            [self.a, self.b, 0.0, 0.0],
+            [self.c, self.d, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [
+                self.tx.to_pixels() as f32,
+                self.ty.to_pixels() as f32,
+                0.0,
+                1.0,
+            ],
+        ]
+    }
+
     #[inline]
     pub fn determinant(&self) -> f32 {
         self.a * self.d - self.b * self.c
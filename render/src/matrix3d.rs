@@ -0,0 +1,435 @@
+use crate::matrix::Matrix;
+use swf::{Point, Twips};
+
+/// A 4x4 transformation matrix, used by display objects with a 3D transform.
+///
+/// The values are stored in column-major order, the same as `flash.geom.Matrix3D.rawData`.
+/// Unlike `Matrix`, the translation is stored in pixels.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Matrix3D {
+    pub raw_data: [f64; 16],
+}
+
+impl Matrix3D {
+    pub const IDENTITY: Self = Self {
+        raw_data: [
+            1.0, 0.0, 0.0, 0.0, //
+            0.0, 1.0, 0.0, 0.0, //
+            0.0, 0.0, 1.0, 0.0, //
+            0.0, 0.0, 0.0, 1.0, //
+        ],
+    };
+
+    /// Builds a matrix out of a translation, a rotation and a scale.
+    ///
+    /// The rotation is given as Euler angles in radians, which are applied in X, Y, Z order.
+    /// This matches `Matrix3D.recompose` with `Orientation3D.EULER_ANGLES`.
+    pub fn compose(translation: [f64; 3], rotation: [f64; 3], scale: [f64; 3]) -> Self {
+        let (sx, cx) = rotation[0].sin_cos();
+        let (sy, cy) = rotation[1].sin_cos();
+        let (sz, cz) = rotation[2].sin_cos();
+        Self {
+            raw_data: [
+                cy * cz * scale[0],
+                cy * sz * scale[0],
+                -sy * scale[0],
+                0.0,
+                (sx * sy * cz - cx * sz) * scale[1],
+                (sx * sy * sz + cx * cz) * scale[1],
+                sx * cy * scale[1],
+                0.0,
+                (cx * sy * cz + sx * sz) * scale[2],
+                (cx * sy * sz - sx * cz) * scale[2],
+                cx * cy * scale[2],
+                0.0,
+                translation[0],
+                translation[1],
+                translation[2],
+                1.0,
+            ],
+        }
+    }
+
+    /// Splits this matrix into a translation, a rotation and a scale.
+    ///
+    /// This is the inverse of `compose`, and matches `Matrix3D.decompose` with
+    /// `Orientation3D.EULER_ANGLES`.
+    pub fn decompose(&self) -> ([f64; 3], [f64; 3], [f64; 3]) {
+        let m = &self.raw_data;
+        let translation = [m[12], m[13], m[14]];
+
+        let column = |i: usize| [m[i * 4], m[i * 4 + 1], m[i * 4 + 2]];
+        let length = |c: [f64; 3]| (c[0] * c[0] + c[1] * c[1] + c[2] * c[2]).sqrt();
+        let (x, y, z) = (column(0), column(1), column(2));
+        let mut scale = [length(x), length(y), length(z)];
+        let determinant = x[0] * (y[1] * z[2] - y[2] * z[1]) - x[1] * (y[0] * z[2] - y[2] * z[0])
+            + x[2] * (y[0] * z[1] - y[1] * z[0]);
+        if determinant < 0.0 {
+            scale[2] = -scale[2];
+        }
+
+        let normalize = |c: [f64; 3], s: f64| {
+            if s == 0.0 {
+                c
+            } else {
+                c.map(|v| v / s)
+            }
+        };
+        let (x, y, z) = (
+            normalize(x, scale[0]),
+            normalize(y, scale[1]),
+            normalize(z, scale[2]),
+        );
+        let rotation_y = (-x[2]).clamp(-1.0, 1.0).asin();
+        let rotation = if x[2] != 1.0 && x[2] != -1.0 {
+            [y[2].atan2(z[2]), rotation_y, x[1].atan2(x[0])]
+        } else {
+            [y[0].atan2(y[1]), rotation_y, 0.0]
+        };
+
+        (translation, rotation, scale)
+    }
+
+    /// Flash's perspective projection, looking along the Z axis towards `center`.
+    ///
+    /// Points at `z = 0` are left in place, while points further away move towards the center.
+    pub fn perspective_projection(focal_length: f64, center: (f64, f64)) -> Self {
+        let (cx, cy) = center;
+        let w = 1.0 / focal_length;
+        Self {
+            raw_data: [
+                1.0,
+                0.0,
+                0.0,
+                0.0,
+                0.0,
+                1.0,
+                0.0,
+                0.0,
+                cx * w,
+                cy * w,
+                1.0,
+                w,
+                0.0,
+                0.0,
+                0.0,
+                1.0,
+            ],
+        }
+    }
+
+    /// Returns the value at the given row and column.
+    #[inline]
+    pub fn get(&self, row: usize, column: usize) -> f64 {
+        self.raw_data[column * 4 + row]
+    }
+
+    /// Transforms a point, returning its homogeneous coordinates.
+    pub fn transform_point(&self, point: [f64; 3]) -> [f64; 4] {
+        let mut out = [0.0; 4];
+        for (row, out) in out.iter_mut().enumerate() {
+            *out = self.get(row, 0) * point[0]
+                + self.get(row, 1) * point[1]
+                + self.get(row, 2) * point[2]
+                + self.get(row, 3);
+        }
+        out
+    }
+
+    /// Returns the 3x3 matrix that maps the local XY plane onto the screen.
+    ///
+    /// Display objects are flat, so their Z coordinate is always zero. This leaves a 2D
+    /// projective transform, with rows for X, Y and W.
+    pub fn plane_projection(&self) -> [[f64; 3]; 3] {
+        [
+            [self.get(0, 0), self.get(0, 1), self.get(0, 3)],
+            [self.get(1, 0), self.get(1, 1), self.get(1, 3)],
+            [self.get(3, 0), self.get(3, 1), self.get(3, 3)],
+        ]
+    }
+
+    /// Projects a point in the local XY plane onto the screen.
+    ///
+    /// Returns `None` if the point is behind the viewer.
+    pub fn project(&self, point: Point<Twips>) -> Option<Point<Twips>> {
+        let [x, y, w] = apply_plane(
+            &self.plane_projection(),
+            [point.x.to_pixels(), point.y.to_pixels(), 1.0],
+        );
+        if w <= 0.0 {
+            return None;
+        }
+        Some(Point::new(
+            Twips::from_pixels(x / w),
+            Twips::from_pixels(y / w),
+        ))
+    }
+
+    /// Finds the point in the local XY plane that is projected onto the given screen point.
+    ///
+    /// Returns `None` if no such point exists, for example when the plane is seen edge-on.
+    pub fn unproject(&self, point: Point<Twips>) -> Option<Point<Twips>> {
+        let inverse = invert_plane(&self.plane_projection())?;
+        let [x, y, w] = apply_plane(&inverse, [point.x.to_pixels(), point.y.to_pixels(), 1.0]);
+        if w == 0.0 || !w.is_finite() {
+            return None;
+        }
+        let point = Point::new(Twips::from_pixels(x / w), Twips::from_pixels(y / w));
+        // Points behind the viewer can't be seen.
+        self.project(point).map(|_| point)
+    }
+
+    /// Approximates this matrix with an affine matrix, dropping the perspective.
+    ///
+    /// This is exact around the local origin, and is used where perspective can't be drawn.
+    pub fn to_matrix(&self) -> Matrix {
+        let w = self.get(3, 3);
+        let w = if w > 0.0 { w } else { 1.0 };
+        Matrix {
+            a: (self.get(0, 0) / w) as f32,
+            b: (self.get(1, 0) / w) as f32,
+            c: (self.get(0, 1) / w) as f32,
+            d: (self.get(1, 1) / w) as f32,
+            tx: Twips::from_pixels(self.get(0, 3) / w),
+            ty: Twips::from_pixels(self.get(1, 3) / w),
+        }
+    }
+
+    /// Returns the column-major matrix that renderers use to transform vertices into stage space.
+    ///
+    /// Vertices are always flattened onto `z = 0`, so that they're never clipped by the depth
+    /// range. The W coordinate is kept, which gives perspective-correct drawing.
+    pub fn to_world_matrix(&self) -> [[f32; 4]; 4] {
+        let column = |c: usize| {
+            [
+                self.get(0, c) as f32,
+                self.get(1, c) as f32,
+                0.0,
+                self.get(3, c) as f32,
+            ]
+        };
+        [column(0), column(1), [0.0, 0.0, 0.0, 0.0], column(3)]
+    }
+
+    /// Inverts this matrix, returning `None` if it has no inverse.
+    pub fn inverse(&self) -> Option<Self> {
+        let m = &self.raw_data;
+        let mut inv = [0.0; 16];
+
+        inv[0] = m[5] * m[10] * m[15] - m[5] * m[11] * m[14] - m[9] * m[6] * m[15]
+            + m[9] * m[7] * m[14]
+            + m[13] * m[6] * m[11]
+            - m[13] * m[7] * m[10];
+        inv[4] = -m[4] * m[10] * m[15] + m[4] * m[11] * m[14] + m[8] * m[6] * m[15]
+            - m[8] * m[7] * m[14]
+            - m[12] * m[6] * m[11]
+            + m[12] * m[7] * m[10];
+        inv[8] = m[4] * m[9] * m[15] - m[4] * m[11] * m[13] - m[8] * m[5] * m[15]
+            + m[8] * m[7] * m[13]
+            + m[12] * m[5] * m[11]
+            - m[12] * m[7] * m[9];
+        inv[12] = -m[4] * m[9] * m[14] + m[4] * m[10] * m[13] + m[8] * m[5] * m[14]
+            - m[8] * m[6] * m[13]
+            - m[12] * m[5] * m[10]
+            + m[12] * m[6] * m[9];
+        inv[1] = -m[1] * m[10] * m[15] + m[1] * m[11] * m[14] + m[9] * m[2] * m[15]
+            - m[9] * m[3] * m[14]
+            - m[13] * m[2] * m[11]
+            + m[13] * m[3] * m[10];
+        inv[5] = m[0] * m[10] * m[15] - m[0] * m[11] * m[14] - m[8] * m[2] * m[15]
+            + m[8] * m[3] * m[14]
+            + m[12] * m[2] * m[11]
+            - m[12] * m[3] * m[10];
+        inv[9] = -m[0] * m[9] * m[15] + m[0] * m[11] * m[13] + m[8] * m[1] * m[15]
+            - m[8] * m[3] * m[13]
+            - m[12] * m[1] * m[11]
+            + m[12] * m[3] * m[9];
+        inv[13] = m[0] * m[9] * m[14] - m[0] * m[10] * m[13] - m[8] * m[1] * m[14]
+            + m[8] * m[2] * m[13]
+            + m[12] * m[1] * m[10]
+            - m[12] * m[2] * m[9];
+        inv[2] = m[1] * m[6] * m[15] - m[1] * m[7] * m[14] - m[5] * m[2] * m[15]
+            + m[5] * m[3] * m[14]
+            + m[13] * m[2] * m[7]
+            - m[13] * m[3] * m[6];
+        inv[6] = -m[0] * m[6] * m[15] + m[0] * m[7] * m[14] + m[4] * m[2] * m[15]
+            - m[4] * m[3] * m[14]
+            - m[12] * m[2] * m[7]
+            + m[12] * m[3] * m[6];
+        inv[10] = m[0] * m[5] * m[15] - m[0] * m[7] * m[13] - m[4] * m[1] * m[15]
+            + m[4] * m[3] * m[13]
+            + m[12] * m[1] * m[7]
+            - m[12] * m[3] * m[5];
+        inv[14] = -m[0] * m[5] * m[14] + m[0] * m[6] * m[13] + m[4] * m[1] * m[14]
+            - m[4] * m[2] * m[13]
+            - m[12] * m[1] * m[6]
+            + m[12] * m[2] * m[5];
+        inv[3] = -m[1] * m[6] * m[11] + m[1] * m[7] * m[10] + m[5] * m[2] * m[11]
+            - m[5] * m[3] * m[10]
+            - m[9] * m[2] * m[7]
+            + m[9] * m[3] * m[6];
+        inv[7] = m[0] * m[6] * m[11] - m[0] * m[7] * m[10] - m[4] * m[2] * m[11]
+            + m[4] * m[3] * m[10]
+            + m[8] * m[2] * m[7]
+            - m[8] * m[3] * m[6];
+        inv[11] = -m[0] * m[5] * m[11] + m[0] * m[7] * m[9] + m[4] * m[1] * m[11]
+            - m[4] * m[3] * m[9]
+            - m[8] * m[1] * m[7]
+            + m[8] * m[3] * m[5];
+        inv[15] = m[0] * m[5] * m[10] - m[0] * m[6] * m[9] - m[4] * m[1] * m[10]
+            + m[4] * m[2] * m[9]
+            + m[8] * m[1] * m[6]
+            - m[8] * m[2] * m[5];
+
+        let det = m[0] * inv[0] + m[1] * inv[4] + m[2] * inv[8] + m[3] * inv[12];
+        if det.abs() < 1e-11 || !det.is_finite() {
+            return None;
+        }
+        Some(Self {
+            raw_data: inv.map(|v| v / det),
+        })
+    }
+}
+
+impl Default for Matrix3D {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl From<Matrix> for Matrix3D {
+    fn from(matrix: Matrix) -> Self {
+        Self {
+            raw_data: [
+                matrix.a.into(),
+                matrix.b.into(),
+                0.0,
+                0.0,
+                matrix.c.into(),
+                matrix.d.into(),
+                0.0,
+                0.0,
+                0.0,
+                0.0,
+                1.0,
+                0.0,
+                matrix.tx.to_pixels(),
+                matrix.ty.to_pixels(),
+                0.0,
+                1.0,
+            ],
+        }
+    }
+}
+
+impl std::ops::Mul for Matrix3D {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        let mut raw_data = [0.0; 16];
+        for column in 0..4 {
+            for row in 0..4 {
+                raw_data[column * 4 + row] =
+                    (0..4).map(|i| self.get(row, i) * rhs.get(i, column)).sum();
+            }
+        }
+        Self { raw_data }
+    }
+}
+
+impl std::ops::Mul<Matrix> for Matrix3D {
+    type Output = Self;
+
+    fn mul(self, rhs: Matrix) -> Self {
+        self * Matrix3D::from(rhs)
+    }
+}
+
+fn apply_plane(m: &[[f64; 3]; 3], v: [f64; 3]) -> [f64; 3] {
+    m.map(|row| row[0] * v[0] + row[1] * v[1] + row[2] * v[2])
+}
+
+fn invert_plane(m: &[[f64; 3]; 3]) -> Option<[[f64; 3]; 3]> {
+    let cofactor =
+        |r0: usize, r1: usize, c0: usize, c1: usize| m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0];
+    let det = m[0][0] * cofactor(1, 2, 1, 2) - m[0][1] * cofactor(1, 2, 0, 2)
+        + m[0][2] * cofactor(1, 2, 0, 1);
+    if det.abs() < 1e-11 || !det.is_finite() {
+        return None;
+    }
+    Some([
+        [
+            cofactor(1, 2, 1, 2) / det,
+            -cofactor(0, 2, 1, 2) / det,
+            cofactor(0, 1, 1, 2) / det,
+        ],
+        [
+            -cofactor(1, 2, 0, 2) / det,
+            cofactor(0, 2, 0, 2) / det,
+            -cofactor(0, 1, 0, 2) / det,
+        ],
+        [
+            cofactor(1, 2, 0, 1) / det,
+            -cofactor(0, 2, 0, 1) / det,
+            cofactor(0, 1, 0, 1) / det,
+        ],
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-9, "{a} != {b}");
+    }
+
+    #[test]
+    fn compose_and_decompose() {
+        let matrix = Matrix3D::compose([10.0, -20.0, 30.0], [0.5, -0.25, 1.0], [2.0, 3.0, 0.5]);
+        let (translation, rotation, scale) = matrix.decompose();
+        for (a, b) in translation.iter().zip([10.0, -20.0, 30.0]) {
+            assert_close(*a, b);
+        }
+        for (a, b) in rotation.iter().zip([0.5, -0.25, 1.0]) {
+            assert_close(*a, b);
+        }
+        for (a, b) in scale.iter().zip([2.0, 3.0, 0.5]) {
+            assert_close(*a, b);
+        }
+    }
+
+    #[test]
+    fn inverse() {
+        let matrix = Matrix3D::perspective_projection(500.0, (100.0, 50.0))
+            * Matrix3D::compose([10.0, -20.0, 30.0], [0.5, -0.25, 1.0], [2.0, 3.0, 0.5]);
+        let product = matrix * matrix.inverse().expect("Matrix is invertible");
+        for (a, b) in product.raw_data.iter().zip(Matrix3D::IDENTITY.raw_data) {
+            assert_close(*a, b);
+        }
+    }
+
+    #[test]
+    fn perspective_projection() {
+        let projection = Matrix3D::perspective_projection(500.0, (100.0, 100.0));
+        // Points on the `z = 0` plane stay in place.
+        let [x, y, _, w] = projection.transform_point([300.0, 0.0, 0.0]);
+        assert_close(x / w, 300.0);
+        assert_close(y / w, 0.0);
+        // Points further away move towards the center.
+        let [x, y, _, w] = projection.transform_point([300.0, 0.0, 500.0]);
+        assert_close(x / w, 200.0);
+        assert_close(y / w, 50.0);
+    }
+
+    #[test]
+    fn unproject() {
+        let matrix = Matrix3D::perspective_projection(500.0, (0.0, 0.0))
+            * Matrix3D::compose([100.0, 50.0, 0.0], [0.0, 1.0, 0.0], [1.0, 1.0, 1.0]);
+        let local = Point::from_pixels(20.0, 30.0);
+        let screen = matrix.project(local).expect("Point is visible");
+        let unprojected = matrix.unproject(screen).expect("Plane is not edge-on");
+        assert!((unprojected.x - local.x).get().abs() <= 1);
+        assert!((unprojected.y - local.y).get().abs() <= 1);
+    }
+}
@@ -75,7 +75,7 @@ pub fn calculate_shape_bounds(shape_records: &[swf::ShapeRecord]) -> swf::Rectan
     bounds
 }
 
-/// `DrawPath` represents a solid fill or a stroke.
+/// `DrawPath` represents a solid fill, a stroke or a mesh of filled triangles.
 /// Fills are always closed paths, while strokes may be open or closed.
 /// Closed paths will have the first point equal to the last point.
 #[derive(Clone, Debug, PartialEq)]
@@ -90,6 +90,16 @@ pub enum DrawPath<'a> {
         commands: Vec<DrawCommand>,
         winding_rule: FillRule,
     },
+    /// Triangles that share a single fill, as drawn by `Graphics.drawTriangles`.
+    /// Each group of three indices into `vertices` makes up a triangle.
+    Triangles {
+        style: &'a FillStyle,
+        vertices: Vec<swf::Point<Twips>>,
+        indices: Vec<u32>,
+        /// The normalized bitmap coordinates of each vertex.
+        /// If given, these are used instead of the matrix of a bitmap fill.
+        uvs: Option<Vec<[f32; 2]>>,
+    },
 }
 
 /// `DistilledShape` represents a ready-to-be-consumed collection of paths (both fills and strokes)
@@ -660,7 +670,11 @@ pub fn shape_hit_test(
 }
 
 /// Test whether the given point is contained within the paths specified by the draw commands.
-pub fn draw_command_fill_hit_test(commands: &[DrawCommand], test_point: swf::Point<Twips>) -> bool {
+pub fn draw_command_fill_hit_test(
+    commands: &[DrawCommand],
+    test_point: swf::Point<Twips>,
+    winding_rule: FillRule,
+) -> bool {
     let mut cursor = swf::Point::ZERO;
     let mut fill_start = swf::Point::ZERO;
     let mut winding = 0;
@@ -687,7 +701,28 @@ pub fn draw_command_fill_hit_test(commands: &[DrawCommand], test_point: swf::Poi
         winding += winding_number_line(test_point, cursor, fill_start);
     }
 
-    winding & 0b1 != 0
+    match winding_rule {
+        FillRule::EvenOdd => winding & 0b1 != 0,
+        FillRule::NonZero => winding != 0,
+    }
+}
+
+/// Test whether the given point is contained within any of the given triangles.
+pub fn triangles_hit_test(
+    vertices: &[swf::Point<Twips>],
+    indices: &[u32],
+    test_point: swf::Point<Twips>,
+) -> bool {
+    indices.chunks_exact(3).any(|triangle| {
+        let [Some(&a), Some(&b), Some(&c)] = [0, 1, 2].map(|i| vertices.get(triangle[i] as usize))
+        else {
+            return false;
+        };
+        winding_number_line(test_point, a, b)
+            + winding_number_line(test_point, b, c)
+            + winding_number_line(test_point, c, a)
+            != 0
+    })
 }
 
 /// Test whether the given point is contained within the strokes specified by the draw commands.
@@ -1316,4 +1351,53 @@ mod tests {
             1,
         );
     }
+
+    /// Two overlapping squares drawn in the same direction.
+    #[test]
+    fn draw_command_fill_winding_rule() {
+        let point = |x, y| swf::Point::new(Twips::new(x), Twips::new(y));
+        let mut commands = vec![];
+        for _ in 0..2 {
+            commands.extend([
+                DrawCommand::MoveTo(point(0, 0)),
+                DrawCommand::LineTo(point(100, 0)),
+                DrawCommand::LineTo(point(100, 100)),
+                DrawCommand::LineTo(point(0, 100)),
+                DrawCommand::LineTo(point(0, 0)),
+            ]);
+        }
+
+        let inside = point(50, 50);
+        assert!(!draw_command_fill_hit_test(
+            &commands,
+            inside,
+            FillRule::EvenOdd
+        ));
+        assert!(draw_command_fill_hit_test(
+            &commands,
+            inside,
+            FillRule::NonZero
+        ));
+        assert!(!draw_command_fill_hit_test(
+            &commands,
+            point(150, 50),
+            FillRule::NonZero
+        ));
+    }
+
+    /// Triangles are hit regardless of their winding direction.
+    #[test]
+    fn triangles_hit_test_winding() {
+        let point = |x, y| swf::Point::new(Twips::new(x), Twips::new(y));
+        let vertices = [point(0, 0), point(100, 0), point(0, 100), point(100, 100)];
+
+        // Clockwise, then counter-clockwise.
+        let indices = [0, 1, 2, 3, 1, 2];
+        assert!(triangles_hit_test(&vertices, &indices, point(25, 25)));
+        assert!(triangles_hit_test(&vertices, &indices, point(75, 75)));
+        assert!(!triangles_hit_test(&vertices, &indices, point(150, 50)));
+
+        // Out of range indices are skipped.
+        assert!(!triangles_hit_test(&vertices, &[0, 1, 7], point(25, 25)));
+    }
 }
@@ -55,68 +55,23 @@ impl ShapeTessellator {
                     ruffle_path_to_lyon_path(commands, *is_closed),
                     true,
                 ),
-            };
-
-            let (draw, color, needs_flush) = match fill_style {
-                swf::FillStyle::Color(color) => (DrawType::Color, color.clone(), false),
-                swf::FillStyle::LinearGradient(gradient) => (
-                    DrawType::Gradient(swf_gradient_to_uniforms(
-                        GradientType::Linear,
-                        gradient,
-                        swf::Fixed8::ZERO,
-                    )),
-                    swf::Color::WHITE,
-                    true,
-                ),
-                swf::FillStyle::RadialGradient(gradient) => (
-                    DrawType::Gradient(swf_gradient_to_uniforms(
-                        GradientType::Radial,
-                        gradient,
-                        swf::Fixed8::ZERO,
-                    )),
-                    swf::Color::WHITE,
-                    true,
-                ),
-                swf::FillStyle::FocalGradient {
-                    gradient,
-                    focal_point,
-                } => (
-                    DrawType::Gradient(swf_gradient_to_uniforms(
-                        GradientType::Focal,
-                        gradient,
-                        *focal_point,
-                    )),
-                    swf::Color::WHITE,
-                    true,
-                ),
-                swf::FillStyle::Bitmap {
-                    id,
-                    matrix,
-                    is_smoothed,
-                    is_repeating,
+                DrawPath::Triangles {
+                    style,
+                    vertices,
+                    indices,
+                    uvs,
                 } => {
-                    if let Some(bitmap) = bitmap_source.bitmap_size(*id) {
-                        (
-                            DrawType::Bitmap(Bitmap {
-                                matrix: swf_bitmap_to_gl_matrix(
-                                    (*matrix).into(),
-                                    bitmap.width.into(),
-                                    bitmap.height.into(),
-                                ),
-                                bitmap_id: *id,
-                                is_smoothed: *is_smoothed,
-                                is_repeating: *is_repeating,
-                            }),
-                            swf::Color::WHITE,
-                            true,
-                        )
-                    } else {
-                        // Missing bitmap -- incorrect character ID in SWF?
-                        continue;
-                    }
+                    self.add_triangles(style, vertices, indices, uvs.as_deref(), bitmap_source);
+                    continue;
                 }
             };
 
+            let Some((draw, color, needs_flush)) = fill_style_to_draw(fill_style, bitmap_source)
+            else {
+                // Missing bitmap -- incorrect character ID in SWF?
+                continue;
+            };
+
             if needs_flush || (self.is_stroke && !next_is_stroke) {
                 // We flush separate draw calls in these cases:
                 // * Non-solid color fills which require their own shader.
@@ -176,6 +131,7 @@ impl ShapeTessellator {
                         &mut buffers_builder,
                     )
                 }
+                DrawPath::Triangles { .. } => unreachable!("Triangles were added above"),
             };
             match result {
                 Ok(_) => {
@@ -214,6 +170,75 @@ impl ShapeTessellator {
         });
         self.mask_index_count = None;
     }
+
+    /// Adds a mesh of triangles that was already triangulated by the caller.
+    fn add_triangles(
+        &mut self,
+        fill_style: &swf::FillStyle,
+        vertices: &[swf::Point<swf::Twips>],
+        indices: &[u32],
+        uvs: Option<&[[f32; 2]]>,
+        bitmap_source: &dyn BitmapSource,
+    ) {
+        let (draw, color, needs_flush) = match (fill_style, uvs) {
+            (
+                swf::FillStyle::Bitmap {
+                    id,
+                    is_smoothed,
+                    is_repeating,
+                    ..
+                },
+                Some(uvs),
+            ) => {
+                if bitmap_source.bitmap_size(*id).is_none() {
+                    return;
+                }
+                let uvs = (0..vertices.len())
+                    .map(|i| uvs.get(i).copied().unwrap_or_default())
+                    .collect();
+                (
+                    DrawType::TexturedTriangles(TexturedTriangles {
+                        uvs,
+                        bitmap_id: *id,
+                        is_smoothed: *is_smoothed,
+                        is_repeating: *is_repeating,
+                    }),
+                    swf::Color::WHITE,
+                    true,
+                )
+            }
+            _ => match fill_style_to_draw(fill_style, bitmap_source) {
+                Some(draw) => draw,
+                None => return,
+            },
+        };
+
+        if needs_flush || self.is_stroke {
+            self.flush_draw(DrawType::Color);
+        }
+        self.is_stroke = false;
+
+        // The vertices are used as they are, so the UVs of textured triangles line up with them.
+        let first_vertex = self.lyon_mesh.vertices.len() as u32;
+        self.lyon_mesh
+            .vertices
+            .extend(vertices.iter().map(|vertex| Vertex {
+                x: vertex.x.to_pixels() as f32,
+                y: vertex.y.to_pixels() as f32,
+                color: color.clone(),
+            }));
+        self.lyon_mesh.indices.extend(
+            indices
+                .chunks_exact(3)
+                .filter(|triangle| triangle.iter().all(|&i| (i as usize) < vertices.len()))
+                .flatten()
+                .map(|i| first_vertex + i),
+        );
+
+        if needs_flush {
+            self.flush_draw(draw);
+        }
+    }
 }
 
 impl Default for ShapeTessellator {
@@ -235,6 +260,7 @@ pub enum DrawType {
     Color,
     Gradient(Gradient),
     Bitmap(Bitmap),
+    TexturedTriangles(TexturedTriangles),
 }
 
 impl DrawType {
@@ -243,6 +269,7 @@ impl DrawType {
             Self::Color => "Color",
             Self::Gradient { .. } => "Gradient",
             Self::Bitmap { .. } => "Bitmap",
+            Self::TexturedTriangles { .. } => "TexturedTriangles",
         }
     }
 }
@@ -272,6 +299,47 @@ pub struct Bitmap {
     pub is_repeating: bool,
 }
 
+/// A bitmap drawn onto triangles with a texture coordinate for each vertex.
+#[derive(Clone, Debug)]
+pub struct TexturedTriangles {
+    /// The normalized texture coordinates of each vertex of the draw.
+    pub uvs: Vec<[f32; 2]>,
+    pub bitmap_id: u16,
+    pub is_smoothed: bool,
+    pub is_repeating: bool,
+}
+
+impl TexturedTriangles {
+    /// Returns the texture matrix that maps the given triangle onto the given texture coordinates,
+    /// in the same form as `Bitmap::matrix`.
+    /// This allows renderers without per-vertex texture coordinates to draw each triangle as a
+    /// bitmap fill. Returns `None` for degenerate triangles.
+    pub fn triangle_matrix(positions: [[f32; 2]; 3], uvs: [[f32; 2]; 3]) -> Option<[[f32; 3]; 3]> {
+        let [x1, y1] = [
+            positions[1][0] - positions[0][0],
+            positions[1][1] - positions[0][1],
+        ];
+        let [x2, y2] = [
+            positions[2][0] - positions[0][0],
+            positions[2][1] - positions[0][1],
+        ];
+        let det = x1 * y2 - x2 * y1;
+        if det == 0.0 {
+            return None;
+        }
+        let [u1, v1] = [uvs[1][0] - uvs[0][0], uvs[1][1] - uvs[0][1]];
+        let [u2, v2] = [uvs[2][0] - uvs[0][0], uvs[2][1] - uvs[0][1]];
+
+        let a = (u1 * y2 - u2 * y1) / det;
+        let b = (u2 * x1 - u1 * x2) / det;
+        let d = (v1 * y2 - v2 * y1) / det;
+        let e = (v2 * x1 - v1 * x2) / det;
+        let c = uvs[0][0] - a * positions[0][0] - b * positions[0][1];
+        let f = uvs[0][1] - d * positions[0][0] - e * positions[0][1];
+        Some([[a, d, 0.0], [b, e, 0.0], [c, f, 1.0]])
+    }
+}
+
 #[allow(clippy::many_single_char_names)]
 fn swf_to_gl_matrix(m: crate::matrix::Matrix) -> [[f32; 3]; 3] {
     let tx = m.tx.get() as f32;
@@ -367,6 +435,71 @@ fn ruffle_path_to_lyon_path(commands: &[DrawCommand], is_closed: bool) -> Path {
     builder.build()
 }
 
+/// Returns the draw type and vertex color used for the given fill style,
+/// and whether it needs a draw call of its own.
+/// Returns `None` if the fill refers to a missing bitmap.
+fn fill_style_to_draw(
+    fill_style: &swf::FillStyle,
+    bitmap_source: &dyn BitmapSource,
+) -> Option<(DrawType, swf::Color, bool)> {
+    let draw = match fill_style {
+        swf::FillStyle::Color(color) => (DrawType::Color, color.clone(), false),
+        swf::FillStyle::LinearGradient(gradient) => (
+            DrawType::Gradient(swf_gradient_to_uniforms(
+                GradientType::Linear,
+                gradient,
+                swf::Fixed8::ZERO,
+            )),
+            swf::Color::WHITE,
+            true,
+        ),
+        swf::FillStyle::RadialGradient(gradient) => (
+            DrawType::Gradient(swf_gradient_to_uniforms(
+                GradientType::Radial,
+                gradient,
+                swf::Fixed8::ZERO,
+            )),
+            swf::Color::WHITE,
+            true,
+        ),
+        swf::FillStyle::FocalGradient {
+            gradient,
+            focal_point,
+        } => (
+            DrawType::Gradient(swf_gradient_to_uniforms(
+                GradientType::Focal,
+                gradient,
+                *focal_point,
+            )),
+            swf::Color::WHITE,
+            true,
+        ),
+        swf::FillStyle::Bitmap {
+            id,
+            matrix,
+            is_smoothed,
+            is_repeating,
+        } => {
+            let bitmap = bitmap_source.bitmap_size(*id)?;
+            (
+                DrawType::Bitmap(Bitmap {
+                    matrix: swf_bitmap_to_gl_matrix(
+                        (*matrix).into(),
+                        bitmap.width.into(),
+                        bitmap.height.into(),
+                    ),
+                    bitmap_id: *id,
+                    is_smoothed: *is_smoothed,
+                    is_repeating: *is_repeating,
+                }),
+                swf::Color::WHITE,
+                true,
+            )
+        }
+    };
+    Some(draw)
+}
+
 /// Converts a gradient to the uniforms used by the shader.
 fn swf_gradient_to_uniforms(
     gradient_type: GradientType,
@@ -406,3 +539,23 @@ impl StrokeVertexConstructor<Vertex> for RuffleVertexCtor {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn triangle_matrix() {
+        let positions = [[10.0, 10.0], [30.0, 10.0], [10.0, 50.0]];
+        let uvs = [[0.0, 0.0], [1.0, 0.0], [0.0, 1.0]];
+        let m = TexturedTriangles::triangle_matrix(positions, uvs).expect("Triangle is valid");
+        for (position, uv) in positions.iter().zip(uvs) {
+            let u = m[0][0] * position[0] + m[1][0] * position[1] + m[2][0];
+            let v = m[0][1] * position[0] + m[1][1] * position[1] + m[2][1];
+            assert!((u - uv[0]).abs() < 1e-6 && (v - uv[1]).abs() < 1e-6);
+        }
+
+        let degenerate = [[0.0, 0.0], [10.0, 10.0], [20.0, 20.0]];
+        assert!(TexturedTriangles::triangle_matrix(degenerate, uvs).is_none());
+    }
+}
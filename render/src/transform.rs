@@ -1,4 +1,5 @@
 use crate::matrix::Matrix;
+use crate::matrix3d::Matrix3D;
 use swf::ColorTransform;
 
 /// Represents the transform for a DisplayObject.
@@ -7,6 +8,30 @@ use swf::ColorTransform;
 pub struct Transform {
     pub matrix: Matrix,
     pub color_transform: ColorTransform,
+
+    /// The 3D transform that is applied after `matrix`, if any.
+    /// This is set for display objects that are drawn in perspective.
+    pub matrix3d: Option<Matrix3D>,
+}
+
+impl Transform {
+    /// The matrix that transforms from object space to stage space, for renderers that can
+    /// draw in perspective. See `Matrix3D::to_world_matrix`.
+    pub fn world_matrix(&self) -> [[f32; 4]; 4] {
+        match self.matrix3d {
+            Some(matrix3d) => (matrix3d * self.matrix).to_world_matrix(),
+            None => self.matrix.to_world_matrix(),
+        }
+    }
+
+    /// The affine matrix that transforms from object space to stage space.
+    /// The perspective of a 3D transform is dropped, for renderers that can't draw it.
+    pub fn flattened_matrix(&self) -> Matrix {
+        match self.matrix3d {
+            Some(matrix3d) => (matrix3d * self.matrix).to_matrix(),
+            None => self.matrix,
+        }
+    }
 }
 
 pub struct TransformStack(Vec<Transform>);
@@ -18,11 +43,22 @@ impl TransformStack {
 
     pub fn push(&mut self, transform: &Transform) {
         let cur_transform = self.transform();
-        let matrix = cur_transform.matrix * transform.matrix;
         let color_transform = cur_transform.color_transform * transform.color_transform;
+        // Once a 3D transform is involved, everything above it is folded into the 3D matrix.
+        let (matrix, matrix3d) = match transform.matrix3d {
+            Some(matrix3d) => (
+                transform.matrix,
+                Some(cur_transform.matrix3d.unwrap_or_default() * cur_transform.matrix * matrix3d),
+            ),
+            None => (
+                cur_transform.matrix * transform.matrix,
+                cur_transform.matrix3d,
+            ),
+        };
         self.0.push(Transform {
             matrix,
             color_transform,
+            matrix3d,
         });
     }
 
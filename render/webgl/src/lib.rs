@@ -74,6 +74,7 @@ const COLOR_FRAGMENT_GLSL: &str = include_str!("../shaders/color.frag");
 const TEXTURE_VERTEX_GLSL: &str = include_str!("../shaders/texture.vert");
 const GRADIENT_FRAGMENT_GLSL: &str = include_str!("../shaders/gradient.frag");
 const BITMAP_FRAGMENT_GLSL: &str = include_str!("../shaders/bitmap.frag");
+const TEXTURED_TRIANGLES_VERTEX_GLSL: &str = include_str!("../shaders/textured_triangles.vert");
 const NUM_VERTEX_ATTRIBUTES: u32 = 2;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -91,6 +92,14 @@ struct Vertex {
     color: u32,
 }
 
+/// A vertex with its own texture coordinates, used for textured triangles.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Pod, Zeroable)]
+struct UvVertex {
+    position: [f32; 2],
+    uv: [f32; 2],
+}
+
 impl From<TessVertex> for Vertex {
     fn from(vertex: TessVertex) -> Self {
         Self {
@@ -122,6 +131,7 @@ pub struct WebGlRenderBackend {
     color_program: ShaderProgram,
     bitmap_program: ShaderProgram,
     gradient_program: ShaderProgram,
+    textured_triangles_program: ShaderProgram,
 
     shape_tessellator: ShapeTessellator,
 
@@ -275,6 +285,10 @@ impl WebGlRenderBackend {
         let color_program = ShaderProgram::new(&gl, &color_vertex, &color_fragment)?;
         let bitmap_program = ShaderProgram::new(&gl, &texture_vertex, &bitmap_fragment)?;
         let gradient_program = ShaderProgram::new(&gl, &texture_vertex, &gradient_fragment)?;
+        let textured_triangles_vertex =
+            Self::compile_shader(&gl, Gl::VERTEX_SHADER, TEXTURED_TRIANGLES_VERTEX_GLSL)?;
+        let textured_triangles_program =
+            ShaderProgram::new(&gl, &textured_triangles_vertex, &bitmap_fragment)?;
 
         gl.enable(Gl::BLEND);
 
@@ -292,6 +306,7 @@ impl WebGlRenderBackend {
             color_program,
             gradient_program,
             bitmap_program,
+            textured_triangles_program,
 
             shape_tessellator: ShapeTessellator::new(),
 
@@ -578,12 +593,32 @@ impl WebGlRenderBackend {
             let vertex_buffer = self.gl.create_buffer().ok_or(Error::UnableToCreateBuffer)?;
             self.gl.bind_buffer(Gl::ARRAY_BUFFER, Some(&vertex_buffer));
 
-            let vertices: Vec<_> = draw.vertices.into_iter().map(Vertex::from).collect();
-            self.gl.buffer_data_with_u8_array(
-                Gl::ARRAY_BUFFER,
-                bytemuck::cast_slice(&vertices),
-                Gl::STATIC_DRAW,
-            );
+            // Textured triangles carry texture coordinates instead of a color.
+            let stride = if let TessDrawType::TexturedTriangles(textured) = &draw.draw_type {
+                let vertices: Vec<_> = draw
+                    .vertices
+                    .iter()
+                    .zip(&textured.uvs)
+                    .map(|(vertex, uv)| UvVertex {
+                        position: [vertex.x, vertex.y],
+                        uv: *uv,
+                    })
+                    .collect();
+                self.gl.buffer_data_with_u8_array(
+                    Gl::ARRAY_BUFFER,
+                    bytemuck::cast_slice(&vertices),
+                    Gl::STATIC_DRAW,
+                );
+                std::mem::size_of::<UvVertex>() as i32
+            } else {
+                let vertices: Vec<_> = draw.vertices.into_iter().map(Vertex::from).collect();
+                self.gl.buffer_data_with_u8_array(
+                    Gl::ARRAY_BUFFER,
+                    bytemuck::cast_slice(&vertices),
+                    Gl::STATIC_DRAW,
+                );
+                std::mem::size_of::<Vertex>() as i32
+            };
 
             let index_buffer = self.gl.create_buffer().ok_or(Error::UnableToCreateBuffer)?;
             self.gl
@@ -598,6 +633,7 @@ impl WebGlRenderBackend {
                 TessDrawType::Color => &self.color_program,
                 TessDrawType::Gradient(_) => &self.gradient_program,
                 TessDrawType::Bitmap(_) => &self.bitmap_program,
+                TessDrawType::TexturedTriangles(_) => &self.textured_triangles_program,
             };
 
             // Unfortunately it doesn't seem to be possible to ensure that vertex attributes will be in
@@ -610,7 +646,7 @@ impl WebGlRenderBackend {
                     2,
                     Gl::FLOAT,
                     false,
-                    12,
+                    stride,
                     0,
                 );
                 self.gl
@@ -623,13 +659,26 @@ impl WebGlRenderBackend {
                     4,
                     Gl::UNSIGNED_BYTE,
                     true,
-                    12,
+                    stride,
                     8,
                 );
                 self.gl
                     .enable_vertex_attrib_array(program.vertex_color_location);
             }
 
+            if program.vertex_uv_location != 0xffff_ffff {
+                self.gl.vertex_attrib_pointer_with_i32(
+                    program.vertex_uv_location,
+                    2,
+                    Gl::FLOAT,
+                    false,
+                    stride,
+                    8,
+                );
+                self.gl
+                    .enable_vertex_attrib_array(program.vertex_uv_location);
+            }
+
             let num_vertex_attributes = program.num_vertex_attributes;
 
             draws.push(match draw.draw_type {
@@ -680,6 +729,25 @@ impl WebGlRenderBackend {
                     num_indices,
                     num_mask_indices,
                 },
+                TessDrawType::TexturedTriangles(textured) => Draw {
+                    draw_type: DrawType::TexturedTriangles(BitmapDraw {
+                        matrix: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+                        handle: bitmap_source.bitmap_handle(textured.bitmap_id, self),
+                        is_smoothed: textured.is_smoothed,
+                        is_repeating: textured.is_repeating,
+                    }),
+                    vao,
+                    vertex_buffer: Buffer {
+                        gl: self.gl.clone(),
+                        buffer: vertex_buffer,
+                    },
+                    index_buffer: Buffer {
+                        gl: self.gl.clone(),
+                        buffer: index_buffer,
+                    },
+                    num_indices,
+                    num_mask_indices,
+                },
             });
 
             self.bind_vertex_array(None);
@@ -1115,23 +1183,15 @@ impl CommandHandler for WebGlRenderBackend {
         };
 
         // Scale the quad to the bitmap's dimensions.
-        let matrix = transform.matrix
-            * ruffle_render::matrix::Matrix::scale(
-                entry.bitmap.width() as f32,
-                entry.bitmap.height() as f32,
-            );
-
-        let world_matrix = [
-            [matrix.a, matrix.b, 0.0, 0.0],
-            [matrix.c, matrix.d, 0.0, 0.0],
-            [0.0, 0.0, 1.0, 0.0],
-            [
-                matrix.tx.to_pixels() as f32,
-                matrix.ty.to_pixels() as f32,
-                0.0,
-                1.0,
-            ],
-        ];
+        let world_matrix = Transform {
+            matrix: transform.matrix
+                * ruffle_render::matrix::Matrix::scale(
+                    entry.bitmap.width() as f32,
+                    entry.bitmap.height() as f32,
+                ),
+            ..transform.clone()
+        }
+        .world_matrix();
 
         let mult_color = transform.color_transform.mult_rgba_normalized();
         let add_color = transform.color_transform.add_rgba_normalized();
@@ -1193,17 +1253,7 @@ impl CommandHandler for WebGlRenderBackend {
     }
 
     fn render_shape(&mut self, shape: ShapeHandle, transform: Transform) {
-        let world_matrix = [
-            [transform.matrix.a, transform.matrix.b, 0.0, 0.0],
-            [transform.matrix.c, transform.matrix.d, 0.0, 0.0],
-            [0.0, 0.0, 1.0, 0.0],
-            [
-                transform.matrix.tx.to_pixels() as f32,
-                transform.matrix.ty.to_pixels() as f32,
-                0.0,
-                1.0,
-            ],
-        ];
+        let world_matrix = transform.world_matrix();
 
         let mult_color = transform.color_transform.mult_rgba_normalized();
         let add_color = transform.color_transform.add_rgba_normalized();
@@ -1230,6 +1280,7 @@ impl CommandHandler for WebGlRenderBackend {
                 DrawType::Color => &self.color_program,
                 DrawType::Gradient(_) => &self.gradient_program,
                 DrawType::Bitmap { .. } => &self.bitmap_program,
+                DrawType::TexturedTriangles { .. } => &self.textured_triangles_program,
             };
 
             // Set common render state, while minimizing unnecessary state changes.
@@ -1291,7 +1342,7 @@ impl CommandHandler for WebGlRenderBackend {
                         (gradient.interpolation == swf::GradientInterpolation::LinearRgb) as i32,
                     );
                 }
-                DrawType::Bitmap(bitmap) => {
+                DrawType::Bitmap(bitmap) | DrawType::TexturedTriangles(bitmap) => {
                     let texture = match &bitmap.handle {
                         Some(handle) => &as_registry_data(handle).texture,
                         None => {
@@ -1345,17 +1396,7 @@ impl CommandHandler for WebGlRenderBackend {
     }
 
     fn draw_rect(&mut self, color: Color, matrix: ruffle_render::matrix::Matrix) {
-        let world_matrix = [
-            [matrix.a, matrix.b, 0.0, 0.0],
-            [matrix.c, matrix.d, 0.0, 0.0],
-            [0.0, 0.0, 1.0, 0.0],
-            [
-                matrix.tx.to_pixels() as f32,
-                matrix.ty.to_pixels() as f32,
-                0.0,
-                1.0,
-            ],
-        ];
+        let world_matrix = matrix.to_world_matrix();
 
         let mult_color = [
             color.r as f32 * 255.0,
@@ -1564,6 +1605,8 @@ enum DrawType {
     Color,
     Gradient(Box<Gradient>),
     Bitmap(BitmapDraw),
+    /// A bitmap drawn with the texture coordinates of each vertex.
+    TexturedTriangles(BitmapDraw),
 }
 
 struct MsaaBuffers {
@@ -1582,6 +1625,7 @@ struct ShaderProgram {
     uniforms: [Option<WebGlUniformLocation>; NUM_UNIFORMS],
     vertex_position_location: u32,
     vertex_color_location: u32,
+    vertex_uv_location: u32,
     num_vertex_attributes: u32,
 }
 
@@ -1649,21 +1693,22 @@ impl ShaderProgram {
 
         let vertex_position_location = gl.get_attrib_location(&program, "position") as u32;
         let vertex_color_location = gl.get_attrib_location(&program, "color") as u32;
-        let num_vertex_attributes = if vertex_position_location != 0xffff_ffff {
-            1
-        } else {
-            0
-        } + if vertex_color_location != 0xffff_ffff {
-            1
-        } else {
-            0
-        };
+        let vertex_uv_location = gl.get_attrib_location(&program, "uv") as u32;
+        let num_vertex_attributes = [
+            vertex_position_location,
+            vertex_color_location,
+            vertex_uv_location,
+        ]
+        .iter()
+        .filter(|&&location| location != 0xffff_ffff)
+        .count() as u32;
 
         Ok(ShaderProgram {
             program,
             uniforms,
             vertex_position_location,
             vertex_color_location,
+            vertex_uv_location,
             num_vertex_attributes,
         })
     }
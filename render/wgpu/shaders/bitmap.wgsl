@@ -20,13 +20,29 @@ struct VertexOutput {
     @group(3) @binding(2) var texture_sampler: sampler;
 #endif
 
+#if vertex_uvs == true
+    /// Vertices with their own texture coordinates, which are used instead of the texture matrix.
+    struct TexturedVertexInput {
+        @location(0) position: vec2<f32>,
+        @location(1) uv: vec2<f32>,
+    };
+#endif
+
 @vertex
+#if vertex_uvs == true
+fn main_vertex(in: TexturedVertexInput) -> VertexOutput {
+#else
 fn main_vertex(in: common::VertexInput) -> VertexOutput {
+#endif
     #if use_push_constants == true
         var transforms = pc.transforms;
     #endif
-    let matrix_ = textureTransforms.texture_matrix;
-    let uv = (mat3x3<f32>(matrix_[0].xyz, matrix_[1].xyz, matrix_[2].xyz) * vec3<f32>(in.position, 1.0)).xy;
+    #if vertex_uvs == true
+        let uv = in.uv;
+    #else
+        let matrix_ = textureTransforms.texture_matrix;
+        let uv = (mat3x3<f32>(matrix_[0].xyz, matrix_[1].xyz, matrix_[2].xyz) * vec3<f32>(in.position, 1.0)).xy;
+    #endif
     let pos = common::globals.view_matrix * transforms.world_matrix * vec4<f32>(in.position.x, in.position.y, 0.0, 1.0);
     return VertexOutput(pos, uv);
 }
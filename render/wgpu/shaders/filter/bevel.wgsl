@@ -0,0 +1,62 @@
+#import filter
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+};
+
+struct Filter {
+    offset: vec2<f32>,
+    strength: f32,
+    inner: u32,
+    knockout: u32,
+    on_top: u32,
+    _padding1: u32,
+    _padding2: u32,
+}
+
+#if use_push_constants == true
+    @group(2) @binding(0) var<uniform> filter_args: Filter;
+    @group(2) @binding(1) var blurred: texture_2d<f32>;
+    @group(2) @binding(2) var gradient: texture_2d<f32>;
+    @group(2) @binding(3) var filter_sampler: sampler;
+#else
+    @group(4) @binding(0) var<uniform> filter_args: Filter;
+    @group(4) @binding(1) var blurred: texture_2d<f32>;
+    @group(4) @binding(2) var gradient: texture_2d<f32>;
+    @group(4) @binding(3) var filter_sampler: sampler;
+#endif
+
+@vertex
+fn main_vertex(in: filter::FilterVertexInput) -> filter::VertexOutput {
+    return filter::main_vertex(in);
+}
+
+@fragment
+fn main_fragment(in: filter::VertexOutput) -> @location(0) vec4<f32> {
+    let f = filter_args;
+    let src = textureSample(filter::texture, filter::texture_sampler, in.uv);
+
+    // The blurred texture is the same size as our target, so sample it using the pixel position.
+    let size = vec2<f32>(textureDimensions(blurred));
+    let shadow = textureSample(blurred, filter_sampler, (in.position.xy - f.offset) / size).a;
+    let highlight = textureSample(blurred, filter_sampler, (in.position.xy + f.offset) / size).a;
+
+    // The start of the gradient is the highlight, and the end is the shadow.
+    let amount = clamp((shadow - highlight) * f.strength, -1.0, 1.0);
+    var bevel = textureSample(gradient, filter_sampler, vec2<f32>((amount * 127.5 + 128.0) / 256.0, 0.5));
+
+    if f.inner != 0u {
+        bevel *= src.a;
+    } else if f.on_top == 0u {
+        bevel *= 1.0 - src.a;
+    }
+
+    if f.knockout != 0u {
+        return bevel;
+    }
+    if f.inner != 0u || f.on_top != 0u {
+        return bevel + src * (1.0 - bevel.a);
+    }
+    return src + bevel * (1.0 - src.a);
+}
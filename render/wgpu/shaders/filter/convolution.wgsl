@@ -0,0 +1,72 @@
+#import filter
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+};
+
+struct Filter {
+    default_color: vec4<f32>,
+    source_rect: vec4<i32>,
+    matrix_x: u32,
+    matrix_y: u32,
+    divisor: f32,
+    bias: f32,
+    clamp: u32,
+    preserve_alpha: u32,
+    _padding1: u32,
+    _padding2: u32,
+    matrix: array<vec4<f32>, 64>,
+}
+
+#if use_push_constants == true
+    @group(2) @binding(0) var<uniform> filter_args: Filter;
+#else
+    @group(4) @binding(0) var<uniform> filter_args: Filter;
+#endif
+
+@vertex
+fn main_vertex(in: filter::FilterVertexInput) -> filter::VertexOutput {
+    return filter::main_vertex(in);
+}
+
+fn unmultiply(color: vec4<f32>) -> vec4<f32> {
+    if color.a == 0.0 {
+        return vec4<f32>(0.0);
+    }
+    return vec4<f32>(color.rgb / color.a, color.a);
+}
+
+@fragment
+fn main_fragment(in: filter::VertexOutput) -> @location(0) vec4<f32> {
+    let rect_min = filter_args.source_rect.xy;
+    let rect_max = filter_args.source_rect.zw;
+    let position = rect_min + vec2<i32>(floor(in.position.xy));
+    let src = textureLoad(filter::texture, position, 0);
+    let preserve_alpha = filter_args.preserve_alpha != 0u;
+
+    var total = vec4<f32>(0.0);
+    let center = vec2<i32>(i32(filter_args.matrix_x / 2u), i32(filter_args.matrix_y / 2u));
+    for (var y = 0u; y < filter_args.matrix_y; y += 1u) {
+        for (var x = 0u; x < filter_args.matrix_x; x += 1u) {
+            var coords = position + vec2<i32>(i32(x), i32(y)) - center;
+            var sample = filter_args.default_color;
+            let inside = all(coords >= rect_min) && all(coords < rect_max);
+            if inside || filter_args.clamp != 0u {
+                sample = textureLoad(filter::texture, clamp(coords, rect_min, rect_max - 1), 0);
+            }
+            if preserve_alpha {
+                sample = unmultiply(sample);
+            }
+            let i = y * filter_args.matrix_x + x;
+            total += sample * filter_args.matrix[i / 4u][i % 4u];
+        }
+    }
+
+    let color = total / filter_args.divisor + filter_args.bias / 255.0;
+    if preserve_alpha {
+        return vec4<f32>(saturate(color.rgb) * src.a, src.a);
+    }
+    let alpha = saturate(color.a);
+    return vec4<f32>(clamp(color.rgb, vec3<f32>(0.0), vec3<f32>(alpha)), alpha);
+}
@@ -0,0 +1,96 @@
+#import filter
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+};
+
+struct Filter {
+    color: vec4<f32>,
+    source_rect: vec4<i32>,
+    map_point: vec2<i32>,
+    scale: vec2<f32>,
+    component_x: u32,
+    component_y: u32,
+    mode: u32,
+    _padding: u32,
+}
+
+#if use_push_constants == true
+    @group(2) @binding(0) var<uniform> filter_args: Filter;
+    @group(2) @binding(1) var map_texture: texture_2d<f32>;
+#else
+    @group(4) @binding(0) var<uniform> filter_args: Filter;
+    @group(4) @binding(1) var map_texture: texture_2d<f32>;
+#endif
+
+@vertex
+fn main_vertex(in: filter::FilterVertexInput) -> filter::VertexOutput {
+    return filter::main_vertex(in);
+}
+
+/// Reads a channel of the map, using the values of `BitmapDataChannel`.
+fn component(color: vec4<f32>, channel: u32) -> f32 {
+    switch channel {
+        case 1u: {
+            return color.r;
+        }
+        case 2u: {
+            return color.g;
+        }
+        case 4u: {
+            return color.b;
+        }
+        case 8u: {
+            return color.a;
+        }
+        default: {
+            // This is the middle of the range, which doesn't displace anything.
+            return 128.0 / 255.0;
+        }
+    }
+}
+
+@fragment
+fn main_fragment(in: filter::VertexOutput) -> @location(0) vec4<f32> {
+    let f = filter_args;
+    let rect_min = f.source_rect.xy;
+    let rect_max = f.source_rect.zw;
+    let offset = vec2<i32>(floor(in.position.xy));
+    let position = rect_min + offset;
+
+    var displacement = vec2<f32>(0.0);
+    let map_coords = offset - f.map_point;
+    if all(map_coords >= vec2<i32>(0)) && all(map_coords < vec2<i32>(textureDimensions(map_texture))) {
+        var map_color = textureLoad(map_texture, map_coords, 0);
+        if map_color.a > 0.0 {
+            map_color = vec4<f32>(map_color.rgb / map_color.a, map_color.a);
+        }
+        let value = vec2<f32>(component(map_color, f.component_x), component(map_color, f.component_y));
+        displacement = (round(value * 255.0) - 128.0) * f.scale / 256.0;
+    }
+
+    var coords = position + vec2<i32>(floor(displacement));
+    if any(coords < rect_min) || any(coords >= rect_max) {
+        let size = rect_max - rect_min;
+        switch f.mode {
+            // Wrap
+            case 0u: {
+                coords = rect_min + ((coords - rect_min) % size + size) % size;
+            }
+            // Clamp
+            case 1u: {
+                coords = clamp(coords, rect_min, rect_max - 1);
+            }
+            // Ignore
+            case 2u: {
+                coords = position;
+            }
+            // Color
+            default: {
+                return f.color;
+            }
+        }
+    }
+    return textureLoad(filter::texture, coords, 0);
+}
@@ -0,0 +1,63 @@
+#import filter
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) uv: vec2<f32>,
+};
+
+struct Filter {
+    offset: vec2<f32>,
+    strength: f32,
+    inner: u32,
+    knockout: u32,
+    composite_source: u32,
+    _padding1: u32,
+    _padding2: u32,
+}
+
+#if use_push_constants == true
+    @group(2) @binding(0) var<uniform> filter_args: Filter;
+    @group(2) @binding(1) var blurred: texture_2d<f32>;
+    @group(2) @binding(2) var gradient: texture_2d<f32>;
+    @group(2) @binding(3) var filter_sampler: sampler;
+#else
+    @group(4) @binding(0) var<uniform> filter_args: Filter;
+    @group(4) @binding(1) var blurred: texture_2d<f32>;
+    @group(4) @binding(2) var gradient: texture_2d<f32>;
+    @group(4) @binding(3) var filter_sampler: sampler;
+#endif
+
+@vertex
+fn main_vertex(in: filter::FilterVertexInput) -> filter::VertexOutput {
+    return filter::main_vertex(in);
+}
+
+@fragment
+fn main_fragment(in: filter::VertexOutput) -> @location(0) vec4<f32> {
+    let f = filter_args;
+    let src = textureSample(filter::texture, filter::texture_sampler, in.uv);
+
+    // The blurred texture is the same size as our target, so sample it using the pixel position.
+    let size = vec2<f32>(textureDimensions(blurred));
+    var alpha = textureSample(blurred, filter_sampler, (in.position.xy - f.offset) / size).a;
+    alpha = select(alpha, 1.0 - alpha, f.inner != 0u);
+    alpha = saturate(alpha * f.strength);
+
+    // The gradient holds premultiplied colors, indexed by the strength of the glow.
+    let glow = textureSample(gradient, filter_sampler, vec2<f32>((alpha * 255.0 + 0.5) / 256.0, 0.5));
+
+    if f.inner != 0u {
+        let inner_glow = glow * src.a;
+        if f.knockout != 0u || f.composite_source == 0u {
+            return inner_glow;
+        }
+        return inner_glow + src * (1.0 - inner_glow.a);
+    }
+    if f.knockout != 0u {
+        return glow * (1.0 - src.a);
+    }
+    if f.composite_source == 0u {
+        return glow;
+    }
+    return src + glow * (1.0 - src.a);
+}
@@ -661,7 +661,18 @@ impl<T: RenderTarget + 'static> RenderBackend for WgpuRenderBackend<T> {
     }
 
     fn is_filter_supported(&self, filter: &Filter) -> bool {
-        matches!(filter, Filter::BlurFilter(_) | Filter::ColorMatrixFilter(_))
+        matches!(
+            filter,
+            Filter::BevelFilter(_)
+                | Filter::BlurFilter(_)
+                | Filter::ColorMatrixFilter(_)
+                | Filter::ConvolutionFilter(_)
+                | Filter::DisplacementMapFilter(_)
+                | Filter::DropShadowFilter(_)
+                | Filter::GlowFilter(_)
+                | Filter::GradientBevelFilter(_)
+                | Filter::GradientGlowFilter(_)
+        )
     }
 
     fn apply_filter(
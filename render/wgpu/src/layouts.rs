@@ -1,4 +1,7 @@
 use crate::globals::GlobalsUniform;
+use crate::surface::filters::{
+    BevelFilterUniform, ConvolutionFilterUniform, DisplacementMapFilterUniform, GlowFilterUniform,
+};
 use crate::{ColorAdjustments, GradientUniforms, TextureTransforms, Transforms};
 
 #[derive(Debug)]
@@ -11,6 +14,10 @@ pub struct BindLayouts {
     pub blend: wgpu::BindGroupLayout,
     pub color_matrix_filter: wgpu::BindGroupLayout,
     pub blur_filter: wgpu::BindGroupLayout,
+    pub glow_filter: wgpu::BindGroupLayout,
+    pub bevel_filter: wgpu::BindGroupLayout,
+    pub convolution_filter: wgpu::BindGroupLayout,
+    pub displacement_map_filter: wgpu::BindGroupLayout,
 }
 
 impl BindLayouts {
@@ -211,6 +218,65 @@ impl BindLayouts {
             label: create_debug_label!("Blur filter binds").as_deref(),
         });
 
+        let glow_filter = create_shadow_filter_layout(
+            device,
+            std::mem::size_of::<GlowFilterUniform>(),
+            create_debug_label!("Glow filter binds").as_deref(),
+        );
+
+        let bevel_filter = create_shadow_filter_layout(
+            device,
+            std::mem::size_of::<BevelFilterUniform>(),
+            create_debug_label!("Bevel filter binds").as_deref(),
+        );
+
+        let convolution_filter =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                entries: &[wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStages::FRAGMENT,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Uniform,
+                        has_dynamic_offset: false,
+                        min_binding_size: wgpu::BufferSize::new(std::mem::size_of::<
+                            ConvolutionFilterUniform,
+                        >() as u64),
+                    },
+                    count: None,
+                }],
+                label: create_debug_label!("Convolution filter binds").as_deref(),
+            });
+
+        let displacement_map_filter =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                entries: &[
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 0,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Uniform,
+                            has_dynamic_offset: false,
+                            min_binding_size: wgpu::BufferSize::new(std::mem::size_of::<
+                                DisplacementMapFilterUniform,
+                            >()
+                                as u64),
+                        },
+                        count: None,
+                    },
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 1,
+                        visibility: wgpu::ShaderStages::FRAGMENT,
+                        ty: wgpu::BindingType::Texture {
+                            multisampled: false,
+                            sample_type: wgpu::TextureSampleType::Float { filterable: false },
+                            view_dimension: wgpu::TextureViewDimension::D2,
+                        },
+                        count: None,
+                    },
+                ],
+                label: create_debug_label!("Displacement map filter binds").as_deref(),
+            });
+
         Self {
             globals,
             transforms,
@@ -220,6 +286,59 @@ impl BindLayouts {
             blend,
             color_matrix_filter,
             blur_filter,
+            glow_filter,
+            bevel_filter,
+            convolution_filter,
+            displacement_map_filter,
         }
     }
 }
+
+/// Glow and bevel filters both read a blurred copy of the source and a gradient.
+fn create_shadow_filter_layout(
+    device: &wgpu::Device,
+    uniform_size: usize,
+    label: Option<&str>,
+) -> wgpu::BindGroupLayout {
+    device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+        entries: &[
+            wgpu::BindGroupLayoutEntry {
+                binding: 0,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Buffer {
+                    ty: wgpu::BufferBindingType::Uniform,
+                    has_dynamic_offset: false,
+                    min_binding_size: wgpu::BufferSize::new(uniform_size as u64),
+                },
+                count: None,
+            },
+            wgpu::BindGroupLayoutEntry {
+                binding: 1,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Texture {
+                    multisampled: false,
+                    sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                    view_dimension: wgpu::TextureViewDimension::D2,
+                },
+                count: None,
+            },
+            wgpu::BindGroupLayoutEntry {
+                binding: 2,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Texture {
+                    multisampled: false,
+                    sample_type: wgpu::TextureSampleType::Float { filterable: true },
+                    view_dimension: wgpu::TextureViewDimension::D2,
+                },
+                count: None,
+            },
+            wgpu::BindGroupLayoutEntry {
+                binding: 3,
+                visibility: wgpu::ShaderStages::FRAGMENT,
+                ty: wgpu::BindingType::Sampler(wgpu::SamplerBindingType::Filtering),
+                count: None,
+            },
+        ],
+        label,
+    })
+}
@@ -135,6 +135,13 @@ impl From<TessVertex> for PosColorVertex {
     }
 }
 
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Pod, Zeroable)]
+struct PosUvVertex {
+    position: [f32; 2],
+    uv: [f32; 2],
+}
+
 #[repr(C)]
 #[derive(Copy, Clone, Debug, Pod, Zeroable)]
 struct GradientUniforms {
@@ -1,7 +1,8 @@
 use crate::backend::WgpuRenderBackend;
 use crate::target::RenderTarget;
 use crate::{
-    as_texture, Descriptors, GradientUniforms, PosColorVertex, PosVertex, TextureTransforms,
+    as_texture, Descriptors, GradientUniforms, PosColorVertex, PosUvVertex, PosVertex,
+    TextureTransforms,
 };
 use std::ops::Range;
 use wgpu::util::DeviceExt;
@@ -77,6 +78,17 @@ impl PendingDraw {
                 .map(PosColorVertex::from)
                 .collect();
             vertex_buffer.add(&vertices)
+        } else if let TessDrawType::TexturedTriangles(textured) = &draw.draw_type {
+            let vertices: Vec<_> = draw
+                .vertices
+                .iter()
+                .zip(&textured.uvs)
+                .map(|(vertex, uv)| PosUvVertex {
+                    position: [vertex.x, vertex.y],
+                    uv: *uv,
+                })
+                .collect();
+            vertex_buffer.add(&vertices)
         } else {
             let vertices: Vec<_> = draw.vertices.into_iter().map(PosVertex::from).collect();
             vertex_buffer.add(&vertices)
@@ -94,9 +106,30 @@ impl PendingDraw {
                 draw_id,
                 uniform_buffer,
             ),
-            TessDrawType::Bitmap(bitmap) => {
-                PendingDrawType::bitmap(bitmap, shape_id, draw_id, source, backend, uniform_buffer)?
-            }
+            TessDrawType::Bitmap(bitmap) => PendingDrawType::bitmap(
+                bitmap,
+                false,
+                shape_id,
+                draw_id,
+                source,
+                backend,
+                uniform_buffer,
+            )?,
+            TessDrawType::TexturedTriangles(textured) => PendingDrawType::bitmap(
+                Bitmap {
+                    // The texture coordinates come from the vertices instead.
+                    matrix: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+                    bitmap_id: textured.bitmap_id,
+                    is_smoothed: textured.is_smoothed,
+                    is_repeating: textured.is_repeating,
+                },
+                true,
+                shape_id,
+                draw_id,
+                source,
+                backend,
+                uniform_buffer,
+            )?,
         };
         Some(PendingDraw {
             draw_type,
@@ -123,6 +156,8 @@ pub enum PendingDrawType {
         texture_view: wgpu::TextureView,
         is_repeating: bool,
         is_smoothed: bool,
+        /// Whether the texture coordinates are given by each vertex.
+        vertex_uvs: bool,
         bind_group_label: Option<String>,
     },
 }
@@ -244,6 +279,7 @@ impl PendingDrawType {
 
     pub fn bitmap(
         bitmap: Bitmap,
+        vertex_uvs: bool,
         shape_id: CharacterId,
         draw_id: usize,
         source: &dyn BitmapSource,
@@ -262,6 +298,7 @@ impl PendingDrawType {
             texture_view,
             is_repeating: bitmap.is_repeating,
             is_smoothed: bitmap.is_smoothed,
+            vertex_uvs,
             bind_group_label,
         })
     }
@@ -320,6 +357,7 @@ impl PendingDrawType {
                 texture_view,
                 is_repeating,
                 is_smoothed,
+                vertex_uvs,
                 bind_group_label,
             } => {
                 let binds = BitmapBinds::new(
@@ -334,7 +372,11 @@ impl PendingDrawType {
                     bind_group_label,
                 );
 
-                DrawType::Bitmap { binds }
+                if vertex_uvs {
+                    DrawType::TexturedTriangles { binds }
+                } else {
+                    DrawType::Bitmap { binds }
+                }
             }
         }
     }
@@ -346,6 +388,7 @@ pub enum DrawType {
     Color,
     Gradient { bind_group: wgpu::BindGroup },
     Bitmap { binds: BitmapBinds },
+    TexturedTriangles { binds: BitmapBinds },
 }
 
 #[derive(Debug)]
@@ -1,7 +1,7 @@
 use crate::blend::{ComplexBlend, TrivialBlend};
 use crate::layouts::BindLayouts;
 use crate::shaders::Shaders;
-use crate::{MaskState, PosColorVertex, PosVertex, PushConstants, Transforms};
+use crate::{MaskState, PosColorVertex, PosUvVertex, PosVertex, PushConstants, Transforms};
 use enum_map::{enum_map, Enum, EnumMap};
 use std::mem;
 use wgpu::{vertex_attr_array, BlendState};
@@ -25,6 +25,16 @@ pub const VERTEX_BUFFERS_DESCRIPTION_COLOR: [wgpu::VertexBufferLayout; 1] =
         ],
     }];
 
+pub const VERTEX_BUFFERS_DESCRIPTION_POS_UV: [wgpu::VertexBufferLayout; 1] =
+    [wgpu::VertexBufferLayout {
+        array_stride: std::mem::size_of::<PosUvVertex>() as u64,
+        step_mode: wgpu::VertexStepMode::Vertex,
+        attributes: &vertex_attr_array![
+            0 => Float32x2,
+            1 => Float32x2,
+        ],
+    }];
+
 #[derive(Debug)]
 pub struct ShapePipeline {
     pub pipelines: EnumMap<MaskState, wgpu::RenderPipeline>,
@@ -44,10 +54,17 @@ pub struct Pipelines {
     /// or use it any any way.
     pub bitmap_opaque_dummy_depth: wgpu::RenderPipeline,
     pub bitmap: EnumMap<TrivialBlend, ShapePipeline>,
+    /// Renders a bitmap onto triangles with their own texture coordinates,
+    /// as drawn by `Graphics.drawTriangles`.
+    pub textured_triangles: ShapePipeline,
     pub gradients: ShapePipeline,
     pub complex_blends: EnumMap<ComplexBlend, ShapePipeline>,
     pub color_matrix_filter: wgpu::RenderPipeline,
     pub blur_filter: wgpu::RenderPipeline,
+    pub glow_filter: wgpu::RenderPipeline,
+    pub bevel_filter: wgpu::RenderPipeline,
+    pub convolution_filter: wgpu::RenderPipeline,
+    pub displacement_map_filter: wgpu::RenderPipeline,
 }
 
 impl ShapePipeline {
@@ -207,6 +224,18 @@ impl Pipelines {
             .try_into()
             .unwrap();
 
+        let textured_triangles_pipeline = create_shape_pipeline(
+            "Textured triangles",
+            device,
+            format,
+            &shaders.textured_triangles_shader,
+            msaa_sample_count,
+            &VERTEX_BUFFERS_DESCRIPTION_POS_UV,
+            &bitmap_blend_bindings,
+            TrivialBlend::Normal.blend_state(),
+            full_push_constants,
+        );
+
         let bitmap_opaque_pipeline_layout_label =
             create_debug_label!("Opaque bitmap pipeline layout");
         let bitmap_opaque_pipeline_layout =
@@ -364,15 +393,64 @@ impl Pipelines {
             multiview: None,
         });
 
+        let glow_filter = create_filter_pipeline(
+            "Glow Filter",
+            device,
+            format,
+            &shaders.glow_filter,
+            msaa_sample_count,
+            bind_layouts,
+            &bind_layouts.glow_filter,
+            full_push_constants,
+        );
+
+        let bevel_filter = create_filter_pipeline(
+            "Bevel Filter",
+            device,
+            format,
+            &shaders.bevel_filter,
+            msaa_sample_count,
+            bind_layouts,
+            &bind_layouts.bevel_filter,
+            full_push_constants,
+        );
+
+        let convolution_filter = create_filter_pipeline(
+            "Convolution Filter",
+            device,
+            format,
+            &shaders.convolution_filter,
+            msaa_sample_count,
+            bind_layouts,
+            &bind_layouts.convolution_filter,
+            full_push_constants,
+        );
+
+        let displacement_map_filter = create_filter_pipeline(
+            "Displacement Map Filter",
+            device,
+            format,
+            &shaders.displacement_map_filter,
+            msaa_sample_count,
+            bind_layouts,
+            &bind_layouts.displacement_map_filter,
+            full_push_constants,
+        );
+
         Self {
             color: color_pipelines,
             bitmap: EnumMap::from_array(bitmap_pipelines),
+            textured_triangles: textured_triangles_pipeline,
             bitmap_opaque,
             bitmap_opaque_dummy_depth,
             gradients: gradient_pipeline,
             complex_blends: complex_blend_pipelines,
             color_matrix_filter,
             blur_filter,
+            glow_filter,
+            bevel_filter,
+            convolution_filter,
+            displacement_map_filter,
         }
     }
 }
@@ -420,6 +498,48 @@ fn create_pipeline_descriptor<'a>(
     }
 }
 
+/// Creates a pipeline that draws a filtered copy of a texture.
+#[allow(clippy::too_many_arguments)]
+fn create_filter_pipeline(
+    name: &str,
+    device: &wgpu::Device,
+    format: wgpu::TextureFormat,
+    shader: &wgpu::ShaderModule,
+    msaa_sample_count: u32,
+    bind_layouts: &BindLayouts,
+    filter_layout: &wgpu::BindGroupLayout,
+    push_constant_ranges: &[wgpu::PushConstantRange],
+) -> wgpu::RenderPipeline {
+    let bind_group_layouts = if device.limits().max_push_constant_size > 0 {
+        vec![&bind_layouts.globals, &bind_layouts.bitmap, filter_layout]
+    } else {
+        vec![
+            &bind_layouts.globals,
+            &bind_layouts.transforms,
+            &bind_layouts.color_transforms,
+            &bind_layouts.bitmap,
+            filter_layout,
+        ]
+    };
+    let pipeline_layout_label = create_debug_label!("{} pipeline layout", name);
+    let pipeline_layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+        label: pipeline_layout_label.as_deref(),
+        bind_group_layouts: &bind_group_layouts,
+        push_constant_ranges,
+    });
+
+    device.create_render_pipeline(&create_pipeline_descriptor(
+        create_debug_label!("{}", name).as_deref(),
+        shader,
+        shader,
+        &pipeline_layout,
+        None,
+        &[Some(format.into())],
+        &VERTEX_BUFFERS_DESCRIPTION_POS,
+        msaa_sample_count,
+    ))
+}
+
 #[allow(clippy::too_many_arguments)]
 fn create_shape_pipeline(
     name: &str,
@@ -17,12 +17,18 @@ pub struct Shaders {
     /// of whether dividing by the alpha value would produce
     /// an out-of-range value).
     pub bitmap_late_saturate_shader: wgpu::ShaderModule,
+    /// Like `bitmap_shader`, but takes the texture coordinates from each vertex.
+    pub textured_triangles_shader: wgpu::ShaderModule,
     pub gradient_shader: wgpu::ShaderModule,
     pub copy_srgb_shader: wgpu::ShaderModule,
     pub copy_shader: wgpu::ShaderModule,
     pub blend_shaders: EnumMap<ComplexBlend, wgpu::ShaderModule>,
     pub color_matrix_filter: wgpu::ShaderModule,
     pub blur_filter: wgpu::ShaderModule,
+    pub glow_filter: wgpu::ShaderModule,
+    pub bevel_filter: wgpu::ShaderModule,
+    pub convolution_filter: wgpu::ShaderModule,
+    pub displacement_map_filter: wgpu::ShaderModule,
 }
 
 impl Shaders {
@@ -34,6 +40,7 @@ impl Shaders {
             ShaderDefValue::Bool(device.limits().max_push_constant_size > 0),
         );
         shader_defs.insert("early_saturate".to_owned(), ShaderDefValue::Bool(true));
+        shader_defs.insert("vertex_uvs".to_owned(), ShaderDefValue::Bool(false));
 
         let mut late_saturate_shader_defs = shader_defs.clone();
         late_saturate_shader_defs.insert("early_saturate".to_owned(), ShaderDefValue::Bool(false));
@@ -59,6 +66,15 @@ impl Shaders {
             "bitmap.wgsl",
             include_str!("../shaders/bitmap.wgsl"),
         );
+        let mut vertex_uvs_shader_defs = shader_defs.clone();
+        vertex_uvs_shader_defs.insert("vertex_uvs".to_owned(), ShaderDefValue::Bool(true));
+        let textured_triangles_shader = make_shader(
+            device,
+            &mut composer,
+            &vertex_uvs_shader_defs,
+            "bitmap.wgsl",
+            include_str!("../shaders/bitmap.wgsl"),
+        );
         let copy_srgb_shader = make_shader(
             device,
             &mut composer,
@@ -87,6 +103,34 @@ impl Shaders {
             "filter/blur.wgsl",
             include_str!("../shaders/filter/blur.wgsl"),
         );
+        let glow_filter = make_shader(
+            device,
+            &mut composer,
+            &shader_defs,
+            "filter/glow.wgsl",
+            include_str!("../shaders/filter/glow.wgsl"),
+        );
+        let bevel_filter = make_shader(
+            device,
+            &mut composer,
+            &shader_defs,
+            "filter/bevel.wgsl",
+            include_str!("../shaders/filter/bevel.wgsl"),
+        );
+        let convolution_filter = make_shader(
+            device,
+            &mut composer,
+            &shader_defs,
+            "filter/convolution.wgsl",
+            include_str!("../shaders/filter/convolution.wgsl"),
+        );
+        let displacement_map_filter = make_shader(
+            device,
+            &mut composer,
+            &shader_defs,
+            "filter/displacement_map.wgsl",
+            include_str!("../shaders/filter/displacement_map.wgsl"),
+        );
         let gradient_shader = make_shader(
             device,
             &mut composer,
@@ -110,12 +154,17 @@ impl Shaders {
             color_shader,
             bitmap_shader,
             bitmap_late_saturate_shader,
+            textured_triangles_shader,
             gradient_shader,
             copy_srgb_shader,
             copy_shader,
             blend_shaders,
             color_matrix_filter,
             blur_filter,
+            glow_filter,
+            bevel_filter,
+            convolution_filter,
+            displacement_map_filter,
         }
     }
 }
@@ -1,4 +1,5 @@
 mod commands;
+pub mod filters;
 pub mod target;
 
 use crate::backend::RenderTargetMode;
@@ -405,19 +406,69 @@ impl Surface {
                 source_size,
                 &filter,
             ),
-            _ => {
-                tracing::warn!("Unsupported filter {filter:?}");
-                // Apply a default color matrix - it's essentially a blit
-                self.apply_color_matrix(
-                    descriptors,
-                    texture_pool,
-                    draw_encoder,
-                    source_texture,
-                    source_point,
-                    source_size,
-                    &Default::default(),
-                )
-            }
+            Filter::GlowFilter(filter) => self.apply_glow(
+                descriptors,
+                texture_pool,
+                draw_encoder,
+                source_texture,
+                source_point,
+                source_size,
+                &filter,
+            ),
+            Filter::DropShadowFilter(filter) => self.apply_drop_shadow(
+                descriptors,
+                texture_pool,
+                draw_encoder,
+                source_texture,
+                source_point,
+                source_size,
+                &filter,
+            ),
+            Filter::BevelFilter(filter) => self.apply_bevel(
+                descriptors,
+                texture_pool,
+                draw_encoder,
+                source_texture,
+                source_point,
+                source_size,
+                &filter,
+            ),
+            Filter::GradientGlowFilter(filter) => self.apply_gradient_glow(
+                descriptors,
+                texture_pool,
+                draw_encoder,
+                source_texture,
+                source_point,
+                source_size,
+                &filter,
+            ),
+            Filter::GradientBevelFilter(filter) => self.apply_gradient_bevel(
+                descriptors,
+                texture_pool,
+                draw_encoder,
+                source_texture,
+                source_point,
+                source_size,
+                &filter,
+            ),
+            Filter::ConvolutionFilter(filter) => self.apply_convolution(
+                descriptors,
+                texture_pool,
+                draw_encoder,
+                source_texture,
+                source_point,
+                source_size,
+                &filter,
+            ),
+            Filter::DisplacementMapFilter(filter) => self.apply_displacement_map(
+                descriptors,
+                texture_pool,
+                draw_encoder,
+                source_texture,
+                source_point,
+                source_size,
+                &filter,
+            ),
         };
 
         // We're about to perform a copy, so make sure that we've applied
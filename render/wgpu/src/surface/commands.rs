@@ -163,6 +163,29 @@ impl<'pass, 'frame: 'pass, 'global: 'frame> CommandRenderer<'pass, 'frame, 'glob
         );
     }
 
+    pub fn prep_textured_triangles(&mut self, bind_group: &'pass wgpu::BindGroup) {
+        if self.needs_depth {
+            self.render_pass.set_pipeline(
+                self.pipelines
+                    .textured_triangles
+                    .pipeline_for(self.mask_state),
+            );
+        } else {
+            self.render_pass
+                .set_pipeline(self.pipelines.textured_triangles.depthless_pipeline());
+        }
+
+        self.render_pass.set_bind_group(
+            if self.descriptors.limits.max_push_constant_size > 0 {
+                1
+            } else {
+                3
+            },
+            bind_group,
+            &[],
+        );
+    }
+
     pub fn draw(
         &mut self,
         vertices: wgpu::BufferSlice<'pass>,
@@ -176,19 +199,11 @@ impl<'pass, 'frame: 'pass, 'global: 'frame> CommandRenderer<'pass, 'frame, 'glob
         self.render_pass.draw_indexed(0..num_indices, 0, 0..1);
     }
 
-    pub fn apply_transform(&mut self, matrix: &Matrix, color_adjustments: &ColorTransform) {
-        let world_matrix = [
-            [matrix.a, matrix.b, 0.0, 0.0],
-            [matrix.c, matrix.d, 0.0, 0.0],
-            [0.0, 0.0, 1.0, 0.0],
-            [
-                matrix.tx.to_pixels() as f32,
-                matrix.ty.to_pixels() as f32,
-                0.0,
-                1.0,
-            ],
-        ];
-
+    pub fn apply_transform(
+        &mut self,
+        world_matrix: [[f32; 4]; 4],
+        color_adjustments: &ColorTransform,
+    ) {
         if self.descriptors.limits.max_push_constant_size > 0 {
             self.render_pass.set_push_constants(
                 wgpu::ShaderStages::VERTEX_FRAGMENT,
@@ -251,10 +266,12 @@ impl<'pass, 'frame: 'pass, 'global: 'frame> CommandRenderer<'pass, 'frame, 'glob
             &descriptors.bitmap_samplers,
         );
         self.prep_bitmap(&bind.bind_group, blend_mode, render_stage3d);
-        self.apply_transform(
-            &(transform.matrix * Matrix::scale(texture.width as f32, texture.height as f32)),
-            &transform.color_transform,
-        );
+        let world_matrix = Transform {
+            matrix: transform.matrix * Matrix::scale(texture.width as f32, texture.height as f32),
+            ..transform.clone()
+        }
+        .world_matrix();
+        self.apply_transform(world_matrix, &transform.color_transform);
 
         self.draw(
             self.descriptors.quad.vertices_pos.slice(..),
@@ -276,7 +293,7 @@ impl<'pass, 'frame: 'pass, 'global: 'frame> CommandRenderer<'pass, 'frame, 'glob
             self.render_pass.push_debug_group("render_texture");
         }
         self.prep_bitmap(bind_group, blend_mode, false);
-        self.apply_transform(&transform.matrix, &transform.color_transform);
+        self.apply_transform(transform.world_matrix(), &transform.color_transform);
 
         self.draw(
             self.descriptors.quad.vertices_pos.slice(..),
@@ -317,8 +334,11 @@ impl<'pass, 'frame: 'pass, 'global: 'frame> CommandRenderer<'pass, 'frame, 'glob
                 DrawType::Bitmap { binds, .. } => {
                     self.prep_bitmap(&binds.bind_group, TrivialBlend::Normal, false);
                 }
+                DrawType::TexturedTriangles { binds } => {
+                    self.prep_textured_triangles(&binds.bind_group);
+                }
             }
-            self.apply_transform(&transform.matrix, &transform.color_transform);
+            self.apply_transform(transform.world_matrix(), &transform.color_transform);
 
             self.draw(
                 mesh.vertex_buffer.slice(draw.vertices.clone()),
@@ -338,10 +358,10 @@ impl<'pass, 'frame: 'pass, 'global: 'frame> CommandRenderer<'pass, 'frame, 'glob
         self.prep_color();
 
         if color == &Color::WHITE {
-            self.apply_transform(matrix, &ColorTransform::IDENTITY);
+            self.apply_transform(matrix.to_world_matrix(), &ColorTransform::IDENTITY);
         } else {
             self.apply_transform(
-                matrix,
+                matrix.to_world_matrix(),
                 &ColorTransform {
                     r_multiply: Fixed8::from_f32(f32::from(color.r) / 255.0),
                     g_multiply: Fixed8::from_f32(f32::from(color.g) / 255.0),
@@ -499,7 +519,7 @@ pub fn chunk_blends<'a>(
                     BlendType::Trivial(blend_mode) => {
                         let transform = Transform {
                             matrix: Matrix::scale(target.width() as f32, target.height() as f32),
-                            color_transform: Default::default(),
+                            ..Default::default()
                         };
                         let texture = target.take_color_texture();
                         let bind_group =
@@ -0,0 +1,730 @@
+use crate::backend::RenderTargetMode;
+use crate::buffer_pool::TexturePool;
+use crate::surface::target::CommandTarget;
+use crate::surface::{make_texture_transform, Surface};
+use crate::{
+    as_texture, Descriptors, PushConstants, Texture, Transforms, DEFAULT_COLOR_ADJUSTMENTS,
+};
+use bytemuck::{Pod, Zeroable};
+use ruffle_render::filters::{DisplacementMapFilter, DisplacementMapFilterMode};
+use swf::{
+    BevelFilter, BlurFilter, BlurFilterFlags, Color, ConvolutionFilter, DropShadowFilter, Fixed16,
+    GlowFilter, GradientFilter, GradientRecord,
+};
+use wgpu::util::DeviceExt;
+
+/// How many colors are precomputed for the gradient of a glow or bevel filter.
+const GRADIENT_SIZE: usize = 256;
+
+/// The largest number of matrix entries supported by the convolution filter.
+/// Flash Player only supports matrices up to 15x15.
+const MAX_CONVOLUTION_MATRIX_SIZE: usize = 256;
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Pod, Zeroable)]
+pub struct GlowFilterUniform {
+    /// How far the glow is moved away from the object, in pixels.
+    offset: [f32; 2],
+    strength: f32,
+    inner: u32,
+    knockout: u32,
+    composite_source: u32,
+    _padding: [u32; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Pod, Zeroable)]
+pub struct BevelFilterUniform {
+    /// How far the highlight and shadow are moved away from the object, in pixels.
+    offset: [f32; 2],
+    strength: f32,
+    inner: u32,
+    knockout: u32,
+    on_top: u32,
+    _padding: [u32; 2],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Pod, Zeroable)]
+pub struct ConvolutionFilterUniform {
+    /// The premultiplied color used for pixels outside of the source.
+    default_color: [f32; 4],
+    /// The area of the source texture being filtered, as `[x_min, y_min, x_max, y_max]`.
+    source_rect: [i32; 4],
+    matrix_x: u32,
+    matrix_y: u32,
+    divisor: f32,
+    bias: f32,
+    clamp: u32,
+    preserve_alpha: u32,
+    _padding: [u32; 2],
+    matrix: [[f32; 4]; MAX_CONVOLUTION_MATRIX_SIZE / 4],
+}
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Pod, Zeroable)]
+pub struct DisplacementMapFilterUniform {
+    /// The premultiplied color used for pixels displaced out of the source.
+    color: [f32; 4],
+    /// The area of the source texture being filtered, as `[x_min, y_min, x_max, y_max]`.
+    source_rect: [i32; 4],
+    map_point: [i32; 2],
+    scale: [f32; 2],
+    component_x: u32,
+    component_y: u32,
+    mode: u32,
+    _padding: u32,
+}
+
+impl Surface {
+    #[allow(clippy::too_many_arguments)]
+    pub fn apply_glow(
+        &self,
+        descriptors: &Descriptors,
+        texture_pool: &mut TexturePool,
+        draw_encoder: &mut wgpu::CommandEncoder,
+        source_texture: &Texture,
+        source_point: (u32, u32),
+        source_size: (u32, u32),
+        filter: &GlowFilter,
+    ) -> CommandTarget {
+        let mut transparent = filter.color.clone();
+        transparent.a = 0;
+        self.apply_glow_like(
+            descriptors,
+            texture_pool,
+            draw_encoder,
+            source_texture,
+            source_point,
+            source_size,
+            blur_filter(filter.blur_x, filter.blur_y, filter.num_passes()),
+            &[
+                GradientRecord {
+                    ratio: 0,
+                    color: transparent,
+                },
+                GradientRecord {
+                    ratio: 255,
+                    color: filter.color.clone(),
+                },
+            ],
+            GlowFilterUniform {
+                offset: [0.0, 0.0],
+                strength: filter.strength.to_f32(),
+                inner: filter.is_inner() as u32,
+                knockout: filter.is_knockout() as u32,
+                composite_source: 1,
+                _padding: [0; 2],
+            },
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn apply_drop_shadow(
+        &self,
+        descriptors: &Descriptors,
+        texture_pool: &mut TexturePool,
+        draw_encoder: &mut wgpu::CommandEncoder,
+        source_texture: &Texture,
+        source_point: (u32, u32),
+        source_size: (u32, u32),
+        filter: &DropShadowFilter,
+    ) -> CommandTarget {
+        let mut transparent = filter.color.clone();
+        transparent.a = 0;
+        self.apply_glow_like(
+            descriptors,
+            texture_pool,
+            draw_encoder,
+            source_texture,
+            source_point,
+            source_size,
+            blur_filter(filter.blur_x, filter.blur_y, filter.num_passes()),
+            &[
+                GradientRecord {
+                    ratio: 0,
+                    color: transparent,
+                },
+                GradientRecord {
+                    ratio: 255,
+                    color: filter.color.clone(),
+                },
+            ],
+            GlowFilterUniform {
+                offset: offset(filter.angle, filter.distance),
+                strength: filter.strength.to_f32(),
+                inner: filter.is_inner() as u32,
+                knockout: filter.is_knockout() as u32,
+                composite_source: (!filter.hide_object()) as u32,
+                _padding: [0; 2],
+            },
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn apply_gradient_glow(
+        &self,
+        descriptors: &Descriptors,
+        texture_pool: &mut TexturePool,
+        draw_encoder: &mut wgpu::CommandEncoder,
+        source_texture: &Texture,
+        source_point: (u32, u32),
+        source_size: (u32, u32),
+        filter: &GradientFilter,
+    ) -> CommandTarget {
+        self.apply_glow_like(
+            descriptors,
+            texture_pool,
+            draw_encoder,
+            source_texture,
+            source_point,
+            source_size,
+            blur_filter(filter.blur_x, filter.blur_y, filter.num_passes()),
+            &filter.colors,
+            GlowFilterUniform {
+                offset: offset(filter.angle, filter.distance),
+                strength: filter.strength.to_f32(),
+                inner: filter.is_inner() as u32,
+                knockout: filter.is_knockout() as u32,
+                composite_source: 1,
+                _padding: [0; 2],
+            },
+        )
+    }
+
+    /// Glows and drop shadows both color a blurred copy of the source's alpha channel,
+    /// which is then composited together with the source.
+    #[allow(clippy::too_many_arguments)]
+    fn apply_glow_like(
+        &self,
+        descriptors: &Descriptors,
+        texture_pool: &mut TexturePool,
+        draw_encoder: &mut wgpu::CommandEncoder,
+        source_texture: &Texture,
+        source_point: (u32, u32),
+        source_size: (u32, u32),
+        blur: BlurFilter,
+        gradient: &[GradientRecord],
+        uniform: GlowFilterUniform,
+    ) -> CommandTarget {
+        let blurred = self.apply_blur(
+            descriptors,
+            texture_pool,
+            draw_encoder,
+            source_texture,
+            source_point,
+            source_size,
+            &blur,
+        );
+        let gradient = create_gradient_texture(descriptors, gradient);
+        let filter_group = self.create_shadow_bind_group(
+            descriptors,
+            &descriptors.bind_layouts.glow_filter,
+            bytemuck::cast_slice(&[uniform]),
+            &blurred,
+            &gradient,
+        );
+        self.run_filter(
+            descriptors,
+            texture_pool,
+            draw_encoder,
+            source_texture,
+            source_point,
+            source_size,
+            &self.pipelines.glow_filter,
+            &filter_group,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn apply_bevel(
+        &self,
+        descriptors: &Descriptors,
+        texture_pool: &mut TexturePool,
+        draw_encoder: &mut wgpu::CommandEncoder,
+        source_texture: &Texture,
+        source_point: (u32, u32),
+        source_size: (u32, u32),
+        filter: &BevelFilter,
+    ) -> CommandTarget {
+        // A bevel is a gradient bevel going from the highlight, over nothing, to the shadow.
+        self.apply_bevel_like(
+            descriptors,
+            texture_pool,
+            draw_encoder,
+            source_texture,
+            source_point,
+            source_size,
+            blur_filter(filter.blur_x, filter.blur_y, filter.num_passes()),
+            &[
+                GradientRecord {
+                    ratio: 0,
+                    color: filter.highlight_color.clone(),
+                },
+                GradientRecord {
+                    ratio: 128,
+                    color: Color::from_rgba(0),
+                },
+                GradientRecord {
+                    ratio: 255,
+                    color: filter.shadow_color.clone(),
+                },
+            ],
+            BevelFilterUniform {
+                offset: offset(filter.angle, filter.distance),
+                strength: filter.strength.to_f32(),
+                inner: filter.is_inner() as u32,
+                knockout: filter.is_knockout() as u32,
+                on_top: filter.is_on_top() as u32,
+                _padding: [0; 2],
+            },
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn apply_gradient_bevel(
+        &self,
+        descriptors: &Descriptors,
+        texture_pool: &mut TexturePool,
+        draw_encoder: &mut wgpu::CommandEncoder,
+        source_texture: &Texture,
+        source_point: (u32, u32),
+        source_size: (u32, u32),
+        filter: &GradientFilter,
+    ) -> CommandTarget {
+        self.apply_bevel_like(
+            descriptors,
+            texture_pool,
+            draw_encoder,
+            source_texture,
+            source_point,
+            source_size,
+            blur_filter(filter.blur_x, filter.blur_y, filter.num_passes()),
+            &filter.colors,
+            BevelFilterUniform {
+                offset: offset(filter.angle, filter.distance),
+                strength: filter.strength.to_f32(),
+                inner: filter.is_inner() as u32,
+                knockout: filter.is_knockout() as u32,
+                on_top: filter.is_on_top() as u32,
+                _padding: [0; 2],
+            },
+        )
+    }
+
+    /// Bevels compare a blurred copy of the source's alpha channel against itself,
+    /// moved towards and away from the light.
+    #[allow(clippy::too_many_arguments)]
+    fn apply_bevel_like(
+        &self,
+        descriptors: &Descriptors,
+        texture_pool: &mut TexturePool,
+        draw_encoder: &mut wgpu::CommandEncoder,
+        source_texture: &Texture,
+        source_point: (u32, u32),
+        source_size: (u32, u32),
+        blur: BlurFilter,
+        gradient: &[GradientRecord],
+        uniform: BevelFilterUniform,
+    ) -> CommandTarget {
+        let blurred = self.apply_blur(
+            descriptors,
+            texture_pool,
+            draw_encoder,
+            source_texture,
+            source_point,
+            source_size,
+            &blur,
+        );
+        let gradient = create_gradient_texture(descriptors, gradient);
+        let filter_group = self.create_shadow_bind_group(
+            descriptors,
+            &descriptors.bind_layouts.bevel_filter,
+            bytemuck::cast_slice(&[uniform]),
+            &blurred,
+            &gradient,
+        );
+        self.run_filter(
+            descriptors,
+            texture_pool,
+            draw_encoder,
+            source_texture,
+            source_point,
+            source_size,
+            &self.pipelines.bevel_filter,
+            &filter_group,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn apply_convolution(
+        &self,
+        descriptors: &Descriptors,
+        texture_pool: &mut TexturePool,
+        draw_encoder: &mut wgpu::CommandEncoder,
+        source_texture: &Texture,
+        source_point: (u32, u32),
+        source_size: (u32, u32),
+        filter: &ConvolutionFilter,
+    ) -> CommandTarget {
+        let mut matrix_x = filter.num_matrix_cols as usize;
+        let mut matrix_y = filter.num_matrix_rows as usize;
+        if matrix_x * matrix_y > MAX_CONVOLUTION_MATRIX_SIZE
+            || filter.matrix.len() < matrix_x * matrix_y
+        {
+            tracing::warn!(
+                "Unsupported convolution matrix of size {matrix_x}x{matrix_y}, ignoring filter"
+            );
+            matrix_x = 0;
+            matrix_y = 0;
+        }
+
+        let mut matrix = [[0.0; 4]; MAX_CONVOLUTION_MATRIX_SIZE / 4];
+        for (i, value) in filter.matrix[..matrix_x * matrix_y].iter().enumerate() {
+            matrix[i / 4][i % 4] = value.to_f32();
+        }
+        let divisor = filter.divisor.to_f32();
+        let uniform = ConvolutionFilterUniform {
+            default_color: premultiplied(&filter.default_color),
+            source_rect: source_rect(source_point, source_size),
+            matrix_x: matrix_x as u32,
+            matrix_y: matrix_y as u32,
+            divisor: if divisor == 0.0 { 1.0 } else { divisor },
+            bias: filter.bias.to_f32(),
+            clamp: filter.is_clamped() as u32,
+            preserve_alpha: filter.is_preserve_alpha() as u32,
+            _padding: [0; 2],
+            matrix,
+        };
+
+        let buffer = descriptors
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: create_debug_label!("Filter arguments").as_deref(),
+                contents: bytemuck::cast_slice(&[uniform]),
+                usage: wgpu::BufferUsages::UNIFORM,
+            });
+        let filter_group = descriptors
+            .device
+            .create_bind_group(&wgpu::BindGroupDescriptor {
+                label: create_debug_label!("Filter group").as_deref(),
+                layout: &descriptors.bind_layouts.convolution_filter,
+                entries: &[wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: buffer.as_entire_binding(),
+                }],
+            });
+        self.run_filter(
+            descriptors,
+            texture_pool,
+            draw_encoder,
+            source_texture,
+            source_point,
+            source_size,
+            &self.pipelines.convolution_filter,
+            &filter_group,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn apply_displacement_map(
+        &self,
+        descriptors: &Descriptors,
+        texture_pool: &mut TexturePool,
+        draw_encoder: &mut wgpu::CommandEncoder,
+        source_texture: &Texture,
+        source_point: (u32, u32),
+        source_size: (u32, u32),
+        filter: &DisplacementMapFilter,
+    ) -> CommandTarget {
+        let Some(map_bitmap) = &filter.map_bitmap else {
+            // Without a map, nothing is displaced at all.
+            return self.apply_color_matrix(
+                descriptors,
+                texture_pool,
+                draw_encoder,
+                source_texture,
+                source_point,
+                source_size,
+                &Default::default(),
+            );
+        };
+
+        let uniform = DisplacementMapFilterUniform {
+            color: premultiplied(&filter.color),
+            source_rect: source_rect(source_point, source_size),
+            map_point: [filter.map_point.0, filter.map_point.1],
+            scale: [filter.scale_x, filter.scale_y],
+            component_x: filter.component_x.into(),
+            component_y: filter.component_y.into(),
+            mode: match filter.mode {
+                DisplacementMapFilterMode::Wrap => 0,
+                DisplacementMapFilterMode::Clamp => 1,
+                DisplacementMapFilterMode::Ignore => 2,
+                DisplacementMapFilterMode::Color => 3,
+            },
+            _padding: 0,
+        };
+
+        let map_view = as_texture(map_bitmap)
+            .texture
+            .create_view(&Default::default());
+        let buffer = descriptors
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: create_debug_label!("Filter arguments").as_deref(),
+                contents: bytemuck::cast_slice(&[uniform]),
+                usage: wgpu::BufferUsages::UNIFORM,
+            });
+        let filter_group = descriptors
+            .device
+            .create_bind_group(&wgpu::BindGroupDescriptor {
+                label: create_debug_label!("Filter group").as_deref(),
+                layout: &descriptors.bind_layouts.displacement_map_filter,
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: buffer.as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: wgpu::BindingResource::TextureView(&map_view),
+                    },
+                ],
+            });
+        self.run_filter(
+            descriptors,
+            texture_pool,
+            draw_encoder,
+            source_texture,
+            source_point,
+            source_size,
+            &self.pipelines.displacement_map_filter,
+            &filter_group,
+        )
+    }
+
+    fn create_shadow_bind_group(
+        &self,
+        descriptors: &Descriptors,
+        layout: &wgpu::BindGroupLayout,
+        uniform: &[u8],
+        blurred: &CommandTarget,
+        gradient: &wgpu::TextureView,
+    ) -> wgpu::BindGroup {
+        let buffer = descriptors
+            .device
+            .create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: create_debug_label!("Filter arguments").as_deref(),
+                contents: uniform,
+                usage: wgpu::BufferUsages::UNIFORM,
+            });
+        descriptors
+            .device
+            .create_bind_group(&wgpu::BindGroupDescriptor {
+                label: create_debug_label!("Filter group").as_deref(),
+                layout,
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: buffer.as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: wgpu::BindingResource::TextureView(blurred.color_view()),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 2,
+                        resource: wgpu::BindingResource::TextureView(gradient),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 3,
+                        resource: wgpu::BindingResource::Sampler(
+                            descriptors.bitmap_samplers.get_sampler(false, true),
+                        ),
+                    },
+                ],
+            })
+    }
+
+    /// Draws the source region through a filter pipeline, into a new target of the same size.
+    #[allow(clippy::too_many_arguments)]
+    fn run_filter(
+        &self,
+        descriptors: &Descriptors,
+        texture_pool: &mut TexturePool,
+        draw_encoder: &mut wgpu::CommandEncoder,
+        source_texture: &Texture,
+        source_point: (u32, u32),
+        source_size: (u32, u32),
+        pipeline: &wgpu::RenderPipeline,
+        filter_group: &wgpu::BindGroup,
+    ) -> CommandTarget {
+        let target = CommandTarget::new(
+            descriptors,
+            texture_pool,
+            wgpu::Extent3d {
+                width: source_size.0,
+                height: source_size.1,
+                depth_or_array_layers: 1,
+            },
+            self.format,
+            self.sample_count,
+            RenderTargetMode::FreshBuffer(wgpu::Color::TRANSPARENT),
+            draw_encoder,
+        );
+        let texture_transform =
+            make_texture_transform(descriptors, source_size, source_point, source_texture);
+        let source_view = source_texture.texture.create_view(&Default::default());
+        let bitmap_group = descriptors
+            .device
+            .create_bind_group(&wgpu::BindGroupDescriptor {
+                label: create_debug_label!("Bitmap copy group").as_deref(),
+                layout: &descriptors.bind_layouts.bitmap,
+                entries: &[
+                    wgpu::BindGroupEntry {
+                        binding: 0,
+                        resource: texture_transform.as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 1,
+                        resource: wgpu::BindingResource::TextureView(&source_view),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 2,
+                        resource: wgpu::BindingResource::Sampler(
+                            descriptors.bitmap_samplers.get_sampler(false, false),
+                        ),
+                    },
+                ],
+            });
+        let mut render_pass = draw_encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: create_debug_label!("Filter").as_deref(),
+            color_attachments: &[target.color_attachments()],
+            depth_stencil_attachment: None,
+        });
+        render_pass.set_pipeline(pipeline);
+
+        render_pass.set_bind_group(0, target.globals().bind_group(), &[]);
+        if descriptors.limits.max_push_constant_size > 0 {
+            render_pass.set_push_constants(
+                wgpu::ShaderStages::VERTEX_FRAGMENT,
+                0,
+                bytemuck::cast_slice(&[PushConstants {
+                    transforms: Transforms {
+                        world_matrix: [
+                            [target.width() as f32, 0.0, 0.0, 0.0],
+                            [0.0, target.height() as f32, 0.0, 0.0],
+                            [0.0, 0.0, 1.0, 0.0],
+                            [0.0, 0.0, 0.0, 1.0],
+                        ],
+                    },
+                    colors: DEFAULT_COLOR_ADJUSTMENTS,
+                }]),
+            );
+            render_pass.set_bind_group(1, &bitmap_group, &[]);
+            render_pass.set_bind_group(2, filter_group, &[]);
+        } else {
+            render_pass.set_bind_group(1, target.whole_frame_bind_group(descriptors), &[0]);
+            render_pass.set_bind_group(2, &descriptors.default_color_bind_group, &[0]);
+            render_pass.set_bind_group(3, &bitmap_group, &[]);
+            render_pass.set_bind_group(4, filter_group, &[]);
+        }
+
+        render_pass.set_vertex_buffer(0, descriptors.quad.vertices_pos.slice(..));
+        render_pass.set_index_buffer(
+            descriptors.quad.indices.slice(..),
+            wgpu::IndexFormat::Uint32,
+        );
+        render_pass.draw_indexed(0..6, 0, 0..1);
+        drop(render_pass);
+        target
+    }
+}
+
+fn blur_filter(blur_x: Fixed16, blur_y: Fixed16, passes: u8) -> BlurFilter {
+    BlurFilter {
+        blur_x,
+        blur_y,
+        flags: BlurFilterFlags::from_passes(passes.clamp(1, 15)),
+    }
+}
+
+/// Converts the angle (in radians) and distance (in pixels) of a filter into an offset.
+fn offset(angle: Fixed16, distance: Fixed16) -> [f32; 2] {
+    let (angle, distance) = (angle.to_f32(), distance.to_f32());
+    [angle.cos() * distance, angle.sin() * distance]
+}
+
+fn source_rect(source_point: (u32, u32), source_size: (u32, u32)) -> [i32; 4] {
+    [
+        source_point.0 as i32,
+        source_point.1 as i32,
+        (source_point.0 + source_size.0) as i32,
+        (source_point.1 + source_size.1) as i32,
+    ]
+}
+
+fn premultiplied(color: &Color) -> [f32; 4] {
+    let alpha = f32::from(color.a) / 255.0;
+    [
+        f32::from(color.r) / 255.0 * alpha,
+        f32::from(color.g) / 255.0 * alpha,
+        f32::from(color.b) / 255.0 * alpha,
+        alpha,
+    ]
+}
+
+/// Creates a texture holding the premultiplied colors of a filter gradient.
+///
+/// Unlike gradient fills, the colors are premultiplied before being interpolated,
+/// so that fading into a fully transparent color does not darken the result.
+fn create_gradient_texture(
+    descriptors: &Descriptors,
+    records: &[GradientRecord],
+) -> wgpu::TextureView {
+    let mut colors = [0; GRADIENT_SIZE * 4];
+    if !records.is_empty() {
+        for (t, color) in colors.chunks_exact_mut(4).enumerate() {
+            let next = records
+                .iter()
+                .position(|record| usize::from(record.ratio) >= t)
+                .unwrap_or(records.len() - 1);
+            let last = next.saturating_sub(1);
+            let (last_record, next_record) = (&records[last], &records[next]);
+            let a = if next_record.ratio > last_record.ratio {
+                ((t as f32 - f32::from(last_record.ratio))
+                    / f32::from(next_record.ratio - last_record.ratio))
+                .clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            let last_color = premultiplied(&last_record.color);
+            let next_color = premultiplied(&next_record.color);
+            for (i, component) in color.iter_mut().enumerate() {
+                let value = last_color[i] + (next_color[i] - last_color[i]) * a;
+                *component = (value * 255.0).round() as u8;
+            }
+        }
+    }
+
+    let texture = descriptors.device.create_texture_with_data(
+        &descriptors.queue,
+        &wgpu::TextureDescriptor {
+            label: create_debug_label!("Filter gradient").as_deref(),
+            size: wgpu::Extent3d {
+                width: GRADIENT_SIZE as u32,
+                height: 1,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format: wgpu::TextureFormat::Rgba8Unorm,
+            usage: wgpu::TextureUsages::TEXTURE_BINDING,
+            view_formats: &[],
+        },
+        &colors,
+    );
+    texture.create_view(&Default::default())
+}
@@ -2,10 +2,18 @@
 
 use crate::cli_options::AnalyzeOpt;
 use crate::file_results::{FileResults, Step};
+use std::collections::HashMap;
 use std::fs::File;
+use std::io::Write;
 
-/// Generate and print statistics related to a scan's results
-pub fn analyze(results: impl Iterator<Item = FileResults>) {
+/// Generate and write out statistics related to a scan's results
+///
+/// `num_stubs` is the number of most frequently encountered stubs to list.
+pub fn analyze(
+    results: impl Iterator<Item = FileResults>,
+    num_stubs: usize,
+    out: &mut impl Write,
+) -> Result<(), std::io::Error> {
     let mut total = 0;
     let mut start = 0;
     let mut read = 0;
@@ -13,10 +21,15 @@ pub fn analyze(results: impl Iterator<Item = FileResults>) {
     let mut parse = 0;
     let mut execute = 0;
     let mut complete = 0;
+    let mut stubs: HashMap<String, usize> = HashMap::new();
 
     for result in results {
         total += 1;
 
+        for stub in result.stubs {
+            *stubs.entry(stub).or_default() += 1;
+        }
+
         match result.progress {
             Step::Start => start += 1,
             Step::Read => read += 1,
@@ -27,7 +40,7 @@ pub fn analyze(results: impl Iterator<Item = FileResults>) {
         }
     }
 
-    println!("Scanned {total} swf files.");
+    writeln!(out, "Scanned {total} swf files.")?;
 
     let digits = [start, read, decompress, parse, execute, complete]
         .iter()
@@ -36,36 +49,130 @@ pub fn analyze(results: impl Iterator<Item = FileResults>) {
         .unwrap()
         + 4;
 
-    println!();
+    writeln!(out)?;
 
     if start > 0 {
-        println!("{start:>digits$} movies panicked or crashed the scanner");
+        writeln!(
+            out,
+            "{start:>digits$} movies panicked or crashed the scanner"
+        )?;
     }
 
-    println!("{read:>digits$} movies failed when reading");
-    println!("{decompress:>digits$} movies failed to decompress");
-    println!("{parse:>digits$} movies failed to parse");
-    println!("{execute:>digits$} movies failed to execute");
-    println!("{complete:>digits$} movies completed without errors");
-    println!();
+    writeln!(out, "{read:>digits$} movies failed when reading")?;
+    writeln!(out, "{decompress:>digits$} movies failed to decompress")?;
+    writeln!(out, "{parse:>digits$} movies failed to parse")?;
+    writeln!(out, "{execute:>digits$} movies failed to execute")?;
+    writeln!(out, "{complete:>digits$} movies completed without errors")?;
+    writeln!(out)?;
+
+    if !stubs.is_empty() {
+        let mut stubs: Vec<(String, usize)> = stubs.into_iter().collect();
+        stubs.sort_by(|(a_name, a_count), (b_name, b_count)| {
+            b_count.cmp(a_count).then_with(|| a_name.cmp(b_name))
+        });
+
+        writeln!(
+            out,
+            "{} distinct stubs were encountered, the most common being:",
+            stubs.len()
+        )?;
+        writeln!(out)?;
+
+        let digits = (stubs[0].1 as f64).log10().ceil() as usize + 4;
+        for (stub, count) in stubs.iter().take(num_stubs) {
+            writeln!(out, "{count:>digits$} movies used {stub}")?;
+        }
+        writeln!(out)?;
+    }
+
+    Ok(())
 }
 
 pub fn analyze_main(opt: AnalyzeOpt) -> Result<(), std::io::Error> {
     let file = File::open(opt.input_path)?;
     let reader = csv::Reader::from_reader(file);
 
-    analyze(reader.into_deserialize::<FileResults>().map(|r| {
-        match r {
-            Ok(fr) => fr,
-            Err(e) => {
-                // Treat unparseable CSV rows as a scanner panic
-                FileResults {
-                    error: Some(format!("{e}")),
-                    ..FileResults::default()
+    analyze(
+        reader.into_deserialize::<FileResults>().map(|r| {
+            match r {
+                Ok(fr) => fr,
+                Err(e) => {
+                    // Treat unparseable CSV rows as a scanner panic
+                    FileResults {
+                        error: Some(format!("{e}")),
+                        ..FileResults::default()
+                    }
                 }
             }
+        }),
+        opt.stubs,
+        &mut std::io::stdout(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(progress: Step, stubs: &[&str]) -> FileResults {
+        FileResults {
+            progress,
+            stubs: stubs.iter().map(|stub| stub.to_string()).collect(),
+            ..FileResults::default()
         }
-    }));
+    }
 
-    Ok(())
+    #[test]
+    fn ranks_stubs_by_movie_count() {
+        let results = vec![
+            result(
+                Step::Complete,
+                &[
+                    "AVM2 flash.media.Camera.getCamera()",
+                    "AVM2 flash.net.NetStream.appendBytes()",
+                ],
+            ),
+            result(Step::Complete, &["AVM2 flash.net.NetStream.appendBytes()"]),
+            result(
+                Step::Execute,
+                &[
+                    "AVM1 System.showSettings()",
+                    "AVM2 flash.net.NetStream.appendBytes()",
+                ],
+            ),
+            result(Step::Parse, &[]),
+        ];
+
+        let mut out = Vec::new();
+        analyze(results.into_iter(), 2, &mut out).expect("Analysis should succeed");
+
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "Scanned 4 swf files.\n\
+             \n\
+             \x20   0 movies failed when reading\n\
+             \x20   0 movies failed to decompress\n\
+             \x20   1 movies failed to parse\n\
+             \x20   1 movies failed to execute\n\
+             \x20   2 movies completed without errors\n\
+             \n\
+             3 distinct stubs were encountered, the most common being:\n\
+             \n\
+             \x20   3 movies used AVM2 flash.net.NetStream.appendBytes()\n\
+             \x20   1 movies used AVM1 System.showSettings()\n\
+             \n"
+        );
+    }
+
+    #[test]
+    fn omits_stubs_when_none_were_encountered() {
+        let results = vec![result(Step::Complete, &[])];
+
+        let mut out = Vec::new();
+        analyze(results.into_iter(), 50, &mut out).expect("Analysis should succeed");
+
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.ends_with("movies completed without errors\n\n"));
+        assert!(!out.contains("stubs"));
+    }
 }
@@ -35,6 +35,14 @@ pub struct ScanOpt {
     /// Filenames to ignore
     #[clap(short = 'i', long = "ignore", action = clap::ArgAction::Append)]
     pub ignore: Vec<String>,
+
+    /// The number of frames to run each SWF file for
+    #[clap(short = 'f', long = "frames", default_value = "30")]
+    pub frames: u32,
+
+    /// The number of most frequently encountered stubs to list
+    #[clap(short = 's', long = "stubs", default_value = "50")]
+    pub stubs: usize,
 }
 
 #[derive(Parser, Debug)]
@@ -42,6 +50,10 @@ pub struct AnalyzeOpt {
     /// The CSV file to reanalyze
     #[clap(name = "input")]
     pub input_path: PathBuf,
+
+    /// The number of most frequently encountered stubs to list
+    #[clap(short = 's', long = "stubs", default_value = "50")]
+    pub stubs: usize,
 }
 
 #[derive(Parser, Debug)]
@@ -49,4 +61,8 @@ pub struct ExecuteReportOpt {
     /// The single SWF file to parse and run
     #[clap(name = "file")]
     pub input_path: PathBuf,
+
+    /// The number of frames to run the SWF file for
+    #[clap(short = 'f', long = "frames", default_value = "30")]
+    pub frames: u32,
 }
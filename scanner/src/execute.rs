@@ -7,14 +7,19 @@ use ruffle_core::backend::navigator::{NullExecutor, NullNavigatorBackend};
 use ruffle_core::limits::ExecutionLimit;
 use ruffle_core::swf::{decompress_swf, parse_swf};
 use ruffle_core::tag_utils::SwfMovie;
-use ruffle_core::PlayerBuilder;
+use ruffle_core::{Player, PlayerBuilder};
 use sha2::{Digest, Sha256};
 use std::io::{stdout, Write};
-use std::panic::catch_unwind;
+use std::panic::{catch_unwind, AssertUnwindSafe};
 use std::path::Path;
+use std::sync::{Arc, Mutex, PoisonError};
 use std::time::{Duration, Instant};
 
-fn execute_swf(file: &Path) {
+/// Run the given SWF file for a number of frames.
+///
+/// The player is stored in `player_slot` as soon as it has been built, so that
+/// it can still be inspected if the movie panics partway through.
+fn execute_swf(file: &Path, num_frames: u32, player_slot: &mut Option<Arc<Mutex<Player>>>) {
     let base_path = file.parent().unwrap();
     let executor = NullExecutor::new();
     let movie = SwfMovie::from_path(file, None).unwrap();
@@ -25,14 +30,27 @@ fn execute_swf(file: &Path) {
         .with_max_execution_duration(Duration::from_secs(300))
         .with_movie(movie)
         .build();
+    *player_slot = Some(player.clone());
 
     player.lock().unwrap().preload(&mut ExecutionLimit::none());
 
-    player.lock().unwrap().run_frame();
-    player.lock().unwrap().update_timers(frame_time);
+    for _ in 0..num_frames {
+        player.lock().unwrap().run_frame();
+        player.lock().unwrap().update_timers(frame_time);
+    }
     //executor.poll_all().unwrap();
 }
 
+/// List every stub that the player has encountered so far, in a stable order.
+fn encountered_stubs(player: &Mutex<Player>) -> Vec<String> {
+    // A panic during execution poisons the lock, but the stubs are still valid.
+    let player = player.lock().unwrap_or_else(PoisonError::into_inner);
+    let mut stubs: Vec<String> = player.stubs().iter().map(|stub| stub.to_string()).collect();
+    stubs.sort();
+
+    stubs
+}
+
 fn checkpoint<W: Write>(
     file_result: &mut FileResults,
     start: &Instant,
@@ -149,8 +167,16 @@ pub fn execute_report_main(execute_report_opt: ExecuteReportOpt) -> Result<(), s
     checkpoint(&mut file_result, &start, &mut writer)?;
     file_result.progress = Step::Execute;
 
-    //Run one frame of the movie in Ruffle.
-    if let Err(e) = catch_unwind(|| execute_swf(&file_path)) {
+    //Run a few frames of the movie in Ruffle.
+    let mut player = None;
+    let result = catch_unwind(AssertUnwindSafe(|| {
+        execute_swf(&file_path, execute_report_opt.frames, &mut player)
+    }));
+    if let Some(player) = player {
+        file_result.stubs = encountered_stubs(&player);
+    }
+
+    if let Err(e) = result {
         match e.downcast::<String>() {
             Ok(e) => {
                 file_result.error = Some(format!("PANIC: {e}"));
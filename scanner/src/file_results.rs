@@ -126,6 +126,15 @@ pub struct FileResults {
     /// The AVM type of the movie.
     #[serde(rename = "AVM Version")]
     pub vm_type: Option<AvmType>,
+
+    /// The unimplemented ActionScript APIs (and other stubs) this SWF used.
+    #[serde(
+        rename = "Stubs",
+        default,
+        serialize_with = "into_lines",
+        deserialize_with = "from_lines"
+    )]
+    pub stubs: Vec<String>,
 }
 
 impl Default for FileResults {
@@ -153,6 +162,7 @@ impl FileResults {
             use_gpu: None,
             use_network_sandbox: None,
             vm_type: None,
+            stubs: vec![],
         }
     }
 }
@@ -170,6 +180,24 @@ where
     s.serialize_str(&out)
 }
 
+/// Formats a list of strings as one per line
+fn into_lines<S>(lines: &[String], s: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    s.serialize_str(&lines.join("\n"))
+}
+
+/// Parses lines into a list of strings
+fn from_lines<'de, D>(d: D) -> Result<Vec<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let lines = String::deserialize(d)?;
+
+    Ok(lines.lines().map(|line| line.to_string()).collect())
+}
+
 /// Parses hex strings into data
 fn from_hex<'de, D>(d: D) -> Result<Vec<u8>, D::Error>
 where
@@ -207,3 +235,64 @@ where
 
     d.deserialize_str(HexVisitor())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(results: &FileResults) -> FileResults {
+        let mut writer = csv::Writer::from_writer(vec![]);
+        writer.serialize(results).unwrap();
+        let data = writer.into_inner().unwrap();
+
+        let mut reader = csv::Reader::from_reader(data.as_slice());
+        reader
+            .deserialize()
+            .next()
+            .expect("CSV should contain a row")
+            .expect("Row should deserialize")
+    }
+
+    #[test]
+    fn stubs_round_trip() {
+        let results = FileResults {
+            hash: vec![0xAB, 0x01],
+            progress: Step::Complete,
+            stubs: vec![
+                "AVM1 System.showSettings()".to_string(),
+                "AVM2 flash.net.NetStream.appendBytes()".to_string(),
+            ],
+            ..FileResults::new("movies/test.swf")
+        };
+
+        let read = round_trip(&results);
+        assert_eq!(read.name, "movies/test.swf");
+        assert_eq!(read.hash, vec![0xAB, 0x01]);
+        assert!(matches!(read.progress, Step::Complete));
+        assert_eq!(read.stubs, results.stubs);
+    }
+
+    #[test]
+    fn empty_stubs_round_trip() {
+        let read = round_trip(&FileResults::new("test.swf"));
+        assert!(read.stubs.is_empty());
+    }
+
+    #[test]
+    fn stubs_column_is_optional() {
+        // Results written before the "Stubs" column existed.
+        let data = "Filename,SHA256 Hash,Progress,Test Duration,Compressed Length,\
+                    Uncompressed Length,Error,Compression,SWF Version,Stage Size,Frame Rate,\
+                    Number of Frames,Direct Blit,GPU,Network Sandbox,AVM Version\n\
+                    test.swf,AB01,Complete,10,,,,,,,,,,,,\n";
+
+        let mut reader = csv::Reader::from_reader(data.as_bytes());
+        let read: FileResults = reader
+            .deserialize()
+            .next()
+            .expect("CSV should contain a row")
+            .expect("Row should deserialize");
+        assert_eq!(read.name, "test.swf");
+        assert!(read.stubs.is_empty());
+    }
+}
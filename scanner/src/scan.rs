@@ -38,12 +38,22 @@ pub fn find_files(root: &Path, ignore: &[String]) -> Vec<DirEntry> {
     results
 }
 
-pub fn scan_file<P: AsRef<OsStr>>(exec_path: P, file: &DirEntry, name: &str) -> FileResults {
+pub fn scan_file<P: AsRef<OsStr>>(
+    exec_path: P,
+    file: &DirEntry,
+    name: &str,
+    frames: u32,
+) -> FileResults {
     let start = Instant::now();
     let mut file_results = FileResults::new(name);
 
     let subproc = Command::new(exec_path)
-        .args(["execute-report", &file.path().to_string_lossy()])
+        .args([
+            "execute-report",
+            "--frames",
+            &frames.to_string(),
+            &file.path().to_string_lossy(),
+        ])
         .output();
     match subproc {
         Ok(output) => {
@@ -68,6 +78,7 @@ pub fn scan_file<P: AsRef<OsStr>>(exec_path: P, file: &DirEntry, name: &str) ->
                             use_gpu,
                             use_network_sandbox,
                             vm_type,
+                            stubs,
                         } = child_results;
 
                         file_results.hash = hash;
@@ -85,6 +96,7 @@ pub fn scan_file<P: AsRef<OsStr>>(exec_path: P, file: &DirEntry, name: &str) ->
                         file_results.use_gpu = use_gpu;
                         file_results.use_network_sandbox = use_network_sandbox;
                         file_results.vm_type = vm_type;
+                        file_results.stubs = stubs;
                     }
                     Err(e) => {
                         file_results.error = Some(e.to_string());
@@ -115,6 +127,7 @@ pub fn scan_file<P: AsRef<OsStr>>(exec_path: P, file: &DirEntry, name: &str) ->
 /// Should be called with parsed options corresponding to the `scan` command.
 pub fn scan_main(opt: ScanOpt) -> Result<(), std::io::Error> {
     let binary_path = env::current_exe()?;
+    let num_stubs = opt.stubs;
     let to_scan = find_files(&opt.input_path, &opt.ignore);
     let mut writer = csv::Writer::from_path(opt.output_path.clone())?;
 
@@ -135,7 +148,7 @@ pub fn scan_main(opt: ScanOpt) -> Result<(), std::io::Error> {
                 .strip_prefix(&opt.input_path)
                 .unwrap_or_else(|_| file.path())
                 .to_slash_lossy();
-            let result = scan_file(&binary_path, &file, &name);
+            let result = scan_file(&binary_path, &file, &name, opt.frames);
 
             progress.inc(1);
             progress.set_message(name.into_owned());
@@ -151,7 +164,5 @@ pub fn scan_main(opt: ScanOpt) -> Result<(), std::io::Error> {
             result
         });
 
-    analyze(result_iter);
-
-    Ok(())
+    analyze(result_iter, num_stubs, &mut std::io::stdout())
 }
@@ -19,6 +19,32 @@ pub enum MouseButton {
     Right,
 }
 
+/// How far the mouse wheel was scrolled.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone)]
+pub enum MouseWheelDelta {
+    /// Scrolled by a number of lines, as reported by most mice.
+    Lines(f64),
+
+    /// Scrolled by a number of pixels, as reported by some touchpads.
+    Pixels(f64),
+}
+
+/// Control inputs to a text field.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone)]
+pub enum TextControlCode {
+    MoveLeft,
+    MoveRight,
+    SelectLeft,
+    SelectRight,
+    SelectAll,
+    Copy,
+    Paste,
+    Cut,
+    Backspace,
+    Enter,
+    Delete,
+}
+
 /// All automated event types supported by FlashTAS.
 ///
 /// A FlashTAS input file consists of a string of `AutomatedEvent`s which are
@@ -47,4 +73,31 @@ pub enum AutomatedEvent {
 
     /// Press a key
     KeyDown { key_code: u8 },
+
+    /// Release a key
+    KeyUp { key_code: u8 },
+
+    /// Scroll the mouse wheel.
+    MouseWheel { delta: MouseWheelDelta },
+
+    /// Move the mouse out of the stage.
+    MouseLeave,
+
+    /// Input a character of text.
+    TextInput { codepoint: char },
+
+    /// Edit text using a control input, such as backspace or paste.
+    TextControl { code: TextControlCode },
+
+    /// Replace the contents of the clipboard.
+    ///
+    /// This is followed by a `TextControl` with the `Paste` code to paste
+    /// the text into the focused text field.
+    SetClipboardText { text: String },
+
+    /// The player window gained focus.
+    FocusGained,
+
+    /// The player window lost focus.
+    FocusLost,
 }
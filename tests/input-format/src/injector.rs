@@ -81,7 +81,16 @@ impl InputInjector {
 
                 match event {
                     AutomatedEvent::Wait => break,
-                    AutomatedEvent::MouseMove { .. } | AutomatedEvent::KeyDown { .. } => {}
+                    AutomatedEvent::MouseMove { .. }
+                    | AutomatedEvent::KeyDown { .. }
+                    | AutomatedEvent::KeyUp { .. }
+                    | AutomatedEvent::MouseWheel { .. }
+                    | AutomatedEvent::TextInput { .. }
+                    | AutomatedEvent::TextControl { .. }
+                    | AutomatedEvent::MouseLeave
+                    | AutomatedEvent::SetClipboardText { .. }
+                    | AutomatedEvent::FocusGained
+                    | AutomatedEvent::FocusLost => {}
                     AutomatedEvent::MouseDown { btn, .. } => {
                         self.buttons |= (*btn).into();
                     }
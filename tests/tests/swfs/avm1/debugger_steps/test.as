@@ -0,0 +1,15 @@
+// Compiled as an AVM1 (SWF 8) movie with two frames. The test attaches the
+// debugger, which logs every pause and continues with the actions from
+// test.toml.
+
+// Frame 1
+function add(a, b) {
+	return a + b;
+}
+trace("before");
+result = add(1, 2);
+trace("after");
+
+// Frame 2
+trace("frame 2");
+stop();
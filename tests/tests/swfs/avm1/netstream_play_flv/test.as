@@ -0,0 +1,19 @@
+// Compiled as an AVM1 (SWF 8) movie at 10 fps.
+
+// Frame 1
+var nc = new NetConnection();
+nc.connect(null);
+var ns = new NetStream(nc);
+ns.onStatus = function(info) {
+	trace("ns.onStatus: " + info.code + " (" + info.level + ")");
+};
+ns.onMetaData = function(info) {
+	trace("ns.onMetaData: duration = " + info.duration + ", width = " + info.width + ", height = " + info.height + ", framerate = " + info.framerate);
+};
+ns.play("video.flv");
+
+// Frames 2 to 5
+trace("ns.time = " + ns.time);
+
+// Frame 5
+stop();
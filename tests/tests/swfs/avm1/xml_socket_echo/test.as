@@ -0,0 +1,25 @@
+// Compiled as an AVM1 (SWF 8) movie. The test runner connects every socket
+// to an echo server.
+
+var xmlSocket = new XMLSocket();
+xmlSocket.onConnect = function(success) {
+	trace("xmlSocket.onConnect: " + success);
+	xmlSocket.send("<hello to=\"world\"/>");
+};
+xmlSocket.onXML = function(doc) {
+	trace("xmlSocket.onXML: " + doc.firstChild.nodeName + ", to = " + doc.firstChild.attributes.to);
+	xmlSocket.close();
+};
+trace("xmlSocket.connect: " + xmlSocket.connect("localhost", 8000));
+
+var rawSocket = new XMLSocket();
+rawSocket.onConnect = function(success) {
+	trace("rawSocket.onConnect: " + success);
+	rawSocket.send("first");
+	rawSocket.send("second");
+};
+rawSocket.onData = function(data) {
+	trace("rawSocket.onData: " + data);
+};
+trace("rawSocket.connect: " + rawSocket.connect("localhost", 8001));
+stop();
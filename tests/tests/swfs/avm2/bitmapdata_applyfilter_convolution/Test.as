@@ -0,0 +1,72 @@
+package {
+	import flash.display.Bitmap;
+	import flash.display.BitmapData;
+	import flash.display.BitmapDataChannel;
+	import flash.display.MovieClip;
+	import flash.filters.*;
+	import flash.geom.Point;
+	import flash.geom.Rectangle;
+
+	public class Test extends MovieClip {
+		static var BG_CELL_SIZE: uint = 8;
+
+		static var SOURCE_WIDTH: uint = 80;
+		static var SOURCE_HEIGHT: uint = 80;
+
+		public function Test() {
+			addChild(new Bitmap(createCheckeredBackground(stage.stageWidth, stage.stageHeight)));
+
+			var src: BitmapData = createSource();
+			var count: uint = 0;
+
+			addImage(count++, src);
+
+			addImage(count++, testFilter(src, new ConvolutionFilter(3, 3, [0, -1, 0, -1, 5, -1, 0, -1, 0])));
+			addImage(count++, testFilter(src, new ConvolutionFilter(3, 3, [-1, -1, -1, -1, 8, -1, -1, -1, -1], 1, 0, false)));
+			addImage(count++, testFilter(src, new ConvolutionFilter(3, 3, [1, 1, 1, 1, 1, 1, 1, 1, 1], 9)));
+			addImage(count++, testFilter(src, new ConvolutionFilter(3, 3, [-2, -1, 0, -1, 1, 1, 0, 1, 2], 1, 128, false, false, 0xFF0000, 1)));
+			addImage(count++, testFilter(src, new ConvolutionFilter(5, 1, [1, 1, 1, 1, 1], 5, 0, false)));
+		}
+
+		static function createCheckeredBackground(width: uint, height: uint): BitmapData {
+			var bg: BitmapData = new BitmapData(width, height, false);
+			for (var x: uint = 0; x < width; x += BG_CELL_SIZE) {
+				for (var y: uint = 0; y < height; y += BG_CELL_SIZE) {
+					var color: uint = 0xFFEEEEEE;
+					if ((x / BG_CELL_SIZE + y / BG_CELL_SIZE) % 2 == 0) {
+						color = 0xFFBBBBBB;
+					}
+					bg.fillRect(new Rectangle(x, y, BG_CELL_SIZE, BG_CELL_SIZE), color);
+				}
+			}
+			return bg;
+		}
+
+		static function createSource(): BitmapData {
+			// A grid of colors on a transparent background, crossed by a black bar.
+			var src: BitmapData = new BitmapData(SOURCE_WIDTH, SOURCE_HEIGHT, true, 0x00000000);
+			for (var row: uint = 0; row < 5; row++) {
+				for (var col: uint = 0; col < 5; col++) {
+					var color: uint = 0xFF0000CC | ((row * 50) << 16) | ((col * 50) << 8);
+					src.fillRect(new Rectangle(15 + col * 10, 15 + row * 10, 10, 10), color);
+				}
+			}
+			src.fillRect(new Rectangle(38, 5, 4, 70), 0xFF000000);
+			return src;
+		}
+
+		function addImage(count: uint, img: BitmapData) {
+			var bitmap: Bitmap = new Bitmap(img);
+			var columns: uint = Math.floor(stage.stageWidth / SOURCE_WIDTH);
+			bitmap.x = (count % columns) * SOURCE_WIDTH;
+			bitmap.y = uint(count / columns) * SOURCE_HEIGHT;
+			addChild(bitmap);
+		}
+
+		static function testFilter(src: BitmapData, filter: BitmapFilter): BitmapData {
+			var dst: BitmapData = new BitmapData(SOURCE_WIDTH, SOURCE_HEIGHT, true, 0x00000000);
+			dst.applyFilter(src, src.rect, new Point(0, 0), filter);
+			return dst;
+		}
+	}
+}
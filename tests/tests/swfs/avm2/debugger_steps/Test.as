@@ -0,0 +1,27 @@
+package {
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			trace("before");
+			var result:int = add(1, 2);
+			trace("result: " + result);
+			outer();
+			trace("after");
+		}
+
+		public function add(a:int, b:int):int {
+			var sum:int = a + b;
+			return sum;
+		}
+
+		public function outer():void {
+			inner();
+			trace("outer done");
+		}
+
+		public function inner():void {
+			trace("inner");
+		}
+	}
+}
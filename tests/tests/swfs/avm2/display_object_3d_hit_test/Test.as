@@ -0,0 +1,47 @@
+package {
+	import flash.display.Sprite;
+	import flash.geom.Point;
+
+	public class Test extends Sprite {
+		public function Test() {
+			// Seen in perspective, the card's right edge comes towards the viewer,
+			// and it covers x = 83 to 124 of the stage instead of x = 60 to 140.
+			var card:Sprite = new Sprite();
+			card.graphics.beginFill(0xFF0000);
+			card.graphics.drawRect(-40, -50, 80, 100);
+			card.graphics.endFill();
+			card.x = 100;
+			card.y = 100;
+			card.rotationY = 60;
+			addChild(card);
+
+			var dot:Sprite = new Sprite();
+			dot.graphics.beginFill(0x000000);
+			dot.graphics.drawRect(-5, -5, 10, 10);
+			dot.graphics.endFill();
+			dot.x = 20;
+			card.addChild(dot);
+
+			trace("// hitTestPoint (shape, bounds)");
+			var points:Array = [[100, 100], [120, 100], [130, 100], [90, 100], [78, 100], [100, 158]];
+			for each (var point:Array in points) {
+				trace(point, card.hitTestPoint(point[0], point[1], true), card.hitTestPoint(point[0], point[1], false));
+			}
+
+			trace("// localToGlobal");
+			trace(card.localToGlobal(new Point(40, 50)));
+			trace(card.localToGlobal(new Point(-40, -50)));
+			trace(dot.localToGlobal(new Point(0, 0)));
+
+			trace("// globalToLocal");
+			trace(card.globalToLocal(new Point(110, 100)));
+			trace(card.globalToLocal(new Point(120, 80)));
+
+			trace("// Round trip");
+			trace(card.localToGlobal(card.globalToLocal(new Point(110, 90))));
+
+			trace("// Child of a rotated object");
+			trace(dot.hitTestPoint(111, 100, true), dot.hitTestPoint(121, 100, true));
+		}
+	}
+}
@@ -0,0 +1,77 @@
+package {
+	import flash.display.Bitmap;
+	import flash.display.BitmapData;
+	import flash.display.MovieClip;
+	import flash.filters.*;
+	import flash.geom.Rectangle;
+
+	public class Test extends MovieClip {
+		static var BG_CELL_SIZE: uint = 8;
+
+		static var SOURCE_WIDTH: uint = 80;
+		static var SOURCE_HEIGHT: uint = 80;
+
+		public function Test() {
+			addChild(new Bitmap(createCheckeredBackground(stage.stageWidth, stage.stageHeight)));
+
+			var src: BitmapData = createSource();
+			var count: uint = 0;
+
+			addFiltered(count++, src, []);
+			addFiltered(count++, src, [new GlowFilter()]);
+			addFiltered(count++, src, [new DropShadowFilter()]);
+			addFiltered(count++, src, [new BlurFilter(4, 4, 1)]);
+			addFiltered(count++, src, [new BevelFilter()]);
+			addFiltered(count++, src, [new GradientGlowFilter(0, 45, [0xFFFFFF, 0xFF0000, 0xFFFF00], [0, 1, 1], [0, 128, 255], 8, 8, 2, 1, "outer")]);
+			addFiltered(count++, src, [new GradientBevelFilter(4, 45, [0xFFFFFF, 0xFFFFFF, 0x000000, 0x000000], [1, 0, 0, 1], [0, 128, 128, 255], 4, 4, 1, 1, "inner")]);
+			addFiltered(count++, src, [new ColorMatrixFilter([
+				0.3, 0.59, 0.11, 0, 0,
+				0.3, 0.59, 0.11, 0, 0,
+				0.3, 0.59, 0.11, 0, 0,
+				0, 0, 0, 1, 0
+			])]);
+			addFiltered(count++, src, [new GlowFilter(0xFF0000, 1, 6, 6, 2), new DropShadowFilter(4, 45, 0x0000FF)]);
+
+			// The color transform applies to the filtered object, including its glow.
+			var faded: Bitmap = addFiltered(count++, src, [new GlowFilter(0x00FF00, 1, 8, 8, 2)]);
+			faded.alpha = 0.5;
+		}
+
+		static function createCheckeredBackground(width: uint, height: uint): BitmapData {
+			var bg: BitmapData = new BitmapData(width, height, false);
+			for (var x: uint = 0; x < width; x += BG_CELL_SIZE) {
+				for (var y: uint = 0; y < height; y += BG_CELL_SIZE) {
+					var color: uint = 0xFFEEEEEE;
+					if ((x / BG_CELL_SIZE + y / BG_CELL_SIZE) % 2 == 0) {
+						color = 0xFFBBBBBB;
+					}
+					bg.fillRect(new Rectangle(x, y, BG_CELL_SIZE, BG_CELL_SIZE), color);
+				}
+			}
+			return bg;
+		}
+
+		static function createSource(): BitmapData {
+			// A grid of colors on a transparent background, crossed by a black bar.
+			var src: BitmapData = new BitmapData(SOURCE_WIDTH, SOURCE_HEIGHT, true, 0x00000000);
+			for (var row: uint = 0; row < 5; row++) {
+				for (var col: uint = 0; col < 5; col++) {
+					var color: uint = 0xFF0000CC | ((row * 50) << 16) | ((col * 50) << 8);
+					src.fillRect(new Rectangle(15 + col * 10, 15 + row * 10, 10, 10), color);
+				}
+			}
+			src.fillRect(new Rectangle(38, 5, 4, 70), 0xFF000000);
+			return src;
+		}
+
+		function addFiltered(count: uint, img: BitmapData, filters: Array): Bitmap {
+			var bitmap: Bitmap = new Bitmap(img);
+			var columns: uint = Math.floor(stage.stageWidth / SOURCE_WIDTH);
+			bitmap.x = (count % columns) * SOURCE_WIDTH;
+			bitmap.y = uint(count / columns) * SOURCE_HEIGHT;
+			bitmap.filters = filters;
+			addChild(bitmap);
+			return bitmap;
+		}
+	}
+}
@@ -0,0 +1,50 @@
+package {
+	import flash.display.MovieClip;
+	import flash.events.Event;
+	import flash.events.ProgressEvent;
+	import flash.net.FileReference;
+
+	// The file dialogs are answered by the `[[file_dialogs]]` of `test.toml`:
+	// a file is picked, then a dialog is cancelled, then a file is saved.
+	public class Test extends MovieClip {
+		private var browseFile:FileReference = new FileReference();
+		private var cancelFile:FileReference = new FileReference();
+		private var saveFile:FileReference = new FileReference();
+
+		public function Test() {
+			listen(browseFile, "browse");
+			listen(cancelFile, "cancel");
+			listen(saveFile, "save");
+
+			browseFile.addEventListener(Event.SELECT, function(event:Event):void {
+				trace("name = " + browseFile.name + ", size = " + browseFile.size + ", type = " + browseFile.type);
+				browseFile.load();
+			});
+			browseFile.addEventListener(Event.COMPLETE, function(event:Event):void {
+				trace("data = " + browseFile.data.readUTFBytes(browseFile.data.bytesAvailable));
+				trace("cancelFile.browse(): " + cancelFile.browse());
+			});
+			cancelFile.addEventListener(Event.CANCEL, function(event:Event):void {
+				saveFile.save("Saved text", "default.txt");
+			});
+			saveFile.addEventListener(Event.COMPLETE, function(event:Event):void {
+				trace("name = " + saveFile.name + ", size = " + saveFile.size);
+			});
+
+			trace("browseFile.browse(): " + browseFile.browse());
+		}
+
+		private function listen(file:FileReference, label:String):void {
+			var onEvent:Function = function(event:Event):void {
+				if (event is ProgressEvent) {
+					trace(label + ": " + event.type + ", bytesLoaded = " + ProgressEvent(event).bytesLoaded + ", bytesTotal = " + ProgressEvent(event).bytesTotal);
+				} else {
+					trace(label + ": " + event.type);
+				}
+			};
+			for each (var type:String in [Event.SELECT, Event.CANCEL, Event.OPEN, ProgressEvent.PROGRESS, Event.COMPLETE]) {
+				file.addEventListener(type, onEvent);
+			}
+		}
+	}
+}
@@ -0,0 +1,115 @@
+package {
+	import flash.display.*;
+	import flash.utils.getQualifiedClassName;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			drawTriangles();
+			triangleCulling();
+			drawPathWinding();
+			cubicCurveTo();
+			graphicsDataRoundTrip();
+		}
+
+		function newShape():Shape {
+			var shape:Shape = new Shape();
+			addChild(shape);
+			return shape;
+		}
+
+		function hits(shape:Shape, points:Array):String {
+			var result:Array = [];
+			for (var i:int = 0; i < points.length; i += 2) {
+				result.push(shape.hitTestPoint(points[i], points[i + 1], true));
+			}
+			return result.join(",");
+		}
+
+		function drawTriangles() {
+			trace("// drawTriangles without indices");
+			var shape:Shape = newShape();
+			shape.graphics.beginFill(0xFF0000);
+			shape.graphics.drawTriangles(Vector.<Number>([0, 0, 40, 0, 0, 40, 50, 0, 90, 0, 50, 40]));
+			shape.graphics.endFill();
+			trace(shape.getBounds(shape));
+			trace(hits(shape, [10, 10, 35, 35, 60, 10, 45, 5]));
+
+			trace("// drawTriangles with indices and a line style");
+			shape = newShape();
+			shape.graphics.lineStyle(4, 0x0000FF);
+			shape.graphics.moveTo(0, 100);
+			shape.graphics.drawTriangles(Vector.<Number>([100, 0, 140, 0, 100, 40, 140, 40]), Vector.<int>([0, 1, 2, 1, 3, 2]));
+			// The line continues from where the pen was before the triangles.
+			shape.graphics.lineTo(20, 100);
+			trace(hits(shape, [10, 100, 60, 50, 120, 0, 115, 15]));
+		}
+
+		function triangleCulling() {
+			trace("// drawTriangles culling");
+			// The first triangle goes clockwise on screen, the second one counter-clockwise.
+			var vertices:Vector.<Number> = Vector.<Number>([0, 0, 40, 0, 0, 40, 50, 0, 50, 40, 90, 0]);
+			for each (var culling:String in [TriangleCulling.NONE, TriangleCulling.POSITIVE, TriangleCulling.NEGATIVE]) {
+				var shape:Shape = newShape();
+				shape.graphics.beginFill(0x00FF00);
+				shape.graphics.drawTriangles(vertices, null, null, culling);
+				shape.graphics.endFill();
+				trace(culling, hits(shape, [10, 10, 60, 10]));
+			}
+		}
+
+		function drawPathWinding() {
+			trace("// drawPath winding");
+			// Two overlapping squares drawn in the same direction.
+			var commands:Vector.<int> = Vector.<int>([1, 2, 2, 2, 2, 1, 2, 2, 2, 2]);
+			var data:Vector.<Number> = Vector.<Number>([0, 0, 40, 0, 40, 40, 0, 40, 0, 0, 20, 20, 60, 20, 60, 60, 20, 60, 20, 20]);
+			for each (var winding:String in [GraphicsPathWinding.EVEN_ODD, GraphicsPathWinding.NON_ZERO]) {
+				var shape:Shape = newShape();
+				shape.graphics.beginFill(0x0000FF);
+				shape.graphics.drawPath(commands, data, winding);
+				shape.graphics.endFill();
+				trace(winding, hits(shape, [30, 30, 10, 10, 50, 50, 50, 10]));
+			}
+		}
+
+		function cubicCurveTo() {
+			trace("// cubicCurveTo");
+			var shape:Shape = newShape();
+			shape.graphics.beginFill(0xFF00FF);
+			shape.graphics.moveTo(0, 0);
+			shape.graphics.cubicCurveTo(0, 100, 100, 100, 100, 0);
+			shape.graphics.endFill();
+			// The curve reaches down to y = 75 at x = 50.
+			trace(hits(shape, [50, 70, 50, 80, 10, 10, 10, 60]));
+		}
+
+		function graphicsDataRoundTrip() {
+			trace("// drawGraphicsData and readGraphicsData");
+			var data:Vector.<IGraphicsData> = new Vector.<IGraphicsData>();
+			data.push(new GraphicsSolidFill(0xFF0000, 0.5));
+			data.push(new GraphicsPath(Vector.<int>([2, 2, 2]), Vector.<Number>([10, 0, 10, 10, 0, 10])));
+			data.push(new GraphicsEndFill());
+			data.push(new GraphicsSolidFill(0x00FF00));
+			data.push(new GraphicsTrianglePath(Vector.<Number>([20, 0, 30, 0, 20, 10]), Vector.<int>([0, 1, 2]), Vector.<Number>([0, 0, 1, 0, 0, 1])));
+			data.push(new GraphicsEndFill());
+			data.push(new GraphicsStroke(2, false, LineScaleMode.NORMAL, CapsStyle.ROUND, JointStyle.ROUND, 3, new GraphicsSolidFill(0x0000FF)));
+			data.push(new GraphicsPath(Vector.<int>([2]), Vector.<Number>([30, 10])));
+
+			var shape:Shape = newShape();
+			shape.graphics.drawGraphicsData(data);
+			for each (var item:Object in shape.graphics.readGraphicsData()) {
+				var name:String = getQualifiedClassName(item);
+				if (item is GraphicsSolidFill) {
+					trace(name, item.color, item.alpha);
+				} else if (item is GraphicsPath) {
+					trace(name, item.commands, item.data, item.winding);
+				} else if (item is GraphicsTrianglePath) {
+					trace(name, item.vertices, item.indices, item.uvtData, item.culling);
+				} else if (item is GraphicsStroke) {
+					trace(name, item.thickness, item.caps, item.joints, item.fill ? item.fill.color : null);
+				} else {
+					trace(name);
+				}
+			}
+		}
+	}
+}
@@ -0,0 +1,50 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Sprite;
+	import flash.events.Event;
+	import flash.events.KeyboardEvent;
+	import flash.events.MouseEvent;
+	import flash.text.TextField;
+	import flash.text.TextFieldType;
+
+	// Checks the events injected by the key release, mouse wheel, text, clipboard
+	// and window focus entries of `input.json`.
+	public class Test extends MovieClip {
+		private var field:TextField = new TextField();
+
+		public function Test() {
+			var box:Sprite = new Sprite();
+			box.name = "box";
+			box.graphics.beginFill(0xFF0000);
+			box.graphics.drawRect(0, 0, 100, 100);
+			box.graphics.endFill();
+			box.addEventListener(MouseEvent.MOUSE_WHEEL, onMouseWheel);
+			addChild(box);
+
+			field.name = "field";
+			field.type = TextFieldType.INPUT;
+			field.x = 200;
+			field.width = 200;
+			field.height = 30;
+			addChild(field);
+			stage.focus = field;
+
+			stage.addEventListener(KeyboardEvent.KEY_DOWN, onKey);
+			stage.addEventListener(KeyboardEvent.KEY_UP, onKey);
+			addEventListener(Event.ACTIVATE, onFocusEvent);
+			addEventListener(Event.DEACTIVATE, onFocusEvent);
+		}
+
+		private function onMouseWheel(event:MouseEvent):void {
+			trace("mouseWheel: delta = " + event.delta + ", target = " + event.target.name);
+		}
+
+		private function onKey(event:KeyboardEvent):void {
+			trace(event.type + ": keyCode = " + event.keyCode + ", target = " + event.target.name + ", text = \"" + field.text + "\"");
+		}
+
+		private function onFocusEvent(event:Event):void {
+			trace(event.type);
+		}
+	}
+}
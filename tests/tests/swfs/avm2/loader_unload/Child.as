@@ -0,0 +1,30 @@
+package {
+	import flash.display.MovieClip;
+	import flash.events.Event;
+	import flash.events.TimerEvent;
+	import flash.media.Sound;
+	import flash.media.SoundChannel;
+	import flash.utils.Timer;
+
+	// The main class of child.swf, compiled together with ChildSound.as.
+	public class Child extends MovieClip {
+		public var frames: int = 0;
+		public var timerTicks: int = 0;
+		public var channel: SoundChannel;
+
+		public function Child() {
+			addEventListener(Event.ENTER_FRAME, function(e: Event): void {
+				frames++;
+			});
+
+			var timer = new Timer(10);
+			timer.addEventListener(TimerEvent.TIMER, function(e: TimerEvent): void {
+				timerTicks++;
+			});
+			timer.start();
+
+			var sound: Sound = new ChildSound();
+			channel = sound.play();
+		}
+	}
+}
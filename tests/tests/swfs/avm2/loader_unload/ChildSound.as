@@ -0,0 +1,7 @@
+package {
+	import flash.media.Sound;
+
+	// Linked to the sound embedded in child.swf.
+	public class ChildSound extends Sound {
+	}
+}
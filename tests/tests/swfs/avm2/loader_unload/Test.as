@@ -0,0 +1,93 @@
+package {
+	import flash.display.Loader;
+	import flash.display.LoaderInfo;
+	import flash.display.MovieClip;
+	import flash.events.Event;
+	import flash.events.IOErrorEvent;
+	import flash.net.URLRequest;
+
+	public class Test extends MovieClip {
+		var loader: Loader;
+		var info: LoaderInfo;
+		var child: Object;
+		var cancelled: Loader;
+
+		var lastFrames: int = 0;
+		var lastTimerTicks: int = 0;
+		var lastPosition: Number = 0;
+		var framesSinceComplete: int = -1;
+
+		public function Test() {
+			loader = new Loader();
+			info = loader.contentLoaderInfo;
+			for each (var type in [Event.OPEN, Event.INIT, Event.COMPLETE, Event.UNLOAD]) {
+				info.addEventListener(type, function(e: Event): void {
+					trace("loader: " + e.type + " event");
+				});
+			}
+			info.addEventListener(Event.COMPLETE, onComplete);
+			loader.load(new URLRequest("child.swf"));
+			addChild(loader);
+
+			// A load that is cancelled before it completes must not fire any events.
+			cancelled = new Loader();
+			for each (var type2 in [Event.OPEN, Event.INIT, Event.COMPLETE, Event.UNLOAD, IOErrorEvent.IO_ERROR]) {
+				cancelled.contentLoaderInfo.addEventListener(type2, function(e: Event): void {
+					trace("cancelled loader: " + e.type + " event");
+				});
+			}
+			cancelled.load(new URLRequest("child.swf"));
+			cancelled.unload();
+			trace("cancelled loader: unloaded before loading");
+
+			addEventListener(Event.ENTER_FRAME, onEnterFrame);
+		}
+
+		function onComplete(e: Event): void {
+			child = loader.content;
+			trace("loader.content is Child: " + (child != null && child.frames !== undefined));
+			trace("info.bytesLoaded == info.bytesTotal: " + (info.bytesLoaded == info.bytesTotal));
+			framesSinceComplete = 0;
+		}
+
+		function onEnterFrame(e: Event): void {
+			if (framesSinceComplete < 0) {
+				return;
+			}
+			framesSinceComplete++;
+
+			if (framesSinceComplete == 3) {
+				trace("");
+				trace("// before unloadAndStop()");
+				traceChild();
+
+				loader.unloadAndStop();
+
+				trace("");
+				trace("// after unloadAndStop()");
+				trace("loader.content: " + loader.content);
+				trace("loader.numChildren: " + loader.numChildren);
+				trace("info.content: " + info.content);
+				trace("info.url: " + info.url);
+				trace("info.bytesLoaded: " + info.bytesLoaded);
+				trace("info.bytesTotal: " + info.bytesTotal);
+			} else if (framesSinceComplete == 6) {
+				trace("");
+				trace("// three frames after unloadAndStop()");
+				traceChild();
+				trace("cancelled.content: " + cancelled.content);
+			}
+		}
+
+		// Whether the child's enterFrame listener, timer and sound kept running
+		// since the last call.
+		function traceChild(): void {
+			trace("child frames advancing: " + (child.frames > lastFrames));
+			trace("child timer ticking: " + (child.timerTicks > lastTimerTicks));
+			trace("child sound playing: " + (child.channel.position > lastPosition));
+			lastFrames = child.frames;
+			lastTimerTicks = child.timerTicks;
+			lastPosition = child.channel.position;
+		}
+	}
+}
@@ -0,0 +1,47 @@
+package {
+	import flash.display.Loader;
+	import flash.display.MovieClip;
+	import flash.events.Event;
+	import flash.events.StatusEvent;
+	import flash.net.LocalConnection;
+	import flash.net.URLRequest;
+
+	public class Test extends MovieClip {
+		var receiver: LocalConnection;
+		var loader: Loader;
+
+		public function Test() {
+			receiver = new LocalConnection();
+			receiver.client = {
+				fromAvm1: function(message: String, number: Number): void {
+					trace("AVM2 received: " + message + ", " + number);
+				}
+			};
+
+			trace("receiver.domain: " + receiver.domain);
+
+			try {
+				receiver.connect("bad:name");
+				trace("connect(\"bad:name\") did not throw");
+			} catch (e: ArgumentError) {
+				trace("connect(\"bad:name\") threw " + e.errorID);
+			}
+
+			receiver.connect("avm2_connection");
+			trace("AVM2 connected to avm2_connection");
+
+			loader = new Loader();
+			loader.contentLoaderInfo.addEventListener(Event.COMPLETE, onComplete);
+			loader.load(new URLRequest("child.swf"));
+			addChild(loader);
+		}
+
+		function onComplete(event: Event): void {
+			var sender = new LocalConnection();
+			sender.addEventListener(StatusEvent.STATUS, function(event: StatusEvent): void {
+				trace("AVM2 send status: " + event.level);
+			});
+			sender.send("avm1_connection", "fromAvm2", "hello from AVM2", 2);
+		}
+	}
+}
@@ -0,0 +1,16 @@
+// Compiled as an AVM1 (SWF 8) movie, and loaded by test.swf.
+
+var receiver = new LocalConnection();
+receiver.fromAvm2 = function(message, number) {
+	trace("AVM1 received: " + message + ", " + number);
+
+	var sender = new LocalConnection();
+	sender.onStatus = function(info) {
+		trace("AVM1 send status: " + info.level);
+	};
+	sender.send("avm2_connection", "fromAvm1", "hello from AVM1", 1);
+};
+
+trace("AVM1 connect(\"bad:name\"): " + receiver.connect("bad:name"));
+trace("AVM1 connect(\"avm1_connection\"): " + receiver.connect("avm1_connection"));
+stop();
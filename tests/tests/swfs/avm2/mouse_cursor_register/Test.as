@@ -0,0 +1,57 @@
+package {
+	import flash.display.BitmapData;
+	import flash.display.MovieClip;
+	import flash.geom.Point;
+	import flash.ui.Mouse;
+	import flash.ui.MouseCursor;
+	import flash.ui.MouseCursorData;
+
+	// The test UI backend logs registered cursors, and custom cursors when they're displayed.
+	public class Test extends MovieClip {
+		public function Test() {
+			trace("Mouse.supportsNativeCursor: " + Mouse.supportsNativeCursor);
+			trace("Mouse.cursor: " + Mouse.cursor);
+
+			attempt("Mouse.cursor = \"spinner\"", function():void { Mouse.cursor = "spinner"; });
+			attempt("Mouse.cursor = null", function():void { Mouse.cursor = null; });
+			attempt("Mouse.registerCursor(null, data)", function():void { Mouse.registerCursor(null, new MouseCursorData()); });
+			attempt("Mouse.registerCursor(\"spinner\", null)", function():void { Mouse.registerCursor("spinner", null); });
+			attempt("Mouse.registerCursor(\"spinner\", data without bitmaps)", function():void { Mouse.registerCursor("spinner", new MouseCursorData()); });
+			var empty:MouseCursorData = new MouseCursorData();
+			empty.data = new Vector.<BitmapData>();
+			attempt("Mouse.registerCursor(\"spinner\", data with no bitmaps)", function():void { Mouse.registerCursor("spinner", empty); });
+
+			var data:MouseCursorData = new MouseCursorData();
+			data.data = Vector.<BitmapData>([new BitmapData(16, 16, true, 0xFF000000), new BitmapData(16, 16, true, 0xFFFFFFFF)]);
+			data.hotSpot = new Point(1, 2);
+			data.frameRate = 10;
+			Mouse.registerCursor("spinner", data);
+			Mouse.cursor = "spinner";
+			trace("Mouse.cursor: " + Mouse.cursor);
+
+			Mouse.cursor = MouseCursor.IBEAM;
+			trace("Mouse.cursor: " + Mouse.cursor);
+			Mouse.cursor = "spinner";
+
+			addFrameScript(1, frame2);
+		}
+
+		private function frame2():void {
+			Mouse.unregisterCursor("spinner");
+			trace("Mouse.cursor after unregisterCursor: " + Mouse.cursor);
+			attempt("Mouse.cursor = \"spinner\"", function():void { Mouse.cursor = "spinner"; });
+			// Unregistering an unknown cursor is ignored.
+			Mouse.unregisterCursor("spinner");
+			stop();
+		}
+
+		private function attempt(description:String, action:Function):void {
+			try {
+				action();
+				trace(description + ": no error");
+			} catch (e:Error) {
+				trace(description + ": " + e);
+			}
+		}
+	}
+}
@@ -0,0 +1,48 @@
+package {
+	import flash.display.MovieClip;
+	import flash.events.NetStatusEvent;
+	import flash.net.NetConnection;
+	import flash.net.ObjectEncoding;
+	import flash.net.Responder;
+
+	// The test navigator answers requests to `http://localhost/gateway` with the
+	// `localhost/gateway` file, which replies to both of the calls made to it.
+	public class Test extends MovieClip {
+		public function Test() {
+			var connection:NetConnection = new NetConnection();
+			connection.objectEncoding = ObjectEncoding.AMF0;
+			connection.addEventListener(NetStatusEvent.NET_STATUS, onNetStatus);
+			connection.connect("http://localhost/gateway");
+			trace("connection.connected: " + connection.connected);
+
+			connection.call("Greeter.greet", new Responder(onGreetResult, onGreetStatus), "world");
+			connection.call("Greeter.fail", new Responder(onFailResult, onFailStatus));
+
+			var missing:NetConnection = new NetConnection();
+			missing.objectEncoding = ObjectEncoding.AMF0;
+			missing.addEventListener(NetStatusEvent.NET_STATUS, onNetStatus);
+			missing.connect("http://localhost/missing");
+			missing.call("Greeter.greet", new Responder(onGreetResult, onGreetStatus), "world");
+		}
+
+		private function onGreetResult(result:Object):void {
+			trace("Greeter.greet result: greeting = " + result.greeting + ", count = " + result.count);
+		}
+
+		private function onGreetStatus(status:Object):void {
+			trace("Greeter.greet status: " + status.code);
+		}
+
+		private function onFailResult(result:Object):void {
+			trace("Greeter.fail result: " + result);
+		}
+
+		private function onFailStatus(status:Object):void {
+			trace("Greeter.fail status: code = " + status.code + ", description = " + status.description);
+		}
+
+		private function onNetStatus(event:NetStatusEvent):void {
+			trace("netStatus: code = " + event.info.code + ", level = " + event.info.level);
+		}
+	}
+}
@@ -0,0 +1,38 @@
+package {
+    import flash.display.MovieClip;
+    import flash.events.Event;
+    import flash.events.NetStatusEvent;
+    import flash.media.Video;
+    import flash.net.NetConnection;
+    import flash.net.NetStream;
+
+    public class Test extends MovieClip {
+        private var ns:NetStream;
+
+        public function Test() {
+            var nc:NetConnection = new NetConnection();
+            nc.connect(null);
+
+            ns = new NetStream(nc);
+            ns.client = {
+                onMetaData: function(info:Object):void {
+                    trace("onMetaData: duration = " + info.duration + ", width = " + info.width +
+                        ", height = " + info.height + ", framerate = " + info.framerate);
+                }
+            };
+            ns.addEventListener(NetStatusEvent.NET_STATUS, function(e:NetStatusEvent):void {
+                trace("netStatus: " + e.info.code + " (" + e.info.level + ")");
+            });
+
+            var video:Video = new Video(16, 16);
+            video.attachNetStream(ns);
+            addChild(video);
+
+            addEventListener(Event.ENTER_FRAME, function(e:Event):void {
+                trace("ns.time = " + ns.time);
+            });
+
+            ns.play("video.flv");
+        }
+    }
+}
@@ -0,0 +1,53 @@
+package {
+	import flash.display.BitmapData;
+	import flash.display.MovieClip;
+	import flash.display.Shader;
+	import flash.filters.ShaderFilter;
+	import flash.geom.Point;
+	import flash.geom.Rectangle;
+	import flash.utils.ByteArray;
+
+	public class Test extends MovieClip {
+		// Compiled from shader.pbk.
+		private static const INVERT:Array = [165, 1, 0, 0, 0, 164, 6, 0, 73, 110, 118, 101, 114, 116, 161, 1, 2, 0, 0, 12, 95, 79, 117, 116, 67, 111, 111, 114, 100, 0, 163, 0, 4, 115, 114, 99, 0, 161, 2, 4, 2, 0, 15, 100, 115, 116, 0, 48, 1, 0, 240, 0, 0, 27, 0, 50, 2, 0, 224, 63, 128, 0, 0, 2, 2, 0, 226, 1, 0, 27, 0, 29, 2, 0, 16, 1, 0, 192, 0];
+
+		public function Test() {
+			var source:BitmapData = new BitmapData(3, 1, true, 0);
+			source.setPixel32(0, 0, 0xFFFF0000);
+			source.setPixel32(1, 0, 0x8000FF00);
+			source.setPixel32(2, 0, 0xFF0000FF);
+			var filter:ShaderFilter = new ShaderFilter(new Shader(bytes(INVERT)));
+
+			trace("// Whole bitmap");
+			var dest:BitmapData = new BitmapData(3, 1, true, 0);
+			dest.applyFilter(source, source.rect, new Point(0, 0), filter);
+			trace(pixels(dest));
+
+			trace("// Source rectangle and destination point");
+			dest = new BitmapData(3, 1, true, 0xFF223344);
+			dest.applyFilter(source, new Rectangle(1, 0, 2, 1), new Point(0, 0), filter);
+			trace(pixels(dest));
+
+			trace("// Clone");
+			var clone:ShaderFilter = filter.clone() as ShaderFilter;
+			trace(clone.shader == filter.shader);
+		}
+
+		private static function pixels(bitmap:BitmapData):String {
+			var result:Array = [];
+			for (var x:int = 0; x < bitmap.width; x++) {
+				result.push(bitmap.getPixel32(x, 0).toString(16));
+			}
+			return result.join(",");
+		}
+
+		private static function bytes(values:Array):ByteArray {
+			var result:ByteArray = new ByteArray();
+			for each (var value:int in values) {
+				result.writeByte(value);
+			}
+			result.position = 0;
+			return result;
+		}
+	}
+}
@@ -0,0 +1,59 @@
+package {
+	import flash.display.BitmapData;
+	import flash.display.MovieClip;
+	import flash.display.Shader;
+	import flash.display.ShaderJob;
+	import flash.events.ShaderEvent;
+	import flash.utils.ByteArray;
+
+	public class Test extends MovieClip {
+		// Compiled from shader.pbk.
+		private static const COORDS:Array = [165, 1, 0, 0, 0, 164, 6, 0, 67, 111, 111, 114, 100, 115, 161, 1, 2, 0, 0, 12, 95, 79, 117, 116, 67, 111, 111, 114, 100, 0, 161, 2, 4, 1, 0, 15, 100, 115, 116, 0, 161, 1, 1, 2, 0, 8, 115, 99, 97, 108, 101, 0, 162, 1, 100, 101, 102, 97, 117, 108, 116, 86, 97, 108, 117, 101, 0, 63, 128, 0, 0, 29, 1, 0, 193, 0, 0, 27, 0, 3, 1, 0, 193, 2, 0, 0, 0, 50, 1, 0, 32, 0, 0, 0, 0, 50, 1, 0, 16, 63, 128, 0, 0];
+
+		private static const INVERT:Array = [165, 1, 0, 0, 0, 164, 6, 0, 73, 110, 118, 101, 114, 116, 161, 1, 2, 0, 0, 12, 95, 79, 117, 116, 67, 111, 111, 114, 100, 0, 163, 0, 4, 115, 114, 99, 0, 161, 2, 4, 2, 0, 15, 100, 115, 116, 0, 48, 1, 0, 240, 0, 0, 27, 0, 50, 2, 0, 224, 63, 128, 0, 0, 2, 2, 0, 226, 1, 0, 27, 0, 29, 2, 0, 16, 1, 0, 192, 0];
+
+		public function Test() {
+			var shader:Shader = new Shader(bytes(COORDS));
+
+			trace("// Vector.<Number> target");
+			shader.data.scale.value = [2];
+			var vector:Vector.<Number> = new Vector.<Number>();
+			var job:ShaderJob = new ShaderJob(shader, vector, 2, 2);
+			job.start(true);
+			trace(vector);
+			trace(job.progress);
+
+			trace("// ByteArray target");
+			shader.data.scale.value = [1];
+			var byteArray:ByteArray = new ByteArray();
+			new ShaderJob(shader, byteArray, 1, 1).start(true);
+			trace(byteArray.length);
+			byteArray.position = 0;
+			trace(byteArray.readFloat(), byteArray.readFloat(), byteArray.readFloat(), byteArray.readFloat());
+
+			trace("// BitmapData target, asynchronously");
+			var source:BitmapData = new BitmapData(2, 1, true, 0);
+			source.setPixel32(0, 0, 0xFFFF0000);
+			source.setPixel32(1, 0, 0xFF0000FF);
+			var invert:Shader = new Shader(bytes(INVERT));
+			invert.data.src.input = source;
+			var target:BitmapData = new BitmapData(2, 1, true, 0);
+			var asyncJob:ShaderJob = new ShaderJob(invert, target);
+			asyncJob.addEventListener(ShaderEvent.COMPLETE, function(event:ShaderEvent):void {
+				trace("complete", event.bitmapData == target, asyncJob.progress);
+				trace(target.getPixel32(0, 0).toString(16), target.getPixel32(1, 0).toString(16));
+			});
+			asyncJob.start();
+			trace("started", target.getPixel32(0, 0).toString(16));
+		}
+
+		private static function bytes(values:Array):ByteArray {
+			var result:ByteArray = new ByteArray();
+			for each (var value:int in values) {
+				result.writeByte(value);
+			}
+			result.position = 0;
+			return result;
+		}
+	}
+}
@@ -0,0 +1,43 @@
+package {
+	import flash.display.MovieClip;
+	import flash.events.Event;
+	import flash.events.ProgressEvent;
+	import flash.net.Socket;
+
+	// The test runner connects every socket to an echo server.
+	public class Test extends MovieClip {
+		private var socket:Socket = new Socket();
+
+		public function Test() {
+			socket.addEventListener(Event.CONNECT, onConnect);
+			socket.addEventListener(ProgressEvent.SOCKET_DATA, onSocketData);
+			socket.connect("localhost", 8000);
+			trace("connecting, connected: " + socket.connected);
+		}
+
+		private function onConnect(event:Event):void {
+			trace("connect, connected: " + socket.connected);
+			socket.writeUTFBytes("Hello");
+			socket.writeInt(42);
+			trace("bytesPending: " + socket.bytesPending);
+			socket.flush();
+			trace("bytesPending after flush: " + socket.bytesPending);
+		}
+
+		private function onSocketData(event:ProgressEvent):void {
+			trace("socketData, bytesLoaded: " + event.bytesLoaded);
+			trace("bytesAvailable: " + socket.bytesAvailable);
+			trace("readUTFBytes: " + socket.readUTFBytes(5));
+			trace("readInt: " + socket.readInt());
+			trace("bytesAvailable: " + socket.bytesAvailable);
+
+			socket.close();
+			trace("closed, connected: " + socket.connected);
+			try {
+				socket.flush();
+			} catch (e:Error) {
+				trace("flush after close: " + e);
+			}
+		}
+	}
+}
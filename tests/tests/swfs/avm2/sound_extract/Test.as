@@ -0,0 +1,46 @@
+package {
+	import flash.display.MovieClip;
+	import flash.media.Sound;
+	import flash.utils.ByteArray;
+
+	public class Test extends MovieClip {
+		var sound: Sound;
+
+		public function Test() {
+			// 4000 stereo sample frames of a ramp, inverted on the right channel.
+			var pcm = new ByteArray();
+			for (var i = 0; i < 4000; i++) {
+				pcm.writeShort(i * 8);
+				pcm.writeShort(-i * 8);
+			}
+			pcm.position = 0;
+
+			sound = new Sound();
+			sound.loadPCMFromByteArray(pcm, 4000, "short", true, 44100);
+
+			extract("extract(out, 1000, 0)", 1000, 0);
+			extract("extract(out, 1000)", 1000, -1);
+			extract("extract(out, 1000)", 1000, -1);
+			extract("extract(out, 1000, 3500)", 1000, 3500);
+			extract("extract(out, 1000)", 1000, -1);
+			extract("extract(out, 10, 100)", 10, 100);
+			extract("extract(out, 10, 5000)", 10, 5000);
+		}
+
+		function extract(description: String, length: Number, startPosition: Number): void {
+			var out = new ByteArray();
+			var extracted = sound.extract(out, length, startPosition);
+			trace("// " + description);
+			trace("returned: " + extracted + ", bytes: " + out.length);
+
+			// Print the first and last sample frames, scaled back to 16-bit values.
+			if (extracted > 0) {
+				out.position = 0;
+				trace("first: " + out.readFloat() * 32768 + ", " + out.readFloat() * 32768);
+				out.position = out.length - 8;
+				trace("last: " + out.readFloat() * 32768 + ", " + out.readFloat() * 32768);
+			}
+			trace("");
+		}
+	}
+}
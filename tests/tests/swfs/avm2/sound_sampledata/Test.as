@@ -0,0 +1,34 @@
+package {
+	import flash.display.MovieClip;
+	import flash.events.SampleDataEvent;
+	import flash.media.Sound;
+	import flash.media.SoundChannel;
+
+	public class Test extends MovieClip {
+		var sound: Sound;
+		var channel: SoundChannel;
+		var requests: int = 0;
+
+		public function Test() {
+			sound = new Sound();
+			sound.addEventListener(SampleDataEvent.SAMPLE_DATA, onSampleData);
+			channel = sound.play();
+			trace("sound.play() returned a SoundChannel: " + (channel != null));
+		}
+
+		function onSampleData(event: SampleDataEvent): void {
+			requests++;
+			trace("sampleData #" + requests + ": position = " + event.position + ", data.length = " + event.data.length);
+
+			// Provide three full requests of a square wave, then stop by providing nothing.
+			if (requests > 3) {
+				return;
+			}
+			for (var i = 0; i < 2048; i++) {
+				var sample: Number = ((event.position + i) % 2048 < 1024) ? 0.25 : -0.25;
+				event.data.writeFloat(sample);
+				event.data.writeFloat(-sample);
+			}
+		}
+	}
+}
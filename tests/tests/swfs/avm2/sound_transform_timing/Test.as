@@ -0,0 +1,22 @@
+package {
+	import flash.display.MovieClip;
+	import flash.media.Sound;
+	import flash.media.SoundChannel;
+	import flash.media.SoundTransform;
+
+	// `Tone` is a 0.1 second, 44.1KHz mono sound with a constant amplitude of 0.25.
+	public class Test extends MovieClip {
+		public function Test() {
+			// Half volume, panned halfway to the right: 0.0625 on the left, 0.125 on the right.
+			var channel:SoundChannel = new Tone().play(0, 0, new SoundTransform(0.5, 0.5));
+			trace("frame 1: playing with volume " + channel.soundTransform.volume + ", pan " + channel.soundTransform.pan);
+			addFrameScript(3, frame4);
+		}
+
+		private function frame4():void {
+			// The first sound has finished by now, so there is silence before this one starts.
+			var channel:SoundChannel = new Tone().play();
+			trace("frame 4: playing with volume " + channel.soundTransform.volume + ", pan " + channel.soundTransform.pan);
+		}
+	}
+}
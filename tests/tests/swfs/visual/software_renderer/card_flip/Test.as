@@ -0,0 +1,28 @@
+package {
+	import flash.display.Sprite;
+
+	// Cards flipped around their X and Y axes, drawn with the movie's default perspective.
+	// The black corner shows which way each card is facing.
+	public class Test extends Sprite {
+		public function Test() {
+			addCard(60, 75, 0x3366CC).rotationY = -50;
+			addCard(150, 75, 0xCC3333).rotationX = 50;
+			// Past 90 degrees, the back of the card faces the viewer, mirrored.
+			addCard(240, 75, 0x33AA33).rotationY = 150;
+		}
+
+		private function addCard(x:Number, y:Number, color:uint):Sprite {
+			var card:Sprite = new Sprite();
+			card.graphics.beginFill(color);
+			card.graphics.drawRect(-30, -40, 60, 80);
+			card.graphics.endFill();
+			card.graphics.beginFill(0x000000);
+			card.graphics.drawRect(-30, -40, 20, 20);
+			card.graphics.endFill();
+			card.x = x;
+			card.y = y;
+			addChild(card);
+			return card;
+		}
+	}
+}
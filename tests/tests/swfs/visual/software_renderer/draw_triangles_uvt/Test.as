@@ -0,0 +1,25 @@
+package {
+	import flash.display.BitmapData;
+	import flash.display.MovieClip;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			graphics.beginFill(0x808080);
+			graphics.drawTriangles(Vector.<Number>([0, 0, 10, 0, 0, 10]));
+			graphics.endFill();
+
+			// A 2x2 bitmap stretched over a square: red, green / blue, yellow.
+			var bitmap:BitmapData = new BitmapData(2, 2, false);
+			bitmap.setPixel(0, 0, 0xFF0000);
+			bitmap.setPixel(1, 0, 0x00FF00);
+			bitmap.setPixel(0, 1, 0x0000FF);
+			bitmap.setPixel(1, 1, 0xFFFF00);
+			graphics.beginBitmapFill(bitmap, null, false, false);
+			graphics.drawTriangles(
+				Vector.<Number>([20, 20, 80, 20, 20, 80, 80, 80]),
+				Vector.<int>([0, 1, 2, 1, 3, 2]),
+				Vector.<Number>([0, 0, 1, 0, 0, 1, 1, 1]));
+			graphics.endFill();
+		}
+	}
+}
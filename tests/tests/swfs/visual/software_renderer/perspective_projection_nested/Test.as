@@ -0,0 +1,51 @@
+package {
+	import flash.display.Sprite;
+	import flash.geom.PerspectiveProjection;
+	import flash.geom.Point;
+
+	// Cards use the perspective projection of their nearest ancestor that has one,
+	// and nested 3D objects share the projection of the outermost one.
+	public class Test extends Sprite {
+		public function Test() {
+			var outer:Sprite = new Sprite();
+			outer.x = 20;
+			outer.y = 10;
+			outer.transform.perspectiveProjection = projection(100, 60, 65);
+			addChild(outer);
+			outer.addChild(card(60, 65, 0x3366CC)).rotationY = 45;
+
+			var inner:Sprite = new Sprite();
+			inner.x = 150;
+			inner.transform.perspectiveProjection = projection(30, 60, 65);
+			outer.addChild(inner);
+			inner.addChild(card(60, 65, 0xCC3333)).rotationY = 45;
+
+			var group:Sprite = new Sprite();
+			group.x = 320;
+			group.y = 75;
+			group.rotationX = 30;
+			addChild(group);
+			group.addChild(card(0, 0, 0x33AA33)).rotationY = 40;
+		}
+
+		private function projection(fieldOfView:Number, x:Number, y:Number):PerspectiveProjection {
+			var projection:PerspectiveProjection = new PerspectiveProjection();
+			projection.fieldOfView = fieldOfView;
+			projection.projectionCenter = new Point(x, y);
+			return projection;
+		}
+
+		private function card(x:Number, y:Number, color:uint):Sprite {
+			var card:Sprite = new Sprite();
+			card.graphics.beginFill(color);
+			card.graphics.drawRect(-30, -40, 60, 80);
+			card.graphics.endFill();
+			card.graphics.beginFill(0x000000);
+			card.graphics.drawRect(-30, -40, 20, 20);
+			card.graphics.endFill();
+			card.x = x;
+			card.y = y;
+			return card;
+		}
+	}
+}
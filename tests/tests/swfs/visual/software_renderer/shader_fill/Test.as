@@ -0,0 +1,31 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Shader;
+	import flash.display.Shape;
+	import flash.geom.Matrix;
+	import flash.utils.ByteArray;
+
+	public class Test extends MovieClip {
+		// Compiled from shader.pbk: red left of x = 30, blue from there on.
+		private static const SPLIT:Array = [165, 1, 0, 0, 0, 164, 5, 0, 83, 112, 108, 105, 116, 161, 1, 2, 0, 0, 12, 95, 79, 117, 116, 67, 111, 111, 114, 100, 0, 161, 2, 4, 2, 0, 15, 100, 115, 116, 0, 50, 1, 0, 128, 65, 240, 0, 0, 11, 1, 0, 128, 0, 0, 0, 0, 29, 2, 0, 32, 1, 0, 0, 0, 50, 2, 0, 128, 63, 128, 0, 0, 2, 2, 0, 128, 1, 0, 0, 0, 50, 2, 0, 64, 0, 0, 0, 0, 50, 2, 0, 16, 63, 128, 0, 0];
+
+		public function Test() {
+			// The fill lies outside of the stage in the coordinates of the shape.
+			var shape:Shape = new Shape();
+			shape.graphics.beginShaderFill(new Shader(bytes(SPLIT)), new Matrix(1, 0, 0, 1, 120, 0));
+			shape.graphics.drawRect(110, 10, 80, 80);
+			shape.graphics.endFill();
+			shape.x = -100;
+			addChild(shape);
+		}
+
+		private static function bytes(values:Array):ByteArray {
+			var result:ByteArray = new ByteArray();
+			for each (var value:int in values) {
+				result.writeByte(value);
+			}
+			result.position = 0;
+			return result;
+		}
+	}
+}
@@ -0,0 +1,23 @@
+package {
+	import flash.display.MovieClip;
+	import flash.display.Shape;
+
+	public class Test extends MovieClip {
+		public function Test() {
+			graphics.beginFill(0xFF0000);
+			graphics.drawRect(10, 10, 40, 30);
+			graphics.endFill();
+			graphics.beginFill(0x0000FF, 0.5);
+			graphics.drawRect(30, 20, 50, 50);
+			graphics.endFill();
+
+			var child:Shape = new Shape();
+			child.graphics.beginFill(0x00FF00);
+			child.graphics.drawRect(0, 0, 30, 10);
+			child.graphics.endFill();
+			child.x = 60;
+			child.y = 5;
+			addChild(child);
+		}
+	}
+}
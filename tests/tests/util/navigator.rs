@@ -5,16 +5,56 @@ use ruffle_core::backend::navigator::{
 };
 use ruffle_core::indexmap::IndexMap;
 use ruffle_core::loader::Error;
+use ruffle_core::socket::{ConnectionState, SocketAction, SocketHandle};
 use std::path::{Path, PathBuf};
+use std::sync::mpsc::{Receiver, Sender, TryRecvError};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
 use url::Url;
 
+/// A fake server that sends back anything written to the sockets connected to it.
+///
+/// Nothing is sent back until `echo` is called, which the test runner does once
+/// per frame, so that the data arrives on a predictable frame.
+#[derive(Clone, Default)]
+pub struct EchoServer(Arc<Mutex<Vec<EchoConnection>>>);
+
+struct EchoConnection {
+    handle: SocketHandle,
+    receiver: Receiver<Vec<u8>>,
+    sender: Sender<SocketAction>,
+}
+
+impl EchoServer {
+    /// Sends back the data written to every connection since the last call.
+    pub fn echo(&self) {
+        self.0.lock().unwrap().retain(|connection| loop {
+            match connection.receiver.try_recv() {
+                Ok(data) => {
+                    let action = SocketAction::Data(connection.handle, data);
+                    if connection.sender.send(action).is_err() {
+                        return false;
+                    }
+                }
+                Err(TryRecvError::Empty) => return true,
+                // The socket was closed by the movie.
+                Err(TryRecvError::Disconnected) => return false,
+            }
+        });
+    }
+}
+
 /// A `NavigatorBackend` used by tests that supports logging fetch requests.
 ///
 /// This can be used by tests that fetch data to verify that the request is correct.
+/// Requests for `http` and `https` URLs are answered with the file at `<host>/<path>`
+/// in the test directory.
+/// Sockets always connect to an `EchoServer`.
 pub struct TestNavigatorBackend {
     spawner: NullSpawner,
     relative_base_path: PathBuf,
     log: Option<TestLogBackend>,
+    echo_server: EchoServer,
 }
 
 impl TestNavigatorBackend {
@@ -22,11 +62,13 @@ impl TestNavigatorBackend {
         path: &Path,
         executor: &NullExecutor,
         log: Option<TestLogBackend>,
+        echo_server: EchoServer,
     ) -> Result<Self, std::io::Error> {
         Ok(Self {
             spawner: executor.spawner(),
             relative_base_path: path.canonicalize()?,
             log,
+            echo_server,
         })
     }
 
@@ -84,7 +126,14 @@ impl NavigatorBackend for TestNavigatorBackend {
         }
 
         let mut path = self.relative_base_path.clone();
-        path.push(request.url());
+        match Url::parse(request.url()) {
+            // Tests can't rely on a real server, so web URLs are served from the test directory.
+            Ok(url) if matches!(url.scheme(), "http" | "https") => {
+                path.push(url.host_str().unwrap_or_default());
+                path.push(url.path().trim_start_matches('/'));
+            }
+            _ => path.push(request.url()),
+        }
 
         Box::pin(async move {
             let url = Self::url_from_file_path(&path)
@@ -109,4 +158,29 @@ impl NavigatorBackend for TestNavigatorBackend {
     fn pre_process_url(&self, url: Url) -> Url {
         url
     }
+
+    fn connect_socket(
+        &mut self,
+        host: String,
+        port: u16,
+        _timeout: Duration,
+        handle: SocketHandle,
+        receiver: Receiver<Vec<u8>>,
+        sender: Sender<SocketAction>,
+    ) {
+        // Log request.
+        if let Some(log) = &self.log {
+            log.avm_trace("Navigator::connect_socket:");
+            log.avm_trace(&format!("  Host: {}", host));
+            log.avm_trace(&format!("  Port: {}", port));
+        }
+
+        // Tests can't rely on a real server, so connect to the echo server instead.
+        let _ = sender.send(SocketAction::Connect(handle, ConnectionState::Connected));
+        self.echo_server.0.lock().unwrap().push(EchoConnection {
+            handle,
+            receiver,
+            sender,
+        });
+    }
 }
@@ -1,8 +1,9 @@
 use crate::util::environment::wgpu_descriptors;
-use crate::util::runner::TestAudioBackend;
 use anyhow::{anyhow, Result};
 use approx::assert_relative_eq;
 use regex::Regex;
+use ruffle_core::backend::audio::{write_wav, OfflineAudioBackend};
+use ruffle_core::debugger::DebuggerAction;
 use ruffle_core::tag_utils::SwfMovie;
 use ruffle_core::{PlayerBuilder, ViewportDimensions};
 use ruffle_render::quality::StageQuality;
@@ -19,10 +20,13 @@ pub struct TestOptions {
     pub output_path: PathBuf,
     pub sleep_to_meet_frame_rate: bool,
     pub image_comparison: Option<ImageComparison>,
+    pub audio_comparison: Option<AudioComparison>,
     pub ignore: bool,
     pub approximations: Option<Approximations>,
     pub player_options: PlayerOptions,
     pub log_fetch: bool,
+    pub file_dialogs: Vec<FileDialogResponse>,
+    pub debugger: Option<DebuggerOptions>,
 }
 
 impl Default for TestOptions {
@@ -32,10 +36,13 @@ impl Default for TestOptions {
             output_path: PathBuf::from("output.txt"),
             sleep_to_meet_frame_rate: false,
             image_comparison: None,
+            audio_comparison: None,
             ignore: false,
             approximations: None,
             player_options: PlayerOptions::default(),
             log_fetch: false,
+            file_dialogs: Vec::new(),
+            debugger: None,
         }
     }
 }
@@ -50,6 +57,48 @@ impl TestOptions {
     }
 }
 
+/// The scripted response of the user to a file dialog, in the order the
+/// dialogs are opened.
+#[derive(Clone, Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct FileDialogResponse {
+    /// The files picked by the user, relative to the test directory.
+    /// If empty, the dialog is cancelled.
+    pub files: Vec<PathBuf>,
+}
+
+/// Attaches the ActionScript debugger, which logs every pause to the trace output.
+#[derive(Clone, Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct DebuggerOptions {
+    /// The breakpoints to set, such as `Main/init`, `class:Main` or `frame:2`.
+    pub breakpoints: Vec<String>,
+
+    /// How to continue after each pause, in order. Once these run out,
+    /// execution continues until the next breakpoint.
+    pub actions: Vec<DebuggerStep>,
+}
+
+#[derive(Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DebuggerStep {
+    Continue,
+    StepIn,
+    StepOver,
+    StepOut,
+}
+
+impl From<DebuggerStep> for DebuggerAction {
+    fn from(step: DebuggerStep) -> Self {
+        match step {
+            DebuggerStep::Continue => DebuggerAction::Continue,
+            DebuggerStep::StepIn => DebuggerAction::StepIn,
+            DebuggerStep::StepOver => DebuggerAction::StepOver,
+            DebuggerStep::StepOut => DebuggerAction::StepOut,
+        }
+    }
+}
+
 #[derive(Deserialize, Default)]
 #[serde(default, deny_unknown_fields)]
 pub struct Approximations {
@@ -118,22 +167,21 @@ impl PlayerOptions {
         };
 
         if let Some(render_options) = &self.with_renderer {
+            use ruffle_render_software::SoftwareRenderBackend;
             use ruffle_render_wgpu::backend::WgpuRenderBackend;
             use ruffle_render_wgpu::target::TextureTarget;
 
-            if let Some(descriptors) = wgpu_descriptors() {
+            if render_options.software {
+                player_builder = player_builder
+                    .with_quality(render_options.quality())
+                    .with_renderer(SoftwareRenderBackend::new(width, height));
+            } else if let Some(descriptors) = wgpu_descriptors() {
                 if render_options.is_supported(&descriptors.adapter) {
                     let target = TextureTarget::new(&descriptors.device, (width, height))
                         .map_err(|e| anyhow!(e.to_string()))?;
 
                     player_builder = player_builder
-                        .with_quality(match render_options.sample_count {
-                            16 => StageQuality::High16x16,
-                            8 => StageQuality::High8x8,
-                            4 => StageQuality::High,
-                            2 => StageQuality::Medium,
-                            _ => StageQuality::Low,
-                        })
+                        .with_quality(render_options.quality())
                         .with_renderer(
                             WgpuRenderBackend::new(descriptors.clone(), target)
                                 .map_err(|e| anyhow!(e.to_string()))?,
@@ -143,7 +191,7 @@ impl PlayerOptions {
         }
 
         if self.with_audio {
-            player_builder = player_builder.with_audio(TestAudioBackend::new());
+            player_builder = player_builder.with_audio(OfflineAudioBackend::new());
         }
 
         #[cfg(feature = "imgtests")]
@@ -159,7 +207,7 @@ impl PlayerOptions {
         if let Some(render) = &self.with_renderer {
             // If we don't actually want to check the renderer (ie we're just listing potential tests),
             // don't spend the cost to create it
-            if check_renderer && !render.optional {
+            if check_renderer && !render.optional && !render.software {
                 if let Some(descriptors) = wgpu_descriptors() {
                     if !render.is_supported(&descriptors.adapter) {
                         return false;
@@ -192,11 +240,11 @@ impl ImageComparison {
         actual_image: image::RgbaImage,
         expected_image: image::RgbaImage,
         test_path: &Path,
-        adapter_info: wgpu::AdapterInfo,
+        backend_name: &str,
     ) -> Result<()> {
         use anyhow::Context;
 
-        let suffix = format!("{}-{:?}", std::env::consts::OS, adapter_info.backend);
+        let suffix = format!("{}-{}", std::env::consts::OS, backend_name);
 
         let save_actual_image = || {
             actual_image
@@ -304,12 +352,98 @@ impl ImageComparison {
     }
 }
 
+#[derive(Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct AudioComparison {
+    tolerance: u16,
+    max_outliers: usize,
+}
+
+impl AudioComparison {
+    /// Compares the mixed audio against the contents of a WAV file written by `write_wav`.
+    pub fn test(
+        &self,
+        actual_samples: &[i16],
+        expected_wav: &[u8],
+        test_path: &Path,
+    ) -> Result<()> {
+        use anyhow::Context;
+
+        let save_actual_audio = || -> Result<()> {
+            write_wav(
+                std::io::BufWriter::new(fs::File::create(test_path.join("actual.wav"))?),
+                actual_samples,
+                OfflineAudioBackend::NUM_CHANNELS.into(),
+                OfflineAudioBackend::SAMPLE_RATE,
+            )
+            .context("Couldn't save actual audio")
+        };
+
+        let expected_samples = read_wav_samples(expected_wav)?;
+        if actual_samples.len() != expected_samples.len() {
+            save_actual_audio()?;
+            return Err(anyhow!(
+                "Audio is not the right length. Expected = {} samples, actual = {} samples.",
+                expected_samples.len(),
+                actual_samples.len()
+            ));
+        }
+
+        let differences = actual_samples
+            .iter()
+            .zip(&expected_samples)
+            .map(|(actual, expected)| actual.abs_diff(*expected));
+        let outliers = differences
+            .clone()
+            .filter(|difference| *difference > self.tolerance)
+            .count();
+        let max_difference = differences.max().unwrap_or_default();
+
+        if outliers > self.max_outliers {
+            save_actual_audio()?;
+            return Err(anyhow!(
+                "Number of outliers ({}) is bigger than allowed limit of {}. Max difference is {}",
+                outliers,
+                self.max_outliers,
+                max_difference
+            ));
+        } else {
+            println!("{outliers} outliers found, max difference {max_difference}",);
+        }
+
+        Ok(())
+    }
+}
+
+/// Reads the samples of a 16-bit PCM WAV file.
+fn read_wav_samples(wav: &[u8]) -> Result<Vec<i16>> {
+    if wav.len() < 12 || &wav[0..4] != b"RIFF" || &wav[8..12] != b"WAVE" {
+        return Err(anyhow!("Expected audio is not a WAV file"));
+    }
+    let mut chunks = &wav[12..];
+    while chunks.len() >= 8 {
+        let size = u32::from_le_bytes(chunks[4..8].try_into().unwrap()) as usize;
+        let data = chunks
+            .get(8..8 + size)
+            .ok_or_else(|| anyhow!("Expected audio is truncated"))?;
+        if &chunks[0..4] == b"data" {
+            return Ok(data
+                .chunks_exact(2)
+                .map(|sample| i16::from_le_bytes([sample[0], sample[1]]))
+                .collect());
+        }
+        chunks = &chunks[(8 + size + (size & 1)).min(chunks.len())..];
+    }
+    Err(anyhow!("Expected audio has no data"))
+}
+
 #[derive(Deserialize)]
 #[serde(default, deny_unknown_fields)]
 pub struct RenderOptions {
     optional: bool,
     sample_count: u32,
     exclude_warp: bool,
+    software: bool,
 }
 
 impl Default for RenderOptions {
@@ -318,11 +452,22 @@ impl Default for RenderOptions {
             optional: false,
             sample_count: 1,
             exclude_warp: false,
+            software: false,
         }
     }
 }
 
 impl RenderOptions {
+    fn quality(&self) -> StageQuality {
+        match self.sample_count {
+            16 => StageQuality::High16x16,
+            8 => StageQuality::High8x8,
+            4 => StageQuality::High,
+            2 => StageQuality::Medium,
+            _ => StageQuality::Low,
+        }
+    }
+
     pub fn is_supported(&self, adapter: &wgpu::Adapter) -> bool {
         let info = adapter.get_info();
         // 5140 & 140 is WARP, https://learn.microsoft.com/en-us/windows/win32/direct3ddxgi/d3d10-graphics-programming-guide-dxgi#new-info-about-enumerating-adapters-for-windows-8
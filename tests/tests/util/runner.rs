@@ -1,58 +1,27 @@
-use crate::util::navigator::TestNavigatorBackend;
+use crate::util::navigator::{EchoServer, TestNavigatorBackend};
+use crate::util::options::{DebuggerStep, FileDialogResponse};
 use crate::util::test::Test;
 use anyhow::{anyhow, Result};
-use ruffle_core::backend::audio::{
-    swf, AudioBackend, AudioMixer, DecodeError, RegisterError, SoundHandle, SoundInstanceHandle,
-    SoundTransform,
-};
+use ruffle_core::backend::audio::OfflineAudioBackend;
 use ruffle_core::backend::log::LogBackend;
 use ruffle_core::backend::navigator::NullExecutor;
+use ruffle_core::backend::ui::{
+    CustomMouseCursor, FileDialogFuture, FileFilter, FullscreenError, LanguageIdentifier,
+    LoadedFile, MouseCursor, SaveFile, UiBackend, US_ENGLISH,
+};
+use ruffle_core::debugger::DebuggerAction;
 use ruffle_core::events::KeyCode;
-use ruffle_core::events::MouseButton as RuffleMouseButton;
-use ruffle_core::impl_audio_mixer_backend;
 use ruffle_core::limits::ExecutionLimit;
 use ruffle_core::tag_utils::SwfMovie;
 use ruffle_core::{Player, PlayerBuilder, PlayerEvent};
-use ruffle_input_format::{AutomatedEvent, InputInjector, MouseButton as InputMouseButton};
+use ruffle_input_format::{AutomatedEvent, InputInjector};
 use std::cell::RefCell;
-use std::path::Path;
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
 use std::rc::Rc;
 use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
-pub struct TestAudioBackend {
-    mixer: AudioMixer,
-    buffer: Vec<f32>,
-}
-
-impl TestAudioBackend {
-    const NUM_CHANNELS: u8 = 2;
-    const SAMPLE_RATE: u32 = 44100;
-
-    pub fn new() -> Self {
-        Self {
-            mixer: AudioMixer::new(Self::NUM_CHANNELS, Self::SAMPLE_RATE),
-            buffer: vec![],
-        }
-    }
-}
-
-impl AudioBackend for TestAudioBackend {
-    impl_audio_mixer_backend!(mixer);
-    fn play(&mut self) {}
-    fn pause(&mut self) {}
-
-    fn set_frame_rate(&mut self, frame_rate: f64) {
-        let new_buffer_size =
-            ((Self::NUM_CHANNELS as u32 * Self::SAMPLE_RATE) as f64 / frame_rate).round() as usize;
-        self.buffer.resize(new_buffer_size, 0.0);
-    }
-    fn tick(&mut self) {
-        debug_assert!(!self.buffer.is_empty());
-        self.mixer.mix::<f32>(self.buffer.as_mut());
-    }
-}
-
 #[derive(Clone)]
 pub struct TestLogBackend {
     trace_output: Rc<RefCell<String>>,
@@ -77,6 +46,193 @@ impl LogBackend for TestLogBackend {
     }
 }
 
+/// A UI backend that keeps track of the clipboard, so that tests can paste text,
+/// and answers file dialogs with the responses scripted in `test.toml`.
+///
+/// Custom mouse cursors are logged to the trace output, so that tests can check them.
+pub struct TestUiBackend {
+    clipboard: String,
+    base_path: PathBuf,
+    file_dialogs: VecDeque<FileDialogResponse>,
+    log: TestLogBackend,
+}
+
+impl TestUiBackend {
+    pub fn new(
+        base_path: &Path,
+        file_dialogs: Vec<FileDialogResponse>,
+        log: TestLogBackend,
+    ) -> Self {
+        Self {
+            clipboard: String::new(),
+            base_path: base_path.to_owned(),
+            file_dialogs: file_dialogs.into(),
+            log,
+        }
+    }
+
+    /// The files picked in the next file dialog, or `None` if it's cancelled.
+    fn next_file_dialog(&mut self) -> Option<Vec<PathBuf>> {
+        let response = self.file_dialogs.pop_front()?;
+        if response.files.is_empty() {
+            None
+        } else {
+            Some(response.files)
+        }
+    }
+}
+
+/// A file saved by a test. The data written to it is discarded.
+struct TestSaveFile {
+    name: String,
+}
+
+impl SaveFile for TestSaveFile {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn write(&mut self, _data: &[u8]) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl UiBackend for TestUiBackend {
+    fn mouse_visible(&self) -> bool {
+        true
+    }
+
+    fn set_mouse_visible(&mut self, _visible: bool) {}
+
+    fn set_mouse_cursor(&mut self, cursor: MouseCursor) {
+        // Hovering buttons changes the cursor in many tests, so only custom cursors are logged.
+        if let MouseCursor::Custom(id) = cursor {
+            self.log.avm_trace("UiBackend::set_mouse_cursor:");
+            self.log.avm_trace(&format!("  Custom ID: {id}"));
+        }
+    }
+
+    fn supports_custom_mouse_cursor(&self) -> bool {
+        true
+    }
+
+    fn register_mouse_cursor(&mut self, id: u32, cursor: CustomMouseCursor) {
+        let frames: Vec<_> = cursor
+            .frames
+            .iter()
+            .map(|frame| format!("{}x{}", frame.width, frame.height))
+            .collect();
+        self.log.avm_trace("UiBackend::register_mouse_cursor:");
+        self.log.avm_trace(&format!("  ID: {id}"));
+        self.log
+            .avm_trace(&format!("  Frames: {}", frames.join(", ")));
+        self.log
+            .avm_trace(&format!("  Hot Spot: {:?}", cursor.hot_spot));
+        self.log
+            .avm_trace(&format!("  Frame Rate: {}", cursor.frame_rate));
+    }
+
+    fn unregister_mouse_cursor(&mut self, id: u32) {
+        self.log.avm_trace("UiBackend::unregister_mouse_cursor:");
+        self.log.avm_trace(&format!("  ID: {id}"));
+    }
+
+    fn clipboard_content(&mut self) -> String {
+        self.clipboard.clone()
+    }
+
+    fn set_clipboard_content(&mut self, content: String) {
+        self.clipboard = content;
+    }
+
+    fn set_fullscreen(&mut self, _is_full: bool) -> Result<(), FullscreenError> {
+        Ok(())
+    }
+
+    fn display_unsupported_message(&self) {}
+
+    fn display_root_movie_download_failed_message(&self) {}
+
+    fn message(&self, _message: &str) {}
+
+    fn open_virtual_keyboard(&self) {}
+
+    fn language(&self) -> &LanguageIdentifier {
+        &US_ENGLISH
+    }
+
+    fn display_file_open_dialog(
+        &mut self,
+        _filters: Vec<FileFilter>,
+        multiple: bool,
+    ) -> Option<FileDialogFuture<Vec<LoadedFile>>> {
+        let mut paths = self.next_file_dialog();
+        if let Some(paths) = &mut paths {
+            if !multiple {
+                paths.truncate(1);
+            }
+        }
+
+        let files = paths.map(|paths| {
+            paths
+                .into_iter()
+                .map(|path| LoadedFile {
+                    name: path
+                        .file_name()
+                        .map(|name| name.to_string_lossy().into_owned())
+                        .unwrap_or_default(),
+                    contents: std::fs::read(self.base_path.join(&path))
+                        .expect("File picked in test should exist"),
+                    // Leave the dates unset, to keep the test output deterministic.
+                    creation_date: None,
+                    modification_date: None,
+                })
+                .collect()
+        });
+        Some(Box::pin(async move { files }))
+    }
+
+    fn display_file_save_dialog(
+        &mut self,
+        _file_name: String,
+    ) -> Option<FileDialogFuture<Box<dyn SaveFile>>> {
+        let file = self.next_file_dialog().map(|paths| {
+            let file: Box<dyn SaveFile> = Box::new(TestSaveFile {
+                name: paths[0]
+                    .file_name()
+                    .map(|name| name.to_string_lossy().into_owned())
+                    .unwrap_or_default(),
+            });
+            file
+        });
+        Some(Box::pin(async move { file }))
+    }
+}
+
+/// Logs every pause of the debugger to the trace output, and resumes it
+/// with the actions scripted in `test.toml`.
+fn resume_debugger(
+    player: &mut Player,
+    actions: &mut VecDeque<DebuggerStep>,
+    log: &TestLogBackend,
+) {
+    let debugger = player.debugger_mut();
+    while let Some(state) = debugger.paused_state() {
+        let call_stack: Vec<_> = state.call_stack.iter().map(ToString::to_string).collect();
+        log.avm_trace("Debugger paused:");
+        log.avm_trace(&format!("  Call stack: {}", call_stack.join(" > ")));
+        log.avm_trace(&format!(
+            "  Instruction {}: {}",
+            state.offset, state.instruction
+        ));
+        log.avm_trace(&format!("  Stack: [{}]", state.stack.join(", ")));
+        let action = actions
+            .pop_front()
+            .map_or(DebuggerAction::Continue, Into::into);
+        debugger.resume(action);
+    }
+}
+
 /// Loads an SWF and runs it through the Ruffle core for a number of frames.
 /// Tests that the trace output matches the given expected output.
 pub fn run_swf(
@@ -92,15 +248,22 @@ pub fn run_swf(
     let frame_time_duration = Duration::from_millis(frame_time as u64);
 
     let log = TestLogBackend::new();
+    let echo_server = EchoServer::default();
     let navigator = TestNavigatorBackend::new(
         base_path,
         &executor,
         test.options.log_fetch.then(|| log.clone()),
+        echo_server.clone(),
     )?;
 
     let builder = PlayerBuilder::new()
         .with_log(log.clone())
         .with_navigator(navigator)
+        .with_ui(TestUiBackend::new(
+            base_path,
+            test.options.file_dialogs.clone(),
+            log.clone(),
+        ))
         .with_max_execution_duration(Duration::from_secs(300))
         .with_viewport_dimensions(
             movie.width().to_pixels() as u32,
@@ -116,6 +279,17 @@ pub fn run_swf(
         .with_movie(movie)
         .build();
 
+    let mut debugger_actions = VecDeque::new();
+    if let Some(debugger_options) = &test.options.debugger {
+        let mut player = player.lock().unwrap();
+        let debugger = player.debugger_mut();
+        for breakpoint in &debugger_options.breakpoints {
+            debugger.add_breakpoint(breakpoint.parse().map_err(|e: String| anyhow!(e))?);
+        }
+        debugger.attach();
+        debugger_actions.extend(debugger_options.actions.iter().copied());
+    }
+
     before_start(player.clone())?;
 
     for _ in 0..test.options.num_frames {
@@ -143,37 +317,57 @@ pub fn run_swf(
         {}
 
         player.lock().unwrap().run_frame();
+        resume_debugger(&mut player.lock().unwrap(), &mut debugger_actions, &log);
         player.lock().unwrap().update_timers(frame_time);
+        player.lock().unwrap().update_streams(frame_time);
         player.lock().unwrap().audio_mut().tick();
         executor.run();
+        echo_server.echo();
 
         injector.next(|evt, _btns_down| {
-            player.lock().unwrap().handle_event(match evt {
+            let event = match evt {
                 AutomatedEvent::MouseDown { pos, btn } => PlayerEvent::MouseDown {
                     x: pos.0,
                     y: pos.1,
-                    button: match btn {
-                        InputMouseButton::Left => RuffleMouseButton::Left,
-                        InputMouseButton::Middle => RuffleMouseButton::Middle,
-                        InputMouseButton::Right => RuffleMouseButton::Right,
-                    },
+                    button: (*btn).into(),
                 },
                 AutomatedEvent::MouseMove { pos } => PlayerEvent::MouseMove { x: pos.0, y: pos.1 },
                 AutomatedEvent::MouseUp { pos, btn } => PlayerEvent::MouseUp {
                     x: pos.0,
                     y: pos.1,
-                    button: match btn {
-                        InputMouseButton::Left => RuffleMouseButton::Left,
-                        InputMouseButton::Middle => RuffleMouseButton::Middle,
-                        InputMouseButton::Right => RuffleMouseButton::Right,
-                    },
+                    button: (*btn).into(),
                 },
                 AutomatedEvent::KeyDown { key_code } => PlayerEvent::KeyDown {
                     key_code: KeyCode::from_u8(*key_code).expect("Invalid keycode in test"),
                     key_char: None,
                 },
+                AutomatedEvent::KeyUp { key_code } => PlayerEvent::KeyUp {
+                    key_code: KeyCode::from_u8(*key_code).expect("Invalid keycode in test"),
+                    key_char: None,
+                },
+                AutomatedEvent::MouseWheel { delta } => PlayerEvent::MouseWheel {
+                    delta: (*delta).into(),
+                },
+                AutomatedEvent::MouseLeave => PlayerEvent::MouseLeave,
+                AutomatedEvent::TextInput { codepoint } => PlayerEvent::TextInput {
+                    codepoint: *codepoint,
+                },
+                AutomatedEvent::TextControl { code } => PlayerEvent::TextControl {
+                    code: (*code).into(),
+                },
+                AutomatedEvent::SetClipboardText { text } => {
+                    player
+                        .lock()
+                        .unwrap()
+                        .ui_mut()
+                        .set_clipboard_content(text.to_owned());
+                    return;
+                }
+                AutomatedEvent::FocusGained => PlayerEvent::FocusGained,
+                AutomatedEvent::FocusLost => PlayerEvent::FocusLost,
                 AutomatedEvent::Wait => unreachable!(),
-            });
+            };
+            player.lock().unwrap().handle_event(event);
         });
         // Rendering has side-effects (such as processing 'DisplayObject.scrollRect' updates)
         player.lock().unwrap().render();
@@ -183,19 +377,28 @@ pub fn run_swf(
     // FIXME: Determine how we want to compare against on on-disk image
     #[cfg(feature = "imgtests")]
     if let Some(image_comparison) = &test.options.image_comparison {
-        if crate::util::environment::wgpu_descriptors().is_some() {
-            use anyhow::Context;
-            use ruffle_render_wgpu::backend::WgpuRenderBackend;
-            use ruffle_render_wgpu::target::TextureTarget;
+        use anyhow::Context;
+        use ruffle_render_software::SoftwareRenderBackend;
+        use ruffle_render_wgpu::backend::WgpuRenderBackend;
+        use ruffle_render_wgpu::target::TextureTarget;
 
-            let mut player_lock = player.lock().unwrap();
-            player_lock.render();
-            let renderer = player_lock
-                .renderer_mut()
-                .downcast_mut::<WgpuRenderBackend<TextureTarget>>()
-                .unwrap();
+        let mut player_lock = player.lock().unwrap();
+        player_lock.render();
+        let renderer = player_lock.renderer_mut();
+        let capture = if let Some(renderer) =
+            renderer.downcast_mut::<WgpuRenderBackend<TextureTarget>>()
+        {
+            let backend_name = format!("{:?}", renderer.descriptors().adapter.get_info().backend);
+            Some((renderer.capture_frame(), backend_name))
+        } else {
+            renderer
+                .downcast_mut::<SoftwareRenderBackend>()
+                .map(|renderer| (renderer.capture_frame(), "Software".to_string()))
+        };
 
-            let actual_image = renderer.capture_frame().expect("Failed to capture image");
+        // Without a renderer (e.g. no GPU is available), there's nothing to compare.
+        if let Some((actual_image, backend_name)) = capture {
+            let actual_image = actual_image.expect("Failed to capture image");
 
             let expected_image_path = base_path.join("expected.png");
             if expected_image_path.is_file() {
@@ -203,18 +406,31 @@ pub fn run_swf(
                     .context("Failed to open expected image")?
                     .into_rgba8();
 
-                image_comparison.test(
-                    actual_image,
-                    expected_image,
-                    base_path,
-                    renderer.descriptors().adapter.get_info(),
-                )?;
+                image_comparison.test(actual_image, expected_image, base_path, &backend_name)?;
             } else {
                 actual_image.save(expected_image_path)?;
             }
         }
     }
 
+    if let Some(audio_comparison) = &test.options.audio_comparison {
+        let mut player_lock = player.lock().unwrap();
+        let audio = player_lock
+            .audio_mut()
+            .downcast_mut::<OfflineAudioBackend>()
+            .ok_or_else(|| anyhow!("audio_comparison requires player_options.with_audio"))?;
+
+        let expected_audio_path = base_path.join("expected.wav");
+        if expected_audio_path.is_file() {
+            let expected_audio = std::fs::read(&expected_audio_path)?;
+            audio_comparison.test(audio.samples(), &expected_audio, base_path)?;
+        } else {
+            audio.write_wav(std::io::BufWriter::new(std::fs::File::create(
+                expected_audio_path,
+            )?))?;
+        }
+    }
+
     before_end(player)?;
 
     executor.run();
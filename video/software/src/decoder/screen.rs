@@ -24,6 +24,18 @@ pub enum ScreenError {
 
     #[error("Not all blocks were updated by a supposed keyframe")]
     KeyframeInvalid,
+
+    #[error("Invalid color depth: {0}")]
+    InvalidColorDepth(u8),
+
+    #[error("Block rows {0}..{1} exceed the block height")]
+    InvalidDiffRows(usize, usize),
+
+    #[error("Invalid image size: {0}x{1}")]
+    InvalidImageSize(usize, usize),
+
+    #[error("Unsupported feature: {0}")]
+    UnsupportedFeature(&'static str),
 }
 
 impl From<ScreenError> for Error {
@@ -44,17 +56,17 @@ pub struct ScreenVideoDecoder {
     last_frame: Option<Vec<u8>>,
 }
 
-struct ByteReader<'a> {
+pub(crate) struct ByteReader<'a> {
     data: &'a [u8],
     pos: usize,
 }
 
 impl<'a> ByteReader<'a> {
-    fn new(data: &'a [u8]) -> Self {
+    pub(crate) fn new(data: &'a [u8]) -> Self {
         Self { data, pos: 0 }
     }
 
-    fn read_byte(&mut self) -> Result<u8, ScreenError> {
+    pub(crate) fn read_byte(&mut self) -> Result<u8, ScreenError> {
         if self.pos >= self.data.len() {
             return Err(ScreenError::UnexpectedEOF);
         }
@@ -63,13 +75,13 @@ impl<'a> ByteReader<'a> {
         Ok(byte)
     }
 
-    fn read_u16be(&mut self) -> Result<u16, ScreenError> {
+    pub(crate) fn read_u16be(&mut self) -> Result<u16, ScreenError> {
         let byte1 = self.read_byte()?;
         let byte2 = self.read_byte()?;
         Ok((byte1 as u16) << 8 | (byte2 as u16))
     }
 
-    fn read_buf_ref(&mut self, length: usize) -> Result<&[u8], ScreenError> {
+    pub(crate) fn read_buf_ref(&mut self, length: usize) -> Result<&'a [u8], ScreenError> {
         if self.pos + length > self.data.len() {
             return Err(ScreenError::UnexpectedEOF);
         }
@@ -77,6 +89,29 @@ impl<'a> ByteReader<'a> {
         self.pos += length;
         Ok(result)
     }
+
+    pub(crate) fn read_remaining(&mut self) -> &'a [u8] {
+        let result = &self.data[self.pos..];
+        self.pos = self.data.len();
+        result
+    }
+}
+
+/// Converts a decoded Screen Video image, which is stored bottom-up in BGR
+/// order, into top-down RGB.
+pub(crate) fn bgr_to_rgb_flipped(data: &[u8], w: usize, h: usize) -> Vec<u8> {
+    let mut rgb = vec![0u8; w * h * 3];
+
+    for y in 0..h {
+        let data_row = &data[y * w * 3..(y + 1) * w * 3];
+        let rgb_row = &mut rgb[(h - y - 1) * w * 3..(h - y) * w * 3];
+
+        for (bgr, rgb) in data_row.chunks(3).zip(rgb_row.chunks_mut(3)) {
+            rgb.copy_from_slice(&[bgr[2], bgr[1], bgr[0]]);
+        }
+    }
+
+    rgb
 }
 
 impl ScreenVideoDecoder {
@@ -190,17 +225,7 @@ impl VideoDecoder for ScreenVideoDecoder {
             return Err(ScreenError::KeyframeInvalid.into());
         }
 
-        let mut rgb = vec![0u8; w * h * 3];
-
-        // convert from BGR to RGB and flip Y
-        for y in 0..h {
-            let data_row = &data[y * w * 3..(y + 1) * w * 3];
-            let rgb_row = &mut rgb[(h - y - 1) * w * 3..(h - y) * w * 3];
-
-            for (bgr, rgb) in data_row.chunks(3).zip(rgb_row.chunks_mut(3)) {
-                rgb.copy_from_slice(&[bgr[2], bgr[1], bgr[0]]);
-            }
-        }
+        let rgb = bgr_to_rgb_flipped(&data, w, h);
 
         self.last_frame = Some(data);
 
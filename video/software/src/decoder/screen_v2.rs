@@ -0,0 +1,436 @@
+use crate::decoder::screen::{bgr_to_rgb_flipped, ByteReader, ScreenError};
+use crate::decoder::VideoDecoder;
+use ruffle_render::bitmap::BitmapFormat;
+use ruffle_video::error::Error;
+
+use flate2::{Decompress, FlushDecompress};
+use ruffle_video::frame::{DecodedFrame, EncodedFrame, FrameDependency};
+
+/// The palette used by hybrid blocks, as `0xRRGGBB` colors.
+const DEFAULT_PALETTE: [u32; 128] = [
+    0x000000, 0x333333, 0x666666, 0x999999, 0xCCCCCC, 0xFFFFFF, 0x330000, 0x660000, 0x990000,
+    0xCC0000, 0xFF0000, 0x003300, 0x006600, 0x009900, 0x00CC00, 0x00FF00, 0x000033, 0x000066,
+    0x000099, 0x0000CC, 0x0000FF, 0x333300, 0x666600, 0x999900, 0xCCCC00, 0xFFFF00, 0x003333,
+    0x006666, 0x009999, 0x00CCCC, 0x00FFFF, 0x330033, 0x660066, 0x990099, 0xCC00CC, 0xFF00FF,
+    0xFFFF33, 0xFFFF66, 0xFFFF99, 0xFFFFCC, 0xFF33FF, 0xFF66FF, 0xFF99FF, 0xFFCCFF, 0x33FFFF,
+    0x66FFFF, 0x99FFFF, 0xCCFFFF, 0xCCCC33, 0xCCCC66, 0xCCCC99, 0xCCCCFF, 0xCC33CC, 0xCC66CC,
+    0xCC99CC, 0xCCFFCC, 0x33CCCC, 0x66CCCC, 0x99CCCC, 0xFFCCCC, 0x999933, 0x999966, 0x9999CC,
+    0x9999FF, 0x993399, 0x996699, 0x99CC99, 0x99FF99, 0x339999, 0x669999, 0xCC9999, 0xFF9999,
+    0x666633, 0x666699, 0x6666CC, 0x6666FF, 0x663366, 0x669966, 0x66CC66, 0x66FF66, 0x336666,
+    0x996666, 0xCC6666, 0xFF6666, 0x333366, 0x333399, 0x3333CC, 0x3333FF, 0x336633, 0x339933,
+    0x33CC33, 0x33FF33, 0x663333, 0x993333, 0xCC3333, 0xFF3333, 0x003366, 0x336600, 0x660033,
+    0x006633, 0x330066, 0x663300, 0x336699, 0x669933, 0x993366, 0x339966, 0x663399, 0x996633,
+    0x6699CC, 0x99CC66, 0xCC6699, 0x66CC99, 0x9966CC, 0xCC9966, 0x99CCFF, 0xCCFF99, 0xFF99CC,
+    0x99FFCC, 0xCC99FF, 0xFFCC99, 0x111111, 0x222222, 0x444444, 0x555555, 0xAAAAAA, 0xBBBBBB,
+    0xDDDDDD, 0xEEEEEE,
+];
+
+/// Screen Video V2 decoder.
+///
+/// Blocks may be compressed with the same block of the last keyframe as their
+/// zlib dictionary. Priming from a block of the current frame is out of scope,
+/// and frames using it fail to decode.
+pub struct ScreenVideoV2Decoder {
+    w: usize,
+    h: usize,
+    block_w: usize,
+    block_h: usize,
+
+    tile: Vec<u8>, // acts as a scratch buffer
+
+    last_frame: Option<Vec<u8>>,
+
+    /// The decompressed contents of each block of the last keyframe, which
+    /// later blocks may use to prime their decompressor.
+    keyframe_blocks: Vec<Vec<u8>>,
+}
+
+/// The header of a single image block.
+struct BlockHeader {
+    color_depth: u8,
+
+    /// The first row of the block that is updated, and how many rows are.
+    rows: (usize, usize),
+
+    /// Whether the block data is compressed with the same block of the last
+    /// keyframe as its dictionary.
+    prime_previous: bool,
+}
+
+impl BlockHeader {
+    fn read(src: &mut ByteReader, cur_h: usize) -> Result<Self, ScreenError> {
+        let flags = src.read_byte()?;
+        let color_depth = (flags >> 3) & 0x3;
+        let has_diff_rows = flags & 0x4 != 0;
+        let prime_current = flags & 0x2 != 0;
+        let prime_previous = flags & 0x1 != 0;
+
+        let rows = if has_diff_rows {
+            let start = src.read_byte()? as usize;
+            let height = src.read_byte()? as usize;
+            if start >= cur_h || height == 0 || start + height > cur_h {
+                return Err(ScreenError::InvalidDiffRows(start, start + height));
+            }
+            (start, height)
+        } else {
+            (0, cur_h)
+        };
+
+        if prime_current {
+            return Err(ScreenError::UnsupportedFeature(
+                "zlib priming from the current frame",
+            ));
+        }
+
+        Ok(Self {
+            color_depth,
+            rows,
+            prime_previous,
+        })
+    }
+}
+
+impl ScreenVideoV2Decoder {
+    pub fn new() -> Self {
+        Self {
+            w: 0,
+            h: 0,
+            block_w: 0,
+            block_h: 0,
+            tile: vec![],
+            last_frame: None,
+            keyframe_blocks: vec![],
+        }
+    }
+
+    fn decode_v2(
+        &mut self,
+        src: &mut ByteReader,
+        data: &mut [u8],
+        stride: usize,
+        is_keyframe: bool,
+    ) -> Result<bool, Error> {
+        let blocks_per_row = (0..self.w).step_by(self.block_w).len();
+        let mut is_intra = true;
+        for (yy, row) in data.chunks_mut(stride * self.block_h).enumerate() {
+            let cur_h = (self.h - yy * self.block_h).min(self.block_h);
+            for (xx, x) in (0..self.w).step_by(self.block_w).enumerate() {
+                let cur_w = (self.w - x).min(self.block_w);
+                let block_index = yy * blocks_per_row + xx;
+
+                let data_size = src.read_u16be()? as usize;
+                if data_size == 0 {
+                    is_intra = false;
+                    continue;
+                }
+
+                let mut block = ByteReader::new(src.read_buf_ref(data_size)?);
+                let header = BlockHeader::read(&mut block, cur_h)?;
+                if header.rows != (0, cur_h) || header.prime_previous {
+                    is_intra = false;
+                }
+
+                let mut decompress = if header.prime_previous {
+                    match self.keyframe_blocks.get(block_index) {
+                        Some(dictionary) if !dictionary.is_empty() => {
+                            primed_decompressor(dictionary)?
+                        }
+                        _ => return Err(ScreenError::MissingReferenceFrame.into()),
+                    }
+                } else {
+                    Decompress::new(true)
+                };
+                let primed_len = decompress.total_out();
+                decompress
+                    .decompress(
+                        block.read_remaining(),
+                        &mut self.tile,
+                        FlushDecompress::Finish,
+                    )
+                    .map_err(ScreenError::DecompressionError)?;
+                let tile = &self.tile[..(decompress.total_out() - primed_len) as usize];
+
+                let (start, height) = header.rows;
+                let rows = row[start * stride + x * 3..]
+                    .chunks_mut(stride)
+                    .take(height);
+                match header.color_depth {
+                    0 => {
+                        if tile.len() < cur_w * height * 3 {
+                            return Err(ScreenError::UnexpectedEOF.into());
+                        }
+                        for (dst, src) in rows.zip(tile.chunks(cur_w * 3)) {
+                            dst[..cur_w * 3].copy_from_slice(src);
+                        }
+                    }
+                    2 => decode_hybrid(&mut ByteReader::new(tile), rows, cur_w)?,
+                    depth => return Err(ScreenError::InvalidColorDepth(depth).into()),
+                }
+
+                if is_keyframe {
+                    self.keyframe_blocks[block_index] = tile.to_vec();
+                }
+            }
+        }
+        Ok(is_intra)
+    }
+
+    fn flush(&mut self) {
+        self.last_frame = None;
+        self.keyframe_blocks.clear();
+    }
+}
+
+/// Decodes the rows of a hybrid block, where each pixel is either an index
+/// into the palette or a 15-bit color.
+fn decode_hybrid<'a>(
+    src: &mut ByteReader,
+    rows: impl Iterator<Item = &'a mut [u8]>,
+    cur_w: usize,
+) -> Result<(), ScreenError> {
+    for dst in rows {
+        for pixel in dst[..cur_w * 3].chunks_mut(3) {
+            let byte = src.read_byte()?;
+            let bgr = if byte & 0x80 != 0 {
+                let color = u16::from_be_bytes([byte & 0x7F, src.read_byte()?]);
+                let channel = |shift: u16| {
+                    let value = ((color >> shift) & 0x1F) as u8;
+                    value << 3 | value >> 2
+                };
+                [channel(0), channel(5), channel(10)]
+            } else {
+                let [_, r, g, b] = DEFAULT_PALETTE[byte as usize].to_be_bytes();
+                [b, g, r]
+            };
+            pixel.copy_from_slice(&bgr);
+        }
+    }
+    Ok(())
+}
+
+/// Creates a raw inflate stream whose window already holds `dictionary`, by
+/// feeding it the dictionary as uncompressed deflate blocks.
+fn primed_decompressor(dictionary: &[u8]) -> Result<Decompress, ScreenError> {
+    let mut stored = Vec::with_capacity(dictionary.len() + (dictionary.len() / 0xFFFF + 1) * 5);
+    for chunk in dictionary.chunks(0xFFFF) {
+        let len = chunk.len() as u16;
+        // BFINAL = 0, BTYPE = 00 (no compression)
+        stored.push(0);
+        stored.extend_from_slice(&len.to_le_bytes());
+        stored.extend_from_slice(&(!len).to_le_bytes());
+        stored.extend_from_slice(chunk);
+    }
+
+    let mut decompress = Decompress::new(false);
+    let mut output = vec![0; dictionary.len()];
+    decompress.decompress(&stored, &mut output, FlushDecompress::Sync)?;
+    Ok(decompress)
+}
+
+impl VideoDecoder for ScreenVideoV2Decoder {
+    fn preload_frame(&mut self, encoded_frame: EncodedFrame<'_>) -> Result<FrameDependency, Error> {
+        // As with Screen Video V1, the first byte holds the FrameType and
+        // CodecID fields of the FLV VIDEODATA tag.
+        let frame_type = *encoded_frame
+            .data
+            .first()
+            .ok_or(ScreenError::UnexpectedEOF)?;
+        debug_assert!(frame_type & 0xF == 6);
+
+        match frame_type >> 4 {
+            1 => Ok(FrameDependency::None),
+            2 => Ok(FrameDependency::Past),
+            x => Err(ScreenError::InvalidFrameType(x).into()),
+        }
+    }
+
+    fn decode_frame(&mut self, encoded_frame: EncodedFrame<'_>) -> Result<DecodedFrame, Error> {
+        let (frame_type, data) = encoded_frame
+            .data
+            .split_first()
+            .ok_or(ScreenError::UnexpectedEOF)?;
+        let is_keyframe = frame_type >> 4 == 1;
+
+        if !is_keyframe && self.last_frame.is_none() {
+            return Err(ScreenError::MissingReferenceFrame.into());
+        }
+
+        // The extra preceding byte was split off above
+        let mut br = ByteReader::new(data);
+
+        let hdr0 = br.read_u16be()? as usize;
+        let blk_w = (hdr0 >> 12) * 16 + 16;
+        let w = hdr0 & 0xFFF;
+
+        let hdr1 = br.read_u16be()? as usize;
+        let blk_h = (hdr1 >> 12) * 16 + 16;
+        let h = hdr1 & 0xFFF;
+
+        if w == 0 || h == 0 {
+            return Err(ScreenError::InvalidImageSize(w, h).into());
+        }
+
+        let flags = br.read_byte()?;
+        if flags & 0x2 != 0 {
+            return Err(ScreenError::UnsupportedFeature("I-frame images").into());
+        }
+        if flags & 0x1 != 0 {
+            return Err(ScreenError::UnsupportedFeature("custom palettes").into());
+        }
+
+        if self.w != w || self.h != h || self.block_w != blk_w || self.block_h != blk_h {
+            self.flush();
+            self.tile.resize(blk_w * blk_h * 3, 0);
+            self.w = w;
+            self.h = h;
+            self.block_w = blk_w;
+            self.block_h = blk_h;
+        }
+        if is_keyframe {
+            let num_blocks = (0..w).step_by(blk_w).len() * (0..h).step_by(blk_h).len();
+            self.keyframe_blocks.clear();
+            self.keyframe_blocks.resize(num_blocks, vec![]);
+        }
+
+        let mut data = self
+            .last_frame
+            .clone()
+            .unwrap_or_else(|| vec![0; w * h * 3]);
+
+        let stride = w * 3;
+
+        let is_intra = self.decode_v2(&mut br, data.as_mut_slice(), stride, is_keyframe)?;
+
+        if is_keyframe && !is_intra {
+            return Err(ScreenError::KeyframeInvalid.into());
+        }
+
+        let rgb = bgr_to_rgb_flipped(&data, w, h);
+
+        self.last_frame = Some(data);
+
+        Ok(DecodedFrame::new(
+            w as u32,
+            h as u32,
+            BitmapFormat::Rgb,
+            rgb,
+        ))
+    }
+}
+
+impl Default for ScreenVideoV2Decoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::write::ZlibEncoder;
+    use flate2::Compression;
+    use std::io::Write;
+
+    /// Builds a Screen Video V2 frame of a single 16x16 image, made of one block.
+    fn frame(is_keyframe: bool, block: &[u8]) -> Vec<u8> {
+        let frame_type = if is_keyframe { 1 } else { 2 };
+        let mut data = vec![frame_type << 4 | 6];
+        // Block size 16x16, image size 4x2.
+        data.extend_from_slice(&4u16.to_be_bytes());
+        data.extend_from_slice(&2u16.to_be_bytes());
+        // No I-frame image, no custom palette.
+        data.push(0);
+        data.extend_from_slice(&(block.len() as u16).to_be_bytes());
+        data.extend_from_slice(block);
+        data
+    }
+
+    /// Builds an image block with the given flags, diff rows and uncompressed pixels.
+    fn block(flags: u8, diff_rows: Option<(u8, u8)>, pixels: &[u8]) -> Vec<u8> {
+        let mut data = vec![flags];
+        if let Some((start, height)) = diff_rows {
+            data.extend_from_slice(&[start, height]);
+        }
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(pixels).unwrap();
+        data.extend(encoder.finish().unwrap());
+        data
+    }
+
+    fn decode(decoder: &mut ScreenVideoV2Decoder, data: &[u8]) -> Result<DecodedFrame, Error> {
+        decoder.decode_frame(EncodedFrame {
+            codec: swf::VideoCodec::ScreenVideoV2,
+            data,
+            frame_id: 0,
+        })
+    }
+
+    #[test]
+    fn keyframe_then_interframe() {
+        let mut decoder = ScreenVideoV2Decoder::new();
+
+        // Two BGR rows of four pixels each, stored bottom-up.
+        let bottom = [0, 0, 255].repeat(4);
+        let top = [255, 0, 0].repeat(4);
+        let keyframe = frame(true, &block(0, None, &[bottom, top].concat()));
+        let decoded = decode(&mut decoder, &keyframe).unwrap();
+        assert_eq!(decoded.width(), 4);
+        assert_eq!(decoded.height(), 2);
+        assert_eq!(
+            decoded.data(),
+            [[0, 0, 255].repeat(4), [255, 0, 0].repeat(4)].concat()
+        );
+
+        // Only update the bottom row, with hybrid palette indices for white.
+        let interframe = frame(false, &block(0x10 | 0x4, Some((0, 1)), &[5; 4]));
+        let decoded = decode(&mut decoder, &interframe).unwrap();
+        assert_eq!(
+            decoded.data(),
+            [[0, 0, 255].repeat(4), [255, 255, 255].repeat(4)].concat()
+        );
+    }
+
+    #[test]
+    fn interframe_without_keyframe() {
+        let mut decoder = ScreenVideoV2Decoder::new();
+        let interframe = frame(false, &block(0x4, Some((0, 1)), &[0; 12]));
+        assert!(decode(&mut decoder, &interframe).is_err());
+    }
+
+    #[test]
+    fn empty_frame() {
+        let mut decoder = ScreenVideoV2Decoder::new();
+        assert!(decode(&mut decoder, &[]).is_err());
+        assert!(decoder
+            .preload_frame(EncodedFrame {
+                codec: swf::VideoCodec::ScreenVideoV2,
+                data: &[],
+                frame_id: 0,
+            })
+            .is_err());
+    }
+
+    #[test]
+    fn zero_sized_image() {
+        let mut decoder = ScreenVideoV2Decoder::new();
+        let mut keyframe = frame(true, &block(0, None, &[0; 24]));
+        // Set the image width to 0.
+        keyframe[1..3].copy_from_slice(&0u16.to_be_bytes());
+        assert!(decode(&mut decoder, &keyframe).is_err());
+    }
+
+    #[test]
+    fn invalid_diff_rows() {
+        let mut decoder = ScreenVideoV2Decoder::new();
+        let keyframe = frame(true, &block(0, None, &[0; 24]));
+        decode(&mut decoder, &keyframe).unwrap();
+
+        for diff_rows in [(2, 0), (1, 0), (1, 2), (255, 255)] {
+            let interframe = frame(false, &block(0x4, Some(diff_rows), &[0; 12]));
+            assert!(
+                decode(&mut decoder, &interframe).is_err(),
+                "diff rows {diff_rows:?} should be rejected"
+            );
+        }
+    }
+}
@@ -6,8 +6,11 @@ use ruffle_core::backend::navigator::{
 use ruffle_core::config::NetworkingAccessMode;
 use ruffle_core::indexmap::IndexMap;
 use ruffle_core::loader::Error;
+use ruffle_core::socket::{ConnectionState, SocketAction, SocketHandle};
 use std::borrow::Cow;
+use std::sync::mpsc::{Receiver, Sender};
 use std::sync::Arc;
+use std::time::Duration;
 use tracing_subscriber::layer::Layered;
 use tracing_subscriber::Registry;
 use tracing_wasm::WASMLayer;
@@ -284,4 +287,18 @@ impl NavigatorBackend for WebNavigatorBackend {
         }
         url
     }
+
+    fn connect_socket(
+        &mut self,
+        host: String,
+        port: u16,
+        _timeout: Duration,
+        handle: SocketHandle,
+        _receiver: Receiver<Vec<u8>>,
+        sender: Sender<SocketAction>,
+    ) {
+        // Browsers don't allow opening raw TCP connections.
+        tracing::warn!("Can't connect a socket to {}:{} on web", host, port);
+        let _ = sender.send(SocketAction::Connect(handle, ConnectionState::Failed));
+    }
 }
@@ -1,6 +1,7 @@
 use super::JavascriptPlayer;
 use ruffle_core::backend::ui::{
-    FullscreenError, LanguageIdentifier, MouseCursor, UiBackend, US_ENGLISH,
+    CustomMouseCursor, FileDialogFuture, FileFilter, FullscreenError, LanguageIdentifier,
+    LoadedFile, MouseCursor, SaveFile, UiBackend, US_ENGLISH,
 };
 use ruffle_web_common::JsResult;
 use std::borrow::Cow;
@@ -41,6 +42,8 @@ impl WebUiBackend {
                 MouseCursor::Hand => "pointer",
                 MouseCursor::IBeam => "text",
                 MouseCursor::Grab => "grab",
+                // TODO: Custom cursors could be displayed with a `url()` cursor.
+                MouseCursor::Custom(_) => "auto",
             }
         } else {
             "none"
@@ -67,6 +70,14 @@ impl UiBackend for WebUiBackend {
         self.update_mouse_cursor();
     }
 
+    fn supports_custom_mouse_cursor(&self) -> bool {
+        false
+    }
+
+    fn register_mouse_cursor(&mut self, _id: u32, _cursor: CustomMouseCursor) {}
+
+    fn unregister_mouse_cursor(&mut self, _id: u32) {}
+
     fn clipboard_content(&mut self) -> String {
         // On web, clipboard content is not directly accessible due to security restrictions,
         // but pasting from the clipboard is supported via the JS `paste` event
@@ -143,4 +154,20 @@ impl UiBackend for WebUiBackend {
     fn language(&self) -> &LanguageIdentifier {
         &self.language
     }
+
+    // TODO: Implement file dialogs on web.
+    fn display_file_open_dialog(
+        &mut self,
+        _filters: Vec<FileFilter>,
+        _multiple: bool,
+    ) -> Option<FileDialogFuture<Vec<LoadedFile>>> {
+        None
+    }
+
+    fn display_file_save_dialog(
+        &mut self,
+        _file_name: String,
+    ) -> Option<FileDialogFuture<Box<dyn SaveFile>>> {
+        None
+    }
 }